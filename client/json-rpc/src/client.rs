@@ -47,6 +47,17 @@ impl JsonRpcBatch {
         );
     }
 
+    pub fn add_get_account_resources_request(
+        &mut self,
+        address: AccountAddress,
+        version: Option<u64>,
+    ) {
+        self.add_request(
+            "get_account_resources".to_string(),
+            vec![json!(address.to_string()), json!(version)],
+        );
+    }
+
     pub fn add_get_metadata_request(&mut self, version: Option<u64>) {
         self.add_request("get_metadata".to_string(), vec![json!(version)]);
     }
@@ -3,7 +3,7 @@
 
 use crate::views::{
     AccountStateWithProofView, AccountView, BlockMetadata, CurrencyInfoView, EventView,
-    StateProofView, TransactionView,
+    ResourceView, StateProofView, TransactionView,
 };
 use anyhow::{ensure, format_err, Error, Result};
 
@@ -15,6 +15,7 @@ use std::convert::TryFrom;
 pub enum JsonRpcResponse {
     SubmissionResponse,
     AccountResponse(Option<AccountView>),
+    AccountResourcesResponse(Option<Vec<ResourceView>>),
     StateProofResponse(StateProofView),
     AccountTransactionResponse(Option<TransactionView>),
     TransactionsResponse(Vec<TransactionView>),
@@ -49,6 +50,10 @@ impl TryFrom<(String, Value)> for JsonRpcResponse {
                 };
                 Ok(JsonRpcResponse::AccountResponse(account))
             }
+            "get_account_resources" => {
+                let resources: Option<Vec<ResourceView>> = serde_json::from_value(value)?;
+                Ok(JsonRpcResponse::AccountResourcesResponse(resources))
+            }
             "get_events" => {
                 let events: Vec<EventView> = serde_json::from_value(value)?;
                 Ok(JsonRpcResponse::EventsResponse(events))
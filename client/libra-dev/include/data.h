@@ -1,120 +1,1043 @@
 #ifndef LIBRA_DEV_H
 #define LIBRA_DEV_H
 
-#ifdef __cplusplus
-extern "C" {
-#endif
+/* This file was generated by cbindgen. Do not modify! */
 
-#include <stddef.h>
-#include <stdint.h>
 #include <stdbool.h>
 #include <stddef.h>
+#include <stdint.h>
 
 #define LIBRA_PUBKEY_SIZE 32
+
 #define LIBRA_PRIVKEY_SIZE 32
+
 #define LIBRA_SIGNATURE_SIZE 64
+
 #define LIBRA_ADDRESS_SIZE 16
+
 #define LIBRA_EVENT_KEY_SIZE 24
 
-enum LibraStatus {
-    Ok = 0,
-    InvalidArgument = -1,
-    InternalError = -255,
-};
-
-struct LibraEventHandle {
-    uint64_t count;
-    uint8_t key[LIBRA_EVENT_KEY_SIZE];
-};
-
-struct LibraAccountResource {
-    uint64_t balance;
-    uint64_t sequence;
-    uint8_t authentication_key[LIBRA_PUBKEY_SIZE];
-    bool delegated_key_rotation_capability;
-    bool delegated_withdrawal_capability;
-    struct LibraEventHandle sent_events;
-    struct LibraEventHandle received_events;
-};
-
-struct LibraP2PTransferTransactionArgument {
-    uint64_t value;
-    uint8_t address[LIBRA_ADDRESS_SIZE];
-    const uint8_t* metadata_bytes;
-    size_t metadata_len;
-    const uint8_t* metadata_signature_bytes;
-    size_t metadata_signature_len;
-};
-
-enum TransactionType {
-    PeerToPeer = 0,
-    Mint = 1,
-    Unknown = -1,
-};
-
-struct LibraTransactionPayload {
-    enum TransactionType txn_type;
-    struct LibraP2PTransferTransactionArgument args;
-};
-
-struct LibraRawTransaction {
-    uint8_t sender[LIBRA_ADDRESS_SIZE];
-    uint64_t sequence_number;
-    struct LibraTransactionPayload payload;
-    uint64_t max_gas_amount;
-    uint64_t gas_unit_price;
-    uint64_t expiration_time_secs;
-};
-
-struct LibraSignedTransaction {
-    struct LibraRawTransaction raw_txn;
-    uint8_t public_key[LIBRA_PUBKEY_SIZE];
-    uint8_t signature[LIBRA_SIGNATURE_SIZE];
-};
-
-struct LibraAccountKey {
-    uint8_t address[LIBRA_ADDRESS_SIZE];
-    uint8_t private_key[LIBRA_PRIVKEY_SIZE];
-    uint8_t public_key[LIBRA_PUBKEY_SIZE];
-};
-
-enum LibraEventType {
-    SentPaymentEvent = 1,
-    ReceivedPaymentEvent = 2,
-    UndefinedEvent = -1,
-};
-
-struct LibraPaymentEvent {
-    uint8_t sender_address[LIBRA_ADDRESS_SIZE];
-    uint8_t receiver_address[LIBRA_ADDRESS_SIZE];
-    uint64_t amount;
-    uint8_t* metadata;
-    size_t metadata_len;
-};
-
-struct LibraEvent {
-    enum LibraEventType event_type;
-    // TODO: address
-    uint8_t module[255];
-    uint8_t name[255];
-    // TODO: type_params
-    struct LibraPaymentEvent payment_event_data;
-    // TODO: other type of event_data
-};
-
-/*!
+#define LIBRA_AUTHENTICATION_KEY_SIZE 32
+
+/**
+ * Size of the buffers holding null-terminated identifiers, e.g. module names or currency codes.
+ */
+#define LIBRA_IDENTIFIER_SIZE 255
+
+typedef enum LibraEventType {
+  SentPaymentEvent = 1,
+  ReceivedPaymentEvent = 2,
+  BurnEvent = 3,
+  CancelBurnEvent = 4,
+  MintEvent = 5,
+  ToLBRExchangeRateUpdateEvent = 6,
+  PreburnEvent = 7,
+  UpgradeEvent = 8,
+  NewEpochEvent = 9,
+  NewBlockEvent = 10,
+  UndefinedEvent = -1,
+} LibraEventType;
+
+typedef enum LibraStatus {
+  Ok = 0,
+  InvalidArgument = -1,
+  InternalError = -255,
+} LibraStatus;
+
+typedef enum TransactionType {
+  PeerToPeer = 0,
+  Mint = 1,
+  Unknown = -1,
+} TransactionType;
+
+typedef struct LibraAccountKey {
+  uint8_t address[LIBRA_ADDRESS_SIZE];
+  uint8_t private_key[LIBRA_PRIVKEY_SIZE];
+  uint8_t public_key[LIBRA_PUBKEY_SIZE];
+} LibraAccountKey;
+
+typedef struct LibraEventHandle {
+  uint64_t count;
+  uint8_t key[LIBRA_EVENT_KEY_SIZE];
+} LibraEventHandle;
+
+typedef struct LibraAccountResource {
+  uint64_t balance;
+  uint64_t sequence;
+  uint8_t authentication_key[LIBRA_PUBKEY_SIZE];
+  bool delegated_key_rotation_capability;
+  bool delegated_withdrawal_capability;
+  struct LibraEventHandle sent_events;
+  struct LibraEventHandle received_events;
+} LibraAccountResource;
+
+/**
+ * Data of `SentPaymentEvent` and `ReceivedPaymentEvent`.
+ */
+typedef struct LibraPaymentEvent {
+  uint8_t sender_address[LIBRA_ADDRESS_SIZE];
+  uint8_t receiver_address[LIBRA_ADDRESS_SIZE];
+  uint64_t amount;
+  uint8_t *metadata;
+  uintptr_t metadata_len;
+} LibraPaymentEvent;
+
+/**
+ * A decoded payment event. `event_type` is `SentPaymentEvent`, `ReceivedPaymentEvent` or
+ * `UndefinedEvent` for any other event. Use `LibraEventV2` to decode the other events.
+ */
+typedef struct LibraEvent {
+  enum LibraEventType event_type;
+  uint8_t module[LIBRA_IDENTIFIER_SIZE];
+  uint8_t name[LIBRA_IDENTIFIER_SIZE];
+  struct LibraPaymentEvent payment_event_data;
+} LibraEvent;
+
+/**
+ * Data of `BurnEvent`, `CancelBurnEvent` and `PreburnEvent`.
+ */
+typedef struct LibraBurnEvent {
+  uint64_t amount;
+  uint8_t currency[LIBRA_IDENTIFIER_SIZE];
+  uint8_t preburn_address[LIBRA_ADDRESS_SIZE];
+} LibraBurnEvent;
+
+/**
+ * Data of `MintEvent`.
+ */
+typedef struct LibraMintEvent {
+  uint64_t amount;
+  uint8_t currency[LIBRA_IDENTIFIER_SIZE];
+} LibraMintEvent;
+
+/**
+ * Data of `ToLBRExchangeRateUpdateEvent`.
+ */
+typedef struct LibraToLBRExchangeRateUpdateEvent {
+  uint8_t currency[LIBRA_IDENTIFIER_SIZE];
+  float new_to_lbr_exchange_rate;
+} LibraToLBRExchangeRateUpdateEvent;
+
+/**
+ * Data of `UpgradeEvent`.
+ */
+typedef struct LibraUpgradeEvent {
+  uint8_t *write_set;
+  uintptr_t write_set_len;
+} LibraUpgradeEvent;
+
+/**
+ * Data of `NewEpochEvent`.
+ */
+typedef struct LibraNewEpochEvent {
+  uint64_t epoch;
+} LibraNewEpochEvent;
+
+/**
+ * Data of `NewBlockEvent`.
+ */
+typedef struct LibraNewBlockEvent {
+  uint64_t round;
+  uint8_t proposer[LIBRA_ADDRESS_SIZE];
+  uint64_t proposed_time;
+} LibraNewBlockEvent;
+
+/**
+ * A decoded event of any of the types of `LibraEventType`. Only the data fields matching
+ * `event_type` are set. The layout of `LibraEvent` is left unchanged for existing consumers.
+ */
+typedef struct LibraEventV2 {
+  enum LibraEventType event_type;
+  /**
+   * Address of the module declaring the event type.
+   */
+  uint8_t address[LIBRA_ADDRESS_SIZE];
+  uint8_t module[LIBRA_IDENTIFIER_SIZE];
+  uint8_t name[LIBRA_IDENTIFIER_SIZE];
+  /**
+   * LCS-serialized `Vec<TypeTag>` of the type parameters of the event type, or null if the
+   * event type is not a struct.
+   */
+  uint8_t *type_params;
+  uintptr_t type_params_len;
+  struct LibraPaymentEvent payment_event_data;
+  /**
+   * Currency of `payment_event_data`.
+   */
+  uint8_t payment_currency[LIBRA_IDENTIFIER_SIZE];
+  struct LibraBurnEvent burn_event_data;
+  struct LibraMintEvent mint_event_data;
+  struct LibraToLBRExchangeRateUpdateEvent exchange_rate_update_event_data;
+  struct LibraUpgradeEvent upgrade_event_data;
+  struct LibraNewEpochEvent new_epoch_event_data;
+  struct LibraNewBlockEvent new_block_event_data;
+} LibraEventV2;
+
+typedef struct LibraP2PTransferTransactionArgument {
+  uint64_t value;
+  uint8_t address[LIBRA_ADDRESS_SIZE];
+  const uint8_t *metadata_bytes;
+  uintptr_t metadata_len;
+  const uint8_t *metadata_signature_bytes;
+  uintptr_t metadata_signature_len;
+} LibraP2PTransferTransactionArgument;
+
+typedef struct LibraTransactionPayload {
+  enum TransactionType txn_type;
+  struct LibraP2PTransferTransactionArgument args;
+} LibraTransactionPayload;
+
+typedef struct LibraRawTransaction {
+  uint8_t sender[LIBRA_ADDRESS_SIZE];
+  uint64_t sequence_number;
+  struct LibraTransactionPayload payload;
+  uint64_t max_gas_amount;
+  uint64_t gas_unit_price;
+  uint64_t expiration_time_secs;
+} LibraRawTransaction;
+
+typedef struct LibraSignedTransaction {
+  struct LibraRawTransaction raw_txn;
+  uint8_t public_key[LIBRA_PUBKEY_SIZE];
+  uint8_t signature[LIBRA_SIGNATURE_SIZE];
+} LibraSignedTransaction;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * This function takes in private key in bytes and return the associated public key and address
+ * @param[in] private_key_bytes is private key in bytes
+ * @param[out] out is a pointer to LibraAccountKey struct client passed in by initializing an empty LibraAccountKey struct
+ */
+enum LibraStatus libra_LibraAccountKey_from(const uint8_t *private_key_bytes,
+                                            struct LibraAccountKey *out);
+
+/**
  * Decode LibraAccountResource from bytes in AccountStateBlob.
  *
  * @param[in] buf contains encoded bytes of AccountStateBlob
  * @param[in] len is the length of the signed AccountStateBlob memory buffer.
- * @param[out] caller allocated LibraAccountResource to write into.
+ * @param[out] out is a caller allocated LibraAccountResource to write into.
+ *
+ * @returns status code, one of LibraStatus
+ */
+enum LibraStatus libra_LibraAccountResource_from(const uint8_t *buf,
+                                                 uintptr_t len,
+                                                 struct LibraAccountResource *out);
+
+/**
+ * Return the most recent error string.
+ *
+ * Errors are thread local, so this function must be called on the same
+ * thread that called the API function which got an error. The returned
+ * string pointer is only valid until the next API call. Callers should not
+ * store it or use it past then.
+ */
+const char *libra_strerror(void);
+
+/**
+ * This function takes in an event key, event data and event type tag in bytes, and return LibraEvent.
+ * Only payment events are decoded; use `libra_LibraEventV2_from` to decode the other events.
+ * To get the event in a memory safe manner, the client needs to call free on the output with `libra_LibraEvent_free`.
+ * @param[in] buf_key is event key in bytes
+ * @param[in] len_key is length of event key bytes
+ * @param[in] buf_data is event data in bytes
+ * @param[in] len_data is length of event data bytes
+ * @param[in] buf_type_tag is type tag in bytes
+ * @param[in] len_type_tag is length of type tag bytes
+ * @param[out] out is filled with a rust allocated LibraEvent to write into
+ * @returns status code, one of LibraStatus
+ */
+enum LibraStatus libra_LibraEvent_from(const uint8_t *buf_key,
+                                       uintptr_t len_key,
+                                       const uint8_t *buf_data,
+                                       uintptr_t len_data,
+                                       const uint8_t *buf_type_tag,
+                                       uintptr_t len_type_tag,
+                                       struct LibraEvent **out);
+
+/**
+ * This function takes in an event key, event data and event type tag in bytes, and return LibraEventV2.
+ * To get the event in a memory safe manner, the client needs to call free on the output with `libra_LibraEventV2_free`.
+ * @param[in] buf_key is event key in bytes
+ * @param[in] len_key is length of event key bytes
+ * @param[in] buf_data is event data in bytes
+ * @param[in] len_data is length of event data bytes
+ * @param[in] buf_type_tag is type tag in bytes
+ * @param[in] len_type_tag is length of type tag bytes
+ * @param[out] out is filled with a rust allocated LibraEventV2 to write into
+ * @returns status code, one of LibraStatus
+ */
+enum LibraStatus libra_LibraEventV2_from(const uint8_t *buf_key,
+                                         uintptr_t len_key,
+                                         const uint8_t *buf_data,
+                                         uintptr_t len_data,
+                                         const uint8_t *buf_type_tag,
+                                         uintptr_t len_type_tag,
+                                         struct LibraEventV2 **out);
+
+/**
+ * Function to free the allocation event memory in rust
+ * @param out is the pointer to the event struct allocated, and needs to be freed from client side
+ */
+void libra_LibraEvent_free(struct LibraEvent *ptr);
+
+/**
+ * Function to free the allocation event memory in rust
+ * @param out is the pointer to the event struct allocated, and needs to be freed from client side
+ */
+void libra_LibraEventV2_free(struct LibraEventV2 *ptr);
+
+/**
+ * Create the public key of a `threshold`-of-`num_keys` multi-signature account.
+ * @param[in] public_keys is the concatenation of the num_keys ed25519 public keys of the key holders, of LIBRA_PUBKEY_SIZE bytes each.
+ * @param[in] num_keys is the number of key holders.
+ * @param[in] threshold is the number of signatures required to authenticate a transaction.
+ * @param[out] buf_result is the pointer that will be filled with the memory address of the multi-ed25519 public key allocated in rust. User takes ownership of pointer returned by *buf_result, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] len_result is the length of the public key memory buffer.
+ */
+enum LibraStatus libra_MultiEd25519PublicKey_from(const uint8_t *public_keys,
+                                                  uintptr_t num_keys,
+                                                  uint8_t threshold,
+                                                  uint8_t **buf_result,
+                                                  uintptr_t *len_result);
+
+/**
+ * Compute the authentication key of a multi-signature account. The address of the account is
+ * made of the last LIBRA_ADDRESS_SIZE bytes of the authentication key.
+ * @param[in] buf_public_key is the multi-ed25519 public key in bytes, as returned by libra_MultiEd25519PublicKey_from
+ * @param[in] len_public_key is the length of public key bytes
+ * @param[out] out is a caller allocated buffer of LIBRA_AUTHENTICATION_KEY_SIZE bytes to write the authentication key into
+ */
+enum LibraStatus libra_MultiEd25519AuthenticationKey_from(const uint8_t *buf_public_key,
+                                                          uintptr_t len_public_key,
+                                                          uint8_t *out);
+
+/**
+ * This function takes in a raw transaction, the public key of a multi-signature account and the signatures of
+ * some of its key holders, and returns a signed transaction in bytes. The signatures are checked, and at least
+ * as many as the threshold of the public key are required.
+ * To get the serialized signed transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+ * and call free on the memory address with `libra_free_bytes_buffer`.
+ * @param[in] buf_raw_txn is raw transaction in bytes
+ * @param[in] len_raw_txn is length of raw transaction bytes
+ * @param[in] buf_public_key is the multi-ed25519 public key in bytes, as returned by libra_MultiEd25519PublicKey_from
+ * @param[in] len_public_key is length of public key bytes
+ * @param[in] buf_signatures is the concatenation of the ed25519 signatures of the raw transaction, of LIBRA_SIGNATURE_SIZE bytes each
+ * @param[in] signer_indexes are the positions of the public keys of the signers in the multi-ed25519 public key, one per signature
+ * @param[in] num_signatures is the number of signatures
+ * @param[out] buf_result is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf_result, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] len_result is the length of the signed transaction memory buffer.
+ */
+enum LibraStatus libra_RawTransaction_sign_multi_ed25519(const uint8_t *buf_raw_txn,
+                                                         uintptr_t len_raw_txn,
+                                                         const uint8_t *buf_public_key,
+                                                         uintptr_t len_public_key,
+                                                         const uint8_t *buf_signatures,
+                                                         const uint8_t *signer_indexes,
+                                                         uintptr_t num_signatures,
+                                                         uint8_t **buf_result,
+                                                         uintptr_t *len_result);
+
+/**
+ * Verify the state of an account at a given version against a ledger info.
+ * The ledger info is trusted by the caller: the signatures of the validators are not checked.
+ * All structures are given in LCS bytes, as found in the JSON-RPC responses.
+ * @param[in] address is the account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] version is the version at which the account state is seen.
+ * @param[in] buf_blob is the account state blob, or null if the account does not exist.
+ * @param[in] len_blob is the length of the account state blob bytes.
+ * @param[in] buf_ledger_info_to_transaction_info_proof is the accumulator proof of the transaction info.
+ * @param[in] len_ledger_info_to_transaction_info_proof is the length of the accumulator proof bytes.
+ * @param[in] buf_transaction_info is the transaction info at `version`.
+ * @param[in] len_transaction_info is the length of the transaction info bytes.
+ * @param[in] buf_transaction_info_to_account_proof is the sparse merkle proof of the account state.
+ * @param[in] len_transaction_info_to_account_proof is the length of the sparse merkle proof bytes.
+ * @param[in] buf_ledger_info_with_signatures is the trusted ledger info.
+ * @param[in] len_ledger_info_with_signatures is the length of the ledger info bytes.
+ * @returns LibraStatus::Ok if the account state is proven, LibraStatus::InvalidArgument otherwise.
+ */
+enum LibraStatus libra_AccountStateWithProof_verify(const uint8_t *address,
+                                                    uint64_t version,
+                                                    const uint8_t *buf_blob,
+                                                    uintptr_t len_blob,
+                                                    const uint8_t *buf_ledger_info_to_transaction_info_proof,
+                                                    uintptr_t len_ledger_info_to_transaction_info_proof,
+                                                    const uint8_t *buf_transaction_info,
+                                                    uintptr_t len_transaction_info,
+                                                    const uint8_t *buf_transaction_info_to_account_proof,
+                                                    uintptr_t len_transaction_info_to_account_proof,
+                                                    const uint8_t *buf_ledger_info_with_signatures,
+                                                    uintptr_t len_ledger_info_with_signatures);
+
+/**
+ * Add a `Currency` balance to `account`, which will enable `account` to send and receive
+ * `Libra<Currency>`. Aborts with NOT_A_CURRENCY if `Currency` is not an accepted
+ * currency type in the Libra system Aborts with `LibraAccount::ADD_EXISTING_CURRENCY` if
+ * the account already holds a balance in `Currency`. Aborts with
+ * `LibraAccount::PARENT_VASP_CURRENCY_LIMITS_DNE` if `account` is a `ChildVASP` whose
+ * parent does not have an `AccountLimits<Currency>` resource.
+ *
+ * @param[in] currency is the currency code of the type argument, e.g. "LBR".
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionAddCurrencyToAccountScript_from(const char *currency,
+                                                                  uint8_t **ptr_buf,
+                                                                  uintptr_t *ptr_len);
+
+/**
+ * Add the `KeyRotationCapability` for `to_recover_account` to the `RecoveryAddress`
+ * resource under `recovery_address`. Aborts if `to_recovery_account` and
+ * `to_recovery_address belong to different VASPs, if `recovery_address` does not have a
+ * `RecoveryAddress` resource, or if `to_recover_account` has already extracted its
+ * `KeyRotationCapability`.
+ *
+ * @param[in] recovery_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionAddRecoveryRotationCapabilityScript_from(const uint8_t *recovery_address,
+                                                                           uint8_t **ptr_buf,
+                                                                           uintptr_t *ptr_len);
+
+/**
+ * Add `new_validator` to the pending validator set. Fails if the `new_validator` address
+ * is already in the validator set or does not have a `ValidatorConfig` resource stored
+ * at the address.
+ *
+ * @param[in] validator_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionAddValidatorScript_from(const uint8_t *validator_address,
+                                                          uint8_t **ptr_buf,
+                                                          uintptr_t *ptr_len);
+
+/**
+ * Adds limits and an accounting window for `CoinType` currency to the parent VASP
+ * `account`. This transaction will fail if sent from a child account.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionAllowCurrencyForVaspScript_from(const char *coin_type,
+                                                                  uint8_t **ptr_buf,
+                                                                  uintptr_t *ptr_len);
+
+/**
+ * Permanently destroy the `Token`s stored in the oldest burn request under the `Preburn`
+ * resource. This will only succeed if `account` has a `MintCapability<Token>`, a
+ * `Preburn<Token>` resource exists under `preburn_address`, and there is a pending burn
+ * request. sliding_nonce is a unique nonce for operation, see sliding_nonce.move for
+ * details
+ *
+ * @param[in] token is the currency code of the type argument, e.g. "LBR".
+ * @param[in] sliding_nonce
+ * @param[in] preburn_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionBurnScript_from(const char *token,
+                                                  uint64_t sliding_nonce,
+                                                  const uint8_t *preburn_address,
+                                                  uint8_t **ptr_buf,
+                                                  uintptr_t *ptr_len);
+
+/**
+ * Burn transaction fees that have been collected in the given `currency` and relinquish
+ * to the association. The currency must be non-synthetic.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionBurnTxnFeesScript_from(const char *coin_type,
+                                                         uint8_t **ptr_buf,
+                                                         uintptr_t *ptr_len);
+
+/**
+ * Cancel the oldest burn request from `preburn_address` and return the funds. Fails if
+ * the sender does not have a published `BurnCapability<Token>`.
+ *
+ * @param[in] token is the currency code of the type argument, e.g. "LBR".
+ * @param[in] preburn_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCancelBurnScript_from(const char *token,
+                                                        const uint8_t *preburn_address,
+                                                        uint8_t **ptr_buf,
+                                                        uintptr_t *ptr_len);
+
+/**
+ * Create a `ChildVASP` account for sender `parent_vasp` at `child_address` with a
+ * balance of `child_initial_balance` in `CoinType` and an initial authentication_key
+ * `auth_key_prefix | child_address`. If `add_all_currencies` is true, the child address
+ * will have a zero balance in all available currencies in the system. This account will
+ * a child of the transaction sender, which must be a ParentVASP.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] child_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[in] add_all_currencies
+ * @param[in] child_initial_balance
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateChildVaspAccountScript_from(const char *coin_type,
+                                                                    const uint8_t *child_address,
+                                                                    const uint8_t *auth_key_prefix_bytes,
+                                                                    uintptr_t auth_key_prefix_len,
+                                                                    bool add_all_currencies,
+                                                                    uint64_t child_initial_balance,
+                                                                    uint8_t **ptr_buf,
+                                                                    uintptr_t *ptr_len);
+
+/**
+ * Create an account with the DesignatedDealer role at `addr` with authentication key
+ * `auth_key_prefix` | `addr` and a 0 balance of type `Currency`. If `add_all_currencies`
+ * is true, 0 balances for all available currencies in the system will also be added.
+ * This can only be invoked by an account with the TreasuryCompliance role.
+ *
+ * @param[in] currency is the currency code of the type argument, e.g. "LBR".
+ * @param[in] sliding_nonce
+ * @param[in] addr is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[in] add_all_currencies
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateDesignatedDealerScript_from(const char *currency,
+                                                                    uint64_t sliding_nonce,
+                                                                    const uint8_t *addr,
+                                                                    const uint8_t *auth_key_prefix_bytes,
+                                                                    uintptr_t auth_key_prefix_len,
+                                                                    bool add_all_currencies,
+                                                                    uint8_t **ptr_buf,
+                                                                    uintptr_t *ptr_len);
+
+/**
+ * Create an account with the ParentVASP role at `address` with authentication key
+ * `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+ * `add_all_currencies` is true, 0 balances for all available currencies in the system
+ * will also be added. This can only be invoked by an Association account.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[in] human_name_bytes is the content of `human_name`, or null if it is empty.
+ * @param[in] human_name_len is the length of human_name_bytes.
+ * @param[in] base_url_bytes is the content of `base_url`, or null if it is empty.
+ * @param[in] base_url_len is the length of base_url_bytes.
+ * @param[in] compliance_public_key_bytes is the content of `compliance_public_key`, or null if it is empty.
+ * @param[in] compliance_public_key_len is the length of compliance_public_key_bytes.
+ * @param[in] add_all_currencies
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateParentVaspAccountScript_from(const char *coin_type,
+                                                                     const uint8_t *new_account_address,
+                                                                     const uint8_t *auth_key_prefix_bytes,
+                                                                     uintptr_t auth_key_prefix_len,
+                                                                     const uint8_t *human_name_bytes,
+                                                                     uintptr_t human_name_len,
+                                                                     const uint8_t *base_url_bytes,
+                                                                     uintptr_t base_url_len,
+                                                                     const uint8_t *compliance_public_key_bytes,
+                                                                     uintptr_t compliance_public_key_len,
+                                                                     bool add_all_currencies,
+                                                                     uint8_t **ptr_buf,
+                                                                     uintptr_t *ptr_len);
+
+/**
+ * Extract the `KeyRotationCapability` for `recovery_account` and publish it in a
+ * `RecoveryAddress` resource under `recovery_account`. Aborts if `recovery_account` has
+ * delegated its `KeyRotationCapability`, already has a `RecoveryAddress` resource, or is
+ * not a VASP.
+ *
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateRecoveryAddressScript_from(uint8_t **ptr_buf,
+                                                                   uintptr_t *ptr_len);
+
+/**
+ * Create an account with the ParentVASP role at `address` with authentication key
+ * `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+ * `add_all_currencies` is true, 0 balances for all available currencies in the system
+ * will also be added. This can only be invoked by an Association account. The
+ * `human_name`, `base_url`, and compliance_public_key` fields of the ParentVASP are
+ * filled in with dummy information.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[in] add_all_currencies
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateTestingAccountScript_from(const char *coin_type,
+                                                                  const uint8_t *new_account_address,
+                                                                  const uint8_t *auth_key_prefix_bytes,
+                                                                  uintptr_t auth_key_prefix_len,
+                                                                  bool add_all_currencies,
+                                                                  uint8_t **ptr_buf,
+                                                                  uintptr_t *ptr_len);
+
+/**
+ * Create a validator account at `new_validator_address` with `auth_key_prefix`.
+ *
+ * @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateValidatorAccountScript_from(const uint8_t *new_account_address,
+                                                                    const uint8_t *auth_key_prefix_bytes,
+                                                                    uintptr_t auth_key_prefix_len,
+                                                                    uint8_t **ptr_buf,
+                                                                    uintptr_t *ptr_len);
+
+/**
+ * Create a validator operator account at `new_validator_address` with `auth_key_prefix`.
+ *
+ * @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+ * @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionCreateValidatorOperatorAccountScript_from(const uint8_t *new_account_address,
+                                                                            const uint8_t *auth_key_prefix_bytes,
+                                                                            uintptr_t auth_key_prefix_len,
+                                                                            uint8_t **ptr_buf,
+                                                                            uintptr_t *ptr_len);
+
+/**
+ * Freeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+ * nonce for operation, see sliding_nonce.move for details.
  *
- * @returns status code, one of LibraAPIStatus
-*/
-enum LibraStatus libra_LibraAccountResource_from(const uint8_t *buf, size_t len, struct LibraAccountResource *out);
+ * @param[in] sliding_nonce
+ * @param[in] to_freeze_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionFreezeAccountScript_from(uint64_t sliding_nonce,
+                                                           const uint8_t *to_freeze_account,
+                                                           uint8_t **ptr_buf,
+                                                           uintptr_t *ptr_len);
+
+/**
+ * Mint `amount_lbr` LBR from the sending account's constituent coins and deposits the
+ * resulting LBR into the sending account.
+ *
+ * @param[in] amount_lbr
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionMintLbrScript_from(uint64_t amount_lbr,
+                                                     uint8_t **ptr_buf,
+                                                     uintptr_t *ptr_len);
+
+/**
+ * Modify publishing options. Takes the LCS bytes of a `VMPublishingOption` object as
+ * input.
+ *
+ * @param[in] args_bytes is the content of `args`, or null if it is empty.
+ * @param[in] args_len is the length of args_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionModifyPublishingOptionScript_from(const uint8_t *args_bytes,
+                                                                    uintptr_t args_len,
+                                                                    uint8_t **ptr_buf,
+                                                                    uintptr_t *ptr_len);
+
+/**
+ * Transfer `amount` coins to `recipient_address` with (optional) associated metadata
+ * `metadata` and (optional) `signature` on the metadata, amount, and sender address. The
+ * `metadata` and `signature` parameters are only required if `amount` >= 1_000_000 micro
+ * LBR and the sender and recipient of the funds are two distinct VASPs. Fails if there
+ * is no account at the recipient address or if the sender's balance is lower than
+ * `amount`.
+ *
+ * @param[in] token is the currency code of the type argument, e.g. "LBR".
+ * @param[in] payee is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] amount
+ * @param[in] metadata_bytes is the content of `metadata`, or null if it is empty.
+ * @param[in] metadata_len is the length of metadata_bytes.
+ * @param[in] metadata_signature_bytes is the content of `metadata_signature`, or null if it is empty.
+ * @param[in] metadata_signature_len is the length of metadata_signature_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionPeerToPeerWithMetadataScript_from(const char *token,
+                                                                    const uint8_t *payee,
+                                                                    uint64_t amount,
+                                                                    const uint8_t *metadata_bytes,
+                                                                    uintptr_t metadata_len,
+                                                                    const uint8_t *metadata_signature_bytes,
+                                                                    uintptr_t metadata_signature_len,
+                                                                    uint8_t **ptr_buf,
+                                                                    uintptr_t *ptr_len);
+
+/**
+ * Preburn `amount` `Token`s from `account`. This will only succeed if `account` already
+ * has a published `Preburn<Token>` resource.
+ *
+ * @param[in] token is the currency code of the type argument, e.g. "LBR".
+ * @param[in] amount
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionPreburnScript_from(const char *token,
+                                                     uint64_t amount,
+                                                     uint8_t **ptr_buf,
+                                                     uintptr_t *ptr_len);
+
+/**
+ * (1) Rotate the authentication key of the sender to `public_key` (2) Publish a resource
+ * containing a 32-byte ed25519 public key and the rotation capability of the sender
+ * under the sender's address. Aborts if the sender already has a
+ * `SharedEd25519PublicKey` resource. Aborts if the length of `new_public_key` is not 32.
+ *
+ * @param[in] public_key_bytes is the content of `public_key`, or null if it is empty.
+ * @param[in] public_key_len is the length of public_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionPublishSharedEd25519PublicKeyScript_from(const uint8_t *public_key_bytes,
+                                                                           uintptr_t public_key_len,
+                                                                           uint8_t **ptr_buf,
+                                                                           uintptr_t *ptr_len);
+
+/**
+ * Update configs of all the validators and emit reconfiguration event.
+ *
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionReconfigureScript_from(uint8_t **ptr_buf,
+                                                         uintptr_t *ptr_len);
+
+/**
+ * Adding `to_remove` to the set of pending validator removals. Fails if the `to_remove`
+ * address is already in the validator set or already in the pending removals. Callable
+ * by Validator's operator.
+ *
+ * @param[in] validator_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRemoveValidatorScript_from(const uint8_t *validator_address,
+                                                             uint8_t **ptr_buf,
+                                                             uintptr_t *ptr_len);
+
+/**
+ * Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+ * sha3 hash of an ed25519 public key.
+ *
+ * @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+ * @param[in] new_key_len is the length of new_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateAuthenticationKeyScript_from(const uint8_t *new_key_bytes,
+                                                                     uintptr_t new_key_len,
+                                                                     uint8_t **ptr_buf,
+                                                                     uintptr_t *ptr_len);
+
+/**
+ * Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+ * sha3 hash of an ed25519 public key. This script also takes `sliding_nonce`, as a
+ * unique nonce for this operation. See sliding_nonce.move for details.
+ *
+ * @param[in] sliding_nonce
+ * @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+ * @param[in] new_key_len is the length of new_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateAuthenticationKeyWithNonceScript_from(uint64_t sliding_nonce,
+                                                                              const uint8_t *new_key_bytes,
+                                                                              uintptr_t new_key_len,
+                                                                              uint8_t **ptr_buf,
+                                                                              uintptr_t *ptr_len);
+
+/**
+ * Rotate the authentication key of `to_recover` to `new_key`. Can be invoked by either
+ * `recovery_address` or `to_recover`. Aborts if `recovery_address` does not have the
+ * `KeyRotationCapability` for `to_recover`.
+ *
+ * @param[in] recovery_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] to_recover is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+ * @param[in] new_key_len is the length of new_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateAuthenticationKeyWithRecoveryAddressScript_from(const uint8_t *recovery_address,
+                                                                                        const uint8_t *to_recover,
+                                                                                        const uint8_t *new_key_bytes,
+                                                                                        uintptr_t new_key_len,
+                                                                                        uint8_t **ptr_buf,
+                                                                                        uintptr_t *ptr_len);
+
+/**
+ * Rotate `vasp_root_addr`'s base URL to `new_url`.
+ *
+ * @param[in] new_url_bytes is the content of `new_url`, or null if it is empty.
+ * @param[in] new_url_len is the length of new_url_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateBaseUrlScript_from(const uint8_t *new_url_bytes,
+                                                           uintptr_t new_url_len,
+                                                           uint8_t **ptr_buf,
+                                                           uintptr_t *ptr_len);
+
+/**
+ * Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`.
+ *
+ * @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+ * @param[in] new_key_len is the length of new_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateCompliancePublicKeyScript_from(const uint8_t *new_key_bytes,
+                                                                       uintptr_t new_key_len,
+                                                                       uint8_t **ptr_buf,
+                                                                       uintptr_t *ptr_len);
 
-/*!
- *  Get serialized signed transaction from a list of transaction parameters
+/**
+ * (1) Rotate the public key stored in `account`'s `SharedEd25519PublicKey` resource to
+ * `new_public_key` (2) Rotate the authentication key using the capability stored in
+ * `account`'s `SharedEd25519PublicKey` to a new value derived from `new_public_key`
+ * Aborts if `account` does not have a `SharedEd25519PublicKey` resource. Aborts if the
+ * length of `new_public_key` is not 32.
+ *
+ * @param[in] public_key_bytes is the content of `public_key`, or null if it is empty.
+ * @param[in] public_key_len is the length of public_key_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionRotateSharedEd25519PublicKeyScript_from(const uint8_t *public_key_bytes,
+                                                                          uintptr_t public_key_len,
+                                                                          uint8_t **ptr_buf,
+                                                                          uintptr_t *ptr_len);
+
+/**
+ * Sets the account limits window `tracking_balance` field for `CointType` at
+ * `window_address` to `aggregate_balance`
+ *
+ * @param[in] coint_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] window_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] aggregate_balance
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionSetAccountLimitWindowCurrentHoldingsScript_from(const char *coint_type,
+                                                                                  const uint8_t *window_address,
+                                                                                  uint64_t aggregate_balance,
+                                                                                  uint8_t **ptr_buf,
+                                                                                  uintptr_t *ptr_len);
+
+/**
+ * Set validator's config.
+ *
+ * @param[in] validator_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] consensus_pubkey_bytes is the content of `consensus_pubkey`, or null if it is empty.
+ * @param[in] consensus_pubkey_len is the length of consensus_pubkey_bytes.
+ * @param[in] validator_network_identity_pubkey_bytes is the content of `validator_network_identity_pubkey`, or null if it is empty.
+ * @param[in] validator_network_identity_pubkey_len is the length of validator_network_identity_pubkey_bytes.
+ * @param[in] validator_network_address_bytes is the content of `validator_network_address`, or null if it is empty.
+ * @param[in] validator_network_address_len is the length of validator_network_address_bytes.
+ * @param[in] fullnodes_network_identity_pubkey_bytes is the content of `fullnodes_network_identity_pubkey`, or null if it is empty.
+ * @param[in] fullnodes_network_identity_pubkey_len is the length of fullnodes_network_identity_pubkey_bytes.
+ * @param[in] fullnodes_network_address_bytes is the content of `fullnodes_network_address`, or null if it is empty.
+ * @param[in] fullnodes_network_address_len is the length of fullnodes_network_address_bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionSetValidatorConfigScript_from(const uint8_t *validator_account,
+                                                                const uint8_t *consensus_pubkey_bytes,
+                                                                uintptr_t consensus_pubkey_len,
+                                                                const uint8_t *validator_network_identity_pubkey_bytes,
+                                                                uintptr_t validator_network_identity_pubkey_len,
+                                                                const uint8_t *validator_network_address_bytes,
+                                                                uintptr_t validator_network_address_len,
+                                                                const uint8_t *fullnodes_network_identity_pubkey_bytes,
+                                                                uintptr_t fullnodes_network_identity_pubkey_len,
+                                                                const uint8_t *fullnodes_network_address_bytes,
+                                                                uintptr_t fullnodes_network_address_len,
+                                                                uint8_t **ptr_buf,
+                                                                uintptr_t *ptr_len);
+
+/**
+ * Set validator's operator
+ *
+ * @param[in] operator_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionSetValidatorOperatorScript_from(const uint8_t *operator_account,
+                                                                  uint8_t **ptr_buf,
+                                                                  uintptr_t *ptr_len);
+
+/**
+ * Send `amount` coins of type `Token` to `payee`.
+ *
+ * @param[in] token is the currency code of the type argument, e.g. "LBR".
+ * @param[in] payee is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] amount
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionTestnetMintScript_from(const char *token,
+                                                         const uint8_t *payee,
+                                                         uint64_t amount,
+                                                         uint8_t **ptr_buf,
+                                                         uintptr_t *ptr_len);
+
+/**
+ * Mint 'mint_amount' to 'designated_dealer_address' for 'tier_index' tier. Max valid
+ * tier index is 3 since there are max 4 tiers per DD. Sender should be treasury
+ * compliance account and receiver authorized DD. `sliding_nonce` is a unique nonce for
+ * operation, see sliding_nonce.move for details.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] sliding_nonce
+ * @param[in] designated_dealer_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] mint_amount
+ * @param[in] tier_index
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionTieredMintScript_from(const char *coin_type,
+                                                        uint64_t sliding_nonce,
+                                                        const uint8_t *designated_dealer_address,
+                                                        uint64_t mint_amount,
+                                                        uint64_t tier_index,
+                                                        uint8_t **ptr_buf,
+                                                        uintptr_t *ptr_len);
+
+/**
+ * Unfreeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+ * nonce for operation, see sliding_nonce.move for details.
+ *
+ * @param[in] sliding_nonce
+ * @param[in] to_unfreeze_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUnfreezeAccountScript_from(uint64_t sliding_nonce,
+                                                             const uint8_t *to_unfreeze_account,
+                                                             uint8_t **ptr_buf,
+                                                             uintptr_t *ptr_len);
+
+/**
+ * Unmints `amount_lbr` LBR from the sending account into the constituent coins and
+ * deposits the resulting coins into the sending account."
+ *
+ * @param[in] amount_lbr
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUnmintLbrScript_from(uint64_t amount_lbr,
+                                                       uint8_t **ptr_buf,
+                                                       uintptr_t *ptr_len);
+
+/**
+ * Optionally update thresholds of max balance, inflow, outflow for any limits-bound
+ * accounts with their limits defined at `limit_address`. Limits are defined in terms of
+ * base (on-chain) currency units for `CoinType`. If a new threshold is 0, that
+ * particular config does not get updated. `sliding_nonce` is a unique nonce for
+ * operation, see SlidingNonce.move for details.
+ *
+ * @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+ * @param[in] limit_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+ * @param[in] sliding_nonce
+ * @param[in] new_max_inflow
+ * @param[in] new_max_outflow
+ * @param[in] new_max_holding_balance
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUpdateAccountLimitDefinitionScript_from(const char *coin_type,
+                                                                          const uint8_t *limit_address,
+                                                                          uint64_t sliding_nonce,
+                                                                          uint64_t new_max_inflow,
+                                                                          uint64_t new_max_outflow,
+                                                                          uint64_t new_max_holding_balance,
+                                                                          uint8_t **ptr_buf,
+                                                                          uintptr_t *ptr_len);
+
+/**
+ * Update the on-chain exchange rate to LBR for the given `currency` to be given by
+ * `new_exchange_rate_numerator/new_exchange_rate_denominator`.
+ *
+ * @param[in] currency is the currency code of the type argument, e.g. "LBR".
+ * @param[in] sliding_nonce
+ * @param[in] new_exchange_rate_numerator
+ * @param[in] new_exchange_rate_denominator
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUpdateExchangeRateScript_from(const char *currency,
+                                                                uint64_t sliding_nonce,
+                                                                uint64_t new_exchange_rate_numerator,
+                                                                uint64_t new_exchange_rate_denominator,
+                                                                uint8_t **ptr_buf,
+                                                                uintptr_t *ptr_len);
+
+/**
+ * Update Libra version.
+ *
+ * @param[in] major
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUpdateLibraVersionScript_from(uint64_t major,
+                                                                uint8_t **ptr_buf,
+                                                                uintptr_t *ptr_len);
+
+/**
+ * Allows--true--or disallows--false--minting of `currency` based upon `allow_minting`.
+ *
+ * @param[in] currency is the currency code of the type argument, e.g. "LBR".
+ * @param[in] allow_minting
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUpdateMintingAbilityScript_from(const char *currency,
+                                                                  bool allow_minting,
+                                                                  uint8_t **ptr_buf,
+                                                                  uintptr_t *ptr_len);
+
+/**
+ * Update the travel rule limit to `new_micro_lbr_limit`.
+ *
+ * @param[in] sliding_nonce
+ * @param[in] new_micro_lbr_limit
+ * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+ * @param[out] ptr_len is the length of the script memory buffer.
+ */
+enum LibraStatus libra_TransactionUpdateTravelRuleLimitScript_from(uint64_t sliding_nonce,
+                                                                   uint64_t new_micro_lbr_limit,
+                                                                   uint8_t **ptr_buf,
+                                                                   uintptr_t *ptr_len);
+
+/**
+ * Get serialized signed transaction from a list of transaction parameters
  *
  * To get the serialized transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
  * and call free on the memory address with `libra_free_bytes_buffer`.
@@ -128,11 +1051,20 @@ enum LibraStatus libra_LibraAccountResource_from(const uint8_t *buf, size_t len,
  * @param[in] script_len is the length of script_bytes array.
  * @param[out] ptr_buf is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] ptr_len is the length of the signed transaction memory buffer.
-*/
-enum LibraStatus libra_SignedTransactionBytes_from(const uint8_t sender_private_key[LIBRA_PRIVKEY_SIZE], uint64_t sequence, uint64_t max_gas_amount, uint64_t gas_unit_price, const char* gas_identifier, uint64_t expiration_time_secs, const uint8_t *script_bytes, size_t script_len, uint8_t **ptr_buf, size_t *ptr_len);
+ */
+enum LibraStatus libra_SignedTransactionBytes_from(const uint8_t *sender_private_key_bytes,
+                                                   uint64_t sequence,
+                                                   uint64_t max_gas_amount,
+                                                   uint64_t gas_unit_price,
+                                                   const char *gas_identifier,
+                                                   uint64_t expiration_time_secs,
+                                                   const uint8_t *script_bytes,
+                                                   uintptr_t script_len,
+                                                   uint8_t **ptr_buf,
+                                                   uintptr_t *ptr_len);
 
-/*!
- *  Get script bytes for a P2P transaction
+/**
+ * Get script bytes for a P2P transaction
  *
  * To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
  * and call free on the memory address with `libra_free_bytes_buffer`.
@@ -145,35 +1077,33 @@ enum LibraStatus libra_SignedTransactionBytes_from(const uint8_t sender_private_
  * @param[in] metadata_signature_len is the length of metadata_signature_bytes array.
  * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] ptr_len is the length of the script memory buffer.
-*/
-enum LibraStatus libra_TransactionP2PScript_from(const uint8_t receiver[LIBRA_ADDRESS_SIZE], const char* identifier, uint64_t num_coins, const uint8_t* metadata_bytes, size_t metadata_len, const uint8_t* metadata_signature_bytes, size_t metadata_signature_len, uint8_t **ptr_buf, size_t *ptr_len);
+ */
+enum LibraStatus libra_TransactionP2PScript_from(const uint8_t *receiver,
+                                                 const char *identifier,
+                                                 uint64_t num_coins,
+                                                 const uint8_t *metadata_bytes,
+                                                 uintptr_t metadata_len,
+                                                 const uint8_t *metadata_signature_bytes,
+                                                 uintptr_t metadata_signature_len,
+                                                 uint8_t **ptr_buf,
+                                                 uintptr_t *ptr_len);
 
-/*!
- *  Get script bytes for add currency to account transaction
+/**
+ * Get script bytes for add currency to account transaction
  *
  * To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
  * and call free on the memory address with `libra_free_bytes_buffer`.
  * @param[in] identifier is the identifier of the coin to be sent.
  * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] ptr_len is the length of the script memory buffer.
-*/
-enum LibraStatus libra_TransactionAddCurrencyScript_from(const char* identifier, uint8_t **ptr_buf, size_t *ptr_len);
-
-/*!
- *  Get script bytes for rotating compliance public key of VASP
- *  Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`
- *
- * To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
- * and call free on the memory address with `libra_free_bytes_buffer`.
- * @param[in] new_key_bytes is the bytes of new compliance public key.
- * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
- * @param[out] ptr_len is the length of the script memory buffer.
-*/
-enum LibraStatus libra_TransactionRotateCompliancePublicKeyScript_from(const uint8_t new_key_bytes[LIBRA_PUBKEY_SIZE], uint8_t **ptr_buf, size_t *ptr_len);
+ */
+enum LibraStatus libra_TransactionAddCurrencyScript_from(const char *identifier,
+                                                         uint8_t **ptr_buf,
+                                                         uintptr_t *ptr_len);
 
-/*!
- *  Get script bytes for rotating base url of VASP
- *  Encode a program that rotates `vasp_root_addr`'s base URL to `new_url`.
+/**
+ * Get script bytes for rotating base url of VASP
+ * Encode a program that rotates `vasp_root_addr`'s base URL to `new_url`.
  *
  * To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
  * and call free on the memory address with `libra_free_bytes_buffer`.
@@ -181,27 +1111,19 @@ enum LibraStatus libra_TransactionRotateCompliancePublicKeyScript_from(const uin
  * @param[in] new_url_len is the length of new_key_bytes array.
  * @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] ptr_len is the length of the script memory buffer.
-*/
-enum LibraStatus libra_TransactionRotateBaseURLScript_from(const uint8_t* new_url_bytes, size_t new_url_len, uint8_t **ptr_buf, size_t *ptr_len);
+ */
+enum LibraStatus libra_TransactionRotateBaseURLScript_from(const uint8_t *new_url_bytes,
+                                                           uintptr_t new_url_len,
+                                                           uint8_t **ptr_buf,
+                                                           uintptr_t *ptr_len);
 
-/*!
+/**
  * Function to free the allocation memory in rust for bytes
  * @param buf is the pointer to the bytes allocated in rust, and needs to be freed from client side
  */
-void libra_free_bytes_buffer(const uint8_t* buf);
-
-/*!
- * Decode LibraSignedTransaction from bytes in SignedTransaction proto.
- *
- * @param[in] buf contains encoded bytes of txn_bytes
- * @param[in] len is the length of the signed transaction memory buffer.
- * @param[out] caller allocated LibraSignedTransaction to write into.
- *
- * @returns status code, one of LibraAPIStatus
-*/
-enum LibraStatus libra_LibraSignedTransaction_from(const uint8_t *buf, size_t len, struct LibraSignedTransaction *out);
+void libra_free_bytes_buffer(const uint8_t *buf);
 
-/*!
+/**
  * Get serialized raw transaction from a list of transaction parameters
  *
  * To get the serialized raw transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
@@ -213,14 +1135,28 @@ enum LibraStatus libra_LibraSignedTransaction_from(const uint8_t *buf, size_t le
  * @param[in] max_gas_amount is the maximal total gas specified by wallet to spend for this transaction.
  * @param[in] gas_unit_price is the maximal price can be paid per gas.
  * @param[in] expiration_time_secs is the time this TX remain valid, the format is unix timestamp.
+ * @param[in] metadata_signature_bytes is the metadata signature bytes for given transaction.
+ * @param[in] metadata_signature_len is the length of metadata_signature_bytes array.
  * @param[in] metadata_bytes is the metadata bytes for given transaction.
  * @param[in] metadata_len is the length of metadata_bytes array.
  * @param[out] buf is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] len is the length of the raw transaction memory buffer.
-*/
-enum LibraStatus libra_RawTransactionBytes_from(const uint8_t sender[LIBRA_ADDRESS_SIZE], const uint8_t receiver[LIBRA_ADDRESS_SIZE], uint64_t sequence, uint64_t num_coins, uint64_t max_gas_amount, uint64_t gas_unit_price, uint64_t expiration_time_secs, const uint8_t* metadata_bytes, size_t metadata_len, const uint8_t* metadata_signature_bytes, size_t metadata_signature_len, uint8_t **buf, size_t *len);
+ */
+enum LibraStatus libra_RawTransactionBytes_from(const uint8_t *sender,
+                                                const uint8_t *receiver,
+                                                uint64_t sequence,
+                                                uint64_t num_coins,
+                                                uint64_t max_gas_amount,
+                                                uint64_t gas_unit_price,
+                                                uint64_t expiration_time_secs,
+                                                const uint8_t *metadata_bytes,
+                                                uintptr_t metadata_len,
+                                                const uint8_t *metadata_signature_bytes,
+                                                uintptr_t metadata_signature_len,
+                                                uint8_t **buf,
+                                                uintptr_t *len);
 
-/*!
+/**
  * This function takes in a raw transaction, public key and signature in bytes, and return a signed transaction in bytes.
  * To get the serialized signed transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
  * and call free on the memory address with `libra_free_bytes_buffer`.
@@ -232,45 +1168,31 @@ enum LibraStatus libra_RawTransactionBytes_from(const uint8_t sender[LIBRA_ADDRE
  * @param[in] len_signature is length of signature bytes
  * @param[out] buf_result is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
  * @param[out] len_result is the length of the signed transaction memory buffer.
-*/
-enum LibraStatus libra_RawTransaction_sign(const uint8_t *buf_raw_txn, size_t len_raw_txn, const uint8_t *buf_public_key, size_t len_public_key, const uint8_t *buf_signature, size_t len_signature, uint8_t **buf_result, size_t *len_result);
-
-/*!
- * This function takes in private key in bytes and return the associated public key and address
- * @param[in] private_key_bytes is private key in bytes
- * @param[out] out is a pointer to LibraAccountKey struct client passed in by initializing an empty LibraAccountKey struct
-*/
-enum LibraStatus libra_LibraAccountKey_from(const uint8_t private_key_bytes[LIBRA_PRIVKEY_SIZE], struct LibraAccountKey *out);
+ */
+enum LibraStatus libra_RawTransaction_sign(const uint8_t *buf_raw_txn,
+                                           uintptr_t len_raw_txn,
+                                           const uint8_t *buf_public_key,
+                                           uintptr_t len_public_key,
+                                           const uint8_t *buf_signature,
+                                           uintptr_t len_signature,
+                                           uint8_t **buf_result,
+                                           uintptr_t *len_result);
 
-/*!
- * This function takes in an event key, event data and event type tag in bytes, and return LibraEvent.
- * To get the event in a memory safe manner, the client needs to call free on the output with `libra_LibraEvent_free`.
- * @param[in] buf_key is event key in bytes
- * @param[in] len_key is length of event key bytes
- * @param[in] buf_data is event data in bytes
- * @param[in] len_data is length of event data bytes
- * @param[in] buf_type_tag is type tag in bytes
- * @param[in] len_type_tag is length of type tag bytes
- * @param[out] rust allocated LibraEvent to write into
+/**
+ * Decode LibraSignedTransaction from bytes in SignedTransaction.
+ *
+ * @param[in] buf contains encoded bytes of txn_bytes
+ * @param[in] len is the length of the signed transaction memory buffer.
+ * @param[out] out is a caller allocated LibraSignedTransaction to write into.
+ *
  * @returns status code, one of LibraStatus
-*/
-enum LibraStatus libra_LibraEvent_from(const uint8_t *buf_key, size_t len_key, const uint8_t *buf_data, size_t len_data, const uint8_t *buf_type_tag, size_t len_type_tag, struct LibraEvent **out);
-
-/*!
- * Function to free the allocation event memory in rust
- * @param out is the pointer to the event struct allocated, and needs to be freed from client side
  */
-void libra_LibraEvent_free(struct LibraEvent *out);
-
-/*!
- * This function returns the string message of the most recent error in Rust.
- * Error will be in UTF8 string encoding and client does not need to free the string from their side.
- * @returns error message string
-*/
-const char *libra_strerror();
+enum LibraStatus libra_LibraSignedTransaction_from(const uint8_t *buf,
+                                                   uintptr_t len,
+                                                   struct LibraSignedTransaction *out);
 
 #ifdef __cplusplus
-};
-#endif
+} // extern "C"
+#endif // __cplusplus
 
-#endif // LIBRA_DEV_H
+#endif /* LIBRA_DEV_H */
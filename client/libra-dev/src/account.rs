@@ -9,7 +9,9 @@ use libra_crypto::{ed25519::Ed25519PrivateKey, PrivateKey};
 use libra_types::account_address;
 use std::{convert::TryFrom, slice};
 
-/// Takes in private key in bytes and return the associated public key and address
+/// This function takes in private key in bytes and return the associated public key and address
+/// @param[in] private_key_bytes is private key in bytes
+/// @param[out] out is a pointer to LibraAccountKey struct client passed in by initializing an empty LibraAccountKey struct
 #[no_mangle]
 pub unsafe extern "C" fn libra_LibraAccountKey_from(
     private_key_bytes: *const u8,
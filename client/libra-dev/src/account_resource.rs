@@ -57,6 +57,13 @@ pub fn libra_LibraAccountResource_from_safe(
     Err(LibraStatus::InvalidArgument)
 }
 
+/// Decode LibraAccountResource from bytes in AccountStateBlob.
+///
+/// @param[in] buf contains encoded bytes of AccountStateBlob
+/// @param[in] len is the length of the signed AccountStateBlob memory buffer.
+/// @param[out] out is a caller allocated LibraAccountResource to write into.
+///
+/// @returns status code, one of LibraStatus
 #[no_mangle]
 pub unsafe extern "C" fn libra_LibraAccountResource_from(
     buf: *const u8,
@@ -1,8 +1,312 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-#![allow(non_upper_case_globals)]
-#![allow(non_camel_case_types)]
-#![allow(non_snake_case)]
-#![allow(unused)]
-include!(concat!(env!("OUT_DIR"), "/data.rs"));
+//! Types shared with C clients. The header `include/data.h` is generated by `cbindgen` from
+//! these definitions and from the `extern "C"` functions of this crate, see `tests/header.rs`.
+
+use std::ptr;
+
+pub const LIBRA_PUBKEY_SIZE: usize = 32;
+pub const LIBRA_PRIVKEY_SIZE: usize = 32;
+pub const LIBRA_SIGNATURE_SIZE: usize = 64;
+pub const LIBRA_ADDRESS_SIZE: usize = 16;
+pub const LIBRA_EVENT_KEY_SIZE: usize = 24;
+pub const LIBRA_AUTHENTICATION_KEY_SIZE: usize = 32;
+/// Size of the buffers holding null-terminated identifiers, e.g. module names or currency codes.
+pub const LIBRA_IDENTIFIER_SIZE: usize = 255;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LibraStatus {
+    Ok = 0,
+    InvalidArgument = -1,
+    InternalError = -255,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LibraEventHandle {
+    pub count: u64,
+    pub key: [u8; LIBRA_EVENT_KEY_SIZE],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LibraAccountResource {
+    pub balance: u64,
+    pub sequence: u64,
+    pub authentication_key: [u8; LIBRA_PUBKEY_SIZE],
+    pub delegated_key_rotation_capability: bool,
+    pub delegated_withdrawal_capability: bool,
+    pub sent_events: LibraEventHandle,
+    pub received_events: LibraEventHandle,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LibraP2PTransferTransactionArgument {
+    pub value: u64,
+    pub address: [u8; LIBRA_ADDRESS_SIZE],
+    pub metadata_bytes: *const u8,
+    pub metadata_len: usize,
+    pub metadata_signature_bytes: *const u8,
+    pub metadata_signature_len: usize,
+}
+
+impl Default for LibraP2PTransferTransactionArgument {
+    fn default() -> Self {
+        Self {
+            value: 0,
+            address: [0; LIBRA_ADDRESS_SIZE],
+            metadata_bytes: ptr::null(),
+            metadata_len: 0,
+            metadata_signature_bytes: ptr::null(),
+            metadata_signature_len: 0,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransactionType {
+    PeerToPeer = 0,
+    Mint = 1,
+    Unknown = -1,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LibraTransactionPayload {
+    pub txn_type: TransactionType,
+    pub args: LibraP2PTransferTransactionArgument,
+}
+
+impl Default for LibraTransactionPayload {
+    fn default() -> Self {
+        Self {
+            txn_type: TransactionType::Unknown,
+            args: LibraP2PTransferTransactionArgument::default(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LibraRawTransaction {
+    pub sender: [u8; LIBRA_ADDRESS_SIZE],
+    pub sequence_number: u64,
+    pub payload: LibraTransactionPayload,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_time_secs: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraSignedTransaction {
+    pub raw_txn: LibraRawTransaction,
+    pub public_key: [u8; LIBRA_PUBKEY_SIZE],
+    pub signature: [u8; LIBRA_SIGNATURE_SIZE],
+}
+
+impl Default for LibraSignedTransaction {
+    fn default() -> Self {
+        Self {
+            raw_txn: LibraRawTransaction::default(),
+            public_key: [0; LIBRA_PUBKEY_SIZE],
+            signature: [0; LIBRA_SIGNATURE_SIZE],
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LibraAccountKey {
+    pub address: [u8; LIBRA_ADDRESS_SIZE],
+    pub private_key: [u8; LIBRA_PRIVKEY_SIZE],
+    pub public_key: [u8; LIBRA_PUBKEY_SIZE],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LibraEventType {
+    SentPaymentEvent = 1,
+    ReceivedPaymentEvent = 2,
+    BurnEvent = 3,
+    CancelBurnEvent = 4,
+    MintEvent = 5,
+    ToLBRExchangeRateUpdateEvent = 6,
+    PreburnEvent = 7,
+    UpgradeEvent = 8,
+    NewEpochEvent = 9,
+    NewBlockEvent = 10,
+    UndefinedEvent = -1,
+}
+
+/// Data of `SentPaymentEvent` and `ReceivedPaymentEvent`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraPaymentEvent {
+    pub sender_address: [u8; LIBRA_ADDRESS_SIZE],
+    pub receiver_address: [u8; LIBRA_ADDRESS_SIZE],
+    pub amount: u64,
+    pub metadata: *mut u8,
+    pub metadata_len: usize,
+}
+
+impl Default for LibraPaymentEvent {
+    fn default() -> Self {
+        Self {
+            sender_address: [0; LIBRA_ADDRESS_SIZE],
+            receiver_address: [0; LIBRA_ADDRESS_SIZE],
+            amount: 0,
+            metadata: ptr::null_mut(),
+            metadata_len: 0,
+        }
+    }
+}
+
+/// Data of `BurnEvent`, `CancelBurnEvent` and `PreburnEvent`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraBurnEvent {
+    pub amount: u64,
+    pub currency: [u8; LIBRA_IDENTIFIER_SIZE],
+    pub preburn_address: [u8; LIBRA_ADDRESS_SIZE],
+}
+
+impl Default for LibraBurnEvent {
+    fn default() -> Self {
+        Self {
+            amount: 0,
+            currency: [0; LIBRA_IDENTIFIER_SIZE],
+            preburn_address: [0; LIBRA_ADDRESS_SIZE],
+        }
+    }
+}
+
+/// Data of `MintEvent`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraMintEvent {
+    pub amount: u64,
+    pub currency: [u8; LIBRA_IDENTIFIER_SIZE],
+}
+
+impl Default for LibraMintEvent {
+    fn default() -> Self {
+        Self {
+            amount: 0,
+            currency: [0; LIBRA_IDENTIFIER_SIZE],
+        }
+    }
+}
+
+/// Data of `ToLBRExchangeRateUpdateEvent`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraToLBRExchangeRateUpdateEvent {
+    pub currency: [u8; LIBRA_IDENTIFIER_SIZE],
+    pub new_to_lbr_exchange_rate: f32,
+}
+
+impl Default for LibraToLBRExchangeRateUpdateEvent {
+    fn default() -> Self {
+        Self {
+            currency: [0; LIBRA_IDENTIFIER_SIZE],
+            new_to_lbr_exchange_rate: 0.0,
+        }
+    }
+}
+
+/// Data of `UpgradeEvent`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LibraUpgradeEvent {
+    pub write_set: *mut u8,
+    pub write_set_len: usize,
+}
+
+impl Default for LibraUpgradeEvent {
+    fn default() -> Self {
+        Self {
+            write_set: ptr::null_mut(),
+            write_set_len: 0,
+        }
+    }
+}
+
+/// Data of `NewEpochEvent`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LibraNewEpochEvent {
+    pub epoch: u64,
+}
+
+/// Data of `NewBlockEvent`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LibraNewBlockEvent {
+    pub round: u64,
+    pub proposer: [u8; LIBRA_ADDRESS_SIZE],
+    pub proposed_time: u64,
+}
+
+/// A decoded payment event. `event_type` is `SentPaymentEvent`, `ReceivedPaymentEvent` or
+/// `UndefinedEvent` for any other event. Use `LibraEventV2` to decode the other events.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraEvent {
+    pub event_type: LibraEventType,
+    // TODO: address
+    pub module: [u8; LIBRA_IDENTIFIER_SIZE],
+    pub name: [u8; LIBRA_IDENTIFIER_SIZE],
+    // TODO: type_params
+    pub payment_event_data: LibraPaymentEvent,
+}
+
+/// A decoded event of any of the types of `LibraEventType`. Only the data fields matching
+/// `event_type` are set. The layout of `LibraEvent` is left unchanged for existing consumers.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LibraEventV2 {
+    pub event_type: LibraEventType,
+    /// Address of the module declaring the event type.
+    pub address: [u8; LIBRA_ADDRESS_SIZE],
+    pub module: [u8; LIBRA_IDENTIFIER_SIZE],
+    pub name: [u8; LIBRA_IDENTIFIER_SIZE],
+    /// LCS-serialized `Vec<TypeTag>` of the type parameters of the event type, or null if the
+    /// event type is not a struct.
+    pub type_params: *mut u8,
+    pub type_params_len: usize,
+    pub payment_event_data: LibraPaymentEvent,
+    /// Currency of `payment_event_data`.
+    pub payment_currency: [u8; LIBRA_IDENTIFIER_SIZE],
+    pub burn_event_data: LibraBurnEvent,
+    pub mint_event_data: LibraMintEvent,
+    pub exchange_rate_update_event_data: LibraToLBRExchangeRateUpdateEvent,
+    pub upgrade_event_data: LibraUpgradeEvent,
+    pub new_epoch_event_data: LibraNewEpochEvent,
+    pub new_block_event_data: LibraNewBlockEvent,
+}
+
+impl Default for LibraEventV2 {
+    fn default() -> Self {
+        Self {
+            event_type: LibraEventType::UndefinedEvent,
+            address: [0; LIBRA_ADDRESS_SIZE],
+            module: [0; LIBRA_IDENTIFIER_SIZE],
+            name: [0; LIBRA_IDENTIFIER_SIZE],
+            type_params: ptr::null_mut(),
+            type_params_len: 0,
+            payment_event_data: LibraPaymentEvent::default(),
+            payment_currency: [0; LIBRA_IDENTIFIER_SIZE],
+            burn_event_data: LibraBurnEvent::default(),
+            mint_event_data: LibraMintEvent::default(),
+            exchange_rate_update_event_data: LibraToLBRExchangeRateUpdateEvent::default(),
+            upgrade_event_data: LibraUpgradeEvent::default(),
+            new_epoch_event_data: LibraNewEpochEvent::default(),
+            new_block_event_data: LibraNewBlockEvent::default(),
+        }
+    }
+}
@@ -2,20 +2,36 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    data::{LibraEvent, LibraEventType, LibraPaymentEvent, LibraStatus},
+    data::{
+        LibraBurnEvent, LibraEvent, LibraEventType, LibraEventV2, LibraMintEvent,
+        LibraNewBlockEvent, LibraNewEpochEvent, LibraPaymentEvent, LibraStatus,
+        LibraToLBRExchangeRateUpdateEvent, LibraUpgradeEvent, LIBRA_IDENTIFIER_SIZE,
+    },
     error::*,
 };
 use libra_types::{
     account_address::AccountAddress,
-    account_config::{ReceivedPaymentEvent, SentPaymentEvent},
+    account_config::{
+        BurnEvent, CancelBurnEvent, MintEvent, NewBlockEvent, NewEpochEvent, PreburnEvent,
+        ReceivedPaymentEvent, SentPaymentEvent, ToLBRExchangeRateUpdateEvent, UpgradeEvent,
+    },
     contract_event::ContractEvent,
     event::EventKey,
 };
-use move_core_types::language_storage::TypeTag;
-use std::{convert::TryFrom, ffi::CString, ops::Deref, slice};
-
-const MAX_BUFFER_LENGTH: usize = 255;
-
+use move_core_types::{language_storage::TypeTag, move_resource::MoveResource};
+use std::{convert::TryFrom, slice};
+
+/// This function takes in an event key, event data and event type tag in bytes, and return LibraEvent.
+/// Only payment events are decoded; use `libra_LibraEventV2_from` to decode the other events.
+/// To get the event in a memory safe manner, the client needs to call free on the output with `libra_LibraEvent_free`.
+/// @param[in] buf_key is event key in bytes
+/// @param[in] len_key is length of event key bytes
+/// @param[in] buf_data is event data in bytes
+/// @param[in] len_data is length of event data bytes
+/// @param[in] buf_type_tag is type tag in bytes
+/// @param[in] len_type_tag is length of type tag bytes
+/// @param[out] out is filled with a rust allocated LibraEvent to write into
+/// @returns status code, one of LibraStatus
 #[no_mangle]
 pub unsafe extern "C" fn libra_LibraEvent_from(
     buf_key: *const u8,
@@ -27,23 +43,92 @@ pub unsafe extern "C" fn libra_LibraEvent_from(
     out: *mut *mut LibraEvent,
 ) -> LibraStatus {
     clear_error();
+    match decode_event(
+        buf_key,
+        len_key,
+        buf_data,
+        len_data,
+        buf_type_tag,
+        len_type_tag,
+    ) {
+        Ok(event) => {
+            *out = Box::into_raw(Box::new(into_payment_event(event)));
+            LibraStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+/// This function takes in an event key, event data and event type tag in bytes, and return LibraEventV2.
+/// To get the event in a memory safe manner, the client needs to call free on the output with `libra_LibraEventV2_free`.
+/// @param[in] buf_key is event key in bytes
+/// @param[in] len_key is length of event key bytes
+/// @param[in] buf_data is event data in bytes
+/// @param[in] len_data is length of event data bytes
+/// @param[in] buf_type_tag is type tag in bytes
+/// @param[in] len_type_tag is length of type tag bytes
+/// @param[out] out is filled with a rust allocated LibraEventV2 to write into
+/// @returns status code, one of LibraStatus
+#[no_mangle]
+pub unsafe extern "C" fn libra_LibraEventV2_from(
+    buf_key: *const u8,
+    len_key: usize,
+    buf_data: *const u8,
+    len_data: usize,
+    buf_type_tag: *const u8,
+    len_type_tag: usize,
+    out: *mut *mut LibraEventV2,
+) -> LibraStatus {
+    clear_error();
+    match decode_event(
+        buf_key,
+        len_key,
+        buf_data,
+        len_data,
+        buf_type_tag,
+        len_type_tag,
+    ) {
+        Ok(event) => {
+            *out = Box::into_raw(Box::new(event));
+            LibraStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe fn decode_event(
+    buf_key: *const u8,
+    len_key: usize,
+    buf_data: *const u8,
+    len_data: usize,
+    buf_type_tag: *const u8,
+    len_type_tag: usize,
+) -> Result<LibraEventV2, LibraStatus> {
     if buf_key.is_null() {
         update_last_error("buf_key parameter must not be null.".to_string());
-        return LibraStatus::InvalidArgument;
+        return Err(LibraStatus::InvalidArgument);
     }
     if buf_data.is_null() {
         update_last_error("buf_data parameter must not be null.".to_string());
-        return LibraStatus::InvalidArgument;
+        return Err(LibraStatus::InvalidArgument);
     }
     if buf_type_tag.is_null() {
         update_last_error("buf_type_tag parameter must not be null.".to_string());
-        return LibraStatus::InvalidArgument;
+        return Err(LibraStatus::InvalidArgument);
     }
 
     let buffer_key: &[u8] = slice::from_raw_parts(buf_key, len_key);
     let buffer_data: &[u8] = slice::from_raw_parts(buf_data, len_data);
     let buffer_type_tag: &[u8] = slice::from_raw_parts(buf_type_tag, len_type_tag);
 
+    if buffer_key.len() != EventKey::LENGTH {
+        update_last_error(format!(
+            "Invalid event key length: {}, expected {}",
+            buffer_key.len(),
+            EventKey::LENGTH
+        ));
+        return Err(LibraStatus::InvalidArgument);
+    }
     let mut key = [0u8; EventKey::LENGTH];
     key.copy_from_slice(buffer_key);
 
@@ -55,121 +140,234 @@ pub unsafe extern "C" fn libra_LibraEvent_from(
         Ok(result) => result,
         Err(e) => {
             update_last_error(format!("Error deserializing type tag: {}", e.to_string()));
-            return LibraStatus::InvalidArgument;
+            return Err(LibraStatus::InvalidArgument);
         }
     };
 
-    let mut module = [0u8; MAX_BUFFER_LENGTH];
-    let mut name = [0u8; MAX_BUFFER_LENGTH];
+    let mut event = LibraEventV2::default();
 
-    if let TypeTag::Struct(struct_tag) = type_tag.clone() {
-        let module_tmp = match CString::new(struct_tag.module.into_string()) {
+    if let TypeTag::Struct(struct_tag) = &type_tag {
+        event.address = struct_tag.address.into();
+        event.module = match identifier_buffer(struct_tag.module.as_str()) {
             Ok(res) => res,
             Err(e) => {
-                update_last_error(format!(
-                    "Error converting module to string: {}",
-                    e.to_string()
-                ));
-                return LibraStatus::InvalidArgument;
+                update_last_error(format!("Error converting module to string: {}", e));
+                return Err(LibraStatus::InvalidArgument);
             }
         };
-        let module_bytes = module_tmp.as_bytes_with_nul();
-        let module_len = module_bytes.len();
-        module[..module_len].copy_from_slice(module_bytes);
-
-        let name_tmp = match CString::new(struct_tag.name.into_string()) {
+        event.name = match identifier_buffer(struct_tag.name.as_str()) {
+            Ok(res) => res,
+            Err(e) => {
+                update_last_error(format!("Error converting struct tag name to string: {}", e));
+                return Err(LibraStatus::InvalidArgument);
+            }
+        };
+        let type_params = match lcs::to_bytes(&struct_tag.type_params) {
             Ok(res) => res,
             Err(e) => {
-                update_last_error(format!(
-                    "Error converting struct tag name to string: {}",
-                    e.to_string()
-                ));
-                return LibraStatus::InvalidArgument;
+                update_last_error(format!("Error serializing type params: {}", e));
+                return Err(LibraStatus::InternalError);
             }
         };
-        let name_bytes = name_tmp.as_bytes_with_nul();
-        let name_len = name_bytes.len();
-        name[..name_len].copy_from_slice(name_bytes);
+        event.type_params = into_raw_bytes(&type_params);
+        event.type_params_len = type_params.len();
     };
 
     let account_event = ContractEvent::new(EventKey::new(key), 0, type_tag, buffer_data.to_vec());
 
-    let mut event_enum = LibraEventType::UndefinedEvent;
-    let mut event_data: Option<LibraPaymentEvent> = None;
-
-    match SentPaymentEvent::try_from(&account_event) {
-        Ok(res) => {
-            event_enum = LibraEventType::SentPaymentEvent;
-
-            let metadata_len = (*res.metadata()).len();
-            let metadata_ptr = if metadata_len > 0 {
-                let metadata = res.metadata().clone().into_boxed_slice();
-                (*Box::into_raw(metadata)).as_mut_ptr()
-            } else {
-                std::ptr::null_mut()
-            };
-
-            event_data = Some(LibraPaymentEvent {
-                sender_address: key_address,
-                receiver_address: res.receiver().into(),
-                amount: res.amount(),
-                metadata: metadata_ptr,
-                metadata_len,
-            });
+    let event_type = match decode_event_data(&account_event, key_address, &mut event) {
+        Ok(event_type) => event_type,
+        Err(e) => {
+            // Events of unknown types are still returned, without data.
+            update_last_error(e);
+            LibraEventType::UndefinedEvent
         }
-        Err(e) => update_last_error(format!(
-            "Error deserializing SentPaymentEvent: {}",
-            e.to_string()
-        )),
     };
+    event.event_type = event_type;
 
-    match ReceivedPaymentEvent::try_from(&account_event) {
-        Ok(res) => {
-            event_enum = LibraEventType::ReceivedPaymentEvent;
-
-            let metadata_len = (*res.metadata()).len();
-            let metadata_ptr = if metadata_len > 0 {
-                let metadata = res.metadata().clone().into_boxed_slice();
-                (*Box::into_raw(metadata)).as_mut_ptr()
-            } else {
-                std::ptr::null_mut()
-            };
-
-            event_data = Some(LibraPaymentEvent {
-                sender_address: res.sender().into(),
-                receiver_address: key_address,
-                amount: res.amount(),
-                metadata: metadata_ptr,
-                metadata_len,
-            });
-        }
-        Err(e) => update_last_error(format!(
-            "Error deserializing ReceivedPaymentEvent: {}",
-            e.to_string()
-        )),
+    Ok(event)
+}
+
+/// Keep the fields of `event` that exist in a `LibraEvent`, i.e. the data of payment events,
+/// and release the rest.
+unsafe fn into_payment_event(event: LibraEventV2) -> LibraEvent {
+    free_raw_bytes(event.type_params, event.type_params_len);
+    free_raw_bytes(
+        event.upgrade_event_data.write_set,
+        event.upgrade_event_data.write_set_len,
+    );
+    let event_type = match event.event_type {
+        LibraEventType::SentPaymentEvent | LibraEventType::ReceivedPaymentEvent => event.event_type,
+        _ => LibraEventType::UndefinedEvent,
     };
+    LibraEvent {
+        event_type,
+        module: event.module,
+        name: event.name,
+        payment_event_data: event.payment_event_data,
+    }
+}
 
-    let result = Box::new(LibraEvent {
-        event_type: event_enum,
-        module,
-        name,
-        payment_event_data: event_data.unwrap_or_default(),
-    });
+/// Decode the data of a known event into the matching field of `out` and return the type of
+/// the event.
+fn decode_event_data(
+    event: &ContractEvent,
+    key_address: [u8; AccountAddress::LENGTH],
+    out: &mut LibraEventV2,
+) -> Result<LibraEventType, String> {
+    let type_tag = event.type_tag();
+    let is = |struct_tag| *type_tag == TypeTag::Struct(struct_tag);
+    let error =
+        |name: &str, e: &dyn std::fmt::Display| format!("Error deserializing {}: {}", name, e);
+
+    if is(SentPaymentEvent::struct_tag()) {
+        let data = SentPaymentEvent::try_from(event)
+            .map_err(|e| error(SentPaymentEvent::STRUCT_NAME, &e))?;
+        out.payment_event_data = LibraPaymentEvent {
+            sender_address: key_address,
+            receiver_address: data.receiver().into(),
+            amount: data.amount(),
+            metadata: into_raw_bytes(data.metadata()),
+            metadata_len: data.metadata().len(),
+        };
+        out.payment_currency = identifier_buffer(data.currency_code().as_str())?;
+        Ok(LibraEventType::SentPaymentEvent)
+    } else if is(ReceivedPaymentEvent::struct_tag()) {
+        let data = ReceivedPaymentEvent::try_from(event)
+            .map_err(|e| error(ReceivedPaymentEvent::STRUCT_NAME, &e))?;
+        out.payment_event_data = LibraPaymentEvent {
+            sender_address: data.sender().into(),
+            receiver_address: key_address,
+            amount: data.amount(),
+            metadata: into_raw_bytes(data.metadata()),
+            metadata_len: data.metadata().len(),
+        };
+        out.payment_currency = identifier_buffer(data.currency_code().as_str())?;
+        Ok(LibraEventType::ReceivedPaymentEvent)
+    } else if is(BurnEvent::struct_tag()) {
+        let data = BurnEvent::try_from(event).map_err(|e| error(BurnEvent::STRUCT_NAME, &e))?;
+        out.burn_event_data = LibraBurnEvent {
+            amount: data.amount(),
+            currency: identifier_buffer(data.currency_code().as_str())?,
+            preburn_address: data.preburn_address().into(),
+        };
+        Ok(LibraEventType::BurnEvent)
+    } else if is(CancelBurnEvent::struct_tag()) {
+        let data = CancelBurnEvent::try_from(event)
+            .map_err(|e| error(CancelBurnEvent::STRUCT_NAME, &e))?;
+        out.burn_event_data = LibraBurnEvent {
+            amount: data.amount(),
+            currency: identifier_buffer(data.currency_code().as_str())?,
+            preburn_address: data.preburn_address().into(),
+        };
+        Ok(LibraEventType::CancelBurnEvent)
+    } else if is(PreburnEvent::struct_tag()) {
+        let data =
+            PreburnEvent::try_from(event).map_err(|e| error(PreburnEvent::STRUCT_NAME, &e))?;
+        out.burn_event_data = LibraBurnEvent {
+            amount: data.amount(),
+            currency: identifier_buffer(data.currency_code().as_str())?,
+            preburn_address: data.preburn_address().into(),
+        };
+        Ok(LibraEventType::PreburnEvent)
+    } else if is(MintEvent::struct_tag()) {
+        let data = MintEvent::try_from(event).map_err(|e| error(MintEvent::STRUCT_NAME, &e))?;
+        out.mint_event_data = LibraMintEvent {
+            amount: data.amount(),
+            currency: identifier_buffer(data.currency_code().as_str())?,
+        };
+        Ok(LibraEventType::MintEvent)
+    } else if is(ToLBRExchangeRateUpdateEvent::struct_tag()) {
+        let data = ToLBRExchangeRateUpdateEvent::try_from(event)
+            .map_err(|e| error(ToLBRExchangeRateUpdateEvent::STRUCT_NAME, &e))?;
+        out.exchange_rate_update_event_data = LibraToLBRExchangeRateUpdateEvent {
+            currency: identifier_buffer(data.currency_code().as_str())?,
+            new_to_lbr_exchange_rate: data.new_to_lbr_exchange_rate(),
+        };
+        Ok(LibraEventType::ToLBRExchangeRateUpdateEvent)
+    } else if is(UpgradeEvent::struct_tag()) {
+        let data =
+            UpgradeEvent::try_from(event).map_err(|e| error(UpgradeEvent::STRUCT_NAME, &e))?;
+        out.upgrade_event_data = LibraUpgradeEvent {
+            write_set: into_raw_bytes(data.write_set()),
+            write_set_len: data.write_set().len(),
+        };
+        Ok(LibraEventType::UpgradeEvent)
+    } else if is(NewEpochEvent::struct_tag()) {
+        let data =
+            NewEpochEvent::try_from(event).map_err(|e| error(NewEpochEvent::STRUCT_NAME, &e))?;
+        out.new_epoch_event_data = LibraNewEpochEvent {
+            epoch: data.epoch(),
+        };
+        Ok(LibraEventType::NewEpochEvent)
+    } else if is(NewBlockEvent::struct_tag()) {
+        let data =
+            NewBlockEvent::try_from(event).map_err(|e| error(NewBlockEvent::STRUCT_NAME, &e))?;
+        out.new_block_event_data = LibraNewBlockEvent {
+            round: data.round(),
+            proposer: data.proposer().into(),
+            proposed_time: data.proposed_time(),
+        };
+        Ok(LibraEventType::NewBlockEvent)
+    } else {
+        Err(format!("Unknown event type: {}", type_tag))
+    }
+}
 
-    *out = Box::into_raw(result);
+/// Copy an identifier into a null-terminated buffer.
+fn identifier_buffer(identifier: &str) -> Result<[u8; LIBRA_IDENTIFIER_SIZE], String> {
+    let bytes = identifier.as_bytes();
+    if bytes.len() >= LIBRA_IDENTIFIER_SIZE {
+        return Err(format!("Identifier is too long: {}", identifier));
+    }
+    let mut buffer = [0u8; LIBRA_IDENTIFIER_SIZE];
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    Ok(buffer)
+}
 
-    LibraStatus::Ok
+/// Move a copy of `bytes` to the heap, to be released with `free_raw_bytes`. Empty buffers are
+/// represented by a null pointer.
+fn into_raw_bytes(bytes: &[u8]) -> *mut u8 {
+    if bytes.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        (*Box::into_raw(bytes.to_vec().into_boxed_slice())).as_mut_ptr()
+    }
 }
 
+unsafe fn free_raw_bytes(bytes: *mut u8, len: usize) {
+    if !bytes.is_null() {
+        let _to_drop = Box::from_raw(slice::from_raw_parts_mut(bytes, len));
+    }
+}
+
+/// Function to free the allocation event memory in rust
+/// @param out is the pointer to the event struct allocated, and needs to be freed from client side
 #[no_mangle]
 pub unsafe extern "C" fn libra_LibraEvent_free(ptr: *mut LibraEvent) {
-    let _to_drop = Box::from_raw(ptr);
+    let to_drop = Box::from_raw(ptr);
 
-    let metadata = _to_drop.deref().payment_event_data.metadata;
-    let metadata_len = _to_drop.deref().payment_event_data.metadata_len;
-    if !metadata.is_null() {
-        let _to_drop = Box::from_raw(slice::from_raw_parts_mut(metadata, metadata_len));
-    }
+    free_raw_bytes(
+        to_drop.payment_event_data.metadata,
+        to_drop.payment_event_data.metadata_len,
+    );
+}
+
+/// Function to free the allocation event memory in rust
+/// @param out is the pointer to the event struct allocated, and needs to be freed from client side
+#[no_mangle]
+pub unsafe extern "C" fn libra_LibraEventV2_free(ptr: *mut LibraEventV2) {
+    let to_drop = Box::from_raw(ptr);
+
+    free_raw_bytes(to_drop.type_params, to_drop.type_params_len);
+    free_raw_bytes(
+        to_drop.payment_event_data.metadata,
+        to_drop.payment_event_data.metadata_len,
+    );
+    free_raw_bytes(
+        to_drop.upgrade_event_data.write_set,
+        to_drop.upgrade_event_data.write_set_len,
+    );
 }
 
 #[test]
@@ -185,7 +383,7 @@ fn test_libra_LibraEvent_from() {
         identifier::Identifier,
         language_storage::{StructTag, TypeTag::Struct},
     };
-    use std::ffi::CStr;
+    use std::{ffi::CStr, os::raw::c_char};
 
     let public_key = Ed25519PrivateKey::generate_for_testing().public_key();
     let sender_address = account_address::from_public_key(&public_key);
@@ -232,11 +430,12 @@ fn test_libra_LibraEvent_from() {
     assert_eq!(result, LibraStatus::Ok);
 
     unsafe {
-        let module_c_string: &CStr = CStr::from_ptr((*libra_event).module.as_ptr() as *const i8);
+        let module_c_string: &CStr =
+            CStr::from_ptr((*libra_event).module.as_ptr() as *const c_char);
         let module_str_slice: &str = module_c_string.to_str().unwrap();
         assert_eq!(module_str_slice, module);
 
-        let name_c_string: &CStr = CStr::from_ptr((*libra_event).name.as_ptr() as *const i8);
+        let name_c_string: &CStr = CStr::from_ptr((*libra_event).name.as_ptr() as *const c_char);
         let name_str_slice: &str = name_c_string.to_str().unwrap();
         assert_eq!(name_str_slice, name);
 
@@ -257,3 +456,97 @@ fn test_libra_LibraEvent_from() {
         libra_LibraEvent_free(libra_event);
     }
 }
+
+#[test]
+fn test_libra_LibraEventV2_from_burn_event() {
+    use libra_types::account_config::{from_currency_code_string, COIN1_NAME};
+    use std::{ffi::CStr, os::raw::c_char};
+
+    let key = EventKey::new_from_address(&AccountAddress::random(), 0);
+    let amount = 1_000;
+    let preburn_address = AccountAddress::random();
+    // Events are serialized as the tuple of their fields.
+    let event_data = lcs::to_bytes(&(
+        amount,
+        from_currency_code_string(COIN1_NAME).unwrap(),
+        preburn_address,
+    ))
+    .unwrap();
+    let burn_type_tag = lcs::to_bytes(&TypeTag::Struct(BurnEvent::struct_tag())).unwrap();
+
+    let mut libra_event: *mut LibraEventV2 = std::ptr::null_mut();
+    let result = unsafe {
+        libra_LibraEventV2_from(
+            key.as_bytes().as_ptr(),
+            key.as_bytes().len(),
+            event_data.as_ptr(),
+            event_data.len(),
+            burn_type_tag.as_ptr(),
+            burn_type_tag.len(),
+            &mut libra_event,
+        )
+    };
+    assert_eq!(result, LibraStatus::Ok);
+
+    unsafe {
+        assert_eq!((*libra_event).event_type, LibraEventType::BurnEvent);
+        assert_eq!(
+            AccountAddress::new((*libra_event).address),
+            BurnEvent::struct_tag().address
+        );
+        let type_params =
+            slice::from_raw_parts((*libra_event).type_params, (*libra_event).type_params_len);
+        assert_eq!(
+            lcs::from_bytes::<Vec<TypeTag>>(type_params).unwrap(),
+            BurnEvent::struct_tag().type_params
+        );
+        let burn_event = (*libra_event).burn_event_data;
+        assert_eq!(burn_event.amount, amount);
+        assert_eq!(
+            AccountAddress::new(burn_event.preburn_address),
+            preburn_address
+        );
+        let currency = CStr::from_ptr(burn_event.currency.as_ptr() as *const c_char);
+        assert_eq!(currency.to_str().unwrap(), COIN1_NAME);
+        libra_LibraEventV2_free(libra_event);
+    }
+
+    // Events of unknown types are returned without data.
+    let type_tag = lcs::to_bytes(&TypeTag::Bool).unwrap();
+    let result = unsafe {
+        libra_LibraEventV2_from(
+            key.as_bytes().as_ptr(),
+            key.as_bytes().len(),
+            event_data.as_ptr(),
+            event_data.len(),
+            type_tag.as_ptr(),
+            type_tag.len(),
+            &mut libra_event,
+        )
+    };
+    assert_eq!(result, LibraStatus::Ok);
+    unsafe {
+        assert_eq!((*libra_event).event_type, LibraEventType::UndefinedEvent);
+        assert!((*libra_event).type_params.is_null());
+        libra_LibraEventV2_free(libra_event);
+    }
+
+    // `LibraEvent` only holds payment events.
+    let mut libra_event: *mut LibraEvent = std::ptr::null_mut();
+    let result = unsafe {
+        libra_LibraEvent_from(
+            key.as_bytes().as_ptr(),
+            key.as_bytes().len(),
+            event_data.as_ptr(),
+            event_data.len(),
+            burn_type_tag.as_ptr(),
+            burn_type_tag.len(),
+            &mut libra_event,
+        )
+    };
+    assert_eq!(result, LibraStatus::Ok);
+    unsafe {
+        assert_eq!((*libra_event).event_type, LibraEventType::UndefinedEvent);
+        libra_LibraEvent_free(libra_event);
+    }
+}
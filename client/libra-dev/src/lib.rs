@@ -8,18 +8,29 @@
 
 pub mod account;
 pub mod account_resource;
-mod data;
+pub mod data;
 pub mod error;
 pub mod event;
+pub mod multisig;
+pub mod proof;
+mod script_args;
+pub mod scripts;
 pub mod transaction;
 
 use crate::data::{
-    LIBRA_ADDRESS_SIZE, LIBRA_EVENT_KEY_SIZE, LIBRA_PRIVKEY_SIZE, LIBRA_PUBKEY_SIZE,
+    LIBRA_ADDRESS_SIZE, LIBRA_AUTHENTICATION_KEY_SIZE, LIBRA_EVENT_KEY_SIZE, LIBRA_PRIVKEY_SIZE,
+    LIBRA_PUBKEY_SIZE, LIBRA_SIGNATURE_SIZE,
+};
+use libra_crypto::ed25519::{
+    ED25519_PRIVATE_KEY_LENGTH, ED25519_PUBLIC_KEY_LENGTH, ED25519_SIGNATURE_LENGTH,
+};
+use libra_types::{
+    account_address::AccountAddress, event::EventKey, transaction::authenticator::AuthenticationKey,
 };
-use libra_crypto::ed25519::{ED25519_PRIVATE_KEY_LENGTH, ED25519_PUBLIC_KEY_LENGTH};
-use libra_types::{account_address::AccountAddress, event::EventKey};
 
-static_assertions::const_assert_eq!(LIBRA_PUBKEY_SIZE, ED25519_PUBLIC_KEY_LENGTH as u32);
-static_assertions::const_assert_eq!(LIBRA_PRIVKEY_SIZE, ED25519_PRIVATE_KEY_LENGTH as u32);
-static_assertions::const_assert_eq!(LIBRA_ADDRESS_SIZE, AccountAddress::LENGTH as u32);
-static_assertions::const_assert_eq!(LIBRA_EVENT_KEY_SIZE, EventKey::LENGTH as u32);
+static_assertions::const_assert_eq!(LIBRA_PUBKEY_SIZE, ED25519_PUBLIC_KEY_LENGTH);
+static_assertions::const_assert_eq!(LIBRA_PRIVKEY_SIZE, ED25519_PRIVATE_KEY_LENGTH);
+static_assertions::const_assert_eq!(LIBRA_SIGNATURE_SIZE, ED25519_SIGNATURE_LENGTH);
+static_assertions::const_assert_eq!(LIBRA_ADDRESS_SIZE, AccountAddress::LENGTH);
+static_assertions::const_assert_eq!(LIBRA_EVENT_KEY_SIZE, EventKey::LENGTH);
+static_assertions::const_assert_eq!(LIBRA_AUTHENTICATION_KEY_SIZE, AuthenticationKey::LENGTH);
@@ -0,0 +1,315 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for K-of-N multi-signature accounts. Each of the N key holders signs the raw
+//! transaction with their own ed25519 key, as for single-signature accounts, and the signatures
+//! of any K of them are then assembled into a multi-ed25519 signed transaction.
+
+use crate::{
+    data::{LibraStatus, LIBRA_AUTHENTICATION_KEY_SIZE, LIBRA_PUBKEY_SIZE, LIBRA_SIGNATURE_SIZE},
+    error::*,
+};
+use lcs::to_bytes;
+use libra_crypto::{
+    ed25519::{Ed25519PublicKey, Ed25519Signature},
+    multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+};
+use libra_types::transaction::{
+    authenticator::AuthenticationKey, RawTransaction, SignedTransaction,
+};
+use std::{convert::TryFrom, slice};
+
+/// Create the public key of a `threshold`-of-`num_keys` multi-signature account.
+/// @param[in] public_keys is the concatenation of the num_keys ed25519 public keys of the key holders, of LIBRA_PUBKEY_SIZE bytes each.
+/// @param[in] num_keys is the number of key holders.
+/// @param[in] threshold is the number of signatures required to authenticate a transaction.
+/// @param[out] buf_result is the pointer that will be filled with the memory address of the multi-ed25519 public key allocated in rust. User takes ownership of pointer returned by *buf_result, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] len_result is the length of the public key memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_MultiEd25519PublicKey_from(
+    public_keys: *const u8,
+    num_keys: usize,
+    threshold: u8,
+    buf_result: *mut *mut u8,
+    len_result: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    if public_keys.is_null() {
+        update_last_error("public_keys parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let public_keys_bytes = slice::from_raw_parts(public_keys, num_keys * LIBRA_PUBKEY_SIZE);
+
+    let mut keys = Vec::with_capacity(num_keys);
+    for key_bytes in public_keys_bytes.chunks(LIBRA_PUBKEY_SIZE) {
+        match Ed25519PublicKey::try_from(key_bytes) {
+            Ok(key) => keys.push(key),
+            Err(e) => {
+                update_last_error(format!("Invalid public key bytes: {}", e.to_string()));
+                return LibraStatus::InvalidArgument;
+            }
+        }
+    }
+    let public_key = match MultiEd25519PublicKey::new(keys, threshold) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!(
+                "Invalid multi-signature public key: {}",
+                e.to_string()
+            ));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    let public_key_bytes = public_key.to_bytes();
+    let key_buf: *mut u8 = libc::malloc(public_key_bytes.len()).cast();
+    key_buf.copy_from(public_key_bytes.as_ptr(), public_key_bytes.len());
+
+    *buf_result = key_buf;
+    *len_result = public_key_bytes.len();
+
+    LibraStatus::Ok
+}
+
+/// Compute the authentication key of a multi-signature account. The address of the account is
+/// made of the last LIBRA_ADDRESS_SIZE bytes of the authentication key.
+/// @param[in] buf_public_key is the multi-ed25519 public key in bytes, as returned by libra_MultiEd25519PublicKey_from
+/// @param[in] len_public_key is the length of public key bytes
+/// @param[out] out is a caller allocated buffer of LIBRA_AUTHENTICATION_KEY_SIZE bytes to write the authentication key into
+#[no_mangle]
+pub unsafe extern "C" fn libra_MultiEd25519AuthenticationKey_from(
+    buf_public_key: *const u8,
+    len_public_key: usize,
+    out: *mut u8,
+) -> LibraStatus {
+    clear_error();
+    if buf_public_key.is_null() {
+        update_last_error("buf_public_key parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let public_key_bytes: &[u8] = slice::from_raw_parts(buf_public_key, len_public_key);
+    let public_key = match MultiEd25519PublicKey::try_from(public_key_bytes) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!("Invalid public key bytes: {}", e.to_string()));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    let authentication_key = AuthenticationKey::multi_ed25519(&public_key);
+    out.copy_from(
+        authentication_key.as_ref().as_ptr(),
+        LIBRA_AUTHENTICATION_KEY_SIZE,
+    );
+
+    LibraStatus::Ok
+}
+
+/// This function takes in a raw transaction, the public key of a multi-signature account and the signatures of
+/// some of its key holders, and returns a signed transaction in bytes. The signatures are checked, and at least
+/// as many as the threshold of the public key are required.
+/// To get the serialized signed transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] buf_raw_txn is raw transaction in bytes
+/// @param[in] len_raw_txn is length of raw transaction bytes
+/// @param[in] buf_public_key is the multi-ed25519 public key in bytes, as returned by libra_MultiEd25519PublicKey_from
+/// @param[in] len_public_key is length of public key bytes
+/// @param[in] buf_signatures is the concatenation of the ed25519 signatures of the raw transaction, of LIBRA_SIGNATURE_SIZE bytes each
+/// @param[in] signer_indexes are the positions of the public keys of the signers in the multi-ed25519 public key, one per signature
+/// @param[in] num_signatures is the number of signatures
+/// @param[out] buf_result is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf_result, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] len_result is the length of the signed transaction memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_RawTransaction_sign_multi_ed25519(
+    buf_raw_txn: *const u8,
+    len_raw_txn: usize,
+    buf_public_key: *const u8,
+    len_public_key: usize,
+    buf_signatures: *const u8,
+    signer_indexes: *const u8,
+    num_signatures: usize,
+    buf_result: *mut *mut u8,
+    len_result: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    if buf_raw_txn.is_null() {
+        update_last_error("buf_raw_txn parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let raw_txn_bytes: &[u8] = slice::from_raw_parts(buf_raw_txn, len_raw_txn);
+    let raw_txn: RawTransaction = match lcs::from_bytes(&raw_txn_bytes) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!(
+                "Error deserializing raw transaction, invalid raw_txn bytes or length: {}",
+                e.to_string()
+            ));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    if buf_public_key.is_null() {
+        update_last_error("buf_public_key parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let public_key_bytes: &[u8] = slice::from_raw_parts(buf_public_key, len_public_key);
+    let public_key = match MultiEd25519PublicKey::try_from(public_key_bytes) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!("Invalid public key bytes: {}", e.to_string()));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    if buf_signatures.is_null() {
+        update_last_error("buf_signatures parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    if signer_indexes.is_null() {
+        update_last_error("signer_indexes parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let signatures_bytes: &[u8] =
+        slice::from_raw_parts(buf_signatures, num_signatures * LIBRA_SIGNATURE_SIZE);
+    let indexes: &[u8] = slice::from_raw_parts(signer_indexes, num_signatures);
+    let mut signatures = Vec::with_capacity(num_signatures);
+    for (signature_bytes, index) in signatures_bytes.chunks(LIBRA_SIGNATURE_SIZE).zip(indexes) {
+        match Ed25519Signature::try_from(signature_bytes) {
+            Ok(signature) => signatures.push((signature, *index)),
+            Err(e) => {
+                update_last_error(format!("Invalid signature bytes: {}", e.to_string()));
+                return LibraStatus::InvalidArgument;
+            }
+        }
+    }
+    let signature = match MultiEd25519Signature::new(signatures) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!("Invalid signatures: {}", e.to_string()));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    let signed_txn = SignedTransaction::new_multisig(raw_txn, public_key, signature);
+    if let Err(e) = signed_txn.clone().check_signature() {
+        update_last_error(format!("Invalid signatures: {}", e.to_string()));
+        return LibraStatus::InvalidArgument;
+    }
+    let signed_txn_bytes = match to_bytes(&signed_txn) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!(
+                "Error serializing signed transaction: {}",
+                e.to_string()
+            ));
+            return LibraStatus::InternalError;
+        }
+    };
+
+    let txn_buf: *mut u8 = libc::malloc(signed_txn_bytes.len()).cast();
+    txn_buf.copy_from(signed_txn_bytes.as_ptr(), signed_txn_bytes.len());
+
+    *buf_result = txn_buf;
+    *len_result = signed_txn_bytes.len();
+
+    LibraStatus::Ok
+}
+
+/// Create a 2-of-3 account and sign a transaction with two of its keys
+#[test]
+fn test_libra_RawTransaction_sign_multi_ed25519() {
+    use crate::transaction::libra_free_bytes_buffer;
+    use libra_crypto::{ed25519::Ed25519PrivateKey, PrivateKey, SigningKey, Uniform};
+    use libra_types::{account_config::LBR_NAME, transaction::Script};
+    use std::time::Duration;
+
+    let private_keys: Vec<_> = (0..3)
+        .map(|_| Ed25519PrivateKey::generate_for_testing())
+        .collect();
+    let public_keys: Vec<u8> = private_keys
+        .iter()
+        .flat_map(|key| key.public_key().to_bytes().to_vec())
+        .collect();
+
+    let mut public_key_buf: *mut u8 = std::ptr::null_mut();
+    let mut public_key_len: usize = 0;
+    let result = unsafe {
+        libra_MultiEd25519PublicKey_from(
+            public_keys.as_ptr(),
+            3,
+            2,
+            &mut public_key_buf,
+            &mut public_key_len,
+        )
+    };
+    assert_eq!(result, LibraStatus::Ok);
+    let public_key_bytes = unsafe { slice::from_raw_parts(public_key_buf, public_key_len) };
+    let public_key = MultiEd25519PublicKey::try_from(public_key_bytes).unwrap();
+    assert_eq!(*public_key.threshold(), 2);
+
+    let mut authentication_key = [0u8; LIBRA_AUTHENTICATION_KEY_SIZE];
+    let result = unsafe {
+        libra_MultiEd25519AuthenticationKey_from(
+            public_key_buf,
+            public_key_len,
+            authentication_key.as_mut_ptr(),
+        )
+    };
+    assert_eq!(result, LibraStatus::Ok);
+    let expected_authentication_key = AuthenticationKey::multi_ed25519(&public_key);
+    assert_eq!(
+        authentication_key.to_vec(),
+        expected_authentication_key.to_vec()
+    );
+
+    let raw_txn = RawTransaction::new_script(
+        expected_authentication_key.derived_address(),
+        0,
+        Script::new(vec![], vec![], vec![]),
+        1_000_000,
+        0,
+        LBR_NAME.to_owned(),
+        Duration::from_secs(100),
+    );
+    let raw_txn_bytes = to_bytes(&raw_txn).unwrap();
+
+    // The first and the last key holders sign.
+    let signatures: Vec<u8> = [&private_keys[0], &private_keys[2]]
+        .iter()
+        .flat_map(|key| key.sign(&raw_txn).to_bytes().to_vec())
+        .collect();
+    let sign = |indexes: &[u8], buf_result: &mut *mut u8, len_result: &mut usize| unsafe {
+        libra_RawTransaction_sign_multi_ed25519(
+            raw_txn_bytes.as_ptr(),
+            raw_txn_bytes.len(),
+            public_key_buf,
+            public_key_len,
+            signatures.as_ptr(),
+            indexes.as_ptr(),
+            indexes.len(),
+            buf_result,
+            len_result,
+        )
+    };
+
+    let mut signed_txn_buf: *mut u8 = std::ptr::null_mut();
+    let mut signed_txn_len: usize = 0;
+    assert_eq!(
+        sign(&[0, 1], &mut signed_txn_buf, &mut signed_txn_len),
+        LibraStatus::InvalidArgument
+    );
+    assert_eq!(
+        sign(&[0, 2], &mut signed_txn_buf, &mut signed_txn_len),
+        LibraStatus::Ok
+    );
+
+    let signed_txn_bytes = unsafe { slice::from_raw_parts(signed_txn_buf, signed_txn_len) };
+    let signed_txn: SignedTransaction = lcs::from_bytes(signed_txn_bytes).unwrap();
+    assert_eq!(signed_txn.clone().into_raw_transaction(), raw_txn);
+    assert!(signed_txn.check_signature().is_ok());
+
+    unsafe {
+        libra_free_bytes_buffer(public_key_buf);
+        libra_free_bytes_buffer(signed_txn_buf);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification of the account states returned by full nodes, e.g. by the
+//! `get_account_state_with_proof` JSON-RPC method, against a trusted ledger info.
+
+use crate::{
+    data::{LibraStatus, LIBRA_ADDRESS_SIZE},
+    error::*,
+};
+use libra_types::{
+    account_address::AccountAddress,
+    account_state_blob::{AccountStateBlob, AccountStateWithProof},
+    ledger_info::LedgerInfoWithSignatures,
+    proof::{
+        AccountStateProof, SparseMerkleProof, TransactionAccumulatorProof, TransactionInfoWithProof,
+    },
+    transaction::TransactionInfo,
+};
+use serde::de::DeserializeOwned;
+use std::{convert::TryFrom, slice};
+
+/// Deserialize the LCS bytes of the parameter `name`.
+unsafe fn from_lcs_buffer<T: DeserializeOwned>(
+    buf: *const u8,
+    len: usize,
+    name: &str,
+) -> Result<T, LibraStatus> {
+    if buf.is_null() {
+        update_last_error(format!("{} parameter must not be null.", name));
+        return Err(LibraStatus::InvalidArgument);
+    }
+    let bytes: &[u8] = slice::from_raw_parts(buf, len);
+    lcs::from_bytes(bytes).map_err(|e| {
+        update_last_error(format!(
+            "Error deserializing {}, invalid bytes or length: {}",
+            name,
+            e.to_string()
+        ));
+        LibraStatus::InvalidArgument
+    })
+}
+
+/// Verify the state of an account at a given version against a ledger info.
+/// The ledger info is trusted by the caller: the signatures of the validators are not checked.
+/// All structures are given in LCS bytes, as found in the JSON-RPC responses.
+/// @param[in] address is the account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] version is the version at which the account state is seen.
+/// @param[in] buf_blob is the account state blob, or null if the account does not exist.
+/// @param[in] len_blob is the length of the account state blob bytes.
+/// @param[in] buf_ledger_info_to_transaction_info_proof is the accumulator proof of the transaction info.
+/// @param[in] len_ledger_info_to_transaction_info_proof is the length of the accumulator proof bytes.
+/// @param[in] buf_transaction_info is the transaction info at `version`.
+/// @param[in] len_transaction_info is the length of the transaction info bytes.
+/// @param[in] buf_transaction_info_to_account_proof is the sparse merkle proof of the account state.
+/// @param[in] len_transaction_info_to_account_proof is the length of the sparse merkle proof bytes.
+/// @param[in] buf_ledger_info_with_signatures is the trusted ledger info.
+/// @param[in] len_ledger_info_with_signatures is the length of the ledger info bytes.
+/// @returns LibraStatus::Ok if the account state is proven, LibraStatus::InvalidArgument otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn libra_AccountStateWithProof_verify(
+    address: *const u8,
+    version: u64,
+    buf_blob: *const u8,
+    len_blob: usize,
+    buf_ledger_info_to_transaction_info_proof: *const u8,
+    len_ledger_info_to_transaction_info_proof: usize,
+    buf_transaction_info: *const u8,
+    len_transaction_info: usize,
+    buf_transaction_info_to_account_proof: *const u8,
+    len_transaction_info_to_account_proof: usize,
+    buf_ledger_info_with_signatures: *const u8,
+    len_ledger_info_with_signatures: usize,
+) -> LibraStatus {
+    clear_error();
+    if address.is_null() {
+        update_last_error("address parameter must not be null.".to_string());
+        return LibraStatus::InvalidArgument;
+    }
+    let address_bytes: &[u8] = slice::from_raw_parts(address, LIBRA_ADDRESS_SIZE);
+    let address = match AccountAddress::try_from(address_bytes) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!("Invalid address: {}", e.to_string()));
+            return LibraStatus::InvalidArgument;
+        }
+    };
+
+    let blob: Option<AccountStateBlob> = if buf_blob.is_null() {
+        None
+    } else {
+        match from_lcs_buffer(buf_blob, len_blob, "blob") {
+            Ok(result) => Some(result),
+            Err(status) => return status,
+        }
+    };
+    let ledger_info_to_transaction_info_proof: TransactionAccumulatorProof = match from_lcs_buffer(
+        buf_ledger_info_to_transaction_info_proof,
+        len_ledger_info_to_transaction_info_proof,
+        "ledger_info_to_transaction_info_proof",
+    ) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+    let transaction_info: TransactionInfo = match from_lcs_buffer(
+        buf_transaction_info,
+        len_transaction_info,
+        "transaction_info",
+    ) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+    let transaction_info_to_account_proof: SparseMerkleProof = match from_lcs_buffer(
+        buf_transaction_info_to_account_proof,
+        len_transaction_info_to_account_proof,
+        "transaction_info_to_account_proof",
+    ) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+    let ledger_info_with_signatures: LedgerInfoWithSignatures = match from_lcs_buffer(
+        buf_ledger_info_with_signatures,
+        len_ledger_info_with_signatures,
+        "ledger_info_with_signatures",
+    ) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    let account_state_with_proof = AccountStateWithProof::new(
+        version,
+        blob,
+        AccountStateProof::new(
+            TransactionInfoWithProof::new(ledger_info_to_transaction_info_proof, transaction_info),
+            transaction_info_to_account_proof,
+        ),
+    );
+    if let Err(e) =
+        account_state_with_proof.verify(ledger_info_with_signatures.ledger_info(), version, address)
+    {
+        update_last_error(format!("Invalid account state proof: {}", e.to_string()));
+        return LibraStatus::InvalidArgument;
+    }
+
+    LibraStatus::Ok
+}
+
+/// Verify the proof of an account state in a ledger whose state tree has a single leaf
+#[test]
+fn test_libra_AccountStateWithProof_verify() {
+    use libra_crypto::{hash::CryptoHash, HashValue};
+    use libra_types::{
+        block_info::BlockInfo, ledger_info::LedgerInfo, proof::SparseMerkleLeafNode,
+        vm_status::StatusCode,
+    };
+    use std::collections::BTreeMap;
+
+    let address = AccountAddress::random();
+    let blob = AccountStateBlob::from(vec![1, 2, 3]);
+    let leaf = SparseMerkleLeafNode::new(address.hash(), blob.hash());
+    let transaction_info = TransactionInfo::new(
+        HashValue::zero(),
+        leaf.hash(),
+        HashValue::zero(),
+        0,
+        StatusCode::EXECUTED,
+    );
+    let ledger_info = LedgerInfoWithSignatures::new(
+        LedgerInfo::new(
+            BlockInfo::new(0, 0, HashValue::zero(), transaction_info.hash(), 0, 0, None),
+            HashValue::zero(),
+        ),
+        BTreeMap::new(),
+    );
+
+    let blob_bytes = lcs::to_bytes(&blob).unwrap();
+    let accumulator_proof_bytes = lcs::to_bytes(&TransactionAccumulatorProof::new(vec![])).unwrap();
+    let transaction_info_bytes = lcs::to_bytes(&transaction_info).unwrap();
+    let account_proof_bytes = lcs::to_bytes(&SparseMerkleProof::new(Some(leaf), vec![])).unwrap();
+    let ledger_info_bytes = lcs::to_bytes(&ledger_info).unwrap();
+
+    let verify = |address: &AccountAddress, blob_bytes: Option<&[u8]>| unsafe {
+        libra_AccountStateWithProof_verify(
+            address.as_ref().as_ptr(),
+            0,
+            blob_bytes.map_or(std::ptr::null(), |bytes| bytes.as_ptr()),
+            blob_bytes.map_or(0, |bytes| bytes.len()),
+            accumulator_proof_bytes.as_ptr(),
+            accumulator_proof_bytes.len(),
+            transaction_info_bytes.as_ptr(),
+            transaction_info_bytes.len(),
+            account_proof_bytes.as_ptr(),
+            account_proof_bytes.len(),
+            ledger_info_bytes.as_ptr(),
+            ledger_info_bytes.len(),
+        )
+    };
+
+    assert_eq!(verify(&address, Some(&blob_bytes)), LibraStatus::Ok);
+
+    // The account exists, and no other account does.
+    assert_eq!(verify(&address, None), LibraStatus::InvalidArgument);
+    let other_blob_bytes = lcs::to_bytes(&AccountStateBlob::from(vec![4])).unwrap();
+    assert_eq!(
+        verify(&address, Some(&other_blob_bytes)),
+        LibraStatus::InvalidArgument
+    );
+    assert_eq!(
+        verify(&AccountAddress::random(), Some(&blob_bytes)),
+        LibraStatus::InvalidArgument
+    );
+}
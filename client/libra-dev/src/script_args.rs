@@ -0,0 +1,82 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions of the C arguments of the generated script builders in `scripts.rs`. On error,
+//! the most recent error is updated and the status to return is given back.
+
+use crate::{data::LibraStatus, error::*};
+use libra_types::{
+    account_address::AccountAddress,
+    account_config::{from_currency_code_string, type_tag_for_currency_code},
+    transaction::Script,
+};
+use move_core_types::language_storage::TypeTag;
+use std::{convert::TryFrom, ffi::CStr, os::raw::c_char, slice};
+
+/// Read the currency code `identifier`, e.g. "LBR", as the type tag of the currency.
+pub(crate) unsafe fn type_tag_from_currency_code(
+    identifier: *const c_char,
+    name: &str,
+) -> Result<TypeTag, LibraStatus> {
+    if identifier.is_null() {
+        update_last_error(format!("{} parameter must not be null.", name));
+        return Err(LibraStatus::InvalidArgument);
+    }
+    let identifier = CStr::from_ptr(identifier).to_string_lossy();
+    match from_currency_code_string(&identifier) {
+        Ok(currency_code) => Ok(type_tag_for_currency_code(currency_code)),
+        Err(e) => {
+            update_last_error(format!("Invalid {}: {}", name, e.to_string()));
+            Err(LibraStatus::InvalidArgument)
+        }
+    }
+}
+
+/// Read an account address of `AccountAddress::LENGTH` bytes.
+pub(crate) unsafe fn account_address_from_bytes(
+    bytes: *const u8,
+    name: &str,
+) -> Result<AccountAddress, LibraStatus> {
+    if bytes.is_null() {
+        update_last_error(format!("{} parameter must not be null.", name));
+        return Err(LibraStatus::InvalidArgument);
+    }
+    let buf = slice::from_raw_parts(bytes, AccountAddress::LENGTH);
+    AccountAddress::try_from(buf).map_err(|e| {
+        update_last_error(format!("Invalid {}: {}", name, e.to_string()));
+        LibraStatus::InvalidArgument
+    })
+}
+
+/// Copy a byte vector. A null pointer stands for an empty vector.
+pub(crate) unsafe fn vec_from_bytes(bytes: *const u8, len: usize) -> Vec<u8> {
+    if bytes.is_null() {
+        vec![]
+    } else {
+        slice::from_raw_parts(bytes, len).to_vec()
+    }
+}
+
+/// Serialize a script into a buffer allocated in Rust, to be freed with
+/// `libra_free_bytes_buffer`.
+pub(crate) unsafe fn write_script(
+    script: &Script,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    let script_bytes = match lcs::to_bytes(script) {
+        Ok(result) => result,
+        Err(e) => {
+            update_last_error(format!("Error serializing Script: {}", e.to_string()));
+            return LibraStatus::InternalError;
+        }
+    };
+
+    let script_buf: *mut u8 = libc::malloc(script_bytes.len()).cast();
+    script_buf.copy_from(script_bytes.as_ptr(), script_bytes.len());
+
+    *ptr_buf = script_buf;
+    *ptr_len = script_bytes.len();
+
+    LibraStatus::Ok
+}
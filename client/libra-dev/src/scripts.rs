@@ -0,0 +1,1188 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+// This file was generated. Do not modify!
+//
+// To re-generate this code, run: `(cd language/stdlib && cargo run --release)`
+
+use crate::{
+    data::LibraStatus,
+    error::clear_error,
+    script_args::{
+        account_address_from_bytes, type_tag_from_currency_code, vec_from_bytes, write_script,
+    },
+};
+use std::os::raw::c_char;
+use transaction_builder::*;
+
+/// Add a `Currency` balance to `account`, which will enable `account` to send and receive
+/// `Libra<Currency>`. Aborts with NOT_A_CURRENCY if `Currency` is not an accepted
+/// currency type in the Libra system Aborts with `LibraAccount::ADD_EXISTING_CURRENCY` if
+/// the account already holds a balance in `Currency`. Aborts with
+/// `LibraAccount::PARENT_VASP_CURRENCY_LIMITS_DNE` if `account` is a `ChildVASP` whose
+/// parent does not have an `AccountLimits<Currency>` resource.
+///
+/// @param[in] currency is the currency code of the type argument, e.g. "LBR".
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionAddCurrencyToAccountScript_from(
+    currency: *const c_char,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let currency = match type_tag_from_currency_code(currency, "currency") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_add_currency_to_account_script(currency);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Add the `KeyRotationCapability` for `to_recover_account` to the `RecoveryAddress`
+/// resource under `recovery_address`. Aborts if `to_recovery_account` and
+/// `to_recovery_address belong to different VASPs, if `recovery_address` does not have a
+/// `RecoveryAddress` resource, or if `to_recover_account` has already extracted its
+/// `KeyRotationCapability`.
+///
+/// @param[in] recovery_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionAddRecoveryRotationCapabilityScript_from(
+    recovery_address: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let recovery_address = match account_address_from_bytes(recovery_address, "recovery_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_add_recovery_rotation_capability_script(recovery_address);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Add `new_validator` to the pending validator set. Fails if the `new_validator` address
+/// is already in the validator set or does not have a `ValidatorConfig` resource stored
+/// at the address.
+///
+/// @param[in] validator_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionAddValidatorScript_from(
+    validator_address: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let validator_address = match account_address_from_bytes(validator_address, "validator_address")
+    {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_add_validator_script(validator_address);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Adds limits and an accounting window for `CoinType` currency to the parent VASP
+/// `account`. This transaction will fail if sent from a child account.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionAllowCurrencyForVaspScript_from(
+    coin_type: *const c_char,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_allow_currency_for_vasp_script(coin_type);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Permanently destroy the `Token`s stored in the oldest burn request under the `Preburn`
+/// resource. This will only succeed if `account` has a `MintCapability<Token>`, a
+/// `Preburn<Token>` resource exists under `preburn_address`, and there is a pending burn
+/// request. sliding_nonce is a unique nonce for operation, see sliding_nonce.move for
+/// details
+///
+/// @param[in] token is the currency code of the type argument, e.g. "LBR".
+/// @param[in] sliding_nonce
+/// @param[in] preburn_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionBurnScript_from(
+    token: *const c_char,
+    sliding_nonce: u64,
+    preburn_address: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let token = match type_tag_from_currency_code(token, "token") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let preburn_address = match account_address_from_bytes(preburn_address, "preburn_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_burn_script(token, sliding_nonce, preburn_address);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Burn transaction fees that have been collected in the given `currency` and relinquish
+/// to the association. The currency must be non-synthetic.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionBurnTxnFeesScript_from(
+    coin_type: *const c_char,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_burn_txn_fees_script(coin_type);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Cancel the oldest burn request from `preburn_address` and return the funds. Fails if
+/// the sender does not have a published `BurnCapability<Token>`.
+///
+/// @param[in] token is the currency code of the type argument, e.g. "LBR".
+/// @param[in] preburn_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCancelBurnScript_from(
+    token: *const c_char,
+    preburn_address: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let token = match type_tag_from_currency_code(token, "token") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let preburn_address = match account_address_from_bytes(preburn_address, "preburn_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_cancel_burn_script(token, preburn_address);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create a `ChildVASP` account for sender `parent_vasp` at `child_address` with a
+/// balance of `child_initial_balance` in `CoinType` and an initial authentication_key
+/// `auth_key_prefix | child_address`. If `add_all_currencies` is true, the child address
+/// will have a zero balance in all available currencies in the system. This account will
+/// a child of the transaction sender, which must be a ParentVASP.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] child_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[in] add_all_currencies
+/// @param[in] child_initial_balance
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateChildVaspAccountScript_from(
+    coin_type: *const c_char,
+    child_address: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    add_all_currencies: bool,
+    child_initial_balance: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let child_address = match account_address_from_bytes(child_address, "child_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+
+    let script = encode_create_child_vasp_account_script(
+        coin_type,
+        child_address,
+        auth_key_prefix,
+        add_all_currencies,
+        child_initial_balance,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create an account with the DesignatedDealer role at `addr` with authentication key
+/// `auth_key_prefix` | `addr` and a 0 balance of type `Currency`. If `add_all_currencies`
+/// is true, 0 balances for all available currencies in the system will also be added.
+/// This can only be invoked by an account with the TreasuryCompliance role.
+///
+/// @param[in] currency is the currency code of the type argument, e.g. "LBR".
+/// @param[in] sliding_nonce
+/// @param[in] addr is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[in] add_all_currencies
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateDesignatedDealerScript_from(
+    currency: *const c_char,
+    sliding_nonce: u64,
+    addr: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    add_all_currencies: bool,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let currency = match type_tag_from_currency_code(currency, "currency") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let addr = match account_address_from_bytes(addr, "addr") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+
+    let script = encode_create_designated_dealer_script(
+        currency,
+        sliding_nonce,
+        addr,
+        auth_key_prefix,
+        add_all_currencies,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create an account with the ParentVASP role at `address` with authentication key
+/// `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+/// `add_all_currencies` is true, 0 balances for all available currencies in the system
+/// will also be added. This can only be invoked by an Association account.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[in] human_name_bytes is the content of `human_name`, or null if it is empty.
+/// @param[in] human_name_len is the length of human_name_bytes.
+/// @param[in] base_url_bytes is the content of `base_url`, or null if it is empty.
+/// @param[in] base_url_len is the length of base_url_bytes.
+/// @param[in] compliance_public_key_bytes is the content of `compliance_public_key`, or null if it is empty.
+/// @param[in] compliance_public_key_len is the length of compliance_public_key_bytes.
+/// @param[in] add_all_currencies
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateParentVaspAccountScript_from(
+    coin_type: *const c_char,
+    new_account_address: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    human_name_bytes: *const u8,
+    human_name_len: usize,
+    base_url_bytes: *const u8,
+    base_url_len: usize,
+    compliance_public_key_bytes: *const u8,
+    compliance_public_key_len: usize,
+    add_all_currencies: bool,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let new_account_address =
+        match account_address_from_bytes(new_account_address, "new_account_address") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+    let human_name = vec_from_bytes(human_name_bytes, human_name_len);
+    let base_url = vec_from_bytes(base_url_bytes, base_url_len);
+    let compliance_public_key =
+        vec_from_bytes(compliance_public_key_bytes, compliance_public_key_len);
+
+    let script = encode_create_parent_vasp_account_script(
+        coin_type,
+        new_account_address,
+        auth_key_prefix,
+        human_name,
+        base_url,
+        compliance_public_key,
+        add_all_currencies,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Extract the `KeyRotationCapability` for `recovery_account` and publish it in a
+/// `RecoveryAddress` resource under `recovery_account`. Aborts if `recovery_account` has
+/// delegated its `KeyRotationCapability`, already has a `RecoveryAddress` resource, or is
+/// not a VASP.
+///
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateRecoveryAddressScript_from(
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_create_recovery_address_script();
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create an account with the ParentVASP role at `address` with authentication key
+/// `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+/// `add_all_currencies` is true, 0 balances for all available currencies in the system
+/// will also be added. This can only be invoked by an Association account. The
+/// `human_name`, `base_url`, and compliance_public_key` fields of the ParentVASP are
+/// filled in with dummy information.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[in] add_all_currencies
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateTestingAccountScript_from(
+    coin_type: *const c_char,
+    new_account_address: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    add_all_currencies: bool,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let new_account_address =
+        match account_address_from_bytes(new_account_address, "new_account_address") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+
+    let script = encode_create_testing_account_script(
+        coin_type,
+        new_account_address,
+        auth_key_prefix,
+        add_all_currencies,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create a validator account at `new_validator_address` with `auth_key_prefix`.
+///
+/// @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateValidatorAccountScript_from(
+    new_account_address: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_account_address =
+        match account_address_from_bytes(new_account_address, "new_account_address") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+
+    let script = encode_create_validator_account_script(new_account_address, auth_key_prefix);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Create a validator operator account at `new_validator_address` with `auth_key_prefix`.
+///
+/// @param[in] new_account_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] auth_key_prefix_bytes is the content of `auth_key_prefix`, or null if it is empty.
+/// @param[in] auth_key_prefix_len is the length of auth_key_prefix_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionCreateValidatorOperatorAccountScript_from(
+    new_account_address: *const u8,
+    auth_key_prefix_bytes: *const u8,
+    auth_key_prefix_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_account_address =
+        match account_address_from_bytes(new_account_address, "new_account_address") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+    let auth_key_prefix = vec_from_bytes(auth_key_prefix_bytes, auth_key_prefix_len);
+
+    let script =
+        encode_create_validator_operator_account_script(new_account_address, auth_key_prefix);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Freeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+/// nonce for operation, see sliding_nonce.move for details.
+///
+/// @param[in] sliding_nonce
+/// @param[in] to_freeze_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionFreezeAccountScript_from(
+    sliding_nonce: u64,
+    to_freeze_account: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let to_freeze_account = match account_address_from_bytes(to_freeze_account, "to_freeze_account")
+    {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_freeze_account_script(sliding_nonce, to_freeze_account);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Mint `amount_lbr` LBR from the sending account's constituent coins and deposits the
+/// resulting LBR into the sending account.
+///
+/// @param[in] amount_lbr
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionMintLbrScript_from(
+    amount_lbr: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_mint_lbr_script(amount_lbr);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Modify publishing options. Takes the LCS bytes of a `VMPublishingOption` object as
+/// input.
+///
+/// @param[in] args_bytes is the content of `args`, or null if it is empty.
+/// @param[in] args_len is the length of args_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionModifyPublishingOptionScript_from(
+    args_bytes: *const u8,
+    args_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let args = vec_from_bytes(args_bytes, args_len);
+
+    let script = encode_modify_publishing_option_script(args);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Transfer `amount` coins to `recipient_address` with (optional) associated metadata
+/// `metadata` and (optional) `signature` on the metadata, amount, and sender address. The
+/// `metadata` and `signature` parameters are only required if `amount` >= 1_000_000 micro
+/// LBR and the sender and recipient of the funds are two distinct VASPs. Fails if there
+/// is no account at the recipient address or if the sender's balance is lower than
+/// `amount`.
+///
+/// @param[in] token is the currency code of the type argument, e.g. "LBR".
+/// @param[in] payee is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] amount
+/// @param[in] metadata_bytes is the content of `metadata`, or null if it is empty.
+/// @param[in] metadata_len is the length of metadata_bytes.
+/// @param[in] metadata_signature_bytes is the content of `metadata_signature`, or null if it is empty.
+/// @param[in] metadata_signature_len is the length of metadata_signature_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionPeerToPeerWithMetadataScript_from(
+    token: *const c_char,
+    payee: *const u8,
+    amount: u64,
+    metadata_bytes: *const u8,
+    metadata_len: usize,
+    metadata_signature_bytes: *const u8,
+    metadata_signature_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let token = match type_tag_from_currency_code(token, "token") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let payee = match account_address_from_bytes(payee, "payee") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let metadata = vec_from_bytes(metadata_bytes, metadata_len);
+    let metadata_signature = vec_from_bytes(metadata_signature_bytes, metadata_signature_len);
+
+    let script = encode_peer_to_peer_with_metadata_script(
+        token,
+        payee,
+        amount,
+        metadata,
+        metadata_signature,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Preburn `amount` `Token`s from `account`. This will only succeed if `account` already
+/// has a published `Preburn<Token>` resource.
+///
+/// @param[in] token is the currency code of the type argument, e.g. "LBR".
+/// @param[in] amount
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionPreburnScript_from(
+    token: *const c_char,
+    amount: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let token = match type_tag_from_currency_code(token, "token") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_preburn_script(token, amount);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// (1) Rotate the authentication key of the sender to `public_key` (2) Publish a resource
+/// containing a 32-byte ed25519 public key and the rotation capability of the sender
+/// under the sender's address. Aborts if the sender already has a
+/// `SharedEd25519PublicKey` resource. Aborts if the length of `new_public_key` is not 32.
+///
+/// @param[in] public_key_bytes is the content of `public_key`, or null if it is empty.
+/// @param[in] public_key_len is the length of public_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionPublishSharedEd25519PublicKeyScript_from(
+    public_key_bytes: *const u8,
+    public_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let public_key = vec_from_bytes(public_key_bytes, public_key_len);
+
+    let script = encode_publish_shared_ed25519_public_key_script(public_key);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Update configs of all the validators and emit reconfiguration event.
+///
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionReconfigureScript_from(
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_reconfigure_script();
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Adding `to_remove` to the set of pending validator removals. Fails if the `to_remove`
+/// address is already in the validator set or already in the pending removals. Callable
+/// by Validator's operator.
+///
+/// @param[in] validator_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRemoveValidatorScript_from(
+    validator_address: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let validator_address = match account_address_from_bytes(validator_address, "validator_address")
+    {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_remove_validator_script(validator_address);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+/// sha3 hash of an ed25519 public key.
+///
+/// @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+/// @param[in] new_key_len is the length of new_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateAuthenticationKeyScript_from(
+    new_key_bytes: *const u8,
+    new_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_key = vec_from_bytes(new_key_bytes, new_key_len);
+
+    let script = encode_rotate_authentication_key_script(new_key);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+/// sha3 hash of an ed25519 public key. This script also takes `sliding_nonce`, as a
+/// unique nonce for this operation. See sliding_nonce.move for details.
+///
+/// @param[in] sliding_nonce
+/// @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+/// @param[in] new_key_len is the length of new_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateAuthenticationKeyWithNonceScript_from(
+    sliding_nonce: u64,
+    new_key_bytes: *const u8,
+    new_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_key = vec_from_bytes(new_key_bytes, new_key_len);
+
+    let script = encode_rotate_authentication_key_with_nonce_script(sliding_nonce, new_key);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Rotate the authentication key of `to_recover` to `new_key`. Can be invoked by either
+/// `recovery_address` or `to_recover`. Aborts if `recovery_address` does not have the
+/// `KeyRotationCapability` for `to_recover`.
+///
+/// @param[in] recovery_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] to_recover is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+/// @param[in] new_key_len is the length of new_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateAuthenticationKeyWithRecoveryAddressScript_from(
+    recovery_address: *const u8,
+    to_recover: *const u8,
+    new_key_bytes: *const u8,
+    new_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let recovery_address = match account_address_from_bytes(recovery_address, "recovery_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let to_recover = match account_address_from_bytes(to_recover, "to_recover") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let new_key = vec_from_bytes(new_key_bytes, new_key_len);
+
+    let script = encode_rotate_authentication_key_with_recovery_address_script(
+        recovery_address,
+        to_recover,
+        new_key,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Rotate `vasp_root_addr`'s base URL to `new_url`.
+///
+/// @param[in] new_url_bytes is the content of `new_url`, or null if it is empty.
+/// @param[in] new_url_len is the length of new_url_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateBaseUrlScript_from(
+    new_url_bytes: *const u8,
+    new_url_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_url = vec_from_bytes(new_url_bytes, new_url_len);
+
+    let script = encode_rotate_base_url_script(new_url);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`.
+///
+/// @param[in] new_key_bytes is the content of `new_key`, or null if it is empty.
+/// @param[in] new_key_len is the length of new_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateCompliancePublicKeyScript_from(
+    new_key_bytes: *const u8,
+    new_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let new_key = vec_from_bytes(new_key_bytes, new_key_len);
+
+    let script = encode_rotate_compliance_public_key_script(new_key);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// (1) Rotate the public key stored in `account`'s `SharedEd25519PublicKey` resource to
+/// `new_public_key` (2) Rotate the authentication key using the capability stored in
+/// `account`'s `SharedEd25519PublicKey` to a new value derived from `new_public_key`
+/// Aborts if `account` does not have a `SharedEd25519PublicKey` resource. Aborts if the
+/// length of `new_public_key` is not 32.
+///
+/// @param[in] public_key_bytes is the content of `public_key`, or null if it is empty.
+/// @param[in] public_key_len is the length of public_key_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionRotateSharedEd25519PublicKeyScript_from(
+    public_key_bytes: *const u8,
+    public_key_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let public_key = vec_from_bytes(public_key_bytes, public_key_len);
+
+    let script = encode_rotate_shared_ed25519_public_key_script(public_key);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Sets the account limits window `tracking_balance` field for `CointType` at
+/// `window_address` to `aggregate_balance`
+///
+/// @param[in] coint_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] window_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] aggregate_balance
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionSetAccountLimitWindowCurrentHoldingsScript_from(
+    coint_type: *const c_char,
+    window_address: *const u8,
+    aggregate_balance: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coint_type = match type_tag_from_currency_code(coint_type, "coint_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let window_address = match account_address_from_bytes(window_address, "window_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_set_account_limit_window_current_holdings_script(
+        coint_type,
+        window_address,
+        aggregate_balance,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Set validator's config.
+///
+/// @param[in] validator_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] consensus_pubkey_bytes is the content of `consensus_pubkey`, or null if it is empty.
+/// @param[in] consensus_pubkey_len is the length of consensus_pubkey_bytes.
+/// @param[in] validator_network_identity_pubkey_bytes is the content of `validator_network_identity_pubkey`, or null if it is empty.
+/// @param[in] validator_network_identity_pubkey_len is the length of validator_network_identity_pubkey_bytes.
+/// @param[in] validator_network_address_bytes is the content of `validator_network_address`, or null if it is empty.
+/// @param[in] validator_network_address_len is the length of validator_network_address_bytes.
+/// @param[in] fullnodes_network_identity_pubkey_bytes is the content of `fullnodes_network_identity_pubkey`, or null if it is empty.
+/// @param[in] fullnodes_network_identity_pubkey_len is the length of fullnodes_network_identity_pubkey_bytes.
+/// @param[in] fullnodes_network_address_bytes is the content of `fullnodes_network_address`, or null if it is empty.
+/// @param[in] fullnodes_network_address_len is the length of fullnodes_network_address_bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionSetValidatorConfigScript_from(
+    validator_account: *const u8,
+    consensus_pubkey_bytes: *const u8,
+    consensus_pubkey_len: usize,
+    validator_network_identity_pubkey_bytes: *const u8,
+    validator_network_identity_pubkey_len: usize,
+    validator_network_address_bytes: *const u8,
+    validator_network_address_len: usize,
+    fullnodes_network_identity_pubkey_bytes: *const u8,
+    fullnodes_network_identity_pubkey_len: usize,
+    fullnodes_network_address_bytes: *const u8,
+    fullnodes_network_address_len: usize,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let validator_account = match account_address_from_bytes(validator_account, "validator_account")
+    {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let consensus_pubkey = vec_from_bytes(consensus_pubkey_bytes, consensus_pubkey_len);
+    let validator_network_identity_pubkey = vec_from_bytes(
+        validator_network_identity_pubkey_bytes,
+        validator_network_identity_pubkey_len,
+    );
+    let validator_network_address = vec_from_bytes(
+        validator_network_address_bytes,
+        validator_network_address_len,
+    );
+    let fullnodes_network_identity_pubkey = vec_from_bytes(
+        fullnodes_network_identity_pubkey_bytes,
+        fullnodes_network_identity_pubkey_len,
+    );
+    let fullnodes_network_address = vec_from_bytes(
+        fullnodes_network_address_bytes,
+        fullnodes_network_address_len,
+    );
+
+    let script = encode_set_validator_config_script(
+        validator_account,
+        consensus_pubkey,
+        validator_network_identity_pubkey,
+        validator_network_address,
+        fullnodes_network_identity_pubkey,
+        fullnodes_network_address,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Set validator's operator
+///
+/// @param[in] operator_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionSetValidatorOperatorScript_from(
+    operator_account: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let operator_account = match account_address_from_bytes(operator_account, "operator_account") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_set_validator_operator_script(operator_account);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Send `amount` coins of type `Token` to `payee`.
+///
+/// @param[in] token is the currency code of the type argument, e.g. "LBR".
+/// @param[in] payee is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] amount
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionTestnetMintScript_from(
+    token: *const c_char,
+    payee: *const u8,
+    amount: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let token = match type_tag_from_currency_code(token, "token") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let payee = match account_address_from_bytes(payee, "payee") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_testnet_mint_script(token, payee, amount);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Mint 'mint_amount' to 'designated_dealer_address' for 'tier_index' tier. Max valid
+/// tier index is 3 since there are max 4 tiers per DD. Sender should be treasury
+/// compliance account and receiver authorized DD. `sliding_nonce` is a unique nonce for
+/// operation, see sliding_nonce.move for details.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] sliding_nonce
+/// @param[in] designated_dealer_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] mint_amount
+/// @param[in] tier_index
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionTieredMintScript_from(
+    coin_type: *const c_char,
+    sliding_nonce: u64,
+    designated_dealer_address: *const u8,
+    mint_amount: u64,
+    tier_index: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let designated_dealer_address =
+        match account_address_from_bytes(designated_dealer_address, "designated_dealer_address") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+
+    let script = encode_tiered_mint_script(
+        coin_type,
+        sliding_nonce,
+        designated_dealer_address,
+        mint_amount,
+        tier_index,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Unfreeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+/// nonce for operation, see sliding_nonce.move for details.
+///
+/// @param[in] sliding_nonce
+/// @param[in] to_unfreeze_account is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUnfreezeAccountScript_from(
+    sliding_nonce: u64,
+    to_unfreeze_account: *const u8,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let to_unfreeze_account =
+        match account_address_from_bytes(to_unfreeze_account, "to_unfreeze_account") {
+            Ok(value) => value,
+            Err(status) => return status,
+        };
+
+    let script = encode_unfreeze_account_script(sliding_nonce, to_unfreeze_account);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Unmints `amount_lbr` LBR from the sending account into the constituent coins and
+/// deposits the resulting coins into the sending account."
+///
+/// @param[in] amount_lbr
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUnmintLbrScript_from(
+    amount_lbr: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_unmint_lbr_script(amount_lbr);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Optionally update thresholds of max balance, inflow, outflow for any limits-bound
+/// accounts with their limits defined at `limit_address`. Limits are defined in terms of
+/// base (on-chain) currency units for `CoinType`. If a new threshold is 0, that
+/// particular config does not get updated. `sliding_nonce` is a unique nonce for
+/// operation, see SlidingNonce.move for details.
+///
+/// @param[in] coin_type is the currency code of the type argument, e.g. "LBR".
+/// @param[in] limit_address is an account address of LIBRA_ADDRESS_SIZE bytes.
+/// @param[in] sliding_nonce
+/// @param[in] new_max_inflow
+/// @param[in] new_max_outflow
+/// @param[in] new_max_holding_balance
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUpdateAccountLimitDefinitionScript_from(
+    coin_type: *const c_char,
+    limit_address: *const u8,
+    sliding_nonce: u64,
+    new_max_inflow: u64,
+    new_max_outflow: u64,
+    new_max_holding_balance: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let coin_type = match type_tag_from_currency_code(coin_type, "coin_type") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let limit_address = match account_address_from_bytes(limit_address, "limit_address") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_update_account_limit_definition_script(
+        coin_type,
+        limit_address,
+        sliding_nonce,
+        new_max_inflow,
+        new_max_outflow,
+        new_max_holding_balance,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Update the on-chain exchange rate to LBR for the given `currency` to be given by
+/// `new_exchange_rate_numerator/new_exchange_rate_denominator`.
+///
+/// @param[in] currency is the currency code of the type argument, e.g. "LBR".
+/// @param[in] sliding_nonce
+/// @param[in] new_exchange_rate_numerator
+/// @param[in] new_exchange_rate_denominator
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUpdateExchangeRateScript_from(
+    currency: *const c_char,
+    sliding_nonce: u64,
+    new_exchange_rate_numerator: u64,
+    new_exchange_rate_denominator: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let currency = match type_tag_from_currency_code(currency, "currency") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_update_exchange_rate_script(
+        currency,
+        sliding_nonce,
+        new_exchange_rate_numerator,
+        new_exchange_rate_denominator,
+    );
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Update Libra version.
+///
+/// @param[in] major
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUpdateLibraVersionScript_from(
+    major: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_update_libra_version_script(major);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Allows--true--or disallows--false--minting of `currency` based upon `allow_minting`.
+///
+/// @param[in] currency is the currency code of the type argument, e.g. "LBR".
+/// @param[in] allow_minting
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUpdateMintingAbilityScript_from(
+    currency: *const c_char,
+    allow_minting: bool,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+    let currency = match type_tag_from_currency_code(currency, "currency") {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+
+    let script = encode_update_minting_ability_script(currency, allow_minting);
+    write_script(&script, ptr_buf, ptr_len)
+}
+
+/// Update the travel rule limit to `new_micro_lbr_limit`.
+///
+/// @param[in] sliding_nonce
+/// @param[in] new_micro_lbr_limit
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_TransactionUpdateTravelRuleLimitScript_from(
+    sliding_nonce: u64,
+    new_micro_lbr_limit: u64,
+    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {
+    clear_error();
+
+    let script = encode_update_travel_rule_limit_script(sliding_nonce, new_micro_lbr_limit);
+    write_script(&script, ptr_buf, ptr_len)
+}
@@ -11,7 +11,7 @@ use crate::{
 };
 use lcs::{from_bytes, to_bytes};
 use libra_crypto::{
-    ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature, ED25519_PUBLIC_KEY_LENGTH},
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
     test_utils::KeyPair,
     PrivateKey,
 };
@@ -25,20 +25,33 @@ use libra_types::{
         SignedTransaction, TransactionArgument, TransactionPayload,
     },
 };
-use std::{convert::TryFrom, ffi::CStr, slice, time::Duration};
+use std::{convert::TryFrom, ffi::CStr, os::raw::c_char, slice, time::Duration};
 use transaction_builder::{
     encode_add_currency_to_account_script, encode_rotate_base_url_script,
-    encode_rotate_compliance_public_key_script, encode_transfer_with_metadata_script,
-    get_transaction_name,
+    encode_transfer_with_metadata_script, get_transaction_name,
 };
 
+/// Get serialized signed transaction from a list of transaction parameters
+///
+/// To get the serialized transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] sender_private_key is sender's private key
+/// @param[in] sequence is the sequence number of this transaction corresponding to sender's account.
+/// @param[in] max_gas_amount is the maximal total gas specified by wallet to spend for this transaction.
+/// @param[in] gas_unit_price is the maximal price can be paid per gas.
+/// @param[in] gas_identifier is the identifier of the coin to be used as gas.
+/// @param[in] expiration_time_secs is the time this TX remain valid, the format is unix timestamp.
+/// @param[in] script_bytes is the script bytes for given transaction.
+/// @param[in] script_len is the length of script_bytes array.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the signed transaction memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_SignedTransactionBytes_from(
     sender_private_key_bytes: *const u8,
     sequence: u64,
     max_gas_amount: u64,
     gas_unit_price: u64,
-    gas_identifier: *const i8,
+    gas_identifier: *const c_char,
     expiration_time_secs: u64,
     script_bytes: *const u8,
     script_len: usize,
@@ -121,10 +134,23 @@ pub unsafe extern "C" fn libra_SignedTransactionBytes_from(
     LibraStatus::Ok
 }
 
+/// Get script bytes for a P2P transaction
+///
+/// To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] receiver is the receiver's address.
+/// @param[in] identifier is the identifier of the coin to be sent.
+/// @param[in] num_coins is the amount of money to be sent.
+/// @param[in] metadata_bytes is the metadata bytes for given transaction.
+/// @param[in] metadata_len is the length of metadata_bytes array.
+/// @param[in] metadata_signature_bytes is the metadata signature bytes for given transaction.
+/// @param[in] metadata_signature_len is the length of metadata_signature_bytes array.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_TransactionP2PScript_from(
     receiver: *const u8,
-    identifier: *const i8,
+    identifier: *const c_char,
     num_coins: u64,
     metadata_bytes: *const u8,
     metadata_len: usize,
@@ -200,9 +226,16 @@ pub unsafe extern "C" fn libra_TransactionP2PScript_from(
     LibraStatus::Ok
 }
 
+/// Get script bytes for add currency to account transaction
+///
+/// To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] identifier is the identifier of the coin to be sent.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_TransactionAddCurrencyScript_from(
-    identifier: *const i8,
+    identifier: *const c_char,
     ptr_buf: *mut *mut u8,
     ptr_len: *mut usize,
 ) -> LibraStatus {
@@ -243,53 +276,15 @@ pub unsafe extern "C" fn libra_TransactionAddCurrencyScript_from(
     LibraStatus::Ok
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn libra_TransactionRotateCompliancePublicKeyScript_from(
-    new_key_bytes: *const u8,
-    ptr_buf: *mut *mut u8,
-    ptr_len: *mut usize,
-) -> LibraStatus {
-    clear_error();
-
-    if new_key_bytes.is_null() {
-        update_last_error("new_key_bytes parameter must not be null.".to_string());
-        return LibraStatus::InvalidArgument;
-    }
-    let new_key_buf: &[u8] = slice::from_raw_parts(new_key_bytes, ED25519_PUBLIC_KEY_LENGTH);
-    let new_compliance_public_key = match Ed25519PublicKey::try_from(new_key_buf) {
-        Ok(result) => result,
-        Err(e) => {
-            update_last_error(format!(
-                "Invalid compliance public key bytes: {}",
-                e.to_string()
-            ));
-            return LibraStatus::InvalidArgument;
-        }
-    };
-
-    let script =
-        encode_rotate_compliance_public_key_script(new_compliance_public_key.to_bytes().to_vec());
-
-    let script_bytes = match to_bytes(&script) {
-        Ok(result) => result,
-        Err(e) => {
-            update_last_error(format!(
-                "Error serializing rotate compliance public key Script: {}",
-                e.to_string()
-            ));
-            return LibraStatus::InternalError;
-        }
-    };
-
-    let script_buf: *mut u8 = libc::malloc(script_bytes.len()).cast();
-    script_buf.copy_from(script_bytes.as_ptr(), script_bytes.len());
-
-    *ptr_buf = script_buf;
-    *ptr_len = script_bytes.len();
-
-    LibraStatus::Ok
-}
-
+/// Get script bytes for rotating base url of VASP
+/// Encode a program that rotates `vasp_root_addr`'s base URL to `new_url`.
+///
+/// To get the serialized script in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] new_url_bytes is the bytes of new base URL for the VASP.
+/// @param[in] new_url_len is the length of new_key_bytes array.
+/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_TransactionRotateBaseURLScript_from(
     new_url_bytes: *const u8,
@@ -327,12 +322,31 @@ pub unsafe extern "C" fn libra_TransactionRotateBaseURLScript_from(
     LibraStatus::Ok
 }
 
+/// Function to free the allocation memory in rust for bytes
+/// @param buf is the pointer to the bytes allocated in rust, and needs to be freed from client side
 #[no_mangle]
 pub unsafe extern "C" fn libra_free_bytes_buffer(buf: *const u8) {
     assert!(!buf.is_null());
     libc::free(buf as *mut libc::c_void);
 }
 
+/// Get serialized raw transaction from a list of transaction parameters
+///
+/// To get the serialized raw transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] sender is the sender's address
+/// @param[in] receiver is the receiver's address
+/// @param[in] sequence is the sequence number of this transaction corresponding to sender's account.
+/// @param[in] num_coins is the amount of money to be sent.
+/// @param[in] max_gas_amount is the maximal total gas specified by wallet to spend for this transaction.
+/// @param[in] gas_unit_price is the maximal price can be paid per gas.
+/// @param[in] expiration_time_secs is the time this TX remain valid, the format is unix timestamp.
+/// @param[in] metadata_signature_bytes is the metadata signature bytes for given transaction.
+/// @param[in] metadata_signature_len is the length of metadata_signature_bytes array.
+/// @param[in] metadata_bytes is the metadata bytes for given transaction.
+/// @param[in] metadata_len is the length of metadata_bytes array.
+/// @param[out] buf is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] len is the length of the raw transaction memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_RawTransactionBytes_from(
     sender: *const u8,
@@ -427,6 +441,17 @@ pub unsafe extern "C" fn libra_RawTransactionBytes_from(
     LibraStatus::Ok
 }
 
+/// This function takes in a raw transaction, public key and signature in bytes, and return a signed transaction in bytes.
+/// To get the serialized signed transaction in a memory safe manner, the client needs to pass in a pointer to a pointer to the allocated memory in rust
+/// and call free on the memory address with `libra_free_bytes_buffer`.
+/// @param[in] buf_raw_txn is raw transaction in bytes
+/// @param[in] len_raw_txn is length of raw transaction bytes
+/// @param[in] buf_public_key is public key in bytes
+/// @param[in] len_public_key is length of public key bytes
+/// @param[in] buf_signature is signature in bytes
+/// @param[in] len_signature is length of signature bytes
+/// @param[out] buf_result is the pointer that will be filled with the memory address of the transaction allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] len_result is the length of the signed transaction memory buffer.
 #[no_mangle]
 pub unsafe extern "C" fn libra_RawTransaction_sign(
     buf_raw_txn: *const u8,
@@ -502,6 +527,13 @@ pub unsafe extern "C" fn libra_RawTransaction_sign(
     LibraStatus::Ok
 }
 
+/// Decode LibraSignedTransaction from bytes in SignedTransaction.
+///
+/// @param[in] buf contains encoded bytes of txn_bytes
+/// @param[in] len is the length of the signed transaction memory buffer.
+/// @param[out] out is a caller allocated LibraSignedTransaction to write into.
+///
+/// @returns status code, one of LibraStatus
 #[no_mangle]
 pub unsafe extern "C" fn libra_LibraSignedTransaction_from(
     buf: *const u8,
@@ -534,8 +566,8 @@ pub unsafe extern "C" fn libra_LibraSignedTransaction_from(
     // authenticators
     let public_key_bytes = signed_txn.authenticator().public_key_bytes();
     let signature_bytes = signed_txn.authenticator().signature_bytes();
-    let mut public_key = [0; LIBRA_PUBKEY_SIZE as usize];
-    let mut signature = [0; LIBRA_SIGNATURE_SIZE as usize];
+    let mut public_key = [0; LIBRA_PUBKEY_SIZE];
+    let mut signature = [0; LIBRA_SIGNATURE_SIZE];
 
     let public_key_bytes = &public_key_bytes[..public_key.len()];
     public_key.copy_from_slice(public_key_bytes);
@@ -645,8 +677,9 @@ pub unsafe extern "C" fn libra_LibraSignedTransaction_from(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::scripts::libra_TransactionRotateCompliancePublicKeyScript_from;
     use lcs::from_bytes;
-    use libra_crypto::{PrivateKey, SigningKey, Uniform};
+    use libra_crypto::{ed25519::ED25519_PUBLIC_KEY_LENGTH, PrivateKey, SigningKey, Uniform};
     use libra_types::{
         account_config::COIN1_NAME,
         transaction::{SignedTransaction, TransactionArgument},
@@ -965,6 +998,7 @@ mod test {
         let script_result = unsafe {
             libra_TransactionRotateCompliancePublicKeyScript_from(
                 new_compliance_public_key.to_bytes().as_ptr(),
+                ED25519_PUBLIC_KEY_LENGTH,
                 script_buf_ptr,
                 &mut script_len,
             )
@@ -0,0 +1,43 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{env, fs, path::Path};
+
+const HEADER_PATH: &str = "include/data.h";
+
+const UPDATE_BASELINE: &str = "UPDATE_BASELINE";
+const UB: &str = "UB";
+
+fn read_bool_var(name: &str) -> bool {
+    env::var(name).map_or(false, |value| value == "1" || value == "true")
+}
+
+// The C header is generated from the Rust definitions of the types in `src/data.rs` and of the
+// `extern "C"` functions of this crate, including the generated script builders in
+// `src/scripts.rs`. Run this test with `UB=1` to regenerate it.
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let crate_dir = Path::new(&crate_dir);
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("Unable to read cbindgen.toml.");
+    let mut header = vec![];
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("Unable to generate the C header.")
+        .write(&mut header);
+
+    let header_path = crate_dir.join(HEADER_PATH);
+    if read_bool_var(UPDATE_BASELINE) || read_bool_var(UB) {
+        fs::write(&header_path, header).unwrap();
+    } else {
+        let expected = fs::read(&header_path).unwrap();
+        assert!(
+            header == expected,
+            "{} is out of date, run `UB=1 cargo test -p libra-dev --test header` to regenerate it",
+            HEADER_PATH
+        );
+    }
+}
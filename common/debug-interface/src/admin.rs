@@ -0,0 +1,128 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Registry of admin commands served by the NodeDebugService under `/admin/<command>`.
+//!
+//! Components register a handler for each command they support, for example, mempool registers
+//! `mempool` to dump its contents. Handlers receive the query parameters of the request and
+//! return a json value or an error. They are executed on a blocking thread, so they may acquire
+//! locks or wait on other components.
+
+use anyhow::{format_err, Result};
+use libra_logger::LevelFilter;
+use once_cell::sync::Lazy;
+use serde_json::{json, value as json};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+pub type AdminParams = HashMap<String, String>;
+pub type AdminHandler = Arc<dyn Fn(&AdminParams) -> Result<json::Value> + Send + Sync>;
+
+pub const LOG_LEVEL: &str = "log_level";
+
+static ADMIN_HANDLERS: Lazy<RwLock<HashMap<String, AdminHandler>>> = Lazy::new(|| {
+    let mut handlers: HashMap<String, AdminHandler> = HashMap::new();
+    handlers.insert(LOG_LEVEL.into(), Arc::new(log_level));
+    RwLock::new(handlers)
+});
+
+/// Registers the handler for the given command, replacing any prior handler. Components that are
+/// recreated, such as consensus across epochs, can simply register again.
+pub fn register_handler<F>(command: &str, handler: F)
+where
+    F: Fn(&AdminParams) -> Result<json::Value> + Send + Sync + 'static,
+{
+    ADMIN_HANDLERS
+        .write()
+        .unwrap()
+        .insert(command.into(), Arc::new(handler));
+}
+
+pub fn unregister_handler(command: &str) {
+    ADMIN_HANDLERS.write().unwrap().remove(command);
+}
+
+/// Returns the names of all registered commands.
+pub fn commands() -> Vec<String> {
+    let mut commands: Vec<_> = ADMIN_HANDLERS.read().unwrap().keys().cloned().collect();
+    commands.sort();
+    commands
+}
+
+/// Executes the given command, returns None if no handler is registered for it.
+pub fn handle(command: &str, params: &AdminParams) -> Option<Result<json::Value>> {
+    // Release the lock before executing, handlers may take a while or register other handlers.
+    let handler = ADMIN_HANDLERS.read().unwrap().get(command).cloned()?;
+    Some(handler(params))
+}
+
+/// Sets the log level of a module when called with `module` and `level`, clears the override
+/// when called with only `module`, and in all cases returns the active overrides.
+fn log_level(params: &AdminParams) -> Result<json::Value> {
+    match (params.get("module"), params.get("level")) {
+        (Some(module), Some(level)) => {
+            let level = LevelFilter::from_str(level)
+                .map_err(|_| format_err!("Invalid log level: {}", level))?;
+            libra_logger::set_module_level(module, level);
+        }
+        (Some(module), None) => libra_logger::clear_module_level(module),
+        (None, Some(_)) => return Err(format_err!("Missing module")),
+        (None, None) => (),
+    }
+
+    let levels: HashMap<_, _> = libra_logger::module_levels()
+        .into_iter()
+        .map(|(module, level)| (module, level.to_string()))
+        .collect();
+    Ok(json!(levels))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_register_and_handle() {
+        assert!(handle("test_command", &AdminParams::new()).is_none());
+
+        register_handler("test_command", |params| {
+            params
+                .get("value")
+                .map(|value| json!(value))
+                .ok_or_else(|| format_err!("Missing value"))
+        });
+        assert!(commands().contains(&"test_command".to_string()));
+
+        let mut params = AdminParams::new();
+        assert!(handle("test_command", &params).unwrap().is_err());
+        params.insert("value".into(), "5".into());
+        assert_eq!(
+            handle("test_command", &params).unwrap().unwrap(),
+            json!("5")
+        );
+
+        unregister_handler("test_command");
+        assert!(handle("test_command", &params).is_none());
+    }
+
+    #[test]
+    fn test_log_level() {
+        let mut params = AdminParams::new();
+        params.insert("level".into(), "debug".into());
+        assert!(handle(LOG_LEVEL, &params).unwrap().is_err());
+
+        params.insert("module".into(), "test_module".into());
+        let levels = handle(LOG_LEVEL, &params).unwrap().unwrap();
+        assert_eq!(levels["test_module"], json!("DEBUG"));
+
+        params.insert("level".into(), "verbose".into());
+        assert!(handle(LOG_LEVEL, &params).unwrap().is_err());
+
+        params.remove("level");
+        let levels = handle(LOG_LEVEL, &params).unwrap().unwrap();
+        assert!(levels.get("test_module").is_none());
+    }
+}
@@ -6,14 +6,17 @@ use anyhow::Result;
 use reqwest::blocking;
 use std::collections::HashMap;
 
+pub mod admin;
 pub mod json_log;
 pub mod libra_trace;
 pub mod node_debug_service;
 pub mod trace;
+pub mod trace_export;
 
 pub mod prelude {
     pub use crate::{
-        end_trace, event, node_sampling_data, send_logs, trace_code_block, trace_edge, trace_event,
+        end_trace, event, node_sampling_data, send_logs, start_trace, trace_code_block, trace_edge,
+        trace_event,
     };
 }
 
@@ -61,6 +64,33 @@ impl NodeDebugClient {
 
         Ok(response.json()?)
     }
+
+    /// Executes an admin command, see `admin` for the available commands and their parameters.
+    pub fn admin(
+        &mut self,
+        admin_token: &str,
+        command: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value> {
+        let response = self
+            .client
+            .post(&format!("{}/admin/{}", self.addr, command))
+            .bearer_auth(admin_token)
+            .query(params)
+            .send()?;
+
+        let status = response.status();
+        let value = response.json()?;
+        if status.is_success() {
+            Ok(value)
+        } else {
+            Err(anyhow::format_err!(
+                "Admin command failed {}: {}",
+                status,
+                value
+            ))
+        }
+    }
 }
 
 /// Implement default utility client for AsyncNodeDebugInterface
@@ -3,7 +3,7 @@
 
 use crate::json_log::JsonLogEntry;
 use anyhow::{bail, ensure, Result};
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 pub const TRACE_EVENT: &str = "trace_event";
 pub const TRACE_EDGE: &str = "trace_edge";
@@ -34,7 +34,9 @@ struct CategorySampling {
 macro_rules! trace_event {
     ($stage:expr, $node:tt) => {
         if $crate::is_selected($crate::node_sampling_data!($node)) {
-            trace_event!($stage; {$crate::format_node!($node), module_path!(), Option::<u64>::None});
+            let node = $crate::format_node!($node);
+            $crate::trace_export::record_event(&node, $stage, module_path!());
+            trace_event!($stage; {node, module_path!(), Option::<u64>::None});
         }
     };
     ($stage:expr; {$node:expr, $path:expr, $duration:expr}) => {
@@ -48,6 +50,18 @@ macro_rules! trace_event {
     }
 }
 
+/// Starts the trace of a node, see `trace_export` for how trace contexts propagate across peers.
+#[macro_export]
+macro_rules! start_trace {
+    ($node:tt) => {
+        if $crate::trace_export::is_enabled()
+            && $crate::is_selected($crate::node_sampling_data!($node))
+        {
+            $crate::trace_export::start_trace(&$crate::format_node!($node));
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! node_sampling_data {
     ({$type:expr, $($rest:tt)*}) => {{
@@ -111,6 +125,7 @@ pub struct TraceBlockGuard {
     node: String,
     module_path: &'static str,
     started: Instant,
+    start_time: SystemTime,
 }
 
 impl TraceBlockGuard {
@@ -125,6 +140,7 @@ impl TraceBlockGuard {
             node,
             module_path,
             started,
+            start_time: SystemTime::now(),
         }
     }
 }
@@ -132,6 +148,12 @@ impl TraceBlockGuard {
 impl Drop for TraceBlockGuard {
     fn drop(&mut self) {
         let duration = format!("{:.0?}", Instant::now().duration_since(self.started));
+        crate::trace_export::record_span(
+            &self.node,
+            self.stage.trim_end_matches("::done"),
+            self.module_path,
+            self.start_time,
+        );
         trace_event!(self.stage; {self.node, self.module_path, duration});
     }
 }
@@ -140,9 +162,11 @@ impl Drop for TraceBlockGuard {
 macro_rules! end_trace {
     ($stage:expr, $node:tt) => {
         if $crate::is_selected($crate::node_sampling_data!($node)) {
+            let node = $crate::format_node!($node);
+            $crate::trace_export::record_event(&node, $stage, module_path!());
             let json = serde_json::json!({
                     "path": module_path!(),
-                    "node": $crate::format_node!($node),
+                    "node": node,
                     "stage": $stage,
                     "end": true,
                 });
@@ -155,10 +179,13 @@ macro_rules! end_trace {
 macro_rules! trace_edge {
     ($stage:expr, $node_from:tt, $node_to:tt) => {
         if $crate::is_selected($crate::node_sampling_data!($node_from)) {
+            let node_from = $crate::format_node!($node_from);
+            let node_to = $crate::format_node!($node_to);
+            $crate::trace_export::record_edge(&node_from, &node_to, $stage, module_path!());
             let json = serde_json::json!({
                     "path": module_path!(),
-                    "node": $crate::format_node!($node_from),
-                    "node_to": $crate::format_node!($node_to),
+                    "node": node_from,
+                    "node_to": node_to,
                     "stage": $stage,
                 });
             $crate::send_logs!($crate::libra_trace::TRACE_EDGE, json);
@@ -3,10 +3,13 @@
 
 //! Debug interface to access information in a specific node.
 
-use crate::json_log;
-use std::net::SocketAddr;
+use crate::{admin, json_log};
+use serde_json::json;
+use std::{collections::HashMap, convert::Infallible, net::SocketAddr};
 use tokio::runtime::{Builder, Runtime};
-use warp::Filter;
+use warp::{http::StatusCode, Filter};
+
+const BEARER: &str = "Bearer ";
 
 #[derive(Debug)]
 pub struct NodeDebugService {
@@ -14,7 +17,9 @@ pub struct NodeDebugService {
 }
 
 impl NodeDebugService {
-    pub fn new(address: SocketAddr) -> Self {
+    /// Admin endpoints are only served if an admin_token is provided, in which case every admin
+    /// request must carry an `Authorization: Bearer <admin_token>` header.
+    pub fn new(address: SocketAddr, admin_token: Option<String>) -> Self {
         let runtime = Builder::new()
             .thread_name("nodedebug-")
             .threaded_scheduler()
@@ -29,7 +34,18 @@ impl NodeDebugService {
         // GET /evnets
         let events = warp::path("events").map(|| warp::reply::json(&json_log::pop_last_entries()));
 
-        let routes = warp::get().and(metrics.or(events));
+        // POST /admin/<command>?<params>
+        // Commands may change the state of the node, so they are never served over GET, which
+        // browsers issue on their own for links and prefetches.
+        let admin = warp::post()
+            .and(warp::path!("admin" / String))
+            .and(warp::header::optional::<String>("authorization"))
+            .and(warp::query::<HashMap<String, String>>())
+            .and_then(move |command, authorization, params| {
+                handle_admin(admin_token.clone(), command, authorization, params)
+            });
+
+        let routes = warp::get().and(metrics.or(events)).or(admin);
 
         let server = runtime.enter(move || warp::serve(routes).bind(address));
         runtime.handle().spawn(server);
@@ -37,3 +53,47 @@ impl NodeDebugService {
         Self { runtime }
     }
 }
+
+async fn handle_admin(
+    admin_token: Option<String>,
+    command: String,
+    authorization: Option<String>,
+    params: HashMap<String, String>,
+) -> Result<impl warp::Reply, Infallible> {
+    let reply = |status, value| Ok(warp::reply::with_status(warp::reply::json(&value), status));
+
+    let admin_token = match admin_token {
+        Some(admin_token) => admin_token,
+        None => return reply(StatusCode::NOT_FOUND, json!("Admin endpoints are disabled")),
+    };
+    let authorized = authorization
+        .as_ref()
+        .filter(|authorization| authorization.starts_with(BEARER))
+        .map_or(false, |authorization| {
+            constant_time_eq(&authorization[BEARER.len()..], &admin_token)
+        });
+    if !authorized {
+        return reply(StatusCode::UNAUTHORIZED, json!("Invalid admin token"));
+    }
+
+    let result = tokio::task::spawn_blocking(move || admin::handle(&command, &params)).await;
+    match result {
+        Ok(Some(Ok(value))) => reply(StatusCode::OK, value),
+        Ok(Some(Err(e))) => reply(StatusCode::BAD_REQUEST, json!(e.to_string())),
+        Ok(None) => reply(
+            StatusCode::NOT_FOUND,
+            json!({ "error": "Unknown command", "commands": admin::commands() }),
+        ),
+        Err(e) => reply(StatusCode::INTERNAL_SERVER_ERROR, json!(e.to_string())),
+    }
+}
+
+/// Compares tokens without leaking the position of the first mismatch through timing.
+fn constant_time_eq(lhs: &str, rhs: &str) -> bool {
+    lhs.len() == rhs.len()
+        && lhs
+            .bytes()
+            .zip(rhs.bytes())
+            .fold(0, |acc, (l, r)| acc | (l ^ r))
+            == 0
+}
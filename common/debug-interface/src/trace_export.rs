@@ -0,0 +1,377 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exports libra_trace events as OpenTelemetry spans via OTLP over HTTP with JSON encoding, which
+//! is accepted by the OpenTelemetry collector as well as by Jaeger.
+//!
+//! Every trace node, e.g., `txn::<sender>::<sequence_number>` or `block::<id>`, is mapped to a
+//! TraceContext derived from the node itself. Events, code blocks and edges recorded for a node
+//! become spans whose parent is the span of that context. Since every peer derives the same
+//! context, a transaction can be followed across nodes without sending any context along: mempool
+//! broadcasts carry the sender and sequence number of the transactions and consensus proposals
+//! carry the block id, from which the receiving peers derive the context. Transactions are linked
+//! to the blocks they are pulled into via `trace_edge`.
+//!
+//! Only the peer that starts a trace, e.g., the node a transaction is submitted to or the proposer
+//! of a block, exports its root span.
+
+use anyhow::{ensure, Result};
+use libra_crypto::HashValue;
+use libra_logger::prelude::*;
+use once_cell::sync::OnceCell;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, value as json};
+use std::{
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, SyncSender},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Maximum number of spans buffered before new spans are dropped.
+const MAX_PENDING_SPANS: usize = 10_000;
+const MAX_BATCH_SIZE: usize = 512;
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+/// OTLP SpanKind for spans that represent internal operations.
+const SPAN_KIND_INTERNAL: u8 = 1;
+
+/// Identifies the trace and the parent span for all spans recorded for a trace node.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    pub span_id: [u8; 8],
+}
+
+impl TraceContext {
+    /// Derives the context from the node, such that all peers derive the same context.
+    fn derived(node: &str) -> Self {
+        let hash = HashValue::sha3_256_of(node.as_bytes());
+        let mut context = Self {
+            trace_id: [0; 16],
+            span_id: [0; 8],
+        };
+        context.trace_id.copy_from_slice(&hash.as_ref()[..16]);
+        context.span_id.copy_from_slice(&hash.as_ref()[16..24]);
+        context
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub trace_id: [u8; 16],
+    pub span_id: [u8; 8],
+    pub parent_span_id: Option<[u8; 8]>,
+    pub name: String,
+    pub start_time: SystemTime,
+    pub end_time: SystemTime,
+    pub attributes: Vec<(&'static str, String)>,
+    /// Contexts of other traces this span relates to, e.g., the block a transaction was pulled
+    /// into.
+    pub links: Vec<TraceContext>,
+}
+
+impl Span {
+    fn new(
+        node: &str,
+        name: &str,
+        path: &str,
+        start_time: SystemTime,
+        end_time: SystemTime,
+    ) -> Self {
+        let context = TraceContext::derived(node);
+        let mut span_id = [0; 8];
+        OsRng.fill_bytes(&mut span_id);
+        Self {
+            trace_id: context.trace_id,
+            span_id,
+            parent_span_id: Some(context.span_id),
+            name: name.to_string(),
+            start_time,
+            end_time,
+            attributes: vec![
+                ("libra.node", node.to_string()),
+                ("code.namespace", path.to_string()),
+            ],
+            links: vec![],
+        }
+    }
+}
+
+static SPAN_SENDER: OnceCell<Mutex<SyncSender<Span>>> = OnceCell::new();
+
+/// Whether spans are exported, which is cheap enough to check before doing any tracing work.
+pub fn is_enabled() -> bool {
+    SPAN_SENDER.get().is_some()
+}
+
+/// Starts the trace rooted at the node by exporting its root span.
+pub fn start_trace(node: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let context = TraceContext::derived(node);
+    let now = SystemTime::now();
+    export(Span {
+        trace_id: context.trace_id,
+        span_id: context.span_id,
+        parent_span_id: None,
+        name: node.to_string(),
+        start_time: now,
+        end_time: now,
+        attributes: vec![("libra.node", node.to_string())],
+        links: vec![],
+    });
+}
+
+/// Records a point in time event for the node.
+pub fn record_event(node: &str, stage: &str, path: &str) {
+    if is_enabled() {
+        let now = SystemTime::now();
+        export(Span::new(node, stage, path, now, now));
+    }
+}
+
+/// Records the execution of a code block for the node.
+pub fn record_span(node: &str, stage: &str, path: &str, start_time: SystemTime) {
+    if is_enabled() {
+        export(Span::new(node, stage, path, start_time, SystemTime::now()));
+    }
+}
+
+/// Records an edge between two nodes as a span of the source node linked to the target node.
+pub fn record_edge(node_from: &str, node_to: &str, stage: &str, path: &str) {
+    if is_enabled() {
+        let now = SystemTime::now();
+        let mut span = Span::new(node_from, stage, path, now, now);
+        span.attributes.push(("libra.node_to", node_to.to_string()));
+        span.links.push(TraceContext::derived(node_to));
+        export(span);
+    }
+}
+
+fn export(span: Span) {
+    if let Some(sender) = SPAN_SENDER.get() {
+        // Tracing must never block the traced code, so spans are dropped when the exporter falls
+        // behind.
+        let _ = sender.lock().unwrap().try_send(span);
+    }
+}
+
+/// Starts exporting spans in batches to the OTLP endpoint, e.g.,
+/// `http://localhost:55681/v1/traces`. Can only be called once per process.
+pub fn init_exporter(endpoint: String, service_name: &str, instance_id: &str) -> Result<()> {
+    let exporter = OtlpExporter::new(endpoint, service_name, instance_id);
+    let (sender, receiver) = mpsc::sync_channel(MAX_PENDING_SPANS);
+    ensure!(
+        SPAN_SENDER.set(Mutex::new(sender)).is_ok(),
+        "Trace exporter already initialized"
+    );
+    thread::Builder::new()
+        .name("libra-trace-export".into())
+        .spawn(move || export_loop(exporter, receiver))?;
+    Ok(())
+}
+
+fn export_loop(exporter: OtlpExporter, receiver: Receiver<Span>) {
+    let mut batch = Vec::with_capacity(MAX_BATCH_SIZE);
+    let mut last_flush = Instant::now();
+    loop {
+        match receiver.recv_timeout(FLUSH_INTERVAL) {
+            Ok(span) => batch.push(span),
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+
+        if batch.len() >= MAX_BATCH_SIZE || last_flush.elapsed() >= FLUSH_INTERVAL {
+            if !batch.is_empty() {
+                if let Err(e) = exporter.export(&batch) {
+                    warn!("Failed to export {} trace spans: {}", batch.len(), e);
+                }
+                batch.clear();
+            }
+            last_flush = Instant::now();
+        }
+    }
+}
+
+/// Sends spans to an OTLP/HTTP endpoint using the JSON encoding.
+pub struct OtlpExporter {
+    client: reqwest::blocking::Client,
+    endpoint: String,
+    resource: json::Value,
+}
+
+impl OtlpExporter {
+    pub fn new(endpoint: String, service_name: &str, instance_id: &str) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            endpoint,
+            resource: json!({
+                "attributes": [
+                    string_attribute("service.name", service_name),
+                    string_attribute("service.instance.id", instance_id),
+                ]
+            }),
+        }
+    }
+
+    pub fn export(&self, spans: &[Span]) -> Result<()> {
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .json(&self.encode(spans))
+            .send()?;
+        ensure!(
+            response.status().is_success(),
+            "Unexpected response status: {}",
+            response.status()
+        );
+        Ok(())
+    }
+
+    /// Encodes the spans as an ExportTraceServiceRequest.
+    pub fn encode(&self, spans: &[Span]) -> json::Value {
+        let spans: Vec<_> = spans.iter().map(encode_span).collect();
+        json!({
+            "resourceSpans": [{
+                "resource": self.resource,
+                "instrumentationLibrarySpans": [{
+                    "instrumentationLibrary": { "name": crate::libra_trace::LIBRA_TRACE },
+                    "spans": spans,
+                }],
+            }],
+        })
+    }
+}
+
+fn encode_span(span: &Span) -> json::Value {
+    let attributes: Vec<_> = span
+        .attributes
+        .iter()
+        .map(|(key, value)| string_attribute(key, value))
+        .collect();
+    let links: Vec<_> = span
+        .links
+        .iter()
+        .map(|link| {
+            json!({
+                "traceId": hex::encode(link.trace_id),
+                "spanId": hex::encode(link.span_id),
+            })
+        })
+        .collect();
+    let mut encoded = json!({
+        "traceId": hex::encode(span.trace_id),
+        "spanId": hex::encode(span.span_id),
+        "name": span.name,
+        "kind": SPAN_KIND_INTERNAL,
+        // 64 bit integers are encoded as strings
+        "startTimeUnixNano": unix_nanos(span.start_time).to_string(),
+        "endTimeUnixNano": unix_nanos(span.end_time).to_string(),
+        "attributes": attributes,
+        "links": links,
+    });
+    if let Some(parent_span_id) = span.parent_span_id {
+        encoded["parentSpanId"] = json!(hex::encode(parent_span_id));
+    }
+    encoded
+}
+
+fn string_attribute(key: &str, value: &str) -> json::Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .expect("now > UNIX_EPOCH")
+        .as_nanos()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+    };
+
+    /// Accepts a single HTTP request, acknowledges it and returns its body.
+    fn collector_stand_in(listener: TcpListener) -> thread::JoinHandle<json::Value> {
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+                if line.starts_with("content-length:") {
+                    content_length = line["content-length:".len()..].trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .unwrap();
+            serde_json::from_slice(&body).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_contexts() {
+        let node = "txn::test_contexts::0";
+        assert_eq!(TraceContext::derived(node), TraceContext::derived(node));
+        assert_ne!(
+            TraceContext::derived(node),
+            TraceContext::derived("txn::test_contexts::1")
+        );
+
+        // Spans recorded on any peer join the trace of the node
+        let now = SystemTime::now();
+        let span = Span::new(node, "stage", "path", now, now);
+        let root = TraceContext::derived(node);
+        assert_eq!(span.trace_id, root.trace_id);
+        assert_eq!(span.parent_span_id, Some(root.span_id));
+    }
+
+    #[test]
+    fn test_export() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+        let collector = collector_stand_in(listener);
+
+        let node = "block::test_export";
+        let context = TraceContext::derived(node);
+        let start_time = UNIX_EPOCH + Duration::from_secs(1);
+        let mut span = Span::new(node, "execute_block", "executor", start_time, start_time);
+        span.end_time = start_time + Duration::from_millis(5);
+
+        let exporter = OtlpExporter::new(endpoint, "libra-node", "validator-0");
+        exporter.export(&[span.clone()]).unwrap();
+
+        let request = collector.join().unwrap();
+        let resource_spans = &request["resourceSpans"][0];
+        assert_eq!(
+            resource_spans["resource"]["attributes"][0]["value"]["stringValue"],
+            json!("libra-node")
+        );
+        let exported = &resource_spans["instrumentationLibrarySpans"][0]["spans"][0];
+        assert_eq!(exported["traceId"], json!(hex::encode(context.trace_id)));
+        assert_eq!(
+            exported["parentSpanId"],
+            json!(hex::encode(context.span_id))
+        );
+        assert_eq!(exported["spanId"], json!(hex::encode(span.span_id)));
+        assert_eq!(exported["name"], json!("execute_block"));
+        assert_eq!(exported["startTimeUnixNano"], json!("1000000000"));
+        assert_eq!(exported["endTimeUnixNano"], json!("1005000000"));
+    }
+}
@@ -132,8 +132,10 @@ pub use struct_log::{
 };
 
 mod text_log;
-pub use log::Level;
-pub use text_log::{Logger, CHANNEL_SIZE, DEFAULT_TARGET};
+pub use log::{Level, LevelFilter};
+pub use text_log::{
+    clear_module_level, module_levels, set_module_level, Logger, CHANNEL_SIZE, DEFAULT_TARGET,
+};
 
 /// Define crit macro that specify libra as the target
 // TODO Remove historical crit from code base since it isn't supported in Rust Log.
@@ -1,11 +1,14 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use arc_swap::ArcSwap;
 use chrono::Utc;
 use env_logger::filter;
-use log::{self, Level, Log, Metadata, Record};
+use log::{self, Level, LevelFilter, Log, Metadata, Record};
+use once_cell::sync::Lazy;
 
 use std::{
+    collections::BTreeMap,
     env, fmt,
     fmt::Write,
     sync::mpsc::{self, Receiver, RecvError, SyncSender, TrySendError},
@@ -16,6 +19,65 @@ pub const CHANNEL_SIZE: usize = 256;
 pub const DEFAULT_TARGET: &str = "libra";
 const RUST_LOG: &str = "RUST_LOG";
 
+/// Per-module log levels that take precedence over the filter the logger was initialized with.
+/// Keys are module path prefixes, e.g., `consensus` or `consensus::round_manager`. Every record
+/// reads the overrides, so they are swapped atomically on update rather than guarded by a lock.
+static MODULE_LEVELS: Lazy<ArcSwap<BTreeMap<String, LevelFilter>>> =
+    Lazy::new(|| ArcSwap::from_pointee(BTreeMap::new()));
+
+/// Overrides the log level for all modules whose path starts with `module`. The most specific
+/// override for a record wins. This can be called at any time, including after initialization.
+pub fn set_module_level(module: &str, level: LevelFilter) {
+    MODULE_LEVELS.rcu(|levels| {
+        let mut levels = BTreeMap::clone(levels);
+        levels.insert(module.to_string(), level);
+        levels
+    });
+    if level > log::max_level() {
+        log::set_max_level(level);
+    }
+}
+
+/// Removes a prior override for `module`, falling back to the initial filter.
+pub fn clear_module_level(module: &str) {
+    MODULE_LEVELS.rcu(|levels| {
+        let mut levels = BTreeMap::clone(levels);
+        levels.remove(module);
+        levels
+    });
+}
+
+/// Returns all currently active per-module overrides.
+pub fn module_levels() -> BTreeMap<String, LevelFilter> {
+    BTreeMap::clone(&MODULE_LEVELS.load())
+}
+
+/// Returns the level of the most specific override matching the record's module, if any.
+fn module_level(record: &Record) -> Option<LevelFilter> {
+    let levels = MODULE_LEVELS.load();
+    if levels.is_empty() {
+        return None;
+    }
+    let module_path = record.module_path()?;
+    levels
+        .iter()
+        .rev()
+        .find(|(module, _)| {
+            module_path == module.as_str()
+                || (module_path.starts_with(module.as_str())
+                    && module_path[module.len()..].starts_with("::"))
+        })
+        .map(|(_, level)| *level)
+}
+
+/// Evaluates the per-module overrides and then the initial filter.
+fn matches(filter: &filter::Filter, record: &Record) -> bool {
+    match module_level(record) {
+        Some(level) => record.level() <= level,
+        None => filter.matches(record),
+    }
+}
+
 /// Logging framework for Libra that encapsulates a minimal dependency logger with support for
 /// environmental variable (RUST_LOG) and asynchronous logging.
 /// Note: only a single logger can be instantiated at a time. Repeated instantiates of the loggers
@@ -143,7 +205,7 @@ impl<W: Writer> Log for SyncLogger<W> {
             return;
         }
 
-        if !self.filter.matches(record) {
+        if !matches(&self.filter, record) {
             return;
         }
 
@@ -212,7 +274,7 @@ impl Log for AsyncLogClient {
             return;
         }
 
-        if !self.filter.matches(record) {
+        if !matches(&self.filter, record) {
             return;
         }
 
@@ -302,5 +364,18 @@ mod tests {
         let string = logs.write().unwrap().remove(0);
         assert!(string.contains("INFO"));
         assert!(string.ends_with("Hello"));
+
+        debug!("Hidden");
+        assert_eq!(logs.read().unwrap().len(), 0);
+        set_module_level("libra_logger::text_log", log::LevelFilter::Debug);
+        debug!("Visible");
+        assert_eq!(logs.read().unwrap().len(), 1);
+        set_module_level("libra_logger", log::LevelFilter::Off);
+        debug!("Visible");
+        assert_eq!(logs.read().unwrap().len(), 2);
+        clear_module_level("libra_logger::text_log");
+        error!("Hidden");
+        assert_eq!(logs.read().unwrap().len(), 2);
+        clear_module_level("libra_logger");
     }
 }
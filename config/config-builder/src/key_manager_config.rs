@@ -5,6 +5,7 @@ use anyhow::Result;
 use libra_config::config::{KeyManagerConfig as KMConfig, SecureBackend, Token, VaultConfig};
 
 pub struct KeyManagerConfig {
+    pub network_rotation_period_secs: Option<u64>,
     pub rotation_period_secs: Option<u64>,
     pub sleep_period_secs: Option<u64>,
     pub txn_expiration_secs: Option<u64>,
@@ -22,6 +23,7 @@ impl Default for KeyManagerConfig {
     fn default() -> Self {
         let template = KMConfig::default();
         Self {
+            network_rotation_period_secs: None,
             rotation_period_secs: None,
             sleep_period_secs: None,
             txn_expiration_secs: None,
@@ -49,6 +51,9 @@ impl KeyManagerConfig {
             token: Token::FromConfig(self.vault_token.clone()),
         });
 
+        if let Some(network_rotation_period_secs) = &self.network_rotation_period_secs {
+            key_manager_config.network_rotation_period_secs = Some(*network_rotation_period_secs);
+        }
         if let Some(rotation_period_secs) = &self.rotation_period_secs {
             key_manager_config.rotation_period_secs = *rotation_period_secs;
         }
@@ -102,6 +102,10 @@ struct KeyManagerArgs {
     /// Specifies the JSON RPC endpoint for the key manager to communicate with.
     json_rpc_endpoint: String,
     #[structopt(long)]
+    /// Specifies the rotation period for validator network key rotations (in seconds). The
+    /// network key is not rotated if unset.
+    network_rotation_period_secs: Option<u64>,
+    #[structopt(long)]
     /// Specifies the rotation period for key rotations (in seconds).
     rotation_period_secs: Option<u64>,
     #[structopt(long)]
@@ -305,6 +309,7 @@ fn build_key_manager(args: KeyManagerArgs) {
     }
 
     let mut config_builder = KeyManagerConfig::new();
+    config_builder.network_rotation_period_secs = args.network_rotation_period_secs;
     config_builder.rotation_period_secs = args.rotation_period_secs;
     config_builder.sleep_period_secs = args.sleep_period_secs;
     config_builder.txn_expiration_secs = args.txn_expiration_secs;
@@ -192,9 +192,8 @@ impl ValidatorConfig {
         let safety_rules_config = &mut config.consensus.safety_rules;
 
         if let Some(server_address) = self.safety_rules_addr {
-            safety_rules_config.service = SafetyRulesService::Process(RemoteService {
-                server_address: server_address.into(),
-            })
+            safety_rules_config.service =
+                SafetyRulesService::Process(RemoteService::new(server_address.into()))
         }
 
         if let Some(backend) = &self.safety_rules_backend {
@@ -25,3 +25,5 @@ pub const LAST_VOTED_ROUND: &str = "last_voted_round";
 pub const PREFERRED_ROUND: &str = "preferred_round";
 pub const WAYPOINT: &str = "waypoint";
 pub const LAST_VOTE: &str = "last_vote";
+/// The public key of the validator network key that was most recently rotated away from
+pub const VALIDATOR_NETWORK_PREVIOUS_KEY: &str = "validator_network_previous_public_key";
@@ -9,6 +9,7 @@ mod json_rpc;
 mod key;
 mod layout;
 mod secure_backend;
+mod validate_genesis;
 mod validator_config;
 mod verify;
 mod waypoint;
@@ -57,6 +58,8 @@ pub enum Command {
     SubmitTransaction(crate::json_rpc::SubmitTransaction),
     #[structopt(about = "Submits a Layout doc to a shared storage")]
     SetLayout(SetLayout),
+    #[structopt(about = "Checks that the shared storage forms a valid genesis")]
+    ValidateGenesis(crate::validate_genesis::ValidateGenesis),
     #[structopt(about = "Constructs and signs a ValidatorConfig")]
     ValidatorConfig(crate::validator_config::ValidatorConfig),
     #[structopt(about = "Verifies and prints the current configuration state")]
@@ -74,6 +77,7 @@ pub enum CommandName {
     ReadAccountState,
     SetLayout,
     SubmitTransaction,
+    ValidateGenesis,
     ValidatorConfig,
     Verify,
 }
@@ -90,6 +94,7 @@ impl From<&Command> for CommandName {
             Command::ReadAccountState(_) => CommandName::ReadAccountState,
             Command::SetLayout(_) => CommandName::SetLayout,
             Command::SubmitTransaction(_) => CommandName::SubmitTransaction,
+            Command::ValidateGenesis(_) => CommandName::ValidateGenesis,
             Command::ValidatorConfig(_) => CommandName::ValidatorConfig,
             Command::Verify(_) => CommandName::Verify,
         }
@@ -108,6 +113,7 @@ impl std::fmt::Display for CommandName {
             CommandName::ReadAccountState => "read-account-state",
             CommandName::SetLayout => "set-layout",
             CommandName::SubmitTransaction => "submit-transaction",
+            CommandName::ValidateGenesis => "validate-genesis",
             CommandName::ValidatorConfig => "validator-config",
             CommandName::Verify => "verify",
         };
@@ -131,6 +137,7 @@ impl Command {
                 .map(|_| "success!")
                 .unwrap()
                 .to_string(),
+            Command::ValidateGenesis(_) => self.validate_genesis().unwrap().to_string(),
             Command::ValidatorConfig(_) => format!("{:?}", self.validator_config().unwrap()),
             Command::Verify(_) => self.verify().unwrap(),
         }
@@ -199,6 +206,13 @@ impl Command {
         }
     }
 
+    pub fn validate_genesis(self) -> Result<crate::validate_genesis::GenesisReport, Error> {
+        match self {
+            Command::ValidateGenesis(validate_genesis) => validate_genesis.execute(),
+            _ => Err(self.unexpected_command(CommandName::ValidateGenesis)),
+        }
+    }
+
     pub fn validator_config(self) -> Result<Transaction, Error> {
         match self {
             Command::ValidatorConfig(config) => config.execute(),
@@ -406,6 +420,108 @@ pub mod tests {
         assert_eq!(local_txn, remote_txn);
     }
 
+    #[test]
+    fn test_validate_genesis() {
+        let helper = StorageHelper::new();
+        let operators = ["alice", "bob", "carol"];
+        let association_ns = "dave";
+        let shared = "_shared";
+
+        let layout_text = "\
+            operators = [\"alice_shared\", \"bob_shared\", \"carol_shared\"]\n\
+            owners = []\n\
+            association = [\"dave_shared\"]\n\
+        ";
+
+        let temppath = libra_temppath::TempPath::new();
+        temppath.create_as_file().unwrap();
+        let mut file = File::create(temppath.path()).unwrap();
+        file.write_all(&layout_text.to_string().into_bytes())
+            .unwrap();
+        file.sync_all().unwrap();
+
+        helper
+            .set_layout(
+                temppath.path().to_str().unwrap(),
+                crate::constants::COMMON_NS,
+            )
+            .unwrap();
+
+        helper.initialize(association_ns.into());
+        helper
+            .association_key(association_ns, &(association_ns.to_string() + shared))
+            .unwrap();
+
+        let validator_address = |index: usize| -> libra_network_address::NetworkAddress {
+            format!("/ip4/10.0.0.{}/tcp/6180", index).parse().unwrap()
+        };
+        let fullnode_address = |index: usize| -> libra_network_address::NetworkAddress {
+            format!("/ip4/10.0.1.{}/tcp/6182", index).parse().unwrap()
+        };
+        for (index, ns) in operators.iter().enumerate() {
+            helper.initialize((*ns).to_string());
+            helper
+                .operator_key(ns, &((*ns).to_string() + shared))
+                .unwrap();
+            helper
+                .validator_config(
+                    AccountAddress::random(),
+                    validator_address(index),
+                    fullnode_address(index),
+                    ns,
+                    &((*ns).to_string() + shared),
+                )
+                .unwrap();
+        }
+
+        // The submitted data forms a valid genesis
+        let report = helper.validate_genesis(None).unwrap();
+        assert!(report.is_valid(), "{}", report);
+        let waypoint = helper.create_waypoint("waypoint").unwrap();
+        assert_eq!(report.waypoint, Some(waypoint));
+        assert!(helper.validate_genesis(Some(waypoint)).unwrap().is_valid());
+
+        let report = helper.validate_genesis(Some(Waypoint::default())).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.problems.len(), 1);
+
+        // Bob reuses the address of Alice and Carol listens on an unspecified address
+        helper
+            .validator_config(
+                AccountAddress::random(),
+                validator_address(0),
+                fullnode_address(1),
+                "bob",
+                "bob_shared",
+            )
+            .unwrap();
+        helper
+            .validator_config(
+                AccountAddress::random(),
+                "/ip4/0.0.0.0/tcp/6180".parse().unwrap(),
+                fullnode_address(2),
+                "carol",
+                "carol_shared",
+            )
+            .unwrap();
+
+        let report = helper.validate_genesis(None).unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.waypoint, None);
+        assert_eq!(report.problems.len(), 2, "{}", report);
+        let (identity, problem) = &report.problems[0];
+        assert_eq!(identity, "carol_shared");
+        assert!(problem.starts_with("validator address /ip4/0.0.0.0/tcp/6180/"));
+        assert!(problem.ends_with("is unreachable"));
+        assert_eq!(
+            report.problems[1],
+            (
+                "bob_shared".to_string(),
+                "validator address is also used by alice_shared".to_string()
+            )
+        );
+    }
+
     #[test]
     fn test_verify() {
         let helper = StorageHelper::new();
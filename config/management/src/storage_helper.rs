@@ -209,6 +209,29 @@ impl StorageHelper {
         command.set_layout()
     }
 
+    #[cfg(test)]
+    pub fn validate_genesis(
+        &self,
+        waypoint: Option<Waypoint>,
+    ) -> Result<crate::validate_genesis::GenesisReport, Error> {
+        let mut args = format!(
+            "
+                management
+                validate-genesis
+                --backend backend={backend};\
+                    path={path}
+            ",
+            backend = crate::secure_backend::DISK,
+            path = self.path_string(),
+        );
+        if let Some(waypoint) = waypoint {
+            args = format!("{} --waypoint {}", args, waypoint);
+        }
+
+        let command = Command::from_iter(args.split_whitespace());
+        command.validate_genesis()
+    }
+
     pub fn validator_config(
         &self,
         owner_address: AccountAddress,
@@ -0,0 +1,442 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    constants, error::Error, genesis::Genesis, layout::Layout,
+    secure_backend::StorageLocation::RemoteStorage, waypoint::genesis_waypoint, SingleBackend,
+};
+use libra_crypto::{ed25519::Ed25519PublicKey, x25519};
+use libra_global_constants::{OPERATOR_KEY, OWNER_KEY};
+use libra_network_address::{parse_ip_tcp, NetworkAddress, RawNetworkAddress};
+use libra_secure_storage::{KVStorage, Storage};
+use libra_types::{
+    account_address,
+    transaction::{authenticator::AuthenticationKey, Transaction, TransactionPayload},
+    waypoint::Waypoint,
+};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+use structopt::StructOpt;
+use transaction_builder::ScriptCall;
+
+/// Dry-run of the genesis ceremony: checks that the data submitted by every identity of the
+/// layout to the shared storage forms a valid genesis, then builds and executes that genesis in
+/// an in-memory database. Owners are optional for now, if some are listed, they are paired with
+/// the operators by position in the layout.
+#[derive(Debug, StructOpt)]
+pub struct ValidateGenesis {
+    #[structopt(flatten)]
+    backend: SingleBackend,
+    /// If specified, the waypoint expected for the genesis, e.g. the one published by the
+    /// association.
+    #[structopt(long)]
+    waypoint: Option<Waypoint>,
+}
+
+/// The outcome of a genesis validation.
+#[derive(Debug, Default)]
+pub struct GenesisReport {
+    /// The problems found, each with the identity of the layout it concerns.
+    pub problems: Vec<(String, String)>,
+    /// The waypoint of the genesis, if it could be executed.
+    pub waypoint: Option<Waypoint>,
+}
+
+impl GenesisReport {
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty() && self.waypoint.is_some()
+    }
+
+    fn add_problem(&mut self, identity: &str, problem: String) {
+        self.problems.push((identity.to_string(), problem));
+    }
+}
+
+impl fmt::Display for GenesisReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Genesis validation:")?;
+        write_break(f)?;
+        for (identity, problem) in &self.problems {
+            writeln!(f, "{} - {}", identity, problem)?;
+        }
+        if let Some(waypoint) = self.waypoint {
+            writeln!(f, "waypoint - {}", waypoint)?;
+        }
+        write_break(f)?;
+        if self.is_valid() {
+            write!(f, "Genesis is valid")
+        } else {
+            write!(
+                f,
+                "Genesis is INVALID, {} problem(s) found",
+                self.problems.len()
+            )
+        }
+    }
+}
+
+/// The contents of a `ValidatorConfig` transaction submitted by an operator.
+struct Registration {
+    operator_key: Ed25519PublicKey,
+    consensus_key: Vec<u8>,
+    validator_network_key: Vec<u8>,
+    validator_address: NetworkAddress,
+    fullnode_network_key: Vec<u8>,
+    fullnode_address: NetworkAddress,
+}
+
+impl ValidateGenesis {
+    pub fn execute(self) -> Result<GenesisReport, Error> {
+        let genesis_helper = Genesis {
+            backend: SingleBackend {
+                backend: self.backend.backend.clone(),
+            },
+            path: None,
+        };
+        let layout = genesis_helper.layout()?;
+        let mut report = GenesisReport::default();
+
+        match layout.association.first() {
+            Some(association) => {
+                if let Err(e) = genesis_helper.association(&layout) {
+                    report.add_problem(association, e.to_string());
+                }
+            }
+            None => report.add_problem(constants::LAYOUT, "no association".into()),
+        }
+        if layout.operators.is_empty() {
+            report.add_problem(constants::LAYOUT, "no operators".into());
+        }
+
+        self.check_owners(&layout, &mut report)?;
+        let registrations = self.check_operators(&layout, &mut report)?;
+        check_duplicates(&registrations, &mut report);
+
+        // The genesis scripts abort on any of the problems found above, e.g. on a duplicate
+        // validator, and vm_genesis::GenesisContext::exec panics when they do.
+        if !report.problems.is_empty() {
+            return Ok(report);
+        }
+
+        // Other problems only surface while building or executing the genesis, which may panic
+        // as well. Either way, they are reported rather than ending the validation.
+        let waypoint = match execute_genesis(&mut report, || {
+            genesis_helper
+                .execute()
+                .and_then(|genesis| genesis_waypoint(&genesis))
+        }) {
+            Some(waypoint) => waypoint,
+            None => return Ok(report),
+        };
+        if let Some(expected_waypoint) = self.waypoint {
+            if waypoint != expected_waypoint {
+                report.add_problem(
+                    "waypoint",
+                    format!("expected {}, found {}", expected_waypoint, waypoint),
+                );
+            }
+        }
+        report.waypoint = Some(waypoint);
+
+        Ok(report)
+    }
+
+    /// Checks that each operator is paired with an owner that submitted its key.
+    fn check_owners(&self, layout: &Layout, report: &mut GenesisReport) -> Result<(), Error> {
+        if layout.owners.is_empty() {
+            return Ok(());
+        }
+
+        for operator in layout.operators.iter().skip(layout.owners.len()) {
+            report.add_problem(operator, "no owner paired with this operator".into());
+        }
+        for owner in layout.owners.iter().skip(layout.operators.len()) {
+            report.add_problem(owner, "no operator paired with this owner".into());
+        }
+        for owner in layout.owners.iter() {
+            let storage = self.remote_storage(owner)?;
+            if let Err(e) = storage
+                .get(OWNER_KEY)
+                .and_then(|v| v.value.ed25519_public_key())
+            {
+                report.add_problem(owner, format!("invalid {}: {}", OWNER_KEY, e));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the keys and the `ValidatorConfig` transaction of each operator.
+    fn check_operators(
+        &self,
+        layout: &Layout,
+        report: &mut GenesisReport,
+    ) -> Result<BTreeMap<String, Registration>, Error> {
+        let mut registrations = BTreeMap::new();
+        for operator in layout.operators.iter() {
+            let storage = self.remote_storage(operator)?;
+            match registration(&storage) {
+                Ok(registration) => {
+                    for problem in check_registration(&registration) {
+                        report.add_problem(operator, problem);
+                    }
+                    registrations.insert(operator.clone(), registration);
+                }
+                Err(problem) => report.add_problem(operator, problem),
+            }
+        }
+        Ok(registrations)
+    }
+
+    fn remote_storage(&self, namespace: &str) -> Result<Storage, Error> {
+        self.backend
+            .backend
+            .clone()
+            .set_namespace(namespace.into())
+            .create_storage(RemoteStorage)
+    }
+}
+
+/// Runs `execute` and returns the waypoint of the genesis, or reports the error or the panic
+/// that prevented it as a genesis problem. The panic hook is silenced for the duration of the
+/// call, so that the panic message only shows up in the report rather than along with a backtrace
+/// in the middle of it.
+fn execute_genesis(
+    report: &mut GenesisReport,
+    execute: impl FnOnce() -> Result<Waypoint, Error>,
+) -> Option<Waypoint> {
+    let panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    let result = panic::catch_unwind(AssertUnwindSafe(execute));
+    panic::set_hook(panic_hook);
+
+    match result {
+        Ok(Ok(waypoint)) => Some(waypoint),
+        Ok(Err(e)) => {
+            report.add_problem("genesis", e.to_string());
+            None
+        }
+        Err(panic) => {
+            let reason = panic
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| panic.downcast_ref::<&str>().copied())
+                .unwrap_or("unknown reason");
+            report.add_problem("genesis", format!("failed to execute genesis: {}", reason));
+            None
+        }
+    }
+}
+
+/// Reads the operator key and the `ValidatorConfig` transaction of an operator, and checks that
+/// the transaction is a registration signed by the operator.
+fn registration(storage: &Storage) -> Result<Registration, String> {
+    let operator_key = storage
+        .get(OPERATOR_KEY)
+        .and_then(|v| v.value.ed25519_public_key())
+        .map_err(|e| format!("invalid {}: {}", OPERATOR_KEY, e))?;
+    let txn = storage
+        .get(constants::VALIDATOR_CONFIG)
+        .and_then(|v| v.value.transaction())
+        .map_err(|e| format!("invalid {}: {}", constants::VALIDATOR_CONFIG, e))?;
+
+    let signed_txn = match txn {
+        Transaction::UserTransaction(signed_txn) => signed_txn,
+        _ => {
+            return Err(format!(
+                "{} is not a user transaction",
+                constants::VALIDATOR_CONFIG
+            ))
+        }
+    };
+    if signed_txn.authenticator().authentication_key() != AuthenticationKey::ed25519(&operator_key)
+    {
+        return Err(format!(
+            "{} is not signed by the {}",
+            constants::VALIDATOR_CONFIG,
+            OPERATOR_KEY
+        ));
+    }
+    signed_txn.clone().check_signature().map_err(|e| {
+        format!(
+            "invalid signature of {}: {}",
+            constants::VALIDATOR_CONFIG,
+            e
+        )
+    })?;
+    let sender = signed_txn.sender();
+    let script = match signed_txn.payload() {
+        TransactionPayload::Script(script) => script,
+        _ => return Err(format!("{} is not a script", constants::VALIDATOR_CONFIG)),
+    };
+
+    match ScriptCall::decode(script) {
+        Some(ScriptCall::SetValidatorConfig {
+            validator_account,
+            consensus_pubkey,
+            validator_network_identity_pubkey,
+            validator_network_address,
+            fullnodes_network_identity_pubkey,
+            fullnodes_network_address,
+        }) => {
+            if validator_account != sender
+                || sender != account_address::from_public_key(&operator_key)
+            {
+                return Err(format!(
+                    "{} does not configure the account of the {}",
+                    constants::VALIDATOR_CONFIG,
+                    OPERATOR_KEY
+                ));
+            }
+            Ok(Registration {
+                operator_key,
+                consensus_key: consensus_pubkey,
+                validator_network_key: validator_network_identity_pubkey,
+                validator_address: network_address(validator_network_address)
+                    .map_err(|e| format!("invalid validator address: {}", e))?,
+                fullnode_network_key: fullnodes_network_identity_pubkey,
+                fullnode_address: network_address(fullnodes_network_address)
+                    .map_err(|e| format!("invalid full node address: {}", e))?,
+            })
+        }
+        _ => Err(format!(
+            "{} is not a set_validator_config script",
+            constants::VALIDATOR_CONFIG
+        )),
+    }
+}
+
+fn network_address(bytes: Vec<u8>) -> Result<NetworkAddress, lcs::Error> {
+    NetworkAddress::try_from(&RawNetworkAddress::new(bytes))
+}
+
+/// Checks the keys and the network addresses of a registration.
+fn check_registration(registration: &Registration) -> Vec<String> {
+    let mut problems = Vec::new();
+    if Ed25519PublicKey::try_from(registration.consensus_key.as_slice()).is_err() {
+        problems.push("invalid consensus key".into());
+    }
+    problems.extend(check_address(
+        "validator",
+        &registration.validator_address,
+        &registration.validator_network_key,
+    ));
+    problems.extend(check_address(
+        "full node",
+        &registration.fullnode_address,
+        &registration.fullnode_network_key,
+    ));
+    problems
+}
+
+/// Checks that a network address can be dialed by other nodes, with the expected network key.
+fn check_address(name: &str, address: &NetworkAddress, network_key: &[u8]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !address.is_libranet_addr() {
+        problems.push(format!(
+            "{} address {} is not a libranet address",
+            name, address
+        ));
+    }
+    if let Some(((ip, port), _)) = parse_ip_tcp(address.as_slice()) {
+        if ip.is_unspecified() || ip.is_loopback() || port == 0 {
+            problems.push(format!("{} address {} is unreachable", name, address));
+        }
+    }
+    match x25519::PublicKey::try_from(network_key) {
+        Ok(network_key) => {
+            if address.find_noise_proto() != Some(network_key) {
+                problems.push(format!(
+                    "{} address {} does not match the {} network key",
+                    name, address, name
+                ));
+            }
+        }
+        Err(_) => problems.push(format!("invalid {} network key", name)),
+    }
+    problems
+}
+
+/// Reports the keys and addresses shared by several operators.
+fn check_duplicates(registrations: &BTreeMap<String, Registration>, report: &mut GenesisReport) {
+    let fields: [(&str, fn(&Registration) -> Vec<u8>); 6] = [
+        (OPERATOR_KEY, |r| r.operator_key.to_bytes().to_vec()),
+        ("consensus key", |r| r.consensus_key.clone()),
+        ("validator network key", |r| r.validator_network_key.clone()),
+        ("validator address", |r| {
+            transport_address(&r.validator_address)
+        }),
+        ("full node network key", |r| r.fullnode_network_key.clone()),
+        ("full node address", |r| {
+            transport_address(&r.fullnode_address)
+        }),
+    ];
+    for (name, field) in fields.iter() {
+        let mut users: BTreeMap<Vec<u8>, &str> = BTreeMap::new();
+        for (operator, registration) in registrations {
+            if let Some(other) = users.insert(field(registration), operator) {
+                report.add_problem(operator, format!("{} is also used by {}", name, other));
+            }
+        }
+    }
+}
+
+/// The `/ip4/<addr>/tcp/<port>` or `/dns/<domain>/tcp/<port>` prefix of a network address, which
+/// is dialed by the other nodes.
+fn transport_address(address: &NetworkAddress) -> Vec<u8> {
+    address
+        .as_slice()
+        .iter()
+        .take(2)
+        .map(|protocol| protocol.to_string())
+        .collect::<String>()
+        .into_bytes()
+}
+
+fn write_break(f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(
+        f,
+        "====================================================================================",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execute_genesis() {
+        let mut report = GenesisReport::default();
+        let waypoint = Waypoint::default();
+        assert_eq!(
+            execute_genesis(&mut report, || Ok(waypoint)),
+            Some(waypoint)
+        );
+        assert!(report.problems.is_empty());
+
+        let error = Error::UnexpectedError("Unable to generate a waypoint".into());
+        let expected_problem = error.to_string();
+        assert_eq!(execute_genesis(&mut report, || Err(error)), None);
+        assert_eq!(
+            report.problems,
+            vec![("genesis".to_string(), expected_problem)]
+        );
+
+        let mut report = GenesisReport::default();
+        assert_eq!(
+            execute_genesis(&mut report, || panic!("genesis script aborted")),
+            None
+        );
+        assert_eq!(
+            report.problems,
+            vec![(
+                "genesis".to_string(),
+                "failed to execute genesis: genesis script aborted".to_string()
+            )]
+        );
+        assert!(!report.is_valid());
+    }
+}
@@ -13,7 +13,7 @@ use executor::db_bootstrapper;
 use libra_global_constants::WAYPOINT;
 use libra_secure_storage::{KVStorage, Storage, Value};
 use libra_temppath::TempPath;
-use libra_types::waypoint::Waypoint;
+use libra_types::{transaction::Transaction, waypoint::Waypoint};
 use libra_vm::LibraVM;
 use libradb::LibraDB;
 use std::{convert::TryInto, str::FromStr};
@@ -37,15 +37,7 @@ impl CreateWaypoint {
         };
 
         let genesis = genesis_helper.execute()?;
-
-        let path = TempPath::new();
-        let libradb =
-            LibraDB::open(&path, false, None).map_err(|e| Error::UnexpectedError(e.to_string()))?;
-        let db_rw = DbReaderWriter::new(libradb);
-
-        let waypoint = db_bootstrapper::bootstrap_db_if_empty::<LibraVM>(&db_rw, &genesis)
-            .map_err(|e| Error::UnexpectedError(e.to_string()))?
-            .ok_or_else(|| Error::UnexpectedError("Unable to generate a waypoint".to_string()))?;
+        let waypoint = genesis_waypoint(&genesis)?;
 
         if let Some(remote) = self.secure_backends.remote {
             let mut remote_storage: Storage = remote.try_into()?;
@@ -59,6 +51,18 @@ impl CreateWaypoint {
     }
 }
 
+/// Executes the genesis in a temporary database and returns its waypoint.
+pub fn genesis_waypoint(genesis: &Transaction) -> Result<Waypoint, Error> {
+    let path = TempPath::new();
+    let libradb =
+        LibraDB::open(&path, false, None).map_err(|e| Error::UnexpectedError(e.to_string()))?;
+    let db_rw = DbReaderWriter::new(libradb);
+
+    db_bootstrapper::bootstrap_db_if_empty::<LibraVM>(&db_rw, genesis)
+        .map_err(|e| Error::UnexpectedError(e.to_string()))?
+        .ok_or_else(|| Error::UnexpectedError("Unable to generate a waypoint".to_string()))
+}
+
 #[derive(Debug, StructOpt)]
 pub struct InsertWaypoint {
     #[structopt(flatten)]
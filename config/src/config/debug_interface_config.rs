@@ -3,13 +3,19 @@
 
 use crate::utils;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct DebugInterfaceConfig {
     pub admission_control_node_debug_port: u16,
     pub address: String,
+    /// Bearer token required by the admin endpoints of the node debug service, which are
+    /// disabled if this is not set.
+    pub admin_token: Option<String>,
+    /// Directory in which the db_checkpoint admin command creates checkpoints. The command is
+    /// disabled if this is not set.
+    pub db_checkpoint_dir: Option<PathBuf>,
     pub libra_trace: LibraTraceConfig,
     pub metrics_server_port: u16,
     pub public_metrics_server_port: u16,
@@ -20,6 +26,8 @@ impl Default for DebugInterfaceConfig {
         DebugInterfaceConfig {
             admission_control_node_debug_port: 6191,
             address: "0.0.0.0".to_string(),
+            admin_token: None,
+            db_checkpoint_dir: None,
             metrics_server_port: 9101,
             public_metrics_server_port: 9102,
             libra_trace: LibraTraceConfig::default(),
@@ -39,6 +47,9 @@ impl DebugInterfaceConfig {
 #[serde(default, deny_unknown_fields)]
 pub struct LibraTraceConfig {
     pub sampling: HashMap<String, String>,
+    /// OTLP/HTTP collector endpoint, e.g., http://localhost:55681/v1/trace, sampled traces are
+    /// exported to it when set.
+    pub otlp_endpoint: Option<String>,
 }
 
 impl Default for LibraTraceConfig {
@@ -46,6 +57,9 @@ impl Default for LibraTraceConfig {
         let mut map = HashMap::new();
         map.insert(String::from("txn"), String::from("1/100"));
         map.insert(String::from("block"), String::from("1/1"));
-        LibraTraceConfig { sampling: map }
+        LibraTraceConfig {
+            sampling: map,
+            otlp_endpoint: None,
+        }
     }
 }
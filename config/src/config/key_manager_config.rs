@@ -17,6 +17,8 @@ const DEFAULT_TXN_EXPIRATION_SECS: u64 = 3600; // 1 hour
 pub struct KeyManagerConfig {
     pub logger: LoggerConfig,
     pub json_rpc_endpoint: String,
+    /// The frequency by which to rotate the validator network key, if at all.
+    pub network_rotation_period_secs: Option<u64>,
     pub rotation_period_secs: u64,
     pub secure_backend: SecureBackend,
     pub sleep_period_secs: u64,
@@ -28,6 +30,7 @@ impl Default for KeyManagerConfig {
         KeyManagerConfig {
             json_rpc_endpoint: DEFAULT_JSON_RPC_ENDPOINT.into(),
             logger: LoggerConfig::default(),
+            network_rotation_period_secs: None,
             rotation_period_secs: DEFAULT_ROTATION_PERIOD_SECS,
             secure_backend: SecureBackend::InMemoryStorage,
             sleep_period_secs: DEFAULT_SLEEP_PERIOD_SECS,
@@ -49,8 +52,6 @@ impl KeyManagerConfig {
     }
 
     pub fn set_data_dir(&mut self, data_dir: PathBuf) {
-        if let SecureBackend::OnDiskStorage(backend) = &mut self.secure_backend {
-            backend.set_data_dir(data_dir);
-        }
+        self.secure_backend.set_data_dir(data_dir);
     }
 }
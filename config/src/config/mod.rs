@@ -243,6 +243,8 @@ impl NodeConfig {
             )?;
         }
 
+        config.consensus.safety_rules.verify()?;
+
         let mut network_ids = HashSet::new();
         let input_dir = RootPath::new(input_path);
         config.execution.load(&input_dir)?;
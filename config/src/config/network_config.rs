@@ -8,6 +8,7 @@ use crate::{
     utils,
 };
 use libra_crypto::{x25519, Uniform};
+use libra_global_constants::{VALIDATOR_NETWORK_KEY, VALIDATOR_NETWORK_PREVIOUS_KEY};
 use libra_network_address::NetworkAddress;
 use libra_secure_storage::{CryptoStorage, KVStorage, Storage};
 use libra_types::{transaction::authenticator::AuthenticationKey, PeerId};
@@ -39,6 +40,9 @@ pub struct NetworkConfig {
     pub connectivity_check_interval_ms: u64,
     // Enable this network to use either gossip discovery or onchain discovery.
     pub discovery_method: DiscoveryMethod,
+    // Inject the faults set through the `network_faults` admin command in the connections of
+    // this network, e.g. latency or partitions. Only meant for tests.
+    pub fault_injection: bool,
     pub identity: Identity,
     // TODO: Add support for multiple listen/advertised addresses in config.
     // The address that this node is listening on for new connections.
@@ -68,6 +72,7 @@ impl NetworkConfig {
         let mut config = Self {
             connectivity_check_interval_ms: 5000,
             discovery_method: DiscoveryMethod::None,
+            fault_injection: false,
             identity: Identity::None,
             listen_address: "/ip4/0.0.0.0/tcp/6180".parse().unwrap(),
             mutual_authentication: false,
@@ -87,6 +92,7 @@ impl NetworkConfig {
         Self {
             connectivity_check_interval_ms: self.connectivity_check_interval_ms,
             discovery_method: self.discovery_method.clone(),
+            fault_injection: self.fault_injection,
             identity: Identity::None,
             listen_address: self.listen_address.clone(),
             mutual_authentication: self.mutual_authentication,
@@ -97,20 +103,19 @@ impl NetworkConfig {
     }
 
     pub fn identity_key(&mut self) -> x25519::PrivateKey {
-        let key = match &mut self.identity {
-            Identity::FromConfig(config) => config.keypair.take_private(),
-            Identity::FromStorage(config) => {
-                let storage: Storage = (&config.backend).into();
-                let key = storage
-                    .export_private_key(&config.key_name)
-                    .expect("Unable to read key");
-                let key = x25519::PrivateKey::from_ed25519_private_bytes(&key.to_bytes())
-                    .expect("Unable to convert key");
-                Some(key)
-            }
+        self.identity_keys().remove(0)
+    }
+
+    /// Returns the identity key followed, for identities held in storage, by the key it was most
+    /// recently rotated away from, if that rotation has been recorded. Until a rotation reaches
+    /// the ValidatorSet, peers only know the older key.
+    pub fn identity_keys(&mut self) -> Vec<x25519::PrivateKey> {
+        let keys = match &mut self.identity {
+            Identity::FromConfig(config) => config.keypair.take_private().map(|key| vec![key]),
+            Identity::FromStorage(config) => Some(config.load_keys().expect("Unable to read key")),
             Identity::None => None,
         };
-        key.expect("identity key should be present")
+        keys.expect("identity key should be present")
     }
 
     pub fn load(&mut self, role: RoleType) -> Result<(), Error> {
@@ -284,11 +289,43 @@ pub struct IdentityFromConfig {
 }
 
 /// This represents an identity in a secure-storage as defined in NodeConfig::secure.
-#[cfg_attr(any(test, feature = "fuzzing"), derive(Clone, PartialEq))]
-#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(PartialEq))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct IdentityFromStorage {
     pub backend: SecureBackend,
     pub key_name: String,
     pub peer_id_name: String,
 }
+
+impl IdentityFromStorage {
+    /// Reads the identity key followed by the key it was most recently rotated away from, if
+    /// KeyManager recorded that rotation and storage still holds that key.
+    pub fn load_keys(&self) -> Result<Vec<x25519::PrivateKey>, libra_secure_storage::Error> {
+        let storage: Storage = (&self.backend).into();
+        let mut keys = vec![storage.export_private_key(&self.key_name)?];
+
+        if self.key_name == VALIDATOR_NETWORK_KEY {
+            let previous_key = match storage.get(VALIDATOR_NETWORK_PREVIOUS_KEY) {
+                Ok(response) => Some(response.value.ed25519_public_key()?),
+                Err(libra_secure_storage::Error::KeyNotSet(_)) => None,
+                Err(e) => return Err(e),
+            };
+            if let Some(previous_key) = previous_key {
+                match storage.export_private_key_for_version(&self.key_name, previous_key) {
+                    Ok(key) => keys.push(key),
+                    Err(libra_secure_storage::Error::KeyVersionNotFound(_)) => (),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(keys
+            .iter()
+            .map(|key| {
+                x25519::PrivateKey::from_ed25519_private_bytes(&key.to_bytes())
+                    .expect("Unable to convert key")
+            })
+            .collect())
+    }
+}
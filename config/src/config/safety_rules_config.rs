@@ -2,10 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    config::{LoggerConfig, SecureBackend},
+    config::{Error, LoggerConfig, SecureBackend},
     keys::KeyPair,
 };
-use libra_crypto::{ed25519::Ed25519PrivateKey, Uniform};
+use libra_crypto::{ed25519::Ed25519PrivateKey, x25519, Uniform};
 use libra_network_address::NetworkAddress;
 use libra_types::{waypoint::Waypoint, PeerId};
 use rand::rngs::StdRng;
@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     net::{SocketAddr, ToSocketAddrs},
     path::PathBuf,
+    time::Duration,
 };
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -39,8 +40,16 @@ impl Default for SafetyRulesConfig {
 
 impl SafetyRulesConfig {
     pub fn set_data_dir(&mut self, data_dir: PathBuf) {
-        if let SecureBackend::OnDiskStorage(backend) = &mut self.backend {
-            backend.set_data_dir(data_dir);
+        self.backend.set_data_dir(data_dir);
+    }
+
+    /// Checks that a remote service, if any, can be reached and authenticated.
+    pub fn verify(&self) -> Result<(), Error> {
+        match &self.service {
+            SafetyRulesService::Process(service) | SafetyRulesService::SpawnedProcess(service) => {
+                service.verify()
+            }
+            _ => Ok(()),
         }
     }
 }
@@ -67,18 +76,66 @@ pub enum SafetyRulesService {
 #[serde(deny_unknown_fields)]
 pub struct RemoteService {
     pub server_address: NetworkAddress,
+    /// If set, the client and server mutually authenticate and encrypt all communication,
+    /// otherwise plaintext TCP is used.
+    #[serde(default)]
+    pub authentication: Option<RemoteAuthentication>,
+    /// Bounds the time a client waits on a request before reconnecting and retrying.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
 }
 
 impl RemoteService {
-    pub fn server_address(&self) -> SocketAddr {
+    pub fn new(server_address: NetworkAddress) -> Self {
+        Self {
+            server_address,
+            authentication: None,
+            timeout_ms: None,
+        }
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn server_address(&self) -> Result<SocketAddr, Error> {
         self.server_address
             .to_socket_addrs()
-            .expect("server_address invalid")
+            .map_err(|e| Error::IO(self.server_address.to_string(), e))?
             .next()
-            .expect("server_address invalid")
+            .ok_or_else(|| {
+                Error::InvariantViolation(format!(
+                    "server_address {} does not resolve to a socket address",
+                    self.server_address
+                ))
+            })
+    }
+
+    pub fn verify(&self) -> Result<(), Error> {
+        self.server_address()?;
+        if let Some(authentication) = &self.authentication {
+            if authentication.trusted_clients.is_empty() {
+                return Err(Error::Missing("authentication.trusted_clients"));
+            }
+        }
+        Ok(())
     }
 }
 
+/// Defines the Noise static keys used to mutually authenticate a remote SafetyRules service and its
+/// clients.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteAuthentication {
+    /// The name of the Ed25519 key, within the SafetyRulesConfig backend, from which this
+    /// endpoint's x25519 static key is derived.
+    pub key_name: String,
+    /// The x25519 public key of the server, the client only talks to a server holding it.
+    pub server_public_key: x25519::PublicKey,
+    /// The x25519 public keys of the clients the server accepts.
+    pub trusted_clients: Vec<x25519::PublicKey>,
+}
+
 #[derive(Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(any(test, feature = "fuzzing"), derive(Clone))]
 pub struct SafetyRulesTestConfig {
@@ -117,3 +174,32 @@ impl SafetyRulesTestConfig {
         self.execution_keypair = Some(KeyPair::<Ed25519PrivateKey>::load(privkey));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_verify_remote_service() {
+        let address: NetworkAddress = "/ip4/127.0.0.1/tcp/6191".parse().unwrap();
+        let mut service = RemoteService::new(address);
+        let mut config = SafetyRulesConfig::default();
+        config.service = SafetyRulesService::Process(service.clone());
+        config.verify().unwrap();
+
+        service.authentication = Some(RemoteAuthentication {
+            key_name: "safety_rules".into(),
+            server_public_key: x25519::PrivateKey::generate(&mut StdRng::from_seed([0; 32]))
+                .public_key(),
+            trusted_clients: vec![],
+        });
+        config.service = SafetyRulesService::Process(service.clone());
+        assert!(config.verify().is_err());
+
+        service.authentication = None;
+        service.server_address = "/dns4/libra.invalid/tcp/6191".parse().unwrap();
+        config.service = SafetyRulesService::SpawnedProcess(service);
+        assert!(config.verify().is_err());
+    }
+}
@@ -3,10 +3,11 @@
 
 use crate::config::Error;
 use libra_secure_storage::{
-    GitHubStorage, InMemoryStorage, NamespacedStorage, OnDiskStorage, Storage, VaultStorage,
+    GitHubStorage, InMemoryStorage, NamespacedStorage, OnDiskStorage, Policy, PolicyStorage,
+    Storage, VaultStorage,
 };
 use serde::{Deserialize, Serialize};
-use std::{fs::File, io::Read, path::PathBuf};
+use std::{collections::BTreeMap, fs::File, io::Read, path::PathBuf};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -15,6 +16,17 @@ pub enum SecureBackend {
     InMemoryStorage,
     Vault(VaultConfig),
     OnDiskStorage(OnDiskStorageConfig),
+    PolicyStorage(PolicyStorageConfig),
+}
+
+impl SecureBackend {
+    pub fn set_data_dir(&mut self, data_dir: PathBuf) {
+        match self {
+            SecureBackend::OnDiskStorage(config) => config.set_data_dir(data_dir),
+            SecureBackend::PolicyStorage(config) => config.set_data_dir(data_dir),
+            _ => (),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -70,6 +82,53 @@ pub struct OnDiskStorageConfig {
     data_dir: PathBuf,
 }
 
+/// Wraps another backend, enforcing a policy per key on behalf of a single identity and recording
+/// every operation in an audit log.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyStorageConfig {
+    /// The backend that holds the keys
+    pub backend: Box<SecureBackend>,
+    /// The caller on whose behalf policies are evaluated, e.g., safety_rules or key_manager
+    pub identity: String,
+    /// Path to the audit log, relative paths are within data_dir
+    pub audit_log: PathBuf,
+    /// The policy for each key, keys without a policy are inaccessible
+    pub policies: BTreeMap<String, Policy>,
+    #[serde(skip)]
+    data_dir: PathBuf,
+}
+
+impl PolicyStorageConfig {
+    pub fn new(
+        backend: SecureBackend,
+        identity: String,
+        audit_log: PathBuf,
+        policies: BTreeMap<String, Policy>,
+    ) -> Self {
+        Self {
+            backend: Box::new(backend),
+            identity,
+            audit_log,
+            policies,
+            data_dir: PathBuf::from("/opt/libra/data/common"),
+        }
+    }
+
+    pub fn audit_log(&self) -> PathBuf {
+        if self.audit_log.is_relative() {
+            self.data_dir.join(&self.audit_log)
+        } else {
+            self.audit_log.clone()
+        }
+    }
+
+    pub fn set_data_dir(&mut self, data_dir: PathBuf) {
+        self.backend.set_data_dir(data_dir.clone());
+        self.data_dir = data_dir;
+    }
+}
+
 /// Tokens can either be directly within this config or stored somewhere on disk.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -157,6 +216,18 @@ impl From<&SecureBackend> for Storage {
                     Storage::from(storage)
                 }
             }
+            SecureBackend::PolicyStorage(config) => {
+                let mut storage = PolicyStorage::new(
+                    Storage::from(config.backend.as_ref()),
+                    config.identity.clone(),
+                    config.audit_log(),
+                )
+                .expect("Unable to open audit log");
+                for (key, policy) in &config.policies {
+                    storage.set_policy(key, policy.clone());
+                }
+                Storage::from(storage)
+            }
             SecureBackend::Vault(config) => Storage::from(VaultStorage::new(
                 config.server.clone(),
                 config.token.read_token().expect("Unable to read token"),
@@ -172,6 +243,7 @@ impl From<&SecureBackend> for Storage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use libra_secure_storage::{Capability, Identity, KVStorage, Permission};
     use std::io::Write;
 
     #[derive(Debug, Deserialize, PartialEq, Serialize)]
@@ -227,6 +299,82 @@ vault:
         serde_yaml::to_string(&from_disk).unwrap();
     }
 
+    #[test]
+    fn test_policy_storage_parsing() {
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Config {
+            backend: SecureBackend,
+        }
+
+        let mut policies = BTreeMap::new();
+        policies.insert(
+            "consensus".to_string(),
+            Policy::new(vec![Permission::new(
+                Identity::User("safety_rules".to_string()),
+                vec![Capability::Read, Capability::Sign],
+            )]),
+        );
+        let expected = Config {
+            backend: SecureBackend::PolicyStorage(PolicyStorageConfig::new(
+                SecureBackend::InMemoryStorage,
+                "safety_rules".to_string(),
+                PathBuf::from("audit.log"),
+                policies,
+            )),
+        };
+
+        let text = r#"
+backend:
+    type: "policy_storage"
+    backend:
+        type: "in_memory_storage"
+    identity: "safety_rules"
+    audit_log: "audit.log"
+    policies:
+        consensus:
+            permissions:
+                - id:
+                    User: "safety_rules"
+                  capabilities: ["Read", "Sign"]
+        "#;
+
+        let mut config: Config = serde_yaml::from_str(text).unwrap();
+        config
+            .backend
+            .set_data_dir(PathBuf::from("/opt/libra/data/common"));
+        assert_eq!(config, expected);
+        serde_yaml::to_string(&expected).unwrap();
+    }
+
+    #[test]
+    fn test_policy_storage_enforcement() {
+        let audit_log = libra_temppath::TempPath::new();
+        let mut policies = BTreeMap::new();
+        policies.insert("readable".to_string(), Policy::public());
+        let mut config = PolicyStorageConfig::new(
+            SecureBackend::InMemoryStorage,
+            "safety_rules".to_string(),
+            audit_log.path().to_path_buf(),
+            policies,
+        );
+        config.set_data_dir(PathBuf::from("/unused"));
+        assert_eq!(config.audit_log(), audit_log.path());
+
+        let mut storage = Storage::from(&SecureBackend::PolicyStorage(config));
+        storage
+            .set("readable", libra_secure_storage::Value::U64(5))
+            .unwrap();
+        assert_eq!(
+            storage.get("readable").unwrap().value,
+            libra_secure_storage::Value::U64(5)
+        );
+        assert!(storage.get("hidden").is_err());
+
+        let entries = libra_secure_storage::AuditLog::verify(audit_log.path()).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries[2].error.is_some());
+    }
+
     #[test]
     fn test_token_reading() {
         let temppath = libra_temppath::TempPath::new();
@@ -22,6 +22,10 @@ fn main() {
         eprintln!("Unable to read provided config: {}", e);
         process::exit(1);
     });
+    if let Err(e) = config.verify() {
+        eprintln!("Invalid config: {}", e);
+        process::exit(1);
+    }
 
     libra_logger::Logger::new()
         .channel_size(config.logger.chan_size)
@@ -3,10 +3,14 @@
 
 use crate::{
     persistent_safety_storage::PersistentSafetyStorage,
-    remote_service::{self, RemoteService},
+    remote_service::{self, ClientConfig, RemoteService},
     safety_rules_manager,
+    serializer::SerializerClient,
+    Error,
+};
+use libra_config::config::{
+    RemoteService as RemoteServiceConfig, SafetyRulesConfig, SafetyRulesService, SecureBackend,
 };
-use libra_config::config::{SafetyRulesConfig, SafetyRulesService};
 
 use std::net::SocketAddr;
 
@@ -16,6 +20,7 @@ pub struct Process {
 
 impl Process {
     pub fn new(mut config: SafetyRulesConfig) -> Self {
+        let backend = config.backend.clone();
         let storage = safety_rules_manager::storage(&mut config);
 
         let verify_vote_proposal_signature = config.verify_vote_proposal_signature;
@@ -24,11 +29,11 @@ impl Process {
             SafetyRulesService::SpawnedProcess(service) => service,
             _ => panic!("Unexpected SafetyRules service: {:?}", config.service),
         };
-        let server_addr = service.server_address();
 
         Self {
             data: Some(ProcessData {
-                server_addr,
+                service: service.clone(),
+                backend,
                 storage,
                 verify_vote_proposal_signature,
             }),
@@ -37,32 +42,41 @@ impl Process {
 
     pub fn start(&mut self) {
         let data = self.data.take().expect("Unable to retrieve ProcessData");
+        let network_server = remote_service::network_server(&data.service, &data.backend)
+            .expect("Unable to create the safety rules server");
         remote_service::execute(
             data.storage,
-            data.server_addr,
+            network_server,
             data.verify_vote_proposal_signature,
         );
     }
 }
 
 struct ProcessData {
-    server_addr: SocketAddr,
+    service: RemoteServiceConfig,
+    backend: SecureBackend,
     storage: PersistentSafetyStorage,
     verify_vote_proposal_signature: bool,
 }
 
 pub struct ProcessService {
-    server_addr: SocketAddr,
+    client_config: ClientConfig,
 }
 
 impl ProcessService {
-    pub fn new(server_addr: SocketAddr) -> Self {
-        Self { server_addr }
+    pub fn new(service: RemoteServiceConfig, backend: SecureBackend) -> Result<Self, Error> {
+        Ok(Self {
+            client_config: ClientConfig::new(&service, &backend)?,
+        })
     }
 }
 
 impl RemoteService for ProcessService {
+    fn client(&self) -> SerializerClient {
+        remote_service::client(self.client_config.network_client())
+    }
+
     fn server_address(&self) -> SocketAddr {
-        self.server_addr
+        self.client_config.server_address()
     }
 }
@@ -32,7 +32,7 @@ impl ProcessClientWrapper {
         let server_port = utils::get_available_port();
         let server_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port).into();
 
-        let remote_service = RemoteService { server_address };
+        let remote_service = RemoteService::new(server_address);
         let mut config = NodeConfig::random().consensus.safety_rules;
         let test_config = config.test.as_mut().unwrap();
         let author = test_config.author;
@@ -58,7 +58,7 @@ impl ProcessClientWrapper {
             .clone()
             .take_private();
 
-        let safety_rules_manager = SafetyRulesManager::new(&mut config);
+        let safety_rules_manager = SafetyRulesManager::new(&mut config).unwrap();
         let safety_rules = safety_rules_manager.client();
 
         Self {
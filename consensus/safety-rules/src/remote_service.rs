@@ -6,28 +6,129 @@ use crate::{
     serializer::{SafetyRulesInput, SerializerClient, SerializerService, TSerializerClient},
     Error, SafetyRules,
 };
+use libra_config::config::{RemoteService as RemoteServiceConfig, SecureBackend};
+use libra_crypto::{x25519, ValidCryptoMaterial};
 use libra_logger::warn;
 use libra_secure_net::{NetworkClient, NetworkServer};
-use std::net::SocketAddr;
+use libra_secure_storage::{CryptoStorage, Storage};
+use std::{convert::TryInto, net::SocketAddr, time::Duration};
+
+/// The number of times connecting to the server is attempted before a request returns an error.
+/// Requests themselves are never retried: once sent, the server may have acted upon them, e.g.,
+/// signed a vote.
+const CONNECT_ATTEMPTS: usize = 3;
 
 pub trait RemoteService {
     fn client(&self) -> SerializerClient {
         let network_client = NetworkClient::new(self.server_address());
-        let service = Box::new(RemoteClient::new(network_client));
-        SerializerClient::new_client(service)
+        client(network_client)
     }
 
     fn server_address(&self) -> SocketAddr;
 }
 
+pub fn client(network_client: NetworkClient) -> SerializerClient {
+    let service = Box::new(RemoteClient::new(network_client));
+    SerializerClient::new_client(service)
+}
+
+/// The resolved configuration of a client for a remote service. Resolving the server address and
+/// loading the authentication key happens once, so that clients are created without failing.
+pub struct ClientConfig {
+    server_addr: SocketAddr,
+    noise: Option<([u8; x25519::PRIVATE_KEY_SIZE], x25519::PublicKey)>,
+    timeout: Option<Duration>,
+}
+
+impl ClientConfig {
+    /// If authentication is configured, clients only talk to a server holding the configured
+    /// server key.
+    pub fn new(config: &RemoteServiceConfig, backend: &SecureBackend) -> Result<Self, Error> {
+        let server_addr = config
+            .server_address()
+            .map_err(|e| Error::InternalError(e.to_string()))?;
+        let noise = match &config.authentication {
+            Some(authentication) => {
+                let key = noise_key(backend, &authentication.key_name)?;
+                let key = key
+                    .to_bytes()
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::InternalError("Invalid x25519 private key".into()))?;
+                Some((key, authentication.server_public_key))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            server_addr,
+            noise,
+            timeout: config.timeout(),
+        })
+    }
+
+    pub fn server_address(&self) -> SocketAddr {
+        self.server_addr
+    }
+
+    pub fn network_client(&self) -> NetworkClient {
+        let network_client = match &self.noise {
+            Some((key, server_public_key)) => NetworkClient::new_with_noise(
+                self.server_addr,
+                x25519::PrivateKey::from(*key),
+                *server_public_key,
+            ),
+            None => NetworkClient::new(self.server_addr),
+        };
+
+        match self.timeout {
+            Some(timeout) => network_client.with_timeout(timeout),
+            None => network_client,
+        }
+    }
+}
+
+/// Creates a server for the given service. If authentication is configured, only clients holding
+/// a key for one of the trusted clients are accepted.
+pub fn network_server(
+    config: &RemoteServiceConfig,
+    backend: &SecureBackend,
+) -> Result<NetworkServer, Error> {
+    let listen_addr = config
+        .server_address()
+        .map_err(|e| Error::InternalError(e.to_string()))?;
+    let network_server = match &config.authentication {
+        Some(authentication) => NetworkServer::new_with_noise(
+            listen_addr,
+            noise_key(backend, &authentication.key_name)?,
+            authentication.trusted_clients.clone(),
+        ),
+        None => NetworkServer::new(listen_addr),
+    };
+
+    Ok(match config.timeout() {
+        Some(timeout) => network_server.with_timeout(timeout),
+        None => network_server,
+    })
+}
+
+/// Loads the Ed25519 key stored under key_name and converts it into a Noise static key.
+fn noise_key(backend: &SecureBackend, key_name: &str) -> Result<x25519::PrivateKey, Error> {
+    let storage: Storage = backend.into();
+    let key = storage
+        .export_private_key(key_name)
+        .map_err(|e| Error::InternalError(e.to_string()))?;
+    x25519::PrivateKey::from_ed25519_private_bytes(&key.to_bytes())
+        .map_err(|e| Error::InternalError(e.to_string()))
+}
+
 pub fn execute(
     storage: PersistentSafetyStorage,
-    listen_addr: SocketAddr,
+    mut network_server: NetworkServer,
     verify_vote_proposal_signature: bool,
 ) {
     let safety_rules = SafetyRules::new(storage, verify_vote_proposal_signature);
     let mut serializer_service = SerializerService::new(safety_rules);
-    let mut network_server = NetworkServer::new(listen_addr);
 
     loop {
         if let Err(e) = process_one_message(&mut network_server, &mut serializer_service) {
@@ -54,13 +155,27 @@ impl RemoteClient {
     pub fn new(network_client: NetworkClient) -> Self {
         Self { network_client }
     }
+
+    fn process_one_message(&mut self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        self.network_client.write(input)?;
+        let result = self.network_client.read()?;
+        Ok(result)
+    }
 }
 
 impl TSerializerClient for RemoteClient {
     fn request(&mut self, input: SafetyRulesInput) -> Result<Vec<u8>, Error> {
         let input_message = lcs::to_bytes(&input)?;
-        self.network_client.write(&input_message)?;
-        let result = self.network_client.read()?;
-        Ok(result)
+        let mut attempt = 1;
+        loop {
+            match self.network_client.connect() {
+                Err(e) if attempt < CONNECT_ATTEMPTS => {
+                    warn!("Connection attempt {} failed, retrying: {}", attempt, e);
+                    attempt += 1;
+                }
+                result => break result?,
+            }
+        }
+        self.process_one_message(&input_message)
     }
 }
@@ -5,21 +5,20 @@ use crate::{
     local_client::LocalClient,
     persistent_safety_storage::PersistentSafetyStorage,
     process::ProcessService,
-    remote_service::RemoteService,
+    remote_service::RemoteService as _,
     serializer::{SerializerClient, SerializerService},
     spawned_process::SpawnedProcess,
     thread::ThreadService,
-    SafetyRules, TSafetyRules,
+    Error, SafetyRules, TSafetyRules,
 };
 use libra_config::{
-    config::{SafetyRulesConfig, SafetyRulesService},
+    config::{RemoteService, SafetyRulesConfig, SafetyRulesService, SecureBackend},
     keys::KeyPair,
 };
 use libra_crypto::ed25519::Ed25519PrivateKey;
 use libra_secure_storage::{KVStorage, Storage};
 use std::{
     convert::TryInto,
-    net::SocketAddr,
     sync::{Arc, RwLock},
 };
 
@@ -78,23 +77,27 @@ pub struct SafetyRulesManager {
 }
 
 impl SafetyRulesManager {
-    pub fn new(config: &mut SafetyRulesConfig) -> Self {
+    /// Fails if a remote service is configured but its client cannot be set up, e.g., the server
+    /// address does not resolve or the authentication key cannot be loaded.
+    pub fn new(config: &mut SafetyRulesConfig) -> Result<Self, Error> {
         match &config.service {
-            SafetyRulesService::Process(conf) => return Self::new_process(conf.server_address()),
+            SafetyRulesService::Process(conf) => {
+                return Self::new_process(conf.clone(), config.backend.clone())
+            }
             SafetyRulesService::SpawnedProcess(_) => return Self::new_spawned_process(config),
             _ => (),
         };
 
         let storage = storage(config);
         let verify_vote_proposal_signature = config.verify_vote_proposal_signature;
-        match config.service {
+        Ok(match config.service {
             SafetyRulesService::Local => Self::new_local(storage, verify_vote_proposal_signature),
             SafetyRulesService::Serializer => {
                 Self::new_serializer(storage, verify_vote_proposal_signature)
             }
             SafetyRulesService::Thread => Self::new_thread(storage, verify_vote_proposal_signature),
             _ => panic!("Unimplemented SafetyRulesService: {:?}", config.service),
-        }
+        })
     }
 
     pub fn new_local(
@@ -107,11 +110,11 @@ impl SafetyRulesManager {
         }
     }
 
-    pub fn new_process(server_addr: SocketAddr) -> Self {
-        let process_service = ProcessService::new(server_addr);
-        Self {
+    pub fn new_process(service: RemoteService, backend: SecureBackend) -> Result<Self, Error> {
+        let process_service = ProcessService::new(service, backend)?;
+        Ok(Self {
             internal_safety_rules: SafetyRulesWrapper::Process(process_service),
-        }
+        })
     }
 
     pub fn new_serializer(
@@ -127,11 +130,11 @@ impl SafetyRulesManager {
         }
     }
 
-    pub fn new_spawned_process(config: &SafetyRulesConfig) -> Self {
-        let process = SpawnedProcess::new(config);
-        Self {
+    pub fn new_spawned_process(config: &SafetyRulesConfig) -> Result<Self, Error> {
+        let process = SpawnedProcess::new(config)?;
+        Ok(Self {
             internal_safety_rules: SafetyRulesWrapper::SpawnedProcess(process),
-        }
+        })
     }
 
     pub fn new_thread(
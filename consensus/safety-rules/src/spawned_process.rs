@@ -1,7 +1,11 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::remote_service::RemoteService;
+use crate::{
+    remote_service::{self, ClientConfig, RemoteService},
+    serializer::SerializerClient,
+    Error,
+};
 
 use libra_config::config::{PersistableConfig, SafetyRulesConfig, SafetyRulesService};
 use libra_temppath::TempPath;
@@ -9,35 +13,40 @@ use std::{net::SocketAddr, process::Child};
 
 pub struct SpawnedProcess {
     handle: Child,
-    server_addr: SocketAddr,
+    client_config: ClientConfig,
     _config_path: TempPath,
 }
 
 impl SpawnedProcess {
-    pub fn new(config: &SafetyRulesConfig) -> Self {
+    pub fn new(config: &SafetyRulesConfig) -> Result<Self, Error> {
         let mut config_path = TempPath::new();
         config_path.persist();
         config_path.create_as_file().unwrap();
         config.save_config(&config_path).unwrap();
 
         let service = &config.service;
-        let server_addr = if let SafetyRulesService::SpawnedProcess(process_config) = service {
-            process_config.server_address()
+        let service = if let SafetyRulesService::SpawnedProcess(process_config) = service {
+            process_config
         } else {
             panic!("Invalid SafeRulesService, expected SpawnedProcess.");
         };
+        let client_config = ClientConfig::new(service, &config.backend)?;
 
-        Self {
+        Ok(Self {
             handle: runner::run(&config_path.path()),
-            server_addr,
+            client_config,
             _config_path: config_path,
-        }
+        })
     }
 }
 
 impl RemoteService for SpawnedProcess {
+    fn client(&self) -> SerializerClient {
+        remote_service::client(self.client_config.network_client())
+    }
+
     fn server_address(&self) -> SocketAddr {
-        self.server_addr
+        self.client_config.server_address()
     }
 }
 
@@ -1,8 +1,20 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{test_utils, SafetyRulesManager};
+use crate::{remote_service, test_utils, SafetyRulesManager, TSafetyRules};
+use libra_config::{
+    config::{RemoteService, SafetyRulesConfig, SafetyRulesService},
+    utils,
+};
+use libra_crypto::{test_utils::TEST_SEED, x25519, PrivateKey, Uniform};
+use libra_secure_net::{NetworkClient, NetworkServer};
 use libra_types::validator_signer::ValidatorSigner;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    thread,
+    time::Duration,
+};
 
 #[test]
 fn test_reconnect() {
@@ -15,3 +27,42 @@ fn test_reconnect() {
     let state1 = safety_rules_manager.client().consensus_state().unwrap();
     assert_eq!(state0, state1);
 }
+
+#[test]
+fn test_noise_authentication() {
+    let mut rng = StdRng::from_seed(TEST_SEED);
+    let server_key = x25519::PrivateKey::generate(&mut rng);
+    let server_public_key = server_key.public_key();
+    let trusted_key = x25519::PrivateKey::generate(&mut rng);
+    let untrusted_key = x25519::PrivateKey::generate(&mut rng);
+    let timeout = Duration::from_secs(5);
+
+    let signer = ValidatorSigner::from_int(0);
+    let storage = test_utils::test_storage(&signer);
+    let server_port = utils::get_available_port();
+    let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+    let network_server =
+        NetworkServer::new_with_noise(server_addr, server_key, vec![trusted_key.public_key()])
+            .with_timeout(timeout);
+    thread::spawn(move || remote_service::execute(storage, network_server, false));
+
+    let mut untrusted_client = remote_service::client(
+        NetworkClient::new_with_noise(server_addr, untrusted_key, server_public_key)
+            .with_timeout(timeout),
+    );
+    assert!(untrusted_client.consensus_state().is_err());
+
+    let mut trusted_client = remote_service::client(
+        NetworkClient::new_with_noise(server_addr, trusted_key, server_public_key)
+            .with_timeout(timeout),
+    );
+    trusted_client.consensus_state().unwrap();
+}
+
+#[test]
+fn test_unresolvable_server_address() {
+    let mut config = SafetyRulesConfig::default();
+    let service = RemoteService::new("/dns4/libra.invalid/tcp/6191".parse().unwrap());
+    config.service = SafetyRulesService::Process(service);
+    assert!(SafetyRulesManager::new(&mut config).is_err());
+}
@@ -12,6 +12,7 @@ use crate::{
     remote_service::{self, RemoteService},
 };
 use libra_config::utils;
+use libra_secure_net::NetworkServer;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     thread::{self, JoinHandle},
@@ -31,7 +32,8 @@ impl ThreadService {
         let server_addr = listen_addr;
 
         let child = thread::spawn(move || {
-            remote_service::execute(storage, listen_addr, verify_vote_proposal_signature)
+            let network_server = NetworkServer::new(listen_addr);
+            remote_service::execute(storage, network_server, verify_vote_proposal_signature)
         });
 
         Self {
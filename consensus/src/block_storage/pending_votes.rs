@@ -200,20 +200,31 @@ impl PendingVotes {
 
         Ok(())
     }
-}
 
-impl fmt::Display for PendingVotes {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (votes, timeout) = (
+    /// Returns the authors of the pending votes grouped by LedgerInfo digest, as well as the
+    /// authors of the pending timeout signatures grouped by round.
+    pub fn summary(
+        &self,
+    ) -> (
+        BTreeMap<HashValue, Vec<Author>>,
+        BTreeMap<Round, Vec<Author>>,
+    ) {
+        (
             self.li_digest_to_votes
                 .iter()
-                .map(|(hash, li)| (hash, li.signatures().keys().collect::<Vec<_>>()))
-                .collect::<BTreeMap<_, _>>(),
+                .map(|(hash, li)| (*hash, li.signatures().keys().cloned().collect()))
+                .collect(),
             self.round_to_tc
                 .iter()
-                .map(|(round, tc)| (round, tc.signatures().keys().collect::<Vec<_>>()))
-                .collect::<BTreeMap<_, _>>(),
-        );
+                .map(|(round, tc)| (*round, tc.signatures().keys().cloned().collect()))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for PendingVotes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (votes, timeout) = self.summary();
         write!(f, "PendingVotes: [")?;
         for (hash, authors) in votes {
             write!(f, "LI {} has {} votes {:?} ", hash, authors.len(), authors)?;
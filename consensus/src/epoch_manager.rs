@@ -15,7 +15,9 @@ use crate::{
     network::{IncomingBlockRetrievalRequest, NetworkReceivers, NetworkSender},
     network_interface::{ConsensusMsg, ConsensusNetworkSender},
     persistent_liveness_storage::{LedgerRecoveryData, PersistentLivenessStorage, RecoveryData},
-    round_manager::{RecoveryManager, RoundManager, UnverifiedEvent, VerifiedEvent},
+    round_manager::{
+        ConsensusDebugState, RecoveryManager, RoundManager, UnverifiedEvent, VerifiedEvent,
+    },
     state_replication::{StateComputer, TxnManager},
     util::time_service::TimeService,
 };
@@ -25,7 +27,8 @@ use consensus_types::{
     common::{Author, Round},
     epoch_retrieval::EpochRetrievalRequest,
 };
-use futures::{select, StreamExt};
+use debug_interface::admin;
+use futures::{channel::mpsc, select, StreamExt};
 use libra_config::config::{ConsensusConfig, ConsensusProposerType, NodeConfig};
 use libra_logger::prelude::*;
 use libra_metrics::monitor;
@@ -39,6 +42,12 @@ use network::protocols::network::Event;
 use safety_rules::{SafetyRulesManager, TSafetyRules};
 use std::{cmp::Ordering, sync::Arc, time::Duration};
 
+/// Bounds the time the debug interface waits for the main loop to report the consensus state.
+const DEBUG_STATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A request for the state of the RoundManager, answered by the main loop.
+type DebugStateRequest = std::sync::mpsc::Sender<Option<ConsensusDebugState>>;
+
 /// RecoveryManager is used to process events in order to sync up with peer if we can't recover from local consensusdb
 /// RoundManager is used for normal event handling.
 /// We suppress clippy warning here because we expect most of the time we will have RoundManager
@@ -93,7 +102,9 @@ impl EpochManager {
         let author = node_config.validator_network.as_ref().unwrap().peer_id();
         let config = node_config.consensus.clone();
         let sr_config = &mut node_config.consensus.safety_rules;
-        let safety_rules_manager = SafetyRulesManager::new(sr_config);
+        // Fail fast on a misconfigured remote safety rules service rather than upon the first epoch
+        let safety_rules_manager = SafetyRulesManager::new(sr_config)
+            .unwrap_or_else(|e| panic!("Unable to set up safety rules: {}", e));
         Self {
             author,
             config,
@@ -474,12 +485,35 @@ impl EpochManager {
         }
     }
 
+    fn process_debug_state_request(&self, request: DebugStateRequest) {
+        let debug_state = match &self.processor {
+            Some(RoundProcessor::Normal(p)) => Some(p.debug_state()),
+            _ => None,
+        };
+        // The debug interface may have given up waiting
+        let _ = request.send(debug_state);
+    }
+
     pub async fn start(
         mut self,
         mut round_timeout_sender_rx: channel::Receiver<Round>,
         mut network_receivers: NetworkReceivers,
         mut reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
     ) {
+        // The state is only computed when the debug interface asks for it
+        let (debug_state_sender, mut debug_state_requests) = mpsc::channel(1);
+        admin::register_handler("consensus_state", move |_params| {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            debug_state_sender
+                .clone()
+                .try_send(sender)
+                .map_err(|e| anyhow!("Unable to request the consensus state: {}", e))?;
+            let debug_state = receiver
+                .recv_timeout(DEBUG_STATE_TIMEOUT)
+                .map_err(|e| anyhow!("Consensus did not report its state: {}", e))?;
+            Ok(serde_json::to_value(debug_state)?)
+        });
+
         // initial start of the processor
         if let Some(payload) = reconfig_events.next().await {
             self.start_processor(payload).await;
@@ -501,6 +535,10 @@ impl EpochManager {
                     round = round_timeout_sender_rx.select_next_some() => {
                         monitor!("process_local_timeout", self.process_local_timeout(round).await)
                     }
+                    request = debug_state_requests.select_next_some() => {
+                        self.process_debug_state_request(request);
+                        Ok(())
+                    }
                 }
             ) {
                 counters::ERROR_COUNT.inc();
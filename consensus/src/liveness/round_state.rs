@@ -202,6 +202,16 @@ impl RoundState {
         self.current_round
     }
 
+    /// Return the highest committed round.
+    pub fn highest_committed_round(&self) -> Round {
+        self.highest_committed_round
+    }
+
+    /// Return the votes that have not yet formed a certificate.
+    pub fn pending_votes(&self) -> &PendingVotes {
+        &self.pending_votes
+    }
+
     /// Returns deadline for current round
     pub fn current_round_deadline(&self) -> Duration {
         self.current_round_deadline
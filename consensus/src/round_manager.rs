@@ -29,14 +29,30 @@ use consensus_types::{
     vote_msg::VoteMsg,
 };
 use debug_interface::prelude::*;
+use libra_crypto::HashValue;
 use libra_logger::prelude::*;
 use libra_types::{epoch_state::EpochState, validator_verifier::ValidatorVerifier};
 #[cfg(test)]
 use safety_rules::ConsensusState;
 use safety_rules::TSafetyRules;
-use std::{sync::Arc, time::Duration};
+use serde::Serialize;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 use termion::color::*;
 
+/// Point in time view of the RoundManager, exposed via the debug interface.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsensusDebugState {
+    pub epoch: u64,
+    pub current_round: Round,
+    pub highest_committed_round: Round,
+    pub highest_quorum_cert: QuorumCert,
+    /// Authors of the pending votes grouped by LedgerInfo digest
+    pub pending_votes: BTreeMap<HashValue, Vec<Author>>,
+    /// Authors of the pending timeout signatures grouped by round
+    pub pending_timeouts: BTreeMap<Round, Vec<Author>>,
+    pub vote_sent: Option<Vote>,
+}
+
 pub enum UnverifiedEvent {
     ProposalMsg(Box<ProposalMsg>),
     VoteMsg(Box<VoteMsg>),
@@ -260,6 +276,8 @@ impl RoundManager {
             .await?;
         let signed_proposal = self.safety_rules.sign_proposal(proposal)?;
         self.txn_manager.trace_transactions(&signed_proposal);
+        // Every validator derives the same context from the block id, so no need to send it along.
+        start_trace!({"block", signed_proposal.id()});
         trace_edge!("parent_proposal", {"block", signed_proposal.parent_id()}, {"block", signed_proposal.id()});
         trace_event!("round_manager::generate_proposal", {"block", signed_proposal.id()});
         debug!("Propose {}", signed_proposal);
@@ -701,4 +719,17 @@ impl RoundManager {
     pub fn round_state(&self) -> &RoundState {
         &self.round_state
     }
+
+    pub fn debug_state(&self) -> ConsensusDebugState {
+        let (pending_votes, pending_timeouts) = self.round_state.pending_votes().summary();
+        ConsensusDebugState {
+            epoch: self.epoch_state.epoch,
+            current_round: self.round_state.current_round(),
+            highest_committed_round: self.round_state.highest_committed_round(),
+            highest_quorum_cert: self.block_store.highest_quorum_cert().as_ref().clone(),
+            pending_votes,
+            pending_timeouts,
+            vote_sent: self.round_state.vote_sent(),
+        }
+    }
 }
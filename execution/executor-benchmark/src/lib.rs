@@ -21,7 +21,7 @@ use libra_types::{
         authenticator::AuthenticationKey, RawTransaction, Script, SignedTransaction, Transaction,
     },
 };
-use libra_vm::LibraVM;
+use libra_vm::{LibraVM, ParallelLibraVM, VMExecutor};
 use libradb::LibraDB;
 use rand::{rngs::StdRng, SeedableRng};
 use std::{
@@ -216,15 +216,15 @@ impl TransactionGenerator {
     }
 }
 
-struct TransactionExecutor {
-    executor: Executor<LibraVM>,
+struct TransactionExecutor<V> {
+    executor: Executor<V>,
     parent_block_id: HashValue,
     block_receiver: mpsc::Receiver<Vec<Transaction>>,
 }
 
-impl TransactionExecutor {
+impl<V: VMExecutor> TransactionExecutor<V> {
     fn new(
-        executor: Executor<LibraVM>,
+        executor: Executor<V>,
         parent_block_id: HashValue,
         block_receiver: mpsc::Receiver<Vec<Transaction>>,
     ) -> Self {
@@ -289,9 +289,9 @@ impl TransactionExecutor {
     }
 }
 
-fn create_storage_service_and_executor(
+fn create_storage_service_and_executor<V: VMExecutor>(
     config: &NodeConfig,
-) -> (Arc<dyn DbReader>, Executor<LibraVM>) {
+) -> (Arc<dyn DbReader>, Executor<V>) {
     let (db, db_rw) = DbReaderWriter::wrap(
         LibraDB::open(
             &config.storage.dir(),
@@ -308,20 +308,48 @@ fn create_storage_service_and_executor(
     (db, executor)
 }
 
-/// Runs the benchmark with given parameters.
+/// Runs the benchmark with given parameters. With `parallel_execution` the user transactions of
+/// each block are executed speculatively in parallel by the `ParallelLibraVM`.
 pub fn run_benchmark(
     num_accounts: usize,
     init_account_balance: u64,
     block_size: usize,
     num_transfer_blocks: usize,
     db_dir: Option<PathBuf>,
+    parallel_execution: bool,
+) {
+    if parallel_execution {
+        run_benchmark_with::<ParallelLibraVM>(
+            num_accounts,
+            init_account_balance,
+            block_size,
+            num_transfer_blocks,
+            db_dir,
+        )
+    } else {
+        run_benchmark_with::<LibraVM>(
+            num_accounts,
+            init_account_balance,
+            block_size,
+            num_transfer_blocks,
+            db_dir,
+        )
+    }
+}
+
+fn run_benchmark_with<V: VMExecutor + 'static>(
+    num_accounts: usize,
+    init_account_balance: u64,
+    block_size: usize,
+    num_transfer_blocks: usize,
+    db_dir: Option<PathBuf>,
 ) {
     let (mut config, genesis_key) = config_builder::test_config();
     if let Some(path) = db_dir {
         config.storage.dir = path;
     }
 
-    let (db, executor) = create_storage_service_and_executor(&config);
+    let (db, executor) = create_storage_service_and_executor::<V>(&config);
     let parent_block_id = executor.committed_block_id();
 
     let (block_sender, block_receiver) = mpsc::sync_channel(50 /* bound */);
@@ -385,12 +413,25 @@ fn create_transaction(
 mod tests {
     #[test]
     fn test_benchmark() {
+        super::run_benchmark(
+            25,    /* num_accounts */
+            10,    /* init_account_balance */
+            5,     /* block_size */
+            5,     /* num_transfer_blocks */
+            None,  /* db_dir */
+            false, /* parallel_execution */
+        );
+    }
+
+    #[test]
+    fn test_parallel_benchmark() {
         super::run_benchmark(
             25,   /* num_accounts */
             10,   /* init_account_balance */
             5,    /* block_size */
             5,    /* num_transfer_blocks */
             None, /* db_dir */
+            true, /* parallel_execution */
         );
     }
 }
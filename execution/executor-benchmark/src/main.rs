@@ -20,6 +20,10 @@ struct Opt {
 
     #[structopt(long, parse(from_os_str))]
     db_dir: Option<PathBuf>,
+
+    /// Execute the user transactions of each block speculatively in parallel.
+    #[structopt(long)]
+    parallel_execution: bool,
 }
 
 fn main() {
@@ -38,5 +42,6 @@ fn main() {
         opt.block_size,
         opt.num_transfer_blocks,
         opt.db_dir,
+        opt.parallel_execution,
     );
 }
@@ -211,6 +211,7 @@ where
             if next_epoch_state.is_some() {
                 txn_data.push(TransactionData::new(
                     HashMap::new(),
+                    WriteSet::default(),
                     vec![],
                     TransactionStatus::Retry,
                     Arc::clone(&current_state_tree),
@@ -270,6 +271,7 @@ where
 
             txn_data.push(TransactionData::new(
                 blobs,
+                vm_output.write_set().clone(),
                 vm_output.events().to_vec(),
                 vm_output.status().clone(),
                 Arc::clone(&state_tree),
@@ -517,7 +519,7 @@ impl<V: VMExecutor> ChunkExecutor for Executor<V> {
         );
         let vm_outputs = {
             let _timer = OP_COUNTERS.timer("vm_execute_chunk_time_s");
-            V::execute_block(transactions.to_vec(), &state_view)?
+            V::execute_block_with_sync_view(transactions.to_vec(), &state_view)?
         };
 
         // Since other validators have committed these transactions, their status should all be
@@ -541,6 +543,7 @@ impl<V: VMExecutor> ChunkExecutor for Executor<V> {
         // Since we have verified the proofs, we just need to verify that each TransactionInfo
         // object matches what we have computed locally.
         let mut txns_to_commit = vec![];
+        let mut write_sets_to_commit = vec![];
         let mut reconfig_events = vec![];
         for ((txn, txn_data), (i, txn_info)) in itertools::zip_eq(
             itertools::zip_eq(transactions, output.transaction_data()),
@@ -565,6 +568,7 @@ impl<V: VMExecutor> ChunkExecutor for Executor<V> {
                 txn_data.gas_used(),
                 txn_data.status().vm_status().major_status,
             ));
+            write_sets_to_commit.push(txn_data.write_set());
             reconfig_events.append(&mut Self::extract_reconfig_events(
                 txn_data.events().to_vec(),
             ));
@@ -580,6 +584,7 @@ impl<V: VMExecutor> ChunkExecutor for Executor<V> {
             first_version,
             ledger_info_to_commit.as_ref(),
         )?;
+        V::on_transactions_committed(&write_sets_to_commit);
 
         let output_trees = output.executed_trees().clone();
         if let Some(ledger_info_with_sigs) = &ledger_info_to_commit {
@@ -668,7 +673,8 @@ impl<V: VMExecutor> BlockExecutor for Executor<V> {
             let vm_outputs = {
                 trace_code_block!("executor::execute_block", {"block", block_id});
                 let _timer = OP_COUNTERS.timer("vm_execute_block_time_s");
-                V::execute_block(transactions.clone(), &state_view).map_err(anyhow::Error::from)?
+                V::execute_block_with_sync_view(transactions.clone(), &state_view)
+                    .map_err(anyhow::Error::from)?
             };
 
             trace_code_block!("executor::process_vm_outputs", {"block", block_id});
@@ -721,6 +727,7 @@ impl<V: VMExecutor> BlockExecutor for Executor<V> {
         // transactions in A, B and C whose status == TransactionStatus::Keep.
         // This must be done before calculate potential skipping of transactions in idempotent commit.
         let mut txns_to_keep = vec![];
+        let mut write_sets_to_keep = vec![];
         let arc_blocks = block_ids
             .iter()
             .map(|id| self.cache.get_block(id))
@@ -740,6 +747,7 @@ impl<V: VMExecutor> BlockExecutor for Executor<V> {
                     txn_data.gas_used(),
                     txn_data.status().vm_status().major_status,
                 ));
+                write_sets_to_keep.push(txn_data.write_set());
             }
         }
 
@@ -802,6 +810,7 @@ impl<V: VMExecutor> BlockExecutor for Executor<V> {
                 Some(&ledger_info_with_sigs),
             )?;
         }
+        V::on_transactions_committed(&write_sets_to_keep[num_txns_to_skip as usize..]);
 
         // Prune the tree.
         for block in blocks {
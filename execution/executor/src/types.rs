@@ -12,6 +12,7 @@ use libra_types::{
     epoch_state::EpochState,
     proof::accumulator::InMemoryAccumulator,
     transaction::{TransactionStatus, Version},
+    write_set::WriteSet,
 };
 use scratchpad::SparseMerkleTree;
 use std::{collections::HashMap, sync::Arc};
@@ -26,6 +27,9 @@ pub struct TransactionData {
     /// new blob.
     account_blobs: HashMap<AccountAddress, AccountStateBlob>,
 
+    /// The write set of this transaction, handed to the VM once the transaction is committed.
+    write_set: WriteSet,
+
     /// The list of events emitted during this transaction.
     events: Vec<ContractEvent>,
 
@@ -50,6 +54,7 @@ pub struct TransactionData {
 impl TransactionData {
     pub fn new(
         account_blobs: HashMap<AccountAddress, AccountStateBlob>,
+        write_set: WriteSet,
         events: Vec<ContractEvent>,
         status: TransactionStatus,
         state_tree: Arc<SparseMerkleTree>,
@@ -59,6 +64,7 @@ impl TransactionData {
     ) -> Self {
         TransactionData {
             account_blobs,
+            write_set,
             events,
             status,
             state_tree,
@@ -72,6 +78,10 @@ impl TransactionData {
         &self.account_blobs
     }
 
+    pub fn write_set(&self) -> &WriteSet {
+        &self.write_set
+    }
+
     pub fn events(&self) -> &[ContractEvent] {
         &self.events
     }
@@ -5,8 +5,8 @@
 use crate::{
     errors::JsonRpcError,
     views::{
-        AccountStateWithProofView, AccountView, BlockMetadata, CurrencyInfoView, EventView,
-        StateProofView, TransactionView,
+        AccountStateWithProofView, AccountView, BlockMetadata, BytesView, CurrencyInfoView,
+        EventDataView, EventView, ResourceView, StateProofView, TransactionView,
     },
 };
 use anyhow::{ensure, format_err, Error, Result};
@@ -20,6 +20,7 @@ use libra_types::{
     account_address::AccountAddress,
     account_config::{from_currency_code_string, CurrencyInfoResource},
     account_state::AccountState,
+    contract_event::ContractEvent,
     event::EventKey,
     ledger_info::LedgerInfoWithSignatures,
     mempool_status::MempoolStatusCode,
@@ -28,9 +29,10 @@ use libra_types::{
     transaction::SignedTransaction,
 };
 use network::counters;
+use resource_viewer::MoveValueAnnotator;
 use serde_json::Value;
 use std::{collections::HashMap, convert::TryFrom, ops::Deref, pin::Pin, str::FromStr, sync::Arc};
-use storage_interface::DbReader;
+use storage_interface::{state_view::DbStateView, DbReader};
 
 #[derive(Clone)]
 pub(crate) struct JsonRpcService {
@@ -79,6 +81,7 @@ impl JsonRpcRequest {
 async fn submit(mut service: JsonRpcService, request: JsonRpcRequest) -> Result<()> {
     let txn_payload: String = serde_json::from_value(request.get_param(0))?;
     let transaction: SignedTransaction = lcs::from_bytes(&hex::decode(txn_payload)?)?;
+    start_trace!({"txn", transaction.sender(), transaction.sequence_number()});
     trace_code_block!("json-rpc::submit", {"txn", transaction.sender(), transaction.sequence_number()});
 
     let (req_sender, callback) = oneshot::channel();
@@ -125,6 +128,48 @@ async fn get_account_state(
     Ok(None)
 }
 
+/// Returns the resources of the account at the given address, as of the given version or by
+/// default the latest one. Resources are decoded to JSON where their types are known: those of the
+/// standard library, and those declared by the modules the account publishes.
+async fn get_account_resources(
+    service: JsonRpcService,
+    request: JsonRpcRequest,
+) -> Result<Option<Vec<ResourceView>>> {
+    let address: String = serde_json::from_value(request.get_param(0))?;
+    let account_address = AccountAddress::from_str(&address)?;
+    let version =
+        serde_json::from_value::<u64>(request.get_param(1)).unwrap_or_else(|_| request.version());
+    ensure!(
+        version <= request.version(),
+        "version {} is greater than the latest ledger version {}",
+        version,
+        request.version()
+    );
+
+    let blob = match service
+        .db
+        .get_account_state_by_version(account_address, version)?
+    {
+        Some(blob) => blob,
+        None => return Ok(None),
+    };
+    let account_state = AccountState::try_from(&blob)?;
+    let state_view = DbStateView::new(service.db.clone(), version);
+    let annotator = MoveValueAnnotator::new(&state_view);
+    let mut resources = vec![];
+    for (path, resource) in annotator.view_account_resources(&account_state)? {
+        let raw = account_state
+            .get(&path)
+            .ok_or_else(|| format_err!("Missing resource at path {}", hex::encode(&path)))?;
+        resources.push(ResourceView {
+            value: resource.map(|r| serde_json::to_value(&r)).transpose()?,
+            raw: BytesView::from(raw),
+            path: BytesView::from(&path),
+        });
+    }
+    Ok(Some(resources))
+}
+
 /// Returns the blockchain metadata for a specified version. If no version is specified, default to
 /// returning the current blockchain metadata
 /// Can be used to verify that target Full Node is up-to-date
@@ -162,6 +207,8 @@ async fn get_transactions(
 
     let mut result = vec![];
 
+    let state_view = DbStateView::new(service.db.clone(), request.version());
+    let mut event_decoder = EventDecoder::new(&state_view);
     let all_events = if include_events {
         txs.events
             .ok_or_else(|| format_err!("Storage layer didn't return events when requested!"))?
@@ -176,13 +223,13 @@ async fn get_transactions(
 
     for (v, (tx, info)) in txs_with_info.enumerate() {
         let events = if include_events {
-            all_events
+            let events = all_events
                 .get(v)
                 .ok_or_else(|| format_err!("Missing events for version: {}", v))?
                 .iter()
                 .cloned()
-                .map(|x| (start_version + v as u64, x).into())
-                .collect()
+                .map(|x| (start_version + v as u64, x));
+            event_decoder.views(events)
         } else {
             vec![]
         };
@@ -227,8 +274,9 @@ async fn get_account_transaction(
             .events
             .unwrap_or_default()
             .into_iter()
-            .map(|x| ((tx_version, x).into()))
-            .collect();
+            .map(|x| (tx_version, x));
+        let state_view = DbStateView::new(service.db.clone(), request.version());
+        let events = EventDecoder::new(&state_view).views(events);
 
         Ok(Some(TransactionView {
             version: tx_version,
@@ -255,10 +303,47 @@ async fn get_events(service: JsonRpcService, request: JsonRpcRequest) -> Result<
     let req_version = request.version();
     let events = events_with_proof
         .into_iter()
-        .filter(|(version, _event)| version <= &req_version)
-        .map(|event| event.into())
-        .collect();
-    Ok(events)
+        .filter(|(version, _event)| version <= &req_version);
+    let state_view = DbStateView::new(service.db.clone(), req_version);
+    Ok(EventDecoder::new(&state_view).views(events))
+}
+
+/// Converts the events of a request to their views, decoding the data of those of types the views
+/// don't cover, as the events of the modules apps publish, with the modules published as of the
+/// version of `state_view`. One decoder serves one request, so the modules and accounts it reads
+/// are read once per request.
+struct EventDecoder<'a> {
+    state_view: &'a DbStateView,
+    // Only created once an event needs it, as it loads the standard library.
+    annotator: Option<MoveValueAnnotator<'a>>,
+}
+
+impl<'a> EventDecoder<'a> {
+    fn new(state_view: &'a DbStateView) -> Self {
+        Self {
+            state_view,
+            annotator: None,
+        }
+    }
+
+    fn views(&mut self, events: impl IntoIterator<Item = (u64, ContractEvent)>) -> Vec<EventView> {
+        events
+            .into_iter()
+            .map(|(txn_version, event)| {
+                let mut view = EventView::from((txn_version, event.clone()));
+                if let EventDataView::Unknown { value } = &mut view.data {
+                    let state_view = self.state_view;
+                    *value = self
+                        .annotator
+                        .get_or_insert_with(|| MoveValueAnnotator::new(state_view))
+                        .view_contract_event(&event)
+                        .ok()
+                        .and_then(|value| serde_json::to_value(&value).ok());
+                }
+                view
+            })
+            .collect()
+    }
 }
 
 /// Returns meta information about supported currencies
@@ -342,6 +427,7 @@ pub(crate) fn build_registry() -> RpcRegistry {
     register_rpc_method!(registry, "submit", submit, 1);
     register_rpc_method!(registry, "get_metadata", get_metadata, 1);
     register_rpc_method!(registry, "get_account_state", get_account_state, 1);
+    register_rpc_method!(registry, "get_account_resources", get_account_resources, 2);
     register_rpc_method!(registry, "get_transactions", get_transactions, 3);
     register_rpc_method!(
         registry,
@@ -19,6 +19,7 @@ use libra_proptest_helpers::ValueGenerator;
 use libra_types::{
     account_address::AccountAddress,
     account_config::AccountResource,
+    account_state::AccountState,
     account_state_blob::{AccountStateBlob, AccountStateWithProof},
     contract_event::ContractEvent,
     event::EventKey,
@@ -508,6 +509,50 @@ fn test_get_account_transaction() {
     }
 }
 
+#[test]
+fn test_get_account_resources() {
+    let (mock_db, client, mut runtime) = create_database_client_and_runtime(1);
+
+    let account = get_first_account_from_mock_db(&mock_db);
+    let blob = &mock_db.all_accounts[&account];
+    let account_state = AccountState::try_from(blob).unwrap();
+    let account_resource = AccountResource::try_from(blob).unwrap();
+
+    let mut batch = JsonRpcBatch::default();
+    batch.add_get_account_resources_request(account, None);
+    let resources = match execute_batch_and_get_first_response(&client, &mut runtime, batch) {
+        JsonRpcResponse::AccountResourcesResponse(resources) => resources.unwrap(),
+        response => panic!("unexpected response: {:?}", response),
+    };
+
+    // Every resource is returned with its bytes
+    for resource in &resources {
+        let path = resource.path.clone().into_bytes().unwrap();
+        let raw = resource.raw.clone().into_bytes().unwrap();
+        assert_eq!(account_state.get(&path), Some(&raw));
+    }
+
+    // The account resource is decoded, with its fields by name
+    let value = resources
+        .iter()
+        .filter_map(|resource| resource.value.as_ref())
+        .find(|value| value["type"]["name"] == "LibraAccount")
+        .expect("account resource wasn't decoded");
+    assert_eq!(value["is_resource"], true);
+    assert_eq!(
+        value["fields"]["sequence_number"],
+        account_resource.sequence_number()
+    );
+
+    // Accounts which don't exist have no resources
+    let mut batch = JsonRpcBatch::default();
+    batch.add_get_account_resources_request(AccountAddress::random(), None);
+    assert_eq!(
+        execute_batch_and_get_first_response(&client, &mut runtime, batch),
+        JsonRpcResponse::AccountResourcesResponse(None)
+    );
+}
+
 #[test]
 // Check that if version and ledger_version parameters are None, then the server returns the latest
 // known state.
@@ -24,7 +24,7 @@ use move_core_types::{
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, convert::TryFrom};
-use transaction_builder::get_transaction_name;
+use transaction_builder::ScriptCall;
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct AmountView {
@@ -156,8 +156,13 @@ pub enum EventDataView {
         proposer: BytesView,
         proposed_time: u64,
     },
+    /// An event of a type the views above don't cover, such as those of the modules apps publish.
+    /// `value` is the JSON of its `resource_viewer::AnnotatedMoveValue` when it can be decoded.
     #[serde(rename = "unknown")]
-    Unknown {},
+    Unknown {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<serde_json::Value>,
+    },
 }
 
 impl From<(u64, ContractEvent)> for EventView {
@@ -285,11 +290,23 @@ impl From<(u64, ContractEvent)> for EventView {
             key: BytesView::from(event.key().as_bytes()),
             sequence_number: event.sequence_number(),
             transaction_version: txn_version,
-            data: event_data.unwrap_or(EventDataView::Unknown {}),
+            data: event_data.unwrap_or(EventDataView::Unknown { value: None }),
         }
     }
 }
 
+/// A resource of an account.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ResourceView {
+    /// The path of the resource in the account.
+    pub path: BytesView,
+    /// The resource as the JSON of its `resource_viewer::AnnotatedMoveStruct`, or null if its type
+    /// isn't known.
+    pub value: Option<serde_json::Value>,
+    /// The LCS bytes of the resource.
+    pub raw: BytesView,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct BlockMetadata {
     pub version: u64,
@@ -355,7 +372,6 @@ pub enum TransactionDataView {
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "type")]
-// TODO cover all script types
 pub enum ScriptView {
     #[serde(rename = "peer_to_peer_transaction")]
     PeerToPeer {
@@ -372,12 +388,22 @@ pub enum ScriptView {
         auth_key_prefix: BytesView,
         amount: u64,
     },
+    #[serde(rename = "stdlib_script")]
+    StdlibScript {
+        name: String,
+        type_arguments: Vec<ScriptArgumentView>,
+        arguments: Vec<ScriptArgumentView>,
+    },
     #[serde(rename = "unknown_transaction")]
     Unknown {},
 }
 
-impl ScriptView {
-    // TODO cover all script types
+/// A named argument of a stdlib script. Integers and booleans are printed in decimal,
+/// addresses and byte vectors in hex, and type arguments as Move type tags.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ScriptArgumentView {
+    pub name: String,
+    pub value: String,
 }
 
 impl From<Transaction> for TransactionDataView {
@@ -437,63 +463,68 @@ impl From<AccountRole> for AccountRoleView {
 
 impl From<TransactionPayload> for ScriptView {
     fn from(value: TransactionPayload) -> Self {
-        let empty_vec: Vec<TransactionArgument> = vec![];
-        let empty_ty_vec: Vec<String> = vec![];
-        let unknown_currency = "unknown_currency".to_string();
-
-        let (code, args, ty_args) = match value {
-            TransactionPayload::WriteSet(_) => ("genesis".to_string(), empty_vec, empty_ty_vec),
-            TransactionPayload::Script(script) => (
-                get_transaction_name(script.code()),
-                script.args().to_vec(),
-                script
-                    .ty_args()
-                    .iter()
-                    .map(|type_tag| match type_tag {
-                        TypeTag::Struct(StructTag { module, .. }) => module.to_string(),
-                        tag => format!("{}", tag),
-                    })
-                    .collect(),
-            ),
-            TransactionPayload::Module(_) => {
-                ("module publishing".to_string(), empty_vec, empty_ty_vec)
+        let script = match value {
+            TransactionPayload::Script(script) => script,
+            TransactionPayload::WriteSet(_) | TransactionPayload::Module(_) => {
+                return ScriptView::Unknown {}
             }
         };
 
-        let res = match code.as_str() {
-            "peer_to_peer_with_metadata_transaction" => {
-                if let [TransactionArgument::Address(receiver), TransactionArgument::U64(amount), TransactionArgument::U8Vector(metadata), TransactionArgument::U8Vector(metadata_signature)] =
-                    &args[..]
-                {
-                    Ok(ScriptView::PeerToPeer {
-                        receiver: receiver.to_string(),
-                        amount: *amount,
-                        currency: ty_args.get(0).unwrap_or(&unknown_currency).to_string(),
-                        metadata: BytesView::from(metadata),
-                        metadata_signature: BytesView::from(metadata_signature),
+        // `decode` checks the bytecode and the arguments of the script against the ABI of a
+        // known stdlib script.
+        match ScriptCall::decode(&script) {
+            Some(ScriptCall::PeerToPeerWithMetadata {
+                token,
+                payee,
+                amount,
+                metadata,
+                metadata_signature,
+            }) => ScriptView::PeerToPeer {
+                receiver: payee.to_string(),
+                amount,
+                currency: currency_code_from_type_tag(&token),
+                metadata: BytesView::from(&metadata),
+                metadata_signature: BytesView::from(&metadata_signature),
+            },
+            Some(call) => ScriptView::StdlibScript {
+                name: call.name().to_string(),
+                type_arguments: call
+                    .type_arguments()
+                    .into_iter()
+                    .map(|(name, type_tag)| ScriptArgumentView {
+                        name: name.to_string(),
+                        value: type_tag.to_string(),
                     })
-                } else {
-                    Err(format_err!("Unable to parse PeerToPeer arguments"))
-                }
-            }
-            "mint" => {
-                if let [TransactionArgument::Address(receiver), TransactionArgument::U8Vector(auth_key_prefix), TransactionArgument::U64(amount)] =
-                    &args[..]
-                {
-                    let currency = ty_args.get(0).unwrap_or(&unknown_currency).to_string();
-                    Ok(ScriptView::Mint {
-                        receiver: receiver.to_string(),
-                        auth_key_prefix: BytesView::from(auth_key_prefix),
-                        amount: *amount,
-                        currency,
+                    .collect(),
+                arguments: call
+                    .arguments()
+                    .into_iter()
+                    .map(|(name, value)| ScriptArgumentView {
+                        name: name.to_string(),
+                        value: transaction_argument_to_string(&value),
                     })
-                } else {
-                    Err(format_err!("Unable to parse PeerToPeer arguments"))
-                }
-            }
-            _ => Err(format_err!("Unknown scripts")),
-        };
-        res.unwrap_or(ScriptView::Unknown {})
+                    .collect(),
+            },
+            None => ScriptView::Unknown {},
+        }
+    }
+}
+
+fn currency_code_from_type_tag(type_tag: &TypeTag) -> String {
+    match type_tag {
+        TypeTag::Struct(StructTag { module, .. }) => module.to_string(),
+        tag => format!("{}", tag),
+    }
+}
+
+fn transaction_argument_to_string(arg: &TransactionArgument) -> String {
+    match arg {
+        TransactionArgument::U8(value) => value.to_string(),
+        TransactionArgument::U64(value) => value.to_string(),
+        TransactionArgument::U128(value) => value.to_string(),
+        TransactionArgument::Bool(value) => value.to_string(),
+        TransactionArgument::Address(address) => address.to_string(),
+        TransactionArgument::U8Vector(bytes) => hex::encode(bytes),
     }
 }
 
@@ -615,3 +646,79 @@ impl TryFrom<AccountStateProof> for AccountStateProofView {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libra_types::{
+        account_address::AccountAddress, account_config::lbr_type_tag, transaction::Script,
+    };
+
+    fn script_view(script: Script) -> ScriptView {
+        TransactionPayload::Script(script).into()
+    }
+
+    #[test]
+    fn peer_to_peer_script_view() {
+        let payee = AccountAddress::random();
+        let view = script_view(
+            transaction_builder::encode_peer_to_peer_with_metadata_script(
+                lbr_type_tag(),
+                payee,
+                100,
+                vec![1, 2],
+                vec![3],
+            ),
+        );
+        assert_eq!(
+            view,
+            ScriptView::PeerToPeer {
+                receiver: payee.to_string(),
+                amount: 100,
+                currency: "LBR".to_string(),
+                metadata: BytesView("0102".to_string()),
+                metadata_signature: BytesView("03".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn stdlib_script_view() {
+        let preburn_address = AccountAddress::random();
+        let view = script_view(transaction_builder::encode_burn_script(
+            lbr_type_tag(),
+            7,
+            preburn_address,
+        ));
+        let argument = |name: &str, value: String| ScriptArgumentView {
+            name: name.to_string(),
+            value,
+        };
+        assert_eq!(
+            view,
+            ScriptView::StdlibScript {
+                name: "burn".to_string(),
+                type_arguments: vec![argument("token", lbr_type_tag().to_string())],
+                arguments: vec![
+                    argument("sliding_nonce", "7".to_string()),
+                    argument("preburn_address", preburn_address.to_string()),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn mismatched_script_view() {
+        let script = transaction_builder::encode_mint_lbr_script(1);
+        let wrong_arity = Script::new(script.code().to_vec(), vec![], vec![]);
+        assert_eq!(script_view(wrong_arity), ScriptView::Unknown {});
+        let wrong_type = Script::new(
+            script.code().to_vec(),
+            vec![],
+            vec![TransactionArgument::U8(1)],
+        );
+        assert_eq!(script_view(wrong_type), ScriptView::Unknown {});
+        let unknown = Script::new(vec![0xde, 0xad], vec![], vec![]);
+        assert_eq!(script_view(unknown), ScriptView::Unknown {});
+    }
+}
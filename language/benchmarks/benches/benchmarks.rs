@@ -5,16 +5,26 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use language_benchmarks::{move_vm::bench, transactions::TransactionBencher};
 use language_e2e_tests::account_universe::P2PTransferGen;
 use proptest::prelude::*;
+use std::{env, path::PathBuf};
+
+/// When set, the transaction benchmarks also write a gas profile in the folded stack format to
+/// this directory, e.g., to render it with `inferno-flamegraph`.
+const GAS_PROFILE_DIR_ENV_VAR: &str = "GAS_PROFILE_DIR";
 
 //
 // Transaction benchmarks
 //
 
 fn peer_to_peer(c: &mut Criterion) {
-    c.bench_function("peer_to_peer", |b| {
-        let bencher = TransactionBencher::new(any_with::<P2PTransferGen>((1_000, 1_000_000)));
-        bencher.bench(b)
-    });
+    let bencher = TransactionBencher::new(any_with::<P2PTransferGen>((1_000, 1_000_000)));
+    c.bench_function("peer_to_peer", |b| bencher.bench(b));
+
+    if let Ok(dir) = env::var(GAS_PROFILE_DIR_ENV_VAR) {
+        let path = PathBuf::from(dir).join("peer_to_peer.folded");
+        bencher
+            .profile_gas(&path)
+            .expect("Failed to write gas profile");
+    }
 }
 
 criterion_group!(txn_benches, peer_to_peer);
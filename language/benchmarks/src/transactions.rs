@@ -9,7 +9,9 @@ use language_e2e_tests::{
 };
 use libra_proptest_helpers::ValueGenerator;
 use libra_types::transaction::SignedTransaction;
+use move_vm_types::gas_profiler::GasProfiler;
 use proptest::{collection::vec, strategy::Strategy};
+use std::{io, path::Path};
 
 /// Benchmarking support for transactions.
 #[derive(Clone, Debug)]
@@ -66,6 +68,25 @@ where
             BatchSize::LargeInput,
         )
     }
+
+    /// Profiles the gas used by the scripts of a generated set of transactions and writes the
+    /// aggregated profile to `path` in the folded stack format.
+    pub fn profile_gas(&self, path: &Path) -> io::Result<()> {
+        let state = TransactionBenchState::with_size(
+            &self.strategy,
+            self.num_accounts,
+            self.num_transactions,
+        );
+        let mut profiler = GasProfiler::new();
+        for txn in &state.transactions {
+            let (_, profile) = state
+                .executor
+                .profile_gas(txn.clone())
+                .map_err(|status| io::Error::new(io::ErrorKind::Other, format!("{:?}", status)))?;
+            profiler.merge(&profile);
+        }
+        profiler.write_folded_stacks(path)
+    }
 }
 
 struct TransactionBenchState {
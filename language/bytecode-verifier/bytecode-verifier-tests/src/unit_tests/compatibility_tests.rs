@@ -0,0 +1,116 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use bytecode_verifier::{
+    check_compatibility, compatibility::Incompatibility, verify_compatibility,
+};
+use libra_types::vm_status::StatusCode;
+use move_core_types::identifier::Identifier;
+use vm::file_format::{
+    basic_test_module, CompiledModuleMut, FieldDefinition, IdentifierIndex, Signature,
+    SignatureIndex, SignatureToken, StructDefinitionIndex, StructFieldInformation, TypeSignature,
+};
+
+// basic_test_module with `foo` made public.
+fn public_test_module() -> CompiledModuleMut {
+    let mut m = basic_test_module();
+    m.function_defs[0].is_public = true;
+    m
+}
+
+fn check(old: CompiledModuleMut, new: CompiledModuleMut) -> Vec<Incompatibility> {
+    check_compatibility(&old.freeze().unwrap(), &new.freeze().unwrap())
+}
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(name).unwrap()
+}
+
+#[test]
+fn same_module_is_compatible() {
+    assert!(check(public_test_module(), public_test_module()).is_empty());
+}
+
+#[test]
+fn struct_changes() {
+    let mut new = public_test_module();
+    if let StructFieldInformation::Declared(fields) = &mut new.struct_defs[0].field_information {
+        fields.push(FieldDefinition {
+            name: IdentifierIndex(0),
+            signature: TypeSignature(SignatureToken::Bool),
+        });
+    }
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::StructFieldsChanged(ident("Bar"))]
+    );
+
+    let mut new = public_test_module();
+    if let StructFieldInformation::Declared(fields) = &mut new.struct_defs[0].field_information {
+        fields[0].signature = TypeSignature(SignatureToken::U128);
+    }
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::StructFieldsChanged(ident("Bar"))]
+    );
+
+    let mut new = public_test_module();
+    new.struct_handles[0].is_nominal_resource = true;
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::StructKindChanged(ident("Bar"))]
+    );
+}
+
+#[test]
+fn public_function_changes() {
+    let mut new = public_test_module();
+    new.function_defs[0].is_public = false;
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::PublicFunctionRemoved(ident("foo"))]
+    );
+
+    let mut new = public_test_module();
+    new.signatures.push(Signature(vec![SignatureToken::U64]));
+    new.function_handles[0].parameters = SignatureIndex(1);
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::PublicFunctionSignatureChanged(ident(
+            "foo"
+        ))]
+    );
+
+    let mut new = public_test_module();
+    new.function_defs[0].acquires_global_resources = vec![StructDefinitionIndex(0)];
+    assert_eq!(
+        check(public_test_module(), new),
+        vec![Incompatibility::PublicFunctionAcquiresChanged(ident("foo"))]
+    );
+}
+
+#[test]
+fn private_function_changes_are_compatible() {
+    let mut new = basic_test_module();
+    new.signatures.push(Signature(vec![SignatureToken::U64]));
+    new.function_handles[0].parameters = SignatureIndex(1);
+    assert!(check(basic_test_module(), new.clone()).is_empty());
+
+    // Making a function public is compatible, the reverse is not.
+    assert!(check(basic_test_module(), public_test_module()).is_empty());
+}
+
+#[test]
+fn verify_reports_incompatibility() {
+    let mut new = public_test_module();
+    new.function_defs[0].is_public = false;
+    let status = verify_compatibility(
+        &public_test_module().freeze().unwrap(),
+        &new.freeze().unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        status.major_status,
+        StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE
+    );
+}
@@ -3,6 +3,7 @@
 
 pub mod bounds_tests;
 pub mod code_unit_tests;
+pub mod compatibility_tests;
 pub mod constants_tests;
 pub mod control_flow_tests;
 pub mod duplication_tests;
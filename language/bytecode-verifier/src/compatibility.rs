@@ -0,0 +1,248 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module checks that a new version of a module can replace a published one.
+//!
+//! Published code and data depend on the declarations of a module: values of its structs are
+//! stored with the layout they were published with, and callers were verified against the
+//! signatures and acquires of its public functions. A new version is compatible if it keeps all
+//! of those. It may add structs and functions, change private functions, and change the code of
+//! any function.
+use libra_types::vm_status::{StatusCode, VMStatus};
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use std::{collections::BTreeMap, fmt};
+use vm::{
+    access::ModuleAccess,
+    errors::VMResult,
+    file_format::{
+        CompiledModule, FunctionDefinition, Kind, SignatureToken, StructDefinition,
+        StructFieldInformation, StructHandleIndex,
+    },
+};
+
+/// A way in which the new version of a module is incompatible with the old one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Incompatibility {
+    ModuleIdChanged(ModuleId, ModuleId),
+    StructRemoved(Identifier),
+    /// The struct became or stopped being a resource, or the kinds of its type parameters changed.
+    StructKindChanged(Identifier),
+    /// The names, order or types of the fields changed, or the struct became or stopped being
+    /// native.
+    StructFieldsChanged(Identifier),
+    /// The public function was removed or made private.
+    PublicFunctionRemoved(Identifier),
+    /// The kinds of the type parameters, the parameters or the return types changed.
+    PublicFunctionSignatureChanged(Identifier),
+    PublicFunctionAcquiresChanged(Identifier),
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Incompatibility::*;
+        match self {
+            ModuleIdChanged(old, new) => write!(
+                f,
+                "module {}::{} is replaced by {}::{}",
+                old.address(),
+                old.name(),
+                new.address(),
+                new.name()
+            ),
+            StructRemoved(name) => write!(f, "struct {} is removed", name),
+            StructKindChanged(name) => write!(f, "kind of struct {} changed", name),
+            StructFieldsChanged(name) => write!(f, "fields of struct {} changed", name),
+            PublicFunctionRemoved(name) => write!(f, "public function {} is removed", name),
+            PublicFunctionSignatureChanged(name) => {
+                write!(f, "signature of public function {} changed", name)
+            }
+            PublicFunctionAcquiresChanged(name) => {
+                write!(f, "acquires of public function {} changed", name)
+            }
+        }
+    }
+}
+
+/// A struct referenced from a signature, identified independently of the handles of a module.
+type StructId = (ModuleId, Identifier);
+
+/// A signature token with its structs resolved, so that it can be compared across modules.
+#[derive(Debug, Eq, PartialEq)]
+enum Type {
+    Bool,
+    U8,
+    U64,
+    U128,
+    Address,
+    Signer,
+    Vector(Box<Type>),
+    Struct(StructId, Vec<Type>),
+    Reference(Box<Type>),
+    MutableReference(Box<Type>),
+    TypeParameter(u16),
+}
+
+/// Returns all the ways in which `new` is incompatible with `old`, empty if `new` can replace it.
+pub fn check_compatibility(old: &CompiledModule, new: &CompiledModule) -> Vec<Incompatibility> {
+    let mut incompatibilities = vec![];
+    if old.self_id() != new.self_id() {
+        incompatibilities.push(Incompatibility::ModuleIdChanged(
+            old.self_id(),
+            new.self_id(),
+        ));
+        return incompatibilities;
+    }
+
+    let new_structs = struct_defs_by_name(new);
+    for (name, old_def) in struct_defs_by_name(old) {
+        match new_structs.get(&name) {
+            Some(new_def) => {
+                if struct_kind(old, old_def) != struct_kind(new, new_def) {
+                    incompatibilities.push(Incompatibility::StructKindChanged(name.clone()));
+                }
+                if struct_fields(old, old_def) != struct_fields(new, new_def) {
+                    incompatibilities.push(Incompatibility::StructFieldsChanged(name));
+                }
+            }
+            None => incompatibilities.push(Incompatibility::StructRemoved(name)),
+        }
+    }
+
+    let new_functions = public_function_defs_by_name(new);
+    for (name, old_def) in public_function_defs_by_name(old) {
+        match new_functions.get(&name) {
+            Some(new_def) => {
+                if function_signature(old, old_def) != function_signature(new, new_def) {
+                    incompatibilities.push(Incompatibility::PublicFunctionSignatureChanged(
+                        name.clone(),
+                    ));
+                }
+                if function_acquires(old, old_def) != function_acquires(new, new_def) {
+                    incompatibilities.push(Incompatibility::PublicFunctionAcquiresChanged(name));
+                }
+            }
+            None => incompatibilities.push(Incompatibility::PublicFunctionRemoved(name)),
+        }
+    }
+    incompatibilities
+}
+
+/// Checks that `new` can replace `old`, reporting the first incompatibility otherwise.
+pub fn verify_compatibility(old: &CompiledModule, new: &CompiledModule) -> VMResult<()> {
+    match check_compatibility(old, new).into_iter().next() {
+        Some(incompatibility) => Err(VMStatus::new(
+            StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+        )
+        .with_message(incompatibility.to_string())),
+        None => Ok(()),
+    }
+}
+
+fn struct_defs_by_name(module: &CompiledModule) -> BTreeMap<Identifier, &StructDefinition> {
+    module
+        .struct_defs()
+        .iter()
+        .map(|def| {
+            let handle = module.struct_handle_at(def.struct_handle);
+            (module.identifier_at(handle.name).to_owned(), def)
+        })
+        .collect()
+}
+
+fn public_function_defs_by_name(
+    module: &CompiledModule,
+) -> BTreeMap<Identifier, &FunctionDefinition> {
+    module
+        .function_defs()
+        .iter()
+        .filter(|def| def.is_public)
+        .map(|def| {
+            let handle = module.function_handle_at(def.function);
+            (module.identifier_at(handle.name).to_owned(), def)
+        })
+        .collect()
+}
+
+fn struct_kind(module: &CompiledModule, def: &StructDefinition) -> (bool, Vec<Kind>) {
+    let handle = module.struct_handle_at(def.struct_handle);
+    (handle.is_nominal_resource, handle.type_parameters.clone())
+}
+
+fn struct_fields(
+    module: &CompiledModule,
+    def: &StructDefinition,
+) -> Option<Vec<(Identifier, Type)>> {
+    match &def.field_information {
+        StructFieldInformation::Native => None,
+        StructFieldInformation::Declared(fields) => Some(
+            fields
+                .iter()
+                .map(|field| {
+                    (
+                        module.identifier_at(field.name).to_owned(),
+                        resolve_type(module, &field.signature.0),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn function_signature(
+    module: &CompiledModule,
+    def: &FunctionDefinition,
+) -> (Vec<Kind>, Vec<Type>, Vec<Type>) {
+    let handle = module.function_handle_at(def.function);
+    let resolve_all = |tokens: &[SignatureToken]| -> Vec<Type> {
+        tokens
+            .iter()
+            .map(|token| resolve_type(module, token))
+            .collect()
+    };
+    (
+        handle.type_parameters.clone(),
+        resolve_all(&module.signature_at(handle.parameters).0),
+        resolve_all(&module.signature_at(handle.return_).0),
+    )
+}
+
+fn function_acquires(module: &CompiledModule, def: &FunctionDefinition) -> Vec<Identifier> {
+    let mut acquires: Vec<_> = def
+        .acquires_global_resources
+        .iter()
+        .map(|idx| {
+            let handle = module.struct_handle_at(module.struct_def_at(*idx).struct_handle);
+            module.identifier_at(handle.name).to_owned()
+        })
+        .collect();
+    acquires.sort();
+    acquires
+}
+
+fn resolve_type(module: &CompiledModule, token: &SignatureToken) -> Type {
+    match token {
+        SignatureToken::Bool => Type::Bool,
+        SignatureToken::U8 => Type::U8,
+        SignatureToken::U64 => Type::U64,
+        SignatureToken::U128 => Type::U128,
+        SignatureToken::Address => Type::Address,
+        SignatureToken::Signer => Type::Signer,
+        SignatureToken::Vector(ty) => Type::Vector(Box::new(resolve_type(module, ty))),
+        SignatureToken::Struct(idx) => Type::Struct(resolve_struct(module, *idx), vec![]),
+        SignatureToken::StructInstantiation(idx, tys) => Type::Struct(
+            resolve_struct(module, *idx),
+            tys.iter().map(|ty| resolve_type(module, ty)).collect(),
+        ),
+        SignatureToken::Reference(ty) => Type::Reference(Box::new(resolve_type(module, ty))),
+        SignatureToken::MutableReference(ty) => {
+            Type::MutableReference(Box::new(resolve_type(module, ty)))
+        }
+        SignatureToken::TypeParameter(idx) => Type::TypeParameter(*idx),
+    }
+}
+
+fn resolve_struct(module: &CompiledModule, idx: StructHandleIndex) -> StructId {
+    let handle = module.struct_handle_at(idx);
+    let module_id = module.module_id_for_handle(module.module_handle_at(handle.module));
+    (module_id, module.identifier_at(handle.name).to_owned())
+}
@@ -8,6 +8,7 @@
 // Bounds checks are implemented in the `vm` crate.
 pub mod check_duplication;
 pub mod code_unit_verifier;
+pub mod compatibility;
 pub mod constants;
 pub mod control_flow;
 pub mod control_flow_graph;
@@ -22,6 +23,7 @@ pub mod verifier;
 
 pub use check_duplication::DuplicationChecker;
 pub use code_unit_verifier::CodeUnitVerifier;
+pub use compatibility::{check_compatibility, verify_compatibility};
 pub use dependencies::DependencyChecker;
 pub use instruction_consistency::InstructionConsistency;
 pub use resources::ResourceTransitiveChecker;
@@ -31,6 +31,7 @@ use move_core_types::{
 };
 use move_vm_runtime::{data_cache::TransactionDataCache, move_vm::MoveVM};
 use move_vm_types::{
+    gas_profiler::GasProfiler,
     gas_schedule::{zero_cost_schedule, CostStrategy},
     values::Value,
 };
@@ -134,6 +135,7 @@ impl FakeExecutor {
     /// Applies a [`WriteSet`] to this executor's data store.
     pub fn apply_write_set(&mut self, write_set: &WriteSet) {
         self.data_store.add_write_set(write_set);
+        LibraVM::on_transactions_committed(&[write_set]);
     }
 
     /// Adds an account to this executor's data store.
@@ -282,6 +284,16 @@ impl FakeExecutor {
         self.block_time
     }
 
+    /// Executes the transaction against the current state the same way `execute_transaction`
+    /// does, with a gas profiler attached, and returns its output along with the profile of the
+    /// gas charged. Use `GasProfiler::write_folded_stacks` to export the profile for a flamegraph.
+    pub fn profile_gas(
+        &self,
+        txn: SignedTransaction,
+    ) -> Result<(TransactionOutput, GasProfiler), VMStatus> {
+        LibraVM::execute_user_transaction_with_profiler(&self.data_store, txn)
+    }
+
     pub fn exec(
         &mut self,
         module_name: &str,
@@ -14,10 +14,12 @@ mod create_account;
 mod data_store;
 mod execution_strategies;
 mod failed_transaction_tests;
+mod gas_profiler;
 mod genesis;
 mod mint;
 mod module_publishing;
 mod on_chain_configs;
+mod parallel_execution;
 mod peer_to_peer;
 mod rotate_key;
 mod scripts;
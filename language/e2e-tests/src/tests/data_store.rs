@@ -6,6 +6,7 @@ use bytecode_verifier::verify_module;
 use compiler::Compiler;
 use libra_types::{
     account_config::LBR_NAME,
+    on_chain_config::VMPublishingOption,
     transaction::{Module, SignedTransaction, Transaction, TransactionPayload, TransactionStatus},
     vm_status::{StatusCode, VMStatus},
 };
@@ -249,6 +250,102 @@ fn add_module_txn(sender: &AccountData, seq_num: u64) -> (CompiledModule, Signed
     )
 }
 
+// The code cache is shared by the VMs of all blocks, the previous version of an upgraded module
+// must not be served from it
+#[test]
+fn upgrade_module_across_blocks() {
+    let mut executor =
+        FakeExecutor::from_genesis_with_options(VMPublishingOption::OpenWithUpgrades);
+    let sender = AccountData::new(1_000_000, 10);
+    executor.add_account_data(&sender);
+
+    for (i, version) in [1u64, 2, 3].iter().enumerate() {
+        let seq_num = 10 + 2 * i as u64;
+        let (module, txn) = version_module_txn(&sender, seq_num, *version);
+        executor.execute_and_apply(txn);
+
+        let output = executor.execute_and_apply(check_version_txn(
+            &sender,
+            seq_num + 1,
+            *version,
+            vec![module],
+        ));
+        assert_eq!(
+            output.status().vm_status().major_status,
+            StatusCode::EXECUTED
+        );
+    }
+}
+
+fn version_module_txn(
+    sender: &AccountData,
+    seq_num: u64,
+    version: u64,
+) -> (CompiledModule, SignedTransaction) {
+    let module_code = format!(
+        "
+        module M {{
+            public version(): u64 {{
+                return {};
+            }}
+        }}
+        ",
+        version
+    );
+    let compiler = Compiler {
+        address: *sender.address(),
+        ..Compiler::default()
+    };
+    let module = compiler
+        .into_compiled_module("file_name", module_code.as_str())
+        .expect("Module compilation failed");
+    let mut module_blob = vec![];
+    module
+        .serialize(&mut module_blob)
+        .expect("Module must serialize");
+    (
+        module,
+        sender.account().create_signed_txn_impl(
+            *sender.address(),
+            TransactionPayload::Module(Module::new(module_blob)),
+            seq_num,
+            100_000,
+            0,
+            LBR_NAME.to_owned(),
+        ),
+    )
+}
+
+fn check_version_txn(
+    sender: &AccountData,
+    seq_num: u64,
+    version: u64,
+    extra_deps: Vec<CompiledModule>,
+) -> SignedTransaction {
+    let program = format!(
+        "
+            import 0x{}.M;
+
+            main() {{
+                assert(M.version() == {}, 42);
+                return;
+            }}
+        ",
+        sender.address(),
+        version,
+    );
+
+    let script = compile_script_with_address(sender.address(), "file_name", &program, extra_deps);
+    sender.account().create_signed_txn_impl(
+        *sender.address(),
+        script,
+        seq_num,
+        100_000,
+        0,
+        LBR_NAME.to_owned(),
+    )
+}
+
 fn add_resource_txn(
     sender: &AccountData,
     seq_num: u64,
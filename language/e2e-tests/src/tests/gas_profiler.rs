@@ -0,0 +1,59 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{account::AccountData, common_transactions::peer_to_peer_txn, executor::FakeExecutor};
+use libra_types::{
+    transaction::TransactionStatus,
+    vm_status::{StatusCode, VMStatus},
+};
+use move_vm_types::gas_profiler::INTRINSIC_LABEL;
+
+#[test]
+fn profile_peer_to_peer() {
+    let mut executor = FakeExecutor::from_genesis_file();
+    let sender = AccountData::new(1_000_000, 10);
+    let receiver = AccountData::new(100_000, 10);
+    executor.add_account_data(&sender);
+    executor.add_account_data(&receiver);
+
+    let txn = peer_to_peer_txn(sender.account(), receiver.account(), 10, 1_000);
+    let (profiled_output, profiler) = executor.profile_gas(txn.clone()).unwrap();
+    let output = executor.execute_transaction(txn);
+    assert_eq!(profiled_output, output);
+    assert_eq!(
+        output.status(),
+        &TransactionStatus::Keep(VMStatus::new(StatusCode::EXECUTED))
+    );
+
+    // The prologue and epilogue are not charged, so the profile covers all the gas used.
+    assert_eq!(profiler.total(), output.gas_used());
+
+    assert!(profiler.gas_by_function().contains_key("Script::main"));
+    assert!(profiler.gas_by_instruction().contains_key(INTRINSIC_LABEL));
+    assert!(profiler
+        .gas_by_function()
+        .keys()
+        .any(|function| function.starts_with("0x")));
+
+    assert!(profiler
+        .folded_stacks()
+        .lines()
+        .any(|line| line.starts_with("Script::main;0x")));
+}
+
+#[test]
+fn profile_failed_transaction() {
+    let mut executor = FakeExecutor::from_genesis_file();
+    let sender = AccountData::new(1_000_000, 10);
+    let receiver = AccountData::new(100_000, 10);
+    executor.add_account_data(&sender);
+    executor.add_account_data(&receiver);
+
+    // Aborts within the transfer since the sender does not have enough funds
+    let txn = peer_to_peer_txn(sender.account(), receiver.account(), 10, 10_000_000);
+    let (output, profiler) = executor.profile_gas(txn).unwrap();
+    assert!(!output.status().is_discarded());
+    assert_eq!(profiler.total(), output.gas_used());
+    // The functions left by the abort are unwound
+    assert_eq!(profiler.depth(), 0);
+}
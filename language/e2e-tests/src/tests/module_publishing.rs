@@ -117,6 +117,61 @@ fn duplicate_module() {
     ));
 }
 
+// With upgrades allowed, a module can be re-published as long as the new version is compatible
+#[test]
+fn upgrade_module() {
+    let mut executor =
+        FakeExecutor::from_genesis_with_options(VMPublishingOption::OpenWithUpgrades);
+
+    let sequence_number = 2;
+    let account = AccountData::new(1_000_000, sequence_number);
+    executor.add_account_data(&account);
+
+    let versions = [
+        "
+        module M {
+            public f(): u64 { return 1; }
+        }
+        ",
+        // changes the code of `f` and adds `g`
+        "
+        module M {
+            public f(): u64 { return 2; }
+            public g() { return; }
+        }
+        ",
+        // removes `g`
+        "
+        module M {
+            public f(): u64 { return 3; }
+        }
+        ",
+    ];
+    let statuses = [
+        StatusCode::EXECUTED,
+        StatusCode::EXECUTED,
+        StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+    ];
+
+    for (i, (program, status)) in versions.iter().zip(statuses.iter()).enumerate() {
+        let compiled_module = compile_module_with_address(account.address(), "file_name", program);
+        let txn = account.account().create_signed_txn_impl(
+            *account.address(),
+            compiled_module,
+            sequence_number + i as u64,
+            100_000,
+            1,
+            LBR_NAME.to_owned(),
+        );
+        let output = executor.execute_transaction(txn);
+        executor.apply_write_set(output.write_set());
+        assert!(transaction_status_eq(
+            &output.status(),
+            &TransactionStatus::Keep(VMStatus::new(*status)),
+        ));
+    }
+}
+
 #[test]
 pub fn test_publishing_no_modules_non_whitelist_script() {
     // create a FakeExecutor with a genesis from file
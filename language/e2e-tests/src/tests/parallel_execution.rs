@@ -0,0 +1,48 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{account::AccountData, common_transactions::peer_to_peer_txn, executor::FakeExecutor};
+use libra_types::{
+    transaction::{Transaction, TransactionStatus},
+    vm_status::{StatusCode, VMStatus},
+};
+use libra_vm::{LibraVM, ParallelLibraVM, VMExecutor};
+
+#[test]
+fn parallel_execution_matches_sequential() {
+    let mut executor = FakeExecutor::from_genesis_file();
+    let accounts: Vec<_> = (0..8)
+        .map(|_| {
+            let account = AccountData::new(1_000_000, 10);
+            executor.add_account_data(&account);
+            account
+        })
+        .collect();
+    let account = |i: usize| accounts[i].account();
+
+    let txns = vec![
+        // disjoint transfers
+        peer_to_peer_txn(account(0), account(1), 10, 1_000),
+        peer_to_peer_txn(account(2), account(3), 10, 1_000),
+        // same receiver as the first transfer
+        peer_to_peer_txn(account(4), account(1), 10, 1_000),
+        // same sender as the first transfer, only valid after it
+        peer_to_peer_txn(account(0), account(5), 11, 1_000),
+        // spends funds received earlier in the block
+        peer_to_peer_txn(account(3), account(6), 10, 1_000_500),
+        // stale sequence number
+        peer_to_peer_txn(account(7), account(6), 9, 1_000),
+    ];
+    let block: Vec<_> = txns.into_iter().map(Transaction::UserTransaction).collect();
+
+    let sequential = LibraVM::execute_block(block.clone(), executor.get_state_view()).unwrap();
+    let parallel =
+        ParallelLibraVM::execute_block_with_sync_view(block, executor.get_state_view()).unwrap();
+    assert_eq!(sequential, parallel);
+
+    let executed = TransactionStatus::Keep(VMStatus::new(StatusCode::EXECUTED));
+    for output in &parallel[..5] {
+        assert_eq!(output.status(), &executed);
+    }
+    assert!(parallel[5].status().is_discarded());
+}
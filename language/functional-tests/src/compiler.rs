@@ -3,7 +3,10 @@
 
 use anyhow::Result;
 use libra_types::account_address::AccountAddress;
-use vm::file_format::{CompiledModule, CompiledScript};
+use vm::{
+    errors::StackTrace,
+    file_format::{CompiledModule, CompiledScript},
+};
 
 pub trait Compiler {
     /// Compile a transaction script or module.
@@ -15,6 +18,12 @@ pub trait Compiler {
     ) -> Result<ScriptOrModule>;
 
     fn use_compiled_genesis(&self) -> bool;
+
+    /// Renders the stack trace of a failed transaction with the source location of each frame.
+    /// Returns None if the compiler does not keep track of source locations.
+    fn render_stack_trace(&mut self, _stack_trace: &StackTrace) -> Option<String> {
+        None
+    }
 }
 
 pub enum ScriptOrModule {
@@ -29,6 +29,7 @@ use move_core_types::{
     gas_schedule::{GasAlgebra, GasConstants},
     language_storage::ModuleId,
 };
+use move_vm_runtime::stack_trace;
 use std::{
     fmt::{self, Debug},
     str::FromStr,
@@ -97,6 +98,7 @@ pub enum OutputType {
     CompiledScript(Box<CompiledScript>),
     CompilerLog(String),
     TransactionOutput(Box<TransactionOutput>),
+    StackTrace(String),
 }
 
 impl OutputType {
@@ -168,6 +170,7 @@ impl fmt::Display for OutputType {
             CompiledScript(cs) => write!(f, "{:#?}", cs),
             CompilerLog(s) => write!(f, "{}", s),
             TransactionOutput(output) => write!(f, "{:#?}", output),
+            StackTrace(stack_trace) => write!(f, "Stack trace:\n{}", stack_trace),
         }
     }
 }
@@ -396,6 +399,33 @@ fn run_transaction(
     }
 }
 
+/// Runs a single transaction, and logs the stack trace of the execution if it failed.
+fn run_transaction_with_stack_trace<TComp: Compiler>(
+    compiler: &mut TComp,
+    exec: &mut FakeExecutor,
+    transaction: SignedTransaction,
+    log: &mut EvaluationLog,
+) -> Result<TransactionOutput> {
+    // Drop the trace of any failure that did not fail the transaction, e.g., in a prior block.
+    stack_trace::take_stack_trace();
+    let res = run_transaction(exec, transaction);
+    if res.is_err() {
+        if let Some(stack_trace) = stack_trace::take_stack_trace() {
+            let rendered = compiler
+                .render_stack_trace(&stack_trace)
+                .unwrap_or_else(|| {
+                    stack_trace
+                        .iter()
+                        .rev()
+                        .map(|frame| format!("    at {}\n", frame))
+                        .collect()
+                });
+            log.append(EvaluationOutput::Output(OutputType::StackTrace(rendered)));
+        }
+    }
+    res
+}
+
 /// Serializes the script then deserializes it.
 fn serialize_and_deserialize_script(script: &CompiledScript) -> Result<()> {
     let mut script_blob = vec![];
@@ -497,7 +527,12 @@ fn eval_transaction<TComp: Compiler>(
             log.append(EvaluationOutput::Stage(Stage::Runtime));
             let script_transaction =
                 make_script_transaction(&exec, &transaction.config, compiled_script)?;
-            let txn_output = unwrap_or_abort!(run_transaction(exec, script_transaction));
+            let txn_output = unwrap_or_abort!(run_transaction_with_stack_trace(
+                compiler,
+                exec,
+                script_transaction,
+                log
+            ));
             log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
                 Box::new(txn_output),
             )));
@@ -535,7 +570,12 @@ fn eval_transaction<TComp: Compiler>(
             log.append(EvaluationOutput::Stage(Stage::Runtime));
             let module_transaction =
                 make_module_transaction(&exec, &transaction.config, compiled_module)?;
-            let txn_output = unwrap_or_abort!(run_transaction(exec, module_transaction));
+            let txn_output = unwrap_or_abort!(run_transaction_with_stack_trace(
+                compiler,
+                exec,
+                module_transaction,
+                log
+            ));
             log.append(EvaluationOutput::Output(OutputType::TransactionOutput(
                 Box::new(txn_output),
             )));
@@ -603,6 +643,7 @@ pub fn eval<TComp: Compiler>(
     for data in config.accounts.values() {
         exec.add_account_data(&data);
     }
+    stack_trace::record_stack_traces(true);
 
     for (idx, command) in commands.iter().enumerate() {
         match command {
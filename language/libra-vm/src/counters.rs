@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use libra_metrics::{
-    register_histogram, register_int_counter_vec, register_int_gauge, Histogram, IntCounterVec,
-    IntGauge,
+    register_histogram, register_int_counter, register_int_counter_vec, register_int_gauge,
+    Histogram, IntCounter, IntCounterVec, IntGauge,
 };
 use once_cell::sync::Lazy;
 
@@ -29,6 +29,16 @@ pub static TRANSACTIONS_EXECUTED: Lazy<IntCounterVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Count the number of speculatively executed transactions that had to be executed again because
+/// they read a value written by an earlier transaction in the block.
+pub static SPECULATIVE_EXECUTION_CONFLICTS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "libra_vm_speculative_execution_conflicts",
+        "Number of speculatively executed transactions that were re-executed"
+    )
+    .unwrap()
+});
+
 pub static BLOCK_TRANSACTION_COUNT: Lazy<IntGauge> = Lazy::new(|| {
     register_int_gauge!(
         "libra_vm_block_transaction_count",
@@ -10,7 +10,10 @@ use libra_types::{
     write_set::{WriteOp, WriteSet},
 };
 use move_vm_runtime::data_cache::RemoteCache;
-use std::collections::btree_map::BTreeMap;
+use std::{
+    cell::RefCell,
+    collections::{btree_map::BTreeMap, BTreeSet},
+};
 use vm::errors::*;
 
 /// A local cache for a given a `StateView`. The cache is private to the Libra layer
@@ -45,17 +48,15 @@ impl<'a> StateViewCache<'a> {
     pub(crate) fn get(&self, access_path: &AccessPath) -> VMResult<Option<Vec<u8>>> {
         match self.data_map.get(access_path) {
             Some(opt_data) => Ok(opt_data.clone()),
-            None => match self.data_view.get(&access_path) {
-                Ok(remote_data) => Ok(remote_data),
-                // TODO: should we forward some error info?
-                Err(_) => {
-                    crit!("[VM] Error getting data from storage for {:?}", access_path);
-                    Err(VMStatus::new(StatusCode::STORAGE_ERROR))
-                }
-            },
+            None => get_from_view(self.data_view, access_path),
         }
     }
 
+    // The changes published so far, on top of the `StateView`.
+    pub(crate) fn data_map(&self) -> &BTreeMap<AccessPath, Option<Vec<u8>>> {
+        &self.data_map
+    }
+
     // Publishes a `WriteSet` computed at the end of a transaction.
     // The effect is to build a layer in front of the `StateView` which keeps
     // track of the data as if the changes were applied immediately.
@@ -80,6 +81,55 @@ impl<'block> RemoteCache for StateViewCache<'block> {
     }
 }
 
+fn get_from_view(data_view: &dyn StateView, access_path: &AccessPath) -> VMResult<Option<Vec<u8>>> {
+    match data_view.get(&access_path) {
+        Ok(remote_data) => Ok(remote_data),
+        // TODO: should we forward some error info?
+        Err(_) => {
+            crit!("[VM] Error getting data from storage for {:?}", access_path);
+            Err(VMStatus::new(StatusCode::STORAGE_ERROR))
+        }
+    }
+}
+
+/// A `RemoteCache` over the changes of a `StateViewCache` and the `Sync` view it was created from
+/// that records every access path read through it.
+///
+/// Transactions executed speculatively against the same `StateViewCache` use it to find out
+/// whether they observed a value that an earlier transaction in the block has since written.
+pub(crate) struct ReadSetRecorder<'a> {
+    data_map: &'a BTreeMap<AccessPath, Option<Vec<u8>>>,
+    data_view: &'a (dyn StateView + Sync),
+    read_set: RefCell<BTreeSet<AccessPath>>,
+}
+
+impl<'a> ReadSetRecorder<'a> {
+    pub(crate) fn new(
+        data_map: &'a BTreeMap<AccessPath, Option<Vec<u8>>>,
+        data_view: &'a (dyn StateView + Sync),
+    ) -> Self {
+        Self {
+            data_map,
+            data_view,
+            read_set: RefCell::new(BTreeSet::new()),
+        }
+    }
+
+    pub(crate) fn into_read_set(self) -> BTreeSet<AccessPath> {
+        self.read_set.into_inner()
+    }
+}
+
+impl<'a> RemoteCache for ReadSetRecorder<'a> {
+    fn get(&self, access_path: &AccessPath) -> VMResult<Option<Vec<u8>>> {
+        self.read_set.borrow_mut().insert(access_path.clone());
+        match self.data_map.get(access_path) {
+            Some(opt_data) => Ok(opt_data.clone()),
+            None => get_from_view(self.data_view, access_path),
+        }
+    }
+}
+
 // Adapter to convert a `StateView` into a `RemoteCache`.
 pub struct RemoteStorage<'a>(&'a dyn StateView);
 
@@ -117,12 +117,13 @@ mod unit_tests;
 
 pub mod system_module_names;
 
-pub use crate::libra_vm::LibraVM;
+pub use crate::libra_vm::{convert_txn_args, LibraVM, ParallelLibraVM};
 
 use libra_state_view::StateView;
 use libra_types::{
     transaction::{SignedTransaction, Transaction, TransactionOutput, VMValidatorResult},
     vm_status::VMStatus,
+    write_set::WriteSet,
 };
 
 /// This trait describes the VM's validation interfaces.
@@ -139,14 +140,26 @@ pub trait VMValidator {
 
 /// This trait describes the VM's execution interface.
 pub trait VMExecutor: Send {
-    // NOTE: At the moment there are no persistent caches that live past the end of a block (that's
-    // why execute_block doesn't take &self.)
-    // There are some cache invalidation issues around transactions publishing code that need to be
-    // sorted out before that's possible.
+    // NOTE: The only cache that lives past the end of a block is the process-wide code cache of
+    // the Move VM, which executors keep coherent with the committed state by calling
+    // `on_transactions_committed` (that's why execute_block doesn't take &self.)
 
     /// Executes a block of transactions and returns output for each one of them.
     fn execute_block(
         transactions: Vec<Transaction>,
         state_view: &dyn StateView,
     ) -> Result<Vec<TransactionOutput>, VMStatus>;
+
+    /// Like `execute_block`, but the state view can be shared between threads, which allows the
+    /// VM to execute the transactions of the block in parallel.
+    fn execute_block_with_sync_view(
+        transactions: Vec<Transaction>,
+        state_view: &(dyn StateView + Sync),
+    ) -> Result<Vec<TransactionOutput>, VMStatus> {
+        Self::execute_block(transactions, state_view)
+    }
+
+    /// Called once transactions with the given write sets are committed, for the VM to drop what
+    /// its process-wide caches hold for the state they overwrite.
+    fn on_transactions_committed(_write_sets: &[&WriteSet]) {}
 }
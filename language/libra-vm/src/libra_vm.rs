@@ -3,7 +3,7 @@
 
 use crate::{
     counters::*,
-    data_cache::{RemoteStorage, StateViewCache},
+    data_cache::{ReadSetRecorder, RemoteStorage, StateViewCache},
     system_module_names::*,
     transaction_metadata::TransactionMetadata,
     VMExecutor, VMValidator,
@@ -24,25 +24,31 @@ use libra_types::{
         VMValidatorResult,
     },
     vm_status::{sub_status, StatusCode, VMStatus},
-    write_set::{WriteSet, WriteSetMut},
+    write_set::{WriteOp, WriteSet, WriteSetMut},
 };
 use move_core_types::{
     gas_schedule::{AbstractMemorySize, CostTable, GasAlgebra, GasCarrier, GasUnits},
     identifier::IdentStr,
-    language_storage::{ResourceKey, StructTag, TypeTag},
+    language_storage::{ResourceKey, StructTag, TypeTag, CODE_TAG},
     move_resource::MoveResource,
 };
 
 use move_vm_runtime::{
+    code_cache,
     data_cache::{RemoteCache, TransactionDataCache},
     move_vm::MoveVM,
 };
 use move_vm_types::{
+    gas_profiler::GasProfiler,
     gas_schedule::{calculate_intrinsic_gas, zero_cost_schedule, CostStrategy},
     values::Value,
 };
 use rayon::prelude::*;
-use std::{collections::HashSet, convert::TryFrom, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashSet},
+    convert::TryFrom,
+    sync::Arc,
+};
 use vm::errors::{convert_prologue_runtime_error, VMResult};
 
 /// Any transation sent from an account with a role id below this cutoff will be priorited over
@@ -86,6 +92,25 @@ impl LibraVM {
         self.load_configs_impl(&RemoteStorage::new(state))
     }
 
+    /// Executes a user transaction against `state_view` the same way `execute_block` does, with a
+    /// `GasProfiler` attached to the `CostStrategy` charging for it. Returns the output along with
+    /// the profile of the gas charged, e.g., to export it with
+    /// `GasProfiler::write_folded_stacks`.
+    pub fn execute_user_transaction_with_profiler(
+        state_view: &dyn StateView,
+        txn: SignedTransaction,
+    ) -> VMResult<(TransactionOutput, GasProfiler)> {
+        let mut vm = LibraVM::new();
+        let data_cache = StateViewCache::new(state_view);
+        vm.load_configs_impl(&data_cache);
+        let txn = txn
+            .check_signature()
+            .map_err(|_| VMStatus::new(StatusCode::INVALID_SIGNATURE))?;
+        let mut profiler = GasProfiler::new();
+        let output = vm.execute_user_transaction(&data_cache, &txn, Some(&mut profiler));
+        Ok((output, profiler))
+    }
+
     fn on_chain_config(&self) -> VMResult<&VMConfig> {
         self.on_chain_config
             .as_ref()
@@ -326,21 +351,23 @@ impl LibraVM {
     }
 
     fn execute_verified_payload(
-        &mut self,
-        remote_cache: &mut StateViewCache<'_>,
+        &self,
+        remote_cache: &dyn RemoteCache,
         txn_data: &TransactionMetadata,
         payload: VerifiedTransactionPayload,
         account_currency_symbol: &IdentStr,
+        profiler: Option<&mut GasProfiler>,
     ) -> TransactionOutput {
         let gas_schedule = match self.get_gas_schedule() {
             Ok(s) => s,
             Err(e) => return discard_error_output(e),
         };
         let mut cost_strategy = CostStrategy::transaction(gas_schedule, txn_data.max_gas_amount());
+        if profiler.is_some() {
+            cost_strategy = cost_strategy.with_profiler();
+        }
         let mut data_store = TransactionDataCache::new(remote_cache);
-        // TODO: The logic for handling falied transaction fee is pretty ugly right now. Fix it later.
-        let mut failed_gas_left = GasUnits::new(0);
-        match payload {
+        let result = match payload {
             VerifiedTransactionPayload::Module(m) => cost_strategy
                 .charge_intrinsic_gas(txn_data.transaction_size())
                 .and_then(|_| {
@@ -349,17 +376,27 @@ impl LibraVM {
                     // This will change to the sender's address once module publishing becomes open.
                     // REVIEW: should we check that the address of the Module is in fact
                     // `CORE_CODE_ADDRESS`?
-                    let module_address = if self.on_chain_config()?.publishing_option.is_open() {
+                    let publishing_option = &self.on_chain_config()?.publishing_option;
+                    let module_address = if publishing_option.is_open() {
                         txn_data.sender()
                     } else {
                         account_config::CORE_CODE_ADDRESS
                     };
-                    self.move_vm.publish_module(
-                        m,
-                        module_address,
-                        &mut data_store,
-                        &mut cost_strategy,
-                    )
+                    if publishing_option.allows_module_upgrades() {
+                        self.move_vm.publish_module_upgrade(
+                            m,
+                            module_address,
+                            &mut data_store,
+                            &mut cost_strategy,
+                        )
+                    } else {
+                        self.move_vm.publish_module(
+                            m,
+                            module_address,
+                            &mut data_store,
+                            &mut cost_strategy,
+                        )
+                    }
                 }),
             VerifiedTransactionPayload::Script(s, ty_args, args) => {
                 let ret = cost_strategy
@@ -381,39 +418,41 @@ impl LibraVM {
                 TXN_EXECUTION_GAS_USAGE.observe(gas_usage as f64);
                 ret
             }
+        };
+        // TODO: The logic for handling falied transaction fee is pretty ugly right now. Fix it later.
+        let failed_gas_left = cost_strategy.remaining_gas();
+        // The epilogues are not charged for, so the profile covers all the gas used.
+        if let (Some(profiler), Some(profile)) = (profiler, cost_strategy.take_profiler()) {
+            *profiler = profile;
         }
-        .map_err(|err| {
-            failed_gas_left = cost_strategy.remaining_gas();
-            err
-        })
-        .and_then(|_| {
-            failed_gas_left = cost_strategy.remaining_gas();
-            let mut cost_strategy = CostStrategy::system(gas_schedule, failed_gas_left);
-            self.run_success_epilogue(
-                &mut data_store,
-                &mut cost_strategy,
-                txn_data,
-                account_currency_symbol,
-            )
+        result
             .and_then(|_| {
-                get_transaction_output(
+                let mut cost_strategy = CostStrategy::system(gas_schedule, failed_gas_left);
+                self.run_success_epilogue(
                     &mut data_store,
-                    &cost_strategy,
+                    &mut cost_strategy,
                     txn_data,
-                    VMStatus::new(StatusCode::EXECUTED),
+                    account_currency_symbol,
+                )
+                .and_then(|_| {
+                    get_transaction_output(
+                        &mut data_store,
+                        &cost_strategy,
+                        txn_data,
+                        VMStatus::new(StatusCode::EXECUTED),
+                    )
+                })
+            })
+            .unwrap_or_else(|err| {
+                self.failed_transaction_cleanup(
+                    err,
+                    gas_schedule,
+                    failed_gas_left,
+                    txn_data,
+                    remote_cache,
+                    account_currency_symbol,
                 )
             })
-        })
-        .unwrap_or_else(|err| {
-            self.failed_transaction_cleanup(
-                err,
-                gas_schedule,
-                failed_gas_left,
-                txn_data,
-                remote_cache,
-                account_currency_symbol,
-            )
-        })
     }
 
     /// Generates a transaction output for a transaction that encountered errors during the
@@ -424,7 +463,7 @@ impl LibraVM {
         gas_schedule: &CostTable,
         gas_left: GasUnits<GasCarrier>,
         txn_data: &TransactionMetadata,
-        remote_cache: &mut StateViewCache<'_>,
+        remote_cache: &dyn RemoteCache,
         account_currency_symbol: &IdentStr,
     ) -> TransactionOutput {
         let mut cost_strategy = CostStrategy::system(gas_schedule, gas_left);
@@ -447,10 +486,10 @@ impl LibraVM {
     }
 
     fn execute_user_transaction(
-        &mut self,
-        _state_view: &dyn StateView,
-        remote_cache: &mut StateViewCache<'_>,
+        &self,
+        remote_cache: &dyn RemoteCache,
         txn: &SignatureCheckedTransaction,
+        profiler: Option<&mut GasProfiler>,
     ) -> TransactionOutput {
         let txn_data = TransactionMetadata::new(txn);
         let account_currency_symbol =
@@ -460,7 +499,7 @@ impl LibraVM {
             let _timer = TXN_VERIFICATION_SECONDS.start_timer();
             self.verify_user_transaction_impl(txn, remote_cache, &currency_code)
         });
-        let result = verified_payload
+        verified_payload
             .and_then(|verified_payload| {
                 account_currency_symbol.and_then(|account_currency_symbol| {
                     let _timer = TXN_EXECUTION_SECONDS.start_timer();
@@ -469,14 +508,11 @@ impl LibraVM {
                         &txn_data,
                         verified_payload,
                         &account_currency_symbol,
+                        profiler,
                     ))
                 })
             })
-            .unwrap_or_else(discard_error_output);
-        if let TransactionStatus::Keep(_) = result.status() {
-            remote_cache.push_write_set(result.write_set())
-        };
-        result
+            .unwrap_or_else(discard_error_output)
     }
 
     fn read_writeset(
@@ -839,6 +875,7 @@ impl LibraVM {
         &mut self,
         transactions: Vec<Transaction>,
         state_view: &dyn StateView,
+        sync_view: Option<&(dyn StateView + Sync)>,
     ) -> VMResult<Vec<TransactionOutput>> {
         let count = transactions.len();
         let mut result = vec![];
@@ -853,7 +890,7 @@ impl LibraVM {
                         current_block_id,
                         txns,
                         &mut data_cache,
-                        state_view,
+                        sync_view,
                     )?;
                     result.append(&mut outs);
                 }
@@ -887,7 +924,7 @@ impl LibraVM {
         block_id: HashValue,
         txn_block: Vec<SignedTransaction>,
         data_cache: &mut StateViewCache<'_>,
-        state_view: &dyn StateView,
+        sync_view: Option<&(dyn StateView + Sync)>,
     ) -> VMResult<Vec<TransactionOutput>> {
         self.load_configs_impl(data_cache);
         let signature_verified_block: Vec<Result<SignatureCheckedTransaction, VMStatus>>;
@@ -901,17 +938,31 @@ impl LibraVM {
                 })
                 .collect();
         }
-        let mut result = vec![];
         trace_code_block!("libra_vm::execute_transactions", {"block", block_id});
-        for transaction in signature_verified_block {
-            let output = match transaction {
-                Ok(txn) => {
-                    let _timer = TXN_TOTAL_SECONDS.start_timer();
-                    self.execute_user_transaction(state_view, data_cache, &txn)
+        // Speculation relies on the loaded code not changing within the block, so blocks that
+        // publish modules are always executed sequentially.
+        let result = match sync_view {
+            Some(sync_view) if !publishes_modules(&signature_verified_block) => {
+                self.execute_speculatively(&signature_verified_block, data_cache, sync_view)
+            }
+            _ => {
+                let mut result = vec![];
+                for transaction in &signature_verified_block {
+                    let output = self.execute_checked_transaction(&*data_cache, transaction);
+                    if let TransactionStatus::Keep(_) = output.status() {
+                        data_cache.push_write_set(output.write_set())
+                    };
+
+                    // `result` is initially empty, a single element is pushed per loop iteration and
+                    // the number of iterations is bound to the max size of `signature_verified_block`
+                    assume!(result.len() < usize::max_value());
+                    result.push(output);
                 }
-                Err(e) => discard_error_output(e),
-            };
+                result
+            }
+        };
 
+        for output in &result {
             // Increment the counter for transactions executed.
             let counter_label = match output.status() {
                 TransactionStatus::Keep(_) => Some("success"),
@@ -921,13 +972,64 @@ impl LibraVM {
             if let Some(label) = counter_label {
                 TRANSACTIONS_EXECUTED.with_label_values(&[label]).inc();
             }
+        }
+        Ok(result)
+    }
 
-            // `result` is initially empty, a single element is pushed per loop iteration and
-            // the number of iterations is bound to the max size of `signature_verified_block`
-            assume!(result.len() < usize::max_value());
+    /// Executes all transactions in parallel against the state at the start of the block while
+    /// recording the access paths each of them reads. The outputs are then committed in order, a
+    /// transaction that read an access path written by an earlier transaction in the block is
+    /// executed again on top of the up to date state. This produces exactly the same outputs as
+    /// executing the transactions one after the other.
+    /// `sync_view` must be the state view `data_cache` was created from.
+    fn execute_speculatively(
+        &self,
+        txn_block: &[Result<SignatureCheckedTransaction, VMStatus>],
+        data_cache: &mut StateViewCache<'_>,
+        sync_view: &(dyn StateView + Sync),
+    ) -> Vec<TransactionOutput> {
+        let speculative_outputs: Vec<(TransactionOutput, BTreeSet<AccessPath>)> = {
+            let data_map = data_cache.data_map();
+            txn_block
+                .par_iter()
+                .map(|transaction| {
+                    let recorder = ReadSetRecorder::new(data_map, sync_view);
+                    let output = self.execute_checked_transaction(&recorder, transaction);
+                    (output, recorder.into_read_set())
+                })
+                .collect()
+        };
+
+        let mut written = HashSet::new();
+        let mut result = Vec::with_capacity(txn_block.len());
+        for (transaction, (output, read_set)) in txn_block.iter().zip(speculative_outputs) {
+            let output = if read_set.iter().any(|ap| written.contains(ap)) {
+                SPECULATIVE_EXECUTION_CONFLICTS.inc();
+                self.execute_checked_transaction(&*data_cache, transaction)
+            } else {
+                output
+            };
+            if let TransactionStatus::Keep(_) = output.status() {
+                data_cache.push_write_set(output.write_set());
+                written.extend(output.write_set().iter().map(|(ap, _)| ap.clone()));
+            };
             result.push(output);
         }
-        Ok(result)
+        result
+    }
+
+    fn execute_checked_transaction(
+        &self,
+        remote_cache: &dyn RemoteCache,
+        transaction: &Result<SignatureCheckedTransaction, VMStatus>,
+    ) -> TransactionOutput {
+        match transaction {
+            Ok(txn) => {
+                let _timer = TXN_TOTAL_SECONDS.start_timer();
+                self.execute_user_transaction(remote_cache, txn, None)
+            }
+            Err(e) => discard_error_output(e.clone()),
+        }
     }
 }
 
@@ -1035,7 +1137,49 @@ impl VMExecutor for LibraVM {
         state_view: &dyn StateView,
     ) -> VMResult<Vec<TransactionOutput>> {
         let mut vm = LibraVM::new();
-        vm.execute_block_impl(transactions, state_view)
+        vm.execute_block_impl(transactions, state_view, None)
+    }
+
+    /// Drops the versions of the modules written by the committed transactions from the code
+    /// cache, other than the committed ones.
+    fn on_transactions_committed(write_sets: &[&WriteSet]) {
+        for (access_path, write_op) in write_sets.iter().flat_map(|write_set| write_set.iter()) {
+            if access_path.path.first() != Some(&CODE_TAG) {
+                continue;
+            }
+            let blob = match write_op {
+                WriteOp::Value(blob) => Some(blob.as_slice()),
+                WriteOp::Deletion => None,
+            };
+            code_cache::commit_module(access_path, blob);
+        }
+    }
+}
+
+/// A `VMExecutor` that, given a state view that can be shared between threads, executes the user
+/// transactions of a block speculatively in parallel and re-executes those that conflict with
+/// earlier transactions, see `LibraVM::execute_speculatively`. Its outputs are identical to those
+/// of `LibraVM`.
+pub struct ParallelLibraVM;
+
+impl VMExecutor for ParallelLibraVM {
+    fn execute_block(
+        transactions: Vec<Transaction>,
+        state_view: &dyn StateView,
+    ) -> VMResult<Vec<TransactionOutput>> {
+        LibraVM::execute_block(transactions, state_view)
+    }
+
+    fn execute_block_with_sync_view(
+        transactions: Vec<Transaction>,
+        state_view: &(dyn StateView + Sync),
+    ) -> VMResult<Vec<TransactionOutput>> {
+        let mut vm = LibraVM::new();
+        vm.execute_block_impl(transactions, state_view, Some(state_view))
+    }
+
+    fn on_transactions_committed(write_sets: &[&WriteSet]) {
+        LibraVM::on_transactions_committed(write_sets)
     }
 }
 
@@ -1158,13 +1302,20 @@ pub fn chunk_block_transactions(txns: Vec<Transaction>) -> Vec<TransactionBlock>
     blocks
 }
 
+fn publishes_modules(txn_block: &[Result<SignatureCheckedTransaction, VMStatus>]) -> bool {
+    txn_block.iter().any(|transaction| match transaction {
+        Ok(txn) => matches!(txn.payload(), TransactionPayload::Module(_)),
+        Err(_) => false,
+    })
+}
+
 enum VerifiedTransactionPayload {
     Script(Vec<u8>, Vec<TypeTag>, Vec<Value>),
     Module(Vec<u8>),
 }
 
 /// Convert the transaction arguments into move values.
-fn convert_txn_args(args: &[TransactionArgument]) -> Vec<Value> {
+pub fn convert_txn_args(args: &[TransactionArgument]) -> Vec<Value> {
     args.iter()
         .map(|arg| match arg {
             TransactionArgument::U8(i) => Value::u8(*i),
@@ -390,6 +390,9 @@ pub enum StatusCode {
     GENERIC_MEMBER_OPCODE_MISMATCH = 1090,
     FUNCTION_RESOLUTION_FAILURE = 1091,
     INVALID_OPERATION_IN_SCRIPT = 1094,
+    // The new version of a published module changes the layout of a struct, or the signature
+    // or acquires of a public function of the version it replaces.
+    BACKWARD_INCOMPATIBLE_MODULE_UPDATE = 1095,
 
     // These are errors that the VM might raise if a violation of internal
     // invariants takes place.
@@ -0,0 +1,47 @@
+// Test that failures are reported with the source location of each frame. Line numbers are
+// relative to the text of the transaction, without comments and empty lines.
+
+//! account: alice
+
+//! sender: alice
+module M {
+    public fun check_positive(n: u64) {
+        assert(n > 0, 77);
+    }
+
+    public fun countdown(n: u64) {
+        check_positive(n);
+        countdown(n - 1)
+    }
+}
+// check: EXECUTED
+
+//! new-transaction
+//! sender: alice
+script {
+use {{alice}}::M;
+fun main() {
+    M::countdown(2);
+}
+}
+// check: "M::check_positive"
+// check: ":3:"
+// check: "M::countdown"
+// check: ":6:"
+// check: "Script::main"
+// check: ":4:"
+// check: ABORTED
+// check: 77
+
+//! new-transaction
+//! sender: alice
+script {
+use 0x1::Vector;
+fun main() {
+    let v = Vector::empty<u64>();
+    Vector::pop_back(&mut v);
+}
+}
+// check: "Script::main"
+// check: ":5:"
+// check: NATIVE_FUNCTION_ERROR
@@ -11,10 +11,12 @@ use move_lang::{
     compiled_unit::CompiledUnit,
     move_compile_no_report,
     shared::Address,
+    stack_trace::SourceMaps,
     test_utils::{read_bool_var, stdlib_files},
 };
 use std::{convert::TryFrom, fmt, io::Write, path::Path};
 use tempfile::NamedTempFile;
+use vm::errors::StackTrace;
 
 pub const STD_LIB_DIR: &str = "../../stdlib/modules";
 pub const FUNCTIONAL_TEST_DIR: &str = "tests";
@@ -22,15 +24,36 @@ pub const FUNCTIONAL_TEST_DIR: &str = "tests";
 struct MoveSourceCompiler {
     deps: Vec<String>,
     temp_files: Vec<NamedTempFile>,
+    stdlib_files: Vec<String>,
+    // The source maps of the stdlib are only added the first time they are needed.
+    stdlib_source_maps_added: bool,
+    source_maps: SourceMaps,
 }
 
 impl MoveSourceCompiler {
     fn new(stdlib_modules_file_names: Vec<String>) -> Self {
         MoveSourceCompiler {
-            deps: stdlib_modules_file_names,
+            deps: stdlib_modules_file_names.clone(),
             temp_files: vec![],
+            stdlib_files: stdlib_modules_file_names,
+            stdlib_source_maps_added: false,
+            source_maps: SourceMaps::new(),
         }
     }
+
+    fn add_stdlib_source_maps(&mut self) -> Result<()> {
+        let (files, units) =
+            move_compile_no_report(&self.stdlib_files, &[], Some(Address::LIBRA_CORE))?;
+        let units = match units {
+            Ok(units) => units,
+            Err(_) => bail!("Failed to compile the stdlib"),
+        };
+        self.source_maps.add_files(files);
+        for unit in &units {
+            self.source_maps.add_unit(unit);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -76,9 +99,11 @@ impl Compiler for MoveSourceCompiler {
                 if len != 1 {
                     bail!("Invalid input. Expected 1 compiled unit but got {}", len)
                 }
+                self.source_maps.add_files(files);
                 units.pop().unwrap()
             }
         };
+        self.source_maps.add_unit(&unit);
 
         Ok(match unit {
             CompiledUnit::Script { script, .. } => ScriptOrModule::Script(script),
@@ -95,6 +120,17 @@ impl Compiler for MoveSourceCompiler {
     fn use_compiled_genesis(&self) -> bool {
         false
     }
+
+    fn render_stack_trace(&mut self, stack_trace: &StackTrace) -> Option<String> {
+        let in_stdlib = stack_trace
+            .iter()
+            .any(|frame| !self.source_maps.contains(frame));
+        if in_stdlib && !self.stdlib_source_maps_added {
+            self.stdlib_source_maps_added = true;
+            self.add_stdlib_source_maps().ok()?;
+        }
+        Some(self.source_maps.render(stack_trace))
+    }
 }
 
 fn functional_testsuite(path: &Path) -> datatest_stable::Result<()> {
@@ -695,6 +695,8 @@ fn function(
 fn function_(context: &mut Context, pfunction: P::Function) -> (FunctionName, E::Function) {
     let P::Function {
         loc,
+        // checked, and test functions removed, by `unit_test::filter_tests`
+        attributes: _attributes,
         name,
         visibility,
         signature: psignature,
@@ -13,19 +13,22 @@ pub mod errors;
 pub mod expansion;
 pub mod hlir;
 pub mod ir_translation;
+pub mod lint;
 pub mod naming;
 pub mod parser;
 pub mod shared;
+pub mod stack_trace;
 pub mod test_utils;
 mod to_bytecode;
 pub mod typing;
+pub mod unit_test;
 
 use anyhow::anyhow;
 use codespan::{ByteIndex, Span};
 use compiled_unit::CompiledUnit;
 use errors::*;
 use move_ir_types::location::*;
-use parser::syntax::parse_file_string;
+pub use parser::syntax::parse_file_string;
 use shared::Address;
 use std::{
     collections::{BTreeMap, HashMap},
@@ -35,6 +38,7 @@ use std::{
     path::{Path, PathBuf},
     str::Chars,
 };
+use unit_test::TestCase;
 
 pub const MOVE_EXTENSION: &str = "move";
 pub const MOVE_COMPILED_EXTENSION: &str = "mv";
@@ -107,6 +111,26 @@ pub fn move_compile_no_report(
     })
 }
 
+/// Move compile including the test functions of the targets, returning errors instead of
+/// reporting them to stderr. Also returns the test functions found, see `unit_test`.
+pub fn move_compile_tests(
+    targets: &[String],
+    deps: &[String],
+    sender_opt: Option<Address>,
+) -> anyhow::Result<(
+    FilesSourceText,
+    Result<(Vec<CompiledUnit>, Vec<TestCase>), Errors>,
+)> {
+    let (files, pprog_and_comments_res) = parse_program(targets, deps)?;
+    let res = pprog_and_comments_res.and_then(|(pprog, _)| {
+        let (pprog, tests) = unit_test::filter_tests(pprog, sender_opt, true)?;
+        let cprog = check_parsed_program(pprog, sender_opt)?;
+        let units = to_bytecode::translate::program(cprog)?;
+        Ok((units, tests))
+    });
+    Ok((files, res))
+}
+
 /// Move compile up to expansion phase, returning errors instead of reporting them to stderr.
 ///
 /// This also returns a map containing documentation comments for each source in `targets`.
@@ -120,6 +144,7 @@ pub fn move_compile_to_expansion_no_report(
 )> {
     let (files, pprog_and_comments_res) = parse_program(targets, deps)?;
     let res = pprog_and_comments_res.and_then(|(pprog, comment_map)| {
+        let (pprog, _) = unit_test::filter_tests(pprog, sender_opt, false)?;
         let (eprog, errors) = expansion::translate::program(pprog, sender_opt);
         check_errors(errors)?;
         Ok((eprog, comment_map))
@@ -127,6 +152,55 @@ pub fn move_compile_to_expansion_no_report(
     Ok((files, res))
 }
 
+/// Move check up to the typing phase, returning errors instead of reporting them to stderr.
+///
+/// The typed program is returned whenever the targets parse, even if they have errors, so that
+/// tools can inspect the parts of it that are well-formed.
+pub fn move_check_to_typing_no_report(
+    targets: &[String],
+    deps: &[String],
+    sender_opt: Option<Address>,
+) -> anyhow::Result<(FilesSourceText, Option<typing::ast::Program>, Errors)> {
+    let (files, pprog_and_comments_res) = parse_program(targets, deps)?;
+    let pprog = match pprog_and_comments_res
+        .and_then(|(pprog, _)| unit_test::filter_tests(pprog, sender_opt, false))
+    {
+        Ok((pprog, _)) => pprog,
+        Err(errors) => return Ok((files, None, errors)),
+    };
+    let (eprog, errors) = expansion::translate::program(pprog, sender_opt);
+    let (nprog, errors) = naming::translate::program(eprog, errors);
+    let (tprog, errors) = typing::translate::program(nprog, errors);
+    Ok((files, Some(tprog), errors))
+}
+
+/// Move check the targets and run the lint `passes` over them, returning the findings, or the
+/// errors that prevent linting, instead of reporting them to stderr.
+pub fn move_lint_no_report(
+    targets: &[String],
+    deps: &[String],
+    sender_opt: Option<Address>,
+    passes: &[Box<dyn lint::LintPass>],
+    config: &lint::LintConfig,
+) -> anyhow::Result<(FilesSourceText, Result<Vec<lint::Finding>, Errors>)> {
+    let (files, pprog_and_comments_res) = parse_program(targets, deps)?;
+    let res = pprog_and_comments_res.and_then(|(pprog, _)| {
+        let (pprog, _) = unit_test::filter_tests(pprog, sender_opt, false)?;
+        let (eprog, errors) = expansion::translate::program(pprog, sender_opt);
+        let (nprog, errors) = naming::translate::program(eprog, errors);
+        let (tprog, errors) = typing::translate::program(nprog, errors);
+        check_errors(errors)?;
+        let mut linter = lint::Linter::new(passes, config);
+        linter.typing(&tprog);
+        let (hprog, errors) = hlir::translate::program(tprog);
+        let (cprog, errors) = cfgir::translate::program(errors, hprog);
+        check_errors(errors)?;
+        linter.cfgir(&cprog);
+        Ok(linter.finish())
+    });
+    Ok((files, res))
+}
+
 //**************************************************************************************************
 // Utils
 //**************************************************************************************************
@@ -205,7 +279,15 @@ fn check_program(
     prog: Result<parser::ast::Program, Errors>,
     sender_opt: Option<Address>,
 ) -> Result<cfgir::ast::Program, Errors> {
-    let (eprog, errors) = expansion::translate::program(prog?, sender_opt);
+    let (prog, _) = unit_test::filter_tests(prog?, sender_opt, false)?;
+    check_parsed_program(prog, sender_opt)
+}
+
+fn check_parsed_program(
+    prog: parser::ast::Program,
+    sender_opt: Option<Address>,
+) -> Result<cfgir::ast::Program, Errors> {
+    let (eprog, errors) = expansion::translate::program(prog, sender_opt);
     let (nprog, errors) = naming::translate::program(eprog, errors);
     let (tprog, errors) = typing::translate::program(nprog, errors);
     check_errors(errors)?;
@@ -573,7 +655,7 @@ fn strip_comments(fname: &'static str, input: &str) -> Result<(String, FileComme
 
 // We restrict strings to only ascii visual characters (0x20 <= c <= 0x7E) or a permitted newline
 // character--\n--or a tab--\t.
-fn strip_comments_and_verify(
+pub fn strip_comments_and_verify(
     fname: &'static str,
     string: &str,
 ) -> Result<(String, FileCommentMap), Errors> {
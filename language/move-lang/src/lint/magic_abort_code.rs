@@ -0,0 +1,64 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{visit_sequence, LintContext, LintPass};
+use crate::{
+    expansion::ast::Value_,
+    typing::ast::{self as T, BuiltinFunction_, ExpListItem},
+};
+
+/// Finds `abort` and `assert` given a literal code instead of a named constant, which callers
+/// cannot refer to when they handle the abort.
+pub struct MagicAbortCode;
+
+impl LintPass for MagicAbortCode {
+    fn name(&self) -> &'static str {
+        "magic_abort_code"
+    }
+
+    fn description(&self) -> &'static str {
+        "Abort codes given as numbers instead of named constants"
+    }
+
+    fn check_typing_function(&self, context: &mut LintContext, function: &T::Function) {
+        use T::UnannotatedExp_ as E;
+        let seq = match &function.body.value {
+            T::FunctionBody_::Defined(seq) => seq,
+            T::FunctionBody_::Native => return,
+        };
+        let mut codes = vec![];
+        visit_sequence(seq, &mut |e| match &e.exp.value {
+            E::Abort(code) => codes.push(code.as_ref()),
+            E::Builtin(builtin, args) if builtin.value == BuiltinFunction_::Assert => {
+                if let E::ExpList(items) = &args.exp.value {
+                    if let Some(ExpListItem::Single(code, _)) = items.get(1) {
+                        codes.push(code)
+                    }
+                }
+            }
+            _ => (),
+        });
+        for code in codes {
+            if let Some(literal) = literal(code) {
+                context.report(
+                    code.exp.loc,
+                    format!(
+                        "Magic number '{}' used as an abort code. Consider declaring a constant \
+                         for it",
+                        literal
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn literal(e: &T::Exp) -> Option<u128> {
+    use T::UnannotatedExp_ as E;
+    match &e.exp.value {
+        E::Value(sp!(_, Value_::U64(u))) => Some(u128::from(*u)),
+        E::InferredNum(u) => Some(*u),
+        E::Annotate(e, _) => literal(e),
+        _ => None,
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lints over checked Move programs.
+//!
+//! A lint is a `LintPass` that inspects the functions of the source modules and scripts, either in
+//! the typed program (`typing`) or once they are lowered to control flow graphs (`cfgir`), and
+//! reports findings. Unlike errors, findings never stop compilation: each lint has a level, which a
+//! `LintConfig` can override for all modules or for some of them.
+//!
+//! Some problems one might expect lints for are already errors of the compiler, and are not linted:
+//! unused `use` aliases (expansion), unused `acquires` annotations (typing), unused parameters
+//! (hlir), and unused `let` bindings or assignments (cfgir).
+
+mod magic_abort_code;
+mod unchecked_arithmetic;
+mod unused_signer;
+mod vector_copy;
+
+use crate::{
+    cfgir::ast as G,
+    hlir::ast::{self as H, Command_},
+    parser::ast::FunctionName,
+    typing::ast as T,
+};
+use move_ir_types::location::*;
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+pub use magic_abort_code::MagicAbortCode;
+pub use unchecked_arithmetic::UncheckedArithmetic;
+pub use unused_signer::UnusedSigner;
+pub use vector_copy::VectorCopy;
+
+//**************************************************************************************************
+// Passes
+//**************************************************************************************************
+
+/// A lint. Its checks are called on every function of the source modules and scripts, except
+/// where its level is `Allow`.
+pub trait LintPass {
+    /// The name the lint is configured and reported by.
+    fn name(&self) -> &'static str;
+
+    /// What the lint finds, in a sentence.
+    fn description(&self) -> &'static str;
+
+    /// The level of the lint where it is not configured.
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    /// Checks a function of the typed program.
+    fn check_typing_function(&self, _context: &mut LintContext, _function: &T::Function) {}
+
+    /// Checks a function once lowered to a control flow graph, and optimized.
+    fn check_cfgir_function(&self, _context: &mut LintContext, _function: &G::Function) {}
+}
+
+/// The lints of the compiler.
+pub fn default_passes() -> Vec<Box<dyn LintPass>> {
+    vec![
+        Box::new(UnusedSigner),
+        Box::new(MagicAbortCode),
+        Box::new(VectorCopy),
+        Box::new(UncheckedArithmetic),
+    ]
+}
+
+//**************************************************************************************************
+// Configuration
+//**************************************************************************************************
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// The lint is not run.
+    Allow,
+    /// Findings are reported.
+    Warn,
+    /// Findings are reported, and should fail the build.
+    Deny,
+}
+
+impl LintLevel {
+    pub const ALLOW: &'static str = "allow";
+    pub const WARN: &'static str = "warn";
+    pub const DENY: &'static str = "deny";
+}
+
+impl FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::ALLOW => Ok(LintLevel::Allow),
+            Self::WARN => Ok(LintLevel::Warn),
+            Self::DENY => Ok(LintLevel::Deny),
+            _ => Err(format!(
+                "Invalid lint level '{}'. Expected '{}', '{}' or '{}'",
+                s,
+                Self::ALLOW,
+                Self::WARN,
+                Self::DENY
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            LintLevel::Allow => Self::ALLOW,
+            LintLevel::Warn => Self::WARN,
+            LintLevel::Deny => Self::DENY,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// The levels of the lints, where they differ from their defaults.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    /// Levels by lint name, for all modules and scripts.
+    pub levels: BTreeMap<String, LintLevel>,
+    /// Levels by lint name for a module, named like `0x1::Vector`, or a script. They take
+    /// precedence over `levels`.
+    pub module_levels: BTreeMap<String, BTreeMap<String, LintLevel>>,
+}
+
+impl LintConfig {
+    /// The level of `pass` in `module`.
+    pub fn level(&self, module: &str, pass: &dyn LintPass) -> LintLevel {
+        self.module_levels
+            .get(module)
+            .and_then(|levels| levels.get(pass.name()))
+            .or_else(|| self.levels.get(pass.name()))
+            .copied()
+            .unwrap_or_else(|| pass.default_level())
+    }
+}
+
+//**************************************************************************************************
+// Findings
+//**************************************************************************************************
+
+#[derive(Clone, Debug)]
+pub struct Finding {
+    pub lint: &'static str,
+    pub level: LintLevel,
+    /// The module, like `0x1::Vector`, or the script of the function.
+    pub module: String,
+    pub function: String,
+    pub loc: Loc,
+    pub message: String,
+}
+
+/// Where findings are reported by the lint being run.
+pub struct LintContext {
+    lint: &'static str,
+    level: LintLevel,
+    module: String,
+    function: String,
+    findings: Vec<Finding>,
+}
+
+impl LintContext {
+    pub fn report(&mut self, loc: Loc, message: impl Into<String>) {
+        self.findings.push(Finding {
+            lint: self.lint,
+            level: self.level,
+            module: self.module.clone(),
+            function: self.function.clone(),
+            loc,
+            message: message.into(),
+        })
+    }
+}
+
+//**************************************************************************************************
+// Linter
+//**************************************************************************************************
+
+/// Runs lint passes over a program, as it goes through the compiler.
+pub struct Linter<'a> {
+    passes: &'a [Box<dyn LintPass>],
+    config: &'a LintConfig,
+    context: LintContext,
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(passes: &'a [Box<dyn LintPass>], config: &'a LintConfig) -> Self {
+        Self {
+            passes,
+            config,
+            context: LintContext {
+                lint: "",
+                level: LintLevel::Allow,
+                module: String::new(),
+                function: String::new(),
+                findings: vec![],
+            },
+        }
+    }
+
+    pub fn typing(&mut self, prog: &T::Program) {
+        for (mident, mdef) in &prog.modules {
+            if !mdef.is_source_module {
+                continue;
+            }
+            let module = mident.to_string();
+            for (name, function) in &mdef.functions {
+                self.run(&module, &name, |pass, context| {
+                    pass.check_typing_function(context, function)
+                })
+            }
+        }
+        for (script_name, script) in &prog.scripts {
+            self.run(script_name, &script.function_name, |pass, context| {
+                pass.check_typing_function(context, &script.function)
+            })
+        }
+    }
+
+    pub fn cfgir(&mut self, prog: &G::Program) {
+        for (mident, mdef) in &prog.modules {
+            if !mdef.is_source_module {
+                continue;
+            }
+            let module = mident.to_string();
+            for (name, function) in &mdef.functions {
+                self.run(&module, &name, |pass, context| {
+                    pass.check_cfgir_function(context, function)
+                })
+            }
+        }
+        for (script_name, script) in &prog.scripts {
+            self.run(script_name, &script.function_name, |pass, context| {
+                pass.check_cfgir_function(context, &script.function)
+            })
+        }
+    }
+
+    /// The findings, in source order.
+    pub fn finish(self) -> Vec<Finding> {
+        let mut findings = self.context.findings;
+        findings.sort_by_key(|finding| {
+            let loc = finding.loc;
+            (loc.file(), loc.span().start(), finding.lint)
+        });
+        findings
+    }
+
+    fn run<F>(&mut self, module: &str, function: &FunctionName, mut check: F)
+    where
+        F: FnMut(&dyn LintPass, &mut LintContext),
+    {
+        for pass in self.passes {
+            let level = self.config.level(module, pass.as_ref());
+            if level == LintLevel::Allow {
+                continue;
+            }
+            self.context.lint = pass.name();
+            self.context.level = level;
+            self.context.module = module.to_string();
+            self.context.function = function.to_string();
+            check(pass.as_ref(), &mut self.context)
+        }
+    }
+}
+
+//**************************************************************************************************
+// Visitors
+//**************************************************************************************************
+
+/// Calls `f` on every expression of `seq`, outer expressions first.
+pub fn visit_sequence<'a>(seq: &'a T::Sequence, f: &mut dyn FnMut(&'a T::Exp)) {
+    use T::SequenceItem_ as S;
+    for sp!(_, item_) in seq {
+        match item_ {
+            S::Seq(e) | S::Bind(_, _, e) => visit_exp(e, f),
+            S::Declare(_) => (),
+        }
+    }
+}
+
+/// Calls `f` on `e` and on every expression in it, outer expressions first.
+pub fn visit_exp<'a>(e: &'a T::Exp, f: &mut dyn FnMut(&'a T::Exp)) {
+    use T::UnannotatedExp_ as E;
+    f(e);
+    match &e.exp.value {
+        E::Unit { .. }
+        | E::Value(_)
+        | E::InferredNum(_)
+        | E::Move { .. }
+        | E::Copy { .. }
+        | E::Use(_)
+        | E::Constant(_, _)
+        | E::Break
+        | E::Continue
+        | E::BorrowLocal(_, _)
+        | E::Spec(_, _)
+        | E::UnresolvedError => (),
+
+        E::ModuleCall(call) => visit_exp(&call.arguments, f),
+        E::Builtin(_, e)
+        | E::Loop { body: e, .. }
+        | E::Assign(_, _, e)
+        | E::Return(e)
+        | E::Abort(e)
+        | E::Dereference(e)
+        | E::UnaryExp(_, e)
+        | E::Borrow(_, e, _)
+        | E::TempBorrow(_, e)
+        | E::Cast(e, _)
+        | E::Annotate(e, _) => visit_exp(e, f),
+        E::IfElse(econd, et, ef) => {
+            visit_exp(econd, f);
+            visit_exp(et, f);
+            visit_exp(ef, f)
+        }
+        E::While(econd, ebody) => {
+            visit_exp(econd, f);
+            visit_exp(ebody, f)
+        }
+        E::Block(seq) => visit_sequence(seq, f),
+        E::Mutate(el, er) | E::BinopExp(el, _, _, er) => {
+            visit_exp(el, f);
+            visit_exp(er, f)
+        }
+        E::Pack(_, _, _, fields) => {
+            for (_, (_, (_, fe))) in fields {
+                visit_exp(fe, f)
+            }
+        }
+        E::ExpList(items) => {
+            for item in items {
+                match item {
+                    T::ExpListItem::Single(e, _) | T::ExpListItem::Splat(_, e, _) => {
+                        visit_exp(e, f)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Calls `f` on every expression of `cmd`, outer expressions first.
+pub fn visit_command<'a>(cmd: &'a H::Command, f: &mut dyn FnMut(&'a H::Exp)) {
+    match &cmd.value {
+        Command_::Assign(_, e) => visit_hlir_exp(e, f),
+        Command_::Mutate(el, er) => {
+            visit_hlir_exp(el, f);
+            visit_hlir_exp(er, f)
+        }
+        Command_::Abort(e)
+        | Command_::Return(e)
+        | Command_::IgnoreAndPop { exp: e, .. }
+        | Command_::JumpIf { cond: e, .. } => visit_hlir_exp(e, f),
+        Command_::Break | Command_::Continue | Command_::Jump(_) => (),
+    }
+}
+
+/// Calls `f` on `e` and on every expression in it, outer expressions first.
+pub fn visit_hlir_exp<'a>(e: &'a H::Exp, f: &mut dyn FnMut(&'a H::Exp)) {
+    use H::UnannotatedExp_ as E;
+    f(e);
+    match &e.exp.value {
+        E::Unit { .. }
+        | E::Value(_)
+        | E::Move { .. }
+        | E::Copy { .. }
+        | E::Constant(_)
+        | E::BorrowLocal(_, _)
+        | E::Unreachable
+        | E::Spec(_, _)
+        | E::UnresolvedError => (),
+
+        E::ModuleCall(call) => visit_hlir_exp(&call.arguments, f),
+        E::Builtin(_, e)
+        | E::Freeze(e)
+        | E::Dereference(e)
+        | E::UnaryExp(_, e)
+        | E::Borrow(_, e, _)
+        | E::Cast(e, _) => visit_hlir_exp(e, f),
+        E::BinopExp(el, _, er) => {
+            visit_hlir_exp(el, f);
+            visit_hlir_exp(er, f)
+        }
+        E::Pack(_, _, fields) => {
+            for (_, _, fe) in fields {
+                visit_hlir_exp(fe, f)
+            }
+        }
+        E::ExpList(items) => {
+            for item in items {
+                match item {
+                    H::ExpListItem::Single(e, _) | H::ExpListItem::Splat(_, e, _) => {
+                        visit_hlir_exp(e, f)
+                    }
+                }
+            }
+        }
+    }
+}
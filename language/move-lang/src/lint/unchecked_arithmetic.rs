@@ -0,0 +1,128 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{visit_command, visit_hlir_exp, LintContext, LintPass};
+use crate::{
+    cfgir::ast as G,
+    hlir::ast::{BasicBlocks, Command, Exp, Label, UnannotatedExp_},
+    parser::ast::BinOp_,
+    shared::*,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Finds `+`, `-` and `*` that can overflow or underflow, and so abort, because none of the locals
+/// they are computed from is compared beforehand. A comparison anywhere on a path to the operation
+/// counts as a check, as in `if (x > 0) x - 1` or `while (i < n) { ...; i = i + 1 }`.
+pub struct UncheckedArithmetic;
+
+impl LintPass for UncheckedArithmetic {
+    fn name(&self) -> &'static str {
+        "unchecked_arithmetic"
+    }
+
+    fn description(&self) -> &'static str {
+        "Arithmetic that can abort on overflow, on locals never compared before"
+    }
+
+    fn check_cfgir_function(&self, context: &mut LintContext, function: &G::Function) {
+        let (start, blocks) = match &function.body.value {
+            G::FunctionBody_::Defined { start, blocks, .. } => (*start, blocks),
+            G::FunctionBody_::Native => return,
+        };
+        let predecessors = predecessors(start, blocks);
+        for (label, block) in blocks {
+            // Locals compared in the blocks leading to this one
+            let mut compared = BTreeSet::new();
+            for ancestor in ancestors(&predecessors, *label) {
+                for cmd in &blocks[&ancestor] {
+                    compared.extend(compared_locals(cmd));
+                }
+            }
+            for cmd in block {
+                visit_command(cmd, &mut |e| {
+                    let (lhs, op, rhs) = match &e.exp.value {
+                        UnannotatedExp_::BinopExp(lhs, op, rhs) => (lhs, op, rhs),
+                        _ => return,
+                    };
+                    let outcome = match &op.value {
+                        BinOp_::Add | BinOp_::Mul => "overflow",
+                        BinOp_::Sub => "underflow",
+                        _ => return,
+                    };
+                    let mut operands = locals(lhs);
+                    operands.extend(locals(rhs));
+                    if operands.is_empty() || !operands.is_disjoint(&compared) {
+                        return;
+                    }
+                    context.report(
+                        e.exp.loc,
+                        format!(
+                            "Unchecked '{}', which aborts on {}. Consider comparing its operands \
+                             first, to abort with a meaningful code",
+                            op.value, outcome
+                        ),
+                    )
+                });
+                compared.extend(compared_locals(cmd));
+            }
+        }
+    }
+}
+
+fn predecessors(start: Label, blocks: &BasicBlocks) -> BTreeMap<Label, BTreeSet<Label>> {
+    let mut predecessors = BTreeMap::new();
+    predecessors.insert(start, BTreeSet::new());
+    for (label, block) in blocks {
+        if let Some(last) = block.back() {
+            for successor in last.value.successors() {
+                predecessors
+                    .entry(successor)
+                    .or_insert_with(BTreeSet::new)
+                    .insert(*label);
+            }
+        }
+    }
+    predecessors
+}
+
+/// The blocks from which there is a path to `label`, not including `label` unless it is in a loop.
+fn ancestors(predecessors: &BTreeMap<Label, BTreeSet<Label>>, label: Label) -> BTreeSet<Label> {
+    let mut ancestors = BTreeSet::new();
+    let mut work_list = vec![label];
+    while let Some(label) = work_list.pop() {
+        for predecessor in predecessors.get(&label).into_iter().flatten() {
+            if ancestors.insert(*predecessor) {
+                work_list.push(*predecessor)
+            }
+        }
+    }
+    ancestors
+}
+
+/// The names of the locals in the operands of the comparisons of `cmd`.
+fn compared_locals(cmd: &Command) -> BTreeSet<String> {
+    let mut compared = BTreeSet::new();
+    visit_command(cmd, &mut |e| {
+        if let UnannotatedExp_::BinopExp(lhs, op, rhs) = &e.exp.value {
+            if let BinOp_::Lt | BinOp_::Le | BinOp_::Gt | BinOp_::Ge = &op.value {
+                compared.extend(locals(lhs));
+                compared.extend(locals(rhs));
+            }
+        }
+    });
+    compared
+}
+
+/// The names of the locals `e` is computed from.
+fn locals(e: &Exp) -> BTreeSet<String> {
+    let mut locals = BTreeSet::new();
+    visit_hlir_exp(e, &mut |e| match &e.exp.value {
+        UnannotatedExp_::Move { var, .. }
+        | UnannotatedExp_::Copy { var, .. }
+        | UnannotatedExp_::BorrowLocal(_, var) => {
+            locals.insert(var.value().to_string());
+        }
+        _ => (),
+    });
+    locals
+}
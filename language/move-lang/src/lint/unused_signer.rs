@@ -0,0 +1,71 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{visit_sequence, LintContext, LintPass};
+use crate::{
+    naming::ast::{BuiltinTypeName_, Type, TypeName_, Type_},
+    parser::ast::FunctionVisibility,
+    shared::*,
+    typing::ast as T,
+};
+use std::collections::BTreeSet;
+
+/// Finds public functions that take a signer but never use it, which the compiler accepts when the
+/// parameter is named with a leading underscore. Callers have to give the function the authority
+/// of an account, and should not need to when nothing is done with it.
+pub struct UnusedSigner;
+
+impl LintPass for UnusedSigner {
+    fn name(&self) -> &'static str {
+        "unused_signer"
+    }
+
+    fn description(&self) -> &'static str {
+        "Public functions taking a signer they never use"
+    }
+
+    fn check_typing_function(&self, context: &mut LintContext, function: &T::Function) {
+        if !matches!(function.visibility, FunctionVisibility::Public(_)) {
+            return;
+        }
+        let seq = match &function.body.value {
+            T::FunctionBody_::Defined(seq) => seq,
+            T::FunctionBody_::Native => return,
+        };
+        let used = used_locals(seq);
+        for (param, ty) in &function.signature.parameters {
+            if is_signer(ty) && !used.contains(param.value()) {
+                context.report(
+                    param.loc(),
+                    format!(
+                        "Unused signer '{}'. Public functions should not require the authority of \
+                         an account they do not use",
+                        param
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn is_signer(ty: &Type) -> bool {
+    match &ty.value {
+        Type_::Ref(_, inner) => is_signer(inner),
+        Type_::Apply(_, sp!(_, TypeName_::Builtin(sp!(_, BuiltinTypeName_::Signer))), _) => true,
+        _ => false,
+    }
+}
+
+/// The names of the locals read, borrowed or mentioned in specifications in `seq`.
+fn used_locals(seq: &T::Sequence) -> BTreeSet<&str> {
+    use T::UnannotatedExp_ as E;
+    let mut used = BTreeSet::new();
+    visit_sequence(seq, &mut |e| match &e.exp.value {
+        E::Move { var, .. } | E::Copy { var, .. } | E::Use(var) | E::BorrowLocal(_, var) => {
+            used.insert(var.value());
+        }
+        E::Spec(_, spec_used) => used.extend(spec_used.keys().map(|var| var.value())),
+        _ => (),
+    });
+    used
+}
@@ -0,0 +1,69 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{visit_command, LintContext, LintPass};
+use crate::{
+    cfgir::ast as G,
+    hlir::{
+        ast::{BaseType_, SingleType_, TypeName_, Type_, UnannotatedExp_},
+        translate::{display_var, DisplayVar},
+    },
+    naming::ast::BuiltinTypeName_,
+    shared::*,
+};
+
+/// Finds copies of vectors, by `copy` of a local or by dereference. Copying a vector copies all of
+/// its elements, so costs gas in proportion to its length.
+pub struct VectorCopy;
+
+impl LintPass for VectorCopy {
+    fn name(&self) -> &'static str {
+        "vector_copy"
+    }
+
+    fn description(&self) -> &'static str {
+        "Copies of vectors, which copy all of their elements"
+    }
+
+    fn check_cfgir_function(&self, context: &mut LintContext, function: &G::Function) {
+        let blocks = match &function.body.value {
+            G::FunctionBody_::Defined { blocks, .. } => blocks,
+            G::FunctionBody_::Native => return,
+        };
+        for block in blocks.values() {
+            for cmd in block {
+                visit_command(cmd, &mut |e| {
+                    if !is_vector(&e.ty.value) {
+                        return;
+                    }
+                    let copied = match &e.exp.value {
+                        UnannotatedExp_::Copy { var, .. } => match display_var(var.value()) {
+                            DisplayVar::Orig(name) => format!("vector '{}'", name),
+                            DisplayVar::Tmp => return,
+                        },
+                        UnannotatedExp_::Dereference(_) => "dereferenced vector".to_string(),
+                        _ => return,
+                    };
+                    context.report(
+                        e.exp.loc,
+                        format!(
+                            "Copy of {}. Consider borrowing it instead of copying all of its \
+                             elements",
+                            copied
+                        ),
+                    )
+                })
+            }
+        }
+    }
+}
+
+fn is_vector(ty: &Type_) -> bool {
+    match ty {
+        Type_::Single(sp!(_, SingleType_::Base(sp!(_, BaseType_::Apply(_, tn, _))))) => matches!(
+            &tn.value,
+            TypeName_::Builtin(sp!(_, BuiltinTypeName_::Vector))
+        ),
+        _ => false,
+    }
+}
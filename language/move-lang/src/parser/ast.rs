@@ -158,12 +158,25 @@ pub enum FunctionBody_ {
 pub type FunctionBody = Spanned<FunctionBody_>;
 
 #[derive(PartialEq, Debug)]
+pub enum Attribute_ {
+    // <name>
+    Name(Name),
+    // <name> = <value>
+    Assigned(Name, Box<Value>),
+    // <name>(<attribute>, ..., <attribute>)
+    Parameterized(Name, Vec<Attribute>),
+}
+pub type Attribute = Spanned<Attribute_>;
+
+#[derive(PartialEq, Debug)]
+// #[attr, ..., attr]
 // (public?) foo<T1(: copyable?), ..., TN(: copyable?)>(x1: t1, ..., xn: tn): t1 * ... * tn {
 //    body
 //  }
 // (public?) native foo<T1(: copyable?), ..., TN(: copyable?)>(x1: t1, ..., xn: tn): t1 * ... * tn;
 pub struct Function {
     pub loc: Loc,
+    pub attributes: Vec<Attribute>,
     pub visibility: FunctionVisibility,
     pub signature: FunctionSignature,
     pub acquires: Vec<ModuleAccess>,
@@ -1022,12 +1035,18 @@ impl AstDebug for Function {
     fn ast_debug(&self, w: &mut AstWriter) {
         let Function {
             loc: _loc,
+            attributes,
             visibility,
             signature,
             acquires,
             name,
             body,
         } = self;
+        if !attributes.is_empty() {
+            w.write("#[");
+            w.comma(attributes, |w, attr| attr.ast_debug(w));
+            w.writeln("]");
+        }
         visibility.ast_debug(w);
         if let FunctionBody_::Native = &body.value {
             w.write("native ");
@@ -1046,6 +1065,23 @@ impl AstDebug for Function {
     }
 }
 
+impl AstDebug for Attribute_ {
+    fn ast_debug(&self, w: &mut AstWriter) {
+        match self {
+            Attribute_::Name(n) => w.write(&format!("{}", n)),
+            Attribute_::Assigned(n, v) => {
+                w.write(&format!("{} = ", n));
+                v.ast_debug(w);
+            }
+            Attribute_::Parameterized(n, attrs) => {
+                w.write(&format!("{}(", n));
+                w.comma(attrs, |w, attr| attr.ast_debug(w));
+                w.write(")");
+            }
+        }
+    }
+}
+
 impl AstDebug for FunctionVisibility {
     fn ast_debug(&self, w: &mut AstWriter) {
         match self {
@@ -18,6 +18,7 @@ pub enum Tok {
     IdentifierValue,
     Exclaim,
     ExclaimEqual,
+    NumSign,
     Percent,
     Amp,
     AmpAmp,
@@ -94,6 +95,7 @@ impl fmt::Display for Tok {
             IdentifierValue => "[Identifier]",
             Exclaim => "!",
             ExclaimEqual => "!=",
+            NumSign => "#",
             Percent => "%",
             Amp => "&",
             AmpAmp => "&&",
@@ -367,6 +369,7 @@ fn find_token(file: &'static str, text: &str, start_offset: usize) -> Result<(To
                 (Tok::Exclaim, 1)
             }
         }
+        '#' => (Tok::NumSign, 1),
         '<' => {
             if text.starts_with("<=") {
                 (Tok::LessEqual, 2)
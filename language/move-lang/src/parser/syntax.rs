@@ -1278,18 +1278,84 @@ fn parse_optional_type_parameters<'input>(
 // Functions
 //**************************************************************************************************
 
+// Parse an attribute:
+//      Attribute =
+//          <Identifier>
+//          | <Identifier> "=" <AttributeValue>
+//          | <Identifier> "(" Comma<Attribute> ")"
+//      AttributeValue =
+//          <Value>
+//          | <Num>
+//
+// A number without a suffix is parsed as a u128 value.
+fn parse_attribute<'input>(tokens: &mut Lexer<'input>) -> Result<Attribute, Error> {
+    let start_loc = tokens.start_loc();
+    let n = parse_identifier(tokens)?;
+    let attr = match tokens.peek() {
+        Tok::Equal => {
+            tokens.advance()?;
+            let value = match tokens.peek() {
+                Tok::NumValue => {
+                    let start_loc = tokens.start_loc();
+                    let i = parse_num(tokens)?;
+                    let end_loc = tokens.previous_end_loc();
+                    spanned(tokens.file_name(), start_loc, end_loc, Value_::U128(i))
+                }
+                Tok::AddressValue
+                | Tok::True
+                | Tok::False
+                | Tok::U8Value
+                | Tok::U64Value
+                | Tok::U128Value
+                | Tok::ByteStringValue => parse_value(tokens)?,
+                _ => return Err(unexpected_token_error(tokens, "a value")),
+            };
+            Attribute_::Assigned(n, Box::new(value))
+        }
+        Tok::LParen => {
+            let attrs = parse_comma_list(
+                tokens,
+                Tok::LParen,
+                Tok::RParen,
+                parse_attribute,
+                "an attribute",
+            )?;
+            Attribute_::Parameterized(n, attrs)
+        }
+        _ => Attribute_::Name(n),
+    };
+    let end_loc = tokens.previous_end_loc();
+    Ok(spanned(tokens.file_name(), start_loc, end_loc, attr))
+}
+
+// Parse the attributes of a function:
+//      Attributes = ("#" "[" Comma<Attribute> "]")*
+fn parse_attributes<'input>(tokens: &mut Lexer<'input>) -> Result<Vec<Attribute>, Error> {
+    let mut attributes = vec![];
+    while match_token(tokens, Tok::NumSign)? {
+        attributes.extend(parse_comma_list(
+            tokens,
+            Tok::LBracket,
+            Tok::RBracket,
+            parse_attribute,
+            "an attribute",
+        )?);
+    }
+    Ok(attributes)
+}
+
 // Parse a function declaration:
 //      FunctionDecl =
 //          <NativeFunctionDecl>
 //          | <MoveFunctionDecl>
 //      NativeFunctionDecl =
-//          <DocComments> "native" ( "public" )? "fun"
+//          <DocComments> <Attributes> "native" ( "public" )? "fun"
 //          <FunctionDefName> "(" Comma<Parameter> ")"
 //          (":" <Type>)?
 //          ("acquires" <ModuleAccess> ("," <ModuleAccess>)*)?
 //          ";"
 //      MoveFunctionDecl =
-//          <DocComments> ( "public" )? "fun"
+//          <DocComments> <Attributes> ( "public" )? "fun"
 //          <FunctionDefName> "(" Comma<Parameter> ")"
 //          (":" <Type>)?
 //          ("acquires" <ModuleAccess> ("," <ModuleAccess>)*)?
@@ -1305,6 +1371,7 @@ fn parse_function_decl<'input>(
 ) -> Result<Function, Error> {
     tokens.match_doc_comments();
     let start_loc = tokens.start_loc();
+    let attributes = parse_attributes(tokens)?;
     // Record the source location of the "native" keyword (if there is one).
     let native_opt = if allow_native {
         consume_optional_token_with_loc(tokens, Tok::Native)?
@@ -1392,6 +1459,7 @@ fn parse_function_decl<'input>(
     let loc = make_loc(tokens.file_name(), start_loc, tokens.previous_end_loc());
     Ok(Function {
         loc,
+        attributes,
         visibility,
         signature,
         acquires,
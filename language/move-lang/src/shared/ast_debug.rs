@@ -45,6 +45,13 @@ pub fn print_verbose<T: AstDebug>(t: &T) {
     print!("{}", writer);
 }
 
+/// Returns what `print` would print.
+pub fn display<T: AstDebug>(t: &T) -> String {
+    let mut writer = AstWriter::normal();
+    t.ast_debug(&mut writer);
+    writer.to_string()
+}
+
 pub struct AstWriter {
     verbose: bool,
     margin: usize,
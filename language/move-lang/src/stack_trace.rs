@@ -0,0 +1,124 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rendering of the stack trace of a failed execution as Move source locations, using the source
+//! maps of the compiled units.
+
+use crate::{compiled_unit::CompiledUnit, errors::FilesSourceText};
+use bytecode_source_map::source_map::SourceMap;
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use move_ir_types::location::*;
+use move_vm::{access::ModuleAccess, errors::StackFrame, file_format as F};
+use std::collections::BTreeMap;
+
+struct UnitSourceMap {
+    functions: BTreeMap<Identifier, F::FunctionDefinitionIndex>,
+    source_map: SourceMap<Loc>,
+}
+
+/// The source maps of the modules, and of the script, executed by a transaction.
+#[derive(Default)]
+pub struct SourceMaps {
+    files: FilesSourceText,
+    modules: BTreeMap<ModuleId, UnitSourceMap>,
+    script: Option<UnitSourceMap>,
+}
+
+impl SourceMaps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the sources the units were compiled from.
+    pub fn add_files(&mut self, files: FilesSourceText) {
+        self.files.extend(files)
+    }
+
+    /// Adds the source map of a compiled unit. A script replaces the previously added script.
+    pub fn add_unit(&mut self, unit: &CompiledUnit) {
+        match unit {
+            CompiledUnit::Module {
+                module, source_map, ..
+            } => {
+                let functions = module
+                    .function_defs()
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, def)| {
+                        let handle = module.function_handle_at(def.function);
+                        (
+                            module.identifier_at(handle.name).to_owned(),
+                            F::FunctionDefinitionIndex(idx as F::TableIndex),
+                        )
+                    })
+                    .collect();
+                self.modules.insert(
+                    module.self_id(),
+                    UnitSourceMap {
+                        functions,
+                        source_map: source_map.clone(),
+                    },
+                );
+            }
+            CompiledUnit::Script { source_map, .. } => {
+                let mut functions = BTreeMap::new();
+                functions.insert(
+                    Identifier::new("main").unwrap(),
+                    F::FunctionDefinitionIndex(0),
+                );
+                self.script = Some(UnitSourceMap {
+                    functions,
+                    source_map: source_map.clone(),
+                });
+            }
+        }
+    }
+
+    /// Returns whether the source map of the module of the frame is known.
+    pub fn contains(&self, frame: &StackFrame) -> bool {
+        match &frame.module {
+            Some(module) => self.modules.contains_key(module),
+            None => self.script.is_some(),
+        }
+    }
+
+    /// Returns the file, line and column of the instruction of the frame, if known. Lines and
+    /// columns start at 1.
+    pub fn source_location(&self, frame: &StackFrame) -> Option<(&'static str, usize, usize)> {
+        let unit = match &frame.module {
+            Some(module) => self.modules.get(module)?,
+            None => self.script.as_ref()?,
+        };
+        let function = *unit.functions.get(&frame.function)?;
+        let loc = unit.source_map.get_code_location(function, frame.pc).ok()?;
+        let text = self.files.get(loc.file())?;
+        let before = text.get(..loc.span().start().to_usize())?;
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+        Some((loc.file(), line, column))
+    }
+
+    /// Renders the stack trace with the innermost frame first, one frame per line. Frames
+    /// without a source map are rendered with their pc instead.
+    pub fn render(&self, stack_trace: &[StackFrame]) -> String {
+        stack_trace
+            .iter()
+            .rev()
+            .map(|frame| match self.source_location(frame) {
+                Some((file, line, column)) => {
+                    let function = match &frame.module {
+                        Some(module) => format!(
+                            "{}::{}::{}",
+                            module.address(),
+                            module.name(),
+                            frame.function
+                        ),
+                        None => format!("Script::{}", frame.function),
+                    };
+                    format!("    at {} ({}:{}:{})\n", function, file, line, column)
+                }
+                None => format!("    at {}\n", frame),
+            })
+            .collect()
+    }
+}
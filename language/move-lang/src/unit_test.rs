@@ -0,0 +1,222 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Unit tests written next to module code.
+//!
+//! A module function marked `#[test]` is a unit test. It takes no parameters and passes if it
+//! returns without aborting. Marking it `#[expected_failure]` as well inverts this: the test passes
+//! only if it aborts, and with `#[expected_failure(abort_code = <num>)]` only if it aborts with
+//! that code.
+//!
+//! Test functions are only compiled by `move_compile_tests`. Any other compilation removes them
+//! right after parsing, so they never end up in published modules.
+
+use crate::{
+    errors::*,
+    parser::ast::{
+        Attribute, Attribute_, Definition, Function, ModuleDefinition, ModuleMember, Program,
+        Value_,
+    },
+    shared::*,
+};
+use move_ir_types::location::*;
+use std::{convert::TryFrom, fmt};
+
+const TEST_ATTR: &str = "test";
+const EXPECTED_FAILURE_ATTR: &str = "expected_failure";
+const ABORT_CODE_ATTR: &str = "abort_code";
+
+/// A test function of a module.
+#[derive(Clone, Debug)]
+pub struct TestCase {
+    pub loc: Loc,
+    pub module_address: Address,
+    pub module_name: String,
+    pub function_name: String,
+    /// Set if the test is expected to abort.
+    pub expected_failure: Option<ExpectedFailure>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExpectedFailure {
+    /// The abort code the test must abort with, any code if not set.
+    pub abort_code: Option<u64>,
+}
+
+impl fmt::Display for TestCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}::{}::{}",
+            self.module_address, self.module_name, self.function_name
+        )
+    }
+}
+
+/// Checks the attributes of all functions, and removes the test functions unless `keep_tests` is
+/// set. The test functions of the source definitions are returned, those of the library
+/// definitions are always removed.
+pub(crate) fn filter_tests(
+    mut prog: Program,
+    sender_opt: Option<Address>,
+    keep_tests: bool,
+) -> Result<(Program, Vec<TestCase>), Errors> {
+    let mut errors = vec![];
+    let mut tests = vec![];
+    for def in &mut prog.source_definitions {
+        definition(&mut errors, &mut tests, def, sender_opt, keep_tests)
+    }
+    for def in &mut prog.lib_definitions {
+        definition(&mut errors, &mut vec![], def, sender_opt, false)
+    }
+    if errors.is_empty() {
+        Ok((prog, tests))
+    } else {
+        Err(errors)
+    }
+}
+
+fn definition(
+    errors: &mut Errors,
+    tests: &mut Vec<TestCase>,
+    def: &mut Definition,
+    sender_opt: Option<Address>,
+    keep_tests: bool,
+) {
+    match def {
+        Definition::Module(mdef) => module(errors, tests, mdef, sender_opt, keep_tests),
+        Definition::Address(_, address, mdefs) => {
+            for mdef in mdefs {
+                module(errors, tests, mdef, Some(*address), keep_tests)
+            }
+        }
+        Definition::Script(script) => {
+            if let Some(attr) = script.function.attributes.first() {
+                errors.push(vec![(
+                    attr.loc,
+                    "Attributes are not supported on script functions".to_string(),
+                )])
+            }
+        }
+    }
+}
+
+fn module(
+    errors: &mut Errors,
+    tests: &mut Vec<TestCase>,
+    mdef: &mut ModuleDefinition,
+    address_opt: Option<Address>,
+    keep_tests: bool,
+) {
+    let module_name = mdef.name.to_string();
+    mdef.members.retain(|member| {
+        let function = match member {
+            ModuleMember::Function(function) => function,
+            _ => return true,
+        };
+        let expected_failure = match test_attributes(errors, function) {
+            None => return true,
+            Some(expected_failure) => expected_failure,
+        };
+        if !keep_tests {
+            return false;
+        }
+        match address_opt {
+            Some(module_address) => tests.push(TestCase {
+                loc: function.loc,
+                module_address,
+                module_name: module_name.clone(),
+                function_name: function.name.to_string(),
+                expected_failure,
+            }),
+            None => errors.push(vec![(
+                function.loc,
+                "Cannot run tests of a module without an address. Specify a sender or declare \
+                 the module in an address block"
+                    .to_string(),
+            )]),
+        }
+        true
+    })
+}
+
+// Checks the attributes of the function. Returns None if it is not a test, or its expected
+// failure if it is.
+fn test_attributes(errors: &mut Errors, function: &Function) -> Option<Option<ExpectedFailure>> {
+    let mut test_loc = None;
+    let mut expected_failure = None;
+    for attr in &function.attributes {
+        let (name, args) = match &attr.value {
+            Attribute_::Name(name) => (name, None),
+            Attribute_::Parameterized(name, args) => (name, Some(args)),
+            Attribute_::Assigned(name, _) => (name, None),
+        };
+        match (name.value.as_str(), &attr.value) {
+            (TEST_ATTR, Attribute_::Name(_)) => test_loc = Some(attr.loc),
+            (EXPECTED_FAILURE_ATTR, Attribute_::Name(_))
+            | (EXPECTED_FAILURE_ATTR, Attribute_::Parameterized(_, _)) => {
+                expected_failure = Some((attr.loc, expected_failure_args(errors, args)))
+            }
+            (TEST_ATTR, _) | (EXPECTED_FAILURE_ATTR, _) => errors.push(vec![(
+                attr.loc,
+                format!("Invalid arguments for attribute '{}'", name),
+            )]),
+            _ => errors.push(vec![(attr.loc, format!("Unknown attribute '{}'", name))]),
+        }
+    }
+
+    match (test_loc, expected_failure) {
+        (None, None) => None,
+        (None, Some((loc, _))) => {
+            errors.push(vec![(
+                loc,
+                format!(
+                    "Attribute '{}' is only valid on functions marked '{}'",
+                    EXPECTED_FAILURE_ATTR, TEST_ATTR
+                ),
+            )]);
+            None
+        }
+        (Some(loc), expected_failure) => {
+            let signature = &function.signature;
+            if !signature.type_parameters.is_empty() || !signature.parameters.is_empty() {
+                errors.push(vec![
+                    (function.loc, "Invalid test function".to_string()),
+                    (loc, "Test functions cannot take parameters".to_string()),
+                ])
+            }
+            Some(expected_failure.map(|(_, failure)| failure))
+        }
+    }
+}
+
+fn expected_failure_args(errors: &mut Errors, args: Option<&Vec<Attribute>>) -> ExpectedFailure {
+    let mut abort_code = None;
+    for arg in args.into_iter().flatten() {
+        match &arg.value {
+            Attribute_::Assigned(name, value) if name.value == ABORT_CODE_ATTR => {
+                let code = match &value.value {
+                    Value_::U8(i) => Some(*i as u64),
+                    Value_::U64(i) => Some(*i),
+                    Value_::U128(i) => u64::try_from(*i).ok(),
+                    _ => None,
+                };
+                match code {
+                    Some(code) => abort_code = Some(code),
+                    None => errors.push(vec![(
+                        value.loc,
+                        format!("Expected a u64 value for '{}'", ABORT_CODE_ATTR),
+                    )]),
+                }
+            }
+            _ => errors.push(vec![(
+                arg.loc,
+                format!(
+                    "Invalid argument for attribute '{}'. Expected '{} = <num>'",
+                    EXPECTED_FAILURE_ATTR, ABORT_CODE_ATTR
+                ),
+            )]),
+        }
+    }
+    ExpectedFailure { abort_code }
+}
@@ -0,0 +1,4 @@
+module M {
+    #[foo]
+    fun f() {}
+}
@@ -0,0 +1,21 @@
+address 0x1 {
+module M {
+    public fun add(x: u64, y: u64): u64 { x + y }
+
+    #[test]
+    fun add_zero() {
+        assert(add(1, 0) == 1, 0)
+    }
+
+    #[test, expected_failure]
+    fun add_overflow() {
+        add(18446744073709551615, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 7)]
+    fun add_aborts() {
+        abort 7
+    }
+}
+}
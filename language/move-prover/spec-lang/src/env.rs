@@ -546,6 +546,24 @@ impl GlobalEnv {
             .any(|d| d.severity >= Severity::Warning)
     }
 
+    /// Returns the location and message of each accumulated error, in the order they were
+    /// added. The message of the diagnostic and the one of its primary label are combined.
+    pub fn get_errors(&self) -> Vec<(Loc, String)> {
+        self.diags
+            .borrow()
+            .iter()
+            .filter(|d| d.severity >= Severity::Error)
+            .map(|d| {
+                let label = &d.primary_label;
+                let msg = [d.message.as_str(), label.message.as_str()]
+                    .iter()
+                    .filter(|m| !m.is_empty())
+                    .join(": ");
+                (Loc::new(label.file_id, label.span), msg)
+            })
+            .collect()
+    }
+
     /// Writes accumulated errors to writer.
     pub fn report_errors<W: WriteColor>(&self, writer: &mut W) {
         for diag in self
@@ -0,0 +1,403 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A cache of deserialized and verified modules shared by all the VMs of a process.
+//!
+//! Executors create a new VM for every block, and every VM has its own `Loader`, so without this
+//! cache each block deserializes and verifies again all the modules it uses. Deserialization and
+//! verification only depend on the bytes of a module, so their result is cached here keyed by the
+//! hash of those bytes, and reused by any VM loading the same bytes. The tables of types and
+//! functions a `Loader` links the modules into are still built by every VM, as they are indexed
+//! per `Loader`.
+//!
+//! Modules are cached when a `Loader` loads them, whether the state it reads from is committed or
+//! speculative. As entries are only ever found by the hash of their bytes, an entry for bytes that
+//! were never committed cannot be served in place of other bytes, it is only dropped later. A
+//! module republished at the same `ModuleId`, and so at the same `AccessPath`, hashes differently
+//! and never hits the entry of the previous version. That entry is invalidated by `commit_module`
+//! once the new version is committed, never when a transaction publishing it is executed, as the
+//! transaction may be discarded. The cache holds at most `capacity` modules and evicts the least
+//! recently used ones beyond that.
+
+use libra_crypto::HashValue;
+use libra_metrics::{register_int_counter, register_int_gauge, IntCounter, IntGauge};
+use libra_types::access_path::AccessPath;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::Duration,
+};
+use vm::{access::ModuleAccess, CompiledModule};
+
+/// The number of modules cached by default.
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+static HITS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "move_vm_code_cache_hits",
+        "Number of modules loaded without being deserialized and verified again"
+    )
+    .unwrap()
+});
+
+static MISSES: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "move_vm_code_cache_misses",
+        "Number of modules deserialized and verified because they were not cached"
+    )
+    .unwrap()
+});
+
+static INVALIDATIONS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "move_vm_code_cache_invalidations",
+        "Number of cached modules dropped because a new version was committed"
+    )
+    .unwrap()
+});
+
+static EVICTIONS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "move_vm_code_cache_evictions",
+        "Number of cached modules dropped to stay within the capacity of the cache"
+    )
+    .unwrap()
+});
+
+static MICROS_SAVED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "move_vm_code_cache_verification_micros_saved",
+        "Time not spent deserializing and verifying modules thanks to the cache, in microseconds"
+    )
+    .unwrap()
+});
+
+static ENTRIES: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "move_vm_code_cache_entries",
+        "Number of modules in the cache"
+    )
+    .unwrap()
+});
+
+static CODE_CACHE: Lazy<CodeCache> = Lazy::new(|| CodeCache {
+    export_metrics: true,
+    ..CodeCache::new(DEFAULT_CAPACITY)
+});
+
+/// Counters of a cache since it was created. The counters of the cache of the process are also
+/// exported as metrics.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CodeCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+    pub evictions: u64,
+    /// The time the modules hit took to deserialize and verify when they were cached.
+    pub time_saved: Duration,
+}
+
+impl CodeCacheStats {
+    /// The ratio of loads that hit the cache, 0 if nothing was loaded.
+    pub fn hit_rate(&self) -> f64 {
+        let loads = self.hits + self.misses;
+        if loads == 0 {
+            0.0
+        } else {
+            self.hits as f64 / loads as f64
+        }
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+    evictions: AtomicU64,
+    micros_saved: AtomicU64,
+}
+
+struct Entry {
+    access_path: AccessPath,
+    module: Arc<CompiledModule>,
+    // Time it took to deserialize and verify the module.
+    load_time: Duration,
+    // Value of the clock of the cache when the entry was last used, for LRU eviction. It is
+    // updated under the read lock so lookups do not serialize.
+    last_used: AtomicU64,
+}
+
+struct Entries {
+    capacity: usize,
+    map: HashMap<HashValue, Entry>,
+}
+
+/// A cache of deserialized and verified modules keyed by the hash of their bytes.
+///
+/// Lookups only take a read lock, so the VMs executing a block in parallel do not contend on it.
+/// The `Loader` uses the cache of the process, through the functions of this module; other
+/// instances are independent of it, e.g., to observe the counters of a single cache in tests.
+pub struct CodeCache {
+    entries: RwLock<Entries>,
+    clock: AtomicU64,
+    counters: Counters,
+    export_metrics: bool,
+}
+
+impl CodeCache {
+    /// Creates an empty cache holding at most `capacity` modules. A capacity of 0 disables it.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: RwLock::new(Entries {
+                capacity,
+                map: HashMap::new(),
+            }),
+            clock: AtomicU64::new(0),
+            counters: Counters::default(),
+            export_metrics: false,
+        }
+    }
+
+    /// Returns the module deserialized from the bytes with the given hash, if it is cached.
+    pub fn get(&self, hash: &HashValue) -> Option<Arc<CompiledModule>> {
+        let entries = self.entries.read().unwrap();
+        match entries.map.get(hash) {
+            Some(entry) => {
+                let now = self.clock.fetch_add(1, Ordering::Relaxed) + 1;
+                entry.last_used.store(now, Ordering::Relaxed);
+                let micros = entry.load_time.as_micros() as u64;
+                self.counters.hits.fetch_add(1, Ordering::Relaxed);
+                self.counters
+                    .micros_saved
+                    .fetch_add(micros, Ordering::Relaxed);
+                if self.export_metrics {
+                    HITS.inc();
+                    MICROS_SAVED.inc_by(micros as i64);
+                }
+                Some(Arc::clone(&entry.module))
+            }
+            None => {
+                self.counters.misses.fetch_add(1, Ordering::Relaxed);
+                if self.export_metrics {
+                    MISSES.inc();
+                }
+                None
+            }
+        }
+    }
+
+    /// Caches a module deserialized from the bytes with the given hash. `load_time` is the time
+    /// deserialization and verification took.
+    pub fn insert(&self, hash: HashValue, module: Arc<CompiledModule>, load_time: Duration) {
+        let mut entries = self.entries.write().unwrap();
+        if entries.capacity == 0 || entries.map.contains_key(&hash) {
+            return;
+        }
+        let access_path = AccessPath::code_access_path(&module.self_id());
+        let now = self.clock.fetch_add(1, Ordering::Relaxed) + 1;
+        entries.map.insert(
+            hash,
+            Entry {
+                access_path,
+                module,
+                load_time,
+                last_used: AtomicU64::new(now),
+            },
+        );
+        self.evict(&mut entries);
+    }
+
+    /// Drops the cached versions of the module at `access_path` other than the committed one.
+    /// `blob` holds the committed bytes of the module, or is `None` if the module was deleted.
+    pub fn commit_module(&self, access_path: &AccessPath, blob: Option<&[u8]>) {
+        let committed = blob.map(HashValue::sha3_256_of);
+        let mut entries = self.entries.write().unwrap();
+        let before = entries.map.len();
+        entries
+            .map
+            .retain(|hash, entry| &entry.access_path != access_path || Some(*hash) == committed);
+        let invalidated = (before - entries.map.len()) as u64;
+        self.counters
+            .invalidations
+            .fetch_add(invalidated, Ordering::Relaxed);
+        if self.export_metrics {
+            INVALIDATIONS.inc_by(invalidated as i64);
+            ENTRIES.set(entries.map.len() as i64);
+        }
+    }
+
+    /// Sets the maximum number of modules cached, evicting modules if there are more.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut entries = self.entries.write().unwrap();
+        entries.capacity = capacity;
+        self.evict(&mut entries);
+    }
+
+    /// Drops all the cached modules. The counters are kept.
+    pub fn clear(&self) {
+        self.entries.write().unwrap().map.clear();
+        if self.export_metrics {
+            ENTRIES.set(0);
+        }
+    }
+
+    /// Returns the counters of the cache since it was created.
+    pub fn stats(&self) -> CodeCacheStats {
+        let counters = &self.counters;
+        CodeCacheStats {
+            hits: counters.hits.load(Ordering::Relaxed),
+            misses: counters.misses.load(Ordering::Relaxed),
+            invalidations: counters.invalidations.load(Ordering::Relaxed),
+            evictions: counters.evictions.load(Ordering::Relaxed),
+            time_saved: Duration::from_micros(counters.micros_saved.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn evict(&self, entries: &mut Entries) {
+        while entries.map.len() > entries.capacity {
+            let lru = *entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .map(|(hash, _)| hash)
+                .expect("cache over capacity cannot be empty");
+            entries.map.remove(&lru);
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+            if self.export_metrics {
+                EVICTIONS.inc();
+            }
+        }
+        if self.export_metrics {
+            ENTRIES.set(entries.map.len() as i64);
+        }
+    }
+}
+
+/// Sets the maximum number of modules cached by the process, evicting modules if there are more.
+/// A capacity of 0 disables the cache.
+pub fn set_capacity(capacity: usize) {
+    CODE_CACHE.set_capacity(capacity)
+}
+
+/// Drops all the modules cached by the process. The stats are kept.
+pub fn clear() {
+    CODE_CACHE.clear()
+}
+
+/// Returns the counters of the cache of the process since the start of the process.
+pub fn stats() -> CodeCacheStats {
+    CODE_CACHE.stats()
+}
+
+/// Records that the module at `access_path` was committed with the bytes in `blob`, or deleted if
+/// `blob` is `None`, dropping the other versions of the module cached by the process. Executors
+/// call it once the write sets of transactions are committed.
+pub fn commit_module(access_path: &AccessPath, blob: Option<&[u8]>) {
+    CODE_CACHE.commit_module(access_path, blob)
+}
+
+/// Returns the module deserialized from the bytes with the given hash, if it is cached. A cached
+/// module passed `Loader::verify_module`.
+pub(crate) fn get(hash: &HashValue) -> Option<Arc<CompiledModule>> {
+    CODE_CACHE.get(hash)
+}
+
+/// Caches a module deserialized from the bytes with the given hash, after it passed
+/// `Loader::verify_module`. `load_time` is the time deserialization and verification took.
+pub(crate) fn insert(hash: HashValue, module: Arc<CompiledModule>, load_time: Duration) {
+    CODE_CACHE.insert(hash, module, load_time)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+    use vm::file_format::empty_module;
+
+    fn module_version(name: &str, version: u8) -> (HashValue, Arc<CompiledModule>) {
+        let mut module = empty_module();
+        module.identifiers[0] = Identifier::new(name).unwrap();
+        // Different versions of a module have different bytes.
+        module
+            .address_identifiers
+            .push(AccountAddress::new([version; AccountAddress::LENGTH]));
+        let mut blob = vec![];
+        let module = module.freeze().unwrap();
+        module.serialize(&mut blob).unwrap();
+        (HashValue::sha3_256_of(&blob), Arc::new(module))
+    }
+
+    fn access_path(module: &CompiledModule) -> AccessPath {
+        AccessPath::code_access_path(&module.self_id())
+    }
+
+    #[test]
+    fn hits_and_misses() {
+        let cache = CodeCache::new(DEFAULT_CAPACITY);
+        let (hash, module) = module_version("M", 1);
+        assert!(cache.get(&hash).is_none());
+        cache.insert(hash, module, Duration::from_micros(10));
+        assert!(cache.get(&hash).is_some());
+        assert!(cache.get(&hash).is_some());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+        assert_eq!(stats.time_saved, Duration::from_micros(20));
+    }
+
+    #[test]
+    fn commit_invalidates_other_versions_only() {
+        let cache = CodeCache::new(DEFAULT_CAPACITY);
+        let (old_hash, old) = module_version("M", 1);
+        let (new_hash, new) = module_version("M", 2);
+        let (other_hash, other) = module_version("N", 1);
+        let path = access_path(&old);
+        assert_eq!(path, access_path(&new));
+        cache.insert(old_hash, old, Duration::default());
+        cache.insert(other_hash, other, Duration::default());
+
+        // Executing a publish of the new version does not touch the cache, loading it does not
+        // invalidate the committed version.
+        cache.insert(new_hash, Arc::clone(&new), Duration::default());
+        assert!(cache.get(&old_hash).is_some());
+
+        let mut blob = vec![];
+        new.serialize(&mut blob).unwrap();
+        cache.commit_module(&path, Some(&blob));
+        assert!(cache.get(&old_hash).is_none());
+        assert!(cache.get(&new_hash).is_some());
+        assert!(cache.get(&other_hash).is_some());
+        assert_eq!(cache.stats().invalidations, 1);
+
+        cache.commit_module(&path, None);
+        assert!(cache.get(&new_hash).is_none());
+        assert_eq!(cache.stats().invalidations, 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = CodeCache::new(2);
+        let modules: Vec<_> = (1..=3)
+            .map(|version| module_version("M", version))
+            .collect();
+        cache.insert(modules[0].0, Arc::clone(&modules[0].1), Duration::default());
+        cache.insert(modules[1].0, Arc::clone(&modules[1].1), Duration::default());
+        assert!(cache.get(&modules[0].0).is_some());
+        cache.insert(modules[2].0, Arc::clone(&modules[2].1), Duration::default());
+
+        assert!(cache.get(&modules[0].0).is_some());
+        assert!(cache.get(&modules[1].0).is_none());
+        assert!(cache.get(&modules[2].0).is_some());
+        assert_eq!(cache.stats().evictions, 1);
+
+        cache.set_capacity(0);
+        assert!(cache.get(&modules[0].0).is_none());
+        cache.insert(modules[1].0, Arc::clone(&modules[1].1), Duration::default());
+        assert!(cache.get(&modules[1].0).is_none());
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks for an interactive debugger, only included with the `debugger` feature.
+//!
+//! A `Debugger` installed on a thread with `set_debugger` is asked before every instruction
+//! executed by the interpreter on that thread whether execution should pause. When it should,
+//! the debugger is handed a snapshot of the interpreter state and execution resumes once it
+//! returns. Breakpoints and stepping are implemented by the debugger on top of these two calls.
+
+use move_core_types::language_storage::ModuleId;
+use std::cell::RefCell;
+
+/// The instruction about to be executed.
+#[derive(Clone, Copy, Debug)]
+pub struct DebugLocation<'a> {
+    /// None for the function of a script.
+    pub module: Option<&'a ModuleId>,
+    pub function: &'a str,
+    pub pc: u16,
+    /// The number of frames below the current one, 0 for the entry function.
+    pub depth: usize,
+}
+
+/// A function frame on the call stack.
+#[derive(Clone, Debug)]
+pub struct DebugFrame {
+    pub function: String,
+    pub pc: u16,
+    /// The locals of the frame, printed along with their type.
+    pub locals: Vec<String>,
+}
+
+/// Snapshot of the interpreter state while paused.
+#[derive(Clone, Debug)]
+pub struct DebugState {
+    /// The call stack, the outermost frame first and the current frame last.
+    pub call_stack: Vec<DebugFrame>,
+    pub instruction: String,
+    /// The operand stack, bottom first. The types of operands are not tracked by the
+    /// interpreter so they are printed without them.
+    pub operand_stack: Vec<String>,
+}
+
+pub trait Debugger {
+    /// Called before every instruction, returns whether execution should pause.
+    fn should_pause(&mut self, location: &DebugLocation) -> bool;
+
+    /// Called once execution paused, execution resumes when this returns.
+    fn paused(&mut self, location: &DebugLocation, state: &DebugState);
+}
+
+thread_local! {
+    static DEBUGGER: RefCell<Option<Box<dyn Debugger>>> = RefCell::new(None);
+}
+
+/// Installs the debugger for executions on the current thread, replacing any prior one.
+pub fn set_debugger(debugger: Box<dyn Debugger>) {
+    DEBUGGER.with(|cell| *cell.borrow_mut() = Some(debugger));
+}
+
+/// Removes and returns the debugger of the current thread, if any.
+pub fn take_debugger() -> Option<Box<dyn Debugger>> {
+    DEBUGGER.with(|cell| cell.borrow_mut().take())
+}
+
+pub(crate) fn is_enabled() -> bool {
+    DEBUGGER.with(|cell| cell.borrow().is_some())
+}
+
+pub(crate) fn should_pause(location: &DebugLocation) -> bool {
+    DEBUGGER.with(|cell| match &mut *cell.borrow_mut() {
+        Some(debugger) => debugger.should_pause(location),
+        None => false,
+    })
+}
+
+pub(crate) fn paused(location: &DebugLocation, state: &DebugState) {
+    DEBUGGER.with(|cell| {
+        if let Some(debugger) = &mut *cell.borrow_mut() {
+            debugger.paused(location, state)
+        }
+    })
+}
@@ -1,11 +1,13 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "debugger")]
+use crate::debugger::{self, DebugFrame, DebugLocation, DebugState};
 use crate::{
     data_operations::{borrow_global, move_resource_from, move_resource_to, resource_exists},
     loader::{Function, Loader, Resolver},
     native_functions::FunctionContext,
-    trace,
+    stack_trace, trace,
 };
 use libra_logger::prelude::*;
 use libra_types::{
@@ -96,8 +98,13 @@ impl Interpreter {
         args: Vec<Value>,
     ) -> VMResult<()> {
         // No unwinding of the call stack and value stack need to be done here -- the context will
-        // take care of that.
-        self.execute_main(loader, data_store, cost_strategy, function, ty_args, args)
+        // take care of that. Only the functions tracked by the gas profiler are unwound.
+        let profiled_depth = cost_strategy.profiled_depth();
+        let result = self.execute_main(loader, data_store, cost_strategy, function, ty_args, args);
+        if result.is_err() {
+            cost_strategy.unwind_functions(profiled_depth);
+        }
+        result
     }
 
     /// Main loop for the execution of a function.
@@ -123,16 +130,18 @@ impl Interpreter {
             locals.store_loc(i, value)?;
         }
 
+        cost_strategy.enter_function(|| function.pretty_string());
         let mut current_frame = Frame::new(function, ty_args, locals);
         loop {
             let resolver = current_frame.resolver(loader);
             let exit_code =
                 current_frame //self
                     .execute_code(&resolver, self, data_store, cost_strategy)
-                    .or_else(|err| Err(self.maybe_core_dump(err, &current_frame)))?;
+                    .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
             match exit_code {
                 ExitCode::Return => {
                     current_frame.locals.check_resources_for_return()?;
+                    cost_strategy.exit_function();
                     if let Some(frame) = self.call_stack.pop() {
                         current_frame = frame;
                     } else {
@@ -140,20 +149,25 @@ impl Interpreter {
                     }
                 }
                 ExitCode::Call(fh_idx) => {
-                    cost_strategy.charge_instr_with_size(
-                        Opcodes::CALL,
-                        AbstractMemorySize::new(1 as GasCarrier),
-                    )?;
+                    cost_strategy
+                        .charge_instr_with_size(
+                            Opcodes::CALL,
+                            AbstractMemorySize::new(1 as GasCarrier),
+                        )
+                        .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     let func = resolver.function_at(fh_idx);
+                    cost_strategy.enter_function(|| func.pretty_string());
                     if func.is_native() {
-                        self.call_native(&resolver, data_store, cost_strategy, func, vec![])?;
+                        self.call_native(&resolver, data_store, cost_strategy, func, vec![])
+                            .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
+                        cost_strategy.exit_function();
                         continue;
                     }
                     // TODO: when a native function is executed, the current frame has not yet
                     // been pushed onto the call stack. Fix it.
                     let frame = self
                         .make_call_frame(func, vec![])
-                        .or_else(|err| Err(self.maybe_core_dump(err, &current_frame)))?;
+                        .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     self.call_stack.push(current_frame).or_else(|frame| {
                         let err = VMStatus::new(StatusCode::CALL_STACK_OVERFLOW);
                         Err(self.maybe_core_dump(err, &frame))
@@ -162,21 +176,28 @@ impl Interpreter {
                 }
                 ExitCode::CallGeneric(idx) => {
                     let func_inst = resolver.function_instantiation_at(idx);
-                    cost_strategy.charge_instr_with_size(
-                        Opcodes::CALL_GENERIC,
-                        AbstractMemorySize::new((func_inst.instantiation_size() + 1) as GasCarrier),
-                    )?;
+                    cost_strategy
+                        .charge_instr_with_size(
+                            Opcodes::CALL_GENERIC,
+                            AbstractMemorySize::new(
+                                (func_inst.instantiation_size() + 1) as GasCarrier,
+                            ),
+                        )
+                        .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     let func = loader.function_at(func_inst.handle());
                     let ty_args = func_inst.materialize(current_frame.ty_args())?;
+                    cost_strategy.enter_function(|| func.pretty_string());
                     if func.is_native() {
-                        self.call_native(&resolver, data_store, cost_strategy, func, ty_args)?;
+                        self.call_native(&resolver, data_store, cost_strategy, func, ty_args)
+                            .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
+                        cost_strategy.exit_function();
                         continue;
                     }
                     // TODO: when a native function is executed, the current frame has not yet
                     // been pushed onto the call stack. Fix it.
                     let frame = self
                         .make_call_frame(func, ty_args)
-                        .or_else(|err| Err(self.maybe_core_dump(err, &current_frame)))?;
+                        .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     self.call_stack.push(current_frame).or_else(|frame| {
                         let err = VMStatus::new(StatusCode::CALL_STACK_OVERFLOW);
                         Err(self.maybe_core_dump(err, &frame))
@@ -218,7 +239,7 @@ impl Interpreter {
         let mut native_context = FunctionContext::new(self, data_store, cost_strategy, resolver);
         let native_function = function.get_native()?;
         let result = native_function.dispatch(&mut native_context, ty_args, arguments)?;
-        cost_strategy.deduct_gas(result.cost)?;
+        cost_strategy.charge_native(result.cost)?;
         result.result.and_then(|values| {
             for value in values {
                 self.operand_stack.push(value)?;
@@ -401,9 +422,27 @@ impl Interpreter {
                 state,
             );
         }
+        if stack_trace::is_recording() {
+            stack_trace::record(self.get_stack_trace(current_frame));
+        }
         err
     }
 
+    /// Returns the call stack, ending with the given frame. The pc of every frame has already
+    /// moved past the instruction that failed, or past the call to the frame above.
+    fn get_stack_trace(&self, current_frame: &Frame) -> StackTrace {
+        self.call_stack
+            .0
+            .iter()
+            .chain(std::iter::once(current_frame))
+            .map(|frame| StackFrame {
+                module: frame.function.module_id().cloned(),
+                function: frame.function.identifier().clone(),
+                pc: frame.pc.saturating_sub(1),
+            })
+            .collect()
+    }
+
     #[allow(dead_code)]
     fn debug_print_frame<B: Write>(
         &self,
@@ -491,6 +530,70 @@ impl Interpreter {
         Ok(())
     }
 
+    /// Reports the instruction about to be executed to the debugger installed on this thread,
+    /// and hands it the state of the interpreter if it decides to pause.
+    #[cfg(feature = "debugger")]
+    fn debug_hook(
+        &self,
+        resolver: &Resolver,
+        current_frame: &Frame,
+        instruction: &Bytecode,
+    ) -> VMResult<()> {
+        if !debugger::is_enabled() {
+            return Ok(());
+        }
+        let location = DebugLocation {
+            module: current_frame.function.module_id(),
+            function: current_frame.function.name(),
+            pc: current_frame.pc,
+            depth: self.call_stack.0.len(),
+        };
+        if !debugger::should_pause(&location) {
+            return Ok(());
+        }
+
+        // Frames on the call stack have moved past the call they are waiting on.
+        let loader = resolver.loader();
+        let mut call_stack = vec![];
+        for frame in &self.call_stack.0 {
+            let resolver = frame.resolver(loader);
+            call_stack.push(Self::debug_frame(&resolver, frame, frame.pc - 1)?);
+        }
+        call_stack.push(Self::debug_frame(
+            resolver,
+            current_frame,
+            current_frame.pc,
+        )?);
+        let state = DebugState {
+            call_stack,
+            instruction: format!("{:?}", instruction),
+            operand_stack: self
+                .operand_stack
+                .0
+                .iter()
+                .map(|value| value.to_string())
+                .collect(),
+        };
+        debugger::paused(&location, &state);
+        Ok(())
+    }
+
+    #[cfg(feature = "debugger")]
+    fn debug_frame(resolver: &Resolver, frame: &Frame, pc: u16) -> VMResult<DebugFrame> {
+        let mut locals = vec![];
+        for (idx, local) in frame.function.locals().0.iter().enumerate() {
+            let ty = resolver.make_fat_type(local, frame.ty_args())?;
+            let mut buf = String::new();
+            values::debug::print_local(&mut buf, &ty, &frame.locals, idx)?;
+            locals.push(buf);
+        }
+        Ok(DebugFrame {
+            function: frame.function.pretty_string(),
+            pc,
+            locals,
+        })
+    }
+
     /// Generate a string which is the status of the interpreter: call stack, current bytecode
     /// stream, locals and operand stack.
     ///
@@ -659,6 +762,8 @@ impl Frame {
         loop {
             for instruction in &code[self.pc as usize..] {
                 trace!(self.function.pretty_string(), self.pc, instruction);
+                #[cfg(feature = "debugger")]
+                interpreter.debug_hook(resolver, self, instruction)?;
                 self.pc += 1;
 
                 match instruction {
@@ -672,14 +777,18 @@ impl Frame {
                     }
                     Bytecode::BrTrue(offset) => {
                         cost_strategy.charge_instr(Opcodes::BR_TRUE)?;
-                        if interpreter.operand_stack.pop_as::<bool>()? {
+                        let cond = interpreter.operand_stack.pop_as::<bool>()?;
+                        trace_branch!(self.function.pretty_string(), self.pc - 1, cond);
+                        if cond {
                             self.pc = *offset;
                             break;
                         }
                     }
                     Bytecode::BrFalse(offset) => {
                         cost_strategy.charge_instr(Opcodes::BR_FALSE)?;
-                        if !interpreter.operand_stack.pop_as::<bool>()? {
+                        let cond = interpreter.operand_stack.pop_as::<bool>()?;
+                        trace_branch!(self.function.pretty_string(), self.pc - 1, cond);
+                        if !cond {
                             self.pc = *offset;
                             break;
                         }
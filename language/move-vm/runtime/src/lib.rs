@@ -12,12 +12,16 @@
 #[macro_use]
 extern crate mirai_annotations;
 
+pub mod code_cache;
 pub mod data_cache;
 mod data_operations;
+#[cfg(feature = "debugger")]
+pub mod debugger;
 mod interpreter;
 mod loader;
 pub mod move_vm;
 mod native_functions;
 mod runtime;
+pub mod stack_trace;
 #[macro_use]
 mod tracing;
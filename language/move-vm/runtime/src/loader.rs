@@ -1,7 +1,7 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::native_functions::NativeFunction;
+use crate::{code_cache, native_functions::NativeFunction};
 use bytecode_verifier::{
     constants, instantiation_loops::InstantiationLoopChecker, verify_main_signature,
     CodeUnitVerifier, DependencyChecker, DuplicationChecker, InstructionConsistency,
@@ -29,6 +29,7 @@ use std::{
     fmt::Debug,
     hash::Hash,
     sync::{Arc, Mutex},
+    time::Instant,
 };
 use vm::{
     access::{ModuleAccess, ScriptAccess},
@@ -126,7 +127,7 @@ impl ModuleCache {
         self.modules.get(id).map(|module| Arc::clone(module))
     }
 
-    fn insert(&mut self, id: ModuleId, module: CompiledModule) -> VMResult<Arc<Module>> {
+    fn insert(&mut self, id: ModuleId, module: Arc<CompiledModule>) -> VMResult<Arc<Module>> {
         self.add_module(&module)?;
         let module = Module::new(module, self)?;
         match self.get(&id) {
@@ -326,6 +327,14 @@ impl Loader {
         }
     }
 
+    // Drops all loaded scripts and modules, they are loaded again from the data store when next
+    // used. Called when a module is upgraded, as cached entities may refer to its old version.
+    pub(crate) fn flush(&self) {
+        *self.scripts.lock().unwrap() = ScriptCache::new();
+        *self.module_cache.lock().unwrap() = ModuleCache::new();
+        self.libra_cache.lock().unwrap().clear();
+    }
+
     // Entry point for function execution (`MoveVM::execute_function`).
     // Loading verifies the module if it was never loaded.
     // Type parameters are checked as well after every type is loaded.
@@ -616,27 +625,35 @@ impl Loader {
         &self,
         id: &ModuleId,
         data_store: &mut dyn DataStore,
-    ) -> VMResult<CompiledModule> {
-        let module = match data_store.load_module(id) {
-            Ok(blob) => match CompiledModule::deserialize(&blob) {
-                Ok(module) => module,
-                Err(err) => {
-                    crit!("[VM] Storage contains a malformed module with id {:?}", id);
-                    return Err(err);
-                }
-            },
+    ) -> VMResult<Arc<CompiledModule>> {
+        let blob = match data_store.load_module(id) {
+            Ok(blob) => blob,
             Err(err) => {
                 crit!("[VM] Error fetching module with id {:?}", id);
                 return Err(err);
             }
         };
-        match self.verify_module(&module) {
-            Ok(_) => {
-                self.check_dependencies(&module, data_store)?;
-                Ok(module)
+        // Modules verified by any VM of the process are cached by the hash of their bytes.
+        let hash = HashValue::sha3_256_of(&blob);
+        let module = match code_cache::get(&hash) {
+            Some(module) => module,
+            None => {
+                let start = Instant::now();
+                let module = match CompiledModule::deserialize(&blob) {
+                    Ok(module) => module,
+                    Err(err) => {
+                        crit!("[VM] Storage contains a malformed module with id {:?}", id);
+                        return Err(err);
+                    }
+                };
+                self.verify_module(&module)?;
+                let module = Arc::new(module);
+                code_cache::insert(hash, Arc::clone(&module), start.elapsed());
+                module
             }
-            Err(err) => Err(err),
-        }
+        };
+        self.check_dependencies(&module, data_store)?;
+        Ok(module)
     }
 
     fn check_dependencies(
@@ -782,6 +799,11 @@ impl<'a> Resolver<'a> {
         self.loader.type_to_fat_type(ty)
     }
 
+    #[cfg(feature = "debugger")]
+    pub(crate) fn loader(&self) -> &'a Loader {
+        self.loader
+    }
+
     pub(crate) fn is_resource(&self, ty: &Type) -> VMResult<bool> {
         self.loader.is_resource(ty)
     }
@@ -803,8 +825,11 @@ impl<'a> Resolver<'a> {
                     .subst(type_context)?;
                 self.loader.type_to_fat_type(&ty)
             }
-            // TODO: this may not be true at all when it comes to printing (locals for instance)
-            BinaryType::Script(_) => unreachable!("Scripts cannot have type operations"),
+            // Scripts have no type operations, but their locals may still need to be printed.
+            BinaryType::Script(script) => {
+                let ty = script.make_type(token).subst(type_context)?;
+                self.loader.type_to_fat_type(&ty)
+            }
         }
     }
 }
@@ -824,7 +849,7 @@ impl<'a> TypeConverter for Resolver<'a> {
 pub struct Module {
     id: ModuleId,
     // primitive pools
-    module: CompiledModule,
+    module: Arc<CompiledModule>,
 
     // types as indexes into the Loader type list
     struct_refs: Vec<usize>,
@@ -843,7 +868,7 @@ pub struct Module {
 }
 
 impl Module {
-    fn new(module: CompiledModule, cache: &ModuleCache) -> VMResult<Self> {
+    fn new(module: Arc<CompiledModule>, cache: &ModuleCache) -> VMResult<Self> {
         let id = module.self_id();
 
         let mut struct_refs = vec![];
@@ -987,6 +1012,32 @@ struct Script {
 }
 
 impl Script {
+    /// Converts a signature token of the script into a type, like `ModuleCache::make_type` does
+    /// for modules, using the structs the script was resolved against.
+    fn make_type(&self, tok: &SignatureToken) -> Type {
+        match tok {
+            SignatureToken::Bool => Type::Bool,
+            SignatureToken::U8 => Type::U8,
+            SignatureToken::U64 => Type::U64,
+            SignatureToken::U128 => Type::U128,
+            SignatureToken::Address => Type::Address,
+            SignatureToken::Signer => Type::Signer,
+            SignatureToken::TypeParameter(idx) => Type::TyParam(*idx as usize),
+            SignatureToken::Vector(inner_tok) => Type::Vector(Box::new(self.make_type(inner_tok))),
+            SignatureToken::Reference(inner_tok) => {
+                Type::Reference(Box::new(self.make_type(inner_tok)))
+            }
+            SignatureToken::MutableReference(inner_tok) => {
+                Type::MutableReference(Box::new(self.make_type(inner_tok)))
+            }
+            SignatureToken::Struct(sh_idx) => Type::Struct(self.struct_refs[sh_idx.0 as usize]),
+            SignatureToken::StructInstantiation(sh_idx, tys) => Type::StructInstantiation(
+                self.struct_refs[sh_idx.0 as usize],
+                tys.iter().map(|tok| self.make_type(tok)).collect(),
+            ),
+        }
+    }
+
     fn new(script: CompiledScript, script_hash: &HashValue, cache: &ModuleCache) -> VMResult<Self> {
         let mut struct_refs = vec![];
         for struct_handle in script.struct_handles() {
@@ -1177,6 +1228,10 @@ impl Function {
         self.name.as_str()
     }
 
+    pub(crate) fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+
     pub(crate) fn code(&self) -> &[Bytecode] {
         &self.code
     }
@@ -62,7 +62,21 @@ impl MoveVM {
         cost_strategy: &mut CostStrategy,
     ) -> VMResult<()> {
         self.runtime
-            .publish_module(module, sender, data_store, cost_strategy)
+            .publish_module(module, sender, data_store, cost_strategy, false)
+    }
+
+    /// Publishes a module like `publish_module`, except that a module already published under
+    /// the same name is replaced, provided the new version is compatible with it (see
+    /// `bytecode_verifier::compatibility`).
+    pub fn publish_module_upgrade(
+        &self,
+        module: Vec<u8>,
+        sender: AccountAddress,
+        data_store: &mut dyn DataStore,
+        cost_strategy: &mut CostStrategy,
+    ) -> VMResult<()> {
+        self.runtime
+            .publish_module(module, sender, data_store, cost_strategy, true)
     }
 }
 
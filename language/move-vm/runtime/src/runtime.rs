@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{interpreter::Interpreter, loader::Loader};
+use bytecode_verifier::verify_compatibility;
 use libra_logger::prelude::*;
 use libra_types::vm_status::{StatusCode, VMStatus};
 use move_core_types::{
@@ -35,6 +36,7 @@ impl VMRuntime {
         sender: AccountAddress,
         data_store: &mut dyn DataStore,
         _cost_strategy: &mut CostStrategy,
+        allow_upgrade: bool,
     ) -> VMResult<()> {
         // deserialize the module. Perform bounds check. After this indexes can be
         // used with the `[]` operator
@@ -58,19 +60,30 @@ impl VMRuntime {
         }
 
         // Make sure that there is not already a module with this name published
-        // under the transaction sender's account.
+        // under the transaction sender's account, unless it is an upgrade, in which case the new
+        // version must be compatible with the published one.
         let module_id = compiled_module.self_id();
-        if data_store.exists_module(&module_id) {
-            return Err(vm_status(
-                Location::default(),
-                StatusCode::DUPLICATE_MODULE_NAME,
-            ));
-        };
+        let upgrade = data_store.exists_module(&module_id);
+        if upgrade {
+            if !allow_upgrade {
+                return Err(vm_status(
+                    Location::default(),
+                    StatusCode::DUPLICATE_MODULE_NAME,
+                ));
+            }
+            let published = CompiledModule::deserialize(&data_store.load_module(&module_id)?)?;
+            verify_compatibility(&published, &compiled_module)?;
+        }
 
         // perform bytecode and loading verification
         self.loader.verify_module(&compiled_module)?;
 
-        data_store.publish_module(module_id, module)
+        data_store.publish_module(module_id, module)?;
+        // Code loaded from the published version must not be used anymore.
+        if upgrade {
+            self.loader.flush();
+        }
+        Ok(())
     }
 
     pub(crate) fn execute_script(
@@ -0,0 +1,39 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Optional recording of the call stack when an execution fails.
+//!
+//! Recording is off by default and is switched on per thread with `record_stack_traces`. When
+//! on, the interpreter saves the call stack of every failed execution on the thread, which can
+//! then be retrieved with `take_stack_trace`. Only the last failure is kept, so tools should
+//! take the trace right after the execution they are interested in.
+
+use std::cell::{Cell, RefCell};
+use vm::errors::StackTrace;
+
+thread_local! {
+    static RECORDING: Cell<bool> = Cell::new(false);
+    static LAST_STACK_TRACE: RefCell<Option<StackTrace>> = RefCell::new(None);
+}
+
+/// Turns the recording of stack traces on or off for executions on the current thread.
+pub fn record_stack_traces(enabled: bool) {
+    RECORDING.with(|recording| recording.set(enabled));
+    if !enabled {
+        take_stack_trace();
+    }
+}
+
+/// Returns the stack trace of the last failed execution on the current thread, if any was
+/// recorded since the last call.
+pub fn take_stack_trace() -> Option<StackTrace> {
+    LAST_STACK_TRACE.with(|trace| trace.borrow_mut().take())
+}
+
+pub(crate) fn is_recording() -> bool {
+    RECORDING.with(|recording| recording.get())
+}
+
+pub(crate) fn record(stack_trace: StackTrace) {
+    LAST_STACK_TRACE.with(|trace| *trace.borrow_mut() = Some(stack_trace));
+}
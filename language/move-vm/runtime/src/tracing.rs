@@ -1,30 +1,39 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(debug_assertions)]
+//! Traces the instructions the VM executes to the file named by `MOVE_VM_TRACE`, when it is set,
+//! in debug builds or with the `tracing` feature. Each line is either
+//! `<function>,<code offset>,<instruction>` for an instruction about to be executed, or
+//! `<function>,<code offset>,branch:<true|false>` for the condition a `BrTrue` or `BrFalse` found.
+//!
+//! No crate enables the `tracing` feature by default. To trace release builds of tests, enable it
+//! through the `tracing` feature of move-coverage, as in
+//! `cargo test --release -p language-e2e-tests -p move-coverage --features move-coverage/tracing`.
+
+#[cfg(any(debug_assertions, feature = "tracing"))]
 use once_cell::sync::Lazy;
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 use std::{
     env,
     fs::{File, OpenOptions},
     io::Write,
     sync::Mutex,
 };
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 use vm::file_format::Bytecode;
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 const MOVE_VM_TRACING_ENV_VAR_NAME: &str = "MOVE_VM_TRACE";
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 static FILE_PATH: Lazy<String> = Lazy::new(|| {
     env::var(MOVE_VM_TRACING_ENV_VAR_NAME).unwrap_or_else(|_| "move_vm_trace.trace".to_string())
 });
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 static TRACING_ENABLED: Lazy<bool> = Lazy::new(|| env::var(MOVE_VM_TRACING_ENV_VAR_NAME).is_ok());
 
-#[cfg(debug_assertions)]
+#[cfg(any(debug_assertions, feature = "tracing"))]
 static LOGGING_FILE: Lazy<Mutex<File>> = Lazy::new(|| {
     Mutex::new(
         OpenOptions::new()
@@ -36,20 +45,48 @@ static LOGGING_FILE: Lazy<Mutex<File>> = Lazy::new(|| {
     )
 });
 
-// Only include in debug builds
-#[cfg(debug_assertions)]
+// Only include in debug builds, or if the `tracing` feature is enabled
+#[cfg(any(debug_assertions, feature = "tracing"))]
+pub fn is_enabled() -> bool {
+    *TRACING_ENABLED
+}
+
+// The macros only call the functions below if `is_enabled`, so that the VM does not format the
+// name of the function of every instruction when it is not traced.
+#[cfg(any(debug_assertions, feature = "tracing"))]
 pub fn trace(function_desc: &str, pc: u16, instr: &Bytecode) {
-    if *TRACING_ENABLED {
-        let f = &mut *LOGGING_FILE.lock().unwrap();
-        writeln!(f, "{},{},{:?}", function_desc, pc, instr).unwrap();
-    }
+    let f = &mut *LOGGING_FILE.lock().unwrap();
+    writeln!(f, "{},{},{:?}", function_desc, pc, instr).unwrap();
+}
+
+#[cfg(any(debug_assertions, feature = "tracing"))]
+pub fn trace_branch(function_desc: &str, pc: u16, cond: bool) {
+    let f = &mut *LOGGING_FILE.lock().unwrap();
+    writeln!(f, "{},{},branch:{}", function_desc, pc, cond).unwrap();
 }
 
 #[macro_export]
 macro_rules! trace {
     ($function_desc:expr, $pc:expr, $instr:tt) => {
-        // Only include this code in debug releases
-        #[cfg(debug_assertions)]
-        crate::tracing::trace(&$function_desc, $pc, &$instr)
+        // Only include this code in debug releases, or with the `tracing` feature
+        #[cfg(any(debug_assertions, feature = "tracing"))]
+        {
+            if crate::tracing::is_enabled() {
+                crate::tracing::trace(&$function_desc, $pc, &$instr)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace_branch {
+    ($function_desc:expr, $pc:expr, $cond:expr) => {
+        // Only include this code in debug releases, or with the `tracing` feature
+        #[cfg(any(debug_assertions, feature = "tracing"))]
+        {
+            if crate::tracing::is_enabled() {
+                crate::tracing::trace_branch(&$function_desc, $pc, $cond)
+            }
+        }
     };
 }
@@ -0,0 +1,122 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Attribution of charged gas to Move functions and instructions.
+//!
+//! A `GasProfiler` is attached to a `CostStrategy` with `CostStrategy::with_profiler`. The
+//! interpreter reports every function it enters and leaves, and the `CostStrategy` reports
+//! every charge along with the instruction (or native call) it is for. Only gas that is
+//! actually deducted is recorded, so a system `CostStrategy` produces an empty profile.
+//!
+//! The profile can be exported in the folded stack format understood by `inferno` and
+//! `flamegraph.pl`, where each line is a `;` separated call stack followed by the gas charged
+//! with that stack, e.g. `Script::main;0x1::LibraAccount::pay_from;ADD 12`.
+
+use move_core_types::gas_schedule::GasCarrier;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+/// Label used for the cost reported by native functions.
+pub const NATIVE_LABEL: &str = "NATIVE";
+/// Label used for the intrinsic cost of a transaction.
+pub const INTRINSIC_LABEL: &str = "INTRINSIC";
+/// Label used for gas deducted without naming an instruction.
+pub const OTHER_LABEL: &str = "OTHER";
+
+#[derive(Clone, Debug, Default)]
+pub struct GasProfiler {
+    stack: Vec<String>,
+    folded_stacks: BTreeMap<String, GasCarrier>,
+    by_function: BTreeMap<String, GasCarrier>,
+    by_instruction: BTreeMap<String, GasCarrier>,
+}
+
+impl GasProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enter_function(&mut self, name: String) {
+        self.stack.push(name);
+    }
+
+    pub fn exit_function(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Number of functions entered but not exited.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Exits the functions entered after the call stack had the given depth, e.g., when
+    /// execution stopped with an error.
+    pub fn unwind(&mut self, depth: usize) {
+        self.stack.truncate(depth);
+    }
+
+    /// Records gas charged for the given instruction (or `NATIVE_LABEL`, `INTRINSIC_LABEL`)
+    /// within the current call stack.
+    pub fn record(&mut self, label: &str, amount: GasCarrier) {
+        if amount == 0 {
+            return;
+        }
+
+        let mut stack = self.stack.join(";");
+        if !stack.is_empty() {
+            stack.push(';');
+        }
+        stack.push_str(label);
+        *self.folded_stacks.entry(stack).or_insert(0) += amount;
+
+        if let Some(function) = self.stack.last() {
+            *self.by_function.entry(function.clone()).or_insert(0) += amount;
+        }
+        *self.by_instruction.entry(label.to_string()).or_insert(0) += amount;
+    }
+
+    /// Adds the gas recorded by another profiler, e.g., to aggregate a block of transactions.
+    pub fn merge(&mut self, other: &GasProfiler) {
+        for (stack, amount) in &other.folded_stacks {
+            *self.folded_stacks.entry(stack.clone()).or_insert(0) += amount;
+        }
+        for (function, amount) in &other.by_function {
+            *self.by_function.entry(function.clone()).or_insert(0) += amount;
+        }
+        for (label, amount) in &other.by_instruction {
+            *self.by_instruction.entry(label.clone()).or_insert(0) += amount;
+        }
+    }
+
+    /// Total gas recorded.
+    pub fn total(&self) -> GasCarrier {
+        self.by_instruction.values().sum()
+    }
+
+    /// Gas charged directly within each function, excluding the functions it calls.
+    pub fn gas_by_function(&self) -> &BTreeMap<String, GasCarrier> {
+        &self.by_function
+    }
+
+    /// Gas charged for each instruction across all functions.
+    pub fn gas_by_instruction(&self) -> &BTreeMap<String, GasCarrier> {
+        &self.by_instruction
+    }
+
+    /// Returns the profile in the folded stack format, one stack per line.
+    pub fn folded_stacks(&self) -> String {
+        self.folded_stacks
+            .iter()
+            .map(|(stack, amount)| format!("{} {}\n", stack, amount))
+            .collect()
+    }
+
+    pub fn write_folded_stacks(&self, path: &Path) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        file.write_all(self.folded_stacks().as_bytes())
+    }
+}
@@ -6,6 +6,7 @@
 //! It is important to note that the cost schedule defined in this file does not track hashing
 //! operations or other native operations; the cost of each native operation will be returned by the
 //! native function itself.
+use crate::gas_profiler::{GasProfiler, INTRINSIC_LABEL, NATIVE_LABEL, OTHER_LABEL};
 use libra_types::{
     transaction::MAX_TRANSACTION_SIZE_IN_BYTES,
     vm_status::{StatusCode, VMStatus},
@@ -35,6 +36,7 @@ pub struct CostStrategy<'a> {
     cost_table: &'a CostTable,
     gas_left: GasUnits<GasCarrier>,
     charge: bool,
+    profiler: Option<GasProfiler>,
 }
 
 impl<'a> CostStrategy<'a> {
@@ -47,6 +49,7 @@ impl<'a> CostStrategy<'a> {
             cost_table,
             gas_left,
             charge: true,
+            profiler: None,
         }
     }
 
@@ -59,6 +62,51 @@ impl<'a> CostStrategy<'a> {
             cost_table,
             gas_left,
             charge: false,
+            profiler: None,
+        }
+    }
+
+    /// Attach a `GasProfiler` that records where the gas charged by this `CostStrategy` goes.
+    pub fn with_profiler(mut self) -> Self {
+        self.profiler = Some(GasProfiler::new());
+        self
+    }
+
+    /// Return the attached `GasProfiler`, if any.
+    pub fn profiler(&self) -> Option<&GasProfiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Detach and return the `GasProfiler`, if any.
+    pub fn take_profiler(&mut self) -> Option<GasProfiler> {
+        self.profiler.take()
+    }
+
+    /// Notify the profiler that execution entered a function. The name is only computed when
+    /// profiling.
+    pub fn enter_function<F: FnOnce() -> String>(&mut self, name: F) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.enter_function(name());
+        }
+    }
+
+    /// Notify the profiler that execution left the current function.
+    pub fn exit_function(&mut self) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.exit_function();
+        }
+    }
+
+    /// Depth of the call stack tracked by the profiler, zero when not profiling.
+    pub fn profiled_depth(&self) -> usize {
+        self.profiler.as_ref().map_or(0, GasProfiler::depth)
+    }
+
+    /// Notify the profiler that the functions entered after the given depth were left due to an
+    /// error.
+    pub fn unwind_functions(&mut self, depth: usize) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.unwind(depth);
         }
     }
 
@@ -74,6 +122,19 @@ impl<'a> CostStrategy<'a> {
 
     /// Charge a given amount of gas and fail if not enough gas units are left.
     pub fn deduct_gas(&mut self, amount: GasUnits<GasCarrier>) -> VMResult<()> {
+        self.deduct_gas_for(|| OTHER_LABEL.to_string(), amount)
+    }
+
+    /// Charge the cost returned by a native function and fail if not enough gas units are left.
+    pub fn charge_native(&mut self, amount: GasUnits<GasCarrier>) -> VMResult<()> {
+        self.deduct_gas_for(|| NATIVE_LABEL.to_string(), amount)
+    }
+
+    fn deduct_gas_for<F: FnOnce() -> String>(
+        &mut self,
+        label: F,
+        amount: GasUnits<GasCarrier>,
+    ) -> VMResult<()> {
         if !self.charge {
             return Ok(());
         }
@@ -82,8 +143,14 @@ impl<'a> CostStrategy<'a> {
             .app(&amount, |curr_gas, gas_amt| curr_gas >= gas_amt)
         {
             self.gas_left = self.gas_left.sub(amount);
+            if let Some(profiler) = &mut self.profiler {
+                profiler.record(&label(), amount.get());
+            }
             Ok(())
         } else {
+            if let Some(profiler) = &mut self.profiler {
+                profiler.record(&label(), self.gas_left.get());
+            }
             // Zero out the internal gas state
             self.gas_left = GasUnits::new(0);
             Err(VMStatus::new(StatusCode::OUT_OF_GAS))
@@ -96,7 +163,8 @@ impl<'a> CostStrategy<'a> {
         opcode: Opcodes,
         size: AbstractMemorySize<GasCarrier>,
     ) -> VMResult<()> {
-        self.deduct_gas(
+        self.deduct_gas_for(
+            || format!("{:?}", opcode),
             self.cost_table
                 .instruction_cost(opcode as u8)
                 .total()
@@ -106,7 +174,10 @@ impl<'a> CostStrategy<'a> {
 
     /// Charge an instruction and fail if not enough gas units are left.
     pub fn charge_instr(&mut self, opcode: Opcodes) -> VMResult<()> {
-        self.deduct_gas(self.cost_table.instruction_cost(opcode as u8).total())
+        self.deduct_gas_for(
+            || format!("{:?}", opcode),
+            self.cost_table.instruction_cost(opcode as u8).total(),
+        )
     }
 
     /// Charge gas related to the overall size of a transaction and fail if not enough
@@ -116,7 +187,7 @@ impl<'a> CostStrategy<'a> {
         instrinsic_cost: AbstractMemorySize<GasCarrier>,
     ) -> VMResult<()> {
         let cost = calculate_intrinsic_gas(instrinsic_cost, &self.cost_table.gas_constants);
-        self.deduct_gas(cost)
+        self.deduct_gas_for(|| INTRINSIC_LABEL.to_string(), cost)
     }
 }
 
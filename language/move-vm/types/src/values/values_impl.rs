@@ -2338,6 +2338,28 @@ pub mod debug {
         }
     }
 
+    pub fn print_local<B: Write>(
+        buf: &mut B,
+        ty: &FatType,
+        locals: &Locals,
+        idx: usize,
+    ) -> VMResult<()> {
+        match &*locals.0.borrow() {
+            Container::General(v) => match v.get(idx) {
+                Some(val) => print_value_impl(buf, ty, val),
+                None => Err(VMStatus::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("local index out of bounds".to_string())),
+            },
+
+            Container::Resource(_)
+            | Container::U8(_)
+            | Container::U64(_)
+            | Container::U128(_)
+            | Container::Bool(_)
+            | Container::Address(_) => unreachable!(),
+        }
+    }
+
     pub fn print_locals<B: Write>(buf: &mut B, tys: &[FatType], locals: &Locals) -> VMResult<()> {
         match &*locals.0.borrow() {
             Container::General(v) => {
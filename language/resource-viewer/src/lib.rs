@@ -10,14 +10,19 @@ use libra_types::{
 };
 use move_core_types::{
     identifier::Identifier,
-    language_storage::StructTag,
+    language_storage::{StructTag, CODE_TAG, RESOURCE_TAG},
     value::{MoveStruct, MoveValue},
 };
+use serde::{
+    ser::{SerializeMap, SerializeStruct},
+    Serialize, Serializer,
+};
 use std::{
     collections::btree_map::BTreeMap,
     convert::TryInto,
     fmt::{Display, Formatter},
 };
+use vm::{access::ModuleAccess, CompiledModule};
 
 pub use cached_access_path_table::update_mapping;
 use move_vm_types::loaded_data::types::{FatStructType, FatType};
@@ -37,9 +42,10 @@ pub struct AnnotatedMoveStruct {
 }
 
 /// AnnotatedMoveValue is a fully expanded version of on chain move data. This should only be used
-/// for debugging/client purpose right now and just for a better visualization of on chain data. In
-/// the long run, we would like to transform this struct to a Json value so that we can have a cross
-/// platform interpretation of the on chain data.
+/// for debugging/client purpose right now and just for a better visualization of on chain data.
+/// It serializes to JSON for a cross platform interpretation of the on chain data: integers are
+/// numbers, except `u128`s which are decimal strings, addresses and byte vectors are hex strings,
+/// and structs are objects with their `type`, `is_resource` and `fields` by name.
 #[derive(Debug)]
 pub enum AnnotatedMoveValue {
     U8(u8),
@@ -76,6 +82,13 @@ impl<'a> MoveValueAnnotator<'a> {
         self.annotate_struct(&move_struct, &ty)
     }
 
+    /// Annotates a resource of the type `tag`, resolving its declaring module from the state if it
+    /// isn't in the standard library.
+    pub fn view_resource(&self, tag: &StructTag, blob: &[u8]) -> Result<AnnotatedMoveStruct> {
+        let ty = self.cache.resolve_struct(tag)?;
+        self.annotate_blob(blob, &ty)
+    }
+
     pub fn view_contract_event(&self, event: &ContractEvent) -> Result<AnnotatedMoveValue> {
         let ty = self.cache.resolve_type(event.type_tag())?;
         let move_ty = (&ty).try_into()?;
@@ -94,6 +107,45 @@ impl<'a> MoveValueAnnotator<'a> {
         Ok(AnnotatedAccountStateBlob(output))
     }
 
+    /// Annotates the resources of `state` whose types are known: those of the standard library,
+    /// and those without type parameters declared by the modules published in `state` itself.
+    /// Returns every resource by its path, with `None` for the resources which can't be annotated.
+    pub fn view_account_resources(
+        &self,
+        state: &AccountState,
+    ) -> Result<Vec<(Vec<u8>, Option<AnnotatedMoveStruct>)>> {
+        let mut published = BTreeMap::new();
+        for (path, blob) in state.iter() {
+            if path.first() == Some(&CODE_TAG) {
+                let module = CompiledModule::deserialize(blob)
+                    .map_err(|status| anyhow!("Module deserialize with error code {:?}", status))?;
+                for tag in resource_struct_tags(&module) {
+                    published.insert(tag.access_vector(), tag);
+                }
+            }
+        }
+        let mut resources = vec![];
+        for (path, blob) in state.iter() {
+            if path.first() != Some(&RESOURCE_TAG) {
+                continue;
+            }
+            let resource = match resource_vec_to_type_tag(path) {
+                Ok(ty) => self.annotate_blob(blob, &ty).ok(),
+                Err(_) => published
+                    .get(path)
+                    .and_then(|tag| self.view_resource(tag, blob).ok()),
+            };
+            resources.push((path.clone(), resource));
+        }
+        Ok(resources)
+    }
+
+    fn annotate_blob(&self, blob: &[u8], ty: &FatStructType) -> Result<AnnotatedMoveStruct> {
+        let struct_def = ty.try_into()?;
+        let move_struct = MoveStruct::simple_deserialize(blob, &struct_def)?;
+        self.annotate_struct(&move_struct, ty)
+    }
+
     fn annotate_struct(
         &self,
         move_struct: &MoveStruct,
@@ -151,6 +203,22 @@ impl<'a> MoveValueAnnotator<'a> {
     }
 }
 
+/// The tags of the resources without type parameters declared by `module`.
+fn resource_struct_tags(module: &CompiledModule) -> Vec<StructTag> {
+    module
+        .struct_defs()
+        .iter()
+        .map(|struct_def| module.struct_handle_at(struct_def.struct_handle))
+        .filter(|handle| handle.is_nominal_resource && handle.type_parameters.is_empty())
+        .map(|handle| StructTag {
+            address: *module.address(),
+            module: module.name().to_owned(),
+            name: module.identifier_at(handle.name).to_owned(),
+            type_params: vec![],
+        })
+        .collect()
+}
+
 fn write_indent(f: &mut Formatter, indent: u64) -> std::fmt::Result {
     for _i in 0..indent {
         write!(f, " ")?;
@@ -228,6 +296,44 @@ impl Display for AnnotatedAccountStateBlob {
     }
 }
 
+impl Serialize for AnnotatedMoveValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            AnnotatedMoveValue::Bool(b) => serializer.serialize_bool(*b),
+            AnnotatedMoveValue::U8(v) => serializer.serialize_u8(*v),
+            AnnotatedMoveValue::U64(v) => serializer.serialize_u64(*v),
+            AnnotatedMoveValue::U128(v) => serializer.serialize_str(&v.to_string()),
+            AnnotatedMoveValue::Address(a) => a.serialize(serializer),
+            AnnotatedMoveValue::Vector(v) => v.serialize(serializer),
+            AnnotatedMoveValue::Bytes(v) => serializer.serialize_str(&hex::encode(v)),
+            AnnotatedMoveValue::Struct(s) => s.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for AnnotatedMoveStruct {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("AnnotatedMoveStruct", 3)?;
+        s.serialize_field("type", &self.type_)?;
+        s.serialize_field("is_resource", &self.is_resource)?;
+        s.serialize_field("fields", &AnnotatedFields(&self.value))?;
+        s.end()
+    }
+}
+
+/// The fields of a struct, serialized as a map in declaration order.
+struct AnnotatedFields<'a>(&'a [(Identifier, AnnotatedMoveValue)]);
+
+impl<'a> Serialize for AnnotatedFields<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in self.0 {
+            map.serialize_entry(name.as_str(), value)?;
+        }
+        map.end()
+    }
+}
+
 #[derive(Default)]
 pub struct NullStateView();
 
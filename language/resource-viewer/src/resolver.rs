@@ -72,7 +72,17 @@ impl<'a> Resolver<'a> {
     pub fn resolve_struct(&self, struct_tag: &StructTag) -> Result<FatStructType> {
         let module = self.get_module(&struct_tag.address, &struct_tag.module)?;
         let struct_def = find_struct_def_in_module(module.clone(), struct_tag.name.as_ident_str())?;
-        self.resolve_struct_definition(module, struct_def)
+        let ty = self.resolve_struct_definition(module, struct_def)?;
+        if struct_tag.type_params.is_empty() {
+            return Ok(ty);
+        }
+        let args = struct_tag
+            .type_params
+            .iter()
+            .map(|type_param| self.resolve_type(type_param))
+            .collect::<Result<Vec<_>>>()?;
+        ty.subst(&args)
+            .map_err(|status| anyhow!("Substitution failure: {:?}", status))
     }
 
     pub fn get_field_names(&self, ty: &FatStructType) -> Result<Vec<Identifier>> {
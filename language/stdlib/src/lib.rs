@@ -40,6 +40,9 @@ pub const COMPILED_TRANSACTION_SCRIPTS_ABI_DIR: &str = "compiled/transaction_scr
 /// Where to write generated transaction builders.
 pub const TRANSACTION_BUILDERS_GENERATED_SOURCE_PATH: &str =
     "../transaction-builder/src/generated.rs";
+/// Where to write the generated C transaction builders of `libra-dev`.
+pub const FFI_TRANSACTION_BUILDERS_GENERATED_SOURCE_PATH: &str =
+    "../../client/libra-dev/src/scripts.rs";
 
 pub fn filter_move_files(dir_iter: impl Iterator<Item = PathBuf>) -> impl Iterator<Item = PathBuf> {
     dir_iter.flat_map(|path| {
@@ -176,3 +179,19 @@ pub fn generate_rust_transaction_builders() {
         .status()
         .expect("Failed to run rustfmt on generated code");
 }
+
+pub fn generate_ffi_transaction_builders() {
+    let abis = transaction_builder_generator::read_abis(COMPILED_TRANSACTION_SCRIPTS_ABI_DIR)
+        .expect("Failed to read generated ABIs");
+    let mut f = std::fs::File::create(FFI_TRANSACTION_BUILDERS_GENERATED_SOURCE_PATH)
+        .expect("Failed to open file for C script build generation");
+    transaction_builder_generator::ffi::output(&mut f, &abis)
+        .expect("Failed to generate C builders for Libra");
+
+    std::process::Command::new("rustfmt")
+        .arg("--config")
+        .arg("merge_imports=true")
+        .arg(FFI_TRANSACTION_BUILDERS_GENERATED_SOURCE_PATH)
+        .status()
+        .expect("Failed to run rustfmt on generated code");
+}
@@ -12,10 +12,10 @@ use std::{
 };
 use stdlib::{
     build_stdlib, build_stdlib_doc, build_transaction_script_abi, build_transaction_script_doc,
-    compile_script, filter_move_files, generate_rust_transaction_builders, save_binary,
-    COMPILED_EXTENSION, COMPILED_OUTPUT_PATH, COMPILED_STDLIB_NAME,
-    COMPILED_TRANSACTION_SCRIPTS_ABI_DIR, COMPILED_TRANSACTION_SCRIPTS_DIR, STD_LIB_DOC_DIR,
-    TRANSACTION_SCRIPTS, TRANSACTION_SCRIPTS_DOC_DIR,
+    compile_script, filter_move_files, generate_ffi_transaction_builders,
+    generate_rust_transaction_builders, save_binary, COMPILED_EXTENSION, COMPILED_OUTPUT_PATH,
+    COMPILED_STDLIB_NAME, COMPILED_TRANSACTION_SCRIPTS_ABI_DIR, COMPILED_TRANSACTION_SCRIPTS_DIR,
+    STD_LIB_DOC_DIR, TRANSACTION_SCRIPTS, TRANSACTION_SCRIPTS_DOC_DIR,
 };
 
 // Generates the compiled stdlib and transaction scripts. Until this is run changes to the source
@@ -131,6 +131,9 @@ fn main() {
         time_it("Generating Rust script builders", || {
             generate_rust_transaction_builders();
         });
+        time_it("Generating C script builders", || {
+            generate_ffi_transaction_builders();
+        });
     }
 }
 
@@ -0,0 +1,105 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::{bail, format_err, Result};
+use bytecode_verifier::check_compatibility;
+use compiled_stdlib::{stdlib_modules, StdLibOptions};
+use move_core_types::language_storage::ModuleId;
+use std::{collections::BTreeMap, fs, path::Path};
+use structopt::StructOpt;
+use vm::{access::ModuleAccess, CompiledModule};
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Compatibility Checker",
+    about = "Check that new versions of Move modules can replace the published ones"
+)]
+struct Args {
+    /// Compare the compiled standard library with a fresh build of its sources, to review a
+    /// stdlib upgrade.
+    #[structopt(long = "stdlib")]
+    pub stdlib: bool,
+
+    /// The published modules: a compiled module, or a directory of compiled modules (`.mv`).
+    #[structopt(required_unless = "stdlib")]
+    pub old: Option<String>,
+
+    /// The new versions of the modules, in the same form as the published ones.
+    #[structopt(required_unless = "stdlib")]
+    pub new: Option<String>,
+}
+
+fn read_modules(path: &str) -> Result<BTreeMap<ModuleId, CompiledModule>> {
+    let path = Path::new(path);
+    let files = if path.is_dir() {
+        let mut files = vec![];
+        for entry in fs::read_dir(path)? {
+            let file = entry?.path();
+            if file.extension().map_or(false, |ext| ext == "mv") {
+                files.push(file);
+            }
+        }
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+    let mut modules = BTreeMap::new();
+    for file in files {
+        let module = CompiledModule::deserialize(&fs::read(&file)?)
+            .map_err(|status| format_err!("cannot deserialize {}: {}", file.display(), status))?;
+        modules.insert(module.self_id(), module);
+    }
+    Ok(modules)
+}
+
+fn stdlib(option: StdLibOptions) -> BTreeMap<ModuleId, CompiledModule> {
+    stdlib_modules(option)
+        .iter()
+        .map(|module| (module.self_id(), module.clone()))
+        .collect()
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let (old, new) = if args.stdlib {
+        (
+            stdlib(StdLibOptions::Compiled),
+            stdlib(StdLibOptions::Fresh),
+        )
+    } else {
+        (
+            read_modules(args.old.as_ref().unwrap())?,
+            read_modules(args.new.as_ref().unwrap())?,
+        )
+    };
+
+    let mut incompatible = 0;
+    for (id, old_module) in &old {
+        let name = format!("{}::{}", id.address(), id.name());
+        match new.get(id) {
+            Some(new_module) => {
+                let incompatibilities = check_compatibility(old_module, new_module);
+                if incompatibilities.is_empty() {
+                    println!("{}: compatible", name);
+                } else {
+                    incompatible += 1;
+                    println!("{}: incompatible", name);
+                    for incompatibility in incompatibilities {
+                        println!("    {}", incompatibility);
+                    }
+                }
+            }
+            None => println!("{}: not updated", name),
+        }
+    }
+    for id in new.keys().filter(|id| !old.contains_key(id)) {
+        println!("{}::{}: new", id.address(), id.name());
+    }
+
+    if incompatible > 0 {
+        bail!("{} module(s) cannot be upgraded", incompatible);
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::{bail, Result};
+use bytecode_source_map::utils::{remap_owned_loc_to_loc, source_map_from_file, OwnedLoc};
+use move_coverage::{coverage_map::CoverageMap, report::CoverageReport};
+use move_lang::{compiled_unit::CompiledUnit, errors, move_compile_no_report, shared::Address};
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use structopt::StructOpt;
+use vm::file_format::CompiledModule;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move Coverage Report",
+    about = "Report the line, function and branch coverage of Move modules as lcov or Cobertura XML"
+)]
+struct Args {
+    /// The path to the coverage map or trace file
+    #[structopt(long = "input-trace-path", short = "t")]
+    pub input_trace_path: String,
+    /// Whether the passed-in file is a raw trace file or a serialized coverage map
+    #[structopt(long = "is-raw-trace", short = "r")]
+    pub is_raw_trace_file: bool,
+    /// The paths to module binaries, each with its source map next to it
+    #[structopt(long = "module-path", short = "b")]
+    pub module_binary_paths: Vec<String>,
+    /// Move source files whose modules are compiled to report on, as the standard library
+    /// modules the trace was collected with
+    #[structopt(long = "source-path", short = "s")]
+    pub source_file_paths: Vec<String>,
+    /// Report on the standard library, compiled from its sources
+    #[structopt(long = "stdlib")]
+    pub stdlib: bool,
+    /// The format of the report, `lcov` or `cobertura`
+    #[structopt(long = "format", short = "f", default_value = "lcov")]
+    pub format: String,
+    /// Optional path to save the report. Printed to stdout if not present.
+    #[structopt(long = "output-path", short = "o")]
+    pub output_path: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    if args.format != "lcov" && args.format != "cobertura" {
+        bail!("Unknown report format '{}'", args.format)
+    }
+    let coverage_map = if args.is_raw_trace_file {
+        CoverageMap::from_trace_file(&args.input_trace_path)
+    } else {
+        CoverageMap::from_binary_file(&args.input_trace_path)
+    };
+
+    let mut report = CoverageReport::new();
+    for path in &args.module_binary_paths {
+        let module = CompiledModule::deserialize(&fs::read(path)?)?;
+        let source_map = source_map_from_file::<OwnedLoc>(&Path::new(path).with_extension("mvsm"))
+            .map(remap_owned_loc_to_loc)?;
+        report.add_module(&module, &source_map, &coverage_map)?;
+    }
+
+    let mut source_files = args.source_file_paths;
+    if args.stdlib {
+        source_files.extend(stdlib::stdlib_files());
+    }
+    if !source_files.is_empty() {
+        let (files, units) = move_compile_no_report(&source_files, &[], Some(Address::LIBRA_CORE))?;
+        let units = match units {
+            Ok(units) => units,
+            Err(errors) => errors::report_errors(files, errors),
+        };
+        for unit in units {
+            if let CompiledUnit::Module {
+                module, source_map, ..
+            } = unit
+            {
+                report.add_module(&module, &source_map, &coverage_map)?;
+            }
+        }
+    }
+
+    let mut writer: Box<dyn Write> = match &args.output_path {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    };
+    if args.format == "lcov" {
+        report.write_lcov(&mut writer)?;
+    } else {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        report.write_cobertura(&mut writer, timestamp)?;
+    }
+    Ok(())
+}
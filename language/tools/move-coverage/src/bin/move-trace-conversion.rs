@@ -3,7 +3,7 @@
 
 #![forbid(unsafe_code)]
 
-use move_coverage::coverage_map::{output_map_to_file, CoverageMap};
+use move_coverage::coverage_map::CoverageMap;
 use std::path::Path;
 use structopt::StructOpt;
 
@@ -36,6 +36,7 @@ fn main() {
         CoverageMap::from_trace_file(&input_path)
     };
 
-    output_map_to_file(&output_path, &coverage_map)
+    coverage_map
+        .to_binary_file(&output_path)
         .expect("Unable to serialize coverage map to output file")
 }
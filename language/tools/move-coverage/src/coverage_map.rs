@@ -14,8 +14,37 @@ use std::{
     path::Path,
 };
 
+/// The number of times each instruction of a function was executed, by code offset.
 pub type FunctionCoverage = BTreeMap<u64, u64>;
 
+/// The outcomes of the conditional branches of a function, by code offset.
+pub type BranchCoverage = BTreeMap<u64, BranchCounts>;
+
+/// Marks the trace lines of the conditions found by `BrTrue` and `BrFalse`, as in
+/// `0x1::M::f,3,branch:true`. Other lines name the instruction executed instead.
+const BRANCH_PREFIX: &str = "branch:";
+
+/// Starts the binary files of coverage maps that record branch coverage, followed by the version
+/// of their format. Files written before branch coverage have no header.
+const BINARY_MAGIC: &[u8] = b"MVCOV";
+
+/// The version of the format of binary files written by `CoverageMap::to_binary_file`.
+const BINARY_VERSION: u8 = 1;
+
+/// The number of times the condition of a `BrTrue` or `BrFalse` was found true and false.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchCounts {
+    pub true_count: u64,
+    pub false_count: u64,
+}
+
+impl BranchCounts {
+    /// The number of times the branch was executed.
+    pub fn total(&self) -> u64 {
+        self.true_count + self.false_count
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CoverageMap {
     pub module_maps: BTreeMap<(AccountAddress, Identifier), ModuleCoverageMap>,
@@ -26,6 +55,7 @@ pub struct ModuleCoverageMap {
     pub module_addr: AccountAddress,
     pub module_name: Identifier,
     pub function_maps: BTreeMap<Identifier, FunctionCoverage>,
+    pub branch_maps: BTreeMap<Identifier, BranchCoverage>,
 }
 
 impl CoverageMap {
@@ -34,9 +64,10 @@ impl CoverageMap {
         let file = File::open(filename).unwrap();
         for line in BufReader::new(file).lines() {
             let line = line.unwrap();
-            let mut splits = line.split(',');
+            let mut splits = line.splitn(3, ',');
             let context = splits.next().unwrap();
             let pc = splits.next().unwrap().parse::<u64>().unwrap();
+            let rest = splits.next().unwrap_or("");
 
             let mut context_segs: Vec<_> = context.split("::").collect();
             let is_script = context_segs.len() == 2;
@@ -49,7 +80,12 @@ impl CoverageMap {
                     .module_maps
                     .entry((addr, module_name.clone()))
                     .or_insert_with(|| ModuleCoverageMap::new(addr, module_name));
-                entry.insert(func_name, pc);
+                if rest.starts_with(BRANCH_PREFIX) {
+                    let cond = rest[BRANCH_PREFIX.len()..].parse::<bool>().unwrap();
+                    entry.insert_branch(func_name, pc, cond);
+                } else {
+                    entry.insert(func_name, pc);
+                }
             }
         }
         self
@@ -63,7 +99,8 @@ impl CoverageMap {
         empty_module_map.update_coverage_from_trace_file(filename)
     }
 
-    /// Takes in a file containing a serialized coverage map and returns a coverage map.
+    /// Takes in a file containing a serialized coverage map and returns a coverage map. Files
+    /// written before branch coverage was recorded are read with empty branch coverage.
     pub fn from_binary_file<P: AsRef<Path>>(filename: P) -> Self {
         let mut bytes = Vec::new();
         File::open(filename)
@@ -71,10 +108,60 @@ impl CoverageMap {
             .and_then(|mut file| file.read_to_end(&mut bytes).ok())
             .ok_or_else(|| format_err!("Error while reading in coverage map binary"))
             .unwrap();
-        lcs::from_bytes(&bytes)
-            .map_err(|_| format_err!("Error deserializing into coverage map"))
+        Self::from_binary(&bytes)
+            .map_err(|err| format_err!("Error deserializing into coverage map: {}", err))
             .unwrap()
     }
+
+    fn from_binary(bytes: &[u8]) -> Result<Self> {
+        if !bytes.starts_with(BINARY_MAGIC) {
+            let legacy: LegacyCoverageMap = lcs::from_bytes(bytes)?;
+            return Ok(legacy.into());
+        }
+        match bytes.get(BINARY_MAGIC.len()) {
+            Some(&BINARY_VERSION) => Ok(lcs::from_bytes(&bytes[BINARY_MAGIC.len() + 1..])?),
+            Some(version) => Err(format_err!("Unsupported coverage map version {}", version)),
+            None => Err(format_err!("Missing coverage map version")),
+        }
+    }
+
+    /// Writes the coverage map to a file that `from_binary_file` reads.
+    pub fn to_binary_file<P: AsRef<Path>>(&self, filename: P) -> Result<()> {
+        let mut file = File::create(filename)?;
+        file.write_all(BINARY_MAGIC)?;
+        file.write_all(&[BINARY_VERSION])?;
+        file.write_all(&lcs::to_bytes(self)?)?;
+        Ok(())
+    }
+}
+
+// The layout of the coverage maps written before branch coverage was recorded.
+#[derive(Deserialize)]
+struct LegacyCoverageMap {
+    module_maps: BTreeMap<(AccountAddress, Identifier), LegacyModuleCoverageMap>,
+}
+
+#[derive(Deserialize)]
+struct LegacyModuleCoverageMap {
+    module_addr: AccountAddress,
+    module_name: Identifier,
+    function_maps: BTreeMap<Identifier, FunctionCoverage>,
+}
+
+impl From<LegacyCoverageMap> for CoverageMap {
+    fn from(legacy: LegacyCoverageMap) -> Self {
+        let module_maps = legacy
+            .module_maps
+            .into_iter()
+            .map(|(key, module_map)| {
+                let mut new_map =
+                    ModuleCoverageMap::new(module_map.module_addr, module_map.module_name);
+                new_map.function_maps = module_map.function_maps;
+                (key, new_map)
+            })
+            .collect();
+        CoverageMap { module_maps }
+    }
 }
 
 impl ModuleCoverageMap {
@@ -83,6 +170,7 @@ impl ModuleCoverageMap {
             module_addr,
             module_name,
             function_maps: BTreeMap::new(),
+            branch_maps: BTreeMap::new(),
         }
     }
 
@@ -95,9 +183,27 @@ impl ModuleCoverageMap {
         *pc_entry += 1;
     }
 
+    pub fn insert_branch(&mut self, func_name: Identifier, pc: u64, cond: bool) {
+        let counts = self
+            .branch_maps
+            .entry(func_name)
+            .or_insert_with(BranchCoverage::new)
+            .entry(pc)
+            .or_insert_with(BranchCounts::default);
+        if cond {
+            counts.true_count += 1;
+        } else {
+            counts.false_count += 1;
+        }
+    }
+
     pub fn get_function_coverage(&self, func_name: &IdentStr) -> Option<&FunctionCoverage> {
         self.function_maps.get(func_name)
     }
+
+    pub fn get_branch_coverage(&self, func_name: &IdentStr) -> Option<&BranchCoverage> {
+        self.branch_maps.get(func_name)
+    }
 }
 
 pub fn output_map_to_file<M: Serialize, P: AsRef<Path>>(file_name: P, data: &M) -> Result<()> {
@@ -0,0 +1,414 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! Line, function and branch coverage of Move source files, as lcov tracefiles or Cobertura XML.
+
+use crate::coverage_map::{BranchCounts, CoverageMap};
+use anyhow::{bail, Result};
+use bytecode_source_map::source_map::SourceMap;
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use move_ir_types::location::Loc;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+};
+use vm::{
+    access::ModuleAccess,
+    file_format::{Bytecode, CodeOffset, FunctionDefinitionIndex, StructDefinitionIndex},
+    CompiledModule,
+};
+
+/// The coverage of the source of some modules. Lines are one based.
+#[derive(Debug, Default)]
+pub struct CoverageReport {
+    pub modules: Vec<ModuleReport>,
+    /// The offsets at which the lines start, by source file.
+    line_starts: BTreeMap<String, Vec<usize>>,
+}
+
+#[derive(Debug)]
+pub struct ModuleReport {
+    pub module: ModuleId,
+    pub source_path: String,
+    /// The functions with code, in definition order.
+    pub functions: Vec<FunctionReport>,
+}
+
+#[derive(Debug)]
+pub struct FunctionReport {
+    pub name: Identifier,
+    /// The line the function is declared on.
+    pub line: usize,
+    /// The number of times the first instruction was executed.
+    pub hits: u64,
+    /// The number of times the line was executed, by line, as the most any instruction starting on
+    /// it was.
+    pub lines: BTreeMap<usize, u64>,
+    pub branches: Vec<BranchReport>,
+}
+
+/// A `BrTrue` or `BrFalse`, which either way goes is a branch of the source.
+#[derive(Debug)]
+pub struct BranchReport {
+    pub line: usize,
+    pub code_offset: CodeOffset,
+    pub counts: BranchCounts,
+}
+
+impl CoverageReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the coverage of `module`, whose code is mapped to its source by `source_map`.
+    pub fn add_module(
+        &mut self,
+        module: &CompiledModule,
+        source_map: &SourceMap<Loc>,
+        coverage_map: &CoverageMap,
+    ) -> Result<()> {
+        let module_id = module.self_id();
+        let module_map = coverage_map
+            .module_maps
+            .get(&(*module_id.address(), module_id.name().to_owned()));
+        let source_path = source_file(module, source_map)?;
+        let mut functions = vec![];
+        for (idx, function_def) in module.function_defs().iter().enumerate() {
+            let code = match &function_def.code {
+                Some(code_unit) => &code_unit.code,
+                None => continue,
+            };
+            let fn_handle = module.function_handle_at(function_def.function);
+            let name = module.identifier_at(fn_handle.name).to_owned();
+            let function_def_idx = FunctionDefinitionIndex(idx as u16);
+            let function_map = source_map.get_function_source_map(function_def_idx)?;
+            let counts = module_map.and_then(|map| map.get_function_coverage(&name));
+            let branch_counts = module_map.and_then(|map| map.get_branch_coverage(&name));
+
+            let mut lines = BTreeMap::new();
+            let mut branches = vec![];
+            for (code_offset, instr) in code.iter().enumerate() {
+                let code_offset = code_offset as CodeOffset;
+                let loc = source_map.get_code_location(function_def_idx, code_offset)?;
+                let line = self.line(&source_path, loc.span().start().to_usize())?;
+                let hits = counts
+                    .and_then(|counts| counts.get(&u64::from(code_offset)))
+                    .copied()
+                    .unwrap_or(0);
+                let line_hits = lines.entry(line).or_insert(0);
+                *line_hits = hits.max(*line_hits);
+                if let Bytecode::BrTrue(_) | Bytecode::BrFalse(_) = instr {
+                    let counts = branch_counts
+                        .and_then(|counts| counts.get(&u64::from(code_offset)))
+                        .copied()
+                        .unwrap_or_default();
+                    branches.push(BranchReport {
+                        line,
+                        code_offset,
+                        counts,
+                    })
+                }
+            }
+            functions.push(FunctionReport {
+                line: self.line(
+                    &source_path,
+                    function_map.decl_location.span().start().to_usize(),
+                )?,
+                hits: counts
+                    .and_then(|counts| counts.get(&0))
+                    .copied()
+                    .unwrap_or(0),
+                name,
+                lines,
+                branches,
+            })
+        }
+        self.modules.push(ModuleReport {
+            module: module_id,
+            source_path,
+            functions,
+        });
+        Ok(())
+    }
+
+    /// The line of the byte `offset` of the file at `path`.
+    fn line(&mut self, path: &str, offset: usize) -> Result<usize> {
+        if !self.line_starts.contains_key(path) {
+            let text = fs::read_to_string(path)?;
+            let starts = std::iter::once(0)
+                .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+                .collect();
+            self.line_starts.insert(path.to_string(), starts);
+        }
+        let starts = &self.line_starts[path];
+        Ok(match starts.binary_search(&offset) {
+            Ok(idx) => idx + 1,
+            Err(idx) => idx,
+        })
+    }
+
+    /// The modules by source file.
+    fn files(&self) -> BTreeMap<&str, Vec<&ModuleReport>> {
+        let mut files = BTreeMap::new();
+        for module in &self.modules {
+            files
+                .entry(module.source_path.as_str())
+                .or_insert_with(Vec::new)
+                .push(module)
+        }
+        files
+    }
+
+    /// Writes the report as an lcov tracefile, with one record per source file.
+    pub fn write_lcov<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for (path, modules) in self.files() {
+            writeln!(w, "TN:")?;
+            writeln!(w, "SF:{}", path)?;
+            let functions = modules.iter().flat_map(|module| {
+                module
+                    .functions
+                    .iter()
+                    .map(move |function| (module.module.name(), function))
+            });
+            for (module_name, function) in functions.clone() {
+                writeln!(w, "FN:{},{}::{}", function.line, module_name, function.name)?;
+            }
+            for (module_name, function) in functions.clone() {
+                writeln!(
+                    w,
+                    "FNDA:{},{}::{}",
+                    function.hits, module_name, function.name
+                )?;
+            }
+            writeln!(w, "FNF:{}", functions.clone().count())?;
+            writeln!(
+                w,
+                "FNH:{}",
+                functions.clone().filter(|(_, f)| f.hits > 0).count()
+            )?;
+
+            let mut branches_found = 0;
+            let mut branches_hit = 0;
+            for (block, branch) in functions
+                .clone()
+                .flat_map(|(_, function)| &function.branches)
+                .enumerate()
+            {
+                let counts = branch.counts;
+                for (idx, count) in [counts.true_count, counts.false_count].iter().enumerate() {
+                    if counts.total() == 0 {
+                        writeln!(w, "BRDA:{},{},{},-", branch.line, block, idx)?;
+                    } else {
+                        writeln!(w, "BRDA:{},{},{},{}", branch.line, block, idx, count)?;
+                    }
+                    branches_found += 1;
+                    if *count > 0 {
+                        branches_hit += 1;
+                    }
+                }
+            }
+            writeln!(w, "BRF:{}", branches_found)?;
+            writeln!(w, "BRH:{}", branches_hit)?;
+
+            let lines = merge_lines(functions.map(|(_, function)| function));
+            for (line, hits) in &lines {
+                writeln!(w, "DA:{},{}", line, hits)?;
+            }
+            writeln!(w, "LF:{}", lines.len())?;
+            writeln!(w, "LH:{}", lines.values().filter(|hits| **hits > 0).count())?;
+            writeln!(w, "end_of_record")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the report as Cobertura XML, with a package per address and a class per module.
+    /// `timestamp` is in milliseconds since the Unix epoch.
+    pub fn write_cobertura<W: Write>(&self, w: &mut W, timestamp: u128) -> io::Result<()> {
+        let mut packages = BTreeMap::new();
+        for module in &self.modules {
+            packages
+                .entry(module.module.address().to_string())
+                .or_insert_with(Vec::new)
+                .push(module)
+        }
+        let all = Counts::of(self.modules.iter().flat_map(|m| &m.functions));
+
+        writeln!(w, r#"<?xml version="1.0" ?>"#)?;
+        writeln!(
+            w,
+            r#"<!DOCTYPE coverage SYSTEM "http://cobertura.sourceforge.net/xml/coverage-04.dtd">"#
+        )?;
+        writeln!(
+            w,
+            r#"<coverage line-rate="{}" branch-rate="{}" lines-covered="{}" lines-valid="{}" branches-covered="{}" branches-valid="{}" complexity="0" version="0.1" timestamp="{}">"#,
+            all.line_rate(),
+            all.branch_rate(),
+            all.lines_covered,
+            all.lines_valid,
+            all.branches_covered,
+            all.branches_valid,
+            timestamp
+        )?;
+        writeln!(w, "  <sources>")?;
+        writeln!(w, "    <source>.</source>")?;
+        writeln!(w, "  </sources>")?;
+        writeln!(w, "  <packages>")?;
+        for (package, modules) in packages {
+            let counts = Counts::of(modules.iter().flat_map(|m| &m.functions));
+            writeln!(
+                w,
+                r#"    <package name="{}" line-rate="{}" branch-rate="{}" complexity="0">"#,
+                package,
+                counts.line_rate(),
+                counts.branch_rate()
+            )?;
+            writeln!(w, "      <classes>")?;
+            for module in modules {
+                let counts = Counts::of(&module.functions);
+                writeln!(
+                    w,
+                    r#"        <class name="{}" filename="{}" line-rate="{}" branch-rate="{}" complexity="0">"#,
+                    module.module.name(),
+                    escape(&module.source_path),
+                    counts.line_rate(),
+                    counts.branch_rate()
+                )?;
+                writeln!(w, "          <methods>")?;
+                for function in &module.functions {
+                    let counts = Counts::of(std::iter::once(function));
+                    writeln!(
+                        w,
+                        r#"            <method name="{}" signature="" line-rate="{}" branch-rate="{}" complexity="0">"#,
+                        function.name,
+                        counts.line_rate(),
+                        counts.branch_rate()
+                    )?;
+                    write_cobertura_lines(w, "              ", std::iter::once(function))?;
+                    writeln!(w, "            </method>")?;
+                }
+                writeln!(w, "          </methods>")?;
+                write_cobertura_lines(w, "          ", &module.functions)?;
+                writeln!(w, "        </class>")?;
+            }
+            writeln!(w, "      </classes>")?;
+            writeln!(w, "    </package>")?;
+        }
+        writeln!(w, "  </packages>")?;
+        writeln!(w, "</coverage>")
+    }
+}
+
+/// The source file of `module`, as the file of the declaration of its first function or struct.
+fn source_file(module: &CompiledModule, source_map: &SourceMap<Loc>) -> Result<String> {
+    let loc = if !module.function_defs().is_empty() {
+        source_map
+            .get_function_source_map(FunctionDefinitionIndex(0))?
+            .decl_location
+    } else if !module.struct_defs().is_empty() {
+        source_map
+            .get_struct_source_map(StructDefinitionIndex(0))?
+            .decl_location
+    } else {
+        bail!("No locations in the source map of {}", module.self_id())
+    };
+    Ok(loc.file().to_string())
+}
+
+/// The hits of the lines of `functions`, by line.
+fn merge_lines<'a>(functions: impl Iterator<Item = &'a FunctionReport>) -> BTreeMap<usize, u64> {
+    let mut lines = BTreeMap::new();
+    for function in functions {
+        for (line, hits) in &function.lines {
+            let line_hits = lines.entry(*line).or_insert(0);
+            *line_hits = (*hits).max(*line_hits);
+        }
+    }
+    lines
+}
+
+fn write_cobertura_lines<'a, W: Write>(
+    w: &mut W,
+    indent: &str,
+    functions: impl IntoIterator<Item = &'a FunctionReport>,
+) -> io::Result<()> {
+    let functions = functions.into_iter().collect::<Vec<_>>();
+    // The outcomes of the branches of each line, and how many were taken.
+    let mut conditions: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+    for branch in functions.iter().flat_map(|f| &f.branches) {
+        let (valid, covered) = conditions.entry(branch.line).or_insert((0, 0));
+        *valid += 2;
+        *covered += (branch.counts.true_count > 0) as usize;
+        *covered += (branch.counts.false_count > 0) as usize;
+    }
+    writeln!(w, "{}<lines>", indent)?;
+    for (line, hits) in merge_lines(functions.into_iter()) {
+        match conditions.get(&line) {
+            None => writeln!(
+                w,
+                r#"{}  <line number="{}" hits="{}" branch="false"/>"#,
+                indent, line, hits
+            )?,
+            Some((valid, covered)) => writeln!(
+                w,
+                r#"{}  <line number="{}" hits="{}" branch="true" condition-coverage="{}% ({}/{})"/>"#,
+                indent,
+                line,
+                hits,
+                covered * 100 / valid,
+                covered,
+                valid
+            )?,
+        }
+    }
+    writeln!(w, "{}</lines>", indent)
+}
+
+#[derive(Default)]
+struct Counts {
+    lines_valid: usize,
+    lines_covered: usize,
+    branches_valid: usize,
+    branches_covered: usize,
+}
+
+impl Counts {
+    fn of<'a>(functions: impl IntoIterator<Item = &'a FunctionReport>) -> Self {
+        let mut counts = Counts::default();
+        for function in functions {
+            counts.lines_valid += function.lines.len();
+            counts.lines_covered += function.lines.values().filter(|hits| **hits > 0).count();
+            for branch in &function.branches {
+                counts.branches_valid += 2;
+                counts.branches_covered += (branch.counts.true_count > 0) as usize;
+                counts.branches_covered += (branch.counts.false_count > 0) as usize;
+            }
+        }
+        counts
+    }
+
+    fn line_rate(&self) -> f64 {
+        rate(self.lines_covered, self.lines_valid)
+    }
+
+    fn branch_rate(&self) -> f64 {
+        rate(self.branches_covered, self.branches_valid)
+    }
+}
+
+fn rate(covered: usize, valid: usize) -> f64 {
+    if valid == 0 {
+        1.0
+    } else {
+        covered as f64 / valid as f64
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,86 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_temppath::TempPath;
+use libra_types::account_address::AccountAddress;
+use move_core_types::identifier::{IdentStr, Identifier};
+use move_coverage::coverage_map::{BranchCounts, CoverageMap, FunctionCoverage};
+use serde::Serialize;
+use std::{collections::BTreeMap, fs};
+
+// The layout of the coverage maps written before branch coverage was recorded.
+#[derive(Serialize)]
+struct LegacyCoverageMap {
+    module_maps: BTreeMap<(AccountAddress, Identifier), LegacyModuleCoverageMap>,
+}
+
+#[derive(Serialize)]
+struct LegacyModuleCoverageMap {
+    module_addr: AccountAddress,
+    module_name: Identifier,
+    function_maps: BTreeMap<Identifier, FunctionCoverage>,
+}
+
+fn module_key() -> (AccountAddress, Identifier) {
+    (
+        AccountAddress::from_hex_literal("0x1").unwrap(),
+        Identifier::new("M").unwrap(),
+    )
+}
+
+#[test]
+fn reads_maps_without_branch_coverage() {
+    let (module_addr, module_name) = module_key();
+    let function_coverage: FunctionCoverage = vec![(0, 2), (1, 1)].into_iter().collect();
+    let mut function_maps = BTreeMap::new();
+    function_maps.insert(Identifier::new("f").unwrap(), function_coverage.clone());
+    let mut module_maps = BTreeMap::new();
+    module_maps.insert(
+        module_key(),
+        LegacyModuleCoverageMap {
+            module_addr,
+            module_name,
+            function_maps,
+        },
+    );
+    let file = TempPath::new();
+    fs::write(
+        file.path(),
+        lcs::to_bytes(&LegacyCoverageMap { module_maps }).unwrap(),
+    )
+    .unwrap();
+
+    let coverage_map = CoverageMap::from_binary_file(file.path());
+    let module_map = &coverage_map.module_maps[&module_key()];
+    let f = IdentStr::new("f").unwrap();
+    assert_eq!(
+        module_map.get_function_coverage(f),
+        Some(&function_coverage)
+    );
+    assert_eq!(module_map.get_branch_coverage(f), None);
+}
+
+#[test]
+fn binary_round_trip() {
+    let trace = TempPath::new();
+    fs::write(
+        trace.path(),
+        "0x1::M::f,0,LdTrue\n0x1::M::f,1,BrTrue(3)\n0x1::M::f,1,branch:true\n",
+    )
+    .unwrap();
+    let coverage_map = CoverageMap::from_trace_file(trace.path());
+    let file = TempPath::new();
+    coverage_map.to_binary_file(file.path()).unwrap();
+
+    let read = CoverageMap::from_binary_file(file.path());
+    let module_map = &read.module_maps[&module_key()];
+    let f = IdentStr::new("f").unwrap();
+    assert_eq!(module_map.get_function_coverage(f).unwrap().len(), 2);
+    assert_eq!(
+        module_map.get_branch_coverage(f).unwrap()[&1],
+        BranchCounts {
+            true_count: 1,
+            false_count: 0,
+        }
+    );
+}
@@ -0,0 +1,130 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_temppath::TempPath;
+use move_coverage::{coverage_map::CoverageMap, report::CoverageReport};
+use move_lang::{compiled_unit::CompiledUnit, move_compile_no_report, shared::Address};
+use std::fs;
+use vm::{access::ModuleAccess, file_format::Bytecode};
+
+const MODULE: &str = r#"address 0x1 {
+module M {
+    public fun max(a: u64, b: u64): u64 {
+        if (a > b) a else b
+    }
+
+    public fun zero(): u64 {
+        0
+    }
+}
+}
+"#;
+
+/// Compiles `MODULE` and reports on a trace of one call to `max`, where `a > b`.
+fn report() -> (TempPath, CoverageReport) {
+    let source = TempPath::new();
+    fs::write(source.path(), MODULE).unwrap();
+    let targets = vec![source.path().to_str().unwrap().to_string()];
+    let (_, units) = move_compile_no_report(&targets, &[], Some(Address::LIBRA_CORE)).unwrap();
+    let (module, source_map) = match units.unwrap().pop().unwrap() {
+        CompiledUnit::Module {
+            module, source_map, ..
+        } => (module, source_map),
+        CompiledUnit::Script { .. } => panic!("Expected a module"),
+    };
+
+    let code = &module.function_defs()[0].code.as_ref().unwrap().code;
+    let mut trace = String::new();
+    for (pc, instr) in code.iter().enumerate() {
+        trace.push_str(&format!("0x1::M::max,{},{:?}\n", pc, instr));
+        match instr {
+            Bytecode::BrTrue(_) | Bytecode::BrFalse(_) => {
+                trace.push_str(&format!("0x1::M::max,{},branch:true\n", pc))
+            }
+            _ => (),
+        }
+    }
+    let trace_file = TempPath::new();
+    fs::write(trace_file.path(), trace).unwrap();
+    let coverage_map = CoverageMap::from_trace_file(trace_file.path());
+
+    let mut report = CoverageReport::new();
+    report
+        .add_module(&module, &source_map, &coverage_map)
+        .unwrap();
+    (source, report)
+}
+
+#[test]
+fn function_and_branch_coverage() {
+    let (_source, report) = report();
+    let functions = &report.modules[0].functions;
+    assert_eq!(
+        functions
+            .iter()
+            .map(|f| (f.name.as_str(), f.line, f.hits))
+            .collect::<Vec<_>>(),
+        vec![("max", 3, 1), ("zero", 7, 0)]
+    );
+    assert_eq!(functions[0].branches.len(), 1);
+    let branch = &functions[0].branches[0];
+    assert_eq!(branch.line, 4);
+    assert_eq!(branch.counts.total(), 1);
+    assert!(functions[1].branches.is_empty());
+    assert_eq!(functions[1].lines.get(&8), Some(&0));
+}
+
+#[test]
+fn lcov_report() {
+    let (source, report) = report();
+    let mut out = vec![];
+    report.write_lcov(&mut out).unwrap();
+    let lcov = String::from_utf8(out).unwrap();
+    let lines = lcov.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "TN:");
+    assert_eq!(lines[1], format!("SF:{}", source.path().to_str().unwrap()));
+    for line in &[
+        "FN:3,M::max",
+        "FN:7,M::zero",
+        "FNDA:1,M::max",
+        "FNDA:0,M::zero",
+        "FNF:2",
+        "FNH:1",
+        "BRF:2",
+        "BRH:1",
+        "DA:4,1",
+        "DA:8,0",
+    ] {
+        assert!(lines.contains(line), "{} not in\n{}", line, lcov);
+    }
+    let taken = lines
+        .iter()
+        .filter(|line| line.starts_with("BRDA:4,0,"))
+        .map(|line| line.rsplit(',').next().unwrap())
+        .collect::<Vec<_>>();
+    assert!(
+        taken == vec!["1", "0"] || taken == vec!["0", "1"],
+        "{}",
+        lcov
+    );
+    assert_eq!(lines.last(), Some(&"end_of_record"));
+}
+
+#[test]
+fn cobertura_report() {
+    let (source, report) = report();
+    let mut out = vec![];
+    report.write_cobertura(&mut out, 0).unwrap();
+    let xml = String::from_utf8(out).unwrap();
+    assert!(xml.contains(&format!(
+        r#"<class name="M" filename="{}""#,
+        source.path().to_str().unwrap()
+    )));
+    assert!(xml.contains(r#"<method name="max""#));
+    assert!(
+        xml.contains(r#"<line number="4" hits="1" branch="true" condition-coverage="50% (1/2)"/>"#)
+    );
+    assert!(xml.contains(r#"<line number="8" hits="0" branch="false"/>"#));
+    assert!(xml.contains(r#"branches-covered="1" branches-valid="2""#));
+    assert!(xml.trim_end().ends_with("</coverage>"));
+}
@@ -0,0 +1,487 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+#![cfg(feature = "debugger")]
+
+//! An interactive debugger for Move scripts.
+//!
+//! The interpreter hooks the debugger relies on are only compiled with the `debugger` feature,
+//! e.g., `cargo run --features debugger -- <script>` from this directory.
+//!
+//! The script is compiled along with the modules it depends on outside of the standard library.
+//! Those modules are published on top of genesis in a `FakeExecutor` and the script is then run
+//! with a `DebugSession` installed in the interpreter. The session reads commands from its input
+//! whenever execution pauses, either on a breakpoint or after a step.
+
+use anyhow::{bail, format_err, Result};
+use language_e2e_tests::executor::FakeExecutor;
+use libra_types::{account_address::AccountAddress, transaction::TransactionArgument};
+use libra_vm::{convert_txn_args, data_cache::RemoteStorage};
+use move_core_types::{gas_schedule::GasUnits, identifier::Identifier, language_storage::ModuleId};
+use move_ir_types::location::Loc;
+use move_lang::{
+    compiled_unit::CompiledUnit, errors::FilesSourceText, move_compile, shared::Address,
+};
+use move_vm_runtime::{
+    data_cache::TransactionDataCache,
+    debugger::{self, DebugLocation, DebugState, Debugger},
+    move_vm::MoveVM,
+};
+use move_vm_types::gas_schedule::{zero_cost_schedule, CostStrategy};
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Write},
+    path::Path,
+    str::FromStr,
+};
+use vm::{
+    access::{ModuleAccess, ScriptAccess},
+    errors::VMResult,
+    file_format::{CodeOffset, FunctionDefinitionIndex},
+};
+
+/// An instruction in a function, the module is None for the function of the script.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct CodeLocation {
+    pub module: Option<ModuleId>,
+    pub function: String,
+    pub pc: CodeOffset,
+}
+
+impl CodeLocation {
+    fn matches(&self, location: &DebugLocation) -> bool {
+        self.pc == location.pc
+            && self.function == location.function
+            && self.module.as_ref() == location.module
+    }
+}
+
+/// A breakpoint as given by the user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// `<address>::<module>::<function>@<pc>`, or `<function>@<pc>` for the script. The pc
+    /// defaults to 0.
+    Code(CodeLocation),
+    /// `<file>:<line>`, the file only needs to be a suffix of the path of the source file.
+    Line { file: String, line: usize },
+}
+
+impl FromStr for Breakpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            format_err!(
+                "Invalid breakpoint {}, expected <address>::<module>::<function>@<pc> or \
+                 <file>:<line>",
+                s
+            )
+        };
+
+        let (name, pc) = match s.rfind('@') {
+            Some(idx) => (
+                &s[..idx],
+                Some(s[idx + 1..].parse().map_err(|_| invalid())?),
+            ),
+            None => (s, None),
+        };
+        if pc.is_some() || name.contains("::") {
+            let parts: Vec<_> = name.split("::").collect();
+            let (module, function) = match parts.as_slice() {
+                [function] => (None, *function),
+                [address, module, function] => {
+                    let address =
+                        AccountAddress::from_hex_literal(address).map_err(|_| invalid())?;
+                    let module = Identifier::new(*module).map_err(|_| invalid())?;
+                    (Some(ModuleId::new(address, module)), *function)
+                }
+                _ => return Err(invalid()),
+            };
+            if function.is_empty() {
+                return Err(invalid());
+            }
+            return Ok(Breakpoint::Code(CodeLocation {
+                module,
+                function: function.to_string(),
+                pc: pc.unwrap_or(0),
+            }));
+        }
+
+        let idx = s.rfind(':').ok_or_else(invalid)?;
+        let line = s[idx + 1..].parse().map_err(|_| invalid())?;
+        Ok(Breakpoint::Line {
+            file: s[..idx].to_string(),
+            line,
+        })
+    }
+}
+
+/// Maps the instructions of the compiled units to their location in the source files.
+pub struct SourceIndex {
+    files: FilesSourceText,
+    functions: BTreeMap<(Option<ModuleId>, String), Vec<Option<Loc>>>,
+}
+
+impl SourceIndex {
+    pub fn new(files: FilesSourceText, units: &[CompiledUnit]) -> Self {
+        let mut functions = BTreeMap::new();
+        for unit in units {
+            match unit {
+                CompiledUnit::Module {
+                    module, source_map, ..
+                } => {
+                    let module_id = module.self_id();
+                    for (idx, function_def) in module.function_defs().iter().enumerate() {
+                        let code = match &function_def.code {
+                            Some(code) => &code.code,
+                            None => continue,
+                        };
+                        let handle = module.function_handle_at(function_def.function);
+                        let locations = (0..code.len())
+                            .map(|pc| {
+                                source_map
+                                    .get_code_location(
+                                        FunctionDefinitionIndex(idx as u16),
+                                        pc as CodeOffset,
+                                    )
+                                    .ok()
+                            })
+                            .collect();
+                        functions.insert(
+                            (
+                                Some(module_id.clone()),
+                                module.identifier_at(handle.name).to_string(),
+                            ),
+                            locations,
+                        );
+                    }
+                }
+                CompiledUnit::Script {
+                    script, source_map, ..
+                } => {
+                    let locations = (0..script.code().code.len())
+                        .map(|pc| {
+                            source_map
+                                .get_code_location(FunctionDefinitionIndex(0), pc as CodeOffset)
+                                .ok()
+                        })
+                        .collect();
+                    functions.insert((None, "main".to_string()), locations);
+                }
+            }
+        }
+        Self { files, functions }
+    }
+
+    /// Returns the source file and line of the instruction, if known.
+    pub fn source_line(
+        &self,
+        module: Option<&ModuleId>,
+        function: &str,
+        pc: CodeOffset,
+    ) -> Option<(&'static str, usize)> {
+        let loc = self
+            .functions
+            .get(&(module.cloned(), function.to_string()))?
+            .get(pc as usize)?
+            .as_ref()?;
+        Some((loc.file(), self.line_of(loc)?))
+    }
+
+    /// Resolves a breakpoint into the instructions it stands for. A line breakpoint stops on
+    /// the first instruction of the line in each function the line belongs to.
+    pub fn resolve(&self, breakpoint: &Breakpoint) -> Result<Vec<CodeLocation>> {
+        let (file, line) = match breakpoint {
+            Breakpoint::Code(location) => return Ok(vec![location.clone()]),
+            Breakpoint::Line { file, line } => (file, *line),
+        };
+
+        let mut locations = vec![];
+        for ((module, function), locs) in &self.functions {
+            let pc = locs.iter().position(|loc| match loc {
+                Some(loc) => {
+                    Path::new(loc.file()).ends_with(file) && self.line_of(loc) == Some(line)
+                }
+                None => false,
+            });
+            if let Some(pc) = pc {
+                locations.push(CodeLocation {
+                    module: module.clone(),
+                    function: function.clone(),
+                    pc: pc as CodeOffset,
+                });
+            }
+        }
+        if locations.is_empty() {
+            bail!("No code at {}:{}", file, line);
+        }
+        Ok(locations)
+    }
+
+    fn line_of(&self, loc: &Loc) -> Option<usize> {
+        let text = self.files.get(loc.file())?;
+        let start = loc.span().start().to_usize();
+        Some(text.get(..start)?.matches('\n').count() + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Mode {
+    /// Run until the next breakpoint.
+    Continue,
+    /// Pause on the next instruction.
+    Step,
+    /// Pause on the next instruction at the given depth or below, stepping over calls.
+    Next(usize),
+    /// Pause on the next instruction below the given depth, i.e., once the function returns.
+    Finish(usize),
+}
+
+const HELP: &str = "\
+Commands:
+    c, continue            run until the next breakpoint
+    s, step                execute one instruction, stepping into calls
+    n, next                execute one instruction, stepping over calls
+    f, finish              run until the current function returns
+    l, locals              print the locals of the current function
+    st, stack              print the operand stack
+    bt, backtrace          print the call stack
+    b, break <breakpoint>  add a breakpoint, <address>::<module>::<function>@<pc> or
+                           <file>:<line>
+    h, help                print this message";
+
+/// A `Debugger` driven by commands read line by line from its input.
+pub struct DebugSession<R, W> {
+    input: R,
+    output: W,
+    index: Option<SourceIndex>,
+    breakpoints: Vec<CodeLocation>,
+    mode: Mode,
+}
+
+impl<R: BufRead, W: Write> DebugSession<R, W> {
+    /// Creates a session that pauses on the first instruction.
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            index: None,
+            breakpoints: vec![],
+            mode: Mode::Step,
+        }
+    }
+
+    /// Uses the index to print source lines and resolve line breakpoints.
+    pub fn with_source_index(mut self, index: SourceIndex) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Starts running until the first breakpoint instead of pausing on the first instruction.
+    pub fn run_to_breakpoint(mut self) -> Self {
+        self.mode = Mode::Continue;
+        self
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: &Breakpoint) -> Result<()> {
+        let locations = match &self.index {
+            Some(index) => index.resolve(breakpoint)?,
+            None => match breakpoint {
+                Breakpoint::Code(location) => vec![location.clone()],
+                Breakpoint::Line { .. } => bail!("Line breakpoints require the source"),
+            },
+        };
+        self.breakpoints.extend(locations);
+        Ok(())
+    }
+
+    fn prompt(&mut self, location: &DebugLocation, state: &DebugState) -> io::Result<()> {
+        write!(
+            self.output,
+            "{} [pc = {}] {}",
+            state
+                .call_stack
+                .last()
+                .map_or("", |frame| frame.function.as_str()),
+            location.pc,
+            state.instruction
+        )?;
+        match self
+            .index
+            .as_ref()
+            .and_then(|index| index.source_line(location.module, location.function, location.pc))
+        {
+            Some((file, line)) => writeln!(self.output, " at {}:{}", file, line)?,
+            None => writeln!(self.output)?,
+        }
+
+        loop {
+            write!(self.output, "(move-debugger) ")?;
+            self.output.flush()?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                // Out of commands, let the execution run to completion.
+                self.breakpoints.clear();
+                self.mode = Mode::Continue;
+                return Ok(());
+            }
+
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some("c"), _) | (Some("continue"), _) => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                (Some("s"), _) | (Some("step"), _) => {
+                    self.mode = Mode::Step;
+                    return Ok(());
+                }
+                (Some("n"), _) | (Some("next"), _) => {
+                    self.mode = Mode::Next(location.depth);
+                    return Ok(());
+                }
+                (Some("f"), _) | (Some("finish"), _) => {
+                    self.mode = Mode::Finish(location.depth);
+                    return Ok(());
+                }
+                (Some("l"), _) | (Some("locals"), _) => {
+                    if let Some(frame) = state.call_stack.last() {
+                        for (idx, local) in frame.locals.iter().enumerate() {
+                            writeln!(self.output, "    [{}] {}", idx, local)?;
+                        }
+                    }
+                }
+                (Some("st"), _) | (Some("stack"), _) => {
+                    for (idx, value) in state.operand_stack.iter().enumerate() {
+                        writeln!(self.output, "    [{}] {}", idx, value)?;
+                    }
+                }
+                (Some("bt"), _) | (Some("backtrace"), _) => {
+                    for (idx, frame) in state.call_stack.iter().enumerate().rev() {
+                        writeln!(
+                            self.output,
+                            "    #{}: {} [pc = {}]",
+                            idx, frame.function, frame.pc
+                        )?;
+                    }
+                }
+                (Some("b"), Some(breakpoint)) | (Some("break"), Some(breakpoint)) => {
+                    match breakpoint
+                        .parse::<Breakpoint>()
+                        .and_then(|breakpoint| self.add_breakpoint(&breakpoint))
+                    {
+                        Ok(()) => writeln!(self.output, "Breakpoint added")?,
+                        Err(err) => writeln!(self.output, "{}", err)?,
+                    }
+                }
+                (Some("h"), _) | (Some("help"), _) => writeln!(self.output, "{}", HELP)?,
+                (None, _) => (),
+                (Some(command), _) => writeln!(
+                    self.output,
+                    "Unknown command {}, type help for a list of commands",
+                    command
+                )?,
+            }
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> Debugger for DebugSession<R, W> {
+    fn should_pause(&mut self, location: &DebugLocation) -> bool {
+        let stepped = match self.mode {
+            Mode::Continue => false,
+            Mode::Step => true,
+            Mode::Next(depth) => location.depth <= depth,
+            Mode::Finish(depth) => location.depth < depth,
+        };
+        stepped
+            || self
+                .breakpoints
+                .iter()
+                .any(|breakpoint| breakpoint.matches(location))
+    }
+
+    fn paused(&mut self, location: &DebugLocation, state: &DebugState) {
+        if self.prompt(location, state).is_err() {
+            // Without input or output the session cannot be driven any further.
+            self.breakpoints.clear();
+            self.mode = Mode::Continue;
+        }
+    }
+}
+
+/// The script to debug and the environment to run it in.
+pub struct DebugConfig {
+    pub script_file: String,
+    /// Modules the script depends on, beyond the standard library which is part of genesis.
+    pub module_files: Vec<String>,
+    pub sender: AccountAddress,
+    pub args: Vec<TransactionArgument>,
+    pub breakpoints: Vec<Breakpoint>,
+    /// Whether to pause on the first instruction rather than the first breakpoint.
+    pub stop_on_entry: bool,
+}
+
+/// Runs the script of the config under the control of a session reading commands from `input`
+/// and writing to `output`, and returns the result of the script.
+pub fn debug_script<R, W>(config: DebugConfig, input: R, output: W) -> Result<VMResult<()>>
+where
+    R: BufRead + 'static,
+    W: Write + 'static,
+{
+    let mut targets = vec![config.script_file.clone()];
+    targets.extend(config.module_files.iter().cloned());
+    let (files, units) = move_compile(
+        &targets,
+        &stdlib::stdlib_files(),
+        Some(Address::new(config.sender.into())),
+    )?;
+
+    let mut executor = FakeExecutor::from_genesis_file();
+    let mut script = None;
+    for unit in &units {
+        match unit {
+            CompiledUnit::Module { module, .. } => executor.add_module(&module.self_id(), module),
+            CompiledUnit::Script {
+                script: compiled, ..
+            } => {
+                if script.is_some() {
+                    bail!("Expected a single script in {}", config.script_file);
+                }
+                let mut bytes = vec![];
+                compiled.serialize(&mut bytes)?;
+                script = Some(bytes);
+            }
+        }
+    }
+    let script = script.ok_or_else(|| format_err!("No script in {}", config.script_file))?;
+
+    let mut session =
+        DebugSession::new(input, output).with_source_index(SourceIndex::new(files, &units));
+    if !config.stop_on_entry {
+        session = session.run_to_breakpoint();
+    }
+    for breakpoint in &config.breakpoints {
+        session.add_breakpoint(breakpoint)?;
+    }
+
+    let args = convert_txn_args(&config.args);
+    let cost_table = zero_cost_schedule();
+    let mut cost_strategy = CostStrategy::system(&cost_table, GasUnits::new(100_000_000));
+    let vm = MoveVM::new();
+    let remote_view = RemoteStorage::new(executor.get_state_view());
+    let mut cache = TransactionDataCache::new(&remote_view);
+
+    debugger::set_debugger(Box::new(session));
+    let result = vm.execute_script(
+        script,
+        vec![],
+        args,
+        config.sender,
+        &mut cache,
+        &mut cost_strategy,
+    );
+    debugger::take_debugger();
+    Ok(result)
+}
@@ -0,0 +1,68 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use libra_types::account_address::AccountAddress;
+use move_core_types::parser::parse_transaction_arguments;
+use move_debugger::{debug_script, Breakpoint, DebugConfig};
+use std::io;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move Debugger",
+    about = "Step through the execution of a Move script on top of genesis"
+)]
+struct Args {
+    /// The Move source file of the script to debug.
+    pub script_file: String,
+
+    /// Move source files of the modules used by the script, beyond the standard library. They
+    /// are published before the script is run.
+    #[structopt(short = "m", long = "modules")]
+    pub module_files: Vec<String>,
+
+    /// The sender of the script.
+    #[structopt(
+        short = "s",
+        long = "sender",
+        default_value = "0x1",
+        parse(try_from_str = AccountAddress::from_hex_literal)
+    )]
+    pub sender: AccountAddress,
+
+    /// The arguments of the script, comma separated, e.g. `0x1, 10u64`.
+    #[structopt(short = "a", long = "args", default_value = "")]
+    pub args: String,
+
+    /// Breakpoints as `<address>::<module>::<function>@<pc>`, `main@<pc>` for the script, or
+    /// `<file>:<line>`.
+    #[structopt(short = "b", long = "break")]
+    pub breakpoints: Vec<Breakpoint>,
+
+    /// Run to the first breakpoint instead of pausing on the first instruction.
+    #[structopt(long = "no-stop-on-entry")]
+    pub no_stop_on_entry: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let config = DebugConfig {
+        script_file: args.script_file,
+        module_files: args.module_files,
+        sender: args.sender,
+        args: parse_transaction_arguments(&args.args)?,
+        breakpoints: args.breakpoints,
+        stop_on_entry: !args.no_stop_on_entry,
+    };
+
+    let stdin = io::stdin();
+    let input = io::BufReader::new(stdin);
+    match debug_script(config, input, io::stdout())? {
+        Ok(()) => println!("Script executed successfully"),
+        Err(status) => println!("Script failed: {}", status),
+    }
+    Ok(())
+}
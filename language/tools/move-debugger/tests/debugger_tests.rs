@@ -0,0 +1,146 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_types::{account_address::AccountAddress, transaction::TransactionArgument};
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use move_debugger::{debug_script, Breakpoint, CodeLocation, DebugConfig};
+use std::{
+    cell::RefCell,
+    io::{self, Cursor, Write},
+    path::PathBuf,
+    rc::Rc,
+};
+
+/// An output the test keeps a handle on while the session owns it.
+#[derive(Clone, Default)]
+struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn source(name: &str) -> String {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/sources");
+    path.push(name);
+    path.to_string_lossy().into_owned()
+}
+
+fn debug(breakpoints: Vec<Breakpoint>, stop_on_entry: bool, commands: &str) -> String {
+    let config = DebugConfig {
+        script_file: source("double.move"),
+        module_files: vec![source("Doubler.move")],
+        sender: AccountAddress::from_hex_literal("0x1").unwrap(),
+        args: vec![TransactionArgument::U64(21)],
+        breakpoints,
+        stop_on_entry,
+    };
+    let output = SharedOutput::default();
+    let result = debug_script(config, Cursor::new(commands.to_string()), output.clone()).unwrap();
+    assert!(result.is_ok(), "{:?}", result);
+    let bytes = output.0.borrow().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+fn doubler() -> ModuleId {
+    ModuleId::new(
+        AccountAddress::from_hex_literal("0x2").unwrap(),
+        Identifier::new("Doubler").unwrap(),
+    )
+}
+
+#[test]
+fn parse_breakpoints() {
+    assert_eq!(
+        "0x2::Doubler::double@2".parse::<Breakpoint>().unwrap(),
+        Breakpoint::Code(CodeLocation {
+            module: Some(doubler()),
+            function: "double".to_string(),
+            pc: 2,
+        })
+    );
+    assert_eq!(
+        "0x2::Doubler::double".parse::<Breakpoint>().unwrap(),
+        Breakpoint::Code(CodeLocation {
+            module: Some(doubler()),
+            function: "double".to_string(),
+            pc: 0,
+        })
+    );
+    assert_eq!(
+        "main@1".parse::<Breakpoint>().unwrap(),
+        Breakpoint::Code(CodeLocation {
+            module: None,
+            function: "main".to_string(),
+            pc: 1,
+        })
+    );
+    assert_eq!(
+        "sources/double.move:4".parse::<Breakpoint>().unwrap(),
+        Breakpoint::Line {
+            file: "sources/double.move".to_string(),
+            line: 4,
+        }
+    );
+    assert!("double.move".parse::<Breakpoint>().is_err());
+    assert!("Doubler::double@2".parse::<Breakpoint>().is_err());
+    assert!("main@first".parse::<Breakpoint>().is_err());
+}
+
+#[test]
+fn stop_on_entry_and_step() {
+    let output = debug(vec![], true, "locals\ns\ns\nstack\nc\n");
+    assert!(output.contains("Script::main [pc = 0]"));
+    assert!(output.contains("[0] 21u64"));
+    assert!(output.contains("Script::main [pc = 2]"));
+}
+
+#[test]
+fn break_in_module_and_finish() {
+    let breakpoint = "0x2::Doubler::double@0".parse().unwrap();
+    let output = debug(vec![breakpoint], false, "backtrace\nfinish\nc\n");
+    assert!(!output.contains("Script::main [pc = 0]"));
+    assert!(output.contains("::Doubler::double [pc = 0]"));
+    assert!(output.contains("#1: 0x"));
+    assert!(output.contains("#0: Script::main"));
+}
+
+#[test]
+fn break_only_in_module_at_address() {
+    let breakpoint = "0xA::Doubler::double@0".parse().unwrap();
+    let output = debug(vec![breakpoint], false, "c\n");
+    assert!(!output.contains("::Doubler::double [pc = 0]"));
+}
+
+#[test]
+fn break_on_line() {
+    let breakpoint = Breakpoint::Line {
+        file: "double.move".to_string(),
+        line: 5,
+    };
+    let output = debug(vec![breakpoint], false, "l\nc\n");
+    assert!(output.contains("double.move:5"));
+    assert!(output.contains("] 42u64"));
+}
+
+#[test]
+fn unknown_line_is_rejected() {
+    let config = DebugConfig {
+        script_file: source("double.move"),
+        module_files: vec![source("Doubler.move")],
+        sender: AccountAddress::from_hex_literal("0x1").unwrap(),
+        args: vec![TransactionArgument::U64(21)],
+        breakpoints: vec![Breakpoint::Line {
+            file: "double.move".to_string(),
+            line: 100,
+        }],
+        stop_on_entry: false,
+    };
+    assert!(debug_script(config, Cursor::new(String::new()), io::sink()).is_err());
+}
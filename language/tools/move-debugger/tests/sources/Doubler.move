@@ -0,0 +1,8 @@
+address 0x2 {
+module Doubler {
+    public fun double(x: u64): u64 {
+        let y = x * 2;
+        y
+    }
+}
+}
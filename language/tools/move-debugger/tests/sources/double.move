@@ -0,0 +1,7 @@
+script {
+use 0x2::Doubler;
+fun main(x: u64) {
+    let y = Doubler::double(x);
+    assert(y == 2 * x, 42);
+}
+}
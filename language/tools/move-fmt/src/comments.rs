@@ -0,0 +1,97 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Extraction of the comments of a source file, which the parser drops.
+
+/// A comment of the source.
+#[derive(Clone, Debug)]
+pub(crate) struct Comment {
+    /// Byte offset of the comment in the source.
+    pub start: usize,
+    pub end: usize,
+    /// The comment with its delimiters, without trailing whitespace.
+    pub text: String,
+    /// Whether this is a `//` comment.
+    pub line: bool,
+    /// Whether the comment is the first thing on its line.
+    pub own_line: bool,
+}
+
+/// Returns the source with all comments replaced by spaces, so that the offsets of the rest are
+/// unchanged, and the comments in the order they appear. Block comments can be nested, and
+/// comment delimiters in byte strings are not comments.
+pub(crate) fn strip_comments(source: &str) -> (String, Vec<Comment>) {
+    let bytes = source.as_bytes();
+    let mut stripped = bytes.to_vec();
+    let mut comments = vec![];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'"' => {
+                pos += 1;
+                while pos < bytes.len() && bytes[pos] != b'"' {
+                    if bytes[pos] == b'\\' {
+                        pos += 1;
+                    }
+                    pos += 1;
+                }
+                pos += 1;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                let start = pos;
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+                comments.push(comment(source, &stripped, start, pos, true));
+                blank_out(&mut stripped[start..pos]);
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                let start = pos;
+                let mut depth = 0;
+                while pos < bytes.len() {
+                    if bytes[pos] == b'/' && bytes.get(pos + 1) == Some(&b'*') {
+                        depth += 1;
+                        pos += 2;
+                    } else if bytes[pos] == b'*' && bytes.get(pos + 1) == Some(&b'/') {
+                        depth -= 1;
+                        pos += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        pos += 1;
+                    }
+                }
+                let end = pos.min(bytes.len());
+                comments.push(comment(source, &stripped, start, end, false));
+                blank_out(&mut stripped[start..end]);
+            }
+            _ => pos += 1,
+        }
+    }
+    // Comments are replaced as a whole, so no multi-byte character is split.
+    let stripped = String::from_utf8(stripped).expect("stripped source must be valid UTF-8");
+    (stripped, comments)
+}
+
+fn comment(source: &str, stripped: &[u8], start: usize, end: usize, line: bool) -> Comment {
+    let line_start = stripped[..start]
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |idx| idx + 1);
+    Comment {
+        start,
+        end,
+        text: source[start..end].trim_end().to_string(),
+        line,
+        own_line: stripped[line_start..start]
+            .iter()
+            .all(|b| b.is_ascii_whitespace()),
+    }
+}
+
+fn blank_out(bytes: &mut [u8]) {
+    for b in bytes.iter_mut().filter(|b| **b != b'\n') {
+        *b = b' ';
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A document describing the layouts source code can be printed with, and the renderer choosing
+//! among them to fit a line width.
+//!
+//! This follows Wadler's "A prettier printer": a `Group` is printed on one line if it fits in the
+//! rest of the current line, with its `Line`s as spaces, and otherwise with all of its own `Line`s
+//! as line breaks. Nested groups make this choice independently.
+
+/// A document to render.
+#[derive(Clone, Debug)]
+pub(crate) enum Doc {
+    Nil,
+    Text(String),
+    /// A space, or a line break if the enclosing group does not fit.
+    Line,
+    /// Nothing, or a line break if the enclosing group does not fit.
+    SoftLine,
+    /// A line break in any case.
+    HardLine,
+    /// A line break, unless at the start of a line.
+    FreshLine,
+    /// A comment copied from the source. The enclosing group is broken if `breaks` is set, and a
+    /// line comment is always followed by a line break.
+    Comment {
+        text: String,
+        line: bool,
+        breaks: bool,
+    },
+    /// Rendered only if the enclosing group does not fit.
+    IfBreak(Box<Doc>),
+    /// Indents the line breaks of the document by one level.
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+}
+
+pub(crate) fn text(s: impl Into<String>) -> Doc {
+    Doc::Text(s.into())
+}
+
+pub(crate) fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+pub(crate) fn nest(doc: Doc) -> Doc {
+    Doc::Nest(Box::new(doc))
+}
+
+pub(crate) fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+pub(crate) fn if_break(doc: Doc) -> Doc {
+    Doc::IfBreak(Box::new(doc))
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Renders the document with lines of at most `max_width` columns where possible, indenting by
+/// `indent_width` spaces per level.
+pub(crate) fn render(doc: &Doc, max_width: usize, indent_width: usize) -> String {
+    Renderer {
+        max_width,
+        indent_width,
+        out: String::new(),
+        column: 0,
+        after_line_comment: false,
+    }
+    .render(doc)
+}
+
+struct Renderer {
+    max_width: usize,
+    indent_width: usize,
+    out: String,
+    column: usize,
+    // Set after a line comment, until the next line break.
+    after_line_comment: bool,
+}
+
+impl Renderer {
+    fn render(mut self, doc: &Doc) -> String {
+        let mut stack = vec![(0, Mode::Break, doc)];
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Nil => (),
+                Doc::Text(s) => self.write(indent, s),
+                Doc::Comment { text, line, .. } => {
+                    self.write(indent, text);
+                    self.after_line_comment = *line;
+                }
+                Doc::Line | Doc::SoftLine if mode == Mode::Flat && !self.after_line_comment => {
+                    if let Doc::Line = doc {
+                        self.write(indent, " ")
+                    }
+                }
+                Doc::Line | Doc::SoftLine | Doc::HardLine => self.new_line(),
+                Doc::FreshLine => {
+                    if self.column != 0 || self.after_line_comment {
+                        self.new_line()
+                    }
+                }
+                Doc::IfBreak(doc) => {
+                    if mode == Mode::Break {
+                        stack.push((indent, mode, doc))
+                    }
+                }
+                Doc::Nest(doc) => stack.push((indent + self.indent_width, mode, doc)),
+                Doc::Group(doc) => {
+                    // Indentation is not written yet at the start of a line.
+                    let column = if self.column == 0 {
+                        indent
+                    } else {
+                        self.column
+                    };
+                    let width = self.max_width.saturating_sub(column);
+                    let mode = if fits(width, (indent, Mode::Flat, doc), &stack) {
+                        Mode::Flat
+                    } else {
+                        Mode::Break
+                    };
+                    stack.push((indent, mode, doc))
+                }
+                Doc::Concat(docs) => {
+                    stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+                }
+            }
+        }
+        self.new_line();
+        let len = self.out.trim_end().len();
+        self.out.truncate(len);
+        self.out.push('\n');
+        self.out
+    }
+
+    fn write(&mut self, indent: usize, s: &str) {
+        if self.after_line_comment {
+            self.new_line();
+        }
+        if self.column == 0 && !s.is_empty() {
+            let s = s.trim_start_matches(' ');
+            if s.is_empty() {
+                return;
+            }
+            self.out.extend((0..indent).map(|_| ' '));
+            self.column = indent;
+            self.push(s);
+        } else {
+            self.push(s);
+        }
+    }
+
+    fn push(&mut self, s: &str) {
+        self.out.push_str(s);
+        match s.rfind('\n') {
+            Some(idx) => self.column = s.len() - idx - 1,
+            None => self.column += s.len(),
+        }
+    }
+
+    // Indentation is only written with the text of the next line, so that blank lines and line
+    // ends carry no whitespace.
+    fn new_line(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+        self.out.push('\n');
+        self.column = 0;
+        self.after_line_comment = false;
+    }
+}
+
+// Returns whether `next` fits in `width` columns when printed flat, followed by the `rest` of the
+// document up to its next line break.
+fn fits(mut width: usize, next: (usize, Mode, &Doc), rest: &[(usize, Mode, &Doc)]) -> bool {
+    let mut rest = rest.iter().rev();
+    let mut stack = vec![next];
+    // Whether the documents are in the group measured, or follow it.
+    let mut in_group = true;
+    loop {
+        let (indent, mode, doc) = match stack.pop() {
+            Some(item) => item,
+            None => match rest.next() {
+                Some(item) => {
+                    in_group = false;
+                    *item
+                }
+                None => return true,
+            },
+        };
+        match doc {
+            Doc::Nil => (),
+            Doc::Text(s) => {
+                if s.len() > width {
+                    return false;
+                }
+                width -= s.len();
+            }
+            Doc::Comment { text, breaks, .. } => {
+                if !in_group {
+                    return true;
+                }
+                if *breaks || text.len() > width {
+                    return false;
+                }
+                width -= text.len();
+            }
+            Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                if let Doc::Line = doc {
+                    if width == 0 {
+                        return false;
+                    }
+                    width -= 1;
+                }
+            }
+            // A group with a line break in any case does not fit on one line.
+            Doc::HardLine | Doc::FreshLine if in_group => return false,
+            Doc::Line | Doc::SoftLine | Doc::HardLine | Doc::FreshLine => return true,
+            Doc::IfBreak(doc) => {
+                if mode == Mode::Break {
+                    stack.push((indent, mode, doc))
+                }
+            }
+            Doc::Nest(doc) | Doc::Group(doc) => stack.push((indent, mode, doc)),
+            Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc))),
+        }
+    }
+}
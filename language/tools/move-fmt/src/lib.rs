@@ -0,0 +1,71 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! Formats Move source files.
+//!
+//! A file is parsed with the Move parser and printed back from its AST, so formatting never
+//! changes the meaning of a program. Comments, which the parser drops, are collected beforehand
+//! and put back next to the code they were written next to. Blank lines between the items of a
+//! block are kept, everything else is laid out anew: one item of a block per line, indented by
+//! `indent_width` spaces, and lists and expressions broken over several lines only if they do
+//! not fit in `max_width` columns.
+
+mod comments;
+mod doc;
+mod printer;
+
+use anyhow::{bail, Result};
+use move_lang::{
+    errors::{report_errors_to_buffer, Errors},
+    parse_file_string, strip_comments_and_verify,
+};
+use std::{collections::BTreeMap, fs};
+
+/// How to lay out formatted code.
+#[derive(Clone, Debug)]
+pub struct FormatConfig {
+    /// The number of spaces per level of indentation.
+    pub indent_width: usize,
+    /// The number of columns lines are kept within, where possible.
+    pub max_width: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            max_width: 100,
+        }
+    }
+}
+
+/// Formats the source of a Move file, named `fname` in errors.
+pub fn format_string(
+    fname: &'static str,
+    source: &str,
+    config: &FormatConfig,
+) -> Result<String, Errors> {
+    // Rejects the files the compiler rejects before parsing them.
+    strip_comments_and_verify(fname, source)?;
+    let (stripped, comments) = comments::strip_comments(source);
+    // Doc comments are formatted like other comments, so the parser need not collect them.
+    let (defs, _) = parse_file_string(fname, &stripped, BTreeMap::new())?;
+    let doc = printer::Printer::new(source, &stripped, comments).file(&defs);
+    Ok(doc::render(&doc, config.max_width, config.indent_width))
+}
+
+/// Formats the Move file at `path`, returning the formatted source.
+pub fn format_file(path: &str, config: &FormatConfig) -> Result<String> {
+    let source = fs::read_to_string(path)?;
+    let fname: &'static str = Box::leak(path.to_string().into_boxed_str());
+    match format_string(fname, &source, config) {
+        Ok(formatted) => Ok(formatted),
+        Err(errors) => {
+            let files = vec![(fname, source)].into_iter().collect();
+            let report = report_errors_to_buffer(files, errors);
+            bail!("{}", String::from_utf8_lossy(&report))
+        }
+    }
+}
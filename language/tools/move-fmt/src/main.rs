@@ -0,0 +1,55 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use move_fmt::{format_file, FormatConfig};
+use move_lang::find_move_filenames;
+use std::{fs, process};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "Move Format", about = "Format Move source files")]
+struct Args {
+    /// Move source files, or directories of them, to format in place.
+    #[structopt(required = true)]
+    pub files: Vec<String>,
+
+    /// Do not write the files, but list those that are not formatted and fail if there are any.
+    #[structopt(long = "check")]
+    pub check: bool,
+
+    /// The number of spaces per level of indentation.
+    #[structopt(long = "indent-width", default_value = "4")]
+    pub indent_width: usize,
+
+    /// The number of columns lines are kept within, where possible.
+    #[structopt(long = "max-width", default_value = "100")]
+    pub max_width: usize,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let config = FormatConfig {
+        indent_width: args.indent_width,
+        max_width: args.max_width,
+    };
+    let mut unformatted = false;
+    for file in find_move_filenames(&args.files)? {
+        let formatted = format_file(&file, &config)?;
+        if formatted == fs::read_to_string(&file)? {
+            continue;
+        }
+        if args.check {
+            println!("{}", file);
+            unformatted = true;
+        } else {
+            fs::write(&file, formatted)?;
+        }
+    }
+    if unformatted {
+        process::exit(1)
+    }
+    Ok(())
+}
@@ -0,0 +1,1282 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion of the parser AST back into source code.
+//!
+//! The printer walks the AST in source order and takes the comments of the source along the way:
+//! the comments before an item of a block are printed on their own lines before it, those that
+//! follow an item on the same line stay after it, and those within an expression are printed
+//! where the next subexpression starts. Parentheses are not kept by the parser, so they are added
+//! back where the precedence of the operators requires them.
+
+use crate::{
+    comments::Comment,
+    doc::{concat, group, if_break, nest, text, Doc},
+};
+use move_ir_types::location::{Loc, Spanned};
+use move_lang::{
+    parser::ast::*,
+    shared::{Identifier, Name},
+};
+
+// Keywords that are only reserved at the start of a spec block member.
+const SPEC_MEMBER_KEYWORDS: &[&str] = &[
+    "aborts_if",
+    "apply",
+    "assert",
+    "assume",
+    "decreases",
+    "ensures",
+    "global",
+    "include",
+    "local",
+    "pragma",
+    "requires",
+    "succeeds_if",
+];
+
+// The names the parser desugars quantifiers to.
+const SPEC_ALL: &str = "$spec_all";
+const SPEC_ANY: &str = "$spec_any";
+const SPEC_DOMAIN: &str = "$spec_domain";
+
+fn start(loc: Loc) -> usize {
+    loc.span().start().to_usize()
+}
+
+fn end(loc: Loc) -> usize {
+    loc.span().end().to_usize()
+}
+
+// The position of the closing delimiter of a construct spanning `loc`.
+fn close(loc: Loc) -> usize {
+    end(loc).saturating_sub(1)
+}
+
+/// How tightly an expression binds, from the loosest to the tightest.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Level {
+    /// Expressions that extend as far to the right as possible, like `if` or `return`.
+    Exp,
+    Binop(u32),
+    Unary,
+    Term,
+}
+
+// The precedence of binary operators, as in the parser.
+fn precedence(op: BinOp_) -> u32 {
+    use BinOp_ as B;
+    match op {
+        B::Implies => 2,
+        B::Or => 3,
+        B::And => 4,
+        B::Eq | B::Neq | B::Lt | B::Gt | B::Le | B::Ge => 5,
+        B::Range => 6,
+        B::BitOr => 7,
+        B::Xor => 8,
+        B::BitAnd => 9,
+        B::Shl | B::Shr => 10,
+        B::Add | B::Sub => 11,
+        B::Mul | B::Div | B::Mod => 12,
+    }
+}
+
+fn level(e: &Exp) -> Level {
+    use Exp_ as E;
+    match &e.value {
+        E::IfElse(..)
+        | E::While(..)
+        | E::Loop(_)
+        | E::Return(_)
+        | E::Abort(_)
+        | E::Assign(..)
+        | E::Lambda(..) => Level::Exp,
+        E::Call(..) if quantifier(e).is_some() => Level::Exp,
+        E::BinopExp(_, op, _) => Level::Binop(precedence(op.value)),
+        E::UnaryExp(..) | E::Borrow(..) | E::Dereference(_) | E::Move(_) | E::Copy(_) => {
+            Level::Unary
+        }
+        _ => Level::Term,
+    }
+}
+
+// Returns whether the expression ends with an `if` without `else`, which would take an `else`
+// that follows the expression.
+fn ends_with_if(e: &Exp) -> bool {
+    use Exp_ as E;
+    match &e.value {
+        E::IfElse(_, _, None) => true,
+        E::IfElse(_, _, Some(e))
+        | E::While(_, e)
+        | E::Loop(e)
+        | E::Return(Some(e))
+        | E::Abort(e)
+        | E::Assign(_, e)
+        | E::Lambda(_, e) => ends_with_if(e),
+        E::Call(..) => match quantifier(e) {
+            Some(q) => ends_with_if(q.body),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// A quantifier, which the parser desugars to a call of a builtin on a range and a lambda.
+struct Quantifier<'a> {
+    forall: bool,
+    var: &'a Var,
+    /// The type quantified over, if the quantifier is not over a range of values.
+    ty: Option<&'a Type>,
+    range: &'a Exp,
+    body: &'a Exp,
+}
+
+fn quantifier(e: &Exp) -> Option<Quantifier<'_>> {
+    let (name, args) = match &e.value {
+        Exp_::Call(access, None, args) => match &access.value {
+            ModuleAccess_::Name(name) => (name, &args.value),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let forall = match name.value.as_str() {
+        SPEC_ALL => true,
+        SPEC_ANY => false,
+        _ => return None,
+    };
+    let (range, lambda) = match args.as_slice() {
+        [range, lambda] => (range, lambda),
+        _ => return None,
+    };
+    let (var, body) = match &lambda.value {
+        Exp_::Lambda(binds, body) => match binds.value.as_slice() {
+            [Spanned {
+                value: Bind_::Var(var),
+                ..
+            }] => (var, &**body),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let ty = match &range.value {
+        Exp_::Call(access, Some(tys), args) if args.value.is_empty() && tys.len() == 1 => {
+            match &access.value {
+                ModuleAccess_::Name(name) if name.value == SPEC_DOMAIN => Some(&tys[0]),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    Some(Quantifier {
+        forall,
+        var,
+        ty,
+        range,
+        body,
+    })
+}
+
+pub(crate) struct Printer<'a> {
+    source: &'a str,
+    /// The source with comments replaced by spaces.
+    stripped: &'a str,
+    comments: Vec<Comment>,
+    next_comment: usize,
+}
+
+impl<'a> Printer<'a> {
+    pub(crate) fn new(source: &'a str, stripped: &'a str, comments: Vec<Comment>) -> Self {
+        Self {
+            source,
+            stripped,
+            comments,
+            next_comment: 0,
+        }
+    }
+
+    //**********************************************************************************************
+    // Comments and blocks
+    //**********************************************************************************************
+
+    // Takes the next comment if it starts before `pos`.
+    fn comment_before(&mut self, pos: usize) -> Option<Comment> {
+        let comment = self.comments.get(self.next_comment)?;
+        if comment.start < pos {
+            self.next_comment += 1;
+            Some(comment.clone())
+        } else {
+            None
+        }
+    }
+
+    fn comment(comment: &Comment) -> Doc {
+        Doc::Comment {
+            text: comment.text.clone(),
+            line: comment.line,
+            breaks: comment.line || comment.own_line || comment.text.contains('\n'),
+        }
+    }
+
+    // The comments before `pos`, within an expression or a list. Block comments that share their
+    // line with code stay inline, others are printed on their own lines.
+    fn leading(&mut self, pos: usize) -> Doc {
+        let mut docs = vec![];
+        while let Some(comment) = self.comment_before(pos) {
+            if comment.own_line {
+                docs.push(Doc::FreshLine);
+            }
+            docs.push(Self::comment(&comment));
+            if comment.own_line || comment.line || comment.text.contains('\n') {
+                docs.push(Doc::HardLine);
+            } else {
+                docs.push(text(" "));
+            }
+        }
+        concat(docs)
+    }
+
+    // The comments before the closing delimiter at `pos` of a list.
+    fn leading_close(&mut self, pos: usize) -> Doc {
+        let mut docs = vec![];
+        while let Some(comment) = self.comment_before(pos) {
+            docs.push(if comment.own_line {
+                Doc::FreshLine
+            } else {
+                text(" ")
+            });
+            docs.push(Self::comment(&comment));
+        }
+        concat(docs)
+    }
+
+    // The comments that follow the code ending at `pos` on the same line, possibly after a
+    // separator, and the comments not printed yet that start before `pos`.
+    fn trailing(&mut self, mut pos: usize) -> Doc {
+        let mut docs = vec![];
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            let separator = |b: &u8| *b == b' ' || *b == b'\t' || *b == b',' || *b == b';';
+            // A block comment followed by code on its line belongs to that code.
+            let follows = comment.start < pos
+                || (self.stripped[pos..comment.start]
+                    .bytes()
+                    .all(|b| separator(&b))
+                    && (comment.line
+                        || self.stripped[comment.end..]
+                            .bytes()
+                            .take_while(|b| *b != b'\n')
+                            .all(|b| separator(&b))));
+            if !follows {
+                break;
+            }
+            docs.push(text(" "));
+            docs.push(Self::comment(comment));
+            pos = pos.max(comment.end);
+            self.next_comment += 1;
+        }
+        concat(docs)
+    }
+
+    fn blank_line_before(&self, pos: usize) -> bool {
+        self.source[..pos]
+            .bytes()
+            .rev()
+            .take_while(|b| b.is_ascii_whitespace())
+            .filter(|b| *b == b'\n')
+            .count()
+            > 1
+    }
+
+    // The line break before an item of a block starting at `pos`, with a blank line if the source
+    // has one there, except before the first item.
+    fn block_line(&self, docs: &mut Vec<Doc>, pos: usize) {
+        if !docs.is_empty() && self.blank_line_before(pos) {
+            docs.push(Doc::HardLine);
+        }
+        docs.push(Doc::HardLine);
+    }
+
+    // Starts an item of a block at `pos`, after the comments before it.
+    fn block_item(&mut self, docs: &mut Vec<Doc>, pos: usize) {
+        while let Some(comment) = self.comment_before(pos) {
+            self.block_line(docs, comment.start);
+            docs.push(Self::comment(&comment));
+        }
+        self.block_line(docs, pos);
+    }
+
+    // Ends a block whose closing brace is at `close`, with one item per line.
+    fn block(&mut self, mut docs: Vec<Doc>, close: usize, indent: bool) -> Doc {
+        while let Some(comment) = self.comment_before(close) {
+            self.block_line(&mut docs, comment.start);
+            docs.push(Self::comment(&comment));
+        }
+        if docs.is_empty() {
+            return text("{}");
+        }
+        let items = concat(docs);
+        concat(vec![
+            text("{"),
+            if indent { nest(items) } else { items },
+            Doc::HardLine,
+            text("}"),
+        ])
+    }
+
+    // A comma separated list, on one line if it fits and with one item per line otherwise.
+    fn list<T>(
+        &mut self,
+        (open, close): (&str, &str),
+        items: &[T],
+        close_pos: usize,
+        spaced: bool,
+        span: impl Fn(&T) -> (usize, usize),
+        print: impl Fn(&mut Self, &T) -> Doc,
+    ) -> Doc {
+        let line = if spaced { Doc::Line } else { Doc::SoftLine };
+        let mut docs = vec![line.clone()];
+        for (idx, item) in items.iter().enumerate() {
+            let (item_start, item_end) = span(item);
+            docs.push(self.leading(item_start));
+            docs.push(print(self, item));
+            if idx + 1 < items.len() {
+                docs.push(text(","));
+                docs.push(self.trailing(item_end));
+                docs.push(Doc::Line);
+            } else {
+                docs.push(if_break(text(",")));
+                docs.push(self.trailing(item_end));
+            }
+        }
+        docs.push(self.leading_close(close_pos));
+        if items.is_empty() {
+            docs.remove(0);
+            return concat(vec![text(open), concat(docs), text(close)]);
+        }
+        group(concat(vec![
+            text(open),
+            nest(concat(docs)),
+            line,
+            text(close),
+        ]))
+    }
+
+    //**********************************************************************************************
+    // Tokens
+    //**********************************************************************************************
+
+    // The identifier or literal starting at `pos`, as written in the source.
+    fn token_at(&self, pos: usize) -> &str {
+        let len = self.stripped[pos..]
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+        &self.stripped[pos..pos + len]
+    }
+
+    // The start of the word before `pos`.
+    fn word_before(&self, pos: usize) -> usize {
+        let bytes = self.stripped.as_bytes();
+        let mut idx = pos;
+        while idx > 0 && bytes[idx - 1].is_ascii_whitespace() {
+            idx -= 1;
+        }
+        while idx > 0 && (bytes[idx - 1].is_ascii_alphanumeric() || bytes[idx - 1] == b'_') {
+            idx -= 1;
+        }
+        idx
+    }
+
+    // The position after the first `c` from `pos`.
+    fn after_char(&self, pos: usize, c: char) -> usize {
+        self.stripped[pos..]
+            .find(c)
+            .map_or(self.stripped.len(), |idx| pos + idx + 1)
+    }
+
+    //**********************************************************************************************
+    // Definitions
+    //**********************************************************************************************
+
+    pub(crate) fn file(mut self, defs: &[Definition]) -> Doc {
+        let mut docs = vec![];
+        for def in defs {
+            let (def_start, def_end) = self.definition_span(def);
+            self.block_item(&mut docs, def_start);
+            docs.push(self.definition(def));
+            docs.push(self.trailing(def_end));
+        }
+        while let Some(comment) = self.comment_before(usize::MAX) {
+            self.block_line(&mut docs, comment.start);
+            docs.push(Self::comment(&comment));
+        }
+        if !docs.is_empty() {
+            // No line break before the first definition.
+            docs.remove(0);
+        }
+        concat(docs)
+    }
+
+    fn definition_span(&self, def: &Definition) -> (usize, usize) {
+        match def {
+            Definition::Module(module) => (start(module.loc), end(module.loc)),
+            Definition::Script(script) => (start(script.loc), end(script.loc)),
+            Definition::Address(loc, _, modules) => {
+                let after_modules = modules
+                    .last()
+                    .map_or_else(|| self.after_char(end(*loc), '{'), |m| end(m.loc));
+                (
+                    self.word_before(start(*loc)),
+                    self.after_char(after_modules, '}'),
+                )
+            }
+        }
+    }
+
+    fn definition(&mut self, def: &Definition) -> Doc {
+        match def {
+            Definition::Module(module) => self.module(module),
+            Definition::Script(script) => self.script(script),
+            Definition::Address(loc, _, modules) => {
+                let (_, def_end) = self.definition_span(def);
+                let mut docs = vec![];
+                for module in modules {
+                    self.block_item(&mut docs, start(module.loc));
+                    docs.push(self.module(module));
+                    docs.push(self.trailing(end(module.loc)));
+                }
+                // Modules are not indented in address blocks.
+                let block = self.block(docs, def_end - 1, false);
+                concat(vec![
+                    text(format!("address {} ", self.token_at(start(*loc)))),
+                    block,
+                ])
+            }
+        }
+    }
+
+    fn module(&mut self, module: &ModuleDefinition) -> Doc {
+        let mut docs = vec![];
+        for member in &module.members {
+            let (member_start, member_end) = match member {
+                ModuleMember::Function(function) => (start(function.loc), end(function.loc)),
+                ModuleMember::Struct(struct_def) => (start(struct_def.loc), end(struct_def.loc)),
+                ModuleMember::Spec(spec) => (start(spec.loc), end(spec.loc)),
+                ModuleMember::Use(use_) => self.use_span(use_),
+                ModuleMember::Constant(constant) => (start(constant.loc), end(constant.loc)),
+            };
+            self.block_item(&mut docs, member_start);
+            docs.push(match member {
+                ModuleMember::Function(function) => self.function(function),
+                ModuleMember::Struct(struct_def) => self.struct_definition(struct_def),
+                ModuleMember::Spec(spec) => self.spec_block(spec),
+                ModuleMember::Use(use_) => self.use_decl(use_),
+                ModuleMember::Constant(constant) => self.constant(constant),
+            });
+            docs.push(self.trailing(member_end));
+        }
+        let block = self.block(docs, close(module.loc), true);
+        concat(vec![text(format!("module {} ", module.name)), block])
+    }
+
+    fn script(&mut self, script: &Script) -> Doc {
+        let mut docs = vec![];
+        self.uses(&mut docs, &script.uses);
+        for constant in &script.constants {
+            self.block_item(&mut docs, start(constant.loc));
+            docs.push(self.constant(constant));
+            docs.push(self.trailing(end(constant.loc)));
+        }
+        self.block_item(&mut docs, start(script.function.loc));
+        docs.push(self.function(&script.function));
+        docs.push(self.trailing(end(script.function.loc)));
+        for spec in &script.specs {
+            self.block_item(&mut docs, start(spec.loc));
+            docs.push(self.spec_block(spec));
+            docs.push(self.trailing(end(spec.loc)));
+        }
+        let block = self.block(docs, close(script.loc), true);
+        concat(vec![text("script "), block])
+    }
+
+    //**********************************************************************************************
+    // Uses
+    //**********************************************************************************************
+
+    fn use_span(&self, use_: &Use) -> (usize, usize) {
+        let ident = match use_ {
+            Use::Module(ident, _) | Use::Members(ident, _) => ident,
+        };
+        let ident_start = start(ident.loc());
+        (
+            self.word_before(ident_start),
+            self.after_char(ident_start, ';'),
+        )
+    }
+
+    fn uses(&mut self, docs: &mut Vec<Doc>, uses: &[Use]) {
+        for use_ in uses {
+            let (use_start, use_end) = self.use_span(use_);
+            self.block_item(docs, use_start);
+            docs.push(self.use_decl(use_));
+            docs.push(self.trailing(use_end));
+        }
+    }
+
+    fn use_decl(&mut self, use_: &Use) -> Doc {
+        let member = |(name, alias): &(Name, Option<Name>)| match alias {
+            Some(alias) => format!("{} as {}", name, alias),
+            None => name.to_string(),
+        };
+        match use_ {
+            Use::Module(ident, None) => text(format!("use {};", self.module_ident(ident))),
+            Use::Module(ident, Some(alias)) => {
+                text(format!("use {} as {};", self.module_ident(ident), alias))
+            }
+            Use::Members(ident, members) if members.len() == 1 => text(format!(
+                "use {}::{};",
+                self.module_ident(ident),
+                member(&members[0])
+            )),
+            Use::Members(ident, members) => {
+                let members = members.iter().map(member).collect::<Vec<_>>();
+                concat(vec![
+                    text(format!("use {}::", self.module_ident(ident))),
+                    self.text_list(("{", "}"), members),
+                    text(";"),
+                ])
+            }
+        }
+    }
+
+    // A comma separated list of items without comments.
+    fn text_list(&mut self, delimiters: (&str, &str), items: Vec<String>) -> Doc {
+        self.list(
+            delimiters,
+            &items,
+            0,
+            false,
+            |_| (0, 0),
+            |_, item| text(item.clone()),
+        )
+    }
+
+    //**********************************************************************************************
+    // Module members
+    //**********************************************************************************************
+
+    fn constant(&mut self, constant: &Constant) -> Doc {
+        concat(vec![
+            text(format!(
+                "const {}: {} = ",
+                constant.name,
+                self.type_(&constant.signature)
+            )),
+            self.exp(&constant.value),
+            text(";"),
+        ])
+    }
+
+    fn struct_definition(&mut self, struct_def: &StructDefinition) -> Doc {
+        let native = matches!(struct_def.fields, StructFields::Native(_));
+        let header = format!(
+            "{}{}struct {}{}",
+            if native { "native " } else { "" },
+            if struct_def.resource_opt.is_some() {
+                "resource "
+            } else {
+                ""
+            },
+            struct_def.name,
+            self.type_parameters(&struct_def.type_parameters)
+        );
+        match &struct_def.fields {
+            StructFields::Native(_) => text(format!("{};", header)),
+            StructFields::Defined(fields) => {
+                let mut docs = vec![];
+                for (field, ty) in fields {
+                    self.block_item(&mut docs, start(field.loc()));
+                    docs.push(text(format!("{}: {},", field, self.type_(ty))));
+                    docs.push(self.trailing(end(ty.loc)));
+                }
+                let block = self.block(docs, close(struct_def.loc), true);
+                concat(vec![text(header), text(" "), block])
+            }
+        }
+    }
+
+    fn function(&mut self, function: &Function) -> Doc {
+        let mut docs = vec![];
+        if !function.attributes.is_empty() {
+            let attributes = function
+                .attributes
+                .iter()
+                .map(|attribute| self.attribute(attribute))
+                .collect::<Vec<_>>();
+            docs.push(text(format!("#[{}]", attributes.join(", "))));
+            docs.push(Doc::HardLine);
+        }
+        let native = matches!(function.body.value, FunctionBody_::Native);
+        let public = matches!(function.visibility, FunctionVisibility::Public(_));
+        let mut header = vec![text(format!(
+            "{}{}fun {}",
+            if native { "native " } else { "" },
+            if public { "public " } else { "" },
+            function.name,
+        ))];
+        header.push(self.function_signature(&function.signature));
+        if !function.acquires.is_empty() {
+            let acquires = function
+                .acquires
+                .iter()
+                .map(|access| self.module_access(access))
+                .collect::<Vec<_>>();
+            // Only moved to the next line if it does not fit after the parameters.
+            header.push(group(concat(vec![
+                Doc::Line,
+                text(format!("acquires {}", acquires.join(", "))),
+            ])));
+        }
+        docs.push(concat(header));
+        match &function.body.value {
+            FunctionBody_::Native => docs.push(text(";")),
+            FunctionBody_::Defined(seq) => {
+                docs.push(text(" "));
+                docs.push(self.sequence(seq, close(function.body.loc)));
+            }
+        }
+        concat(docs)
+    }
+
+    // The type parameters, parameters and return type of a function, the latter omitted if it is
+    // the unit type.
+    fn function_signature(&mut self, signature: &FunctionSignature) -> Doc {
+        let mut docs = vec![text(self.type_parameters(&signature.type_parameters))];
+        let close_pos = signature
+            .parameters
+            .last()
+            .map_or(0, |(_, ty)| self.after_char(end(ty.loc), ')') - 1);
+        docs.push(self.list(
+            ("(", ")"),
+            &signature.parameters,
+            close_pos,
+            false,
+            |(var, ty)| (start(var.loc()), end(ty.loc)),
+            |p, (var, ty)| text(format!("{}: {}", var, p.type_(ty))),
+        ));
+        match &signature.return_type.value {
+            Type_::Unit => (),
+            _ => docs.push(text(format!(": {}", self.type_(&signature.return_type)))),
+        }
+        concat(docs)
+    }
+
+    fn attribute(&self, attribute: &Attribute) -> String {
+        match &attribute.value {
+            Attribute_::Name(name) => name.to_string(),
+            Attribute_::Assigned(name, value) => format!("{} = {}", name, self.value(value)),
+            Attribute_::Parameterized(name, attributes) => format!(
+                "{}({})",
+                name,
+                attributes
+                    .iter()
+                    .map(|attribute| self.attribute(attribute))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    //**********************************************************************************************
+    // Specification blocks
+    //**********************************************************************************************
+
+    fn spec_block(&mut self, spec: &SpecBlock) -> Doc {
+        let target = match &spec.value.target.value {
+            SpecBlockTarget_::Code => "spec ".to_string(),
+            SpecBlockTarget_::Module => "spec module ".to_string(),
+            SpecBlockTarget_::Function(name) => format!("spec fun {} ", name),
+            SpecBlockTarget_::Structure(name) => format!("spec struct {} ", name),
+            SpecBlockTarget_::Schema(name, type_parameters) => format!(
+                "spec schema {}{} ",
+                name,
+                self.type_parameters(type_parameters)
+            ),
+        };
+        let mut docs = vec![];
+        self.uses(&mut docs, &spec.value.uses);
+        for member in &spec.value.members {
+            self.block_item(&mut docs, start(member.loc));
+            docs.push(self.spec_block_member(member));
+            docs.push(self.trailing(end(member.loc)));
+        }
+        let block = self.block(docs, close(spec.loc), true);
+        concat(vec![text(target), block])
+    }
+
+    fn spec_block_member(&mut self, member: &SpecBlockMember) -> Doc {
+        match &member.value {
+            SpecBlockMember_::Condition { kind, exp } => {
+                use SpecConditionKind as K;
+                let keyword = match kind {
+                    K::Assert => "assert",
+                    K::Assume => "assume",
+                    K::Decreases => "decreases",
+                    K::AbortsIf => "aborts_if",
+                    K::SucceedsIf => "succeeds_if",
+                    K::Ensures => "ensures",
+                    K::Requires => "requires",
+                    K::RequiresModule => "requires module",
+                    K::Invariant => "invariant",
+                    K::InvariantUpdate => "invariant update",
+                    K::InvariantPack => "invariant pack",
+                    K::InvariantUnpack => "invariant unpack",
+                    K::InvariantModule => "invariant module",
+                };
+                concat(vec![
+                    text(format!("{} ", keyword)),
+                    self.exp(exp),
+                    text(";"),
+                ])
+            }
+            SpecBlockMember_::Function {
+                uninterpreted,
+                name,
+                signature,
+                body,
+            } => {
+                let native = !uninterpreted && matches!(body.value, FunctionBody_::Native);
+                let mut docs = vec![
+                    text(format!(
+                        "{}define {}",
+                        if native { "native " } else { "" },
+                        name
+                    )),
+                    self.function_signature(signature),
+                ];
+                if let Type_::Unit = signature.return_type.value {
+                    // The return type of a spec function is not optional.
+                    docs.push(text(": ()"))
+                }
+                match &body.value {
+                    FunctionBody_::Native => docs.push(text(";")),
+                    FunctionBody_::Defined(seq) => {
+                        docs.push(text(" "));
+                        docs.push(self.sequence(seq, close(body.loc)));
+                    }
+                }
+                concat(docs)
+            }
+            SpecBlockMember_::Variable {
+                is_global,
+                name,
+                type_parameters,
+                type_,
+            } => {
+                let scope = if *is_global {
+                    "global "
+                } else if SPEC_MEMBER_KEYWORDS.contains(&name.value.as_str()) {
+                    "local "
+                } else {
+                    ""
+                };
+                text(format!(
+                    "{}{}{}: {};",
+                    scope,
+                    name,
+                    self.type_parameters(type_parameters),
+                    self.type_(type_)
+                ))
+            }
+            SpecBlockMember_::Include { exp } => {
+                concat(vec![text("include "), self.exp(exp), text(";")])
+            }
+            SpecBlockMember_::Apply {
+                exp,
+                patterns,
+                exclusion_patterns,
+            } => {
+                let mut docs = vec![text("apply "), self.exp(exp)];
+                docs.push(text(format!(" to {}", self.apply_patterns(patterns))));
+                if !exclusion_patterns.is_empty() {
+                    docs.push(text(format!(
+                        " except {}",
+                        self.apply_patterns(exclusion_patterns)
+                    )));
+                }
+                docs.push(text(";"));
+                concat(docs)
+            }
+            SpecBlockMember_::Pragma { properties } => {
+                let properties = properties
+                    .iter()
+                    .map(|property| match &property.value.value {
+                        // The location of a number without suffix covers the whole property.
+                        Some(Spanned {
+                            value: Value_::U128(i),
+                            ..
+                        }) => format!("{} = {}", property.value.name, i),
+                        Some(value) => format!("{} = {}", property.value.name, self.value(value)),
+                        None => property.value.name.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                text(format!("pragma {};", properties.join(", ")))
+            }
+        }
+    }
+
+    fn apply_patterns(&self, patterns: &[SpecApplyPattern]) -> String {
+        patterns
+            .iter()
+            .map(|pattern| {
+                let visibility = match &pattern.value.visibility {
+                    Some(FunctionVisibility::Public(_)) => "public ",
+                    Some(FunctionVisibility::Internal) => "internal ",
+                    None => "",
+                };
+                let name = pattern
+                    .value
+                    .name_pattern
+                    .iter()
+                    .map(|fragment| match &fragment.value {
+                        SpecApplyFragment_::Wildcard => "*".to_string(),
+                        SpecApplyFragment_::NamePart(name) => name.to_string(),
+                    })
+                    .collect::<String>();
+                format!(
+                    "{}{}{}",
+                    visibility,
+                    name,
+                    self.type_parameters(&pattern.value.type_parameters)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    //**********************************************************************************************
+    // Sequences
+    //**********************************************************************************************
+
+    fn sequence(&mut self, seq: &Sequence, close_pos: usize) -> Doc {
+        let (uses, items, _, last) = seq;
+        let mut docs = vec![];
+        self.uses(&mut docs, uses);
+        for item in items {
+            self.block_item(&mut docs, start(item.loc));
+            docs.push(self.sequence_item(item));
+            docs.push(text(";"));
+            docs.push(self.trailing(end(item.loc)));
+        }
+        if let Some(e) = &**last {
+            self.block_item(&mut docs, start(e.loc));
+            docs.push(self.exp(e));
+            docs.push(self.trailing(end(e.loc)));
+        }
+        self.block(docs, close_pos, true)
+    }
+
+    fn sequence_item(&mut self, item: &SequenceItem) -> Doc {
+        match &item.value {
+            SequenceItem_::Seq(e) => self.exp(e),
+            SequenceItem_::Declare(binds, ty) => concat(vec![
+                text("let "),
+                self.bind_list(binds),
+                self.type_annotation(ty),
+            ]),
+            SequenceItem_::Bind(binds, ty, e) => concat(vec![
+                text("let "),
+                self.bind_list(binds),
+                self.type_annotation(ty),
+                text(" = "),
+                self.exp(e),
+            ]),
+        }
+    }
+
+    fn type_annotation(&self, ty: &Option<Type>) -> Doc {
+        match ty {
+            Some(ty) => text(format!(": {}", self.type_(ty))),
+            None => Doc::Nil,
+        }
+    }
+
+    fn bind_list(&mut self, binds: &BindList) -> Doc {
+        match binds.value.as_slice() {
+            [bind] => self.bind(bind),
+            binds_ => self.list(
+                ("(", ")"),
+                binds_,
+                close(binds.loc),
+                false,
+                |bind| (start(bind.loc), end(bind.loc)),
+                |p, bind| p.bind(bind),
+            ),
+        }
+    }
+
+    fn bind(&mut self, bind: &Bind) -> Doc {
+        match &bind.value {
+            Bind_::Var(var) => text(var.to_string()),
+            Bind_::Unpack(access, tys, fields) => concat(vec![
+                text(format!(
+                    "{}{} ",
+                    self.module_access(access),
+                    self.type_arguments(tys)
+                )),
+                self.list(
+                    ("{", "}"),
+                    fields,
+                    close(bind.loc),
+                    true,
+                    |(field, bind)| (start(field.loc()), end(bind.loc)),
+                    |p, (field, bind)| match &bind.value {
+                        Bind_::Var(var) if var.value() == field.value() => text(var.to_string()),
+                        _ => concat(vec![text(format!("{}: ", field)), p.bind(bind)]),
+                    },
+                ),
+            ]),
+        }
+    }
+
+    //**********************************************************************************************
+    // Expressions
+    //**********************************************************************************************
+
+    fn exp(&mut self, e: &Exp) -> Doc {
+        let leading = self.leading(start(e.loc));
+        concat(vec![leading, self.exp_(e)])
+    }
+
+    // The expression in parentheses if it binds looser than `min_level`.
+    fn operand(&mut self, e: &Exp, min_level: Level) -> Doc {
+        if level(e) < min_level {
+            concat(vec![text("("), self.exp(e), text(")")])
+        } else {
+            self.exp(e)
+        }
+    }
+
+    fn exp_(&mut self, e: &Exp) -> Doc {
+        use Exp_ as E;
+        match &e.value {
+            E::Value(value) => text(self.value(value)),
+            E::InferredNum(i) => text(i.to_string()),
+            E::Move(var) => text(format!("move {}", var)),
+            E::Copy(var) => text(format!("copy {}", var)),
+            E::Name(access, tys) => text(format!(
+                "{}{}",
+                self.module_access(access),
+                self.type_arguments(tys)
+            )),
+            E::Call(access, tys, args) => match quantifier(e) {
+                Some(q) => self.quantifier(q),
+                None => concat(vec![
+                    text(format!(
+                        "{}{}",
+                        self.module_access(access),
+                        self.type_arguments(tys)
+                    )),
+                    self.list(
+                        ("(", ")"),
+                        &args.value,
+                        close(args.loc),
+                        false,
+                        |e| (start(e.loc), end(e.loc)),
+                        |p, e| p.exp(e),
+                    ),
+                ]),
+            },
+            E::Pack(access, tys, fields) => concat(vec![
+                text(format!(
+                    "{}{} ",
+                    self.module_access(access),
+                    self.type_arguments(tys)
+                )),
+                self.list(
+                    ("{", "}"),
+                    fields,
+                    close(e.loc),
+                    true,
+                    |(field, e)| (start(field.loc()), end(e.loc)),
+                    |p, (field, e)| match &e.value {
+                        E::Name(
+                            Spanned {
+                                value: ModuleAccess_::Name(name),
+                                ..
+                            },
+                            None,
+                        ) if name.value == field.value() => text(field.to_string()),
+                        _ => concat(vec![text(format!("{}: ", field)), p.exp(e)]),
+                    },
+                ),
+            ]),
+            E::IfElse(cond, if_true, if_false) => self.if_else(cond, if_true, if_false),
+            E::While(cond, body) => concat(vec![
+                text("while ("),
+                self.exp(cond),
+                text(") "),
+                self.exp(body),
+            ]),
+            E::Loop(body) => concat(vec![text("loop "), self.exp(body)]),
+            E::Block(seq) => self.sequence(seq, close(e.loc)),
+            // `||` is the boolean operator.
+            E::Lambda(binds, body) if binds.value.is_empty() => {
+                concat(vec![text("| | "), self.exp(body)])
+            }
+            E::Lambda(binds, body) => concat(vec![
+                self.list(
+                    ("|", "|"),
+                    &binds.value,
+                    close(binds.loc),
+                    false,
+                    |bind| (start(bind.loc), end(bind.loc)),
+                    |p, bind| p.bind(bind),
+                ),
+                text(" "),
+                self.exp(body),
+            ]),
+            E::ExpList(es) => self.list(
+                ("(", ")"),
+                es,
+                close(e.loc),
+                false,
+                |e| (start(e.loc), end(e.loc)),
+                |p, e| p.exp(e),
+            ),
+            E::Unit => text("()"),
+            E::Assign(lhs, rhs) => concat(vec![
+                self.operand(lhs, Level::Unary),
+                text(" = "),
+                self.exp(rhs),
+            ]),
+            E::Return(None) => text("return"),
+            E::Return(Some(e)) => concat(vec![text("return "), self.exp(e)]),
+            E::Abort(e) => concat(vec![text("abort "), self.exp(e)]),
+            E::Break => text("break"),
+            E::Continue => text("continue"),
+            E::Dereference(e) => concat(vec![text("*"), self.operand(e, Level::Unary)]),
+            E::UnaryExp(op, e) => {
+                concat(vec![text(op.value.symbol()), self.operand(e, Level::Unary)])
+            }
+            E::BinopExp(..) => self.binop_chain(e),
+            E::Borrow(mut_, inner) => {
+                let prefix = match (mut_, &inner.value) {
+                    (true, _) => "&mut ",
+                    // `&&` is the boolean operator.
+                    (false, E::Borrow(..)) => "& ",
+                    (false, _) => "&",
+                };
+                concat(vec![text(prefix), self.operand(inner, Level::Unary)])
+            }
+            E::Dot(e, name) => concat(vec![
+                self.operand(e, Level::Term),
+                text(format!(".{}", name)),
+            ]),
+            E::Index(e, index) => concat(vec![
+                self.operand(e, Level::Term),
+                text("["),
+                self.exp(index),
+                text("]"),
+            ]),
+            E::Cast(e, ty) => concat(vec![
+                text("("),
+                self.exp(e),
+                text(format!(" as {})", self.type_(ty))),
+            ]),
+            E::Annotate(e, ty) => concat(vec![
+                text("("),
+                self.exp(e),
+                text(format!(": {})", self.type_(ty))),
+            ]),
+            E::Spec(spec) => self.spec_block(spec),
+            E::UnresolvedError => unreachable!("the parser does not produce unresolved errors"),
+        }
+    }
+
+    fn if_else(&mut self, cond: &Exp, if_true: &Exp, if_false: &Option<Box<Exp>>) -> Doc {
+        let mut docs = vec![text("if ("), self.exp(cond), text(")")];
+        // An `if` without `else` in the true branch would take the `else` of this one.
+        let if_true_doc = if if_false.is_some() && ends_with_if(if_true) {
+            concat(vec![text("("), self.exp(if_true), text(")")])
+        } else {
+            self.exp(if_true)
+        };
+        let is_block = |e: &Exp| matches!(e.value, Exp_::Block(_));
+        if is_block(if_true) {
+            docs.push(text(" "));
+            docs.push(if_true_doc);
+            if let Some(if_false) = if_false {
+                docs.push(text(" else "));
+                docs.push(self.exp(if_false));
+            }
+            return concat(docs);
+        }
+        docs.push(nest(concat(vec![Doc::Line, if_true_doc])));
+        if let Some(if_false) = if_false {
+            docs.push(Doc::Line);
+            if is_block(if_false) || matches!(if_false.value, Exp_::IfElse(..)) {
+                docs.push(text("else "));
+                docs.push(self.exp(if_false));
+            } else {
+                docs.push(text("else"));
+                docs.push(nest(concat(vec![Doc::Line, self.exp(if_false)])));
+            }
+        }
+        group(concat(docs))
+    }
+
+    // A chain of binary operators of the same precedence, broken before the operators if it does
+    // not fit.
+    fn binop_chain(&mut self, e: &Exp) -> Doc {
+        let prec = match &e.value {
+            Exp_::BinopExp(_, op, _) => precedence(op.value),
+            _ => unreachable!("binop_chain called on {:?}", e),
+        };
+        // Operators of the same precedence associate to the left.
+        let mut operands = vec![];
+        let mut lhs = e;
+        while let Exp_::BinopExp(l, op, r) = &lhs.value {
+            if precedence(op.value) != prec {
+                break;
+            }
+            operands.push((op.value, &**r));
+            lhs = l;
+        }
+        let first = self.operand(lhs, Level::Binop(prec));
+        let mut docs = vec![];
+        for (op, rhs) in operands.into_iter().rev() {
+            if op == BinOp_::Range {
+                // Ranges are written without spaces, like `0..len(v)`.
+                docs.push(text(op.symbol()));
+            } else {
+                docs.push(Doc::Line);
+                docs.push(text(format!("{} ", op.symbol())));
+            }
+            docs.push(self.operand(rhs, Level::Binop(prec + 1)));
+        }
+        group(concat(vec![first, nest(concat(docs))]))
+    }
+
+    fn quantifier(&mut self, mut q: Quantifier) -> Doc {
+        let mut docs = vec![text(if q.forall { "forall " } else { "exists " })];
+        loop {
+            docs.push(text(q.var.to_string()));
+            match q.ty {
+                Some(ty) => docs.push(text(format!(": {}", self.type_(ty)))),
+                None => {
+                    docs.push(text(" in "));
+                    docs.push(self.exp(q.range));
+                }
+            }
+            match quantifier(q.body) {
+                Some(inner) if inner.forall == q.forall => {
+                    docs.push(text(", "));
+                    q = inner;
+                }
+                _ => break,
+            }
+        }
+        // The parser turns `where c: e` into `c ==> e` for `forall`, and `c && e` for `exists`,
+        // with the location of the condition for the operator.
+        let where_op = if q.forall {
+            BinOp_::Implies
+        } else {
+            BinOp_::And
+        };
+        match &q.body.value {
+            Exp_::BinopExp(cond, op, body) if op.value == where_op && op.loc == cond.loc => {
+                docs.push(text(" where "));
+                docs.push(self.exp(cond));
+                docs.push(text(":"));
+                docs.push(nest(concat(vec![Doc::Line, self.exp(body)])));
+            }
+            _ => {
+                docs.push(text(":"));
+                docs.push(nest(concat(vec![Doc::Line, self.exp(q.body)])));
+            }
+        }
+        group(concat(docs))
+    }
+
+    //**********************************************************************************************
+    // Names, types, and values
+    //**********************************************************************************************
+
+    fn module_ident(&self, ident: &ModuleIdent) -> String {
+        format!(
+            "{}::{}",
+            self.token_at(start(ident.loc())),
+            ident.0.value.name
+        )
+    }
+
+    fn module_access(&self, access: &ModuleAccess) -> String {
+        match &access.value {
+            ModuleAccess_::Name(name) => name.to_string(),
+            ModuleAccess_::ModuleAccess(module, name) => format!("{}::{}", module, name),
+            ModuleAccess_::QualifiedModuleAccess(ident, name) => {
+                format!("{}::{}", self.module_ident(ident), name)
+            }
+        }
+    }
+
+    fn type_(&self, ty: &Type) -> String {
+        match &ty.value {
+            Type_::Apply(access, tys) if tys.is_empty() => self.module_access(access),
+            Type_::Apply(access, tys) => {
+                format!("{}<{}>", self.module_access(access), self.types(tys))
+            }
+            Type_::Ref(true, ty) => format!("&mut {}", self.type_(ty)),
+            // `&&` is the boolean operator.
+            Type_::Ref(false, inner) => match inner.value {
+                Type_::Ref(..) => format!("& {}", self.type_(inner)),
+                _ => format!("&{}", self.type_(inner)),
+            },
+            Type_::Fun(args, result) if args.is_empty() => format!("| | {}", self.type_(result)),
+            Type_::Fun(args, result) => format!("|{}| {}", self.types(args), self.type_(result)),
+            Type_::Unit => "()".to_string(),
+            Type_::Multiple(tys) => format!("({})", self.types(tys)),
+        }
+    }
+
+    fn types(&self, tys: &[Type]) -> String {
+        tys.iter()
+            .map(|ty| self.type_(ty))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn type_arguments(&self, tys: &Option<Vec<Type>>) -> String {
+        match tys {
+            Some(tys) => format!("<{}>", self.types(tys)),
+            None => "".to_string(),
+        }
+    }
+
+    fn type_parameters(&self, type_parameters: &[(Name, Kind)]) -> String {
+        if type_parameters.is_empty() {
+            return "".to_string();
+        }
+        let type_parameters = type_parameters
+            .iter()
+            .map(|(name, kind)| match kind.value {
+                Kind_::Unknown => name.to_string(),
+                Kind_::Resource => format!("{}: {}", name, Kind_::RESOURCE_CONSTRAINT),
+                Kind_::Affine | Kind_::Copyable => format!("{}: {}", name, Kind_::VALUE_CONSTRAINT),
+            })
+            .collect::<Vec<_>>();
+        format!("<{}>", type_parameters.join(", "))
+    }
+
+    fn value(&self, value: &Value) -> String {
+        match &value.value {
+            // Numbers and addresses are kept as written, e.g. with leading zeros.
+            Value_::Address(_) | Value_::U8(_) | Value_::U64(_) | Value_::U128(_) => {
+                self.token_at(start(value.loc)).to_string()
+            }
+            Value_::Bool(b) => b.to_string(),
+            Value_::HexString(s) => format!("x\"{}\"", s),
+            Value_::ByteString(s) => format!("b\"{}\"", s),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_temppath::TempPath;
+use move_fmt::{format_file, format_string, FormatConfig};
+use move_lang::{
+    move_compile_no_report, parse_file_string,
+    shared::{ast_debug, Address},
+    strip_comments_and_verify,
+};
+use std::{collections::BTreeMap, fs, path::Path};
+
+const MODULE: &str = r#"address 0x1 {
+module M {
+    use 0x1::Vector;
+    // Counts things.
+    resource struct Counter { value: u64, items: vector<u64> }
+
+
+    public fun bump(c: &mut Counter, by: u64): u64 { // adds `by`
+        c.value = c.value + by;   // may overflow
+        if (c.value > 10) c.value = 0 else c.value = (c.value + 1) * 2;
+        Vector::push_back(&mut c.items, /* the new value */ c.value);
+        c.value
+    }
+
+    spec fun bump {
+        aborts_if c.value + by > max_u64();
+        ensures forall i in 0..len(c.items) where i > 0: c.items[i] >= 0;
+    }
+}
+}
+"#;
+
+const SCRIPT: &str = r#"script {
+use 0x1::LibraAccount;
+fun main(account: &signer, payee: address, amount: u64) {
+  // Pays from the sender.
+  if (amount > 0) LibraAccount::pay_from<LBR::LBR>(account, payee, amount, x"", x"");
+}
+}
+"#;
+
+fn parse(fname: &'static str, source: &str) -> String {
+    let (stripped, _) = strip_comments_and_verify(fname, source).unwrap();
+    let (defs, _) = parse_file_string(fname, &stripped, BTreeMap::new()).unwrap();
+    defs.iter().map(ast_debug::display).collect()
+}
+
+fn compile(files: Vec<String>) -> Vec<(String, Vec<u8>)> {
+    let (_, units) = move_compile_no_report(&files, &[], Some(Address::LIBRA_CORE)).unwrap();
+    let mut units = units
+        .unwrap()
+        .into_iter()
+        .map(|unit| (unit.name(), unit.serialize()))
+        .collect::<Vec<_>>();
+    units.sort();
+    units
+}
+
+#[test]
+fn format_module() {
+    let formatted = format_string("M.move", MODULE, &FormatConfig::default()).unwrap();
+    assert_eq!(
+        formatted,
+        r#"address 0x1 {
+module M {
+    use 0x1::Vector;
+    // Counts things.
+    resource struct Counter {
+        value: u64,
+        items: vector<u64>,
+    }
+
+    public fun bump(c: &mut Counter, by: u64): u64 {
+        // adds `by`
+        c.value = c.value + by; // may overflow
+        if (c.value > 10) c.value = 0 else c.value = (c.value + 1) * 2;
+        Vector::push_back(&mut c.items, /* the new value */ c.value);
+        c.value
+    }
+
+    spec fun bump {
+        aborts_if c.value + by > max_u64();
+        ensures forall i in 0..len(c.items) where i > 0: c.items[i] >= 0;
+    }
+}
+}
+"#
+    );
+}
+
+#[test]
+fn format_with_config() {
+    let config = FormatConfig {
+        indent_width: 2,
+        max_width: 40,
+    };
+    let formatted = format_string("S.move", SCRIPT, &config).unwrap();
+    assert_eq!(
+        formatted,
+        r#"script {
+  use 0x1::LibraAccount;
+  fun main(
+    account: &signer,
+    payee: address,
+    amount: u64,
+  ) {
+    // Pays from the sender.
+    if (amount > 0)
+      LibraAccount::pay_from<LBR::LBR>(
+        account,
+        payee,
+        amount,
+        x"",
+        x"",
+      );
+  }
+}
+"#
+    );
+    assert_eq!(
+        format_string("S.move", &formatted, &config).unwrap(),
+        formatted
+    );
+}
+
+#[test]
+fn format_invalid_file() {
+    assert!(format_string("M.move", "module M { fun }", &FormatConfig::default()).is_err());
+}
+
+#[test]
+fn format_stdlib() {
+    let config = FormatConfig::default();
+    let dir = TempPath::new();
+    dir.create_as_dir().unwrap();
+    let mut formatted_files = vec![];
+    for file in stdlib::stdlib_files() {
+        let fname: &'static str = Box::leak(file.clone().into_boxed_str());
+        let source = fs::read_to_string(&file).unwrap();
+        let formatted = format_file(&file, &config).unwrap();
+        assert_eq!(
+            format_string(fname, &formatted, &config).unwrap(),
+            formatted,
+            "formatting {} twice changes it",
+            file
+        );
+        assert_eq!(
+            parse(fname, &source),
+            parse(fname, &formatted),
+            "formatting {} changes its syntax tree",
+            file
+        );
+        let path = dir.path().join(Path::new(&file).file_name().unwrap());
+        fs::write(&path, formatted).unwrap();
+        formatted_files.push(path.to_string_lossy().into_owned());
+    }
+    assert_eq!(compile(stdlib::stdlib_files()), compile(formatted_files));
+}
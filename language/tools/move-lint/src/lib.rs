@@ -0,0 +1,132 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! Runs the lints of the Move compiler, see `move_lang::lint`, and reports their findings in a
+//! machine readable form.
+
+use anyhow::{bail, Result};
+use move_lang::{
+    errors::{Errors, FilesSourceText},
+    lint::{default_passes, Finding, LintConfig, LintLevel, LintPass},
+    move_lint_no_report,
+    shared::Address,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A lint configuration file, like
+///
+/// ```toml
+/// [lints]
+/// magic_abort_code = "deny"
+///
+/// [modules."0x1::Genesis"]
+/// vector_copy = "allow"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    /// Levels by lint name, for all modules and scripts.
+    #[serde(default)]
+    lints: BTreeMap<String, String>,
+    /// Levels by lint name, by module or script.
+    #[serde(default)]
+    modules: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// Parses a lint configuration file for `passes`, checking that it only names lints among them.
+pub fn parse_config(text: &str, passes: &[Box<dyn LintPass>]) -> Result<LintConfig> {
+    let file: ConfigFile = toml::from_str(text)?;
+    let levels = |levels: BTreeMap<String, String>| {
+        levels
+            .into_iter()
+            .map(|(lint, level)| {
+                if !passes.iter().any(|pass| pass.name() == lint) {
+                    bail!("Unknown lint '{}'", lint)
+                }
+                match level.parse::<LintLevel>() {
+                    Ok(level) => Ok((lint, level)),
+                    Err(msg) => bail!("{}", msg),
+                }
+            })
+            .collect::<Result<BTreeMap<_, _>>>()
+    };
+    let mut config = LintConfig {
+        levels: levels(file.lints)?,
+        ..LintConfig::default()
+    };
+    for (module, module_levels) in file.modules {
+        config.module_levels.insert(module, levels(module_levels)?);
+    }
+    Ok(config)
+}
+
+/// A finding, as reported.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub lint: String,
+    pub level: String,
+    pub module: String,
+    pub function: String,
+    pub file: String,
+    /// The one based line and column of the start of the code found.
+    pub line: usize,
+    pub column: usize,
+    /// The one based line and column just past the end of the code found.
+    pub end_line: usize,
+    pub end_column: usize,
+    pub message: String,
+}
+
+impl Report {
+    pub fn new(files: &FilesSourceText, finding: &Finding) -> Self {
+        let file = finding.loc.file();
+        let text = files.get(file).map_or("", |text| text.as_str());
+        let (line, column) = line_and_column(text, finding.loc.span().start().to_usize());
+        let (end_line, end_column) = line_and_column(text, finding.loc.span().end().to_usize());
+        Self {
+            lint: finding.lint.to_string(),
+            level: finding.level.to_string(),
+            module: finding.module.clone(),
+            function: finding.function.clone(),
+            file: file.to_string(),
+            line,
+            column,
+            end_line,
+            end_column,
+            message: finding.message.clone(),
+        }
+    }
+
+    pub fn is_denied(&self) -> bool {
+        self.level == LintLevel::DENY
+    }
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+/// Lints `targets`, returning the reports of the findings in source order, or the errors that
+/// prevent linting.
+pub fn lint(
+    targets: &[String],
+    deps: &[String],
+    sender: Address,
+    config: &LintConfig,
+) -> Result<(FilesSourceText, Result<Vec<Report>, Errors>)> {
+    let passes = default_passes();
+    let (files, res) = move_lint_no_report(targets, deps, Some(sender), &passes, config)?;
+    let res = res.map(|findings| {
+        findings
+            .iter()
+            .map(|finding| Report::new(&files, finding))
+            .collect()
+    });
+    Ok((files, res))
+}
@@ -0,0 +1,69 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use move_lang::{command_line as cli, errors, lint::default_passes, shared::Address};
+use move_lint::{lint, parse_config};
+use std::{fs, process};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move Lint",
+    about = "Lint Move source files, printing the findings as JSON"
+)]
+struct Args {
+    /// The source files, or directories of them, to lint
+    #[structopt(name = "PATH_TO_SOURCE_FILE", required = true)]
+    pub source_files: Vec<String>,
+
+    /// The library files needed as dependencies, besides the standard library
+    #[structopt(
+        name = "PATH_TO_DEPENDENCY_FILE",
+        short = cli::DEPENDENCY_SHORT,
+        long = cli::DEPENDENCY,
+    )]
+    pub dependencies: Vec<String>,
+
+    /// Do not depend on the standard library, as when linting it
+    #[structopt(long = "no-stdlib")]
+    pub no_stdlib: bool,
+
+    /// The sender address for modules and scripts
+    #[structopt(
+        name = "ADDRESS",
+        short = cli::SENDER_SHORT,
+        long = cli::SENDER,
+        default_value = "0x1",
+        parse(try_from_str = cli::parse_address)
+    )]
+    pub sender: Address,
+
+    /// A TOML file setting the levels of the lints, for all modules or by module
+    #[structopt(long = "config")]
+    pub config: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let config = match &args.config {
+        Some(path) => parse_config(&fs::read_to_string(path)?, &default_passes())?,
+        None => Default::default(),
+    };
+    let mut dependencies = args.dependencies;
+    if !args.no_stdlib {
+        dependencies.extend(stdlib::stdlib_files());
+    }
+    let (files, res) = lint(&args.source_files, &dependencies, args.sender, &config)?;
+    let reports = match res {
+        Ok(reports) => reports,
+        Err(errors) => errors::report_errors(files, errors),
+    };
+    println!("{}", serde_json::to_string_pretty(&reports)?);
+    if reports.iter().any(|report| report.is_denied()) {
+        process::exit(1)
+    }
+    Ok(())
+}
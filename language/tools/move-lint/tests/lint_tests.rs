@@ -0,0 +1,117 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_temppath::TempPath;
+use move_lang::{
+    lint::{default_passes, LintConfig},
+    shared::Address,
+};
+use move_lint::{lint, parse_config, Report};
+use std::fs;
+
+const MODULE: &str = r#"address 0x1 {
+module M {
+    const EZERO: u64 = 0;
+
+    public fun keep(_account: &signer, x: u64): u64 {
+        if (x == 0) abort 7;
+        assert(x > 1, EZERO);
+        x
+    }
+
+    public fun copies(v: vector<u64>): (vector<u64>, vector<u64>) {
+        (copy v, v)
+    }
+
+    public fun arith(a: u64, b: u64): u64 {
+        let c = a + b;
+        let i = 0;
+        while (i < b) { i = i + 1 };
+        c
+    }
+}
+}
+"#;
+
+fn lint_module(config: &LintConfig) -> Vec<Report> {
+    let file = TempPath::new();
+    fs::write(file.path(), MODULE).unwrap();
+    let targets = vec![file.path().to_str().unwrap().to_string()];
+    let (_, res) = lint(&targets, &[], Address::LIBRA_CORE, config).unwrap();
+    res.unwrap()
+}
+
+fn summary(reports: &[Report]) -> Vec<(&str, &str, usize)> {
+    reports
+        .iter()
+        .map(|report| (report.lint.as_str(), report.level.as_str(), report.line))
+        .collect()
+}
+
+#[test]
+fn default_lints() {
+    let reports = lint_module(&LintConfig::default());
+    assert_eq!(
+        summary(&reports),
+        vec![
+            ("unused_signer", "warn", 5),
+            ("magic_abort_code", "warn", 6),
+            ("vector_copy", "warn", 12),
+            ("unchecked_arithmetic", "warn", 16),
+        ]
+    );
+    let report = &reports[1];
+    assert_eq!(report.module, "0x1::M");
+    assert_eq!(report.function, "keep");
+    assert_eq!((report.column, report.end_column), (27, 28));
+    assert!(report.message.contains("'7'"), "{}", report.message);
+}
+
+#[test]
+fn configured_lints() {
+    let config = parse_config(
+        r#"
+        [lints]
+        magic_abort_code = "deny"
+        unchecked_arithmetic = "allow"
+
+        [modules."0x1::M"]
+        vector_copy = "allow"
+        "#,
+        &default_passes(),
+    )
+    .unwrap();
+    let reports = lint_module(&config);
+    assert_eq!(
+        summary(&reports),
+        vec![
+            ("unused_signer", "warn", 5),
+            ("magic_abort_code", "deny", 6)
+        ]
+    );
+    assert!(reports[1].is_denied());
+}
+
+#[test]
+fn invalid_config() {
+    let passes = default_passes();
+    assert!(parse_config("[lints]\nunused_local = \"deny\"\n", &passes).is_err());
+    assert!(parse_config("[lints]\nvector_copy = \"error\"\n", &passes).is_err());
+    assert!(parse_config("[modules.\"0x1::M\"]\nvector_copy = \"error\"\n", &passes).is_err());
+    assert!(parse_config("[other]\n", &passes).is_err());
+}
+
+#[test]
+fn stdlib_lints() {
+    let (_, res) = lint(
+        &stdlib::stdlib_files(),
+        &[],
+        Address::LIBRA_CORE,
+        &LintConfig::default(),
+    )
+    .unwrap();
+    let reports = res.unwrap();
+    assert!(reports
+        .iter()
+        .all(|report| report.module.starts_with("0x1::")));
+}
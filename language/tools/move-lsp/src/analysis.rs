@@ -0,0 +1,124 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs the compiler on a workspace and gathers what the server answers requests from.
+
+use crate::{
+    index::{FileSpan, IndexBuilder, SymbolIndex},
+    protocol::{
+        path_to_uri, Diagnostic, DiagnosticRelatedInformation, Location, Range, SEVERITY_ERROR,
+    },
+};
+use anyhow::Result;
+use move_ir_types::location::*;
+use move_lang::{
+    errors::{Errors, FilesSourceText},
+    move_check_to_typing_no_report,
+    shared::Address,
+};
+use spec_lang::run_spec_lang_compiler;
+use std::collections::BTreeMap;
+
+/// The result of compiling a workspace.
+pub struct Analysis {
+    /// The sources compiled, which requests refer to by position.
+    pub files: FilesSourceText,
+    /// The diagnostics of the files compiled that have any.
+    pub diagnostics: BTreeMap<&'static str, Vec<Diagnostic>>,
+    pub index: SymbolIndex,
+}
+
+impl Analysis {
+    /// Compiles `targets` against `deps`. Specifications are only checked, and indexed, if the
+    /// Move code type checks.
+    pub fn run(targets: &[String], deps: &[String], sender: Address) -> Result<Self> {
+        let (files, prog_opt, errors) =
+            move_check_to_typing_no_report(targets, deps, Some(sender))?;
+        let mut analysis = Self {
+            diagnostics: BTreeMap::new(),
+            files: FilesSourceText::new(),
+            index: SymbolIndex::default(),
+        };
+        let mut builder = IndexBuilder::new(&files);
+        if let Some(prog) = &prog_opt {
+            builder.program(prog);
+        }
+        if prog_opt.is_some() && errors.is_empty() {
+            // This also runs the passes after typing, so reports the errors they find.
+            let env =
+                run_spec_lang_compiler(targets.to_vec(), deps.to_vec(), Some(&sender.to_string()))?;
+            for (loc, message) in env.get_errors() {
+                if let Some((file, _)) = env.get_position(loc.clone()) {
+                    let start = loc.span().start().to_usize();
+                    let end = loc.span().end().to_usize();
+                    analysis.add_diagnostic(&files, &file, start, end, message, vec![]);
+                }
+            }
+            if !env.has_errors() {
+                builder.specs(&env);
+            }
+        }
+        analysis.add_errors(&files, errors);
+        analysis.index = builder.finish();
+        analysis.files = files;
+        Ok(analysis)
+    }
+
+    fn add_errors(&mut self, files: &FilesSourceText, errors: Errors) {
+        for error in errors {
+            let mut labels = error.into_iter();
+            let (loc, message) = match labels.next() {
+                Some(primary) => primary,
+                None => continue,
+            };
+            let related = labels
+                .filter_map(|(loc, message)| {
+                    let location = location(files, loc.file(), span_bounds(loc))?;
+                    Some(DiagnosticRelatedInformation { location, message })
+                })
+                .collect();
+            let (start, end) = span_bounds(loc);
+            self.add_diagnostic(files, loc.file(), start, end, message, related);
+        }
+    }
+
+    fn add_diagnostic(
+        &mut self,
+        files: &FilesSourceText,
+        file: &str,
+        start: usize,
+        end: usize,
+        message: String,
+        related_information: Vec<DiagnosticRelatedInformation>,
+    ) {
+        if let Some((file, text)) = files.get_key_value(file) {
+            self.diagnostics
+                .entry(*file)
+                .or_insert_with(Vec::new)
+                .push(Diagnostic {
+                    range: Range::of_span(text, start, end),
+                    severity: SEVERITY_ERROR,
+                    source: "move".to_string(),
+                    message,
+                    related_information,
+                })
+        }
+    }
+
+    /// Returns the location in a document of a span of a compiled file.
+    pub fn location(&self, span: FileSpan) -> Option<Location> {
+        location(&self.files, span.file, (span.start, span.end))
+    }
+}
+
+fn span_bounds(loc: Loc) -> (usize, usize) {
+    (loc.span().start().to_usize(), loc.span().end().to_usize())
+}
+
+fn location(files: &FilesSourceText, file: &str, (start, end): (usize, usize)) -> Option<Location> {
+    let text = files.get(file)?;
+    Some(Location {
+        uri: path_to_uri(file),
+        range: Range::of_span(text, start, end),
+    })
+}
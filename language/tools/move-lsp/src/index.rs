@@ -0,0 +1,910 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! An index of where the symbols of a program are defined and used, and of the types of its
+//! expressions, built from the typed AST of `move-lang` and from the specifications resolved by
+//! `spec-lang`.
+
+use move_ir_types::location::*;
+use move_lang::{
+    errors::FilesSourceText,
+    naming::ast::{StructFields, TypeName_, Type_},
+    parser::ast::{FunctionVisibility, ModuleIdent, StructName, Var},
+    shared::Identifier,
+    typing::ast::{
+        BuiltinFunction_, Exp, ExpListItem, Function, FunctionBody_, LValue, LValueList, LValue_,
+        ModuleDefinition, Program, Script, Sequence, SequenceItem_, UnannotatedExp_,
+    },
+};
+use spec_lang::{
+    ast::{Exp as SpecExp, Operation, Spec},
+    env::{GlobalEnv, Loc as SpecLoc, ModuleEnv},
+    ty::{Type as SpecType, TypeDisplayContext},
+};
+use std::collections::BTreeMap;
+
+/// A range of bytes of a source file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FileSpan {
+    pub file: &'static str,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl FileSpan {
+    fn contains(&self, file: &str, offset: usize) -> bool {
+        self.file == file && self.start <= offset && offset <= self.end
+    }
+
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// Something with a name that can be defined in one place and used in others. Module members
+/// are identified by the module they are declared in, written like `0x1::M`, and local variables
+/// by where they are declared.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Symbol {
+    Struct(String, String),
+    Field(String, String, String),
+    Function(String, String),
+    Constant(String, String),
+    SpecFun(String, String),
+    SpecVar(String, String),
+    Local(FileSpan),
+}
+
+/// The kinds of members of a module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemberKind {
+    Struct,
+    Function,
+    Constant,
+    SpecFun,
+    SpecVar,
+}
+
+/// A member of a module, for completion.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub kind: MemberKind,
+    pub description: String,
+}
+
+/// An expression and its type.
+#[derive(Clone, Debug)]
+struct TypedSpan {
+    span: FileSpan,
+    ty: String,
+    /// The module and name of the struct the expression is a value of, or a reference to.
+    struct_: Option<(String, String)>,
+}
+
+#[derive(Debug, Default)]
+pub struct SymbolIndex {
+    definitions: BTreeMap<Symbol, FileSpan>,
+    descriptions: BTreeMap<Symbol, String>,
+    occurrences: Vec<(FileSpan, Symbol)>,
+    expressions: Vec<TypedSpan>,
+    /// The members of each module, by the name of the module without its address.
+    members: BTreeMap<String, Vec<Member>>,
+    /// The fields of each struct, with a description of each.
+    fields: BTreeMap<(String, String), Vec<(String, String)>>,
+}
+
+impl SymbolIndex {
+    /// Returns the symbol defined or used at `offset` in `file`.
+    pub fn symbol_at(&self, file: &str, offset: usize) -> Option<&Symbol> {
+        self.occurrences
+            .iter()
+            .filter(|(span, _)| span.contains(file, offset))
+            .min_by_key(|(span, _)| span.len())
+            .map(|(_, symbol)| symbol)
+    }
+
+    pub fn definition(&self, symbol: &Symbol) -> Option<FileSpan> {
+        self.definitions.get(symbol).copied()
+    }
+
+    /// Returns where `symbol` is used, and also where it is defined if `include_definition`.
+    pub fn references(&self, symbol: &Symbol, include_definition: bool) -> Vec<FileSpan> {
+        let definition = self.definition(symbol);
+        self.occurrences
+            .iter()
+            .filter(|(span, s)| s == symbol && (include_definition || definition != Some(*span)))
+            .map(|(span, _)| *span)
+            .collect()
+    }
+
+    /// Describes the symbol at `offset` in `file`, or else the type of the innermost expression
+    /// there, together with the span described.
+    pub fn hover(&self, file: &str, offset: usize) -> Option<(FileSpan, String)> {
+        let symbol = self
+            .occurrences
+            .iter()
+            .filter(|(span, _)| span.contains(file, offset))
+            .min_by_key(|(span, _)| span.len());
+        if let Some((span, symbol)) = symbol {
+            if let Some(description) = self.descriptions.get(symbol) {
+                return Some((*span, description.clone()));
+            }
+        }
+        self.expressions
+            .iter()
+            .filter(|e| e.span.contains(file, offset))
+            .min_by_key(|e| e.span.len())
+            .map(|e| (e.span, e.ty.clone()))
+    }
+
+    /// Returns the members of the modules named `module`, whatever their address.
+    pub fn members(&self, module: &str) -> &[Member] {
+        self.members.get(module).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the fields, and their descriptions, of the struct the innermost expression ending
+    /// at `offset` in `file` is a value of, or a reference to.
+    pub fn fields_of_expression_ending_at(&self, file: &str, offset: usize) -> &[(String, String)] {
+        self.expressions
+            .iter()
+            .filter(|e| e.span.file == file && e.span.end == offset)
+            .min_by_key(|e| e.span.len())
+            .and_then(|e| e.struct_.as_ref())
+            .and_then(|struct_| self.fields.get(struct_))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+//**************************************************************************************************
+// Building
+//**************************************************************************************************
+
+pub struct IndexBuilder<'a> {
+    files: &'a FilesSourceText,
+    index: SymbolIndex,
+    /// The module, or script, being indexed.
+    module: String,
+    /// The locals in scope, innermost scope last, with where they are declared.
+    scopes: Vec<BTreeMap<String, FileSpan>>,
+}
+
+impl<'a> IndexBuilder<'a> {
+    pub fn new(files: &'a FilesSourceText) -> Self {
+        Self {
+            files,
+            index: SymbolIndex::default(),
+            module: String::new(),
+            scopes: vec![],
+        }
+    }
+
+    pub fn finish(mut self) -> SymbolIndex {
+        // Inferred types can point back at code already indexed.
+        self.index.occurrences.sort();
+        self.index.occurrences.dedup();
+        self.index
+    }
+
+    fn define(&mut self, symbol: Symbol, span: FileSpan, description: String) {
+        self.index.occurrences.push((span, symbol.clone()));
+        self.index.descriptions.insert(symbol.clone(), description);
+        self.index.definitions.insert(symbol, span);
+    }
+
+    fn member(&mut self, module: &str, name: &str, kind: MemberKind, symbol: &Symbol) {
+        let description = self.index.descriptions[symbol].clone();
+        self.index
+            .members
+            .entry(module.to_string())
+            .or_insert_with(Vec::new)
+            .push(Member {
+                name: name.to_string(),
+                kind,
+                description,
+            })
+    }
+
+    fn reference(&mut self, symbol: Symbol, span: Option<FileSpan>) {
+        if let Some(span) = span {
+            self.index.occurrences.push((span, symbol))
+        }
+    }
+
+    /// Returns the span of `name` in the code at `file[start..end]`. If the code is not just the
+    /// name, the first occurrence of the name as a word is used, or the last one if `last`.
+    fn find_name(
+        &self,
+        file: &str,
+        start: usize,
+        end: usize,
+        name: &str,
+        last: bool,
+    ) -> Option<FileSpan> {
+        let (&file, text) = self.files.get_key_value(file)?;
+        let code = text.get(start..end)?;
+        let is_ident = |c: Option<char>| c.map_or(false, |c| c.is_ascii_alphanumeric() || c == '_');
+        let mut occurrences = code.match_indices(name).map(|(i, _)| i).filter(|i| {
+            let after = &code[i + name.len()..];
+            !is_ident(code[..*i].chars().next_back())
+                && !is_ident(after.chars().next())
+                && !after.starts_with("::")
+        });
+        let i = if code == name {
+            0
+        } else if last {
+            occurrences.last()?
+        } else {
+            occurrences.next()?
+        };
+        Some(FileSpan {
+            file,
+            start: start + i,
+            end: start + i + name.len(),
+        })
+    }
+
+    fn name_span(&self, loc: Loc, name: &str) -> Option<FileSpan> {
+        let span = loc.span();
+        self.find_name(
+            loc.file(),
+            span.start().to_usize(),
+            span.end().to_usize(),
+            name,
+            false,
+        )
+    }
+
+    /// Returns the span of a name used in the code at `outer`. Names resolved through an alias,
+    /// like the members of a module used in the module itself, are located where they are
+    /// declared, in which case the name is searched for in `outer` instead.
+    fn use_span(&self, name_loc: Loc, outer: Loc, name: &str) -> Option<FileSpan> {
+        let (name_span, outer_span) = (name_loc.span(), outer.span());
+        if name_loc.file() == outer.file()
+            && outer_span.start() <= name_span.start()
+            && name_span.end() <= outer_span.end()
+        {
+            self.name_span(name_loc, name)
+        } else {
+            self.name_span(outer, name)
+        }
+    }
+
+    fn loc_span(&self, loc: Loc) -> Option<FileSpan> {
+        let (&file, _) = self.files.get_key_value(loc.file())?;
+        Some(FileSpan {
+            file,
+            start: loc.span().start().to_usize(),
+            end: loc.span().end().to_usize(),
+        })
+    }
+
+    //**********************************************************************************************
+    // Move code
+    //**********************************************************************************************
+
+    pub fn program(&mut self, prog: &Program) {
+        for (ident, mdef) in &prog.modules {
+            self.module(&ident, mdef)
+        }
+        for (name, script) in &prog.scripts {
+            self.script(name, script)
+        }
+    }
+
+    fn module(&mut self, ident: &ModuleIdent, mdef: &ModuleDefinition) {
+        self.module = ident.to_string();
+        let module_name = ident.0.value.name.to_string();
+        for (name, sdef) in &mdef.structs {
+            let symbol = Symbol::Struct(self.module.clone(), name.to_string());
+            let fields = match &sdef.fields {
+                StructFields::Defined(fields) => {
+                    let mut fields = fields.iter().collect::<Vec<_>>();
+                    fields.sort_by_key(|(_, (idx, _))| *idx);
+                    fields
+                }
+                StructFields::Native(_) => vec![],
+            };
+            let resource = if sdef.resource_opt.is_some() {
+                "resource "
+            } else {
+                ""
+            };
+            let type_parameters = sdef
+                .type_parameters
+                .iter()
+                .map(|tp| tp.user_specified_name.value.clone())
+                .collect::<Vec<_>>();
+            let field_descriptions = fields
+                .iter()
+                .map(|(field, (_, ty))| {
+                    (field.to_string(), format!("{}: {}", field, type_string(ty)))
+                })
+                .collect::<Vec<_>>();
+            let description = format!(
+                "{}struct {}::{}{} {{ {} }}",
+                resource,
+                self.module,
+                name,
+                type_arguments_string(&type_parameters),
+                field_descriptions
+                    .iter()
+                    .map(|(_, d)| d.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            if let Some(span) = self.name_span(name.loc(), name.value()) {
+                self.define(symbol.clone(), span, description);
+                self.member(&module_name, name.value(), MemberKind::Struct, &symbol);
+            }
+            for ((field, (_, ty)), (_, field_description)) in fields.iter().zip(&field_descriptions)
+            {
+                let symbol =
+                    Symbol::Field(self.module.clone(), name.to_string(), field.to_string());
+                if let Some(span) = self.name_span(field.loc(), field.value()) {
+                    self.define(symbol, span, format!("field {}", field_description));
+                }
+                self.type_(ty);
+            }
+            self.index
+                .fields
+                .insert((self.module.clone(), name.to_string()), field_descriptions);
+        }
+        for (name, constant) in &mdef.constants {
+            let symbol = Symbol::Constant(self.module.clone(), name.to_string());
+            let description = format!(
+                "const {}::{}: {}",
+                self.module,
+                name,
+                type_string(&constant.signature)
+            );
+            if let Some(span) = self.name_span(name.loc(), name.value()) {
+                self.define(symbol.clone(), span, description);
+                self.member(&module_name, name.value(), MemberKind::Constant, &symbol);
+            }
+            self.type_(&constant.signature);
+            self.exp(&constant.value);
+        }
+        for (name, function) in &mdef.functions {
+            let symbol = Symbol::Function(self.module.clone(), name.to_string());
+            let description = function_description(&self.module, name.value(), function);
+            if let Some(span) = self.name_span(name.loc(), name.value()) {
+                self.define(symbol.clone(), span, description);
+                self.member(&module_name, name.value(), MemberKind::Function, &symbol);
+            }
+            self.function(function);
+        }
+    }
+
+    fn script(&mut self, name: &str, script: &Script) {
+        // Script constants are only visible in their script, which has no address.
+        self.module = name.to_string();
+        for (name, constant) in &script.constants {
+            let symbol = Symbol::Constant(self.module.clone(), name.to_string());
+            let description = format!("const {}: {}", name, type_string(&constant.signature));
+            if let Some(span) = self.name_span(name.loc(), name.value()) {
+                self.define(symbol, span, description);
+            }
+            self.type_(&constant.signature);
+            self.exp(&constant.value);
+        }
+        self.function(&script.function);
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.scopes = vec![BTreeMap::new()];
+        for (var, ty) in &function.signature.parameters {
+            self.type_(ty);
+            self.declare_local(var, ty);
+        }
+        self.type_(&function.signature.return_type);
+        for (name, loc) in &function.acquires {
+            self.struct_reference(&self.module.clone(), name, *loc);
+        }
+        if let FunctionBody_::Defined(seq) = &function.body.value {
+            self.sequence(seq)
+        }
+        self.scopes.clear();
+    }
+
+    fn struct_reference(&mut self, module: &str, name: &StructName, outer: Loc) {
+        let symbol = Symbol::Struct(module.to_string(), name.to_string());
+        let span = self.use_span(name.loc(), outer, name.value());
+        self.reference(symbol, span)
+    }
+
+    fn field_reference(&mut self, module: &str, struct_: &str, field: &impl Identifier) {
+        let symbol = Symbol::Field(
+            module.to_string(),
+            struct_.to_string(),
+            field.value().to_string(),
+        );
+        let span = self.name_span(field.loc(), field.value());
+        self.reference(symbol, span)
+    }
+
+    fn type_(&mut self, ty: &move_lang::naming::ast::Type) {
+        match &ty.value {
+            Type_::Ref(_, ty) => self.type_(ty),
+            Type_::Apply(_, type_name, tys) => {
+                if let TypeName_::ModuleType(module, name) = &type_name.value {
+                    self.struct_reference(&module.to_string(), name, ty.loc)
+                }
+                for ty in tys {
+                    self.type_(ty)
+                }
+            }
+            Type_::Unit
+            | Type_::Param(_)
+            | Type_::Var(_)
+            | Type_::Anything
+            | Type_::UnresolvedError => (),
+        }
+    }
+
+    fn declare_local(&mut self, var: &Var, ty: &move_lang::naming::ast::Type) {
+        if let Some(span) = self.name_span(var.loc(), var.value()) {
+            let description = format!("{}: {}", var, type_string(ty));
+            self.define(Symbol::Local(span), span, description);
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.insert(var.to_string(), span);
+            }
+        }
+    }
+
+    fn local_reference(&mut self, var: &Var) {
+        let declaration = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(var.value()))
+            .copied();
+        if let Some(declaration) = declaration {
+            let span = self.name_span(var.loc(), var.value());
+            self.reference(Symbol::Local(declaration), span)
+        }
+    }
+
+    fn sequence(&mut self, seq: &Sequence) {
+        self.scopes.push(BTreeMap::new());
+        for item in seq {
+            match &item.value {
+                SequenceItem_::Seq(e) => self.exp(e),
+                SequenceItem_::Declare(lvalues) => self.lvalues(lvalues, true),
+                SequenceItem_::Bind(lvalues, _, e) => {
+                    self.exp(e);
+                    self.lvalues(lvalues, true)
+                }
+            }
+        }
+        self.scopes.pop();
+    }
+
+    fn lvalues(&mut self, lvalues: &LValueList, declare: bool) {
+        for lvalue in &lvalues.value {
+            self.lvalue(lvalue, declare)
+        }
+    }
+
+    fn lvalue(&mut self, lvalue: &LValue, declare: bool) {
+        match &lvalue.value {
+            LValue_::Ignore => (),
+            LValue_::Var(var, ty) => {
+                if declare {
+                    self.declare_local(var, ty)
+                } else {
+                    self.local_reference(var)
+                }
+            }
+            LValue_::Unpack(module, name, _, fields)
+            | LValue_::BorrowUnpack(_, module, name, _, fields) => {
+                let module = module.to_string();
+                self.struct_reference(&module, name, lvalue.loc);
+                for (field, (_, (_, lvalue))) in fields {
+                    self.field_reference(&module, name.value(), &field);
+                    self.lvalue(lvalue, declare)
+                }
+            }
+        }
+    }
+
+    fn exp(&mut self, e: &Exp) {
+        use UnannotatedExp_ as E;
+        if let Some(span) = self.loc_span(e.exp.loc) {
+            if e.ty.value != Type_::Unit {
+                self.index.expressions.push(TypedSpan {
+                    span,
+                    ty: type_string(&e.ty),
+                    struct_: struct_of(&e.ty),
+                })
+            }
+        }
+        match &e.exp.value {
+            E::Unit { .. }
+            | E::Value(_)
+            | E::InferredNum(_)
+            | E::Break
+            | E::Continue
+            | E::Spec(..)
+            | E::UnresolvedError => (),
+            E::Move { var, .. } | E::Copy { var, .. } | E::Use(var) | E::BorrowLocal(_, var) => {
+                self.local_reference(var)
+            }
+            E::Constant(module, name) => {
+                let module = module
+                    .as_ref()
+                    .map_or_else(|| self.module.clone(), |m| m.to_string());
+                let span = self.use_span(name.loc(), e.exp.loc, name.value());
+                self.reference(Symbol::Constant(module, name.to_string()), span)
+            }
+            E::ModuleCall(call) => {
+                let symbol = Symbol::Function(call.module.to_string(), call.name.to_string());
+                let span = self.use_span(call.name.loc(), e.exp.loc, call.name.value());
+                self.reference(symbol, span);
+                for ty in &call.type_arguments {
+                    self.type_(ty)
+                }
+                self.exp(&call.arguments)
+            }
+            E::Builtin(builtin, e) => {
+                match &builtin.value {
+                    BuiltinFunction_::MoveTo(ty)
+                    | BuiltinFunction_::MoveFrom(ty)
+                    | BuiltinFunction_::BorrowGlobal(_, ty)
+                    | BuiltinFunction_::Exists(ty)
+                    | BuiltinFunction_::Freeze(ty) => self.type_(ty),
+                    BuiltinFunction_::Assert => (),
+                }
+                self.exp(e)
+            }
+            E::IfElse(cond, if_true, if_false) => {
+                self.exp(cond);
+                self.exp(if_true);
+                self.exp(if_false)
+            }
+            E::While(cond, body) => {
+                self.exp(cond);
+                self.exp(body)
+            }
+            E::Loop { body, .. } => self.exp(body),
+            E::Block(seq) => self.sequence(seq),
+            E::Assign(lvalues, _, e) => {
+                self.exp(e);
+                self.lvalues(lvalues, false)
+            }
+            E::Mutate(lhs, rhs) | E::BinopExp(lhs, _, _, rhs) => {
+                self.exp(lhs);
+                self.exp(rhs)
+            }
+            E::Return(e)
+            | E::Abort(e)
+            | E::Dereference(e)
+            | E::UnaryExp(_, e)
+            | E::TempBorrow(_, e) => self.exp(e),
+            E::Pack(module, name, _, fields) => {
+                let module = module.to_string();
+                self.struct_reference(&module, name, e.exp.loc);
+                for (field, (_, (_, e))) in fields {
+                    self.field_reference(&module, name.value(), &field);
+                    self.exp(e)
+                }
+            }
+            E::ExpList(items) => {
+                for item in items {
+                    match item {
+                        ExpListItem::Single(e, _) | ExpListItem::Splat(_, e, _) => self.exp(e),
+                    }
+                }
+            }
+            E::Borrow(_, e, field) => {
+                self.exp(e);
+                if let Some((module, name)) = struct_of(&e.ty) {
+                    self.field_reference(&module, &name, field)
+                }
+            }
+            E::Cast(e, ty) | E::Annotate(e, ty) => {
+                self.exp(e);
+                self.type_(ty)
+            }
+        }
+    }
+
+    //**********************************************************************************************
+    // Specifications
+    //**********************************************************************************************
+
+    pub fn specs(&mut self, env: &GlobalEnv) {
+        let pool = env.symbol_pool();
+        let tctx = TypeDisplayContext::WithEnv {
+            env,
+            type_param_names: None,
+        };
+        for module_env in env.get_modules() {
+            if module_env.is_script_module() {
+                continue;
+            }
+            self.module = module_env.get_name().display_full(pool).to_string();
+            let module_name = module_env.get_name().display(pool).to_string();
+            for (_, decl) in module_env.get_spec_vars() {
+                let name = decl.name.display(pool).to_string();
+                let symbol = Symbol::SpecVar(self.module.clone(), name.clone());
+                let description = format!(
+                    "global {}::{}: {}",
+                    self.module,
+                    name,
+                    decl.type_.display(&tctx)
+                );
+                if let Some(span) = self.spec_name_span(env, &decl.loc, &name, false) {
+                    self.define(symbol.clone(), span, description);
+                    self.member(&module_name, &name, MemberKind::SpecVar, &symbol);
+                }
+            }
+            for (_, decl) in module_env.get_spec_funs() {
+                let name = decl.name.display(pool).to_string();
+                let span = self.spec_name_span(env, &decl.loc, &name, false);
+                // Move functions used in specifications are also spec functions.
+                if self.is_move_function(&name, span) {
+                    continue;
+                }
+                let symbol = Symbol::SpecFun(self.module.clone(), name.clone());
+                let description = format!(
+                    "define {}::{}({}): {}",
+                    self.module,
+                    name,
+                    decl.params
+                        .iter()
+                        .map(|(param, ty)| format!(
+                            "{}: {}",
+                            param.display(pool),
+                            ty.display(&tctx)
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    decl.result_type.display(&tctx)
+                );
+                if let Some(span) = span {
+                    self.define(symbol.clone(), span, description);
+                    self.member(&module_name, &name, MemberKind::SpecFun, &symbol);
+                }
+                if let Some(body) = &decl.body {
+                    self.spec_exp(env, &module_env, &tctx, body)
+                }
+            }
+            self.spec(env, &module_env, &tctx, module_env.get_spec());
+            for struct_env in module_env.get_structs() {
+                self.spec(env, &module_env, &tctx, struct_env.get_spec())
+            }
+            for fun_env in module_env.get_functions() {
+                self.spec(env, &module_env, &tctx, fun_env.get_spec())
+            }
+        }
+    }
+
+    fn is_move_function(&self, name: &str, span: Option<FileSpan>) -> bool {
+        let symbol = Symbol::Function(self.module.clone(), name.to_string());
+        span.is_some() && self.index.definitions.get(&symbol) == span.as_ref()
+    }
+
+    fn spec_name_span(
+        &self,
+        env: &GlobalEnv,
+        loc: &SpecLoc,
+        name: &str,
+        last: bool,
+    ) -> Option<FileSpan> {
+        let (file, _) = env.get_position(loc.clone())?;
+        self.find_name(
+            &file,
+            loc.span().start().to_usize(),
+            loc.span().end().to_usize(),
+            name,
+            last,
+        )
+    }
+
+    fn spec(
+        &mut self,
+        env: &GlobalEnv,
+        module_env: &ModuleEnv<'_>,
+        tctx: &TypeDisplayContext<'_>,
+        spec: &Spec,
+    ) {
+        for condition in &spec.conditions {
+            self.spec_exp(env, module_env, tctx, &condition.exp)
+        }
+        for spec in spec.on_impl.values() {
+            self.spec(env, module_env, tctx, spec)
+        }
+    }
+
+    fn spec_exp(
+        &mut self,
+        env: &GlobalEnv,
+        module_env: &ModuleEnv<'_>,
+        tctx: &TypeDisplayContext<'_>,
+        exp: &SpecExp,
+    ) {
+        let pool = env.symbol_pool();
+        // The visitor cannot borrow `self`, so collect what to index first.
+        let mut nodes = vec![];
+        exp.visit(&mut |e| {
+            let symbol = match e {
+                SpecExp::SpecVar(_, mid, vid) => {
+                    let module_env = env.get_module(*mid);
+                    let module = module_env.get_name().display_full(pool).to_string();
+                    let name = module_env.get_spec_var(*vid).name.display(pool).to_string();
+                    Some((Symbol::SpecVar(module, name.clone()), name, false))
+                }
+                SpecExp::Call(_, Operation::Function(mid, fid), _) => {
+                    let module_env = env.get_module(*mid);
+                    let module = module_env.get_name().display_full(pool).to_string();
+                    let name = module_env.get_spec_fun(*fid).name.display(pool).to_string();
+                    Some((Symbol::SpecFun(module, name.clone()), name, false))
+                }
+                SpecExp::Call(_, Operation::Pack(mid, sid), _) => {
+                    let module_env = env.get_module(*mid);
+                    let module = module_env.get_name().display_full(pool).to_string();
+                    let name = module_env
+                        .get_struct(*sid)
+                        .get_name()
+                        .display(pool)
+                        .to_string();
+                    Some((Symbol::Struct(module, name.clone()), name, false))
+                }
+                SpecExp::Call(_, Operation::Select(mid, sid, fid), _) => {
+                    let module_env = env.get_module(*mid);
+                    let module = module_env.get_name().display_full(pool).to_string();
+                    let struct_ = module_env
+                        .get_struct(*sid)
+                        .get_name()
+                        .display(pool)
+                        .to_string();
+                    let name = fid.symbol().display(pool).to_string();
+                    Some((Symbol::Field(module, struct_, name.clone()), name, true))
+                }
+                _ => None,
+            };
+            nodes.push((e.node_id(), symbol))
+        });
+        for (node_id, symbol) in nodes {
+            let loc = module_env.get_node_loc(node_id);
+            let ty = module_env.get_node_type(node_id);
+            if let Some(span) = self.spec_span(env, &loc) {
+                if ty != SpecType::Tuple(vec![]) {
+                    self.index.expressions.push(TypedSpan {
+                        span,
+                        ty: ty.display(tctx).to_string(),
+                        struct_: spec_struct_of(env, &ty),
+                    })
+                }
+            }
+            if let Some((symbol, name, last)) = symbol {
+                let symbol = match symbol {
+                    Symbol::SpecFun(module, name)
+                        if self
+                            .index
+                            .definitions
+                            .contains_key(&Symbol::Function(module.clone(), name.clone())) =>
+                    {
+                        Symbol::Function(module, name)
+                    }
+                    symbol => symbol,
+                };
+                let span = self.spec_name_span(env, &loc, &name, last);
+                self.reference(symbol, span)
+            }
+        }
+    }
+
+    fn spec_span(&self, env: &GlobalEnv, loc: &SpecLoc) -> Option<FileSpan> {
+        let (file, _) = env.get_position(loc.clone())?;
+        let (&file, _) = self.files.get_key_value(file.as_str())?;
+        Some(FileSpan {
+            file,
+            start: loc.span().start().to_usize(),
+            end: loc.span().end().to_usize(),
+        })
+    }
+}
+
+//**************************************************************************************************
+// Display
+//**************************************************************************************************
+
+fn type_string(ty: &move_lang::naming::ast::Type) -> String {
+    match &ty.value {
+        Type_::Unit => "()".to_string(),
+        Type_::Ref(true, ty) => format!("&mut {}", type_string(ty)),
+        Type_::Ref(false, ty) => format!("&{}", type_string(ty)),
+        Type_::Param(tp) => tp.user_specified_name.value.clone(),
+        Type_::Apply(_, type_name, tys) => {
+            let tys = tys.iter().map(type_string).collect::<Vec<_>>();
+            match &type_name.value {
+                TypeName_::Multiple(_) => format!("({})", tys.join(", ")),
+                TypeName_::Builtin(name) => {
+                    format!("{}{}", name.value, type_arguments_string(&tys))
+                }
+                TypeName_::ModuleType(module, name) => {
+                    format!("{}::{}{}", module, name, type_arguments_string(&tys))
+                }
+            }
+        }
+        Type_::Var(_) | Type_::Anything | Type_::UnresolvedError => "_".to_string(),
+    }
+}
+
+fn type_arguments_string(tys: &[String]) -> String {
+    if tys.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", tys.join(", "))
+    }
+}
+
+fn function_description(module: &str, name: &str, function: &Function) -> String {
+    let visibility = match function.visibility {
+        FunctionVisibility::Public(_) => "public ",
+        FunctionVisibility::Internal => "",
+    };
+    let native = match function.body.value {
+        FunctionBody_::Native => "native ",
+        FunctionBody_::Defined(_) => "",
+    };
+    let signature = &function.signature;
+    let type_parameters = signature
+        .type_parameters
+        .iter()
+        .map(|tp| tp.user_specified_name.value.clone())
+        .collect::<Vec<_>>();
+    let parameters = signature
+        .parameters
+        .iter()
+        .map(|(var, ty)| format!("{}: {}", var, type_string(ty)))
+        .collect::<Vec<_>>();
+    let return_type = match &signature.return_type.value {
+        Type_::Unit => String::new(),
+        _ => format!(": {}", type_string(&signature.return_type)),
+    };
+    format!(
+        "{}{}fun {}::{}{}({}){}",
+        visibility,
+        native,
+        module,
+        name,
+        type_arguments_string(&type_parameters),
+        parameters.join(", "),
+        return_type
+    )
+}
+
+/// The module and name of the struct `ty` is, or is a reference to.
+fn struct_of(ty: &move_lang::naming::ast::Type) -> Option<(String, String)> {
+    match &ty.value {
+        Type_::Ref(_, ty) => struct_of(ty),
+        Type_::Apply(_, type_name, _) => match &type_name.value {
+            TypeName_::ModuleType(module, name) => Some((module.to_string(), name.to_string())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn spec_struct_of(env: &GlobalEnv, ty: &SpecType) -> Option<(String, String)> {
+    match ty {
+        SpecType::Reference(_, ty) => spec_struct_of(env, ty),
+        SpecType::Struct(mid, sid, _) => {
+            let pool = env.symbol_pool();
+            let module_env = env.get_module(*mid);
+            Some((
+                module_env.get_name().display_full(pool).to_string(),
+                module_env
+                    .get_struct(*sid)
+                    .get_name()
+                    .display(pool)
+                    .to_string(),
+            ))
+        }
+        _ => None,
+    }
+}
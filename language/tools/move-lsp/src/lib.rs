@@ -0,0 +1,48 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! A language server for Move.
+//!
+//! The server speaks the Language Server Protocol over a byte stream, usually its stdin and
+//! stdout. It compiles the Move files of the workspace the client opens with `move-lang`, up to
+//! type checking, when the workspace is opened and whenever a file is saved, and publishes the
+//! errors found as diagnostics. When the code type checks, the specifications are checked with
+//! `spec-lang` as well. From the typed program, and the resolved specifications, it indexes where
+//! each symbol is defined and used and the type of each expression, which it answers requests for
+//! definitions, references, hovers and completions from.
+
+mod analysis;
+mod index;
+pub mod protocol;
+mod server;
+
+use anyhow::Result;
+use move_lang::shared::Address;
+use server::Server;
+use std::io::{BufRead, Write};
+
+/// What the workspaces are compiled against.
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    /// Move files, or directories of them, the workspace depends on.
+    pub dependencies: Vec<String>,
+    /// The address modules without one are published under.
+    pub sender: Address,
+}
+
+/// Serves the client writing messages to `input` and reading them from `output`, until it asks
+/// the server to exit or closes `input`.
+pub fn serve(config: ServerConfig, mut input: impl BufRead, mut output: impl Write) -> Result<()> {
+    let mut server = Server::new(config);
+    while let Some(message) = protocol::read_message(&mut input)? {
+        for response in server.handle(message) {
+            protocol::write_message(&mut output, &response)?;
+        }
+        if server.exited() {
+            break;
+        }
+    }
+    Ok(())
+}
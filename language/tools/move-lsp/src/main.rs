@@ -0,0 +1,53 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use move_lang::{command_line as cli, shared::Address};
+use move_lsp::{serve, ServerConfig};
+use std::io;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move LSP",
+    about = "Language server for Move, over stdin and stdout"
+)]
+struct Args {
+    /// The library files needed as dependencies, besides the standard library
+    #[structopt(
+        name = "PATH_TO_DEPENDENCY_FILE",
+        short = cli::DEPENDENCY_SHORT,
+        long = cli::DEPENDENCY,
+    )]
+    pub dependencies: Vec<String>,
+
+    /// Do not depend on the standard library
+    #[structopt(long = "no-stdlib")]
+    pub no_stdlib: bool,
+
+    /// The sender address for modules and scripts
+    #[structopt(
+        name = "ADDRESS",
+        short = cli::SENDER_SHORT,
+        long = cli::SENDER,
+        default_value = "0x1",
+        parse(try_from_str = cli::parse_address)
+    )]
+    pub sender: Address,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let mut dependencies = args.dependencies;
+    if !args.no_stdlib {
+        dependencies.extend(stdlib::stdlib_files());
+    }
+    let config = ServerConfig {
+        dependencies,
+        sender: args.sender,
+    };
+    let stdin = io::stdin();
+    serve(config, stdin.lock(), io::stdout())
+}
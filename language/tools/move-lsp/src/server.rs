@@ -0,0 +1,380 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Dispatches the requests and notifications of a client, keeping the state of the session.
+
+use crate::{
+    analysis::Analysis,
+    index::MemberKind,
+    protocol::{
+        self, path_to_uri, uri_to_path, CompletionItem, DidChangeTextDocumentParams,
+        DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams, Hover,
+        InitializeParams, MarkupContent, Position, PublishDiagnosticsParams, ReferenceParams,
+        TextDocumentPositionParams, COMPLETION_KIND_CONSTANT, COMPLETION_KIND_FIELD,
+        COMPLETION_KIND_FUNCTION, COMPLETION_KIND_STRUCT,
+    },
+    ServerConfig,
+};
+use move_lang::find_move_filenames;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+/// An error to answer a request with.
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+type RequestResult = Result<Value, ResponseError>;
+
+pub struct Server {
+    config: ServerConfig,
+    /// The directory of the workspace, whose Move files are compiled.
+    root: Option<String>,
+    /// The text of the open documents, by URI, which may not be saved.
+    documents: BTreeMap<String, String>,
+    analysis: Option<Analysis>,
+    /// The URIs diagnostics were last published for, which need clearing once fixed.
+    published: BTreeSet<String>,
+    exited: bool,
+}
+
+impl Server {
+    pub fn new(config: ServerConfig) -> Self {
+        Self {
+            config,
+            root: None,
+            documents: BTreeMap::new(),
+            analysis: None,
+            published: BTreeSet::new(),
+            exited: false,
+        }
+    }
+
+    /// Whether the client asked the server to exit.
+    pub fn exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Handles a message from the client, returning the messages to send back.
+    pub fn handle(&mut self, message: Value) -> Vec<Value> {
+        let method = match message.get("method").and_then(Value::as_str) {
+            Some(method) => method.to_string(),
+            // Responses to requests of the server, which it does not send.
+            None => return vec![],
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        match message.get("id") {
+            Some(id) => {
+                let response = match self.request(&method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err(error) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": { "code": error.code, "message": error.message },
+                    }),
+                };
+                vec![response]
+            }
+            None => self.notification(&method, params),
+        }
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> RequestResult {
+        match method {
+            "initialize" => self.initialize(parse_params(params)?),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/definition" => self.definition(parse_params(params)?),
+            "textDocument/references" => self.references(parse_params(params)?),
+            "textDocument/hover" => self.hover(parse_params(params)?),
+            "textDocument/completion" => self.completion(parse_params(params)?),
+            _ => Err(ResponseError::new(
+                protocol::METHOD_NOT_FOUND,
+                format!("unknown method {}", method),
+            )),
+        }
+    }
+
+    fn notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+        match method {
+            "initialized" => self.analyze(),
+            "textDocument/didOpen" => {
+                if let Ok(params) = parse_params::<DidOpenTextDocumentParams>(params) {
+                    let document = params.text_document;
+                    self.documents.insert(document.uri, document.text);
+                }
+                if self.analysis.is_none() {
+                    return self.analyze();
+                }
+                vec![]
+            }
+            "textDocument/didChange" => {
+                if let Ok(params) = parse_params::<DidChangeTextDocumentParams>(params) {
+                    if let Some(change) = params.content_changes.into_iter().last() {
+                        self.documents.insert(params.text_document.uri, change.text);
+                    }
+                }
+                vec![]
+            }
+            "textDocument/didSave" => {
+                if let Ok(params) = parse_params::<DidSaveTextDocumentParams>(params) {
+                    if let Some(text) = params.text {
+                        self.documents.insert(params.text_document.uri, text);
+                    }
+                }
+                self.analyze()
+            }
+            "textDocument/didClose" => {
+                if let Ok(params) = parse_params::<DidCloseTextDocumentParams>(params) {
+                    self.documents.remove(&params.text_document.uri);
+                }
+                vec![]
+            }
+            "exit" => {
+                self.exited = true;
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+
+    //**********************************************************************************************
+    // Analysis
+    //**********************************************************************************************
+
+    /// The Move files of the workspace, and any other open ones.
+    fn targets(&self) -> anyhow::Result<Vec<String>> {
+        let mut paths = self.root.iter().cloned().collect::<Vec<_>>();
+        paths.extend(
+            self.documents
+                .keys()
+                .filter_map(|uri| uri_to_path(uri))
+                .filter(|path| path.ends_with(".move")),
+        );
+        let mut targets = vec![];
+        for target in find_move_filenames(&paths)? {
+            let target = absolute(&target);
+            if !targets.contains(&target) {
+                targets.push(target)
+            }
+        }
+        Ok(targets)
+    }
+
+    /// Compiles the workspace and publishes the diagnostics found, clearing earlier ones.
+    fn analyze(&mut self) -> Vec<Value> {
+        let result = self.targets().and_then(|targets| {
+            let deps = find_move_filenames(&self.config.dependencies)?
+                .iter()
+                .map(|dep| absolute(dep))
+                .filter(|dep| !targets.contains(dep))
+                .collect::<Vec<_>>();
+            Analysis::run(&targets, &deps, self.config.sender)
+        });
+        let analysis = match result {
+            Ok(analysis) => analysis,
+            Err(error) => {
+                return vec![json!({
+                    "jsonrpc": "2.0",
+                    "method": "window/showMessage",
+                    "params": { "type": 1, "message": format!("{:#}", error) },
+                })]
+            }
+        };
+        let mut diagnostics = analysis
+            .diagnostics
+            .iter()
+            .map(|(file, diagnostics)| (path_to_uri(file), diagnostics.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let published = diagnostics.keys().cloned().collect();
+        for uri in &self.published {
+            diagnostics.entry(uri.clone()).or_insert_with(Vec::new);
+        }
+        self.published = published;
+        self.analysis = Some(analysis);
+        diagnostics
+            .into_iter()
+            .map(|(uri, diagnostics)| {
+                let params = PublishDiagnosticsParams { uri, diagnostics };
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/publishDiagnostics",
+                    "params": params,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the analysis, the compiled file and the offset in it of a document position.
+    fn locate(&self, uri: &str, position: Position) -> Option<(&Analysis, &'static str, usize)> {
+        let analysis = self.analysis.as_ref()?;
+        let path = uri_to_path(uri)?;
+        let (&file, text) = analysis.files.get_key_value(path.as_str())?;
+        Some((analysis, file, position.offset(text)?))
+    }
+
+    //**********************************************************************************************
+    // Requests
+    //**********************************************************************************************
+
+    fn initialize(&mut self, params: InitializeParams) -> RequestResult {
+        self.root = params
+            .root_uri
+            .as_deref()
+            .and_then(uri_to_path)
+            .map(|root| absolute(&root));
+        Ok(json!({
+            "capabilities": {
+                "textDocumentSync": {
+                    "openClose": true,
+                    "change": 1,
+                    "save": { "includeText": false },
+                },
+                "definitionProvider": true,
+                "referencesProvider": true,
+                "hoverProvider": true,
+                "completionProvider": { "triggerCharacters": [":", "."] },
+            },
+            "serverInfo": { "name": "move-lsp" },
+        }))
+    }
+
+    fn definition(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let location = self
+            .locate(&params.text_document.uri, params.position)
+            .and_then(|(analysis, file, offset)| {
+                let symbol = analysis.index.symbol_at(file, offset)?;
+                analysis.location(analysis.index.definition(symbol)?)
+            });
+        Ok(json!(location))
+    }
+
+    fn references(&self, params: ReferenceParams) -> RequestResult {
+        let locations = self
+            .locate(&params.text_document.uri, params.position)
+            .and_then(|(analysis, file, offset)| {
+                let symbol = analysis.index.symbol_at(file, offset)?;
+                let spans = analysis
+                    .index
+                    .references(symbol, params.context.include_declaration);
+                Some(
+                    spans
+                        .into_iter()
+                        .filter_map(|span| analysis.location(span))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .unwrap_or_default();
+        Ok(json!(locations))
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let hover = self
+            .locate(&params.text_document.uri, params.position)
+            .and_then(|(analysis, file, offset)| {
+                let (span, description) = analysis.index.hover(file, offset)?;
+                Some(Hover {
+                    contents: MarkupContent {
+                        kind: "markdown".to_string(),
+                        value: format!("```move\n{}\n```", description),
+                    },
+                    range: analysis.location(span)?.range,
+                })
+            });
+        Ok(json!(hover))
+    }
+
+    /// Completes the members of a module after `M::`, and the fields of a struct after `e.`.
+    fn completion(&self, params: TextDocumentPositionParams) -> RequestResult {
+        let uri = &params.text_document.uri;
+        let analysis = match &self.analysis {
+            Some(analysis) => analysis,
+            None => return Ok(json!([])),
+        };
+        // Completions are requested while typing, so from the unsaved text.
+        let text = match self.documents.get(uri) {
+            Some(text) => text.clone(),
+            None => match uri_to_path(uri).and_then(|path| fs::read_to_string(path).ok()) {
+                Some(text) => text,
+                None => return Ok(json!([])),
+            },
+        };
+        let offset = match params.position.offset(&text) {
+            Some(offset) => offset,
+            None => return Ok(json!([])),
+        };
+        let before = &text[..offset];
+        let prefix_start = identifier_start(before);
+        let prefix = &before[prefix_start..];
+        let before = &before[..prefix_start];
+        let mut items = vec![];
+        if before.ends_with("::") {
+            let before = &before[..before.len() - 2];
+            let module_start = identifier_start(before);
+            for member in analysis.index.members(&before[module_start..]) {
+                let kind = match member.kind {
+                    MemberKind::Struct => COMPLETION_KIND_STRUCT,
+                    MemberKind::Function | MemberKind::SpecFun => COMPLETION_KIND_FUNCTION,
+                    MemberKind::Constant | MemberKind::SpecVar => COMPLETION_KIND_CONSTANT,
+                };
+                items.push(CompletionItem {
+                    label: member.name.clone(),
+                    kind,
+                    detail: member.description.clone(),
+                })
+            }
+        } else if before.ends_with('.') {
+            let file = uri_to_path(uri)
+                .and_then(|path| analysis.files.get_key_value(path.as_str()).map(|(f, _)| *f));
+            if let Some(file) = file {
+                // The expression before the dot is assumed not to have moved since the last save.
+                let fields = analysis
+                    .index
+                    .fields_of_expression_ending_at(file, prefix_start - 1);
+                for (name, description) in fields {
+                    items.push(CompletionItem {
+                        label: name.clone(),
+                        kind: COMPLETION_KIND_FIELD,
+                        detail: description.clone(),
+                    })
+                }
+            }
+        }
+        items.retain(|item| item.label.starts_with(prefix));
+        Ok(json!(items))
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, ResponseError> {
+    serde_json::from_value(params)
+        .map_err(|error| ResponseError::new(protocol::INVALID_PARAMS, error.to_string()))
+}
+
+/// The byte offset of the identifier `text` ends with, if any, or else of its end.
+fn identifier_start(text: &str) -> usize {
+    text.char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+        .last()
+        .map_or(text.len(), |(i, _)| i)
+}
+
+/// Makes `path` absolute, so that the same file is always named the same, and has a URI.
+fn absolute(path: &str) -> String {
+    fs::canonicalize(path)
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| path.to_string())
+}
@@ -0,0 +1,347 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_temppath::TempPath;
+use move_lang::shared::Address;
+use move_lsp::{
+    protocol::{path_to_uri, read_message, write_message},
+    serve, ServerConfig,
+};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    io::{self, BufReader, Cursor, Read, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+/// One end of a stream of bytes between the test and the server.
+struct ChannelReader {
+    receiver: Receiver<Vec<u8>>,
+    buffer: Cursor<Vec<u8>>,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let read = self.buffer.read(buf)?;
+            if read > 0 {
+                return Ok(read);
+            }
+            match self.receiver.recv() {
+                Ok(bytes) => self.buffer = Cursor::new(bytes),
+                // The other end is closed.
+                Err(_) => return Ok(0),
+            }
+        }
+    }
+}
+
+struct ChannelWriter(Sender<Vec<u8>>);
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "closed"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn pipe() -> (ChannelWriter, BufReader<ChannelReader>) {
+    let (sender, receiver) = channel();
+    let reader = ChannelReader {
+        receiver,
+        buffer: Cursor::new(vec![]),
+    };
+    (ChannelWriter(sender), BufReader::new(reader))
+}
+
+/// A session with a server running on its own thread, as if over stdio.
+struct Session {
+    input: ChannelWriter,
+    output: BufReader<ChannelReader>,
+    server: JoinHandle<()>,
+    next_id: u64,
+}
+
+impl Session {
+    /// Starts a server and initializes it for the workspace at `root`.
+    fn start(root: &Path) -> Self {
+        let (input, server_input) = pipe();
+        let (server_output, output) = pipe();
+        let config = ServerConfig {
+            dependencies: vec![],
+            sender: Address::LIBRA_CORE,
+        };
+        let server = thread::spawn(move || serve(config, server_input, server_output).unwrap());
+        let mut session = Self {
+            input,
+            output,
+            server,
+            next_id: 0,
+        };
+        let result = session.request("initialize", json!({ "rootUri": uri(root) }));
+        assert_eq!(result["capabilities"]["hoverProvider"], json!(true));
+        session.notify("initialized", json!({}));
+        session
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        let message = json!({ "jsonrpc": "2.0", "method": method, "params": params });
+        write_message(&mut self.input, &message).unwrap();
+    }
+
+    /// Sends a request and returns the result it is answered with, ignoring notifications.
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = json!(self.next_id);
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        write_message(&mut self.input, &message).unwrap();
+        loop {
+            let message = self.receive();
+            if message.get("id") == Some(&id) {
+                assert_eq!(message.get("error"), None);
+                return message["result"].clone();
+            }
+        }
+    }
+
+    fn receive(&mut self) -> Value {
+        read_message(&mut self.output)
+            .unwrap()
+            .expect("server closed the session")
+    }
+
+    fn finish(mut self) {
+        assert_eq!(self.request("shutdown", Value::Null), Value::Null);
+        self.notify("exit", Value::Null);
+        self.server.join().unwrap();
+    }
+}
+
+fn sources() -> PathBuf {
+    fs::canonicalize(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/sources")).unwrap()
+}
+
+fn uri(path: &Path) -> String {
+    path_to_uri(path.to_str().unwrap())
+}
+
+/// The position of the byte `offset` of `text`, in UTF-16 code units as in the protocol.
+fn position_of(text: &str, offset: usize) -> Value {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    json!({
+        "line": before.matches('\n').count(),
+        "character": before[line_start..].encode_utf16().count(),
+    })
+}
+
+/// The parameters of a request about the position `offset` bytes into the first occurrence of
+/// `needle` in the file at `path`.
+fn at(path: &Path, needle: &str, offset: usize) -> Value {
+    let text = fs::read_to_string(path).unwrap();
+    json!({
+        "textDocument": { "uri": uri(path) },
+        "position": position_of(&text, text.find(needle).unwrap() + offset),
+    })
+}
+
+fn line_of(location: &Value) -> u64 {
+    location["range"]["start"]["line"].as_u64().unwrap()
+}
+
+fn labels(items: &Value) -> Vec<&str> {
+    let mut labels = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    labels.sort();
+    labels
+}
+
+#[test]
+fn navigate() {
+    let counter = sources().join("Counter.move");
+    let tally = sources().join("Tally.move");
+    let mut session = Session::start(&sources());
+
+    // A function of another module.
+    let definition = session.request("textDocument/definition", at(&tally, "increment", 0));
+    assert_eq!(definition["uri"], json!(uri(&counter)));
+    assert_eq!(line_of(&definition), 12);
+    let references = session.request(
+        "textDocument/references",
+        json!({
+            "textDocument": at(&tally, "increment", 3)["textDocument"],
+            "position": at(&tally, "increment", 3)["position"],
+            "context": { "includeDeclaration": true },
+        }),
+    );
+    assert_eq!(references.as_array().unwrap().len(), 3);
+
+    // A struct used through an alias in its own module.
+    let definition = session.request("textDocument/definition", at(&counter, "T { value", 0));
+    assert_eq!(line_of(&definition), 2);
+
+    // Locals and the types of expressions.
+    let hover = session.request("textDocument/hover", at(&tally, "c: &mut", 0));
+    let contents = hover["contents"]["value"].as_str().unwrap();
+    assert!(contents.contains("c: &mut 0x1::Counter::T"), "{}", contents);
+    let definition = session.request("textDocument/definition", at(&counter, "v <= MAX", 0));
+    assert_eq!(line_of(&definition), 13);
+    let hover = session.request("textDocument/hover", at(&counter, "c.value + 1", 3));
+    let contents = hover["contents"]["value"].as_str().unwrap();
+    assert!(contents.contains("value: u64"), "{}", contents);
+
+    // Specifications.
+    let definition = session.request("textDocument/definition", at(&counter, "is_small(c);", 0));
+    assert_eq!(line_of(&definition), 23);
+    let hover = session.request("textDocument/hover", at(&counter, "is_small(c);", 0));
+    let contents = hover["contents"]["value"].as_str().unwrap();
+    assert!(
+        contents.contains("define 0x1::Counter::is_small"),
+        "{}",
+        contents
+    );
+    let definition = session.request("textDocument/definition", at(&counter, "c.value == old", 2));
+    assert_eq!(line_of(&definition), 3);
+
+    // Nothing to go to.
+    let definition = session.request("textDocument/definition", at(&counter, "address", 0));
+    assert_eq!(definition, Value::Null);
+
+    session.finish();
+}
+
+#[test]
+fn complete() {
+    let counter = sources().join("Counter.move");
+    let tally = sources().join("Tally.move");
+    let mut session = Session::start(&sources());
+
+    let members = session.request("textDocument/completion", at(&tally, "Counter::get", 9));
+    assert_eq!(
+        labels(&members),
+        vec!["MAX", "T", "get", "increment", "is_small", "new"]
+    );
+    let members = session.request("textDocument/completion", at(&tally, "Counter::get", 10));
+    assert_eq!(labels(&members), vec!["get"]);
+
+    let fields = session.request("textDocument/completion", at(&counter, "c.value + 1", 2));
+    assert_eq!(labels(&fields), vec!["value"]);
+
+    // Completion is from the text being edited, not the one saved.
+    let text = fs::read_to_string(&tally)
+        .unwrap()
+        .replace("Counter::get(c)", "Counter::in");
+    session.notify(
+        "textDocument/didOpen",
+        json!({
+            "textDocument": {
+                "uri": uri(&tally),
+                "languageId": "move",
+                "version": 1,
+                "text": text,
+            },
+        }),
+    );
+    let end = text.find("Counter::in").unwrap() + "Counter::in".len();
+    let members = session.request(
+        "textDocument/completion",
+        json!({ "textDocument": { "uri": uri(&tally) }, "position": position_of(&text, end) }),
+    );
+    assert_eq!(labels(&members), vec!["increment"]);
+
+    // Positions count UTF-16 code units, and may follow characters of any width.
+    let text = text.replace("Counter::in", "/* Zähler 😀 */ Counter::in");
+    session.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": uri(&tally), "version": 2 },
+            "contentChanges": [{ "text": text }],
+        }),
+    );
+    let mut complete = |position: Value| {
+        session.request(
+            "textDocument/completion",
+            json!({ "textDocument": { "uri": uri(&tally) }, "position": position }),
+        )
+    };
+    let end = text.find("Counter::in").unwrap() + "Counter::in".len();
+    assert_eq!(
+        labels(&complete(position_of(&text, end))),
+        vec!["increment"]
+    );
+    // So may identifiers.
+    let end = text.find("Zähler").unwrap() + "Zähler".len();
+    assert_eq!(complete(position_of(&text, end)), json!([]));
+    // A position in the middle of a character has nothing to complete.
+    let mut position = position_of(&text, text.find('😀').unwrap());
+    position["character"] = json!(position["character"].as_u64().unwrap() + 1);
+    assert_eq!(complete(position), json!([]));
+
+    session.finish();
+}
+
+#[test]
+fn diagnostics_on_save() {
+    let root = TempPath::new();
+    root.create_as_dir().unwrap();
+    let root = fs::canonicalize(root.path()).unwrap();
+    let file = root.join("M.move");
+    fs::write(
+        &file,
+        "address 0x1 {\nmodule M {\n    public fun f(): u64 {\n        true\n    }\n}\n}\n",
+    )
+    .unwrap();
+    let mut session = Session::start(&root);
+
+    let published = session.receive();
+    assert_eq!(
+        published["method"],
+        json!("textDocument/publishDiagnostics")
+    );
+    assert_eq!(published["params"]["uri"], json!(uri(&file)));
+    let diagnostics = published["params"]["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(line_of(&diagnostics[0]), 3);
+
+    // Fixing the file clears its diagnostics once it is saved.
+    fs::write(
+        &file,
+        "address 0x1 {\nmodule M {\n    public fun f(): u64 {\n        0\n    }\n}\n}\n",
+    )
+    .unwrap();
+    session.notify(
+        "textDocument/didSave",
+        json!({ "textDocument": { "uri": uri(&file) } }),
+    );
+    let published = session.receive();
+    assert_eq!(published["params"]["uri"], json!(uri(&file)));
+    assert_eq!(published["params"]["diagnostics"], json!([]));
+
+    session.finish();
+}
+
+#[test]
+fn unknown_request() {
+    let mut session = Session::start(&sources());
+    write_message(
+        &mut session.input,
+        &json!({ "jsonrpc": "2.0", "id": "x", "method": "workspace/symbol", "params": {} }),
+    )
+    .unwrap();
+    let response = session.receive();
+    assert_eq!(response["id"], json!("x"));
+    assert_eq!(response["error"]["code"], json!(-32601));
+    session.finish();
+}
@@ -0,0 +1,34 @@
+address 0x1 {
+module Counter {
+    resource struct T {
+        value: u64,
+    }
+
+    const MAX: u64 = 100;
+
+    public fun new(): T {
+        T { value: 0 }
+    }
+
+    public fun increment(c: &mut T) {
+        let v = c.value + 1;
+        assert(v <= MAX, 1);
+        c.value = v;
+    }
+
+    public fun get(c: &T): u64 {
+        c.value
+    }
+
+    spec module {
+        define is_small(c: T): bool {
+            c.value < 100
+        }
+    }
+
+    spec fun increment {
+        aborts_if !is_small(c);
+        ensures c.value == old(c.value) + 1;
+    }
+}
+}
@@ -0,0 +1,11 @@
+address 0x1 {
+module Tally {
+    use 0x1::Counter;
+
+    public fun bump_twice(c: &mut Counter::T): u64 {
+        Counter::increment(c);
+        Counter::increment(c);
+        Counter::get(c)
+    }
+}
+}
@@ -0,0 +1,200 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+//! Runs the unit tests of Move modules, the functions marked `#[test]` (see
+//! `move_lang::unit_test`).
+//!
+//! The targets are compiled with their test functions and their modules are added on top of
+//! genesis in a `FakeExecutor`. Every test then runs in its own `TransactionDataCache` over that
+//! state, so tests never see the writes of one another, and its result is compared with the
+//! failure it is expected to have, if any.
+
+use anyhow::{bail, Result};
+use language_e2e_tests::executor::FakeExecutor;
+use libra_types::{
+    account_address::AccountAddress,
+    vm_status::{StatusCode, VMStatus},
+};
+use libra_vm::{data_cache::RemoteStorage, LibraVM};
+use move_core_types::{gas_schedule::GasUnits, identifier::Identifier, language_storage::ModuleId};
+use move_lang::{
+    compiled_unit::CompiledUnit,
+    errors::report_errors_to_buffer,
+    move_compile_tests,
+    shared::Address,
+    stack_trace::SourceMaps,
+    unit_test::{ExpectedFailure, TestCase},
+};
+use move_vm_runtime::{data_cache::TransactionDataCache, move_vm::MoveVM, stack_trace};
+use move_vm_types::gas_schedule::CostStrategy;
+use std::io::Write;
+use vm::access::ModuleAccess;
+
+/// The tests to run and the environment to run them in.
+pub struct UnitTestConfig {
+    /// Move source files, or directories of them, whose tests are run.
+    pub targets: Vec<String>,
+    /// Move source files of the modules the targets use, beyond the standard library.
+    pub deps: Vec<String>,
+    /// The address of the modules declared outside of an address block.
+    pub sender: AccountAddress,
+    /// Only the tests whose name, e.g. `0x1::M::f`, contains this string are run.
+    pub filter: Option<String>,
+    /// The gas each test can use.
+    pub max_gas: u64,
+}
+
+/// The outcome of a test.
+#[derive(Clone, Debug)]
+pub struct TestResult {
+    pub test: TestCase,
+    /// `EXECUTED` if the test returned.
+    pub status: VMStatus,
+    pub gas_used: u64,
+    pub passed: bool,
+    /// Where the test failed, for tests that did not pass because of an unexpected failure.
+    pub stack_trace: Option<String>,
+}
+
+impl TestResult {
+    /// A short description of how the test ended.
+    pub fn describe(&self) -> String {
+        let ended = match self.status.major_status {
+            StatusCode::EXECUTED => "returned".to_string(),
+            StatusCode::ABORTED => format!(
+                "aborted with code {}",
+                self.status.sub_status.unwrap_or_default()
+            ),
+            code => format!("failed with {:?}", code),
+        };
+        match (&self.test.expected_failure, self.passed) {
+            (_, true) | (None, false) => ended,
+            (Some(ExpectedFailure { abort_code: None }), false) => {
+                format!("expected to abort but {}", ended)
+            }
+            (
+                Some(ExpectedFailure {
+                    abort_code: Some(code),
+                }),
+                false,
+            ) => format!("expected to abort with code {} but {}", code, ended),
+        }
+    }
+}
+
+/// Compiles the targets of the config and runs their tests, in the order they are declared.
+pub fn run_tests(config: &UnitTestConfig) -> Result<Vec<TestResult>> {
+    let mut deps = stdlib::stdlib_files();
+    deps.extend(config.deps.iter().cloned());
+    let (files, res) = move_compile_tests(
+        &config.targets,
+        &deps,
+        Some(Address::new(config.sender.into())),
+    )?;
+    let (units, tests) = match res {
+        Ok(units_and_tests) => units_and_tests,
+        Err(errors) => bail!(
+            "{}",
+            String::from_utf8_lossy(&report_errors_to_buffer(files, errors))
+        ),
+    };
+
+    let mut executor = FakeExecutor::from_genesis_file();
+    let mut source_maps = SourceMaps::new();
+    source_maps.add_files(files);
+    for unit in &units {
+        if let CompiledUnit::Module { module, .. } = unit {
+            executor.add_module(&module.self_id(), module);
+        }
+        source_maps.add_unit(unit);
+    }
+
+    let mut libra_vm = LibraVM::new();
+    libra_vm.load_configs(executor.get_state_view());
+    let gas_schedule = libra_vm.get_gas_schedule()?;
+    let remote_view = RemoteStorage::new(executor.get_state_view());
+    let vm = MoveVM::new();
+
+    stack_trace::record_stack_traces(true);
+    let mut results = vec![];
+    for test in tests {
+        if let Some(filter) = &config.filter {
+            if !test.to_string().contains(filter.as_str()) {
+                continue;
+            }
+        }
+        let module_id = ModuleId::new(
+            AccountAddress::new(test.module_address.to_u8()),
+            Identifier::new(test.module_name.as_str())?,
+        );
+        let function_name = Identifier::new(test.function_name.as_str())?;
+        let mut cost_strategy =
+            CostStrategy::transaction(gas_schedule, GasUnits::new(config.max_gas));
+        let mut data_cache = TransactionDataCache::new(&remote_view);
+        let status = match vm.execute_function(
+            &module_id,
+            &function_name,
+            vec![],
+            vec![],
+            config.sender,
+            &mut data_cache,
+            &mut cost_strategy,
+        ) {
+            Ok(()) => VMStatus::new(StatusCode::EXECUTED),
+            Err(status) => status,
+        };
+        let gas_used = config.max_gas - cost_strategy.remaining_gas().get();
+
+        let passed = match (&test.expected_failure, status.major_status) {
+            (None, StatusCode::EXECUTED) => true,
+            (Some(ExpectedFailure { abort_code }), StatusCode::ABORTED) => {
+                abort_code.is_none() || *abort_code == status.sub_status
+            }
+            _ => false,
+        };
+        let stack_trace = stack_trace::take_stack_trace()
+            .filter(|_| !passed)
+            .map(|trace| source_maps.render(&trace));
+        results.push(TestResult {
+            test,
+            status,
+            gas_used,
+            passed,
+            stack_trace,
+        });
+    }
+    stack_trace::record_stack_traces(false);
+    Ok(results)
+}
+
+/// Writes one line per test, the stack trace of unexpected failures, and a summary. Returns
+/// whether all tests passed.
+pub fn report_results<W: Write>(results: &[TestResult], mut output: W) -> Result<bool> {
+    writeln!(output, "Running {} tests", results.len())?;
+    for result in results {
+        writeln!(
+            output,
+            "[ {} ] {}: {} (gas used: {})",
+            if result.passed { "PASS" } else { "FAIL" },
+            result.test,
+            result.describe(),
+            result.gas_used
+        )?;
+        if let Some(stack_trace) = &result.stack_trace {
+            write!(output, "{}", stack_trace)?;
+        }
+    }
+    let passed = results.iter().filter(|result| result.passed).count();
+    let failed = results.len() - passed;
+    writeln!(
+        output,
+        "Test result: {}. Total tests: {}; passed: {}; failed: {}",
+        if failed == 0 { "OK" } else { "FAILED" },
+        results.len(),
+        passed,
+        failed
+    )?;
+    Ok(failed == 0)
+}
@@ -0,0 +1,58 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use libra_types::account_address::AccountAddress;
+use move_unit_test::{report_results, run_tests, UnitTestConfig};
+use std::{io, process};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "Move Test",
+    about = "Run the #[test] functions of Move modules"
+)]
+struct Args {
+    /// Move source files, or directories of them, whose tests are run.
+    #[structopt(required = true)]
+    pub targets: Vec<String>,
+
+    /// Move source files of the modules used by the targets, beyond the standard library.
+    #[structopt(short = "d", long = "dependencies")]
+    pub deps: Vec<String>,
+
+    /// The address of the modules declared outside of an address block.
+    #[structopt(
+        short = "s",
+        long = "sender",
+        default_value = "0x1",
+        parse(try_from_str = AccountAddress::from_hex_literal)
+    )]
+    pub sender: AccountAddress,
+
+    /// Only run the tests whose name, e.g. `0x1::M::f`, contains this string.
+    #[structopt(short = "f", long = "filter")]
+    pub filter: Option<String>,
+
+    /// The gas each test can use.
+    #[structopt(long = "max-gas", default_value = "1000000")]
+    pub max_gas: u64,
+}
+
+fn main() -> Result<()> {
+    let args = Args::from_args();
+    let config = UnitTestConfig {
+        targets: args.targets,
+        deps: args.deps,
+        sender: args.sender,
+        filter: args.filter,
+        max_gas: args.max_gas,
+    };
+    let results = run_tests(&config)?;
+    if !report_results(&results, io::stdout())? {
+        process::exit(1)
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+address 0x2 {
+module Counter {
+    resource struct Counter { value: u64 }
+
+    const EOVERFLOW: u64 = 1;
+
+    public fun new(): Counter {
+        Counter { value: 0 }
+    }
+
+    public fun increment(counter: &mut Counter, by: u64) {
+        assert(by <= 100 && counter.value <= 100 - by, EOVERFLOW);
+        counter.value = counter.value + by;
+    }
+
+    public fun destroy(counter: Counter): u64 {
+        let Counter { value } = counter;
+        value
+    }
+
+    #[test]
+    fun increments() {
+        let counter = new();
+        increment(&mut counter, 40);
+        increment(&mut counter, 2);
+        assert(destroy(counter) == 42, 100);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun overflows() {
+        let counter = new();
+        increment(&mut counter, 101);
+        destroy(counter);
+    }
+
+    #[test, expected_failure]
+    fun wrong_expectation() {
+        destroy(new());
+    }
+
+    #[test]
+    fun wrong_value() {
+        let counter = new();
+        increment(&mut counter, 1);
+        assert(destroy(counter) == 2, 100);
+    }
+}
+}
@@ -0,0 +1,71 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use libra_types::{account_address::AccountAddress, vm_status::StatusCode};
+use move_unit_test::{report_results, run_tests, TestResult, UnitTestConfig};
+use std::path::PathBuf;
+
+fn config(filter: Option<&str>) -> UnitTestConfig {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/sources/Counter.move");
+    UnitTestConfig {
+        targets: vec![path.to_string_lossy().into_owned()],
+        deps: vec![],
+        sender: AccountAddress::from_hex_literal("0x1").unwrap(),
+        filter: filter.map(|filter| filter.to_string()),
+        max_gas: 1_000_000,
+    }
+}
+
+fn result<'a>(results: &'a [TestResult], function_name: &str) -> &'a TestResult {
+    results
+        .iter()
+        .find(|result| result.test.function_name == function_name)
+        .unwrap()
+}
+
+#[test]
+fn run_counter_tests() {
+    let results = run_tests(&config(None)).unwrap();
+    assert_eq!(results.len(), 4);
+
+    let increments = result(&results, "increments");
+    assert!(increments.passed);
+    assert!(increments.gas_used > 0);
+
+    let overflows = result(&results, "overflows");
+    assert!(overflows.passed);
+    assert_eq!(overflows.status.major_status, StatusCode::ABORTED);
+    assert_eq!(overflows.status.sub_status, Some(1));
+    assert!(overflows.stack_trace.is_none());
+
+    let wrong_expectation = result(&results, "wrong_expectation");
+    assert!(!wrong_expectation.passed);
+    assert_eq!(
+        wrong_expectation.describe(),
+        "expected to abort but returned"
+    );
+
+    let wrong_value = result(&results, "wrong_value");
+    assert!(!wrong_value.passed);
+    assert_eq!(wrong_value.describe(), "aborted with code 100");
+    assert!(wrong_value
+        .stack_trace
+        .as_ref()
+        .unwrap()
+        .contains("Counter.move"));
+
+    let mut output = vec![];
+    assert!(!report_results(&results, &mut output).unwrap());
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("[ PASS ] 0x2::Counter::increments"));
+    assert!(output.contains("Total tests: 4; passed: 2; failed: 2"));
+}
+
+#[test]
+fn filter_tests() {
+    let results = run_tests(&config(Some("Counter::over"))).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].passed);
+    assert!(report_results(&results, vec![]).unwrap());
+}
@@ -0,0 +1,135 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+// Package lcs implements the serialization side of Libra Canonical Serialization (LCS).
+package lcs
+
+import (
+	"bytes"
+	"encoding/binary"
+	"fmt"
+	"math"
+)
+
+// MaxLength is the largest length accepted for sequences, strings and byte arrays.
+const MaxLength = math.MaxInt32
+
+// Uint128 is an unsigned 128-bit integer.
+type Uint128 struct {
+	High uint64
+	Low  uint64
+}
+
+// Int128 is a signed 128-bit integer in two's complement.
+type Int128 struct {
+	High int64
+	Low  uint64
+}
+
+// Serializer accumulates the LCS encoding of values.
+type Serializer struct {
+	buffer bytes.Buffer
+}
+
+// NewSerializer creates an empty serializer.
+func NewSerializer() *Serializer {
+	return new(Serializer)
+}
+
+// SerializeLen writes the length of a sequence, string or byte array.
+func (s *Serializer) SerializeLen(value uint64) error {
+	if value > MaxLength {
+		return fmt.Errorf("incorrect length value: %d", value)
+	}
+	s.serializeUleb128(uint32(value))
+	return nil
+}
+
+// SerializeVariantIndex writes the index of an enum variant.
+func (s *Serializer) SerializeVariantIndex(value uint32) error {
+	s.serializeUleb128(value)
+	return nil
+}
+
+// SerializeOptionTag writes whether an optional value is present.
+func (s *Serializer) SerializeOptionTag(value bool) error {
+	return s.SerializeBool(value)
+}
+
+func (s *Serializer) SerializeBool(value bool) error {
+	if value {
+		return s.buffer.WriteByte(1)
+	}
+	return s.buffer.WriteByte(0)
+}
+
+func (s *Serializer) SerializeU8(value uint8) error {
+	return s.buffer.WriteByte(value)
+}
+
+func (s *Serializer) SerializeU16(value uint16) error {
+	return binary.Write(&s.buffer, binary.LittleEndian, value)
+}
+
+func (s *Serializer) SerializeU32(value uint32) error {
+	return binary.Write(&s.buffer, binary.LittleEndian, value)
+}
+
+func (s *Serializer) SerializeU64(value uint64) error {
+	return binary.Write(&s.buffer, binary.LittleEndian, value)
+}
+
+func (s *Serializer) SerializeU128(value Uint128) error {
+	if err := s.SerializeU64(value.Low); err != nil {
+		return err
+	}
+	return s.SerializeU64(value.High)
+}
+
+func (s *Serializer) SerializeI8(value int8) error {
+	return s.SerializeU8(uint8(value))
+}
+
+func (s *Serializer) SerializeI16(value int16) error {
+	return s.SerializeU16(uint16(value))
+}
+
+func (s *Serializer) SerializeI32(value int32) error {
+	return s.SerializeU32(uint32(value))
+}
+
+func (s *Serializer) SerializeI64(value int64) error {
+	return s.SerializeU64(uint64(value))
+}
+
+func (s *Serializer) SerializeI128(value Int128) error {
+	if err := s.SerializeU64(value.Low); err != nil {
+		return err
+	}
+	return s.SerializeI64(value.High)
+}
+
+func (s *Serializer) SerializeStr(value string) error {
+	return s.SerializeBytes([]byte(value))
+}
+
+func (s *Serializer) SerializeBytes(value []byte) error {
+	if err := s.SerializeLen(uint64(len(value))); err != nil {
+		return err
+	}
+	_, err := s.buffer.Write(value)
+	return err
+}
+
+// GetBytes returns the bytes serialized so far.
+func (s *Serializer) GetBytes() []byte {
+	return s.buffer.Bytes()
+}
+
+func (s *Serializer) serializeUleb128(value uint32) {
+	for value >= 0x80 {
+		s.buffer.WriteByte(byte(value&0x7f) | 0x80)
+		value >>= 7
+	}
+	s.buffer.WriteByte(byte(value))
+}
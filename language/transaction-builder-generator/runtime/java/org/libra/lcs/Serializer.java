@@ -0,0 +1,124 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+package org.libra.lcs;
+
+import java.io.ByteArrayOutputStream;
+import java.math.BigInteger;
+import java.nio.charset.StandardCharsets;
+
+/**
+ * Serializer for Libra Canonical Serialization (LCS).
+ *
+ * Unsigned integers are carried by the signed Java type of the same width, e.g. a `u64` value
+ * is read from the 64 bits of a `Long`. 128-bit integers are carried by a `BigInteger`.
+ */
+public final class Serializer {
+    private static final long MAX_LENGTH = Integer.MAX_VALUE;
+    private static final BigInteger MAX_U128 = BigInteger.ONE.shiftLeft(128);
+    private static final BigInteger MIN_I128 = BigInteger.ONE.shiftLeft(127).negate();
+    private static final BigInteger MAX_I128 = BigInteger.ONE.shiftLeft(127);
+
+    private final ByteArrayOutputStream output = new ByteArrayOutputStream();
+
+    public void serializeLen(long value) {
+        if (value < 0 || value > MAX_LENGTH) {
+            throw new IllegalArgumentException("Incorrect length value: " + value);
+        }
+        serializeUleb128((int) value);
+    }
+
+    public void serializeVariantIndex(int value) {
+        serializeUleb128(value);
+    }
+
+    public void serializeOptionTag(boolean value) {
+        output.write(value ? 1 : 0);
+    }
+
+    public void serializeBool(Boolean value) {
+        output.write(value.booleanValue() ? 1 : 0);
+    }
+
+    public void serializeU8(Byte value) {
+        output.write(value.byteValue());
+    }
+
+    public void serializeU16(Short value) {
+        writeLittleEndian(value.longValue(), 2);
+    }
+
+    public void serializeU32(Integer value) {
+        writeLittleEndian(value.longValue(), 4);
+    }
+
+    public void serializeU64(Long value) {
+        writeLittleEndian(value.longValue(), 8);
+    }
+
+    public void serializeU128(BigInteger value) {
+        if (value.signum() < 0 || value.compareTo(MAX_U128) >= 0) {
+            throw new IllegalArgumentException("Invalid value for an unsigned 128-bit integer");
+        }
+        writeLittleEndian(value, 16);
+    }
+
+    public void serializeI8(Byte value) {
+        serializeU8(value);
+    }
+
+    public void serializeI16(Short value) {
+        serializeU16(value);
+    }
+
+    public void serializeI32(Integer value) {
+        serializeU32(value);
+    }
+
+    public void serializeI64(Long value) {
+        serializeU64(value);
+    }
+
+    public void serializeI128(BigInteger value) {
+        if (value.compareTo(MIN_I128) < 0 || value.compareTo(MAX_I128) >= 0) {
+            throw new IllegalArgumentException("Invalid value for a signed 128-bit integer");
+        }
+        writeLittleEndian(value.signum() < 0 ? value.add(MAX_U128) : value, 16);
+    }
+
+    public void serializeStr(String value) {
+        serializeBytes(value.getBytes(StandardCharsets.UTF_8));
+    }
+
+    public void serializeBytes(byte[] value) {
+        serializeLen(value.length);
+        output.write(value, 0, value.length);
+    }
+
+    public byte[] getBytes() {
+        return output.toByteArray();
+    }
+
+    private void serializeUleb128(int value) {
+        while ((value >>> 7) != 0) {
+            output.write((value & 0x7f) | 0x80);
+            value >>>= 7;
+        }
+        output.write(value);
+    }
+
+    private void writeLittleEndian(long value, int size) {
+        for (int i = 0; i < size; i++) {
+            output.write((int) (value >>> (8 * i)));
+        }
+    }
+
+    private void writeLittleEndian(BigInteger value, int size) {
+        byte[] bytes = value.toByteArray();
+        // `toByteArray` is big-endian and may include an extra sign byte.
+        for (int i = 0; i < size; i++) {
+            int index = bytes.length - 1 - i;
+            output.write(index >= 0 ? bytes[index] : 0);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+package org.libra.lcs;
+
+/** A pair of values, serialized one after the other. */
+public final class Tuple2<T0, T1> {
+    public final T0 field0;
+    public final T1 field1;
+
+    public Tuple2(T0 field0, T1 field1) {
+        this.field0 = java.util.Objects.requireNonNull(field0, "field0 must not be null");
+        this.field1 = java.util.Objects.requireNonNull(field1, "field1 must not be null");
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+package org.libra.lcs;
+
+/** A triple of values, serialized one after the other. */
+public final class Tuple3<T0, T1, T2> {
+    public final T0 field0;
+    public final T1 field1;
+    public final T2 field2;
+
+    public Tuple3(T0 field0, T1 field1, T2 field2) {
+        this.field0 = java.util.Objects.requireNonNull(field0, "field0 must not be null");
+        this.field1 = java.util.Objects.requireNonNull(field1, "field1 must not be null");
+        this.field2 = java.util.Objects.requireNonNull(field2, "field2 must not be null");
+    }
+}
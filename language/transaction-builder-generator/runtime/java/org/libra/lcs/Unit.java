@@ -0,0 +1,9 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+package org.libra.lcs;
+
+/** The unit value `()`, which takes no space in LCS. */
+public final class Unit {
+    public Unit() {}
+}
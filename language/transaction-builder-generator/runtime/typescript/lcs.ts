@@ -0,0 +1,131 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+// Serializer for Libra Canonical Serialization (LCS).
+//
+// Integers of at most 32 bits are carried by a `number`, larger integers by a `bigint`.
+
+const MAX_LENGTH = 2 ** 31 - 1;
+
+export class Serializer {
+  private buffer: Uint8Array = new Uint8Array(64);
+  private offset = 0;
+
+  public serializeLen(value: number): void {
+    if (!Number.isInteger(value) || value < 0 || value > MAX_LENGTH) {
+      throw new Error(`Incorrect length value: ${value}`);
+    }
+    this.serializeUleb128(value);
+  }
+
+  public serializeVariantIndex(value: number): void {
+    this.serializeUleb128(value);
+  }
+
+  public serializeOptionTag(value: boolean): void {
+    this.writeByte(value ? 1 : 0);
+  }
+
+  public serializeBool(value: boolean): void {
+    this.writeByte(value ? 1 : 0);
+  }
+
+  public serializeU8(value: number): void {
+    this.writeNumber(value, 1, 0, 2 ** 8);
+  }
+
+  public serializeU16(value: number): void {
+    this.writeNumber(value, 2, 0, 2 ** 16);
+  }
+
+  public serializeU32(value: number): void {
+    this.writeNumber(value, 4, 0, 2 ** 32);
+  }
+
+  public serializeU64(value: bigint): void {
+    this.writeBigInt(value, 8, BigInt(0), BigInt(1) << BigInt(64));
+  }
+
+  public serializeU128(value: bigint): void {
+    this.writeBigInt(value, 16, BigInt(0), BigInt(1) << BigInt(128));
+  }
+
+  public serializeI8(value: number): void {
+    this.writeNumber(value, 1, -(2 ** 7), 2 ** 7);
+  }
+
+  public serializeI16(value: number): void {
+    this.writeNumber(value, 2, -(2 ** 15), 2 ** 15);
+  }
+
+  public serializeI32(value: number): void {
+    this.writeNumber(value, 4, -(2 ** 31), 2 ** 31);
+  }
+
+  public serializeI64(value: bigint): void {
+    const bound = BigInt(1) << BigInt(63);
+    this.writeBigInt(value, 8, -bound, bound);
+  }
+
+  public serializeI128(value: bigint): void {
+    const bound = BigInt(1) << BigInt(127);
+    this.writeBigInt(value, 16, -bound, bound);
+  }
+
+  public serializeStr(value: string): void {
+    this.serializeBytes(new TextEncoder().encode(value));
+  }
+
+  public serializeBytes(value: Uint8Array): void {
+    this.serializeLen(value.length);
+    this.ensureCapacity(value.length);
+    this.buffer.set(value, this.offset);
+    this.offset += value.length;
+  }
+
+  public getBytes(): Uint8Array {
+    return this.buffer.slice(0, this.offset);
+  }
+
+  private serializeUleb128(value: number): void {
+    while (value >= 0x80) {
+      this.writeByte((value & 0x7f) | 0x80);
+      value = Math.floor(value / 0x80);
+    }
+    this.writeByte(value);
+  }
+
+  private writeNumber(value: number, size: number, min: number, max: number): void {
+    if (!Number.isInteger(value) || value < min || value >= max) {
+      throw new Error(`Invalid value for a ${8 * size}-bit integer: ${value}`);
+    }
+    this.writeBigInt(BigInt(value), size, BigInt(min), BigInt(max));
+  }
+
+  private writeBigInt(value: bigint, size: number, min: bigint, max: bigint): void {
+    if (value < min || value >= max) {
+      throw new Error(`Invalid value for a ${8 * size}-bit integer: ${value}`);
+    }
+    // Two's complement for negative values.
+    let bits = value < BigInt(0) ? value + (BigInt(1) << BigInt(8 * size)) : value;
+    for (let i = 0; i < size; i++) {
+      this.writeByte(Number(bits & BigInt(0xff)));
+      bits >>= BigInt(8);
+    }
+  }
+
+  private writeByte(value: number): void {
+    this.ensureCapacity(1);
+    this.buffer[this.offset] = value;
+    this.offset += 1;
+  }
+
+  private ensureCapacity(size: number): void {
+    if (this.offset + size <= this.buffer.length) {
+      return;
+    }
+    const buffer = new Uint8Array(Math.max(2 * this.buffer.length, this.offset + size));
+    buffer.set(this.buffer);
+    this.buffer = buffer;
+  }
+}
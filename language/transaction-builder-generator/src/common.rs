@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_core_types::language_storage::TypeTag;
+use serde_reflection::{ContainerFormat, Format, Named, VariantFormat};
 
 /// Useful error message.
 pub(crate) fn type_not_allowed(type_tag: &TypeTag) -> ! {
@@ -22,3 +23,67 @@ pub(crate) fn prepare_doc_string(doc: &str) -> String {
         doc = doc2;
     }
 }
+
+/// Convert a snake_case name (e.g. a script name) into CamelCase, starting with a lowercase
+/// letter unless `capitalize` is set.
+pub(crate) fn to_camel_case(name: &str, capitalize: bool) -> String {
+    let mut result = String::new();
+    let mut upper = capitalize;
+    for c in name.chars() {
+        if c == '_' {
+            upper = !result.is_empty() || capitalize;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// The fields of a container, in serialization order. Anonymous fields are named `value`
+/// (newtypes) or `field0`, `field1`, etc (tuples).
+pub(crate) fn container_fields(format: &ContainerFormat) -> Vec<Named<Format>> {
+    match format {
+        ContainerFormat::UnitStruct => Vec::new(),
+        ContainerFormat::NewTypeStruct(format) => newtype_fields(format),
+        ContainerFormat::TupleStruct(formats) => tuple_fields(formats),
+        ContainerFormat::Struct(fields) => fields.clone(),
+        ContainerFormat::Enum(_) => panic!("Enums do not have fields."),
+    }
+}
+
+/// The fields of an enum variant, in serialization order (see `container_fields`).
+pub(crate) fn variant_fields(format: &VariantFormat) -> Vec<Named<Format>> {
+    match format {
+        VariantFormat::Unit => Vec::new(),
+        VariantFormat::NewType(format) => newtype_fields(format),
+        VariantFormat::Tuple(formats) => tuple_fields(formats),
+        VariantFormat::Struct(fields) => fields.clone(),
+        VariantFormat::Variable(_) => panic!("Registry should not contain variables."),
+    }
+}
+
+fn newtype_fields(format: &Format) -> Vec<Named<Format>> {
+    vec![Named {
+        name: "value".to_string(),
+        value: format.clone(),
+    }]
+}
+
+fn tuple_fields(formats: &[Format]) -> Vec<Named<Format>> {
+    formats
+        .iter()
+        .enumerate()
+        .map(|(index, format)| Named {
+            name: format!("field{}", index),
+            value: format.clone(),
+        })
+        .collect()
+}
+
+/// Useful error message for the formats that LCS (or the target language) cannot support.
+pub(crate) fn format_not_supported(format: &Format) -> ! {
+    panic!("Libra types cannot contain values of format {:?}.", format);
+}
@@ -0,0 +1,172 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{to_camel_case, type_not_allowed};
+use libra_types::transaction::{ArgumentABI, ScriptABI, TypeArgumentABI};
+use move_core_types::language_storage::TypeTag;
+
+use std::io::{Result, Write};
+
+/// Output the `extern "C"` transaction builders of the `libra-dev` crate for the given ABIs.
+/// The generated functions rely on the Rust transaction builders and on the argument
+/// conversions of `libra-dev`. The C declarations are extracted from them by `cbindgen`.
+pub fn output(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    output_preamble(out)?;
+    for abi in abis {
+        output_builder(out, abi)?;
+    }
+    Ok(())
+}
+
+fn output_preamble(out: &mut dyn Write) -> Result<()> {
+    writeln!(
+        out,
+        r#"
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+// This file was generated. Do not modify!
+//
+// To re-generate this code, run: `(cd language/stdlib && cargo run --release)`
+
+use crate::data::LibraStatus;
+use crate::error::clear_error;
+use crate::script_args::{{account_address_from_bytes, type_tag_from_currency_code, vec_from_bytes, write_script}};
+use std::os::raw::c_char;
+use transaction_builder::*;
+"#
+    )
+}
+
+fn output_builder(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    write!(out, "\n{}", quote_doc(abi.doc()))?;
+    writeln!(out, "///")?;
+    for ty_arg in abi.ty_args() {
+        writeln!(
+            out,
+            "/// @param[in] {} is the currency code of the type argument, e.g. \"LBR\".",
+            ty_arg.name()
+        )?;
+    }
+    for arg in abi.args() {
+        write!(out, "{}", quote_parameter_doc(arg))?;
+    }
+    writeln!(
+        out,
+        r#"/// @param[out] ptr_buf is the pointer that will be filled with the memory address of the script allocated in rust. User takes ownership of pointer returned by *buf, which needs to be freed using libra_free_bytes_buffer
+/// @param[out] ptr_len is the length of the script memory buffer.
+#[no_mangle]
+pub unsafe extern "C" fn libra_Transaction{}Script_from("#,
+        to_camel_case(abi.name(), true),
+    )?;
+    for parameter in quote_type_parameters(abi.ty_args())
+        .into_iter()
+        .chain(quote_parameters(abi.args()))
+    {
+        writeln!(out, "    {},", parameter)?;
+    }
+    writeln!(
+        out,
+        r#"    ptr_buf: *mut *mut u8,
+    ptr_len: *mut usize,
+) -> LibraStatus {{
+    clear_error();"#
+    )?;
+    for ty_arg in abi.ty_args() {
+        output_conversion(out, ty_arg.name(), "type_tag_from_currency_code")?;
+    }
+    for arg in abi.args() {
+        match arg.type_tag() {
+            TypeTag::Address => {
+                output_conversion(out, arg.name(), "account_address_from_bytes")?;
+            }
+            TypeTag::Vector(_) => {
+                writeln!(
+                    out,
+                    "    let {0} = vec_from_bytes({0}_bytes, {0}_len);",
+                    arg.name()
+                )?;
+            }
+            _ => (),
+        }
+    }
+    writeln!(
+        out,
+        r#"
+    let script = encode_{}_script({});
+    write_script(&script, ptr_buf, ptr_len)
+}}"#,
+        abi.name(),
+        abi.ty_args()
+            .iter()
+            .map(|ty_arg| ty_arg.name())
+            .chain(abi.args().iter().map(|arg| arg.name()))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn output_conversion(out: &mut dyn Write, name: &str, converter: &str) -> Result<()> {
+    writeln!(
+        out,
+        r#"    let {0} = match {1}({0}, "{0}") {{
+        Ok(value) => value,
+        Err(status) => return status,
+    }};"#,
+        name, converter,
+    )
+}
+
+fn quote_doc(doc: &str) -> String {
+    let doc = crate::common::prepare_doc_string(doc);
+    let text = textwrap::fill(&doc, 86);
+    textwrap::indent(&text, "/// ")
+}
+
+fn quote_parameter_doc(arg: &ArgumentABI) -> String {
+    use TypeTag::*;
+    match arg.type_tag() {
+        Address => format!(
+            "/// @param[in] {} is an account address of LIBRA_ADDRESS_SIZE bytes.\n",
+            arg.name()
+        ),
+        Vector(_) => format!(
+            "/// @param[in] {0}_bytes is the content of `{0}`, or null if it is empty.\n\
+             /// @param[in] {0}_len is the length of {0}_bytes.\n",
+            arg.name()
+        ),
+        _ => format!("/// @param[in] {}\n", arg.name()),
+    }
+}
+
+fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+    ty_args
+        .iter()
+        .map(|ty_arg| format!("{}: *const c_char", ty_arg.name()))
+        .collect()
+}
+
+fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+    args.iter()
+        .flat_map(|arg| quote_parameter(arg.name(), arg.type_tag()))
+        .collect()
+}
+
+fn quote_parameter(name: &str, type_tag: &TypeTag) -> Vec<String> {
+    use TypeTag::*;
+    match type_tag {
+        Bool => vec![format!("{}: bool", name)],
+        U8 => vec![format!("{}: u8", name)],
+        U64 => vec![format!("{}: u64", name)],
+        Address => vec![format!("{}: *const u8", name)],
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => vec![
+                format!("{}_bytes: *const u8", name),
+                format!("{}_len: usize", name),
+            ],
+            _ => type_not_allowed(type_tag),
+        },
+        // C has no standard 128-bit integer type.
+        U128 | Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
@@ -19,6 +19,9 @@ enum Language {
     Python3,
     Rust,
     Cpp,
+    TypeScript,
+    Java,
+    Go,
 }
 }
 
@@ -45,6 +48,7 @@ struct Options {
 
     /// Module name for the transaction builders installed in the `target_source_dir`.
     /// Rust crates may contain a version number, e.g. "test:1.2.0".
+    /// In Java, this is the package of the class `Helpers`, e.g. "org.libra.stdlib".
     #[structopt(long)]
     module_name: Option<String>,
 
@@ -58,6 +62,8 @@ struct Options {
     libra_version_number: String,
 
     /// Optional package name where to find the `libra_types` module (useful in Python).
+    /// In Java, this is the package of the Libra types (default: `org.libra.types`).
+    /// In Go, this is the import path of the target directory.
     #[structopt(long)]
     libra_package_name: Option<String>,
 }
@@ -79,6 +85,16 @@ fn main() {
                 Language::Cpp => {
                     buildgen::cpp::output(&mut out, &abis, options.module_name.as_deref()).unwrap()
                 }
+                Language::TypeScript => buildgen::typescript::output(&mut out, &abis).unwrap(),
+                Language::Java => {
+                    buildgen::java::output(&mut out, &abis, options.module_name.as_deref()).unwrap()
+                }
+                Language::Go => buildgen::golang::output(
+                    &mut out,
+                    &abis,
+                    options.module_name.as_deref().unwrap_or("librastdlib"),
+                )
+                .unwrap(),
             }
             return;
         }
@@ -95,6 +111,18 @@ fn main() {
                 )),
                 Language::Rust => Box::new(serdegen::rust::Installer::new(install_dir.clone())),
                 Language::Cpp => Box::new(serdegen::cpp::Installer::new(install_dir.clone())),
+                // Not supported by serde-generate yet: Libra types are generated by this crate.
+                Language::TypeScript => {
+                    Box::new(buildgen::typescript::Installer::new(install_dir.clone()))
+                }
+                Language::Java => Box::new(buildgen::java::Installer::new(
+                    install_dir.clone(),
+                    options.libra_package_name.clone(),
+                )),
+                Language::Go => Box::new(buildgen::golang::Installer::new(
+                    install_dir.clone(),
+                    options.libra_package_name.clone(),
+                )),
             };
 
         match options.language {
@@ -115,6 +143,11 @@ fn main() {
                     format!("libra-types:{}", options.libra_version_number)
                 }
             }
+            Language::Java => options
+                .libra_package_name
+                .clone()
+                .unwrap_or_else(|| buildgen::java::DEFAULT_LIBRA_PACKAGE.to_string()),
+            Language::Go => "libratypes".to_string(),
             _ => "libra_types".to_string(),
         };
         installer.install_module(&name, &registry).unwrap();
@@ -133,6 +166,15 @@ fn main() {
                 options.libra_version_number,
             )),
             Language::Cpp => Box::new(buildgen::cpp::Installer::new(install_dir)),
+            Language::TypeScript => Box::new(buildgen::typescript::Installer::new(install_dir)),
+            Language::Java => Box::new(buildgen::java::Installer::new(
+                install_dir,
+                options.libra_package_name,
+            )),
+            Language::Go => Box::new(buildgen::golang::Installer::new(
+                install_dir,
+                options.libra_package_name,
+            )),
         };
 
     if let Some(name) = options.module_name {
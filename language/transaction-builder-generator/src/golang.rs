@@ -0,0 +1,457 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    container_fields, format_not_supported, to_camel_case, type_not_allowed, variant_fields,
+};
+use libra_types::transaction::{ArgumentABI, ScriptABI, TypeArgumentABI};
+use move_core_types::language_storage::TypeTag;
+use serde_reflection::{ContainerFormat, Format, Named, Registry};
+
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Name of the Go package of the LCS runtime.
+const LCS_PACKAGE: &str = "lcs";
+
+/// Name of the Go package of the Libra types.
+const LIBRA_PACKAGE: &str = "libratypes";
+
+/// Output a Go package `package_name` providing transaction builders for the given ABIs.
+/// The packages `libratypes` and `lcs` are imported without prefix (e.g. from GOPATH).
+pub fn output(out: &mut dyn Write, abis: &[ScriptABI], package_name: &str) -> Result<()> {
+    output_with_import_prefix(out, abis, package_name, None)
+}
+
+fn output_with_import_prefix(
+    out: &mut dyn Write,
+    abis: &[ScriptABI],
+    package_name: &str,
+    import_prefix: Option<&str>,
+) -> Result<()> {
+    output_preamble(out, abis, package_name, import_prefix)?;
+    for abi in abis {
+        output_builder(out, abi)?;
+    }
+    Ok(())
+}
+
+fn quote_import_path(import_prefix: Option<&str>, package_name: &str) -> String {
+    match import_prefix {
+        None => format!("\"{}\"", package_name),
+        Some(prefix) => format!("\"{}/{}\"", prefix, package_name),
+    }
+}
+
+fn output_preamble(
+    out: &mut dyn Write,
+    abis: &[ScriptABI],
+    package_name: &str,
+    import_prefix: Option<&str>,
+) -> Result<()> {
+    writeln!(out, "package {}\n\nimport (", package_name)?;
+    // Go rejects unused imports and the LCS runtime is only needed for `lcs.Uint128`.
+    let uses_u128 = abis.iter().any(|abi| {
+        abi.args()
+            .iter()
+            .any(|arg| arg.type_tag() == &TypeTag::U128)
+    });
+    if uses_u128 {
+        writeln!(out, "\t{}", quote_import_path(import_prefix, LCS_PACKAGE))?;
+    }
+    writeln!(
+        out,
+        "\t{}\n)",
+        quote_import_path(import_prefix, LIBRA_PACKAGE)
+    )
+}
+
+fn output_builder(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    write!(out, "\n{}", quote_doc(abi.doc()))?;
+    writeln!(
+        out,
+        "func Encode{}Script({}) libratypes.Script {{",
+        to_camel_case(abi.name(), /* capitalize */ true),
+        [
+            quote_type_parameters(abi.ty_args()),
+            quote_parameters(abi.args()),
+        ]
+        .concat()
+        .join(", ")
+    )?;
+    writeln!(
+        out,
+        r#"	return libratypes.Script{{
+		Code:   {},
+		TyArgs: []libratypes.TypeTag{{{}}},
+		Args:   []libratypes.TransactionArgument{{{}}},
+	}}
+}}"#,
+        quote_code(abi.code()),
+        quote_type_arguments(abi.ty_args()),
+        quote_arguments(abi.args()),
+    )?;
+    Ok(())
+}
+
+fn quote_doc(doc: &str) -> String {
+    let doc = crate::common::prepare_doc_string(doc);
+    let text = textwrap::fill(&doc, 86);
+    textwrap::indent(&text, "// ")
+}
+
+fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+    ty_args
+        .iter()
+        .map(|ty_arg| format!("{} libratypes.TypeTag", ty_arg.name()))
+        .collect()
+}
+
+fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+    args.iter()
+        .map(|arg| format!("{} {}", arg.name(), quote_type(arg.type_tag())))
+        .collect()
+}
+
+fn quote_code(code: &[u8]) -> String {
+    format!(
+        "[]byte{{{}}}",
+        code.iter()
+            .map(|x| format!("{}", x))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+    ty_args
+        .iter()
+        .map(|ty_arg| ty_arg.name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_arguments(args: &[ArgumentABI]) -> String {
+    args.iter()
+        .map(|arg| make_transaction_argument(arg.type_tag(), arg.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_type(type_tag: &TypeTag) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => "bool".into(),
+        U8 => "uint8".into(),
+        U64 => "uint64".into(),
+        U128 => "lcs.Uint128".into(),
+        Address => "libratypes.AccountAddress".into(),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => "[]byte".into(),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+fn make_transaction_argument(type_tag: &TypeTag, name: &str) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => format!("libratypes.TransactionArgument__Bool{{Value: {}}}", name),
+        U8 => format!("libratypes.TransactionArgument__U8{{Value: {}}}", name),
+        U64 => format!("libratypes.TransactionArgument__U64{{Value: {}}}", name),
+        U128 => format!("libratypes.TransactionArgument__U128{{Value: {}}}", name),
+        Address => format!("libratypes.TransactionArgument__Address{{Value: {}}}", name),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => format!(
+                "libratypes.TransactionArgument__U8Vector{{Value: {}}}",
+                name
+            ),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+/// Output a Go package `package_name` defining the Libra types of the registry.
+/// * Enums are interfaces implemented by one struct `E__<name>` per variant.
+/// * Newtype structs are named types, e.g. `type AccountAddress [16]uint8`, unless their
+/// content is an enum.
+/// * Every type can be serialized in LCS with the method `LcsSerialize`.
+pub fn output_libra_types(
+    out: &mut dyn Write,
+    registry: &Registry,
+    package_name: &str,
+    import_prefix: Option<&str>,
+) -> Result<()> {
+    writeln!(
+        out,
+        "package {}\n\nimport (\n\t{}\n)",
+        package_name,
+        quote_import_path(import_prefix, LCS_PACKAGE)
+    )?;
+    for (name, format) in registry {
+        match format {
+            ContainerFormat::Enum(variants) => {
+                writeln!(
+                    out,
+                    r#"
+type {0} interface {{
+	is{0}()
+	Serialize(serializer *lcs.Serializer) error
+	LcsSerialize() ([]byte, error)
+}}"#,
+                    name
+                )?;
+                for (index, variant) in variants {
+                    let variant_name = format!("{}__{}", name, variant.name);
+                    let fields = variant_fields(&variant.value);
+                    output_struct(out, &variant_name, &fields)?;
+                    writeln!(out, "\nfunc ({}) is{}() {{}}", variant_name, name)?;
+                    output_serialize_start(out, &variant_name)?;
+                    let code = check_call(&format!("serializer.SerializeVariantIndex({})", index));
+                    write!(out, "{}", textwrap::indent(&code, "\t"))?;
+                    output_serialize_fields(out, &fields)?;
+                    output_serialize_end(out, &variant_name)?;
+                }
+            }
+            ContainerFormat::NewTypeStruct(format) if !is_enum(registry, format) => {
+                writeln!(out, "\ntype {} {}", name, quote_format(format))?;
+                output_serialize_start(out, name)?;
+                let value = format!("({})(obj)", quote_format(format));
+                let code = quote_serialize(format, &value, 0);
+                write!(out, "{}", textwrap::indent(&code, "\t"))?;
+                output_serialize_end(out, name)?;
+            }
+            _ => {
+                let fields = container_fields(format);
+                output_struct(out, name, &fields)?;
+                output_serialize_start(out, name)?;
+                output_serialize_fields(out, &fields)?;
+                output_serialize_end(out, name)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_enum(registry: &Registry, format: &Format) -> bool {
+    match format {
+        Format::TypeName(name) => matches!(registry.get(name), Some(ContainerFormat::Enum(_))),
+        _ => false,
+    }
+}
+
+fn output_struct(out: &mut dyn Write, name: &str, fields: &[Named<Format>]) -> Result<()> {
+    if fields.is_empty() {
+        return writeln!(out, "\ntype {} struct{{}}", name);
+    }
+    writeln!(out, "\ntype {} struct {{", name)?;
+    for field in fields {
+        writeln!(
+            out,
+            "\t{} {}",
+            to_camel_case(&field.name, /* capitalize */ true),
+            quote_format(&field.value)
+        )?;
+    }
+    writeln!(out, "}}")
+}
+
+fn output_serialize_start(out: &mut dyn Write, name: &str) -> Result<()> {
+    writeln!(
+        out,
+        "\nfunc (obj {}) Serialize(serializer *lcs.Serializer) error {{",
+        name
+    )
+}
+
+fn output_serialize_fields(out: &mut dyn Write, fields: &[Named<Format>]) -> Result<()> {
+    for field in fields {
+        let value = format!("obj.{}", to_camel_case(&field.name, /* capitalize */ true));
+        let code = quote_serialize(&field.value, &value, 0);
+        write!(out, "{}", textwrap::indent(&code, "\t"))?;
+    }
+    Ok(())
+}
+
+fn output_serialize_end(out: &mut dyn Write, name: &str) -> Result<()> {
+    writeln!(
+        out,
+        r#"	return nil
+}}
+
+func (obj {}) LcsSerialize() ([]byte, error) {{
+	serializer := lcs.NewSerializer()
+	if err := obj.Serialize(serializer); err != nil {{
+		return nil, err
+	}}
+	return serializer.GetBytes(), nil
+}}"#,
+        name
+    )
+}
+
+/// Go type of the values of the given format.
+fn quote_format(format: &Format) -> String {
+    use Format::*;
+    match format {
+        TypeName(name) => name.clone(),
+        Unit => "struct{}".into(),
+        Bool => "bool".into(),
+        I8 => "int8".into(),
+        I16 => "int16".into(),
+        I32 => "int32".into(),
+        I64 => "int64".into(),
+        I128 => "lcs.Int128".into(),
+        U8 => "uint8".into(),
+        U16 => "uint16".into(),
+        U32 => "uint32".into(),
+        U64 => "uint64".into(),
+        U128 => "lcs.Uint128".into(),
+        Str => "string".into(),
+        Bytes => "[]byte".into(),
+        Option(format) => format!("*{}", quote_format(format)),
+        Seq(format) => format!("[]{}", quote_format(format)),
+        TupleArray { content, size } => format!("[{}]{}", size, quote_format(content)),
+        Tuple(formats) => format!(
+            "struct {{{}}}",
+            formats
+                .iter()
+                .enumerate()
+                .map(|(index, format)| format!("Field{} {}", index, quote_format(format)))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ),
+        _ => format_not_supported(format),
+    }
+}
+
+/// Go statements serializing the expression `value` of the given format.
+fn quote_serialize(format: &Format, value: &str, depth: usize) -> String {
+    use Format::*;
+    let check = |method: &str| check_call(&format!("serializer.{}({})", method, value));
+    match format {
+        TypeName(_) => check_call(&format!("{}.Serialize(serializer)", value)),
+        Unit => String::new(),
+        Bool => check("SerializeBool"),
+        I8 => check("SerializeI8"),
+        I16 => check("SerializeI16"),
+        I32 => check("SerializeI32"),
+        I64 => check("SerializeI64"),
+        I128 => check("SerializeI128"),
+        U8 => check("SerializeU8"),
+        U16 => check("SerializeU16"),
+        U32 => check("SerializeU32"),
+        U64 => check("SerializeU64"),
+        U128 => check("SerializeU128"),
+        Str => check("SerializeStr"),
+        Bytes => check("SerializeBytes"),
+        Option(format) => format!(
+            "if {} != nil {{\n{}{}}} else {{\n{}}}\n",
+            value,
+            textwrap::indent(&check_call("serializer.SerializeOptionTag(true)"), "\t"),
+            textwrap::indent(
+                &quote_serialize(format, &format!("(*{})", value), depth),
+                "\t"
+            ),
+            textwrap::indent(&check_call("serializer.SerializeOptionTag(false)"), "\t"),
+        ),
+        Seq(format) => format!(
+            "{}{}",
+            check_call(&format!("serializer.SerializeLen(uint64(len({})))", value)),
+            quote_serialize_items(format, value, depth),
+        ),
+        TupleArray { content, .. } => quote_serialize_items(content, value, depth),
+        Tuple(formats) => formats
+            .iter()
+            .enumerate()
+            .map(|(index, format)| {
+                quote_serialize(format, &format!("{}.Field{}", value, index), depth)
+            })
+            .collect(),
+        _ => format_not_supported(format),
+    }
+}
+
+/// Go statement calling a function that may return an error.
+fn check_call(call: &str) -> String {
+    format!("if err := {}; err != nil {{\n\treturn err\n}}\n", call)
+}
+
+fn quote_serialize_items(format: &Format, value: &str, depth: usize) -> String {
+    let item = format!("item{}", depth);
+    format!(
+        "for _, {} := range {} {{\n{}}}\n",
+        item,
+        value,
+        textwrap::indent(&quote_serialize(format, &item, depth + 1), "\t"),
+    )
+}
+
+/// Install Go packages as sub-directories of `install_dir`. Packages import each other as
+/// `<import_prefix>/<name>` where the prefix is the import path of `install_dir`, if any.
+pub struct Installer {
+    install_dir: PathBuf,
+    import_prefix: Option<String>,
+}
+
+impl Installer {
+    pub fn new(install_dir: PathBuf, import_prefix: Option<String>) -> Self {
+        Installer {
+            install_dir,
+            import_prefix,
+        }
+    }
+
+    fn create_package_file(&self, name: &str) -> Result<std::fs::File> {
+        let dir_path = self.install_dir.join(name);
+        std::fs::create_dir_all(&dir_path)?;
+        std::fs::File::create(dir_path.join(format!("{}.go", name)))
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[ScriptABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_package_file(name)?;
+        output_with_import_prefix(&mut file, abis, name, self.import_prefix.as_deref())?;
+        Ok(())
+    }
+}
+
+impl serde_generate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    /// Install the Libra types of the registry. Transaction builders expect the package
+    /// to be named `libratypes`.
+    fn install_module(
+        &self,
+        name: &str,
+        registry: &Registry,
+    ) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_package_file(name)?;
+        output_libra_types(&mut file, registry, name, self.import_prefix.as_deref())?;
+        Ok(())
+    }
+
+    /// The LCS runtime contains everything the generated code needs.
+    fn install_serde_runtime(&self) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn install_lcs_runtime(&self) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_package_file(LCS_PACKAGE)?;
+        file.write_all(include_str!("../runtime/golang/lcs/lcs.go").as_bytes())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,508 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    container_fields, format_not_supported, to_camel_case, type_not_allowed, variant_fields,
+};
+use libra_types::transaction::{ArgumentABI, ScriptABI, TypeArgumentABI};
+use move_core_types::language_storage::TypeTag;
+use serde_reflection::{ContainerFormat, Format, Named, Registry};
+
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Package of the LCS runtime.
+const LCS_PACKAGE: &str = "org.libra.lcs";
+
+/// Default package of the Libra types.
+pub const DEFAULT_LIBRA_PACKAGE: &str = "org.libra.types";
+
+/// Name of the class holding the transaction builders.
+const BUILDERS_CLASS: &str = "Helpers";
+
+/// Output a Java class `Helpers` providing transaction builders for the given ABIs.
+/// Libra types are expected in the package `org.libra.types`.
+pub fn output(out: &mut dyn Write, abis: &[ScriptABI], package_name: Option<&str>) -> Result<()> {
+    output_with_libra_package(out, abis, package_name, DEFAULT_LIBRA_PACKAGE)
+}
+
+fn output_with_libra_package(
+    out: &mut dyn Write,
+    abis: &[ScriptABI],
+    package_name: Option<&str>,
+    libra_package_name: &str,
+) -> Result<()> {
+    output_preamble(out, package_name, libra_package_name)?;
+    writeln!(out, "\npublic final class {} {{", BUILDERS_CLASS)?;
+    for abi in abis {
+        output_builder(out, abi)?;
+    }
+    writeln!(out, "\n}}")
+}
+
+fn output_preamble(
+    out: &mut dyn Write,
+    package_name: Option<&str>,
+    libra_package_name: &str,
+) -> Result<()> {
+    if let Some(name) = package_name {
+        writeln!(out, "package {};\n", name)?;
+    }
+    writeln!(
+        out,
+        r#"import java.math.BigInteger;
+import {0}.AccountAddress;
+import {0}.Script;
+import {0}.TransactionArgument;
+import {0}.TypeTag;"#,
+        libra_package_name
+    )
+}
+
+fn output_builder(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    write!(out, "\n{}", quote_doc(abi.doc()))?;
+    writeln!(
+        out,
+        "    public static Script encode{}Script({}) {{",
+        to_camel_case(abi.name(), /* capitalize */ true),
+        [
+            quote_type_parameters(abi.ty_args()),
+            quote_parameters(abi.args()),
+        ]
+        .concat()
+        .join(", ")
+    )?;
+    writeln!(
+        out,
+        r#"        return new Script(
+            {},
+            java.util.Arrays.<TypeTag>asList({}),
+            java.util.Arrays.<TransactionArgument>asList({}));
+    }}"#,
+        quote_code(abi.code()),
+        quote_type_arguments(abi.ty_args()),
+        quote_arguments(abi.args()),
+    )?;
+    Ok(())
+}
+
+fn quote_doc(doc: &str) -> String {
+    let doc = crate::common::prepare_doc_string(doc);
+    let text = textwrap::indent(&textwrap::fill(&doc, 86), "     * ");
+    format!("    /**\n{}     */\n", text)
+}
+
+fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+    ty_args
+        .iter()
+        .map(|ty_arg| format!("TypeTag {}", ty_arg.name()))
+        .collect()
+}
+
+fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+    args.iter()
+        .map(|arg| format!("{} {}", quote_type(arg.type_tag()), arg.name()))
+        .collect()
+}
+
+fn quote_code(code: &[u8]) -> String {
+    format!(
+        "new byte[] {{{}}}",
+        code.iter()
+            .map(|x| {
+                if *x < 128 {
+                    format!("{}", x)
+                } else {
+                    format!("(byte) {}", x)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+    ty_args
+        .iter()
+        .map(|ty_arg| ty_arg.name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_arguments(args: &[ArgumentABI]) -> String {
+    args.iter()
+        .map(|arg| make_transaction_argument(arg.type_tag(), arg.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_type(type_tag: &TypeTag) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => "Boolean".into(),
+        U8 => "Byte".into(),
+        U64 => "Long".into(),
+        U128 => "BigInteger".into(),
+        Address => "AccountAddress".into(),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => "byte[]".into(),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+fn make_transaction_argument(type_tag: &TypeTag, name: &str) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => format!("new TransactionArgument.Bool({})", name),
+        U8 => format!("new TransactionArgument.U8({})", name),
+        U64 => format!("new TransactionArgument.U64({})", name),
+        U128 => format!("new TransactionArgument.U128({})", name),
+        Address => format!("new TransactionArgument.Address({})", name),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => format!("new TransactionArgument.U8Vector({})", name),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+/// Output the Java class defining the Libra type `name` in the package `package_name`.
+/// Each class can be serialized in LCS with the method `lcsSerialize`.
+pub fn output_libra_type(
+    out: &mut dyn Write,
+    package_name: &str,
+    name: &str,
+    format: &ContainerFormat,
+) -> Result<()> {
+    writeln!(out, "package {};\n", package_name)?;
+    match format {
+        ContainerFormat::Enum(variants) => {
+            writeln!(out, "public abstract class {} {{", name)?;
+            writeln!(
+                out,
+                "    abstract public void serialize({}.Serializer serializer);\n",
+                LCS_PACKAGE
+            )?;
+            output_lcs_serialize(out)?;
+            for (index, variant) in variants {
+                let fields = variant_fields(&variant.value);
+                writeln!(
+                    out,
+                    "\n    public static final class {} extends {} {{",
+                    variant.name, name
+                )?;
+                output_fields(out, package_name, &variant.name, &fields, "        ")?;
+                writeln!(
+                    out,
+                    "\n        public void serialize({}.Serializer serializer) {{",
+                    LCS_PACKAGE
+                )?;
+                writeln!(
+                    out,
+                    "            serializer.serializeVariantIndex({});",
+                    index
+                )?;
+                output_serialize_fields(out, package_name, &fields, "            ")?;
+                writeln!(out, "        }}\n    }}")?;
+            }
+        }
+        _ => {
+            let fields = container_fields(format);
+            writeln!(out, "public final class {} {{", name)?;
+            output_fields(out, package_name, name, &fields, "    ")?;
+            writeln!(
+                out,
+                "\n    public void serialize({}.Serializer serializer) {{",
+                LCS_PACKAGE
+            )?;
+            output_serialize_fields(out, package_name, &fields, "        ")?;
+            writeln!(out, "    }}\n")?;
+            output_lcs_serialize(out)?;
+        }
+    }
+    writeln!(out, "}}")
+}
+
+fn output_fields(
+    out: &mut dyn Write,
+    package_name: &str,
+    class_name: &str,
+    fields: &[Named<Format>],
+    indent: &str,
+) -> Result<()> {
+    for field in fields {
+        writeln!(
+            out,
+            "{}public final {} {};",
+            indent,
+            quote_format(package_name, &field.value),
+            field.name
+        )?;
+    }
+    if fields.is_empty() {
+        return writeln!(out, "{}public {}() {{}}", indent, class_name);
+    }
+    writeln!(
+        out,
+        "\n{}public {}({}) {{",
+        indent,
+        class_name,
+        fields
+            .iter()
+            .map(|field| format!(
+                "{} {}",
+                quote_format(package_name, &field.value),
+                field.name
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )?;
+    for field in fields {
+        writeln!(
+            out,
+            "{0}    this.{1} = java.util.Objects.requireNonNull({1}, \"{1} must not be null\");",
+            indent, field.name
+        )?;
+    }
+    writeln!(out, "{}}}", indent)
+}
+
+fn output_serialize_fields(
+    out: &mut dyn Write,
+    package_name: &str,
+    fields: &[Named<Format>],
+    indent: &str,
+) -> Result<()> {
+    for field in fields {
+        let code = quote_serialize(
+            package_name,
+            &field.value,
+            &format!("this.{}", field.name),
+            0,
+        );
+        write!(out, "{}", textwrap::indent(&code, indent))?;
+    }
+    Ok(())
+}
+
+fn output_lcs_serialize(out: &mut dyn Write) -> Result<()> {
+    writeln!(
+        out,
+        r#"    public byte[] lcsSerialize() {{
+        {0}.Serializer serializer = new {0}.Serializer();
+        serialize(serializer);
+        return serializer.getBytes();
+    }}"#,
+        LCS_PACKAGE
+    )
+}
+
+/// Java type of the values of the given format. Libra types are always fully qualified
+/// because enum variants may shadow them, e.g. `TransactionPayload.Script`.
+fn quote_format(package_name: &str, format: &Format) -> String {
+    use Format::*;
+    match format {
+        TypeName(name) => format!("{}.{}", package_name, name),
+        Unit => format!("{}.Unit", LCS_PACKAGE),
+        Bool => "Boolean".into(),
+        I8 | U8 => "Byte".into(),
+        I16 | U16 => "Short".into(),
+        I32 | U32 => "Integer".into(),
+        I64 | U64 => "Long".into(),
+        I128 | U128 => "java.math.BigInteger".into(),
+        Str => "String".into(),
+        Bytes => "byte[]".into(),
+        Option(format) => format!("java.util.Optional<{}>", quote_format(package_name, format)),
+        Seq(format)
+        | TupleArray {
+            content: format, ..
+        } => format!("java.util.List<{}>", quote_format(package_name, format)),
+        Tuple(formats) if formats.len() == 2 || formats.len() == 3 => format!(
+            "{}.Tuple{}<{}>",
+            LCS_PACKAGE,
+            formats.len(),
+            formats
+                .iter()
+                .map(|format| quote_format(package_name, format))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => format_not_supported(format),
+    }
+}
+
+/// Java statements serializing the expression `value` of the given format.
+fn quote_serialize(package_name: &str, format: &Format, value: &str, depth: usize) -> String {
+    use Format::*;
+    match format {
+        TypeName(_) => format!("{}.serialize(serializer);\n", value),
+        Unit => String::new(),
+        Bool => format!("serializer.serializeBool({});\n", value),
+        I8 => format!("serializer.serializeI8({});\n", value),
+        I16 => format!("serializer.serializeI16({});\n", value),
+        I32 => format!("serializer.serializeI32({});\n", value),
+        I64 => format!("serializer.serializeI64({});\n", value),
+        I128 => format!("serializer.serializeI128({});\n", value),
+        U8 => format!("serializer.serializeU8({});\n", value),
+        U16 => format!("serializer.serializeU16({});\n", value),
+        U32 => format!("serializer.serializeU32({});\n", value),
+        U64 => format!("serializer.serializeU64({});\n", value),
+        U128 => format!("serializer.serializeU128({});\n", value),
+        Str => format!("serializer.serializeStr({});\n", value),
+        Bytes => format!("serializer.serializeBytes({});\n", value),
+        Option(format) => format!(
+            r#"if ({0}.isPresent()) {{
+    serializer.serializeOptionTag(true);
+{1}}} else {{
+    serializer.serializeOptionTag(false);
+}}
+"#,
+            value,
+            textwrap::indent(
+                &quote_serialize(package_name, format, &format!("{}.get()", value), depth),
+                "    "
+            ),
+        ),
+        Seq(format) => format!(
+            "serializer.serializeLen({}.size());\n{}",
+            value,
+            quote_serialize_items(package_name, format, value, depth),
+        ),
+        TupleArray { content, size } => format!(
+            r#"if ({0}.size() != {1}) {{
+    throw new IllegalArgumentException("Invalid length for fixed-size array: " + {0}.size() + " instead of " + {1});
+}}
+{2}"#,
+            value,
+            size,
+            quote_serialize_items(package_name, content, value, depth),
+        ),
+        Tuple(formats) => formats
+            .iter()
+            .enumerate()
+            .map(|(index, format)| {
+                quote_serialize(
+                    package_name,
+                    format,
+                    &format!("{}.field{}", value, index),
+                    depth,
+                )
+            })
+            .collect(),
+        _ => format_not_supported(format),
+    }
+}
+
+fn quote_serialize_items(package_name: &str, format: &Format, value: &str, depth: usize) -> String {
+    let item = format!("item{}", depth);
+    format!(
+        "for ({} {} : {}) {{\n{}}}\n",
+        quote_format(package_name, format),
+        item,
+        value,
+        textwrap::indent(
+            &quote_serialize(package_name, format, &item, depth + 1),
+            "    "
+        ),
+    )
+}
+
+/// Install Java transaction builders and Libra types as source files under `install_dir`,
+/// following the usual directory layout of Java packages.
+pub struct Installer {
+    install_dir: PathBuf,
+    libra_package_name: Option<String>,
+}
+
+impl Installer {
+    pub fn new(install_dir: PathBuf, libra_package_name: Option<String>) -> Self {
+        Installer {
+            install_dir,
+            libra_package_name,
+        }
+    }
+
+    fn create_package_dir(&self, package_name: &str) -> Result<PathBuf> {
+        let mut dir_path = self.install_dir.clone();
+        for part in package_name.split('.') {
+            dir_path = dir_path.join(part);
+        }
+        std::fs::create_dir_all(&dir_path)?;
+        Ok(dir_path)
+    }
+
+    fn libra_package_name(&self) -> &str {
+        self.libra_package_name
+            .as_deref()
+            .unwrap_or(DEFAULT_LIBRA_PACKAGE)
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[ScriptABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.create_package_dir(name)?;
+        let mut file = std::fs::File::create(dir_path.join(format!("{}.java", BUILDERS_CLASS)))?;
+        output_with_libra_package(&mut file, abis, Some(name), self.libra_package_name())?;
+        Ok(())
+    }
+}
+
+impl serde_generate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    /// Install the Libra types of the registry in the package `name`.
+    fn install_module(
+        &self,
+        name: &str,
+        registry: &Registry,
+    ) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.create_package_dir(name)?;
+        for (type_name, format) in registry {
+            let mut file = std::fs::File::create(dir_path.join(format!("{}.java", type_name)))?;
+            output_libra_type(&mut file, name, type_name, format)?;
+        }
+        Ok(())
+    }
+
+    /// The LCS runtime contains everything the generated code needs.
+    fn install_serde_runtime(&self) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn install_lcs_runtime(&self) -> std::result::Result<(), Self::Error> {
+        let dir_path = self.create_package_dir(LCS_PACKAGE)?;
+        for (file_name, content) in &[
+            (
+                "Serializer.java",
+                include_str!("../runtime/java/org/libra/lcs/Serializer.java"),
+            ),
+            (
+                "Tuple2.java",
+                include_str!("../runtime/java/org/libra/lcs/Tuple2.java"),
+            ),
+            (
+                "Tuple3.java",
+                include_str!("../runtime/java/org/libra/lcs/Tuple3.java"),
+            ),
+            (
+                "Unit.java",
+                include_str!("../runtime/java/org/libra/lcs/Unit.java"),
+            ),
+        ] {
+            std::fs::write(dir_path.join(file_name), content)?;
+        }
+        Ok(())
+    }
+}
@@ -6,10 +6,18 @@ use std::io::Read;
 
 /// Support for code-generation in C++17.
 pub mod cpp;
+/// Support for code-generation of the `extern "C"` functions of `libra-dev`, in Rust.
+pub mod ffi;
+/// Support for code-generation in Go.
+pub mod golang;
+/// Support for code-generation in Java.
+pub mod java;
 /// Support for code-generation in Python 3.
 pub mod python3;
 /// Support for code-generation in Rust.
 pub mod rust;
+/// Support for code-generation in TypeScript.
+pub mod typescript;
 
 /// Internals shared between languages.
 mod common;
@@ -1,23 +1,40 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::common::type_not_allowed;
+use crate::common::{to_camel_case, type_not_allowed};
 use libra_types::transaction::{ArgumentABI, ScriptABI, TypeArgumentABI};
 use move_core_types::language_storage::TypeTag;
 
 use std::{
+    collections::BTreeSet,
     io::{Result, Write},
     path::PathBuf,
 };
 
 /// Output transaction builders in Rust for the given ABIs.
 /// If `local_types` is true, we generate a file suitable for the Libra codebase itself
-/// rather than using serde-generated, standalone definitions.
+/// rather than using serde-generated, standalone definitions. In this case, we also
+/// generate the enum `ScriptCall` and the decoders that recognize a `Script` and recover
+/// its typed arguments.
 pub fn output(out: &mut dyn Write, abis: &[ScriptABI], local_types: bool) -> Result<()> {
     output_preamble(out, local_types)?;
+    if local_types {
+        output_script_call_enum(out, abis)?;
+        output_script_call_impl(out, abis)?;
+    }
     for abi in abis {
         output_builder(out, abi, local_types)?;
     }
+    if local_types {
+        for abi in abis {
+            output_decoder(out, abi)?;
+        }
+        output_decoder_map(out, abis)?;
+        output_decoding_helpers(out, abis)?;
+        for abi in abis {
+            output_code_constant(out, abi)?;
+        }
+    }
     Ok(())
 }
 
@@ -34,6 +51,8 @@ fn output_preamble(out: &mut dyn Write, local_types: bool) -> Result<()> {
 use move_core_types::language_storage::TypeTag;
 use libra_types::transaction::{Script, TransactionArgument};
 use libra_types::account_address::AccountAddress;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
 "#
     } else {
         r#"
@@ -44,6 +63,145 @@ use serde_bytes::ByteBuf;
     writeln!(out, "{}", preamble)
 }
 
+fn output_script_call_enum(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    writeln!(
+        out,
+        r#"
+/// Structured representation of a call into a known Move script.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScriptCall {{"#
+    )?;
+    for abi in abis {
+        write!(out, "{}", textwrap::indent(&quote_doc(abi.doc()), "    "))?;
+        writeln!(
+            out,
+            "    {} {{ {} }},",
+            to_camel_case(abi.name(), true),
+            [
+                quote_type_parameters(abi.ty_args()),
+                quote_parameters(abi.args()),
+            ]
+            .concat()
+            .join(", ")
+        )?;
+    }
+    writeln!(out, "}}")
+}
+
+fn output_script_call_impl(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    writeln!(
+        out,
+        r#"
+impl ScriptCall {{
+    /// Build a Libra `Script` from a structured object `ScriptCall`.
+    pub fn encode(self) -> Script {{
+        use ScriptCall::*;
+        match self {{"#
+    )?;
+    for abi in abis {
+        writeln!(
+            out,
+            "            {} {{ {} }} => encode_{}_script({}),",
+            to_camel_case(abi.name(), true),
+            quote_field_names(abi),
+            abi.name(),
+            quote_field_names(abi),
+        )?;
+    }
+    writeln!(
+        out,
+        r#"        }}
+    }}
+
+    /// Try to recognize a Libra `Script` and convert it into a structured object `ScriptCall`.
+    pub fn decode(script: &Script) -> Option<ScriptCall> {{
+        match SCRIPT_DECODER_MAP.get(script.code()) {{
+            Some(decoder) => decoder(script),
+            None => None,
+        }}
+    }}
+
+    /// Return the name of a Libra `Script` from a structured object `ScriptCall`.
+    pub fn name(&self) -> &'static str {{
+        use ScriptCall::*;
+        match self {{"#
+    )?;
+    for abi in abis {
+        writeln!(
+            out,
+            "            {} {{ .. }} => \"{}\",",
+            to_camel_case(abi.name(), true),
+            abi.name(),
+        )?;
+    }
+    writeln!(
+        out,
+        r#"        }}
+    }}
+
+    /// Return the names and values of the type arguments of a structured object `ScriptCall`.
+    pub fn type_arguments(&self) -> Vec<(&'static str, &TypeTag)> {{
+        use ScriptCall::*;
+        match self {{"#
+    )?;
+    for abi in abis {
+        writeln!(
+            out,
+            "            {} {{ {} }} => vec![{}],",
+            to_camel_case(abi.name(), true),
+            abi.ty_args()
+                .iter()
+                .map(|ty_arg| ty_arg.name())
+                .chain(std::iter::once(".."))
+                .collect::<Vec<_>>()
+                .join(", "),
+            abi.ty_args()
+                .iter()
+                .map(|ty_arg| format!("(\"{0}\", {0})", ty_arg.name()))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )?;
+    }
+    writeln!(
+        out,
+        r#"        }}
+    }}
+
+    /// Return the names and values of the arguments of a structured object `ScriptCall`.
+    pub fn arguments(&self) -> Vec<(&'static str, TransactionArgument)> {{
+        use ScriptCall::*;
+        match self {{"#
+    )?;
+    for abi in abis {
+        writeln!(
+            out,
+            "            {} {{ {} }} => vec![{}],",
+            to_camel_case(abi.name(), true),
+            abi.args()
+                .iter()
+                .map(|arg| arg.name())
+                .chain(std::iter::once(".."))
+                .collect::<Vec<_>>()
+                .join(", "),
+            abi.args()
+                .iter()
+                .map(|arg| format!(
+                    "(\"{}\", {})",
+                    arg.name(),
+                    make_transaction_argument(arg.type_tag(), &quote_argument_value(arg), true)
+                ))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )?;
+    }
+    writeln!(
+        out,
+        r#"        }}
+    }}
+}}"#
+    )
+}
+
 fn output_builder(out: &mut dyn Write, abi: &ScriptABI, local_types: bool) -> Result<()> {
     write!(out, "\n{}", quote_doc(abi.doc()))?;
     writeln!(
@@ -61,11 +219,11 @@ fn output_builder(out: &mut dyn Write, abi: &ScriptABI, local_types: bool) -> Re
         writeln!(
             out,
             r#"    Script::new(
-        {},
+        {}.to_vec(),
         vec![{}],
         vec![{}],
     )"#,
-            quote_code(abi.code()),
+            quote_code_constant_name(abi),
             quote_type_arguments(abi.ty_args()),
             quote_arguments(abi.args(), local_types),
         )?;
@@ -73,7 +231,7 @@ fn output_builder(out: &mut dyn Write, abi: &ScriptABI, local_types: bool) -> Re
         writeln!(
             out,
             r#"    Script {{
-        code: ByteBuf::from({}),
+        code: ByteBuf::from(vec!{}),
         ty_args: vec![{}],
         args: vec![{}],
     }}"#,
@@ -86,6 +244,137 @@ fn output_builder(out: &mut dyn Write, abi: &ScriptABI, local_types: bool) -> Re
     Ok(())
 }
 
+fn output_decoder(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    let quote_names = |names: Vec<&str>| names.join(", ");
+    writeln!(
+        out,
+        r#"
+fn decode_{}_script(script: &Script) -> Option<ScriptCall> {{
+    match (script.ty_args(), script.args()) {{
+        ([{}], [{}]) => Some(ScriptCall::{} {{"#,
+        abi.name(),
+        quote_names(abi.ty_args().iter().map(|ty_arg| ty_arg.name()).collect()),
+        quote_names(abi.args().iter().map(|arg| arg.name()).collect()),
+        to_camel_case(abi.name(), true),
+    )?;
+    for ty_arg in abi.ty_args() {
+        writeln!(out, "            {0}: {0}.clone(),", ty_arg.name())?;
+    }
+    for arg in abi.args() {
+        writeln!(
+            out,
+            "            {0}: decode_{1}_argument({0})?,",
+            arg.name(),
+            quote_decoder_suffix(arg.type_tag()),
+        )?;
+    }
+    writeln!(
+        out,
+        r#"        }}),
+        _ => None,
+    }}
+}}"#
+    )
+}
+
+fn output_decoder_map(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    writeln!(
+        out,
+        r#"
+type DecoderMap = HashMap<Vec<u8>, fn(&Script) -> Option<ScriptCall>>;
+
+static SCRIPT_DECODER_MAP: Lazy<DecoderMap> = Lazy::new(|| {{
+    let mut map: DecoderMap = HashMap::new();"#
+    )?;
+    for abi in abis {
+        writeln!(
+            out,
+            "    map.insert({}.to_vec(), decode_{}_script);",
+            quote_code_constant_name(abi),
+            abi.name(),
+        )?;
+    }
+    writeln!(out, "    map\n}});")
+}
+
+fn output_decoding_helpers(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    // Only emit the helpers that are actually used to avoid dead code.
+    let suffixes: BTreeSet<_> = abis
+        .iter()
+        .flat_map(|abi| abi.args().iter())
+        .map(|arg| quote_decoder_suffix(arg.type_tag()))
+        .collect();
+    for suffix in suffixes {
+        let (ty, variant, value) = match suffix {
+            "bool" => ("bool", "Bool", "*value"),
+            "u8" => ("u8", "U8", "*value"),
+            "u64" => ("u64", "U64", "*value"),
+            "u128" => ("u128", "U128", "*value"),
+            "address" => ("AccountAddress", "Address", "*value"),
+            "u8vector" => ("Vec<u8>", "U8Vector", "value.clone()"),
+            _ => unreachable!(),
+        };
+        writeln!(
+            out,
+            r#"
+fn decode_{}_argument(arg: &TransactionArgument) -> Option<{}> {{
+    match arg {{
+        TransactionArgument::{}(value) => Some({}),
+        _ => None,
+    }}
+}}"#,
+            suffix, ty, variant, value,
+        )?;
+    }
+    Ok(())
+}
+
+fn output_code_constant(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    writeln!(
+        out,
+        "\nconst {}: &[u8] = &{};",
+        quote_code_constant_name(abi),
+        quote_code(abi.code()),
+    )
+}
+
+fn quote_code_constant_name(abi: &ScriptABI) -> String {
+    format!("{}_CODE", abi.name().to_uppercase())
+}
+
+fn quote_field_names(abi: &ScriptABI) -> String {
+    abi.ty_args()
+        .iter()
+        .map(|ty_arg| ty_arg.name())
+        .chain(abi.args().iter().map(|arg| arg.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_argument_value(arg: &ArgumentABI) -> String {
+    match arg.type_tag() {
+        TypeTag::Vector(_) => format!("{}.clone()", arg.name()),
+        _ => format!("*{}", arg.name()),
+    }
+}
+
+fn quote_decoder_suffix(type_tag: &TypeTag) -> &'static str {
+    use TypeTag::*;
+    match type_tag {
+        Bool => "bool",
+        U8 => "u8",
+        U64 => "u64",
+        U128 => "u128",
+        Address => "address",
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => "u8vector",
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
 fn quote_doc(doc: &str) -> String {
     let doc = crate::common::prepare_doc_string(doc);
     let text = textwrap::fill(&doc, 86);
@@ -107,7 +396,7 @@ fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
 
 fn quote_code(code: &[u8]) -> String {
     format!(
-        "vec![{}]",
+        "[{}]",
         code.iter()
             .map(|x| format!("{}", x))
             .collect::<Vec<_>>()
@@ -0,0 +1,391 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    container_fields, format_not_supported, to_camel_case, type_not_allowed, variant_fields,
+};
+use libra_types::transaction::{ArgumentABI, ScriptABI, TypeArgumentABI};
+use move_core_types::language_storage::TypeTag;
+use serde_reflection::{ContainerFormat, Format, Named, Registry};
+
+use std::{
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Output transaction builders in TypeScript for the given ABIs.
+pub fn output(out: &mut dyn Write, abis: &[ScriptABI]) -> Result<()> {
+    output_preamble(out)?;
+    for abi in abis {
+        output_builder(out, abi)?;
+    }
+    Ok(())
+}
+
+fn output_preamble(out: &mut dyn Write) -> Result<()> {
+    writeln!(
+        out,
+        r#"import {{
+  AccountAddress,
+  Script,
+  TransactionArgumentVariantAddress,
+  TransactionArgumentVariantBool,
+  TransactionArgumentVariantU128,
+  TransactionArgumentVariantU64,
+  TransactionArgumentVariantU8,
+  TransactionArgumentVariantU8Vector,
+  TypeTag,
+}} from './libra_types';"#
+    )
+}
+
+fn output_builder(out: &mut dyn Write, abi: &ScriptABI) -> Result<()> {
+    write!(out, "\n{}", quote_doc(abi.doc()))?;
+    writeln!(
+        out,
+        "export function encode{}Script({}): Script {{",
+        to_camel_case(abi.name(), /* capitalize */ true),
+        [
+            quote_type_parameters(abi.ty_args()),
+            quote_parameters(abi.args()),
+        ]
+        .concat()
+        .join(", ")
+    )?;
+    writeln!(
+        out,
+        r#"  return new Script(
+    {},
+    [{}],
+    [{}],
+  );
+}}"#,
+        quote_code(abi.code()),
+        quote_type_arguments(abi.ty_args()),
+        quote_arguments(abi.args()),
+    )?;
+    Ok(())
+}
+
+fn quote_doc(doc: &str) -> String {
+    let doc = crate::common::prepare_doc_string(doc);
+    let text = textwrap::indent(&textwrap::fill(&doc, 86), " * ");
+    format!("/**\n{} */\n", text)
+}
+
+fn quote_type_parameters(ty_args: &[TypeArgumentABI]) -> Vec<String> {
+    ty_args
+        .iter()
+        .map(|ty_arg| format!("{}: TypeTag", ty_arg.name()))
+        .collect()
+}
+
+fn quote_parameters(args: &[ArgumentABI]) -> Vec<String> {
+    args.iter()
+        .map(|arg| format!("{}: {}", arg.name(), quote_type(arg.type_tag())))
+        .collect()
+}
+
+fn quote_code(code: &[u8]) -> String {
+    format!(
+        "new Uint8Array([{}])",
+        code.iter()
+            .map(|x| format!("{}", x))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn quote_type_arguments(ty_args: &[TypeArgumentABI]) -> String {
+    ty_args
+        .iter()
+        .map(|ty_arg| ty_arg.name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_arguments(args: &[ArgumentABI]) -> String {
+    args.iter()
+        .map(|arg| make_transaction_argument(arg.type_tag(), arg.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_type(type_tag: &TypeTag) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => "boolean".into(),
+        U8 => "number".into(),
+        U64 => "bigint".into(),
+        U128 => "bigint".into(),
+        Address => "AccountAddress".into(),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => "Uint8Array".into(),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+fn make_transaction_argument(type_tag: &TypeTag, name: &str) -> String {
+    use TypeTag::*;
+    match type_tag {
+        Bool => format!("new TransactionArgumentVariantBool({})", name),
+        U8 => format!("new TransactionArgumentVariantU8({})", name),
+        U64 => format!("new TransactionArgumentVariantU64({})", name),
+        U128 => format!("new TransactionArgumentVariantU128({})", name),
+        Address => format!("new TransactionArgumentVariantAddress({})", name),
+        Vector(type_tag) => match type_tag.as_ref() {
+            U8 => format!("new TransactionArgumentVariantU8Vector({})", name),
+            _ => type_not_allowed(type_tag),
+        },
+
+        Struct(_) | Signer => type_not_allowed(type_tag),
+    }
+}
+
+/// Output a TypeScript module defining the Libra types of the registry. Each class can be
+/// serialized in LCS with the method `lcsSerialize`. The variants of an enum `E` are the
+/// subclasses `EVariant<name>` of the abstract class `E`.
+pub fn output_libra_types(out: &mut dyn Write, registry: &Registry) -> Result<()> {
+    writeln!(out, "import {{ Serializer }} from './lcs';")?;
+    for (name, format) in registry {
+        match format {
+            ContainerFormat::Enum(variants) => {
+                writeln!(
+                    out,
+                    r#"
+export abstract class {} {{
+  abstract serialize(serializer: Serializer): void;
+"#,
+                    name
+                )?;
+                output_lcs_serialize(out)?;
+                writeln!(out, "}}")?;
+                for (index, variant) in variants {
+                    let fields = variant_fields(&variant.value);
+                    writeln!(
+                        out,
+                        "\nexport class {}Variant{} extends {} {{",
+                        name, variant.name, name
+                    )?;
+                    output_fields(out, &fields, /* is_variant */ true)?;
+                    writeln!(out, "\n  public serialize(serializer: Serializer): void {{")?;
+                    writeln!(out, "    serializer.serializeVariantIndex({});", index)?;
+                    output_serialize_fields(out, &fields)?;
+                    writeln!(out, "  }}\n}}")?;
+                }
+            }
+            _ => {
+                let fields = container_fields(format);
+                writeln!(out, "\nexport class {} {{", name)?;
+                output_fields(out, &fields, /* is_variant */ false)?;
+                writeln!(out, "\n  public serialize(serializer: Serializer): void {{")?;
+                output_serialize_fields(out, &fields)?;
+                writeln!(out, "  }}\n")?;
+                output_lcs_serialize(out)?;
+                writeln!(out, "}}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn output_fields(out: &mut dyn Write, fields: &[Named<Format>], is_variant: bool) -> Result<()> {
+    for field in fields {
+        writeln!(
+            out,
+            "  public {}: {};",
+            field.name,
+            quote_format(&field.value)
+        )?;
+    }
+    if !fields.is_empty() {
+        writeln!(out)?;
+    }
+    writeln!(
+        out,
+        "  constructor({}) {{",
+        fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, quote_format(&field.value)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )?;
+    if is_variant {
+        writeln!(out, "    super();")?;
+    }
+    for field in fields {
+        writeln!(out, "    this.{0} = {0};", field.name)?;
+    }
+    writeln!(out, "  }}")
+}
+
+fn output_serialize_fields(out: &mut dyn Write, fields: &[Named<Format>]) -> Result<()> {
+    for field in fields {
+        let code = quote_serialize(&field.value, &format!("this.{}", field.name), 0);
+        write!(out, "{}", textwrap::indent(&code, "    "))?;
+    }
+    Ok(())
+}
+
+fn output_lcs_serialize(out: &mut dyn Write) -> Result<()> {
+    writeln!(
+        out,
+        r#"  public lcsSerialize(): Uint8Array {{
+    const serializer = new Serializer();
+    this.serialize(serializer);
+    return serializer.getBytes();
+  }}"#
+    )
+}
+
+/// TypeScript type of the values of the given format.
+fn quote_format(format: &Format) -> String {
+    use Format::*;
+    match format {
+        TypeName(name) => name.clone(),
+        Unit => "null".into(),
+        Bool => "boolean".into(),
+        I8 | I16 | I32 | U8 | U16 | U32 => "number".into(),
+        I64 | I128 | U64 | U128 => "bigint".into(),
+        Str => "string".into(),
+        Bytes => "Uint8Array".into(),
+        Option(format) => format!("{} | null", quote_format(format)),
+        Seq(format)
+        | TupleArray {
+            content: format, ..
+        } => match format.as_ref() {
+            Option(_) => format!("({})[]", quote_format(format)),
+            _ => format!("{}[]", quote_format(format)),
+        },
+        Tuple(formats) => format!(
+            "[{}]",
+            formats
+                .iter()
+                .map(quote_format)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => format_not_supported(format),
+    }
+}
+
+/// TypeScript statements serializing the expression `value` of the given format.
+fn quote_serialize(format: &Format, value: &str, depth: usize) -> String {
+    use Format::*;
+    match format {
+        TypeName(_) => format!("{}.serialize(serializer);\n", value),
+        Unit => String::new(),
+        Bool => format!("serializer.serializeBool({});\n", value),
+        I8 => format!("serializer.serializeI8({});\n", value),
+        I16 => format!("serializer.serializeI16({});\n", value),
+        I32 => format!("serializer.serializeI32({});\n", value),
+        I64 => format!("serializer.serializeI64({});\n", value),
+        I128 => format!("serializer.serializeI128({});\n", value),
+        U8 => format!("serializer.serializeU8({});\n", value),
+        U16 => format!("serializer.serializeU16({});\n", value),
+        U32 => format!("serializer.serializeU32({});\n", value),
+        U64 => format!("serializer.serializeU64({});\n", value),
+        U128 => format!("serializer.serializeU128({});\n", value),
+        Str => format!("serializer.serializeStr({});\n", value),
+        Bytes => format!("serializer.serializeBytes({});\n", value),
+        Option(format) => format!(
+            r#"if ({0} !== null) {{
+  serializer.serializeOptionTag(true);
+{1}}} else {{
+  serializer.serializeOptionTag(false);
+}}
+"#,
+            value,
+            textwrap::indent(&quote_serialize(format, value, depth), "  "),
+        ),
+        Seq(format) => format!(
+            "serializer.serializeLen({}.length);\n{}",
+            value,
+            quote_serialize_items(format, value, depth),
+        ),
+        TupleArray { content, size } => format!(
+            r#"if ({0}.length !== {1}) {{
+  throw new Error(`Invalid length for fixed-size array: ${{{0}.length}} instead of {1}`);
+}}
+{2}"#,
+            value,
+            size,
+            quote_serialize_items(content, value, depth),
+        ),
+        Tuple(formats) => formats
+            .iter()
+            .enumerate()
+            .map(|(index, format)| quote_serialize(format, &format!("{}[{}]", value, index), depth))
+            .collect(),
+        _ => format_not_supported(format),
+    }
+}
+
+fn quote_serialize_items(format: &Format, value: &str, depth: usize) -> String {
+    let item = format!("item{}", depth);
+    format!(
+        "for (const {} of {}) {{\n{}}}\n",
+        item,
+        value,
+        textwrap::indent(&quote_serialize(format, &item, depth + 1), "  "),
+    )
+}
+
+/// Install TypeScript modules in `install_dir`. Modules import each other with relative
+/// paths, e.g. `./libra_types`.
+pub struct Installer {
+    install_dir: PathBuf,
+}
+
+impl Installer {
+    pub fn new(install_dir: PathBuf) -> Self {
+        Installer { install_dir }
+    }
+
+    fn create_module_file(&self, name: &str) -> Result<std::fs::File> {
+        std::fs::create_dir_all(&self.install_dir)?;
+        std::fs::File::create(self.install_dir.join(format!("{}.ts", name)))
+    }
+}
+
+impl crate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_transaction_builders(
+        &self,
+        name: &str,
+        abis: &[ScriptABI],
+    ) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_module_file(name)?;
+        output(&mut file, abis)?;
+        Ok(())
+    }
+}
+
+impl serde_generate::SourceInstaller for Installer {
+    type Error = Box<dyn std::error::Error>;
+
+    fn install_module(
+        &self,
+        name: &str,
+        registry: &Registry,
+    ) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_module_file(name)?;
+        output_libra_types(&mut file, registry)?;
+        Ok(())
+    }
+
+    /// The LCS runtime contains everything the generated code needs.
+    fn install_serde_runtime(&self) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn install_lcs_runtime(&self) -> std::result::Result<(), Self::Error> {
+        let mut file = self.create_module_file("lcs")?;
+        file.write_all(include_str!("../runtime/typescript/lcs.ts").as_bytes())?;
+        Ok(())
+    }
+}
@@ -1,7 +1,14 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use libra_types::transaction::ScriptABI;
+use libra_types::{
+    account_address::AccountAddress,
+    transaction::{Script, ScriptABI, TransactionArgument},
+};
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{StructTag, TypeTag},
+};
 use serde_generate as serdegen;
 use serde_generate::SourceInstaller as _;
 use serde_reflection::Registry;
@@ -21,6 +28,84 @@ fn get_stdlib_script_abis() -> Vec<ScriptABI> {
     buildgen::read_abis(path).expect("reading ABI files should not fail")
 }
 
+const UPDATE_BASELINE: &str = "UPDATE_BASELINE";
+const UB: &str = "UB";
+
+fn read_bool_var(name: &str) -> bool {
+    std::env::var(name).map_or(false, |value| value == "1" || value == "true")
+}
+
+/// Compares generated code with the golden file checked in at `path`. Run the tests with `UB=1`
+/// to regenerate the golden files after changing a generator or the stdlib scripts.
+fn assert_matches_golden_file(path: &str, output: Vec<u8>) {
+    if read_bool_var(UPDATE_BASELINE) || read_bool_var(UB) {
+        std::fs::write(path, output).unwrap();
+    } else {
+        let expected = std::fs::read(path).unwrap();
+        assert!(
+            output == expected,
+            "{} is out of date, run `UB=1 cargo test -p transaction-builder-generator --test generation` to regenerate it",
+            path
+        );
+    }
+}
+
+/// Hex-encoded LCS bytes of the sample scripts printed by the test programs below, one per line.
+/// Scripts are built like the Rust transaction builders do, i.e. with `Script::new`.
+fn get_expected_output(abis: &[ScriptABI]) -> String {
+    let get_code = |name: &str| {
+        abis.iter()
+            .find(|abi| abi.name() == name)
+            .expect("script should exist")
+            .code()
+            .to_vec()
+    };
+    let token = TypeTag::Struct(StructTag {
+        address: AccountAddress::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+        module: Identifier::new("LBR").unwrap(),
+        name: Identifier::new("LBR").unwrap(),
+        type_params: vec![],
+    });
+    let payee = AccountAddress::new([0x22; 16]);
+    let scripts = vec![
+        Script::new(
+            get_code("peer_to_peer_with_metadata"),
+            vec![token],
+            vec![
+                TransactionArgument::Address(payee),
+                TransactionArgument::U64(1_234_567),
+                TransactionArgument::U8Vector(vec![1, 2, 3]),
+                TransactionArgument::U8Vector(vec![4, 5, 6]),
+            ],
+        ),
+        Script::new(
+            get_code("update_minting_ability"),
+            vec![TypeTag::Vector(Box::new(TypeTag::U64))],
+            vec![TransactionArgument::Bool(true)],
+        ),
+        Script::new(
+            vec![0xff, 0x00],
+            vec![TypeTag::Signer],
+            vec![
+                TransactionArgument::U128((1 << 127) + 3),
+                TransactionArgument::U8(255),
+                TransactionArgument::Address(payee),
+            ],
+        ),
+    ];
+    scripts
+        .iter()
+        .map(|script| {
+            let bytes = lcs::to_bytes(script).unwrap();
+            let hex = bytes
+                .iter()
+                .map(|x| format!("{:02x}", x))
+                .collect::<String>();
+            hex + "\n"
+        })
+        .collect()
+}
+
 // Cannot run this test in the CI of Libra.
 #[test]
 #[ignore]
@@ -131,6 +216,28 @@ serde_bytes = "0.11"
     assert!(status.success());
 }
 
+#[test]
+fn test_that_ffi_code_matches_libra_dev() {
+    let abis = get_stdlib_script_abis();
+    let dir = tempdir().unwrap();
+
+    let source_path = dir.path().join("scripts.rs");
+    let mut source = std::fs::File::create(&source_path).unwrap();
+    buildgen::ffi::output(&mut source, &abis).unwrap();
+
+    let status = Command::new("rustfmt")
+        .arg("--config")
+        .arg("merge_imports=true")
+        .arg(&source_path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = std::fs::read_to_string(&source_path).unwrap();
+    let expected = std::fs::read_to_string("../../client/libra-dev/src/scripts.rs").unwrap();
+    assert_eq!(output, expected);
+}
+
 #[test]
 #[ignore]
 fn test_that_cpp_code_compiles() {
@@ -158,3 +265,296 @@ fn test_that_cpp_code_compiles() {
         .unwrap();
     assert!(status.success());
 }
+
+#[test]
+fn test_that_java_code_matches_golden_file() {
+    let abis = get_stdlib_script_abis();
+    let mut output = Vec::new();
+    buildgen::java::output(&mut output, &abis, Some("org.libra.stdlib")).unwrap();
+    assert_matches_golden_file("tests/staged/Helpers.java", output);
+}
+
+const JAVA_MAIN: &str = r#"
+import java.math.BigInteger;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import org.libra.stdlib.Helpers;
+import org.libra.types.AccountAddress;
+import org.libra.types.Identifier;
+import org.libra.types.Script;
+import org.libra.types.StructTag;
+import org.libra.types.TransactionArgument;
+import org.libra.types.TypeTag;
+
+public class Main {
+    static AccountAddress address(int fill, int last) {
+        List<Byte> bytes = new ArrayList<Byte>();
+        for (int i = 0; i < 15; i++) {
+            bytes.add((byte) fill);
+        }
+        bytes.add((byte) last);
+        return new AccountAddress(bytes);
+    }
+
+    static void print(Script script) {
+        StringBuilder builder = new StringBuilder();
+        for (byte x : script.lcsSerialize()) {
+            builder.append(String.format("%02x", x));
+        }
+        System.out.println(builder.toString());
+    }
+
+    public static void main(String[] args) {
+        TypeTag token = new TypeTag.Struct(new StructTag(
+            address(0, 1),
+            new Identifier("LBR"),
+            new Identifier("LBR"),
+            new ArrayList<TypeTag>()));
+        AccountAddress payee = address(0x22, 0x22);
+        print(Helpers.encodePeerToPeerWithMetadataScript(
+            token, payee, 1234567L, new byte[] {1, 2, 3}, new byte[] {4, 5, 6}));
+        print(Helpers.encodeUpdateMintingAbilityScript(
+            new TypeTag.Vector(new TypeTag.U64()), true));
+        print(new Script(
+            new byte[] {(byte) 0xff, 0x00},
+            Arrays.<TypeTag>asList(new TypeTag.Signer()),
+            Arrays.<TransactionArgument>asList(
+                new TransactionArgument.U128(BigInteger.ONE.shiftLeft(127).add(BigInteger.valueOf(3))),
+                new TransactionArgument.U8((byte) 255),
+                new TransactionArgument.Address(payee))));
+    }
+}
+"#;
+
+// Cannot run this test in the CI of Libra.
+#[test]
+#[ignore]
+fn test_that_java_code_compiles_and_matches_rust_output() {
+    let registry = get_libra_registry();
+    let abis = get_stdlib_script_abis();
+    let dir = tempdir().unwrap();
+
+    let installer = buildgen::java::Installer::new(dir.path().to_path_buf(), None);
+    installer
+        .install_module(buildgen::java::DEFAULT_LIBRA_PACKAGE, &registry)
+        .unwrap();
+    installer.install_lcs_runtime().unwrap();
+    installer
+        .install_transaction_builders("org.libra.stdlib", &abis)
+        .unwrap();
+    std::fs::write(dir.path().join("Main.java"), JAVA_MAIN).unwrap();
+
+    let mut sources = Vec::new();
+    for package in &["", "org/libra/lcs", "org/libra/types", "org/libra/stdlib"] {
+        for entry in std::fs::read_dir(dir.path().join(package)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension() == Some(std::ffi::OsStr::new("java")) {
+                sources.push(path);
+            }
+        }
+    }
+    let status = Command::new("javac")
+        .arg("-d")
+        .arg(dir.path().join("classes"))
+        .args(&sources)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new("java")
+        .arg("-cp")
+        .arg(dir.path().join("classes"))
+        .arg("Main")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        get_expected_output(&abis)
+    );
+}
+
+#[test]
+fn test_that_typescript_code_matches_golden_file() {
+    let abis = get_stdlib_script_abis();
+    let mut output = Vec::new();
+    buildgen::typescript::output(&mut output, &abis).unwrap();
+    assert_matches_golden_file("tests/staged/stdlib.ts", output);
+}
+
+const TYPESCRIPT_MAIN: &str = r#"
+import {
+  AccountAddress,
+  Identifier,
+  Script,
+  StructTag,
+  TransactionArgumentVariantAddress,
+  TransactionArgumentVariantU128,
+  TransactionArgumentVariantU8,
+  TypeTagVariantSigner,
+  TypeTagVariantStruct,
+  TypeTagVariantU64,
+  TypeTagVariantVector,
+} from './libra_types';
+import * as stdlib from './libra_stdlib';
+
+function address(fill: number, last: number): AccountAddress {
+  const bytes: number[] = new Array(15).fill(fill);
+  bytes.push(last);
+  return new AccountAddress(bytes);
+}
+
+function print(script: Script): void {
+  const bytes = Array.from(script.lcsSerialize());
+  console.log(bytes.map((x) => x.toString(16).padStart(2, '0')).join(''));
+}
+
+const token = new TypeTagVariantStruct(
+  new StructTag(address(0, 1), new Identifier('LBR'), new Identifier('LBR'), []),
+);
+const payee = address(0x22, 0x22);
+print(stdlib.encodePeerToPeerWithMetadataScript(
+  token, payee, BigInt(1234567), new Uint8Array([1, 2, 3]), new Uint8Array([4, 5, 6]),
+));
+print(stdlib.encodeUpdateMintingAbilityScript(
+  new TypeTagVariantVector(new TypeTagVariantU64()), true,
+));
+print(new Script(
+  new Uint8Array([0xff, 0x00]),
+  [new TypeTagVariantSigner()],
+  [
+    new TransactionArgumentVariantU128((BigInt(1) << BigInt(127)) + BigInt(3)),
+    new TransactionArgumentVariantU8(255),
+    new TransactionArgumentVariantAddress(payee),
+  ],
+));
+"#;
+
+// Cannot run this test in the CI of Libra.
+#[test]
+#[ignore]
+fn test_that_typescript_code_compiles_and_matches_rust_output() {
+    let registry = get_libra_registry();
+    let abis = get_stdlib_script_abis();
+    let dir = tempdir().unwrap();
+
+    let installer = buildgen::typescript::Installer::new(dir.path().to_path_buf());
+    installer.install_module("libra_types", &registry).unwrap();
+    installer.install_lcs_runtime().unwrap();
+    installer
+        .install_transaction_builders("libra_stdlib", &abis)
+        .unwrap();
+    std::fs::write(dir.path().join("main.ts"), TYPESCRIPT_MAIN).unwrap();
+
+    let status = Command::new("tsc")
+        .current_dir(dir.path())
+        .args(&["--strict", "--target", "es2020", "--module", "commonjs"])
+        .args(&["--outDir", "out", "main.ts"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new("node")
+        .arg(dir.path().join("out/main.js"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        get_expected_output(&abis)
+    );
+}
+
+#[test]
+fn test_that_go_code_matches_golden_file() {
+    let abis = get_stdlib_script_abis();
+    let mut output = Vec::new();
+    buildgen::golang::output(&mut output, &abis, "librastdlib").unwrap();
+    assert_matches_golden_file("tests/staged/stdlib.go", output);
+}
+
+const GO_MAIN: &str = r#"
+package main
+
+import (
+	"fmt"
+
+	"libra.org/test/lcs"
+	"libra.org/test/librastdlib"
+	"libra.org/test/libratypes"
+)
+
+func address(fill uint8, last uint8) libratypes.AccountAddress {
+	var result libratypes.AccountAddress
+	for i := range result {
+		result[i] = fill
+	}
+	result[15] = last
+	return result
+}
+
+func printScript(script libratypes.Script) {
+	bytes, err := script.LcsSerialize()
+	if err != nil {
+		panic(err)
+	}
+	fmt.Printf("%x\n", bytes)
+}
+
+func main() {
+	token := libratypes.TypeTag__Struct{Value: libratypes.StructTag{
+		Address:    address(0, 1),
+		Module:     libratypes.Identifier("LBR"),
+		Name:       libratypes.Identifier("LBR"),
+		TypeParams: []libratypes.TypeTag{},
+	}}
+	payee := address(0x22, 0x22)
+	printScript(librastdlib.EncodePeerToPeerWithMetadataScript(
+		token, payee, 1234567, []byte{1, 2, 3}, []byte{4, 5, 6}))
+	printScript(librastdlib.EncodeUpdateMintingAbilityScript(
+		libratypes.TypeTag__Vector{Value: libratypes.TypeTag__U64{}}, true))
+	printScript(libratypes.Script{
+		Code:   []byte{0xff, 0x00},
+		TyArgs: []libratypes.TypeTag{libratypes.TypeTag__Signer{}},
+		Args: []libratypes.TransactionArgument{
+			libratypes.TransactionArgument__U128{Value: lcs.Uint128{High: 1 << 63, Low: 3}},
+			libratypes.TransactionArgument__U8{Value: 255},
+			libratypes.TransactionArgument__Address{Value: payee},
+		},
+	})
+}
+"#;
+
+// Cannot run this test in the CI of Libra.
+#[test]
+#[ignore]
+fn test_that_go_code_compiles_and_matches_rust_output() {
+    let registry = get_libra_registry();
+    let abis = get_stdlib_script_abis();
+    let dir = tempdir().unwrap();
+
+    let installer = buildgen::golang::Installer::new(
+        dir.path().to_path_buf(),
+        Some("libra.org/test".to_string()),
+    );
+    installer.install_module("libratypes", &registry).unwrap();
+    installer.install_lcs_runtime().unwrap();
+    installer
+        .install_transaction_builders("librastdlib", &abis)
+        .unwrap();
+    std::fs::write(dir.path().join("go.mod"), "module libra.org/test\n").unwrap();
+    std::fs::write(dir.path().join("main.go"), GO_MAIN).unwrap();
+
+    let output = Command::new("go")
+        .current_dir(dir.path())
+        .arg("run")
+        .arg(".")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        get_expected_output(&abis)
+    );
+}
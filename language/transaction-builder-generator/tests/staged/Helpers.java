@@ -0,0 +1,481 @@
+package org.libra.stdlib;
+
+import java.math.BigInteger;
+import org.libra.types.AccountAddress;
+import org.libra.types.Script;
+import org.libra.types.TransactionArgument;
+import org.libra.types.TypeTag;
+
+public final class Helpers {
+
+    /**
+     * Add a `Currency` balance to `account`, which will enable `account` to send and receive
+     * `Libra<Currency>`. Aborts with NOT_A_CURRENCY if `Currency` is not an accepted
+     * currency type in the Libra system Aborts with `LibraAccount::ADD_EXISTING_CURRENCY` if
+     * the account already holds a balance in `Currency`. Aborts with
+     * `LibraAccount::PARENT_VASP_CURRENCY_LIMITS_DNE` if `account` is a `ChildVASP` whose
+     * parent does not have an `AccountLimits<Currency>` resource.
+     */
+    public static Script encodeAddCurrencyToAccountScript(TypeTag currency) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 26, 8, 43, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 97, 100, 100, 95, 99, 117, 114, 114, 101, 110, 99, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(currency),
+            java.util.Arrays.<TransactionArgument>asList());
+    }
+
+    /**
+     * Add the `KeyRotationCapability` for `to_recover_account` to the `RecoveryAddress`
+     * resource under `recovery_address`. Aborts if `to_recovery_account` and
+     * `to_recovery_address belong to different VASPs, if `recovery_address` does not have a
+     * `RecoveryAddress` resource, or if `to_recover_account` has already extracted its
+     * `KeyRotationCapability`.
+     */
+    public static Script encodeAddRecoveryRotationCapabilityScript(AccountAddress recovery_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 40, 8, 52, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114, 101, 115, 115, 23, 97, 100, 100, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(recovery_address)));
+    }
+
+    /**
+     * Add `new_validator` to the pending validator set. Fails if the `new_validator` address
+     * is already in the validator set or does not have a `ValidatorConfig` resource stored
+     * at the address.
+     */
+    public static Script encodeAddValidatorScript(AccountAddress validator_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 26, 8, 38, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 13, 97, 100, 100, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(validator_address)));
+    }
+
+    /**
+     * Adds limits and an accounting window for `CoinType` currency to the parent VASP
+     * `account`. This transaction will fail if sent from a child account.
+     */
+    public static Script encodeAllowCurrencyForVaspScript(TypeTag coin_type) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 24, 8, 43, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 3, 1, 6, 12, 1, 1, 0, 1, 9, 0, 4, 86, 65, 83, 80, 18, 116, 114, 121, 95, 97, 108, 108, 111, 119, 95, 99, 117, 114, 114, 101, 110, 99, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 2, 4, 11, 0, 56, 0, 1, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList());
+    }
+
+    /**
+     * Permanently destroy the `Token`s stored in the oldest burn request under the `Preburn`
+     * resource. This will only succeed if `account` has a `MintCapability<Token>`, a
+     * `Preburn<Token>` resource exists under `preburn_address`, and there is a pending burn
+     * request. sliding_nonce is a unique nonce for operation, see sliding_nonce.move for
+     * details
+     */
+    public static Script encodeBurnScript(TypeTag token, Long sliding_nonce, AccountAddress preburn_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 17, 7, 34, 46, 8, 80, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 1, 9, 0, 5, 76, 105, 98, 114, 97, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 4, 98, 117, 114, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(token),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.Address(preburn_address)));
+    }
+
+    /**
+     * Burn transaction fees that have been collected in the given `currency` and relinquish
+     * to the association. The currency must be non-synthetic.
+     */
+    public static Script encodeBurnTxnFeesScript(TypeTag coin_type) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 25, 8, 42, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 14, 84, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 70, 101, 101, 9, 98, 117, 114, 110, 95, 102, 101, 101, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList());
+    }
+
+    /**
+     * Cancel the oldest burn request from `preburn_address` and return the funds. Fails if
+     * the sender does not have a published `BurnCapability<Token>`.
+     */
+    public static Script encodeCancelBurnScript(TypeTag token, AccountAddress preburn_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 25, 8, 43, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 5, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 11, 99, 97, 110, 99, 101, 108, 95, 98, 117, 114, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11, 0, 10, 1, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(token),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(preburn_address)));
+    }
+
+    /**
+     * Create a `ChildVASP` account for sender `parent_vasp` at `child_address` with a
+     * balance of `child_initial_balance` in `CoinType` and an initial authentication_key
+     * `auth_key_prefix | child_address`. If `add_all_currencies` is true, the child address
+     * will have a zero balance in all available currencies in the system. This account will
+     * a child of the transaction sender, which must be a ParentVASP.
+     */
+    public static Script encodeCreateChildVaspAccountScript(TypeTag coin_type, AccountAddress child_address, byte[] auth_key_prefix, Boolean add_all_currencies, Long child_initial_balance) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 8, 1, 0, 2, 2, 2, 4, 3, 6, 22, 4, 28, 4, 5, 32, 35, 7, 67, 123, 8, (byte) 190, 1, 16, 6, (byte) 206, 1, 4, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 1, 1, 0, 3, 2, 3, 0, 0, 4, 4, 1, 1, 1, 0, 5, 3, 1, 0, 0, 6, 2, 6, 4, 6, 12, 5, 10, 2, 1, 0, 1, 6, 12, 1, 8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 5, 6, 12, 5, 10, 2, 1, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 99, 114, 101, 97, 116, 101, 95, 99, 104, 105, 108, 100, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 2, 1, 0, 1, 1, 5, 3, 25, 10, 0, 10, 1, 11, 2, 10, 3, 56, 0, 10, 4, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 3, 10, 5, 22, 11, 0, 17, 1, 12, 5, 14, 5, 10, 1, 10, 4, 7, 0, 7, 0, 56, 1, 11, 5, 17, 3, 5, 24, 11, 0, 1, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(child_address), new TransactionArgument.U8Vector(auth_key_prefix), new TransactionArgument.Bool(add_all_currencies), new TransactionArgument.U64(child_initial_balance)));
+    }
+
+    /**
+     * Create an account with the DesignatedDealer role at `addr` with authentication key
+     * `auth_key_prefix` | `addr` and a 0 balance of type `Currency`. If `add_all_currencies`
+     * is true, 0 balances for all available currencies in the system will also be added.
+     * This can only be invoked by an account with the TreasuryCompliance role.
+     */
+    public static Script encodeCreateDesignatedDealerScript(TypeTag currency, Long sliding_nonce, AccountAddress addr, byte[] auth_key_prefix, Boolean add_all_currencies) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 6, 3, 6, 16, 4, 22, 2, 5, 24, 28, 7, 52, 99, 8, (byte) 151, 1, 16, 0, 0, 0, 1, 0, 2, 2, 3, 0, 1, 0, 0, 4, 2, 1, 0, 1, 5, 3, 1, 1, 1, 2, 5, 2, 6, 12, 3, 0, 3, 6, 12, 5, 3, 4, 6, 12, 5, 10, 2, 1, 5, 6, 12, 3, 5, 10, 2, 1, 1, 9, 0, 16, 68, 101, 115, 105, 103, 110, 97, 116, 101, 100, 68, 101, 97, 108, 101, 114, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 8, 97, 100, 100, 95, 116, 105, 101, 114, 24, 99, 114, 101, 97, 116, 101, 95, 100, 101, 115, 105, 103, 110, 97, 116, 101, 100, 95, 100, 101, 97, 108, 101, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 25, 10, 0, 10, 1, 17, 0, 10, 0, 10, 2, 11, 3, 10, 4, 56, 0, 10, 0, 10, 2, 6, 32, (byte) 161, 7, 0, 0, 0, 0, 0, 17, 1, 10, 0, 10, 2, 6, 64, 75, 76, 0, 0, 0, 0, 0, 17, 1, 10, 0, 10, 2, 6, (byte) 128, (byte) 240, (byte) 250, 2, 0, 0, 0, 0, 17, 1, 11, 0, 10, 2, 6, 0, 101, (byte) 205, 29, 0, 0, 0, 0, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(currency),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.Address(addr), new TransactionArgument.U8Vector(auth_key_prefix), new TransactionArgument.Bool(add_all_currencies)));
+    }
+
+    /**
+     * Create an account with the ParentVASP role at `address` with authentication key
+     * `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+     * `add_all_currencies` is true, 0 balances for all available currencies in the system
+     * will also be added. This can only be invoked by an Association account.
+     */
+    public static Script encodeCreateParentVaspAccountScript(TypeTag coin_type, AccountAddress new_account_address, byte[] auth_key_prefix, byte[] human_name, byte[] base_url, byte[] compliance_public_key, Boolean add_all_currencies) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 17, 7, 27, 40, 8, 67, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 1, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 26, 99, 114, 101, 97, 116, 101, 95, 112, 97, 114, 101, 110, 116, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 9, 11, 0, 10, 1, 11, 2, 11, 3, 11, 4, 11, 5, 10, 6, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(new_account_address), new TransactionArgument.U8Vector(auth_key_prefix), new TransactionArgument.U8Vector(human_name), new TransactionArgument.U8Vector(base_url), new TransactionArgument.U8Vector(compliance_public_key), new TransactionArgument.Bool(add_all_currencies)));
+    }
+
+    /**
+     * Extract the `KeyRotationCapability` for `recovery_account` and publish it in a
+     * `RecoveryAddress` resource under `recovery_account`. Aborts if `recovery_account` has
+     * delegated its `KeyRotationCapability`, already has a `RecoveryAddress` resource, or is
+     * not a VASP.
+     */
+    public static Script encodeCreateRecoveryAddressScript() {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 24, 8, 35, 16, 0, 0, 0, 1, 0, 1, 0, 1, 6, 12, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114, 101, 115, 115, 7, 112, 117, 98, 108, 105, 115, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 3, 11, 0, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList());
+    }
+
+    /**
+     * Create an account with the ParentVASP role at `address` with authentication key
+     * `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+     * `add_all_currencies` is true, 0 balances for all available currencies in the system
+     * will also be added. This can only be invoked by an Association account. The
+     * `human_name`, `base_url`, and compliance_public_key` fields of the ParentVASP are
+     * filled in with dummy information.
+     */
+    public static Script encodeCreateTestingAccountScript(TypeTag coin_type, AccountAddress new_account_address, byte[] auth_key_prefix, Boolean add_all_currencies) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 24, 7, 34, 40, 8, 74, 16, 6, 90, 68, 0, 0, 0, 1, 0, 1, 1, 1, 0, 3, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 1, 0, 4, 6, 12, 5, 10, 2, 1, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 26, 99, 114, 101, 97, 116, 101, 95, 112, 97, 114, 101, 110, 116, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 2, 8, 7, 116, 101, 115, 116, 110, 101, 116, 10, 2, 18, 17, 104, 116, 116, 112, 115, 58, 47, 47, 108, 105, 98, 114, 97, 46, 111, 114, 103, 10, 2, 33, 32, (byte) 183, (byte) 163, (byte) 193, 45, (byte) 192, (byte) 200, (byte) 199, 72, (byte) 171, 7, 82, 91, 112, 17, 34, (byte) 184, (byte) 139, (byte) 215, (byte) 143, 96, 12, 118, 52, 45, 39, (byte) 242, 94, 95, (byte) 146, 68, 76, (byte) 222, 1, 1, 2, 1, 9, 11, 0, 10, 1, 11, 2, 7, 0, 7, 1, 7, 2, 10, 3, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(new_account_address), new TransactionArgument.U8Vector(auth_key_prefix), new TransactionArgument.Bool(add_all_currencies)));
+    }
+
+    /**
+     * Create a validator account at `new_validator_address` with `auth_key_prefix`.
+     */
+    public static Script encodeCreateValidatorAccountScript(AccountAddress new_account_address, byte[] auth_key_prefix) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 38, 8, 52, 16, 0, 0, 0, 1, 0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 24, 99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(new_account_address), new TransactionArgument.U8Vector(auth_key_prefix)));
+    }
+
+    /**
+     * Create a validator operator account at `new_validator_address` with `auth_key_prefix`.
+     */
+    public static Script encodeCreateValidatorOperatorAccountScript(AccountAddress new_account_address, byte[] auth_key_prefix) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 47, 8, 61, 16, 0, 0, 0, 1, 0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 33, 99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 111, 112, 101, 114, 97, 116, 111, 114, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(new_account_address), new TransactionArgument.U8Vector(auth_key_prefix)));
+    }
+
+    /**
+     * Freeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+     * nonce for operation, see sliding_nonce.move for details.
+     */
+    public static Script encodeFreezeAccountScript(Long sliding_nonce, AccountAddress to_freeze_account) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 63, 8, 91, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 14, 102, 114, 101, 101, 122, 101, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.Address(to_freeze_account)));
+    }
+
+    /**
+     * Mint `amount_lbr` LBR from the sending account's constituent coins and deposits the
+     * resulting LBR into the sending account.
+     */
+    public static Script encodeMintLbrScript(Long amount_lbr) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 99, 8, (byte) 136, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6, 8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 10, 115, 116, 97, 112, 108, 101, 95, 108, 98, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 10, 1, 17, 2, 11, 2, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(amount_lbr)));
+    }
+
+    /**
+     * Modify publishing options. Takes the LCS bytes of a `VMPublishingOption` object as
+     * input.
+     */
+    public static Script encodeModifyPublishingOptionScript(byte[] args) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 36, 8, 49, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 13, 76, 105, 98, 114, 97, 86, 77, 67, 111, 110, 102, 105, 103, 21, 115, 101, 116, 95, 112, 117, 98, 108, 105, 115, 104, 105, 110, 103, 95, 111, 112, 116, 105, 111, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(args)));
+    }
+
+    /**
+     * Transfer `amount` coins to `recipient_address` with (optional) associated metadata
+     * `metadata` and (optional) `signature` on the metadata, amount, and sender address. The
+     * `metadata` and `signature` parameters are only required if `amount` >= 1_000_000 micro
+     * LBR and the sender and recipient of the funds are two distinct VASPs. Fails if there
+     * is no account at the recipient address or if the sender's balance is lower than
+     * `amount`.
+     */
+    public static Script encodePeerToPeerWithMetadataScript(TypeTag token, AccountAddress payee, Long amount, byte[] metadata, byte[] metadata_signature) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 29, 7, 53, 97, 8, (byte) 150, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3, 0, 1, 5, 1, 6, 12, 1, 8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 0, 5, 6, 12, 5, 3, 10, 2, 10, 2, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 12, 11, 0, 17, 0, 12, 5, 14, 5, 10, 1, 10, 2, 11, 3, 11, 4, 56, 0, 11, 5, 17, 2, 2},
+            java.util.Arrays.<TypeTag>asList(token),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(payee), new TransactionArgument.U64(amount), new TransactionArgument.U8Vector(metadata), new TransactionArgument.U8Vector(metadata_signature)));
+    }
+
+    /**
+     * Preburn `amount` `Token`s from `account`. This will only succeed if `account` already
+     * has a published `Preburn<Token>` resource.
+     */
+    public static Script encodePreburnScript(TypeTag token, Long amount) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 21, 7, 45, 96, 8, (byte) 141, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3, 0, 1, 5, 1, 6, 12, 1, 8, 0, 3, 6, 12, 6, 8, 0, 3, 0, 2, 6, 12, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 7, 112, 114, 101, 98, 117, 114, 110, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 10, 10, 0, 17, 0, 12, 2, 11, 0, 14, 2, 10, 1, 56, 0, 11, 2, 17, 2, 2},
+            java.util.Arrays.<TypeTag>asList(token),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(amount)));
+    }
+
+    /**
+     * (1) Rotate the authentication key of the sender to `public_key` (2) Publish a resource
+     * containing a 32-byte ed25519 public key and the rotation capability of the sender
+     * under the sender's address. Aborts if the sender already has a
+     * `SharedEd25519PublicKey` resource. Aborts if the length of `new_public_key` is not 32.
+     */
+    public static Script encodePublishSharedEd25519PublicKeyScript(byte[] public_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 31, 8, 44, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53, 49, 57, 80, 117, 98, 108, 105, 99, 75, 101, 121, 7, 112, 117, 98, 108, 105, 115, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(public_key)));
+    }
+
+    /**
+     * Update configs of all the validators and emit reconfiguration event.
+     */
+    public static Script encodeReconfigureScript() {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 35, 8, 46, 16, 0, 0, 0, 1, 0, 1, 0, 1, 6, 12, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 22, 117, 112, 100, 97, 116, 101, 95, 97, 110, 100, 95, 114, 101, 99, 111, 110, 102, 105, 103, 117, 114, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 3, 11, 0, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList());
+    }
+
+    /**
+     * Adding `to_remove` to the set of pending validator removals. Fails if the `to_remove`
+     * address is already in the validator set or already in the pending removals. Callable
+     * by Validator's operator.
+     */
+    public static Script encodeRemoveValidatorScript(AccountAddress validator_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 16, 114, 101, 109, 111, 118, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(validator_address)));
+    }
+
+    /**
+     * Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+     * sha3 hash of an ed25519 public key.
+     */
+    public static Script encodeRotateAuthenticationKeyScript(byte[] new_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 18, 7, 39, 125, 8, (byte) 164, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6, 8, 0, 10, 2, 2, 6, 12, 10, 2, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 21, 75, 101, 121, 82, 111, 116, 97, 116, 105, 111, 110, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 101, 120, 116, 114, 97, 99, 116, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 114, 101, 115, 116, 111, 114, 101, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 11, 1, 17, 2, 11, 2, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(new_key)));
+    }
+
+    /**
+     * Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+     * sha3 hash of an ed25519 public key. This script also takes `sliding_nonce`, as a
+     * unique nonce for this operation. See sliding_nonce.move for details.
+     */
+    public static Script encodeRotateAuthenticationKeyWithNonceScript(Long sliding_nonce, byte[] new_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 2, 4, 4, 3, 8, 20, 5, 28, 23, 7, 51, (byte) 160, 1, 8, (byte) 211, 1, 16, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 2, 3, 0, 0, 5, 3, 1, 0, 0, 6, 4, 1, 0, 2, 6, 12, 3, 0, 1, 6, 12, 1, 8, 0, 2, 6, 8, 0, 10, 2, 3, 6, 12, 3, 10, 2, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 21, 75, 101, 121, 82, 111, 116, 97, 116, 105, 111, 110, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 101, 120, 116, 114, 97, 99, 116, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 114, 101, 115, 116, 111, 114, 101, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 3, 12, 10, 0, 10, 1, 17, 0, 11, 0, 17, 1, 12, 3, 14, 3, 11, 2, 17, 3, 11, 3, 17, 2, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.U8Vector(new_key)));
+    }
+
+    /**
+     * Rotate the authentication key of `to_recover` to `new_key`. Can be invoked by either
+     * `recovery_address` or `to_recover`. Aborts if `recovery_address` does not have the
+     * `KeyRotationCapability` for `to_recover`.
+     */
+    public static Script encodeRotateAuthenticationKeyWithRecoveryAddressScript(AccountAddress recovery_address, AccountAddress to_recover, byte[] new_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 8, 7, 15, 42, 8, 57, 16, 0, 0, 0, 1, 0, 1, 0, 4, 6, 12, 5, 5, 10, 2, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114, 101, 115, 115, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 6, 11, 0, 10, 1, 10, 2, 11, 3, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(recovery_address), new TransactionArgument.Address(to_recover), new TransactionArgument.U8Vector(new_key)));
+    }
+
+    /**
+     * Rotate `vasp_root_addr`'s base URL to `new_url`.
+     */
+    public static Script encodeRotateBaseUrlScript(byte[] new_url) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 21, 8, 34, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 15, 114, 111, 116, 97, 116, 101, 95, 98, 97, 115, 101, 95, 117, 114, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(new_url)));
+    }
+
+    /**
+     * Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`.
+     */
+    public static Script encodeRotateCompliancePublicKeyScript(byte[] new_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 28, 114, 111, 116, 97, 116, 101, 95, 99, 111, 109, 112, 108, 105, 97, 110, 99, 101, 95, 112, 117, 98, 108, 105, 99, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(new_key)));
+    }
+
+    /**
+     * (1) Rotate the public key stored in `account`'s `SharedEd25519PublicKey` resource to
+     * `new_public_key` (2) Rotate the authentication key using the capability stored in
+     * `account`'s `SharedEd25519PublicKey` to a new value derived from `new_public_key`
+     * Aborts if `account` does not have a `SharedEd25519PublicKey` resource. Aborts if the
+     * length of `new_public_key` is not 32.
+     */
+    public static Script encodeRotateSharedEd25519PublicKeyScript(byte[] public_key) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53, 49, 57, 80, 117, 98, 108, 105, 99, 75, 101, 121, 10, 114, 111, 116, 97, 116, 101, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U8Vector(public_key)));
+    }
+
+    /**
+     * Sets the account limits window `tracking_balance` field for `CointType` at
+     * `window_address` to `aggregate_balance`
+     */
+    public static Script encodeSetAccountLimitWindowCurrentHoldingsScript(TypeTag coint_type, AccountAddress window_address, Long aggregate_balance) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 35, 8, 54, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 3, 6, 12, 5, 3, 0, 1, 9, 0, 13, 65, 99, 99, 111, 117, 110, 116, 76, 105, 109, 105, 116, 115, 20, 115, 101, 116, 95, 99, 117, 114, 114, 101, 110, 116, 95, 104, 111, 108, 100, 105, 110, 103, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 5, 11, 0, 10, 1, 10, 2, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coint_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(window_address), new TransactionArgument.U64(aggregate_balance)));
+    }
+
+    /**
+     * Set validator's config.
+     */
+    public static Script encodeSetValidatorConfigScript(AccountAddress validator_account, byte[] consensus_pubkey, byte[] validator_network_identity_pubkey, byte[] validator_network_address, byte[] fullnodes_network_identity_pubkey, byte[] fullnodes_network_address) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 15, 7, 22, 27, 8, 49, 16, 0, 0, 0, 1, 0, 1, 0, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 10, 2, 0, 15, 86, 97, 108, 105, 100, 97, 116, 111, 114, 67, 111, 110, 102, 105, 103, 10, 115, 101, 116, 95, 99, 111, 110, 102, 105, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 9, 11, 0, 10, 1, 11, 2, 11, 3, 11, 4, 11, 5, 11, 6, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(validator_account), new TransactionArgument.U8Vector(consensus_pubkey), new TransactionArgument.U8Vector(validator_network_identity_pubkey), new TransactionArgument.U8Vector(validator_network_address), new TransactionArgument.U8Vector(fullnodes_network_identity_pubkey), new TransactionArgument.U8Vector(fullnodes_network_address)));
+    }
+
+    /**
+     * Set validator's operator
+     */
+    public static Script encodeSetValidatorOperatorScript(AccountAddress operator_account) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 15, 86, 97, 108, 105, 100, 97, 116, 111, 114, 67, 111, 110, 102, 105, 103, 12, 115, 101, 116, 95, 111, 112, 101, 114, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(operator_account)));
+    }
+
+    /**
+     * Send `amount` coins of type `Token` to `payee`.
+     */
+    public static Script encodeTestnetMintScript(TypeTag token, AccountAddress payee, Long amount) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 8, 1, 0, 4, 2, 4, 4, 3, 8, 26, 4, 34, 2, 5, 36, 38, 7, 74, 125, 8, (byte) 199, 1, 16, 6, (byte) 215, 1, 22, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 1, 2, 0, 0, 5, 0, 3, 0, 0, 6, 4, 5, 1, 1, 0, 7, 3, 5, 0, 3, 8, 1, 6, 12, 1, 5, 1, 1, 1, 8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 0, 3, 6, 12, 5, 3, 7, 8, 0, 1, 3, 1, 3, 1, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 6, 83, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115, 115, 95, 111, 102, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 9, 101, 120, 105, 115, 116, 115, 95, 97, 116, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, (byte) 221, 10, 2, 1, 0, 1, 1, 6, 7, 42, 10, 1, 17, 1, 12, 4, 11, 4, 3, 9, 11, 0, 1, 6, (byte) 203, 21, 122, 0, 0, 0, 0, 0, 39, 10, 0, 17, 0, 7, 0, 33, 12, 6, 11, 6, 3, 20, 11, 0, 1, 6, (byte) 204, 21, 122, 0, 0, 0, 0, 0, 39, 10, 2, 6, 0, (byte) 128, (byte) 198, (byte) 164, 126, (byte) 141, 3, 0, 37, 12, 8, 11, 8, 3, 30, 11, 0, 1, 6, (byte) 205, 21, 122, 0, 0, 0, 0, 0, 39, 11, 0, 17, 2, 12, 3, 14, 3, 10, 1, 10, 2, 7, 1, 7, 1, 56, 0, 11, 3, 17, 4, 2},
+            java.util.Arrays.<TypeTag>asList(token),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(payee), new TransactionArgument.U64(amount)));
+    }
+
+    /**
+     * Mint 'mint_amount' to 'designated_dealer_address' for 'tier_index' tier. Max valid
+     * tier index is 3 since there are max 4 tiers per DD. Sender should be treasury
+     * compliance account and receiver authorized DD. `sliding_nonce` is a unique nonce for
+     * operation, see sliding_nonce.move for details.
+     */
+    public static Script encodeTieredMintScript(TypeTag coin_type, Long sliding_nonce, AccountAddress designated_dealer_address, Long mint_amount, Long tier_index) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 21, 7, 38, 60, 8, 98, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 4, 6, 12, 5, 3, 3, 5, 6, 12, 3, 5, 3, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 11, 116, 105, 101, 114, 101, 100, 95, 109, 105, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 9, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 10, 3, 10, 4, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.Address(designated_dealer_address), new TransactionArgument.U64(mint_amount), new TransactionArgument.U64(tier_index)));
+    }
+
+    /**
+     * Unfreeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+     * nonce for operation, see sliding_nonce.move for details.
+     */
+    public static Script encodeUnfreezeAccountScript(Long sliding_nonce, AccountAddress to_unfreeze_account) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 65, 8, 93, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 16, 117, 110, 102, 114, 101, 101, 122, 101, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.Address(to_unfreeze_account)));
+    }
+
+    /**
+     * Unmints `amount_lbr` LBR from the sending account into the constituent coins and
+     * deposits the resulting coins into the sending account."
+     */
+    public static Script encodeUnmintLbrScript(Long amount_lbr) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 101, 8, (byte) 138, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6, 8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 12, 117, 110, 115, 116, 97, 112, 108, 101, 95, 108, 98, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 10, 1, 17, 2, 11, 2, 17, 1, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(amount_lbr)));
+    }
+
+    /**
+     * Optionally update thresholds of max balance, inflow, outflow for any limits-bound
+     * accounts with their limits defined at `limit_address`. Limits are defined in terms of
+     * base (on-chain) currency units for `CoinType`. If a new threshold is 0, that
+     * particular config does not get updated. `sliding_nonce` is a unique nonce for
+     * operation, see SlidingNonce.move for details.
+     */
+    public static Script encodeUpdateAccountLimitDefinitionScript(TypeTag coin_type, AccountAddress limit_address, Long sliding_nonce, Long new_max_inflow, Long new_max_outflow, Long new_max_holding_balance) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 23, 7, 40, 74, 8, 114, 16, 0, 0, 0, 1, 0, 2, 0, 1, 1, 1, 1, 3, 2, 1, 0, 0, 4, 5, 6, 12, 5, 3, 3, 3, 0, 2, 6, 12, 3, 6, 6, 12, 5, 3, 3, 3, 3, 1, 9, 0, 13, 65, 99, 99, 111, 117, 110, 116, 76, 105, 109, 105, 116, 115, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 24, 117, 112, 100, 97, 116, 101, 95, 108, 105, 109, 105, 116, 115, 95, 100, 101, 102, 105, 110, 105, 116, 105, 111, 110, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 10, 10, 0, 10, 2, 17, 1, 11, 0, 10, 1, 10, 3, 10, 4, 10, 5, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(coin_type),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Address(limit_address), new TransactionArgument.U64(sliding_nonce), new TransactionArgument.U64(new_max_inflow), new TransactionArgument.U64(new_max_outflow), new TransactionArgument.U64(new_max_holding_balance)));
+    }
+
+    /**
+     * Update the on-chain exchange rate to LBR for the given `currency` to be given by
+     * `new_exchange_rate_numerator/new_exchange_rate_denominator`.
+     */
+    public static Script encodeUpdateExchangeRateScript(TypeTag currency, Long sliding_nonce, Long new_exchange_rate_numerator, Long new_exchange_rate_denominator) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 7, 1, 0, 6, 2, 6, 4, 3, 10, 16, 4, 26, 2, 5, 28, 25, 7, 53, 100, 8, (byte) 153, 1, 16, 0, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 3, 0, 1, 0, 2, 4, 2, 3, 0, 1, 5, 4, 3, 1, 1, 2, 6, 2, 3, 3, 1, 8, 0, 2, 6, 12, 3, 0, 2, 6, 12, 8, 0, 4, 6, 12, 3, 3, 3, 1, 9, 0, 12, 70, 105, 120, 101, 100, 80, 111, 105, 110, 116, 51, 50, 5, 76, 105, 98, 114, 97, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 20, 99, 114, 101, 97, 116, 101, 95, 102, 114, 111, 109, 95, 114, 97, 116, 105, 111, 110, 97, 108, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 24, 117, 112, 100, 97, 116, 101, 95, 108, 98, 114, 95, 101, 120, 99, 104, 97, 110, 103, 101, 95, 114, 97, 116, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 5, 1, 11, 10, 0, 10, 1, 17, 1, 10, 2, 10, 3, 17, 0, 12, 4, 11, 0, 11, 4, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(currency),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.U64(new_exchange_rate_numerator), new TransactionArgument.U64(new_exchange_rate_denominator)));
+    }
+
+    /**
+     * Update Libra version.
+     */
+    public static Script encodeUpdateLibraVersionScript(Long major) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 17, 8, 29, 16, 0, 0, 0, 1, 0, 1, 0, 2, 6, 12, 3, 0, 12, 76, 105, 98, 114, 97, 86, 101, 114, 115, 105, 111, 110, 3, 115, 101, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(major)));
+    }
+
+    /**
+     * Allows--true--or disallows--false--minting of `currency` based upon `allow_minting`.
+     */
+    public static Script encodeUpdateMintingAbilityScript(TypeTag currency, Boolean allow_minting) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 29, 8, 47, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 1, 0, 1, 9, 0, 5, 76, 105, 98, 114, 97, 22, 117, 112, 100, 97, 116, 101, 95, 109, 105, 110, 116, 105, 110, 103, 95, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11, 0, 10, 1, 56, 0, 2},
+            java.util.Arrays.<TypeTag>asList(currency),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.Bool(allow_minting)));
+    }
+
+    /**
+     * Update the travel rule limit to `new_micro_lbr_limit`.
+     */
+    public static Script encodeUpdateTravelRuleLimitScript(Long sliding_nonce, Long new_micro_lbr_limit) {
+        return new Script(
+            new byte[] {(byte) 161, 28, (byte) 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 10, 7, 24, 77, 8, 101, 16, 0, 0, 0, 1, 0, 2, 0, 1, 0, 1, 3, 0, 1, 0, 2, 6, 12, 3, 0, 3, 6, 12, 3, 3, 20, 68, 117, 97, 108, 65, 116, 116, 101, 115, 116, 97, 116, 105, 111, 110, 76, 105, 109, 105, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 20, 115, 101, 116, 95, 109, 105, 99, 114, 111, 108, 105, 98, 114, 97, 95, 108, 105, 109, 105, 116, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 1, 7, 10, 0, 10, 1, 17, 1, 11, 0, 10, 2, 17, 0, 2},
+            java.util.Arrays.<TypeTag>asList(),
+            java.util.Arrays.<TransactionArgument>asList(new TransactionArgument.U64(sliding_nonce), new TransactionArgument.U64(new_micro_lbr_limit)));
+    }
+
+}
@@ -10,6 +10,970 @@ use libra_types::{
     transaction::{Script, TransactionArgument},
 };
 use move_core_types::language_storage::TypeTag;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// Structured representation of a call into a known Move script.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScriptCall {
+    /// Add a `Currency` balance to `account`, which will enable `account` to send and receive
+    /// `Libra<Currency>`. Aborts with NOT_A_CURRENCY if `Currency` is not an accepted
+    /// currency type in the Libra system Aborts with `LibraAccount::ADD_EXISTING_CURRENCY` if
+    /// the account already holds a balance in `Currency`. Aborts with
+    /// `LibraAccount::PARENT_VASP_CURRENCY_LIMITS_DNE` if `account` is a `ChildVASP` whose
+    /// parent does not have an `AccountLimits<Currency>` resource.
+    AddCurrencyToAccount { currency: TypeTag },
+    /// Add the `KeyRotationCapability` for `to_recover_account` to the `RecoveryAddress`
+    /// resource under `recovery_address`. Aborts if `to_recovery_account` and
+    /// `to_recovery_address belong to different VASPs, if `recovery_address` does not have a
+    /// `RecoveryAddress` resource, or if `to_recover_account` has already extracted its
+    /// `KeyRotationCapability`.
+    AddRecoveryRotationCapability { recovery_address: AccountAddress },
+    /// Add `new_validator` to the pending validator set. Fails if the `new_validator` address
+    /// is already in the validator set or does not have a `ValidatorConfig` resource stored
+    /// at the address.
+    AddValidator { validator_address: AccountAddress },
+    /// Adds limits and an accounting window for `CoinType` currency to the parent VASP
+    /// `account`. This transaction will fail if sent from a child account.
+    AllowCurrencyForVasp { coin_type: TypeTag },
+    /// Permanently destroy the `Token`s stored in the oldest burn request under the `Preburn`
+    /// resource. This will only succeed if `account` has a `MintCapability<Token>`, a
+    /// `Preburn<Token>` resource exists under `preburn_address`, and there is a pending burn
+    /// request. sliding_nonce is a unique nonce for operation, see sliding_nonce.move for
+    /// details
+    Burn {
+        token: TypeTag,
+        sliding_nonce: u64,
+        preburn_address: AccountAddress,
+    },
+    /// Burn transaction fees that have been collected in the given `currency` and relinquish
+    /// to the association. The currency must be non-synthetic.
+    BurnTxnFees { coin_type: TypeTag },
+    /// Cancel the oldest burn request from `preburn_address` and return the funds. Fails if
+    /// the sender does not have a published `BurnCapability<Token>`.
+    CancelBurn {
+        token: TypeTag,
+        preburn_address: AccountAddress,
+    },
+    /// Create a `ChildVASP` account for sender `parent_vasp` at `child_address` with a
+    /// balance of `child_initial_balance` in `CoinType` and an initial authentication_key
+    /// `auth_key_prefix | child_address`. If `add_all_currencies` is true, the child address
+    /// will have a zero balance in all available currencies in the system. This account will
+    /// a child of the transaction sender, which must be a ParentVASP.
+    CreateChildVaspAccount {
+        coin_type: TypeTag,
+        child_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        add_all_currencies: bool,
+        child_initial_balance: u64,
+    },
+    /// Create an account with the DesignatedDealer role at `addr` with authentication key
+    /// `auth_key_prefix` | `addr` and a 0 balance of type `Currency`. If `add_all_currencies`
+    /// is true, 0 balances for all available currencies in the system will also be added.
+    /// This can only be invoked by an account with the TreasuryCompliance role.
+    CreateDesignatedDealer {
+        currency: TypeTag,
+        sliding_nonce: u64,
+        addr: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        add_all_currencies: bool,
+    },
+    /// Create an account with the ParentVASP role at `address` with authentication key
+    /// `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+    /// `add_all_currencies` is true, 0 balances for all available currencies in the system
+    /// will also be added. This can only be invoked by an Association account.
+    CreateParentVaspAccount {
+        coin_type: TypeTag,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        human_name: Vec<u8>,
+        base_url: Vec<u8>,
+        compliance_public_key: Vec<u8>,
+        add_all_currencies: bool,
+    },
+    /// Extract the `KeyRotationCapability` for `recovery_account` and publish it in a
+    /// `RecoveryAddress` resource under `recovery_account`. Aborts if `recovery_account` has
+    /// delegated its `KeyRotationCapability`, already has a `RecoveryAddress` resource, or is
+    /// not a VASP.
+    CreateRecoveryAddress {},
+    /// Create an account with the ParentVASP role at `address` with authentication key
+    /// `auth_key_prefix` | `new_account_address` and a 0 balance of type `currency`. If
+    /// `add_all_currencies` is true, 0 balances for all available currencies in the system
+    /// will also be added. This can only be invoked by an Association account. The
+    /// `human_name`, `base_url`, and compliance_public_key` fields of the ParentVASP are
+    /// filled in with dummy information.
+    CreateTestingAccount {
+        coin_type: TypeTag,
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+        add_all_currencies: bool,
+    },
+    /// Create a validator account at `new_validator_address` with `auth_key_prefix`.
+    CreateValidatorAccount {
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+    },
+    /// Create a validator operator account at `new_validator_address` with `auth_key_prefix`.
+    CreateValidatorOperatorAccount {
+        new_account_address: AccountAddress,
+        auth_key_prefix: Vec<u8>,
+    },
+    /// Freeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+    /// nonce for operation, see sliding_nonce.move for details.
+    FreezeAccount {
+        sliding_nonce: u64,
+        to_freeze_account: AccountAddress,
+    },
+    /// Mint `amount_lbr` LBR from the sending account's constituent coins and deposits the
+    /// resulting LBR into the sending account.
+    MintLbr { amount_lbr: u64 },
+    /// Modify publishing options. Takes the LCS bytes of a `VMPublishingOption` object as
+    /// input.
+    ModifyPublishingOption { args: Vec<u8> },
+    /// Transfer `amount` coins to `recipient_address` with (optional) associated metadata
+    /// `metadata` and (optional) `signature` on the metadata, amount, and sender address. The
+    /// `metadata` and `signature` parameters are only required if `amount` >= 1_000_000 micro
+    /// LBR and the sender and recipient of the funds are two distinct VASPs. Fails if there
+    /// is no account at the recipient address or if the sender's balance is lower than
+    /// `amount`.
+    PeerToPeerWithMetadata {
+        token: TypeTag,
+        payee: AccountAddress,
+        amount: u64,
+        metadata: Vec<u8>,
+        metadata_signature: Vec<u8>,
+    },
+    /// Preburn `amount` `Token`s from `account`. This will only succeed if `account` already
+    /// has a published `Preburn<Token>` resource.
+    Preburn { token: TypeTag, amount: u64 },
+    /// (1) Rotate the authentication key of the sender to `public_key` (2) Publish a resource
+    /// containing a 32-byte ed25519 public key and the rotation capability of the sender
+    /// under the sender's address. Aborts if the sender already has a
+    /// `SharedEd25519PublicKey` resource. Aborts if the length of `new_public_key` is not 32.
+    PublishSharedEd25519PublicKey { public_key: Vec<u8> },
+    /// Update configs of all the validators and emit reconfiguration event.
+    Reconfigure {},
+    /// Adding `to_remove` to the set of pending validator removals. Fails if the `to_remove`
+    /// address is already in the validator set or already in the pending removals. Callable
+    /// by Validator's operator.
+    RemoveValidator { validator_address: AccountAddress },
+    /// Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+    /// sha3 hash of an ed25519 public key.
+    RotateAuthenticationKey { new_key: Vec<u8> },
+    /// Rotate the sender's authentication key to `new_key`. `new_key` should be a 256 bit
+    /// sha3 hash of an ed25519 public key. This script also takes `sliding_nonce`, as a
+    /// unique nonce for this operation. See sliding_nonce.move for details.
+    RotateAuthenticationKeyWithNonce {
+        sliding_nonce: u64,
+        new_key: Vec<u8>,
+    },
+    /// Rotate the authentication key of `to_recover` to `new_key`. Can be invoked by either
+    /// `recovery_address` or `to_recover`. Aborts if `recovery_address` does not have the
+    /// `KeyRotationCapability` for `to_recover`.
+    RotateAuthenticationKeyWithRecoveryAddress {
+        recovery_address: AccountAddress,
+        to_recover: AccountAddress,
+        new_key: Vec<u8>,
+    },
+    /// Rotate `vasp_root_addr`'s base URL to `new_url`.
+    RotateBaseUrl { new_url: Vec<u8> },
+    /// Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`.
+    RotateCompliancePublicKey { new_key: Vec<u8> },
+    /// (1) Rotate the public key stored in `account`'s `SharedEd25519PublicKey` resource to
+    /// `new_public_key` (2) Rotate the authentication key using the capability stored in
+    /// `account`'s `SharedEd25519PublicKey` to a new value derived from `new_public_key`
+    /// Aborts if `account` does not have a `SharedEd25519PublicKey` resource. Aborts if the
+    /// length of `new_public_key` is not 32.
+    RotateSharedEd25519PublicKey { public_key: Vec<u8> },
+    /// Sets the account limits window `tracking_balance` field for `CointType` at
+    /// `window_address` to `aggregate_balance`
+    SetAccountLimitWindowCurrentHoldings {
+        coint_type: TypeTag,
+        window_address: AccountAddress,
+        aggregate_balance: u64,
+    },
+    /// Set validator's config.
+    SetValidatorConfig {
+        validator_account: AccountAddress,
+        consensus_pubkey: Vec<u8>,
+        validator_network_identity_pubkey: Vec<u8>,
+        validator_network_address: Vec<u8>,
+        fullnodes_network_identity_pubkey: Vec<u8>,
+        fullnodes_network_address: Vec<u8>,
+    },
+    /// Set validator's operator
+    SetValidatorOperator { operator_account: AccountAddress },
+    /// Send `amount` coins of type `Token` to `payee`.
+    TestnetMint {
+        token: TypeTag,
+        payee: AccountAddress,
+        amount: u64,
+    },
+    /// Mint 'mint_amount' to 'designated_dealer_address' for 'tier_index' tier. Max valid
+    /// tier index is 3 since there are max 4 tiers per DD. Sender should be treasury
+    /// compliance account and receiver authorized DD. `sliding_nonce` is a unique nonce for
+    /// operation, see sliding_nonce.move for details.
+    TieredMint {
+        coin_type: TypeTag,
+        sliding_nonce: u64,
+        designated_dealer_address: AccountAddress,
+        mint_amount: u64,
+        tier_index: u64,
+    },
+    /// Unfreeze account `address`. Initiator must be authorized. `sliding_nonce` is a unique
+    /// nonce for operation, see sliding_nonce.move for details.
+    UnfreezeAccount {
+        sliding_nonce: u64,
+        to_unfreeze_account: AccountAddress,
+    },
+    /// Unmints `amount_lbr` LBR from the sending account into the constituent coins and
+    /// deposits the resulting coins into the sending account."
+    UnmintLbr { amount_lbr: u64 },
+    /// Optionally update thresholds of max balance, inflow, outflow for any limits-bound
+    /// accounts with their limits defined at `limit_address`. Limits are defined in terms of
+    /// base (on-chain) currency units for `CoinType`. If a new threshold is 0, that
+    /// particular config does not get updated. `sliding_nonce` is a unique nonce for
+    /// operation, see SlidingNonce.move for details.
+    UpdateAccountLimitDefinition {
+        coin_type: TypeTag,
+        limit_address: AccountAddress,
+        sliding_nonce: u64,
+        new_max_inflow: u64,
+        new_max_outflow: u64,
+        new_max_holding_balance: u64,
+    },
+    /// Update the on-chain exchange rate to LBR for the given `currency` to be given by
+    /// `new_exchange_rate_numerator/new_exchange_rate_denominator`.
+    UpdateExchangeRate {
+        currency: TypeTag,
+        sliding_nonce: u64,
+        new_exchange_rate_numerator: u64,
+        new_exchange_rate_denominator: u64,
+    },
+    /// Update Libra version.
+    UpdateLibraVersion { major: u64 },
+    /// Allows--true--or disallows--false--minting of `currency` based upon `allow_minting`.
+    UpdateMintingAbility {
+        currency: TypeTag,
+        allow_minting: bool,
+    },
+    /// Update the travel rule limit to `new_micro_lbr_limit`.
+    UpdateTravelRuleLimit {
+        sliding_nonce: u64,
+        new_micro_lbr_limit: u64,
+    },
+}
+
+impl ScriptCall {
+    /// Build a Libra `Script` from a structured object `ScriptCall`.
+    pub fn encode(self) -> Script {
+        use ScriptCall::*;
+        match self {
+            AddCurrencyToAccount { currency } => encode_add_currency_to_account_script(currency),
+            AddRecoveryRotationCapability { recovery_address } => {
+                encode_add_recovery_rotation_capability_script(recovery_address)
+            }
+            AddValidator { validator_address } => encode_add_validator_script(validator_address),
+            AllowCurrencyForVasp { coin_type } => encode_allow_currency_for_vasp_script(coin_type),
+            Burn {
+                token,
+                sliding_nonce,
+                preburn_address,
+            } => encode_burn_script(token, sliding_nonce, preburn_address),
+            BurnTxnFees { coin_type } => encode_burn_txn_fees_script(coin_type),
+            CancelBurn {
+                token,
+                preburn_address,
+            } => encode_cancel_burn_script(token, preburn_address),
+            CreateChildVaspAccount {
+                coin_type,
+                child_address,
+                auth_key_prefix,
+                add_all_currencies,
+                child_initial_balance,
+            } => encode_create_child_vasp_account_script(
+                coin_type,
+                child_address,
+                auth_key_prefix,
+                add_all_currencies,
+                child_initial_balance,
+            ),
+            CreateDesignatedDealer {
+                currency,
+                sliding_nonce,
+                addr,
+                auth_key_prefix,
+                add_all_currencies,
+            } => encode_create_designated_dealer_script(
+                currency,
+                sliding_nonce,
+                addr,
+                auth_key_prefix,
+                add_all_currencies,
+            ),
+            CreateParentVaspAccount {
+                coin_type,
+                new_account_address,
+                auth_key_prefix,
+                human_name,
+                base_url,
+                compliance_public_key,
+                add_all_currencies,
+            } => encode_create_parent_vasp_account_script(
+                coin_type,
+                new_account_address,
+                auth_key_prefix,
+                human_name,
+                base_url,
+                compliance_public_key,
+                add_all_currencies,
+            ),
+            CreateRecoveryAddress {} => encode_create_recovery_address_script(),
+            CreateTestingAccount {
+                coin_type,
+                new_account_address,
+                auth_key_prefix,
+                add_all_currencies,
+            } => encode_create_testing_account_script(
+                coin_type,
+                new_account_address,
+                auth_key_prefix,
+                add_all_currencies,
+            ),
+            CreateValidatorAccount {
+                new_account_address,
+                auth_key_prefix,
+            } => encode_create_validator_account_script(new_account_address, auth_key_prefix),
+            CreateValidatorOperatorAccount {
+                new_account_address,
+                auth_key_prefix,
+            } => encode_create_validator_operator_account_script(
+                new_account_address,
+                auth_key_prefix,
+            ),
+            FreezeAccount {
+                sliding_nonce,
+                to_freeze_account,
+            } => encode_freeze_account_script(sliding_nonce, to_freeze_account),
+            MintLbr { amount_lbr } => encode_mint_lbr_script(amount_lbr),
+            ModifyPublishingOption { args } => encode_modify_publishing_option_script(args),
+            PeerToPeerWithMetadata {
+                token,
+                payee,
+                amount,
+                metadata,
+                metadata_signature,
+            } => encode_peer_to_peer_with_metadata_script(
+                token,
+                payee,
+                amount,
+                metadata,
+                metadata_signature,
+            ),
+            Preburn { token, amount } => encode_preburn_script(token, amount),
+            PublishSharedEd25519PublicKey { public_key } => {
+                encode_publish_shared_ed25519_public_key_script(public_key)
+            }
+            Reconfigure {} => encode_reconfigure_script(),
+            RemoveValidator { validator_address } => {
+                encode_remove_validator_script(validator_address)
+            }
+            RotateAuthenticationKey { new_key } => encode_rotate_authentication_key_script(new_key),
+            RotateAuthenticationKeyWithNonce {
+                sliding_nonce,
+                new_key,
+            } => encode_rotate_authentication_key_with_nonce_script(sliding_nonce, new_key),
+            RotateAuthenticationKeyWithRecoveryAddress {
+                recovery_address,
+                to_recover,
+                new_key,
+            } => encode_rotate_authentication_key_with_recovery_address_script(
+                recovery_address,
+                to_recover,
+                new_key,
+            ),
+            RotateBaseUrl { new_url } => encode_rotate_base_url_script(new_url),
+            RotateCompliancePublicKey { new_key } => {
+                encode_rotate_compliance_public_key_script(new_key)
+            }
+            RotateSharedEd25519PublicKey { public_key } => {
+                encode_rotate_shared_ed25519_public_key_script(public_key)
+            }
+            SetAccountLimitWindowCurrentHoldings {
+                coint_type,
+                window_address,
+                aggregate_balance,
+            } => encode_set_account_limit_window_current_holdings_script(
+                coint_type,
+                window_address,
+                aggregate_balance,
+            ),
+            SetValidatorConfig {
+                validator_account,
+                consensus_pubkey,
+                validator_network_identity_pubkey,
+                validator_network_address,
+                fullnodes_network_identity_pubkey,
+                fullnodes_network_address,
+            } => encode_set_validator_config_script(
+                validator_account,
+                consensus_pubkey,
+                validator_network_identity_pubkey,
+                validator_network_address,
+                fullnodes_network_identity_pubkey,
+                fullnodes_network_address,
+            ),
+            SetValidatorOperator { operator_account } => {
+                encode_set_validator_operator_script(operator_account)
+            }
+            TestnetMint {
+                token,
+                payee,
+                amount,
+            } => encode_testnet_mint_script(token, payee, amount),
+            TieredMint {
+                coin_type,
+                sliding_nonce,
+                designated_dealer_address,
+                mint_amount,
+                tier_index,
+            } => encode_tiered_mint_script(
+                coin_type,
+                sliding_nonce,
+                designated_dealer_address,
+                mint_amount,
+                tier_index,
+            ),
+            UnfreezeAccount {
+                sliding_nonce,
+                to_unfreeze_account,
+            } => encode_unfreeze_account_script(sliding_nonce, to_unfreeze_account),
+            UnmintLbr { amount_lbr } => encode_unmint_lbr_script(amount_lbr),
+            UpdateAccountLimitDefinition {
+                coin_type,
+                limit_address,
+                sliding_nonce,
+                new_max_inflow,
+                new_max_outflow,
+                new_max_holding_balance,
+            } => encode_update_account_limit_definition_script(
+                coin_type,
+                limit_address,
+                sliding_nonce,
+                new_max_inflow,
+                new_max_outflow,
+                new_max_holding_balance,
+            ),
+            UpdateExchangeRate {
+                currency,
+                sliding_nonce,
+                new_exchange_rate_numerator,
+                new_exchange_rate_denominator,
+            } => encode_update_exchange_rate_script(
+                currency,
+                sliding_nonce,
+                new_exchange_rate_numerator,
+                new_exchange_rate_denominator,
+            ),
+            UpdateLibraVersion { major } => encode_update_libra_version_script(major),
+            UpdateMintingAbility {
+                currency,
+                allow_minting,
+            } => encode_update_minting_ability_script(currency, allow_minting),
+            UpdateTravelRuleLimit {
+                sliding_nonce,
+                new_micro_lbr_limit,
+            } => encode_update_travel_rule_limit_script(sliding_nonce, new_micro_lbr_limit),
+        }
+    }
+
+    /// Try to recognize a Libra `Script` and convert it into a structured object `ScriptCall`.
+    pub fn decode(script: &Script) -> Option<ScriptCall> {
+        match SCRIPT_DECODER_MAP.get(script.code()) {
+            Some(decoder) => decoder(script),
+            None => None,
+        }
+    }
+
+    /// Return the name of a Libra `Script` from a structured object `ScriptCall`.
+    pub fn name(&self) -> &'static str {
+        use ScriptCall::*;
+        match self {
+            AddCurrencyToAccount { .. } => "add_currency_to_account",
+            AddRecoveryRotationCapability { .. } => "add_recovery_rotation_capability",
+            AddValidator { .. } => "add_validator",
+            AllowCurrencyForVasp { .. } => "allow_currency_for_vasp",
+            Burn { .. } => "burn",
+            BurnTxnFees { .. } => "burn_txn_fees",
+            CancelBurn { .. } => "cancel_burn",
+            CreateChildVaspAccount { .. } => "create_child_vasp_account",
+            CreateDesignatedDealer { .. } => "create_designated_dealer",
+            CreateParentVaspAccount { .. } => "create_parent_vasp_account",
+            CreateRecoveryAddress { .. } => "create_recovery_address",
+            CreateTestingAccount { .. } => "create_testing_account",
+            CreateValidatorAccount { .. } => "create_validator_account",
+            CreateValidatorOperatorAccount { .. } => "create_validator_operator_account",
+            FreezeAccount { .. } => "freeze_account",
+            MintLbr { .. } => "mint_lbr",
+            ModifyPublishingOption { .. } => "modify_publishing_option",
+            PeerToPeerWithMetadata { .. } => "peer_to_peer_with_metadata",
+            Preburn { .. } => "preburn",
+            PublishSharedEd25519PublicKey { .. } => "publish_shared_ed25519_public_key",
+            Reconfigure { .. } => "reconfigure",
+            RemoveValidator { .. } => "remove_validator",
+            RotateAuthenticationKey { .. } => "rotate_authentication_key",
+            RotateAuthenticationKeyWithNonce { .. } => "rotate_authentication_key_with_nonce",
+            RotateAuthenticationKeyWithRecoveryAddress { .. } => {
+                "rotate_authentication_key_with_recovery_address"
+            }
+            RotateBaseUrl { .. } => "rotate_base_url",
+            RotateCompliancePublicKey { .. } => "rotate_compliance_public_key",
+            RotateSharedEd25519PublicKey { .. } => "rotate_shared_ed25519_public_key",
+            SetAccountLimitWindowCurrentHoldings { .. } => {
+                "set_account_limit_window_current_holdings"
+            }
+            SetValidatorConfig { .. } => "set_validator_config",
+            SetValidatorOperator { .. } => "set_validator_operator",
+            TestnetMint { .. } => "testnet_mint",
+            TieredMint { .. } => "tiered_mint",
+            UnfreezeAccount { .. } => "unfreeze_account",
+            UnmintLbr { .. } => "unmint_lbr",
+            UpdateAccountLimitDefinition { .. } => "update_account_limit_definition",
+            UpdateExchangeRate { .. } => "update_exchange_rate",
+            UpdateLibraVersion { .. } => "update_libra_version",
+            UpdateMintingAbility { .. } => "update_minting_ability",
+            UpdateTravelRuleLimit { .. } => "update_travel_rule_limit",
+        }
+    }
+
+    /// Return the names and values of the type arguments of a structured object `ScriptCall`.
+    pub fn type_arguments(&self) -> Vec<(&'static str, &TypeTag)> {
+        use ScriptCall::*;
+        match self {
+            AddCurrencyToAccount { currency, .. } => vec![("currency", currency)],
+            AddRecoveryRotationCapability { .. } => vec![],
+            AddValidator { .. } => vec![],
+            AllowCurrencyForVasp { coin_type, .. } => vec![("coin_type", coin_type)],
+            Burn { token, .. } => vec![("token", token)],
+            BurnTxnFees { coin_type, .. } => vec![("coin_type", coin_type)],
+            CancelBurn { token, .. } => vec![("token", token)],
+            CreateChildVaspAccount { coin_type, .. } => vec![("coin_type", coin_type)],
+            CreateDesignatedDealer { currency, .. } => vec![("currency", currency)],
+            CreateParentVaspAccount { coin_type, .. } => vec![("coin_type", coin_type)],
+            CreateRecoveryAddress { .. } => vec![],
+            CreateTestingAccount { coin_type, .. } => vec![("coin_type", coin_type)],
+            CreateValidatorAccount { .. } => vec![],
+            CreateValidatorOperatorAccount { .. } => vec![],
+            FreezeAccount { .. } => vec![],
+            MintLbr { .. } => vec![],
+            ModifyPublishingOption { .. } => vec![],
+            PeerToPeerWithMetadata { token, .. } => vec![("token", token)],
+            Preburn { token, .. } => vec![("token", token)],
+            PublishSharedEd25519PublicKey { .. } => vec![],
+            Reconfigure { .. } => vec![],
+            RemoveValidator { .. } => vec![],
+            RotateAuthenticationKey { .. } => vec![],
+            RotateAuthenticationKeyWithNonce { .. } => vec![],
+            RotateAuthenticationKeyWithRecoveryAddress { .. } => vec![],
+            RotateBaseUrl { .. } => vec![],
+            RotateCompliancePublicKey { .. } => vec![],
+            RotateSharedEd25519PublicKey { .. } => vec![],
+            SetAccountLimitWindowCurrentHoldings { coint_type, .. } => {
+                vec![("coint_type", coint_type)]
+            }
+            SetValidatorConfig { .. } => vec![],
+            SetValidatorOperator { .. } => vec![],
+            TestnetMint { token, .. } => vec![("token", token)],
+            TieredMint { coin_type, .. } => vec![("coin_type", coin_type)],
+            UnfreezeAccount { .. } => vec![],
+            UnmintLbr { .. } => vec![],
+            UpdateAccountLimitDefinition { coin_type, .. } => vec![("coin_type", coin_type)],
+            UpdateExchangeRate { currency, .. } => vec![("currency", currency)],
+            UpdateLibraVersion { .. } => vec![],
+            UpdateMintingAbility { currency, .. } => vec![("currency", currency)],
+            UpdateTravelRuleLimit { .. } => vec![],
+        }
+    }
+
+    /// Return the names and values of the arguments of a structured object `ScriptCall`.
+    pub fn arguments(&self) -> Vec<(&'static str, TransactionArgument)> {
+        use ScriptCall::*;
+        match self {
+            AddCurrencyToAccount { .. } => vec![],
+            AddRecoveryRotationCapability {
+                recovery_address, ..
+            } => vec![(
+                "recovery_address",
+                TransactionArgument::Address(*recovery_address),
+            )],
+            AddValidator {
+                validator_address, ..
+            } => vec![(
+                "validator_address",
+                TransactionArgument::Address(*validator_address),
+            )],
+            AllowCurrencyForVasp { .. } => vec![],
+            Burn {
+                sliding_nonce,
+                preburn_address,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "preburn_address",
+                    TransactionArgument::Address(*preburn_address),
+                ),
+            ],
+            BurnTxnFees { .. } => vec![],
+            CancelBurn {
+                preburn_address, ..
+            } => vec![(
+                "preburn_address",
+                TransactionArgument::Address(*preburn_address),
+            )],
+            CreateChildVaspAccount {
+                child_address,
+                auth_key_prefix,
+                add_all_currencies,
+                child_initial_balance,
+                ..
+            } => vec![
+                (
+                    "child_address",
+                    TransactionArgument::Address(*child_address),
+                ),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+                (
+                    "add_all_currencies",
+                    TransactionArgument::Bool(*add_all_currencies),
+                ),
+                (
+                    "child_initial_balance",
+                    TransactionArgument::U64(*child_initial_balance),
+                ),
+            ],
+            CreateDesignatedDealer {
+                sliding_nonce,
+                addr,
+                auth_key_prefix,
+                add_all_currencies,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                ("addr", TransactionArgument::Address(*addr)),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+                (
+                    "add_all_currencies",
+                    TransactionArgument::Bool(*add_all_currencies),
+                ),
+            ],
+            CreateParentVaspAccount {
+                new_account_address,
+                auth_key_prefix,
+                human_name,
+                base_url,
+                compliance_public_key,
+                add_all_currencies,
+                ..
+            } => vec![
+                (
+                    "new_account_address",
+                    TransactionArgument::Address(*new_account_address),
+                ),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+                (
+                    "human_name",
+                    TransactionArgument::U8Vector(human_name.clone()),
+                ),
+                ("base_url", TransactionArgument::U8Vector(base_url.clone())),
+                (
+                    "compliance_public_key",
+                    TransactionArgument::U8Vector(compliance_public_key.clone()),
+                ),
+                (
+                    "add_all_currencies",
+                    TransactionArgument::Bool(*add_all_currencies),
+                ),
+            ],
+            CreateRecoveryAddress { .. } => vec![],
+            CreateTestingAccount {
+                new_account_address,
+                auth_key_prefix,
+                add_all_currencies,
+                ..
+            } => vec![
+                (
+                    "new_account_address",
+                    TransactionArgument::Address(*new_account_address),
+                ),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+                (
+                    "add_all_currencies",
+                    TransactionArgument::Bool(*add_all_currencies),
+                ),
+            ],
+            CreateValidatorAccount {
+                new_account_address,
+                auth_key_prefix,
+                ..
+            } => vec![
+                (
+                    "new_account_address",
+                    TransactionArgument::Address(*new_account_address),
+                ),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+            ],
+            CreateValidatorOperatorAccount {
+                new_account_address,
+                auth_key_prefix,
+                ..
+            } => vec![
+                (
+                    "new_account_address",
+                    TransactionArgument::Address(*new_account_address),
+                ),
+                (
+                    "auth_key_prefix",
+                    TransactionArgument::U8Vector(auth_key_prefix.clone()),
+                ),
+            ],
+            FreezeAccount {
+                sliding_nonce,
+                to_freeze_account,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "to_freeze_account",
+                    TransactionArgument::Address(*to_freeze_account),
+                ),
+            ],
+            MintLbr { amount_lbr, .. } => {
+                vec![("amount_lbr", TransactionArgument::U64(*amount_lbr))]
+            }
+            ModifyPublishingOption { args, .. } => {
+                vec![("args", TransactionArgument::U8Vector(args.clone()))]
+            }
+            PeerToPeerWithMetadata {
+                payee,
+                amount,
+                metadata,
+                metadata_signature,
+                ..
+            } => vec![
+                ("payee", TransactionArgument::Address(*payee)),
+                ("amount", TransactionArgument::U64(*amount)),
+                ("metadata", TransactionArgument::U8Vector(metadata.clone())),
+                (
+                    "metadata_signature",
+                    TransactionArgument::U8Vector(metadata_signature.clone()),
+                ),
+            ],
+            Preburn { amount, .. } => vec![("amount", TransactionArgument::U64(*amount))],
+            PublishSharedEd25519PublicKey { public_key, .. } => vec![(
+                "public_key",
+                TransactionArgument::U8Vector(public_key.clone()),
+            )],
+            Reconfigure { .. } => vec![],
+            RemoveValidator {
+                validator_address, ..
+            } => vec![(
+                "validator_address",
+                TransactionArgument::Address(*validator_address),
+            )],
+            RotateAuthenticationKey { new_key, .. } => {
+                vec![("new_key", TransactionArgument::U8Vector(new_key.clone()))]
+            }
+            RotateAuthenticationKeyWithNonce {
+                sliding_nonce,
+                new_key,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                ("new_key", TransactionArgument::U8Vector(new_key.clone())),
+            ],
+            RotateAuthenticationKeyWithRecoveryAddress {
+                recovery_address,
+                to_recover,
+                new_key,
+                ..
+            } => vec![
+                (
+                    "recovery_address",
+                    TransactionArgument::Address(*recovery_address),
+                ),
+                ("to_recover", TransactionArgument::Address(*to_recover)),
+                ("new_key", TransactionArgument::U8Vector(new_key.clone())),
+            ],
+            RotateBaseUrl { new_url, .. } => {
+                vec![("new_url", TransactionArgument::U8Vector(new_url.clone()))]
+            }
+            RotateCompliancePublicKey { new_key, .. } => {
+                vec![("new_key", TransactionArgument::U8Vector(new_key.clone()))]
+            }
+            RotateSharedEd25519PublicKey { public_key, .. } => vec![(
+                "public_key",
+                TransactionArgument::U8Vector(public_key.clone()),
+            )],
+            SetAccountLimitWindowCurrentHoldings {
+                window_address,
+                aggregate_balance,
+                ..
+            } => vec![
+                (
+                    "window_address",
+                    TransactionArgument::Address(*window_address),
+                ),
+                (
+                    "aggregate_balance",
+                    TransactionArgument::U64(*aggregate_balance),
+                ),
+            ],
+            SetValidatorConfig {
+                validator_account,
+                consensus_pubkey,
+                validator_network_identity_pubkey,
+                validator_network_address,
+                fullnodes_network_identity_pubkey,
+                fullnodes_network_address,
+                ..
+            } => vec![
+                (
+                    "validator_account",
+                    TransactionArgument::Address(*validator_account),
+                ),
+                (
+                    "consensus_pubkey",
+                    TransactionArgument::U8Vector(consensus_pubkey.clone()),
+                ),
+                (
+                    "validator_network_identity_pubkey",
+                    TransactionArgument::U8Vector(validator_network_identity_pubkey.clone()),
+                ),
+                (
+                    "validator_network_address",
+                    TransactionArgument::U8Vector(validator_network_address.clone()),
+                ),
+                (
+                    "fullnodes_network_identity_pubkey",
+                    TransactionArgument::U8Vector(fullnodes_network_identity_pubkey.clone()),
+                ),
+                (
+                    "fullnodes_network_address",
+                    TransactionArgument::U8Vector(fullnodes_network_address.clone()),
+                ),
+            ],
+            SetValidatorOperator {
+                operator_account, ..
+            } => vec![(
+                "operator_account",
+                TransactionArgument::Address(*operator_account),
+            )],
+            TestnetMint { payee, amount, .. } => vec![
+                ("payee", TransactionArgument::Address(*payee)),
+                ("amount", TransactionArgument::U64(*amount)),
+            ],
+            TieredMint {
+                sliding_nonce,
+                designated_dealer_address,
+                mint_amount,
+                tier_index,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "designated_dealer_address",
+                    TransactionArgument::Address(*designated_dealer_address),
+                ),
+                ("mint_amount", TransactionArgument::U64(*mint_amount)),
+                ("tier_index", TransactionArgument::U64(*tier_index)),
+            ],
+            UnfreezeAccount {
+                sliding_nonce,
+                to_unfreeze_account,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "to_unfreeze_account",
+                    TransactionArgument::Address(*to_unfreeze_account),
+                ),
+            ],
+            UnmintLbr { amount_lbr, .. } => {
+                vec![("amount_lbr", TransactionArgument::U64(*amount_lbr))]
+            }
+            UpdateAccountLimitDefinition {
+                limit_address,
+                sliding_nonce,
+                new_max_inflow,
+                new_max_outflow,
+                new_max_holding_balance,
+                ..
+            } => vec![
+                (
+                    "limit_address",
+                    TransactionArgument::Address(*limit_address),
+                ),
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                ("new_max_inflow", TransactionArgument::U64(*new_max_inflow)),
+                (
+                    "new_max_outflow",
+                    TransactionArgument::U64(*new_max_outflow),
+                ),
+                (
+                    "new_max_holding_balance",
+                    TransactionArgument::U64(*new_max_holding_balance),
+                ),
+            ],
+            UpdateExchangeRate {
+                sliding_nonce,
+                new_exchange_rate_numerator,
+                new_exchange_rate_denominator,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "new_exchange_rate_numerator",
+                    TransactionArgument::U64(*new_exchange_rate_numerator),
+                ),
+                (
+                    "new_exchange_rate_denominator",
+                    TransactionArgument::U64(*new_exchange_rate_denominator),
+                ),
+            ],
+            UpdateLibraVersion { major, .. } => vec![("major", TransactionArgument::U64(*major))],
+            UpdateMintingAbility { allow_minting, .. } => {
+                vec![("allow_minting", TransactionArgument::Bool(*allow_minting))]
+            }
+            UpdateTravelRuleLimit {
+                sliding_nonce,
+                new_micro_lbr_limit,
+                ..
+            } => vec![
+                ("sliding_nonce", TransactionArgument::U64(*sliding_nonce)),
+                (
+                    "new_micro_lbr_limit",
+                    TransactionArgument::U64(*new_micro_lbr_limit),
+                ),
+            ],
+        }
+    }
+}
 
 /// Add a `Currency` balance to `account`, which will enable `account` to send and receive
 /// `Libra<Currency>`. Aborts with NOT_A_CURRENCY if `Currency` is not an accepted
@@ -19,12 +983,7 @@ use move_core_types::language_storage::TypeTag;
 /// parent does not have an `AccountLimits<Currency>` resource.
 pub fn encode_add_currency_to_account_script(currency: TypeTag) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 26, 8, 43,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65,
-            99, 99, 111, 117, 110, 116, 12, 97, 100, 100, 95, 99, 117, 114, 114, 101, 110, 99, 121,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2,
-        ],
+        ADD_CURRENCY_TO_ACCOUNT_CODE.to_vec(),
         vec![currency],
         vec![],
     )
@@ -37,13 +996,7 @@ pub fn encode_add_currency_to_account_script(currency: TypeTag) -> Script {
 /// `KeyRotationCapability`.
 pub fn encode_add_recovery_rotation_capability_script(recovery_address: AccountAddress) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 40, 8, 52, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100,
-            114, 101, 115, 115, 23, 97, 100, 100, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95,
-            99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
-        ],
+        ADD_RECOVERY_ROTATION_CAPABILITY_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::Address(recovery_address)],
     )
@@ -54,12 +1007,7 @@ pub fn encode_add_recovery_rotation_capability_script(recovery_address: AccountA
 /// at the address.
 pub fn encode_add_validator_script(validator_address: AccountAddress) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 26, 8, 38, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109,
-            13, 97, 100, 100, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
-        ],
+        ADD_VALIDATOR_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::Address(validator_address)],
     )
@@ -69,12 +1017,7 @@ pub fn encode_add_validator_script(validator_address: AccountAddress) -> Script
 /// `account`. This transaction will fail if sent from a child account.
 pub fn encode_allow_currency_for_vasp_script(coin_type: TypeTag) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 24, 8, 43,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 3, 1, 6, 12, 1, 1, 0, 1, 9, 0, 4, 86, 65, 83, 80, 18,
-            116, 114, 121, 95, 97, 108, 108, 111, 119, 95, 99, 117, 114, 114, 101, 110, 99, 121, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 2, 4, 11, 0, 56, 0, 1, 2,
-        ],
+        ALLOW_CURRENCY_FOR_VASP_CODE.to_vec(),
         vec![coin_type],
         vec![],
     )
@@ -91,14 +1034,7 @@ pub fn encode_burn_script(
     preburn_address: AccountAddress,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 17, 7, 34, 46, 8,
-            80, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 2, 6, 12, 5,
-            3, 6, 12, 3, 5, 1, 9, 0, 5, 76, 105, 98, 114, 97, 12, 83, 108, 105, 100, 105, 110, 103,
-            78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95,
-            111, 114, 95, 97, 98, 111, 114, 116, 4, 98, 117, 114, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 56, 0, 2,
-        ],
+        BURN_CODE.to_vec(),
         vec![token],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -110,28 +1046,14 @@ pub fn encode_burn_script(
 /// Burn transaction fees that have been collected in the given `currency` and relinquish
 /// to the association. The currency must be non-synthetic.
 pub fn encode_burn_txn_fees_script(coin_type: TypeTag) -> Script {
-    Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 25, 8, 42,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 14, 84, 114, 97, 110, 115, 97,
-            99, 116, 105, 111, 110, 70, 101, 101, 9, 98, 117, 114, 110, 95, 102, 101, 101, 115, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2,
-        ],
-        vec![coin_type],
-        vec![],
-    )
+    Script::new(BURN_TXN_FEES_CODE.to_vec(), vec![coin_type], vec![])
 }
 
 /// Cancel the oldest burn request from `preburn_address` and return the funds. Fails if
 /// the sender does not have a published `BurnCapability<Token>`.
 pub fn encode_cancel_burn_script(token: TypeTag, preburn_address: AccountAddress) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 25, 8, 43,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 5, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97,
-            65, 99, 99, 111, 117, 110, 116, 11, 99, 97, 110, 99, 101, 108, 95, 98, 117, 114, 110,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11, 0, 10, 1, 56, 0, 2,
-        ],
+        CANCEL_BURN_CODE.to_vec(),
         vec![token],
         vec![TransactionArgument::Address(preburn_address)],
     )
@@ -150,22 +1072,7 @@ pub fn encode_create_child_vasp_account_script(
     child_initial_balance: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 8, 1, 0, 2, 2, 2, 4, 3, 6, 22, 4, 28, 4, 5, 32, 35, 7,
-            67, 123, 8, 190, 1, 16, 6, 206, 1, 4, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 1, 1, 0, 3, 2, 3,
-            0, 0, 4, 4, 1, 1, 1, 0, 5, 3, 1, 0, 0, 6, 2, 6, 4, 6, 12, 5, 10, 2, 1, 0, 1, 6, 12, 1,
-            8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 5, 6, 12, 5, 10, 2, 1, 3, 1, 9, 0, 12, 76, 105,
-            98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119,
-            67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 99, 114, 101, 97, 116, 101, 95, 99,
-            104, 105, 108, 100, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 27, 101,
-            120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112,
-            97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101,
-            115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112,
-            97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 2,
-            1, 0, 1, 1, 5, 3, 25, 10, 0, 10, 1, 11, 2, 10, 3, 56, 0, 10, 4, 6, 0, 0, 0, 0, 0, 0, 0,
-            0, 36, 3, 10, 5, 22, 11, 0, 17, 1, 12, 5, 14, 5, 10, 1, 10, 4, 7, 0, 7, 0, 56, 1, 11,
-            5, 17, 3, 5, 24, 11, 0, 1, 2,
-        ],
+        CREATE_CHILD_VASP_ACCOUNT_CODE.to_vec(),
         vec![coin_type],
         vec![
             TransactionArgument::Address(child_address),
@@ -188,21 +1095,7 @@ pub fn encode_create_designated_dealer_script(
     add_all_currencies: bool,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 6, 3, 6, 16, 4, 22, 2, 5, 24, 28, 7, 52, 99, 8,
-            151, 1, 16, 0, 0, 0, 1, 0, 2, 2, 3, 0, 1, 0, 0, 4, 2, 1, 0, 1, 5, 3, 1, 1, 1, 2, 5, 2,
-            6, 12, 3, 0, 3, 6, 12, 5, 3, 4, 6, 12, 5, 10, 2, 1, 5, 6, 12, 3, 5, 10, 2, 1, 1, 9, 0,
-            16, 68, 101, 115, 105, 103, 110, 97, 116, 101, 100, 68, 101, 97, 108, 101, 114, 12, 76,
-            105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103,
-            78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95,
-            111, 114, 95, 97, 98, 111, 114, 116, 8, 97, 100, 100, 95, 116, 105, 101, 114, 24, 99,
-            114, 101, 97, 116, 101, 95, 100, 101, 115, 105, 103, 110, 97, 116, 101, 100, 95, 100,
-            101, 97, 108, 101, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 25,
-            10, 0, 10, 1, 17, 0, 10, 0, 10, 2, 11, 3, 10, 4, 56, 0, 10, 0, 10, 2, 6, 32, 161, 7, 0,
-            0, 0, 0, 0, 17, 1, 10, 0, 10, 2, 6, 64, 75, 76, 0, 0, 0, 0, 0, 17, 1, 10, 0, 10, 2, 6,
-            128, 240, 250, 2, 0, 0, 0, 0, 17, 1, 11, 0, 10, 2, 6, 0, 101, 205, 29, 0, 0, 0, 0, 17,
-            1, 2,
-        ],
+        CREATE_DESIGNATED_DEALER_CODE.to_vec(),
         vec![currency],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -227,14 +1120,7 @@ pub fn encode_create_parent_vasp_account_script(
     add_all_currencies: bool,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 17, 7, 27, 40, 8,
-            67, 16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 1, 0, 1,
-            9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 26, 99, 114, 101, 97,
-            116, 101, 95, 112, 97, 114, 101, 110, 116, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111,
-            117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 9, 11, 0,
-            10, 1, 11, 2, 11, 3, 11, 4, 11, 5, 10, 6, 56, 0, 2,
-        ],
+        CREATE_PARENT_VASP_ACCOUNT_CODE.to_vec(),
         vec![coin_type],
         vec![
             TransactionArgument::Address(new_account_address),
@@ -252,16 +1138,7 @@ pub fn encode_create_parent_vasp_account_script(
 /// delegated its `KeyRotationCapability`, already has a `RecoveryAddress` resource, or is
 /// not a VASP.
 pub fn encode_create_recovery_address_script() -> Script {
-    Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 24, 8, 35, 16, 0, 0,
-            0, 1, 0, 1, 0, 1, 6, 12, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100,
-            114, 101, 115, 115, 7, 112, 117, 98, 108, 105, 115, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 1, 0, 0, 1, 3, 11, 0, 17, 0, 2,
-        ],
-        vec![],
-        vec![],
-    )
+    Script::new(CREATE_RECOVERY_ADDRESS_CODE.to_vec(), vec![], vec![])
 }
 
 /// Create an account with the ParentVASP role at `address` with authentication key
@@ -277,18 +1154,7 @@ pub fn encode_create_testing_account_script(
     add_all_currencies: bool,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 24, 7, 34, 40, 8,
-            74, 16, 6, 90, 68, 0, 0, 0, 1, 0, 1, 1, 1, 0, 3, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10,
-            2, 1, 0, 4, 6, 12, 5, 10, 2, 1, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111,
-            117, 110, 116, 26, 99, 114, 101, 97, 116, 101, 95, 112, 97, 114, 101, 110, 116, 95,
-            118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 1, 10, 2, 8, 7, 116, 101, 115, 116, 110, 101, 116, 10, 2, 18, 17, 104, 116,
-            116, 112, 115, 58, 47, 47, 108, 105, 98, 114, 97, 46, 111, 114, 103, 10, 2, 33, 32,
-            183, 163, 193, 45, 192, 200, 199, 72, 171, 7, 82, 91, 112, 17, 34, 184, 139, 215, 143,
-            96, 12, 118, 52, 45, 39, 242, 94, 95, 146, 68, 76, 222, 1, 1, 2, 1, 9, 11, 0, 10, 1,
-            11, 2, 7, 0, 7, 1, 7, 2, 10, 3, 56, 0, 2,
-        ],
+        CREATE_TESTING_ACCOUNT_CODE.to_vec(),
         vec![coin_type],
         vec![
             TransactionArgument::Address(new_account_address),
@@ -304,13 +1170,7 @@ pub fn encode_create_validator_account_script(
     auth_key_prefix: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 38, 8, 52, 16, 0, 0,
-            0, 1, 0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117,
-            110, 116, 24, 99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111,
-            114, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2, 17, 0, 2,
-        ],
+        CREATE_VALIDATOR_ACCOUNT_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::Address(new_account_address),
@@ -325,13 +1185,7 @@ pub fn encode_create_validator_operator_account_script(
     auth_key_prefix: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 47, 8, 61, 16, 0, 0,
-            0, 1, 0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117,
-            110, 116, 33, 99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111,
-            114, 95, 111, 112, 101, 114, 97, 116, 111, 114, 95, 97, 99, 99, 111, 117, 110, 116, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2, 17, 0, 2,
-        ],
+        CREATE_VALIDATOR_OPERATOR_ACCOUNT_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::Address(new_account_address),
@@ -347,15 +1201,7 @@ pub fn encode_freeze_account_script(
     to_freeze_account: AccountAddress,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 63, 8, 91, 16, 0,
-            0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12,
-            76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110,
-            103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99,
-            101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 14, 102, 114, 101, 101, 122, 101, 95, 97,
-            99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 7,
-            10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 17, 1, 2,
-        ],
+        FREEZE_ACCOUNT_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -368,18 +1214,7 @@ pub fn encode_freeze_account_script(
 /// resulting LBR into the sending account.
 pub fn encode_mint_lbr_script(amount_lbr: u64) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 99, 8,
-            136, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1,
-            8, 0, 0, 2, 6, 8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117,
-            110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105,
-            116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97,
-            119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 114, 101, 115, 116, 111,
-            114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108,
-            105, 116, 121, 10, 115, 116, 97, 112, 108, 101, 95, 108, 98, 114, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 10, 1, 17, 2, 11, 2,
-            17, 1, 2,
-        ],
+        MINT_LBR_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U64(amount_lbr)],
     )
@@ -389,13 +1224,7 @@ pub fn encode_mint_lbr_script(amount_lbr: u64) -> Script {
 /// input.
 pub fn encode_modify_publishing_option_script(args: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 36, 8, 49, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 13, 76, 105, 98, 114, 97, 86, 77, 67, 111, 110, 102,
-            105, 103, 21, 115, 101, 116, 95, 112, 117, 98, 108, 105, 115, 104, 105, 110, 103, 95,
-            111, 112, 116, 105, 111, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1,
-            4, 11, 0, 11, 1, 17, 0, 2,
-        ],
+        MODIFY_PUBLISHING_OPTION_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(args)],
     )
@@ -415,19 +1244,7 @@ pub fn encode_peer_to_peer_with_metadata_script(
     metadata_signature: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 29, 7,
-            53, 97, 8, 150, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3,
-            0, 1, 5, 1, 6, 12, 1, 8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 0, 5, 6, 12, 5, 3, 10, 2,
-            10, 2, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105,
-            116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101,
-            120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112,
-            97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101,
-            115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112,
-            97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1,
-            4, 1, 12, 11, 0, 17, 0, 12, 5, 14, 5, 10, 1, 10, 2, 11, 3, 11, 4, 56, 0, 11, 5, 17, 2,
-            2,
-        ],
+        PEER_TO_PEER_WITH_METADATA_CODE.to_vec(),
         vec![token],
         vec![
             TransactionArgument::Address(payee),
@@ -442,18 +1259,7 @@ pub fn encode_peer_to_peer_with_metadata_script(
 /// has a published `Preburn<Token>` resource.
 pub fn encode_preburn_script(token: TypeTag, amount: u64) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 21, 7,
-            45, 96, 8, 141, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3,
-            0, 1, 5, 1, 6, 12, 1, 8, 0, 3, 6, 12, 6, 8, 0, 3, 0, 2, 6, 12, 3, 1, 9, 0, 12, 76, 105,
-            98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119,
-            67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95,
-            119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116,
-            121, 7, 112, 114, 101, 98, 117, 114, 110, 27, 114, 101, 115, 116, 111, 114, 101, 95,
-            119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116,
-            121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 10, 10, 0, 17, 0, 12,
-            2, 11, 0, 14, 2, 10, 1, 56, 0, 11, 2, 17, 2, 2,
-        ],
+        PREBURN_CODE.to_vec(),
         vec![token],
         vec![TransactionArgument::U64(amount)],
     )
@@ -465,12 +1271,7 @@ pub fn encode_preburn_script(token: TypeTag, amount: u64) -> Script {
 /// `SharedEd25519PublicKey` resource. Aborts if the length of `new_public_key` is not 32.
 pub fn encode_publish_shared_ed25519_public_key_script(public_key: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 31, 8, 44, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53,
-            49, 57, 80, 117, 98, 108, 105, 99, 75, 101, 121, 7, 112, 117, 98, 108, 105, 115, 104,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
-        ],
+        PUBLISH_SHARED_ED25519_PUBLIC_KEY_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(public_key)],
     )
@@ -478,17 +1279,7 @@ pub fn encode_publish_shared_ed25519_public_key_script(public_key: Vec<u8>) -> S
 
 /// Update configs of all the validators and emit reconfiguration event.
 pub fn encode_reconfigure_script() -> Script {
-    Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 35, 8, 46, 16, 0, 0,
-            0, 1, 0, 1, 0, 1, 6, 12, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 22,
-            117, 112, 100, 97, 116, 101, 95, 97, 110, 100, 95, 114, 101, 99, 111, 110, 102, 105,
-            103, 117, 114, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 3, 11, 0,
-            17, 0, 2,
-        ],
-        vec![],
-        vec![],
-    )
+    Script::new(RECONFIGURE_CODE.to_vec(), vec![], vec![])
 }
 
 /// Adding `to_remove` to the set of pending validator removals. Fails if the `to_remove`
@@ -496,12 +1287,7 @@ pub fn encode_reconfigure_script() -> Script {
 /// by Validator's operator.
 pub fn encode_remove_validator_script(validator_address: AccountAddress) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109,
-            16, 114, 101, 109, 111, 118, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
-        ],
+        REMOVE_VALIDATOR_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::Address(validator_address)],
     )
@@ -511,19 +1297,7 @@ pub fn encode_remove_validator_script(validator_address: AccountAddress) -> Scri
 /// sha3 hash of an ed25519 public key.
 pub fn encode_rotate_authentication_key_script(new_key: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 18, 7, 39, 125, 8,
-            164, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1,
-            8, 0, 0, 2, 6, 8, 0, 10, 2, 2, 6, 12, 10, 2, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111,
-            117, 110, 116, 21, 75, 101, 121, 82, 111, 116, 97, 116, 105, 111, 110, 67, 97, 112, 97,
-            98, 105, 108, 105, 116, 121, 31, 101, 120, 116, 114, 97, 99, 116, 95, 107, 101, 121,
-            95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116,
-            121, 31, 114, 101, 115, 116, 111, 114, 101, 95, 107, 101, 121, 95, 114, 111, 116, 97,
-            116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 114, 111,
-            116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111,
-            110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11,
-            0, 17, 0, 12, 2, 14, 2, 11, 1, 17, 2, 11, 2, 17, 1, 2,
-        ],
+        ROTATE_AUTHENTICATION_KEY_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(new_key)],
     )
@@ -537,22 +1311,7 @@ pub fn encode_rotate_authentication_key_with_nonce_script(
     new_key: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 2, 4, 4, 3, 8, 20, 5, 28, 23, 7, 51, 160, 1,
-            8, 211, 1, 16, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 2, 3, 0, 0, 5, 3, 1, 0, 0,
-            6, 4, 1, 0, 2, 6, 12, 3, 0, 1, 6, 12, 1, 8, 0, 2, 6, 8, 0, 10, 2, 3, 6, 12, 3, 10, 2,
-            12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105,
-            110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110,
-            99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 21, 75, 101, 121, 82, 111, 116, 97,
-            116, 105, 111, 110, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 101, 120, 116,
-            114, 97, 99, 116, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99,
-            97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 114, 101, 115, 116, 111, 114, 101, 95,
-            107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105,
-            108, 105, 116, 121, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110,
-            116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 1, 0, 5, 3, 12, 10, 0, 10, 1, 17, 0, 11, 0, 17, 1, 12, 3, 14, 3, 11, 2,
-            17, 3, 11, 3, 17, 2, 2,
-        ],
+        ROTATE_AUTHENTICATION_KEY_WITH_NONCE_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -570,13 +1329,7 @@ pub fn encode_rotate_authentication_key_with_recovery_address_script(
     new_key: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 8, 7, 15, 42, 8, 57, 16, 0, 0,
-            0, 1, 0, 1, 0, 4, 6, 12, 5, 5, 10, 2, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65,
-            100, 100, 114, 101, 115, 115, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104,
-            101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 6, 11, 0, 10, 1, 10, 2, 11, 3, 17, 0, 2,
-        ],
+        ROTATE_AUTHENTICATION_KEY_WITH_RECOVERY_ADDRESS_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::Address(recovery_address),
@@ -589,12 +1342,7 @@ pub fn encode_rotate_authentication_key_with_recovery_address_script(
 /// Rotate `vasp_root_addr`'s base URL to `new_url`.
 pub fn encode_rotate_base_url_script(new_url: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 21, 8, 34, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 15, 114, 111, 116, 97, 116, 101,
-            95, 98, 97, 115, 101, 95, 117, 114, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
-        ],
+        ROTATE_BASE_URL_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(new_url)],
     )
@@ -603,13 +1351,7 @@ pub fn encode_rotate_base_url_script(new_url: Vec<u8>) -> Script {
 /// Encode a program that rotates `vasp_root_addr`'s compliance public key to `new_key`.
 pub fn encode_rotate_compliance_public_key_script(new_key: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 28, 114, 111, 116, 97, 116, 101,
-            95, 99, 111, 109, 112, 108, 105, 97, 110, 99, 101, 95, 112, 117, 98, 108, 105, 99, 95,
-            107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11,
-            1, 17, 0, 2,
-        ],
+        ROTATE_COMPLIANCE_PUBLIC_KEY_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(new_key)],
     )
@@ -622,13 +1364,7 @@ pub fn encode_rotate_compliance_public_key_script(new_key: Vec<u8>) -> Script {
 /// length of `new_public_key` is not 32.
 pub fn encode_rotate_shared_ed25519_public_key_script(public_key: Vec<u8>) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53,
-            49, 57, 80, 117, 98, 108, 105, 99, 75, 101, 121, 10, 114, 111, 116, 97, 116, 101, 95,
-            107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11,
-            1, 17, 0, 2,
-        ],
+        ROTATE_SHARED_ED25519_PUBLIC_KEY_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U8Vector(public_key)],
     )
@@ -642,13 +1378,7 @@ pub fn encode_set_account_limit_window_current_holdings_script(
     aggregate_balance: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 35, 8, 54,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 3, 6, 12, 5, 3, 0, 1, 9, 0, 13, 65, 99, 99, 111, 117,
-            110, 116, 76, 105, 109, 105, 116, 115, 20, 115, 101, 116, 95, 99, 117, 114, 114, 101,
-            110, 116, 95, 104, 111, 108, 100, 105, 110, 103, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 1, 1, 1, 0, 1, 5, 11, 0, 10, 1, 10, 2, 56, 0, 2,
-        ],
+        SET_ACCOUNT_LIMIT_WINDOW_CURRENT_HOLDINGS_CODE.to_vec(),
         vec![coint_type],
         vec![
             TransactionArgument::Address(window_address),
@@ -667,13 +1397,7 @@ pub fn encode_set_validator_config_script(
     fullnodes_network_address: Vec<u8>,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 15, 7, 22, 27, 8, 49, 16, 0,
-            0, 0, 1, 0, 1, 0, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 10, 2, 0, 15, 86, 97, 108,
-            105, 100, 97, 116, 111, 114, 67, 111, 110, 102, 105, 103, 10, 115, 101, 116, 95, 99,
-            111, 110, 102, 105, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 9,
-            11, 0, 10, 1, 11, 2, 11, 3, 11, 4, 11, 5, 11, 6, 17, 0, 2,
-        ],
+        SET_VALIDATOR_CONFIG_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::Address(validator_account),
@@ -689,12 +1413,7 @@ pub fn encode_set_validator_config_script(
 /// Set validator's operator
 pub fn encode_set_validator_operator_script(operator_account: AccountAddress) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 5, 0, 15, 86, 97, 108, 105, 100, 97, 116, 111, 114, 67, 111,
-            110, 102, 105, 103, 12, 115, 101, 116, 95, 111, 112, 101, 114, 97, 116, 111, 114, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
-        ],
+        SET_VALIDATOR_OPERATOR_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::Address(operator_account)],
     )
@@ -703,25 +1422,7 @@ pub fn encode_set_validator_operator_script(operator_account: AccountAddress) ->
 /// Send `amount` coins of type `Token` to `payee`.
 pub fn encode_testnet_mint_script(token: TypeTag, payee: AccountAddress, amount: u64) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 8, 1, 0, 4, 2, 4, 4, 3, 8, 26, 4, 34, 2, 5, 36, 38, 7,
-            74, 125, 8, 199, 1, 16, 6, 215, 1, 22, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 1,
-            2, 0, 0, 5, 0, 3, 0, 0, 6, 4, 5, 1, 1, 0, 7, 3, 5, 0, 3, 8, 1, 6, 12, 1, 5, 1, 1, 1, 8,
-            0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 0, 3, 6, 12, 5, 3, 7, 8, 0, 1, 3, 1, 3, 1, 3, 1, 9,
-            0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 6, 83, 105, 103, 110, 101,
-            114, 10, 97, 100, 100, 114, 101, 115, 115, 95, 111, 102, 18, 87, 105, 116, 104, 100,
-            114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 9, 101, 120, 105, 115, 116,
-            115, 95, 97, 116, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100,
-            114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95,
-            102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100,
-            114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 1, 5, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 221, 10, 2,
-            1, 0, 1, 1, 6, 7, 42, 10, 1, 17, 1, 12, 4, 11, 4, 3, 9, 11, 0, 1, 6, 203, 21, 122, 0,
-            0, 0, 0, 0, 39, 10, 0, 17, 0, 7, 0, 33, 12, 6, 11, 6, 3, 20, 11, 0, 1, 6, 204, 21, 122,
-            0, 0, 0, 0, 0, 39, 10, 2, 6, 0, 128, 198, 164, 126, 141, 3, 0, 37, 12, 8, 11, 8, 3, 30,
-            11, 0, 1, 6, 205, 21, 122, 0, 0, 0, 0, 0, 39, 11, 0, 17, 2, 12, 3, 14, 3, 10, 1, 10, 2,
-            7, 1, 7, 1, 56, 0, 11, 3, 17, 4, 2,
-        ],
+        TESTNET_MINT_CODE.to_vec(),
         vec![token],
         vec![
             TransactionArgument::Address(payee),
@@ -742,16 +1443,7 @@ pub fn encode_tiered_mint_script(
     tier_index: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 21, 7, 38, 60, 8,
-            98, 16, 0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 4, 6, 12, 5,
-            3, 3, 5, 6, 12, 3, 5, 3, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117,
-            110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101,
-            99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116,
-            11, 116, 105, 101, 114, 101, 100, 95, 109, 105, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 9, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 10, 3, 10, 4, 56,
-            0, 2,
-        ],
+        TIERED_MINT_CODE.to_vec(),
         vec![coin_type],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -769,15 +1461,7 @@ pub fn encode_unfreeze_account_script(
     to_unfreeze_account: AccountAddress,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 65, 8, 93, 16, 0,
-            0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12,
-            76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110,
-            103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99,
-            101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 16, 117, 110, 102, 114, 101, 101, 122,
-            101, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            1, 0, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 17, 1, 2,
-        ],
+        UNFREEZE_ACCOUNT_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -790,18 +1474,7 @@ pub fn encode_unfreeze_account_script(
 /// deposits the resulting coins into the sending account."
 pub fn encode_unmint_lbr_script(amount_lbr: u64) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 101, 8,
-            138, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1,
-            8, 0, 0, 2, 6, 8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117,
-            110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105,
-            116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97,
-            119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 114, 101, 115, 116, 111,
-            114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108,
-            105, 116, 121, 12, 117, 110, 115, 116, 97, 112, 108, 101, 95, 108, 98, 114, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 10, 1, 17,
-            2, 11, 2, 17, 1, 2,
-        ],
+        UNMINT_LBR_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U64(amount_lbr)],
     )
@@ -821,17 +1494,7 @@ pub fn encode_update_account_limit_definition_script(
     new_max_holding_balance: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 23, 7, 40, 74, 8,
-            114, 16, 0, 0, 0, 1, 0, 2, 0, 1, 1, 1, 1, 3, 2, 1, 0, 0, 4, 5, 6, 12, 5, 3, 3, 3, 0, 2,
-            6, 12, 3, 6, 6, 12, 5, 3, 3, 3, 3, 1, 9, 0, 13, 65, 99, 99, 111, 117, 110, 116, 76,
-            105, 109, 105, 116, 115, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101,
-            24, 117, 112, 100, 97, 116, 101, 95, 108, 105, 109, 105, 116, 115, 95, 100, 101, 102,
-            105, 110, 105, 116, 105, 111, 110, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110,
-            99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 1, 1, 1, 3, 1, 10, 10, 0, 10, 2, 17, 1, 11, 0, 10, 1, 10, 3, 10, 4, 10, 5, 56,
-            0, 2,
-        ],
+        UPDATE_ACCOUNT_LIMIT_DEFINITION_CODE.to_vec(),
         vec![coin_type],
         vec![
             TransactionArgument::Address(limit_address),
@@ -852,19 +1515,7 @@ pub fn encode_update_exchange_rate_script(
     new_exchange_rate_denominator: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 6, 2, 6, 4, 3, 10, 16, 4, 26, 2, 5, 28, 25, 7,
-            53, 100, 8, 153, 1, 16, 0, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 3, 0, 1, 0, 2, 4, 2, 3, 0, 1,
-            5, 4, 3, 1, 1, 2, 6, 2, 3, 3, 1, 8, 0, 2, 6, 12, 3, 0, 2, 6, 12, 8, 0, 4, 6, 12, 3, 3,
-            3, 1, 9, 0, 12, 70, 105, 120, 101, 100, 80, 111, 105, 110, 116, 51, 50, 5, 76, 105, 98,
-            114, 97, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 20, 99, 114, 101,
-            97, 116, 101, 95, 102, 114, 111, 109, 95, 114, 97, 116, 105, 111, 110, 97, 108, 21,
-            114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111,
-            114, 116, 24, 117, 112, 100, 97, 116, 101, 95, 108, 98, 114, 95, 101, 120, 99, 104, 97,
-            110, 103, 101, 95, 114, 97, 116, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
-            1, 1, 5, 1, 11, 10, 0, 10, 1, 17, 1, 10, 2, 10, 3, 17, 0, 12, 4, 11, 0, 11, 4, 56, 0,
-            2,
-        ],
+        UPDATE_EXCHANGE_RATE_CODE.to_vec(),
         vec![currency],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -877,12 +1528,7 @@ pub fn encode_update_exchange_rate_script(
 /// Update Libra version.
 pub fn encode_update_libra_version_script(major: u64) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 17, 8, 29, 16, 0, 0,
-            0, 1, 0, 1, 0, 2, 6, 12, 3, 0, 12, 76, 105, 98, 114, 97, 86, 101, 114, 115, 105, 111,
-            110, 3, 115, 101, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11,
-            0, 10, 1, 17, 0, 2,
-        ],
+        UPDATE_LIBRA_VERSION_CODE.to_vec(),
         vec![],
         vec![TransactionArgument::U64(major)],
     )
@@ -891,13 +1537,7 @@ pub fn encode_update_libra_version_script(major: u64) -> Script {
 /// Allows--true--or disallows--false--minting of `currency` based upon `allow_minting`.
 pub fn encode_update_minting_ability_script(currency: TypeTag, allow_minting: bool) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 29, 8, 47,
-            16, 0, 0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 1, 0, 1, 9, 0, 5, 76, 105, 98, 114, 97, 22,
-            117, 112, 100, 97, 116, 101, 95, 109, 105, 110, 116, 105, 110, 103, 95, 97, 98, 105,
-            108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11,
-            0, 10, 1, 56, 0, 2,
-        ],
+        UPDATE_MINTING_ABILITY_CODE.to_vec(),
         vec![currency],
         vec![TransactionArgument::Bool(allow_minting)],
     )
@@ -909,16 +1549,7 @@ pub fn encode_update_travel_rule_limit_script(
     new_micro_lbr_limit: u64,
 ) -> Script {
     Script::new(
-        vec![
-            161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 10, 7, 24, 77, 8, 101, 16,
-            0, 0, 0, 1, 0, 2, 0, 1, 0, 1, 3, 0, 1, 0, 2, 6, 12, 3, 0, 3, 6, 12, 3, 3, 20, 68, 117,
-            97, 108, 65, 116, 116, 101, 115, 116, 97, 116, 105, 111, 110, 76, 105, 109, 105, 116,
-            12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 20, 115, 101, 116, 95,
-            109, 105, 99, 114, 111, 108, 105, 98, 114, 97, 95, 108, 105, 109, 105, 116, 21, 114,
-            101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114,
-            116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 1, 7, 10, 0, 10, 1, 17, 1,
-            11, 0, 10, 2, 17, 0, 2,
-        ],
+        UPDATE_TRAVEL_RULE_LIMIT_CODE.to_vec(),
         vec![],
         vec![
             TransactionArgument::U64(sliding_nonce),
@@ -926,3 +1557,988 @@ pub fn encode_update_travel_rule_limit_script(
         ],
     )
 }
+
+fn decode_add_currency_to_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([currency], []) => Some(ScriptCall::AddCurrencyToAccount {
+            currency: currency.clone(),
+        }),
+        _ => None,
+    }
+}
+
+fn decode_add_recovery_rotation_capability_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [recovery_address]) => Some(ScriptCall::AddRecoveryRotationCapability {
+            recovery_address: decode_address_argument(recovery_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_add_validator_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [validator_address]) => Some(ScriptCall::AddValidator {
+            validator_address: decode_address_argument(validator_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_allow_currency_for_vasp_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([coin_type], []) => Some(ScriptCall::AllowCurrencyForVasp {
+            coin_type: coin_type.clone(),
+        }),
+        _ => None,
+    }
+}
+
+fn decode_burn_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([token], [sliding_nonce, preburn_address]) => Some(ScriptCall::Burn {
+            token: token.clone(),
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            preburn_address: decode_address_argument(preburn_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_burn_txn_fees_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([coin_type], []) => Some(ScriptCall::BurnTxnFees {
+            coin_type: coin_type.clone(),
+        }),
+        _ => None,
+    }
+}
+
+fn decode_cancel_burn_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([token], [preburn_address]) => Some(ScriptCall::CancelBurn {
+            token: token.clone(),
+            preburn_address: decode_address_argument(preburn_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_create_child_vasp_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        (
+            [coin_type],
+            [child_address, auth_key_prefix, add_all_currencies, child_initial_balance],
+        ) => Some(ScriptCall::CreateChildVaspAccount {
+            coin_type: coin_type.clone(),
+            child_address: decode_address_argument(child_address)?,
+            auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+            add_all_currencies: decode_bool_argument(add_all_currencies)?,
+            child_initial_balance: decode_u64_argument(child_initial_balance)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_create_designated_dealer_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([currency], [sliding_nonce, addr, auth_key_prefix, add_all_currencies]) => {
+            Some(ScriptCall::CreateDesignatedDealer {
+                currency: currency.clone(),
+                sliding_nonce: decode_u64_argument(sliding_nonce)?,
+                addr: decode_address_argument(addr)?,
+                auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+                add_all_currencies: decode_bool_argument(add_all_currencies)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_create_parent_vasp_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        (
+            [coin_type],
+            [new_account_address, auth_key_prefix, human_name, base_url, compliance_public_key, add_all_currencies],
+        ) => Some(ScriptCall::CreateParentVaspAccount {
+            coin_type: coin_type.clone(),
+            new_account_address: decode_address_argument(new_account_address)?,
+            auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+            human_name: decode_u8vector_argument(human_name)?,
+            base_url: decode_u8vector_argument(base_url)?,
+            compliance_public_key: decode_u8vector_argument(compliance_public_key)?,
+            add_all_currencies: decode_bool_argument(add_all_currencies)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_create_recovery_address_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], []) => Some(ScriptCall::CreateRecoveryAddress {}),
+        _ => None,
+    }
+}
+
+fn decode_create_testing_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([coin_type], [new_account_address, auth_key_prefix, add_all_currencies]) => {
+            Some(ScriptCall::CreateTestingAccount {
+                coin_type: coin_type.clone(),
+                new_account_address: decode_address_argument(new_account_address)?,
+                auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+                add_all_currencies: decode_bool_argument(add_all_currencies)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_create_validator_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [new_account_address, auth_key_prefix]) => Some(ScriptCall::CreateValidatorAccount {
+            new_account_address: decode_address_argument(new_account_address)?,
+            auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_create_validator_operator_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [new_account_address, auth_key_prefix]) => {
+            Some(ScriptCall::CreateValidatorOperatorAccount {
+                new_account_address: decode_address_argument(new_account_address)?,
+                auth_key_prefix: decode_u8vector_argument(auth_key_prefix)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_freeze_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [sliding_nonce, to_freeze_account]) => Some(ScriptCall::FreezeAccount {
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            to_freeze_account: decode_address_argument(to_freeze_account)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_mint_lbr_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [amount_lbr]) => Some(ScriptCall::MintLbr {
+            amount_lbr: decode_u64_argument(amount_lbr)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_modify_publishing_option_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [args]) => Some(ScriptCall::ModifyPublishingOption {
+            args: decode_u8vector_argument(args)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_peer_to_peer_with_metadata_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([token], [payee, amount, metadata, metadata_signature]) => {
+            Some(ScriptCall::PeerToPeerWithMetadata {
+                token: token.clone(),
+                payee: decode_address_argument(payee)?,
+                amount: decode_u64_argument(amount)?,
+                metadata: decode_u8vector_argument(metadata)?,
+                metadata_signature: decode_u8vector_argument(metadata_signature)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_preburn_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([token], [amount]) => Some(ScriptCall::Preburn {
+            token: token.clone(),
+            amount: decode_u64_argument(amount)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_publish_shared_ed25519_public_key_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [public_key]) => Some(ScriptCall::PublishSharedEd25519PublicKey {
+            public_key: decode_u8vector_argument(public_key)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_reconfigure_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], []) => Some(ScriptCall::Reconfigure {}),
+        _ => None,
+    }
+}
+
+fn decode_remove_validator_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [validator_address]) => Some(ScriptCall::RemoveValidator {
+            validator_address: decode_address_argument(validator_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_rotate_authentication_key_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [new_key]) => Some(ScriptCall::RotateAuthenticationKey {
+            new_key: decode_u8vector_argument(new_key)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_rotate_authentication_key_with_nonce_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [sliding_nonce, new_key]) => Some(ScriptCall::RotateAuthenticationKeyWithNonce {
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            new_key: decode_u8vector_argument(new_key)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_rotate_authentication_key_with_recovery_address_script(
+    script: &Script,
+) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [recovery_address, to_recover, new_key]) => {
+            Some(ScriptCall::RotateAuthenticationKeyWithRecoveryAddress {
+                recovery_address: decode_address_argument(recovery_address)?,
+                to_recover: decode_address_argument(to_recover)?,
+                new_key: decode_u8vector_argument(new_key)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_rotate_base_url_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [new_url]) => Some(ScriptCall::RotateBaseUrl {
+            new_url: decode_u8vector_argument(new_url)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_rotate_compliance_public_key_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [new_key]) => Some(ScriptCall::RotateCompliancePublicKey {
+            new_key: decode_u8vector_argument(new_key)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_rotate_shared_ed25519_public_key_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [public_key]) => Some(ScriptCall::RotateSharedEd25519PublicKey {
+            public_key: decode_u8vector_argument(public_key)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_set_account_limit_window_current_holdings_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([coint_type], [window_address, aggregate_balance]) => {
+            Some(ScriptCall::SetAccountLimitWindowCurrentHoldings {
+                coint_type: coint_type.clone(),
+                window_address: decode_address_argument(window_address)?,
+                aggregate_balance: decode_u64_argument(aggregate_balance)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_set_validator_config_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        (
+            [],
+            [validator_account, consensus_pubkey, validator_network_identity_pubkey, validator_network_address, fullnodes_network_identity_pubkey, fullnodes_network_address],
+        ) => Some(ScriptCall::SetValidatorConfig {
+            validator_account: decode_address_argument(validator_account)?,
+            consensus_pubkey: decode_u8vector_argument(consensus_pubkey)?,
+            validator_network_identity_pubkey: decode_u8vector_argument(
+                validator_network_identity_pubkey,
+            )?,
+            validator_network_address: decode_u8vector_argument(validator_network_address)?,
+            fullnodes_network_identity_pubkey: decode_u8vector_argument(
+                fullnodes_network_identity_pubkey,
+            )?,
+            fullnodes_network_address: decode_u8vector_argument(fullnodes_network_address)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_set_validator_operator_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [operator_account]) => Some(ScriptCall::SetValidatorOperator {
+            operator_account: decode_address_argument(operator_account)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_testnet_mint_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([token], [payee, amount]) => Some(ScriptCall::TestnetMint {
+            token: token.clone(),
+            payee: decode_address_argument(payee)?,
+            amount: decode_u64_argument(amount)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_tiered_mint_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([coin_type], [sliding_nonce, designated_dealer_address, mint_amount, tier_index]) => {
+            Some(ScriptCall::TieredMint {
+                coin_type: coin_type.clone(),
+                sliding_nonce: decode_u64_argument(sliding_nonce)?,
+                designated_dealer_address: decode_address_argument(designated_dealer_address)?,
+                mint_amount: decode_u64_argument(mint_amount)?,
+                tier_index: decode_u64_argument(tier_index)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_unfreeze_account_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [sliding_nonce, to_unfreeze_account]) => Some(ScriptCall::UnfreezeAccount {
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            to_unfreeze_account: decode_address_argument(to_unfreeze_account)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_unmint_lbr_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [amount_lbr]) => Some(ScriptCall::UnmintLbr {
+            amount_lbr: decode_u64_argument(amount_lbr)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_update_account_limit_definition_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        (
+            [coin_type],
+            [limit_address, sliding_nonce, new_max_inflow, new_max_outflow, new_max_holding_balance],
+        ) => Some(ScriptCall::UpdateAccountLimitDefinition {
+            coin_type: coin_type.clone(),
+            limit_address: decode_address_argument(limit_address)?,
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            new_max_inflow: decode_u64_argument(new_max_inflow)?,
+            new_max_outflow: decode_u64_argument(new_max_outflow)?,
+            new_max_holding_balance: decode_u64_argument(new_max_holding_balance)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_update_exchange_rate_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        (
+            [currency],
+            [sliding_nonce, new_exchange_rate_numerator, new_exchange_rate_denominator],
+        ) => Some(ScriptCall::UpdateExchangeRate {
+            currency: currency.clone(),
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            new_exchange_rate_numerator: decode_u64_argument(new_exchange_rate_numerator)?,
+            new_exchange_rate_denominator: decode_u64_argument(new_exchange_rate_denominator)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_update_libra_version_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [major]) => Some(ScriptCall::UpdateLibraVersion {
+            major: decode_u64_argument(major)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_update_minting_ability_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([currency], [allow_minting]) => Some(ScriptCall::UpdateMintingAbility {
+            currency: currency.clone(),
+            allow_minting: decode_bool_argument(allow_minting)?,
+        }),
+        _ => None,
+    }
+}
+
+fn decode_update_travel_rule_limit_script(script: &Script) -> Option<ScriptCall> {
+    match (script.ty_args(), script.args()) {
+        ([], [sliding_nonce, new_micro_lbr_limit]) => Some(ScriptCall::UpdateTravelRuleLimit {
+            sliding_nonce: decode_u64_argument(sliding_nonce)?,
+            new_micro_lbr_limit: decode_u64_argument(new_micro_lbr_limit)?,
+        }),
+        _ => None,
+    }
+}
+
+type DecoderMap = HashMap<Vec<u8>, fn(&Script) -> Option<ScriptCall>>;
+
+static SCRIPT_DECODER_MAP: Lazy<DecoderMap> = Lazy::new(|| {
+    let mut map: DecoderMap = HashMap::new();
+    map.insert(
+        ADD_CURRENCY_TO_ACCOUNT_CODE.to_vec(),
+        decode_add_currency_to_account_script,
+    );
+    map.insert(
+        ADD_RECOVERY_ROTATION_CAPABILITY_CODE.to_vec(),
+        decode_add_recovery_rotation_capability_script,
+    );
+    map.insert(ADD_VALIDATOR_CODE.to_vec(), decode_add_validator_script);
+    map.insert(
+        ALLOW_CURRENCY_FOR_VASP_CODE.to_vec(),
+        decode_allow_currency_for_vasp_script,
+    );
+    map.insert(BURN_CODE.to_vec(), decode_burn_script);
+    map.insert(BURN_TXN_FEES_CODE.to_vec(), decode_burn_txn_fees_script);
+    map.insert(CANCEL_BURN_CODE.to_vec(), decode_cancel_burn_script);
+    map.insert(
+        CREATE_CHILD_VASP_ACCOUNT_CODE.to_vec(),
+        decode_create_child_vasp_account_script,
+    );
+    map.insert(
+        CREATE_DESIGNATED_DEALER_CODE.to_vec(),
+        decode_create_designated_dealer_script,
+    );
+    map.insert(
+        CREATE_PARENT_VASP_ACCOUNT_CODE.to_vec(),
+        decode_create_parent_vasp_account_script,
+    );
+    map.insert(
+        CREATE_RECOVERY_ADDRESS_CODE.to_vec(),
+        decode_create_recovery_address_script,
+    );
+    map.insert(
+        CREATE_TESTING_ACCOUNT_CODE.to_vec(),
+        decode_create_testing_account_script,
+    );
+    map.insert(
+        CREATE_VALIDATOR_ACCOUNT_CODE.to_vec(),
+        decode_create_validator_account_script,
+    );
+    map.insert(
+        CREATE_VALIDATOR_OPERATOR_ACCOUNT_CODE.to_vec(),
+        decode_create_validator_operator_account_script,
+    );
+    map.insert(FREEZE_ACCOUNT_CODE.to_vec(), decode_freeze_account_script);
+    map.insert(MINT_LBR_CODE.to_vec(), decode_mint_lbr_script);
+    map.insert(
+        MODIFY_PUBLISHING_OPTION_CODE.to_vec(),
+        decode_modify_publishing_option_script,
+    );
+    map.insert(
+        PEER_TO_PEER_WITH_METADATA_CODE.to_vec(),
+        decode_peer_to_peer_with_metadata_script,
+    );
+    map.insert(PREBURN_CODE.to_vec(), decode_preburn_script);
+    map.insert(
+        PUBLISH_SHARED_ED25519_PUBLIC_KEY_CODE.to_vec(),
+        decode_publish_shared_ed25519_public_key_script,
+    );
+    map.insert(RECONFIGURE_CODE.to_vec(), decode_reconfigure_script);
+    map.insert(
+        REMOVE_VALIDATOR_CODE.to_vec(),
+        decode_remove_validator_script,
+    );
+    map.insert(
+        ROTATE_AUTHENTICATION_KEY_CODE.to_vec(),
+        decode_rotate_authentication_key_script,
+    );
+    map.insert(
+        ROTATE_AUTHENTICATION_KEY_WITH_NONCE_CODE.to_vec(),
+        decode_rotate_authentication_key_with_nonce_script,
+    );
+    map.insert(
+        ROTATE_AUTHENTICATION_KEY_WITH_RECOVERY_ADDRESS_CODE.to_vec(),
+        decode_rotate_authentication_key_with_recovery_address_script,
+    );
+    map.insert(ROTATE_BASE_URL_CODE.to_vec(), decode_rotate_base_url_script);
+    map.insert(
+        ROTATE_COMPLIANCE_PUBLIC_KEY_CODE.to_vec(),
+        decode_rotate_compliance_public_key_script,
+    );
+    map.insert(
+        ROTATE_SHARED_ED25519_PUBLIC_KEY_CODE.to_vec(),
+        decode_rotate_shared_ed25519_public_key_script,
+    );
+    map.insert(
+        SET_ACCOUNT_LIMIT_WINDOW_CURRENT_HOLDINGS_CODE.to_vec(),
+        decode_set_account_limit_window_current_holdings_script,
+    );
+    map.insert(
+        SET_VALIDATOR_CONFIG_CODE.to_vec(),
+        decode_set_validator_config_script,
+    );
+    map.insert(
+        SET_VALIDATOR_OPERATOR_CODE.to_vec(),
+        decode_set_validator_operator_script,
+    );
+    map.insert(TESTNET_MINT_CODE.to_vec(), decode_testnet_mint_script);
+    map.insert(TIERED_MINT_CODE.to_vec(), decode_tiered_mint_script);
+    map.insert(
+        UNFREEZE_ACCOUNT_CODE.to_vec(),
+        decode_unfreeze_account_script,
+    );
+    map.insert(UNMINT_LBR_CODE.to_vec(), decode_unmint_lbr_script);
+    map.insert(
+        UPDATE_ACCOUNT_LIMIT_DEFINITION_CODE.to_vec(),
+        decode_update_account_limit_definition_script,
+    );
+    map.insert(
+        UPDATE_EXCHANGE_RATE_CODE.to_vec(),
+        decode_update_exchange_rate_script,
+    );
+    map.insert(
+        UPDATE_LIBRA_VERSION_CODE.to_vec(),
+        decode_update_libra_version_script,
+    );
+    map.insert(
+        UPDATE_MINTING_ABILITY_CODE.to_vec(),
+        decode_update_minting_ability_script,
+    );
+    map.insert(
+        UPDATE_TRAVEL_RULE_LIMIT_CODE.to_vec(),
+        decode_update_travel_rule_limit_script,
+    );
+    map
+});
+
+fn decode_address_argument(arg: &TransactionArgument) -> Option<AccountAddress> {
+    match arg {
+        TransactionArgument::Address(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn decode_bool_argument(arg: &TransactionArgument) -> Option<bool> {
+    match arg {
+        TransactionArgument::Bool(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn decode_u64_argument(arg: &TransactionArgument) -> Option<u64> {
+    match arg {
+        TransactionArgument::U64(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn decode_u8vector_argument(arg: &TransactionArgument) -> Option<Vec<u8>> {
+    match arg {
+        TransactionArgument::U8Vector(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+const ADD_CURRENCY_TO_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 26, 8, 43, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111,
+    117, 110, 116, 12, 97, 100, 100, 95, 99, 117, 114, 114, 101, 110, 99, 121, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2,
+];
+
+const ADD_RECOVERY_ROTATION_CAPABILITY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 40, 8, 52, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 5, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114, 101, 115,
+    115, 23, 97, 100, 100, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105,
+    108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1,
+    17, 0, 2,
+];
+
+const ADD_VALIDATOR_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 26, 8, 38, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 13, 97, 100,
+    100, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
+];
+
+const ALLOW_CURRENCY_FOR_VASP_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 24, 8, 43, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 3, 1, 6, 12, 1, 1, 0, 1, 9, 0, 4, 86, 65, 83, 80, 18, 116, 114, 121,
+    95, 97, 108, 108, 111, 119, 95, 99, 117, 114, 114, 101, 110, 99, 121, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 2, 4, 11, 0, 56, 0, 1, 2,
+];
+
+const BURN_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 17, 7, 34, 46, 8, 80, 16,
+    0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5,
+    1, 9, 0, 5, 76, 105, 98, 114, 97, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101,
+    21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111,
+    114, 116, 4, 98, 117, 114, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 7,
+    10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 56, 0, 2,
+];
+
+const BURN_TXN_FEES_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 7, 7, 17, 25, 8, 42, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 1, 6, 12, 0, 1, 9, 0, 14, 84, 114, 97, 110, 115, 97, 99, 116, 105,
+    111, 110, 70, 101, 101, 9, 98, 117, 114, 110, 95, 102, 101, 101, 115, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 3, 11, 0, 56, 0, 2,
+];
+
+const CANCEL_BURN_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 25, 8, 43, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 5, 0, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111,
+    117, 110, 116, 11, 99, 97, 110, 99, 101, 108, 95, 98, 117, 114, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11, 0, 10, 1, 56, 0, 2,
+];
+
+const CREATE_CHILD_VASP_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 8, 1, 0, 2, 2, 2, 4, 3, 6, 22, 4, 28, 4, 5, 32, 35, 7, 67, 123,
+    8, 190, 1, 16, 6, 206, 1, 4, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 1, 1, 0, 3, 2, 3, 0, 0, 4, 4, 1, 1,
+    1, 0, 5, 3, 1, 0, 0, 6, 2, 6, 4, 6, 12, 5, 10, 2, 1, 0, 1, 6, 12, 1, 8, 0, 5, 6, 8, 0, 5, 3,
+    10, 2, 10, 2, 5, 6, 12, 5, 10, 2, 1, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111,
+    117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105,
+    116, 121, 25, 99, 114, 101, 97, 116, 101, 95, 99, 104, 105, 108, 100, 95, 118, 97, 115, 112,
+    95, 97, 99, 99, 111, 117, 110, 116, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116,
+    104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95,
+    102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114,
+    97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 10, 2, 1, 0, 1, 1, 5, 3, 25, 10, 0, 10, 1, 11, 2, 10, 3, 56, 0, 10, 4, 6, 0, 0, 0,
+    0, 0, 0, 0, 0, 36, 3, 10, 5, 22, 11, 0, 17, 1, 12, 5, 14, 5, 10, 1, 10, 4, 7, 0, 7, 0, 56, 1,
+    11, 5, 17, 3, 5, 24, 11, 0, 1, 2,
+];
+
+const CREATE_DESIGNATED_DEALER_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 6, 3, 6, 16, 4, 22, 2, 5, 24, 28, 7, 52, 99, 8, 151, 1,
+    16, 0, 0, 0, 1, 0, 2, 2, 3, 0, 1, 0, 0, 4, 2, 1, 0, 1, 5, 3, 1, 1, 1, 2, 5, 2, 6, 12, 3, 0, 3,
+    6, 12, 5, 3, 4, 6, 12, 5, 10, 2, 1, 5, 6, 12, 3, 5, 10, 2, 1, 1, 9, 0, 16, 68, 101, 115, 105,
+    103, 110, 97, 116, 101, 100, 68, 101, 97, 108, 101, 114, 12, 76, 105, 98, 114, 97, 65, 99, 99,
+    111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101,
+    99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 8, 97,
+    100, 100, 95, 116, 105, 101, 114, 24, 99, 114, 101, 97, 116, 101, 95, 100, 101, 115, 105, 103,
+    110, 97, 116, 101, 100, 95, 100, 101, 97, 108, 101, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1, 1, 1, 4, 1, 25, 10, 0, 10, 1, 17, 0, 10, 0, 10, 2, 11, 3, 10, 4, 56, 0, 10, 0, 10, 2,
+    6, 32, 161, 7, 0, 0, 0, 0, 0, 17, 1, 10, 0, 10, 2, 6, 64, 75, 76, 0, 0, 0, 0, 0, 17, 1, 10, 0,
+    10, 2, 6, 128, 240, 250, 2, 0, 0, 0, 0, 17, 1, 11, 0, 10, 2, 6, 0, 101, 205, 29, 0, 0, 0, 0,
+    17, 1, 2,
+];
+
+const CREATE_PARENT_VASP_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 17, 7, 27, 40, 8, 67, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 1, 0, 1, 9, 0, 12, 76, 105,
+    98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 26, 99, 114, 101, 97, 116, 101, 95, 112, 97, 114,
+    101, 110, 116, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 9, 11, 0, 10, 1, 11, 2, 11, 3, 11, 4, 11, 5, 10, 6, 56,
+    0, 2,
+];
+
+const CREATE_RECOVERY_ADDRESS_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 24, 8, 35, 16, 0, 0, 0, 1,
+    0, 1, 0, 1, 6, 12, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114, 101, 115,
+    115, 7, 112, 117, 98, 108, 105, 115, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+    1, 3, 11, 0, 17, 0, 2,
+];
+
+const CREATE_TESTING_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 24, 7, 34, 40, 8, 74, 16, 6,
+    90, 68, 0, 0, 0, 1, 0, 1, 1, 1, 0, 3, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 1, 0, 4, 6, 12,
+    5, 10, 2, 1, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 26, 99, 114,
+    101, 97, 116, 101, 95, 112, 97, 114, 101, 110, 116, 95, 118, 97, 115, 112, 95, 97, 99, 99, 111,
+    117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 10, 2, 8, 7, 116, 101, 115, 116,
+    110, 101, 116, 10, 2, 18, 17, 104, 116, 116, 112, 115, 58, 47, 47, 108, 105, 98, 114, 97, 46,
+    111, 114, 103, 10, 2, 33, 32, 183, 163, 193, 45, 192, 200, 199, 72, 171, 7, 82, 91, 112, 17,
+    34, 184, 139, 215, 143, 96, 12, 118, 52, 45, 39, 242, 94, 95, 146, 68, 76, 222, 1, 1, 2, 1, 9,
+    11, 0, 10, 1, 11, 2, 7, 0, 7, 1, 7, 2, 10, 3, 56, 0, 2,
+];
+
+const CREATE_VALIDATOR_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 38, 8, 52, 16, 0, 0, 0, 1,
+    0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 24,
+    99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 97, 99, 99, 111,
+    117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2,
+    17, 0, 2,
+];
+
+const CREATE_VALIDATOR_OPERATOR_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 7, 7, 14, 47, 8, 61, 16, 0, 0, 0, 1,
+    0, 1, 0, 3, 6, 12, 5, 10, 2, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 33,
+    99, 114, 101, 97, 116, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 95, 111, 112, 101,
+    114, 97, 116, 111, 114, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 1, 5, 11, 0, 10, 1, 11, 2, 17, 0, 2,
+];
+
+const FREEZE_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 63, 8, 91, 16, 0, 0, 0,
+    1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12, 76, 105, 98,
+    114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110,
+    99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98,
+    111, 114, 116, 14, 102, 114, 101, 101, 122, 101, 95, 97, 99, 99, 111, 117, 110, 116, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2, 17, 1, 2,
+];
+
+const MINT_LBR_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 99, 8, 136, 1,
+    16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6,
+    8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105,
+    116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116,
+    114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108,
+    105, 116, 121, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97,
+    119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 10, 115, 116, 97, 112, 108, 101, 95,
+    108, 98, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17, 0, 12, 2,
+    14, 2, 10, 1, 17, 2, 11, 2, 17, 1, 2,
+];
+
+const MODIFY_PUBLISHING_OPTION_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 36, 8, 49, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 10, 2, 0, 13, 76, 105, 98, 114, 97, 86, 77, 67, 111, 110, 102, 105, 103, 21,
+    115, 101, 116, 95, 112, 117, 98, 108, 105, 115, 104, 105, 110, 103, 95, 111, 112, 116, 105,
+    111, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
+];
+
+const PEER_TO_PEER_WITH_METADATA_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 29, 7, 53, 97, 8,
+    150, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3, 0, 1, 5, 1, 6, 12,
+    1, 8, 0, 5, 6, 8, 0, 5, 3, 10, 2, 10, 2, 0, 5, 6, 12, 5, 3, 10, 2, 10, 2, 1, 9, 0, 12, 76, 105,
+    98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97,
+    112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116,
+    104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 8, 112, 97, 121, 95,
+    102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114,
+    97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 1, 1, 4, 1, 12, 11, 0, 17, 0, 12, 5, 14, 5, 10, 1, 10, 2, 11, 3, 11, 4, 56, 0, 11,
+    5, 17, 2, 2,
+];
+
+const PREBURN_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 2, 2, 2, 4, 3, 6, 16, 4, 22, 2, 5, 24, 21, 7, 45, 96, 8,
+    141, 1, 16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 2, 3, 1, 1, 0, 4, 1, 3, 0, 1, 5, 1, 6, 12,
+    1, 8, 0, 3, 6, 12, 6, 8, 0, 3, 0, 2, 6, 12, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99,
+    111, 117, 110, 116, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108,
+    105, 116, 121, 27, 101, 120, 116, 114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119,
+    95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 7, 112, 114, 101, 98, 117, 114, 110, 27, 114,
+    101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97,
+    98, 105, 108, 105, 116, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 4, 1, 10,
+    10, 0, 17, 0, 12, 2, 11, 0, 14, 2, 10, 1, 56, 0, 11, 2, 17, 2, 2,
+];
+
+const PUBLISH_SHARED_ED25519_PUBLIC_KEY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 31, 8, 44, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53, 49, 57, 80,
+    117, 98, 108, 105, 99, 75, 101, 121, 7, 112, 117, 98, 108, 105, 115, 104, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
+];
+
+const RECONFIGURE_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 4, 7, 11, 35, 8, 46, 16, 0, 0, 0, 1,
+    0, 1, 0, 1, 6, 12, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 22, 117, 112, 100,
+    97, 116, 101, 95, 97, 110, 100, 95, 114, 101, 99, 111, 110, 102, 105, 103, 117, 114, 101, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 3, 11, 0, 17, 0, 2,
+];
+
+const REMOVE_VALIDATOR_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 5, 0, 11, 76, 105, 98, 114, 97, 83, 121, 115, 116, 101, 109, 16, 114, 101,
+    109, 111, 118, 101, 95, 118, 97, 108, 105, 100, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
+];
+
+const ROTATE_AUTHENTICATION_KEY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 18, 7, 39, 125, 8, 164, 1,
+    16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6,
+    8, 0, 10, 2, 2, 6, 12, 10, 2, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 21, 75,
+    101, 121, 82, 111, 116, 97, 116, 105, 111, 110, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121,
+    31, 101, 120, 116, 114, 97, 99, 116, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111,
+    110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 114, 101, 115, 116, 111, 114, 101,
+    95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105,
+    108, 105, 116, 121, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105,
+    99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 4, 1, 9, 11, 0, 17, 0, 12, 2, 14, 2, 11, 1, 17, 2, 11, 2, 17, 1, 2,
+];
+
+const ROTATE_AUTHENTICATION_KEY_WITH_NONCE_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 2, 4, 4, 3, 8, 20, 5, 28, 23, 7, 51, 160, 1, 8, 211,
+    1, 16, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 2, 3, 0, 0, 5, 3, 1, 0, 0, 6, 4, 1, 0, 2,
+    6, 12, 3, 0, 1, 6, 12, 1, 8, 0, 2, 6, 8, 0, 10, 2, 3, 6, 12, 3, 10, 2, 12, 76, 105, 98, 114,
+    97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99,
+    101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98,
+    111, 114, 116, 21, 75, 101, 121, 82, 111, 116, 97, 116, 105, 111, 110, 67, 97, 112, 97, 98,
+    105, 108, 105, 116, 121, 31, 101, 120, 116, 114, 97, 99, 116, 95, 107, 101, 121, 95, 114, 111,
+    116, 97, 116, 105, 111, 110, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 31, 114, 101,
+    115, 116, 111, 114, 101, 95, 107, 101, 121, 95, 114, 111, 116, 97, 116, 105, 111, 110, 95, 99,
+    97, 112, 97, 98, 105, 108, 105, 116, 121, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116,
+    104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 0, 5, 3, 12, 10, 0, 10, 1, 17, 0, 11, 0, 17, 1, 12, 3, 14, 3, 11, 2,
+    17, 3, 11, 3, 17, 2, 2,
+];
+
+const ROTATE_AUTHENTICATION_KEY_WITH_RECOVERY_ADDRESS_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 8, 7, 15, 42, 8, 57, 16, 0, 0, 0, 1,
+    0, 1, 0, 4, 6, 12, 5, 5, 10, 2, 0, 15, 82, 101, 99, 111, 118, 101, 114, 121, 65, 100, 100, 114,
+    101, 115, 115, 25, 114, 111, 116, 97, 116, 101, 95, 97, 117, 116, 104, 101, 110, 116, 105, 99,
+    97, 116, 105, 111, 110, 95, 107, 101, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 1, 6, 11, 0, 10, 1, 10, 2, 11, 3, 17, 0, 2,
+];
+
+const ROTATE_BASE_URL_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 21, 8, 34, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 15, 114, 111, 116, 97, 116, 101, 95, 98, 97,
+    115, 101, 95, 117, 114, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0,
+    11, 1, 17, 0, 2,
+];
+
+const ROTATE_COMPLIANCE_PUBLIC_KEY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 10, 2, 0, 4, 86, 65, 83, 80, 28, 114, 111, 116, 97, 116, 101, 95, 99, 111,
+    109, 112, 108, 105, 97, 110, 99, 101, 95, 112, 117, 98, 108, 105, 99, 95, 107, 101, 121, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
+];
+
+const ROTATE_SHARED_ED25519_PUBLIC_KEY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 6, 7, 13, 34, 8, 47, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 10, 2, 0, 22, 83, 104, 97, 114, 101, 100, 69, 100, 50, 53, 53, 49, 57, 80,
+    117, 98, 108, 105, 99, 75, 101, 121, 10, 114, 111, 116, 97, 116, 101, 95, 107, 101, 121, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 11, 1, 17, 0, 2,
+];
+
+const SET_ACCOUNT_LIMIT_WINDOW_CURRENT_HOLDINGS_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 9, 7, 19, 35, 8, 54, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 3, 6, 12, 5, 3, 0, 1, 9, 0, 13, 65, 99, 99, 111, 117, 110, 116, 76,
+    105, 109, 105, 116, 115, 20, 115, 101, 116, 95, 99, 117, 114, 114, 101, 110, 116, 95, 104, 111,
+    108, 100, 105, 110, 103, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 5,
+    11, 0, 10, 1, 10, 2, 56, 0, 2,
+];
+
+const SET_VALIDATOR_CONFIG_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 15, 7, 22, 27, 8, 49, 16, 0, 0, 0, 1,
+    0, 1, 0, 7, 6, 12, 5, 10, 2, 10, 2, 10, 2, 10, 2, 10, 2, 0, 15, 86, 97, 108, 105, 100, 97, 116,
+    111, 114, 67, 111, 110, 102, 105, 103, 10, 115, 101, 116, 95, 99, 111, 110, 102, 105, 103, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 9, 11, 0, 10, 1, 11, 2, 11, 3, 11, 4, 11,
+    5, 11, 6, 17, 0, 2,
+];
+
+const SET_VALIDATOR_OPERATOR_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 29, 8, 41, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 5, 0, 15, 86, 97, 108, 105, 100, 97, 116, 111, 114, 67, 111, 110, 102, 105,
+    103, 12, 115, 101, 116, 95, 111, 112, 101, 114, 97, 116, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
+];
+
+const TESTNET_MINT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 8, 1, 0, 4, 2, 4, 4, 3, 8, 26, 4, 34, 2, 5, 36, 38, 7, 74, 125,
+    8, 199, 1, 16, 6, 215, 1, 22, 0, 0, 0, 1, 0, 3, 1, 0, 1, 2, 0, 1, 0, 0, 4, 1, 2, 0, 0, 5, 0, 3,
+    0, 0, 6, 4, 5, 1, 1, 0, 7, 3, 5, 0, 3, 8, 1, 6, 12, 1, 5, 1, 1, 1, 8, 0, 5, 6, 8, 0, 5, 3, 10,
+    2, 10, 2, 0, 3, 6, 12, 5, 3, 7, 8, 0, 1, 3, 1, 3, 1, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65,
+    99, 99, 111, 117, 110, 116, 6, 83, 105, 103, 110, 101, 114, 10, 97, 100, 100, 114, 101, 115,
+    115, 95, 111, 102, 18, 87, 105, 116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108,
+    105, 116, 121, 9, 101, 120, 105, 115, 116, 115, 95, 97, 116, 27, 101, 120, 116, 114, 97, 99,
+    116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116,
+    121, 8, 112, 97, 121, 95, 102, 114, 111, 109, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119,
+    105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 221,
+    10, 2, 1, 0, 1, 1, 6, 7, 42, 10, 1, 17, 1, 12, 4, 11, 4, 3, 9, 11, 0, 1, 6, 203, 21, 122, 0, 0,
+    0, 0, 0, 39, 10, 0, 17, 0, 7, 0, 33, 12, 6, 11, 6, 3, 20, 11, 0, 1, 6, 204, 21, 122, 0, 0, 0,
+    0, 0, 39, 10, 2, 6, 0, 128, 198, 164, 126, 141, 3, 0, 37, 12, 8, 11, 8, 3, 30, 11, 0, 1, 6,
+    205, 21, 122, 0, 0, 0, 0, 0, 39, 11, 0, 17, 2, 12, 3, 14, 3, 10, 1, 10, 2, 7, 1, 7, 1, 56, 0,
+    11, 3, 17, 4, 2,
+];
+
+const TIERED_MINT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 21, 7, 38, 60, 8, 98, 16,
+    0, 0, 0, 1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 1, 1, 1, 4, 2, 6, 12, 3, 0, 4, 6, 12, 5, 3, 3, 5, 6, 12,
+    3, 5, 3, 3, 1, 9, 0, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108,
+    105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111,
+    110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 11, 116, 105, 101, 114, 101, 100, 95,
+    109, 105, 110, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 9, 10, 0, 10,
+    1, 17, 0, 11, 0, 10, 2, 10, 3, 10, 4, 56, 0, 2,
+];
+
+const UNFREEZE_ACCOUNT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 14, 7, 28, 65, 8, 93, 16, 0, 0, 0,
+    1, 1, 2, 0, 1, 0, 0, 3, 2, 1, 0, 2, 6, 12, 3, 0, 2, 6, 12, 5, 3, 6, 12, 3, 5, 12, 76, 105, 98,
+    114, 97, 65, 99, 99, 111, 117, 110, 116, 12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110,
+    99, 101, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98,
+    111, 114, 116, 16, 117, 110, 102, 114, 101, 101, 122, 101, 95, 97, 99, 99, 111, 117, 110, 116,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 1, 7, 10, 0, 10, 1, 17, 0, 11, 0, 10, 2,
+    17, 1, 2,
+];
+
+const UNMINT_LBR_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 2, 2, 4, 3, 6, 15, 5, 21, 16, 7, 37, 101, 8, 138, 1,
+    16, 0, 0, 0, 1, 1, 0, 0, 2, 0, 1, 0, 0, 3, 1, 2, 0, 0, 4, 3, 2, 0, 1, 6, 12, 1, 8, 0, 0, 2, 6,
+    8, 0, 3, 2, 6, 12, 3, 12, 76, 105, 98, 114, 97, 65, 99, 99, 111, 117, 110, 116, 18, 87, 105,
+    116, 104, 100, 114, 97, 119, 67, 97, 112, 97, 98, 105, 108, 105, 116, 121, 27, 101, 120, 116,
+    114, 97, 99, 116, 95, 119, 105, 116, 104, 100, 114, 97, 119, 95, 99, 97, 112, 97, 98, 105, 108,
+    105, 116, 121, 27, 114, 101, 115, 116, 111, 114, 101, 95, 119, 105, 116, 104, 100, 114, 97,
+    119, 95, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 12, 117, 110, 115, 116, 97, 112, 108,
+    101, 95, 108, 98, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 4, 1, 9, 11, 0, 17,
+    0, 12, 2, 14, 2, 10, 1, 17, 2, 11, 2, 17, 1, 2,
+];
+
+const UPDATE_ACCOUNT_LIMIT_DEFINITION_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 4, 3, 4, 11, 4, 15, 2, 5, 17, 23, 7, 40, 74, 8, 114, 16,
+    0, 0, 0, 1, 0, 2, 0, 1, 1, 1, 1, 3, 2, 1, 0, 0, 4, 5, 6, 12, 5, 3, 3, 3, 0, 2, 6, 12, 3, 6, 6,
+    12, 5, 3, 3, 3, 3, 1, 9, 0, 13, 65, 99, 99, 111, 117, 110, 116, 76, 105, 109, 105, 116, 115,
+    12, 83, 108, 105, 100, 105, 110, 103, 78, 111, 110, 99, 101, 24, 117, 112, 100, 97, 116, 101,
+    95, 108, 105, 109, 105, 116, 115, 95, 100, 101, 102, 105, 110, 105, 116, 105, 111, 110, 21,
+    114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99, 101, 95, 111, 114, 95, 97, 98, 111, 114,
+    116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 3, 1, 10, 10, 0, 10, 2, 17, 1, 11,
+    0, 10, 1, 10, 3, 10, 4, 10, 5, 56, 0, 2,
+];
+
+const UPDATE_EXCHANGE_RATE_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 7, 1, 0, 6, 2, 6, 4, 3, 10, 16, 4, 26, 2, 5, 28, 25, 7, 53, 100,
+    8, 153, 1, 16, 0, 0, 0, 1, 0, 2, 0, 0, 2, 0, 0, 3, 0, 1, 0, 2, 4, 2, 3, 0, 1, 5, 4, 3, 1, 1, 2,
+    6, 2, 3, 3, 1, 8, 0, 2, 6, 12, 3, 0, 2, 6, 12, 8, 0, 4, 6, 12, 3, 3, 3, 1, 9, 0, 12, 70, 105,
+    120, 101, 100, 80, 111, 105, 110, 116, 51, 50, 5, 76, 105, 98, 114, 97, 12, 83, 108, 105, 100,
+    105, 110, 103, 78, 111, 110, 99, 101, 20, 99, 114, 101, 97, 116, 101, 95, 102, 114, 111, 109,
+    95, 114, 97, 116, 105, 111, 110, 97, 108, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110,
+    99, 101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 24, 117, 112, 100, 97, 116, 101, 95, 108, 98,
+    114, 95, 101, 120, 99, 104, 97, 110, 103, 101, 95, 114, 97, 116, 101, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 5, 1, 11, 10, 0, 10, 1, 17, 1, 10, 2, 10, 3, 17, 0, 12, 4, 11, 0,
+    11, 4, 56, 0, 2,
+];
+
+const UPDATE_LIBRA_VERSION_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 2, 3, 2, 5, 5, 7, 5, 7, 12, 17, 8, 29, 16, 0, 0, 0, 1,
+    0, 1, 0, 2, 6, 12, 3, 0, 12, 76, 105, 98, 114, 97, 86, 101, 114, 115, 105, 111, 110, 3, 115,
+    101, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 4, 11, 0, 10, 1, 17, 0, 2,
+];
+
+const UPDATE_MINTING_ABILITY_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 6, 1, 0, 2, 3, 2, 6, 4, 8, 2, 5, 10, 8, 7, 18, 29, 8, 47, 16, 0,
+    0, 0, 1, 0, 1, 1, 1, 0, 2, 2, 6, 12, 1, 0, 1, 9, 0, 5, 76, 105, 98, 114, 97, 22, 117, 112, 100,
+    97, 116, 101, 95, 109, 105, 110, 116, 105, 110, 103, 95, 97, 98, 105, 108, 105, 116, 121, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 4, 11, 0, 10, 1, 56, 0, 2,
+];
+
+const UPDATE_TRAVEL_RULE_LIMIT_CODE: &[u8] = &[
+    161, 28, 235, 11, 1, 0, 0, 0, 5, 1, 0, 4, 3, 4, 10, 5, 14, 10, 7, 24, 77, 8, 101, 16, 0, 0, 0,
+    1, 0, 2, 0, 1, 0, 1, 3, 0, 1, 0, 2, 6, 12, 3, 0, 3, 6, 12, 3, 3, 20, 68, 117, 97, 108, 65, 116,
+    116, 101, 115, 116, 97, 116, 105, 111, 110, 76, 105, 109, 105, 116, 12, 83, 108, 105, 100, 105,
+    110, 103, 78, 111, 110, 99, 101, 20, 115, 101, 116, 95, 109, 105, 99, 114, 111, 108, 105, 98,
+    114, 97, 95, 108, 105, 109, 105, 116, 21, 114, 101, 99, 111, 114, 100, 95, 110, 111, 110, 99,
+    101, 95, 111, 114, 95, 97, 98, 111, 114, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    0, 2, 1, 7, 10, 0, 10, 1, 17, 1, 11, 0, 10, 2, 17, 0, 2,
+];
@@ -0,0 +1,127 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use compiled_stdlib::transaction_scripts::StdlibScript;
+use libra_types::{
+    account_address::AccountAddress,
+    account_config::lbr_type_tag,
+    transaction::{Script, TransactionArgument},
+};
+use move_core_types::language_storage::TypeTag;
+use transaction_builder::ScriptCall;
+
+fn sample_argument(type_tag: &TypeTag) -> TransactionArgument {
+    match type_tag {
+        TypeTag::Bool => TransactionArgument::Bool(true),
+        TypeTag::U8 => TransactionArgument::U8(7),
+        TypeTag::U64 => TransactionArgument::U64(42),
+        TypeTag::U128 => TransactionArgument::U128(1 << 100),
+        TypeTag::Address => TransactionArgument::Address(AccountAddress::random()),
+        TypeTag::Vector(_) => TransactionArgument::U8Vector(vec![1, 2, 3]),
+        TypeTag::Signer | TypeTag::Struct(_) => panic!("unexpected argument type {}", type_tag),
+    }
+}
+
+// An argument of the same "shape" but of a different type than `arg`.
+fn mistyped_argument(arg: &TransactionArgument) -> TransactionArgument {
+    match arg {
+        TransactionArgument::U64(_) => TransactionArgument::U128(42),
+        _ => TransactionArgument::U64(42),
+    }
+}
+
+fn sample_script(script: StdlibScript) -> Script {
+    let abi = script.abi();
+    Script::new(
+        abi.code().to_vec(),
+        abi.ty_args().iter().map(|_| lbr_type_tag()).collect(),
+        abi.args()
+            .iter()
+            .map(|arg| sample_argument(arg.type_tag()))
+            .collect(),
+    )
+}
+
+#[test]
+fn decode_encode_round_trip() {
+    for stdlib_script in StdlibScript::all() {
+        let abi = stdlib_script.abi();
+        let script = sample_script(stdlib_script);
+        let call = ScriptCall::decode(&script)
+            .unwrap_or_else(|| panic!("failed to decode {}", abi.name()));
+        assert_eq!(call.name(), abi.name());
+        assert_eq!(
+            call.type_arguments()
+                .into_iter()
+                .map(|(name, type_tag)| (name, type_tag.clone()))
+                .collect::<Vec<_>>(),
+            abi.ty_args()
+                .iter()
+                .map(|ty_arg| ty_arg.name())
+                .zip(script.ty_args().iter().cloned())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            call.arguments(),
+            abi.args()
+                .iter()
+                .map(|arg| arg.name())
+                .zip(script.args().iter().cloned())
+                .collect::<Vec<_>>(),
+        );
+
+        let encoded = call.clone().encode();
+        assert_eq!(encoded, script);
+        assert_eq!(ScriptCall::decode(&encoded), Some(call));
+    }
+}
+
+#[test]
+fn decode_rejects_wrong_arity() {
+    for stdlib_script in StdlibScript::all() {
+        let script = sample_script(stdlib_script);
+        let (code, ty_args, args) = (
+            script.code().to_vec(),
+            script.ty_args().to_vec(),
+            script.args().to_vec(),
+        );
+
+        let mut more_args = args.clone();
+        more_args.push(TransactionArgument::U64(0));
+        let extra_arg = Script::new(code.clone(), ty_args.clone(), more_args);
+        assert_eq!(ScriptCall::decode(&extra_arg), None);
+
+        let mut more_ty_args = ty_args.clone();
+        more_ty_args.push(lbr_type_tag());
+        let extra_ty_arg = Script::new(code.clone(), more_ty_args, args.clone());
+        assert_eq!(ScriptCall::decode(&extra_ty_arg), None);
+
+        if !args.is_empty() {
+            let missing_arg = Script::new(code.clone(), ty_args.clone(), args[1..].to_vec());
+            assert_eq!(ScriptCall::decode(&missing_arg), None);
+        }
+        if !ty_args.is_empty() {
+            let missing_ty_arg = Script::new(code, ty_args[1..].to_vec(), args);
+            assert_eq!(ScriptCall::decode(&missing_ty_arg), None);
+        }
+    }
+}
+
+#[test]
+fn decode_rejects_wrong_argument_types() {
+    for stdlib_script in StdlibScript::all() {
+        let script = sample_script(stdlib_script);
+        for i in 0..script.args().len() {
+            let mut args = script.args().to_vec();
+            args[i] = mistyped_argument(&args[i]);
+            let mistyped = Script::new(script.code().to_vec(), script.ty_args().to_vec(), args);
+            assert_eq!(ScriptCall::decode(&mistyped), None);
+        }
+    }
+}
+
+#[test]
+fn decode_rejects_unknown_code() {
+    let script = Script::new(vec![0xde, 0xad], vec![], vec![]);
+    assert_eq!(ScriptCall::decode(&script), None);
+}
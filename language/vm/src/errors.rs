@@ -1,17 +1,44 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::IndexKind;
+use crate::{file_format::CodeOffset, IndexKind};
 use move_core_types::{
     account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
     vm_status::{StatusCode, VMStatus},
 };
+use std::fmt;
 
 // TODO: Fill in the details for Locations. Ideally it should be a unique handle into a function and
 // a pc.
 #[derive(Debug, Default)]
 pub struct Location {}
 
+/// A frame of the call stack at the point an execution failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackFrame {
+    /// The module of the function, None for the function of a script.
+    pub module: Option<ModuleId>,
+    pub function: Identifier,
+    /// The offset of the instruction being executed by the function. For the frames below the
+    /// top this is the call to the function above.
+    pub pc: CodeOffset,
+}
+
+/// The call stack at the point an execution failed, the outermost frame first.
+pub type StackTrace = Vec<StackFrame>;
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.module {
+            Some(module) => write!(f, "{}::{}::", module.address(), module.name())?,
+            None => write!(f, "Script::")?,
+        }
+        write!(f, "{} [pc = {}]", self.function, self.pc)
+    }
+}
+
 /// Error codes that can be emitted by the prologue. These have special significance to the VM when
 /// they are raised during the prologue. However, they can also be raised by user code during
 /// execution of a transaction script. They have no significance to the VM in that case.
@@ -3,10 +3,10 @@
 
 use backup_service::start_backup_service;
 use consensus::{consensus_provider::start_consensus, gen_consensus_reconfig_subscription};
-use debug_interface::{libra_trace, node_debug_service::NodeDebugService};
+use debug_interface::{admin, libra_trace, node_debug_service::NodeDebugService, trace_export};
 use executor::{db_bootstrapper::bootstrap_db_if_empty, Executor};
 use executor_types::ChunkExecutor;
-use futures::{channel::mpsc::channel, executor::block_on};
+use futures::{channel::mpsc::channel, executor::block_on, StreamExt};
 use libra_config::{
     config::{NetworkConfig, NodeConfig, RoleType},
     utils::get_genesis_txn,
@@ -15,17 +15,36 @@ use libra_json_rpc::bootstrap_from_config as bootstrap_rpc;
 use libra_logger::prelude::*;
 use libra_mempool::gen_mempool_reconfig_subscription;
 use libra_metrics::metric_server;
+use libra_types::PeerId;
 use libra_vm::LibraVM;
 use libradb::LibraDB;
+use netcore::transport::faulty::{FaultController, Faults};
+use network::peer_manager::{conn_notifs_channel, ConnectionNotification};
 use network_builder::builder::NetworkBuilder;
+use serde_json::{json, Value};
 use state_synchronizer::StateSynchronizer;
-use std::{boxed::Box, net::ToSocketAddrs, sync::Arc, thread, time::Instant};
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    net::ToSocketAddrs,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 use storage_interface::DbReaderWriter;
 use storage_service::start_storage_service_with_db;
 use tokio::runtime::Runtime;
 
 const AC_SMP_CHANNEL_BUFFER_SIZE: usize = 1_024;
 const INTRA_NODE_CHANNEL_BUFFER_SIZE: usize = 1;
+/// Upper bound of the latency and jitter injected through the network_faults admin command.
+const MAX_NETWORK_FAULT_DELAY_MS: u64 = 60 * 60 * 1_000;
+
+/// Connected peers along with their connection metadata, keyed by network
+type PeerTable = Arc<Mutex<BTreeMap<String, HashMap<PeerId, Value>>>>;
 
 pub struct LibraHandle {
     _rpc: Runtime,
@@ -54,7 +73,210 @@ fn setup_debug_interface(config: &NodeConfig) -> NodeDebugService {
     libra_trace::set_libra_trace(&config.debug_interface.libra_trace.sampling)
         .expect("Failed to set libra trace sampling rate.");
 
-    NodeDebugService::new(addr)
+    if let Some(endpoint) = &config.debug_interface.libra_trace.otlp_endpoint {
+        let peer_id = config
+            .validator_network
+            .as_ref()
+            .or_else(|| config.full_node_networks.first())
+            .map(|network| network.peer_id().to_string())
+            .unwrap_or_default();
+        trace_export::init_exporter(endpoint.clone(), "libra-node", &peer_id)
+            .expect("Failed to start libra trace exporter.");
+    }
+
+    NodeDebugService::new(addr, config.debug_interface.admin_token.clone())
+}
+
+/// Keeps the peer table up to date with the connection events of a single network.
+async fn track_peers(
+    network_id: String,
+    mut conn_notifs: conn_notifs_channel::Receiver,
+    peers: PeerTable,
+) {
+    while let Some(notif) = conn_notifs.next().await {
+        let mut peers = peers.lock().unwrap();
+        let network_peers = peers.entry(network_id.clone()).or_default();
+        match notif {
+            ConnectionNotification::NewPeer(peer_id, address, origin, _context) => {
+                let connected_at = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("now > UNIX_EPOCH")
+                    .as_secs();
+                network_peers.insert(
+                    peer_id,
+                    json!({
+                        "address": address.to_string(),
+                        "origin": origin,
+                        "connected_at": connected_at,
+                    }),
+                );
+            }
+            ConnectionNotification::LostPeer(peer_id, _address, _origin, _reason) => {
+                network_peers.remove(&peer_id);
+            }
+        }
+    }
+}
+
+/// Registers the admin commands that require access to components owned by the node itself.
+fn register_admin_handlers(
+    libra_db: Arc<LibraDB>,
+    peers: PeerTable,
+    db_checkpoint_dir: Option<PathBuf>,
+    fault_controllers: Vec<(String, FaultController)>,
+) {
+    admin::register_handler("network_peers", move |_params| {
+        let peers: BTreeMap<_, BTreeMap<_, _>> = peers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(network_id, network_peers)| {
+                let network_peers = network_peers
+                    .iter()
+                    .map(|(peer_id, metadata)| (peer_id.to_string(), metadata.clone()))
+                    .collect();
+                (network_id.clone(), network_peers)
+            })
+            .collect();
+        Ok(json!(peers))
+    });
+
+    // Checkpoints are confined to the configured directory, requests only pick their name.
+    if let Some(db_checkpoint_dir) = db_checkpoint_dir {
+        admin::register_handler("db_checkpoint", move |params| {
+            let name = params
+                .get("name")
+                .ok_or_else(|| anyhow::format_err!("Missing name"))?;
+            let mut components = Path::new(name).components();
+            match (components.next(), components.next()) {
+                (Some(Component::Normal(_)), None) => (),
+                _ => anyhow::bail!("Invalid checkpoint name: {}", name),
+            }
+            let path = db_checkpoint_dir.join(name);
+            libra_db.create_checkpoint(&path)?;
+            info!("Created DB checkpoint at {:?}", path);
+            Ok(json!(path))
+        });
+    }
+
+    if !fault_controllers.is_empty() {
+        admin::register_handler("network_faults", move |params| {
+            network_faults(&fault_controllers, params)
+        });
+    }
+}
+
+/// Changes the faults injected in the networks with fault injection, or only in `network` if
+/// given, and returns the faults of each network. `heal` removes all the faults, `partition` and
+/// `unpartition` add and remove comma-separated partitioned peers, and `isolated`,
+/// `latency_ms`, `jitter_ms`, `bandwidth` and `drop_rate` set the corresponding fault. Latency
+/// and jitter are bounded by an hour.
+fn network_faults(
+    fault_controllers: &[(String, FaultController)],
+    params: &admin::AdminParams,
+) -> anyhow::Result<Value> {
+    let network = params.get("network");
+    if let Some(network) = network {
+        if !fault_controllers.iter().any(|(id, _)| id == network) {
+            anyhow::bail!("No fault injection in network {}", network);
+        }
+    }
+    let peers = |name: &str| -> anyhow::Result<Vec<PeerId>> {
+        params.get(name).map_or(Ok(vec![]), |peers: &String| {
+            peers.split(',').map(PeerId::from_hex_literal).collect()
+        })
+    };
+    let partition = peers("partition")?;
+    let unpartition = peers("unpartition")?;
+    let heal = param::<bool>(params, "heal")?.unwrap_or(false);
+    let isolated = param::<bool>(params, "isolated")?;
+    let latency_ms = param::<u64>(params, "latency_ms")?;
+    let jitter_ms = param::<u64>(params, "jitter_ms")?;
+    let bandwidth = param::<u64>(params, "bandwidth")?;
+    let drop_rate = param::<f64>(params, "drop_rate")?;
+    for (name, delay_ms) in &[("latency_ms", latency_ms), ("jitter_ms", jitter_ms)] {
+        if let Some(delay_ms) = delay_ms {
+            anyhow::ensure!(
+                *delay_ms <= MAX_NETWORK_FAULT_DELAY_MS,
+                "Invalid {} {}: must be at most {}",
+                name,
+                delay_ms,
+                MAX_NETWORK_FAULT_DELAY_MS
+            );
+        }
+    }
+    if let Some(drop_rate) = drop_rate {
+        anyhow::ensure!(
+            (0.0..=1.0).contains(&drop_rate),
+            "Invalid drop_rate {}: must be between 0 and 1",
+            drop_rate
+        );
+    }
+
+    let mut result = BTreeMap::new();
+    for (network_id, controller) in fault_controllers {
+        if network.map_or(false, |network| network != network_id) {
+            continue;
+        }
+        controller.update(|faults| {
+            if heal {
+                *faults = Faults::default();
+            }
+            faults.partitioned_peers.extend(partition.iter().cloned());
+            for peer_id in &unpartition {
+                faults.partitioned_peers.remove(peer_id);
+            }
+            if let Some(isolated) = isolated {
+                faults.isolated = isolated;
+            }
+            if let Some(latency_ms) = latency_ms {
+                faults.latency = Duration::from_millis(latency_ms);
+            }
+            if let Some(jitter_ms) = jitter_ms {
+                faults.jitter = Duration::from_millis(jitter_ms);
+            }
+            if let Some(bandwidth) = bandwidth {
+                faults.bandwidth = Some(bandwidth);
+            }
+            if let Some(drop_rate) = drop_rate {
+                faults.drop_rate = drop_rate;
+            }
+        });
+        let faults = controller.faults();
+        let mut partitioned_peers: Vec<_> = faults
+            .partitioned_peers
+            .iter()
+            .map(|peer_id| peer_id.to_string())
+            .collect();
+        partitioned_peers.sort();
+        result.insert(
+            network_id.clone(),
+            json!({
+                "partitioned_peers": partitioned_peers,
+                "isolated": faults.isolated,
+                "latency_ms": faults.latency.as_millis() as u64,
+                "jitter_ms": faults.jitter.as_millis() as u64,
+                "bandwidth": faults.bandwidth,
+                "drop_rate": faults.drop_rate,
+            }),
+        );
+    }
+    Ok(json!(result))
+}
+
+fn param<T>(params: &admin::AdminParams, name: &str) -> anyhow::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    params
+        .get(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|e| anyhow::format_err!("Invalid {} {}: {}", name, value, e))
+        })
+        .transpose()
 }
 
 pub fn setup_environment(node_config: &mut NodeConfig) -> LibraHandle {
@@ -100,6 +322,8 @@ pub fn setup_environment(node_config: &mut NodeConfig) -> LibraHandle {
     let mut mempool_network_handles = vec![];
     let mut consensus_network_handles = None;
     let mut reconfig_subscriptions = vec![];
+    let peers = PeerTable::default();
+    let mut fault_controllers = vec![];
 
     let (mempool_reconfig_subscription, mempool_reconfig_events) =
         gen_mempool_reconfig_subscription();
@@ -129,6 +353,18 @@ pub fn setup_environment(node_config: &mut NodeConfig) -> LibraHandle {
             NetworkBuilder::create(&node_config.base.chain_id, role, network_config);
         let network_id = network_config.network_id.clone();
 
+        if let Some(controller) = network_builder.fault_controller() {
+            fault_controllers.push((network_id.to_string(), controller));
+        }
+
+        // Track connected peers for the debug interface.
+        let conn_notifs = network_builder.add_connection_event_listener();
+        runtime.spawn(track_peers(
+            network_id.to_string(),
+            conn_notifs,
+            Arc::clone(&peers),
+        ));
+
         // Create the endpoints to connect the Network to StateSynchronizer.
         let (state_sync_sender, state_sync_events) = network_builder
             .add_protocol_handler(state_synchronizer::network::network_endpoint_config());
@@ -170,6 +406,13 @@ pub fn setup_environment(node_config: &mut NodeConfig) -> LibraHandle {
         debug!("Network started for peer_id: {}", peer_id);
     }
 
+    register_admin_handlers(
+        Arc::clone(&libra_db),
+        peers,
+        node_config.debug_interface.db_checkpoint_dir.clone(),
+        fault_controllers,
+    );
+
     // TODO set up on-chain discovery network based on UpstreamConfig.fallback_network
     // and pass network handles to mempool/state sync
 
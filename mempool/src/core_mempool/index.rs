@@ -268,6 +268,10 @@ impl ParkingLotIndex {
         self.data.remove(&TxnPointer::from(txn));
     }
 
+    pub(crate) fn contains(&self, txn: &MempoolTransaction) -> bool {
+        self.data.contains(&TxnPointer::from(txn))
+    }
+
     /// returns random "non-ready" transaction (with highest sequence number for that account)
     pub(crate) fn pop(&mut self) -> Option<TxnPointer> {
         self.data.iter().rev().next().cloned()
@@ -6,7 +6,7 @@
 use crate::{
     core_mempool::{
         index::TxnPointer,
-        transaction::{MempoolTransaction, TimelineState},
+        transaction::{MempoolTransaction, MempoolTransactionSummary, TimelineState},
         transaction_store::TransactionStore,
         ttl_cache::TtlCache,
     },
@@ -262,4 +262,9 @@ impl Mempool {
     ) -> Vec<(u64, SignedTransaction)> {
         self.transactions.filter_read_timeline(timeline_ids)
     }
+
+    /// Returns summaries of up to `limit` transactions currently in Mempool
+    pub fn gen_snapshot(&self, limit: usize) -> Vec<MempoolTransactionSummary> {
+        self.transactions.gen_snapshot(limit)
+    }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use libra_types::{account_address::AccountAddress, transaction::SignedTransaction};
+use serde::Serialize;
 use std::time::Duration;
 
 #[derive(Clone)]
@@ -44,7 +45,7 @@ impl MempoolTransaction {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Serialize)]
 pub enum TimelineState {
     // transaction is ready for broadcast
     // Associated integer represents it's position in log of such transactions
@@ -56,3 +57,17 @@ pub enum TimelineState {
     // currently we don't broadcast transactions originated on other peers
     NonQualified,
 }
+
+/// Point in time view of a transaction in Mempool, exposed for debugging purposes
+#[derive(Clone, Debug, Serialize)]
+pub struct MempoolTransactionSummary {
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub gas_unit_price: u64,
+    pub ranking_score: u64,
+    // client-specified expiration time of transaction
+    pub expiration_time: Duration,
+    pub timeline_state: TimelineState,
+    // true if transaction can't be included in next block
+    pub is_parked: bool,
+}
@@ -7,7 +7,7 @@ use crate::{
             AccountTransactions, ParkingLotIndex, PriorityIndex, PriorityQueueIter, TTLIndex,
             TimelineIndex,
         },
-        transaction::{MempoolTransaction, TimelineState},
+        transaction::{MempoolTransaction, MempoolTransactionSummary, TimelineState},
     },
     OP_COUNTERS,
 };
@@ -393,4 +393,23 @@ impl TransactionStore {
     pub(crate) fn iter_queue(&self) -> PriorityQueueIter {
         self.priority_index.iter()
     }
+
+    /// Returns summaries of up to `limit` transactions in the store, grouped by account and
+    /// ordered by sequence number
+    pub(crate) fn gen_snapshot(&self, limit: usize) -> Vec<MempoolTransactionSummary> {
+        self.transactions
+            .values()
+            .flat_map(|txns| txns.values())
+            .take(limit)
+            .map(|txn| MempoolTransactionSummary {
+                sender: txn.get_sender(),
+                sequence_number: txn.get_sequence_number(),
+                gas_unit_price: txn.get_gas_price(),
+                ranking_score: txn.ranking_score,
+                expiration_time: txn.txn.expiration_time(),
+                timeline_state: txn.timeline_state,
+                is_parked: self.parking_lot_index.contains(txn),
+            })
+            .collect()
+    }
 }
@@ -13,6 +13,7 @@ use crate::{
 };
 use anyhow::Result;
 use channel::libra_channel;
+use debug_interface::admin;
 use futures::channel::{
     mpsc::{self, Receiver, UnboundedSender},
     oneshot,
@@ -27,6 +28,11 @@ use storage_interface::DbReader;
 use tokio::runtime::{Builder, Handle, Runtime};
 use vm_validator::vm_validator::{TransactionValidation, VMValidator};
 
+/// Number of transactions returned by the mempool admin command, unless a limit is requested.
+const DEFAULT_ADMIN_SNAPSHOT_SIZE: usize = 1_000;
+/// Upper bound on the limit requested from the mempool admin command.
+const MAX_ADMIN_SNAPSHOT_SIZE: usize = 10_000;
+
 /// bootstrap of SharedMempool
 /// creates separate Tokio Runtime that runs following routines:
 ///   - outbound_sync_task (task that periodically broadcasts transactions to peers)
@@ -104,6 +110,16 @@ pub fn bootstrap(
         .build()
         .expect("[shared mempool] failed to create runtime");
     let mempool = Arc::new(Mutex::new(CoreMempool::new(&config)));
+    let admin_mempool = Arc::clone(&mempool);
+    admin::register_handler("mempool", move |params| {
+        let limit = match params.get("limit") {
+            Some(limit) => limit.parse::<usize>()?.min(MAX_ADMIN_SNAPSHOT_SIZE),
+            None => DEFAULT_ADMIN_SNAPSHOT_SIZE,
+        };
+        // Only summarize under the lock, serialization happens after releasing it.
+        let snapshot = admin_mempool.lock().unwrap().gen_snapshot(limit);
+        Ok(serde_json::to_value(snapshot)?)
+    });
     let vm_validator = Arc::new(RwLock::new(VMValidator::new(Arc::clone(&db))));
     start_shared_mempool(
         runtime.handle(),
@@ -441,3 +441,20 @@ fn test_ttl_cache() {
         .unwrap());
     assert_eq!(cache.size(), 0);
 }
+
+#[test]
+fn test_gen_snapshot() {
+    let mut pool = CoreMempool::new(&NodeConfig::random());
+    for seq in &[0, 1, 3] {
+        add_txn(&mut pool, TestTransaction::new(1, *seq, 1)).unwrap();
+    }
+
+    let snapshot = pool.gen_snapshot(10);
+    let summary: Vec<_> = snapshot
+        .iter()
+        .map(|txn| (txn.sequence_number, txn.is_parked))
+        .collect();
+    assert_eq!(summary, vec![(0, false), (1, false), (3, true)]);
+    assert_eq!(snapshot[2].timeline_state, TimelineState::NotReady);
+    assert_eq!(pool.gen_snapshot(2).len(), 2);
+}
@@ -12,7 +12,7 @@
 use channel::{self, message_queues::QueueStyle};
 use libra_config::{
     chain_id::ChainId,
-    config::{DiscoveryMethod, NetworkConfig, RoleType, HANDSHAKE_VERSION},
+    config::{DiscoveryMethod, Identity, NetworkConfig, RoleType, HANDSHAKE_VERSION},
     network_id::{NetworkContext, NetworkId},
 };
 use libra_crypto::x25519;
@@ -20,9 +20,11 @@ use libra_logger::prelude::*;
 use libra_metrics::IntCounterVec;
 use libra_network_address::NetworkAddress;
 use libra_types::PeerId;
+use netcore::transport::faulty::FaultController;
 use network::{
     connectivity_manager::{builder::ConnectivityManagerBuilder, ConnectivityRequest},
     constants,
+    noise::NetworkIdentity,
     peer_manager::{
         builder::{AuthenticationMode, PeerManagerBuilder},
         conn_notifs_channel, ConnectionRequestSender,
@@ -36,6 +38,7 @@ use network::{
 };
 use network_simple_onchain_discovery::{
     builder::ConfigurationChangeListenerBuilder, gen_simple_discovery_reconfig_subscription,
+    OwnIdentity,
 };
 use std::{
     clone::Clone,
@@ -69,6 +72,7 @@ pub struct NetworkBuilder {
     discovery_builder: Option<DiscoveryBuilder>,
     health_checker_builder: Option<HealthCheckerBuilder>,
     peer_manager_builder: PeerManagerBuilder,
+    fault_controller: Option<FaultController>,
 
     reconfig_subscriptions: Vec<ReconfigSubscription>,
 }
@@ -119,6 +123,7 @@ impl NetworkBuilder {
             discovery_builder: None,
             health_checker_builder: None,
             peer_manager_builder,
+            fault_controller: None,
             reconfig_subscriptions: vec![],
         }
     }
@@ -136,12 +141,28 @@ impl NetworkBuilder {
             .expect("Failed to start runtime. Won't be able to start networking.");
 
         let peer_id = config.peer_id();
-        let identity_key = config.identity_key();
+        // The first key is the one peers are dialed with, any other key is only accepted for
+        // inbound handshakes until the ValidatorSet lists it for us.
+        let mut identity_keys = config.identity_keys().into_iter();
+        let identity = NetworkIdentity::new(
+            identity_keys
+                .next()
+                .expect("identity key should be present"),
+        );
+        identity_keys.for_each(|key| identity.add_key(key));
+        let own_identity = OwnIdentity {
+            peer_id,
+            keys: identity.clone(),
+            storage: match &config.identity {
+                Identity::FromStorage(storage) => Some(storage.clone()),
+                _ => None,
+            },
+        };
 
         let authentication_mode = if config.mutual_authentication {
-            AuthenticationMode::Mutual(identity_key)
+            AuthenticationMode::Mutual(identity)
         } else {
-            AuthenticationMode::ServerOnly(identity_key)
+            AuthenticationMode::ServerOnly(identity)
         };
         let pubkey = authentication_mode.public_key();
 
@@ -165,6 +186,10 @@ impl NetworkBuilder {
                 constants::PING_FAILURES_TOLERATED,
             );
 
+        if config.fault_injection {
+            network_builder.add_fault_injection();
+        }
+
         // Sanity check seed addresses.
         config
             .verify_seed_addrs()
@@ -197,11 +222,11 @@ impl NetworkBuilder {
                 );
                 // HACK: gossip relies on on-chain discovery for the eligible peers update.
                 if role == RoleType::Validator {
-                    network_builder.add_configuration_change_listener(role);
+                    network_builder.add_configuration_change_listener(role, own_identity);
                 }
             }
             DiscoveryMethod::Onchain => {
-                network_builder.add_configuration_change_listener(role);
+                network_builder.add_configuration_change_listener(role, own_identity);
             }
             DiscoveryMethod::None => {}
         }
@@ -245,6 +270,21 @@ impl NetworkBuilder {
         self
     }
 
+    /// Inject faults in the connections of this network. The faults are changed through the
+    /// returned controller, none are injected initially.
+    pub fn add_fault_injection(&mut self) -> FaultController {
+        let controller = FaultController::default();
+        self.peer_manager_builder
+            .add_fault_injection(controller.clone());
+        self.fault_controller = Some(controller.clone());
+        controller
+    }
+
+    /// The controller of the faults injected in the connections of this network, if any.
+    pub fn fault_controller(&self) -> Option<FaultController> {
+        self.fault_controller.clone()
+    }
+
     pub fn conn_mgr_reqs_tx(&self) -> Option<channel::Sender<ConnectivityRequest>> {
         match self.connectivity_manager_builder.as_ref() {
             Some(conn_mgr_builder) => Some(conn_mgr_builder.conn_mgr_reqs_tx()),
@@ -333,7 +373,11 @@ impl NetworkBuilder {
         self
     }
 
-    fn add_configuration_change_listener(&mut self, role: RoleType) -> &mut Self {
+    fn add_configuration_change_listener(
+        &mut self,
+        role: RoleType,
+        own_identity: OwnIdentity,
+    ) -> &mut Self {
         let conn_mgr_reqs_tx = self
             .conn_mgr_reqs_tx()
             .expect("ConnectivityManager must be installed for validator");
@@ -347,6 +391,7 @@ impl NetworkBuilder {
                 role,
                 conn_mgr_reqs_tx,
                 simple_discovery_reconfig_rx,
+                Some(own_identity),
             ));
         self.build_configuration_change_listener()
             .start_configuration_change_listener()
@@ -134,7 +134,7 @@ pub fn setup_network() -> DummyNetwork {
     .into_iter()
     .collect();
 
-    let authentication_mode = AuthenticationMode::Mutual(listener_identity_private_key);
+    let authentication_mode = AuthenticationMode::Mutual(listener_identity_private_key.into());
 
     // Set up the listener network
     let mut network_builder = NetworkBuilder::new(
@@ -153,7 +153,7 @@ pub fn setup_network() -> DummyNetwork {
         .add_protocol_handler::<DummyNetworkSender, DummyNetworkEvents>(network_endpoint_config());
     let listener_addr = network_builder.build();
 
-    let authentication_mode = AuthenticationMode::Mutual(dialer_identity_private_key);
+    let authentication_mode = AuthenticationMode::Mutual(dialer_identity_private_key.into());
     let seed_addrs: HashMap<_, _> = [(listener_peer_id, vec![listener_addr])]
         .iter()
         .cloned()
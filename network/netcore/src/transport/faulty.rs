@@ -0,0 +1,657 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fault-injecting Transport
+//!
+//! A [`FaultyTransport`] wraps a base transport, e.g. a [`MemoryTransport`] or a
+//! [`TcpTransport`], and injects the [`Faults`] of its [`FaultController`] in the connections it
+//! establishes. The faults can be changed at any time through the controller, which is shared by
+//! all the connections.
+//!
+//! The connections are reliable byte streams, so the faults are applied to the data as it is
+//! received: latency, jitter and bandwidth caps delay its delivery, and a dropped packet is
+//! delivered after a retransmission delay, as a TCP connection would do. Installing a
+//! [`FaultyTransport`] on two nodes thus injects faults in both directions of their connections.
+//!
+//! Partitions close the connections dialed to the partitioned peers and refuse new dials to
+//! them. The listener side of a connection does not know the identity of the dialer, so the
+//! inbound connections are only closed by isolating the node: a partition set on one node
+//! only blocks its dials, and a partition between two nodes must be set on both of them.
+//! Connections are closed as soon as the faults are changed, even if they are waiting for data.
+//!
+//! [`MemoryTransport`]: crate::transport::memory::MemoryTransport
+//! [`TcpTransport`]: crate::transport::tcp::TcpTransport
+
+use crate::transport::Transport;
+use futures::{
+    future::Future,
+    io::{AsyncRead, AsyncWrite},
+    stream::Stream,
+};
+use libra_network_address::NetworkAddress;
+use libra_types::PeerId;
+use pin_project::pin_project;
+use rand::Rng;
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use tokio::time::{delay_until, Delay, Instant};
+
+/// Size of the chunks read from the wrapped sockets, each of them is a "packet".
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+/// Maximum number of bytes read from a wrapped socket and not yet delivered. The socket is not
+/// read further until some of them are delivered, to keep the backpressure on the sender.
+const MAX_QUEUED_BYTES: usize = 1024 * 1024;
+
+/// Faults injected in the connections of a [`FaultyTransport`].
+#[derive(Clone, Debug)]
+pub struct Faults {
+    /// Delay of the data received by the connections.
+    pub latency: Duration,
+    /// Maximum random delay added to `latency`.
+    pub jitter: Duration,
+    /// Maximum number of bytes received per second by each connection, or `None` to keep the
+    /// bandwidth of the wrapped transport.
+    pub bandwidth: Option<u64>,
+    /// Probability for each chunk of data received to be dropped, between 0 and 1.
+    pub drop_rate: f64,
+    /// Additional delay of the dropped data, before it is retransmitted.
+    pub retransmission_delay: Duration,
+    /// Peers which cannot be dialed, the connections dialed to them are closed. The inbound
+    /// connections from these peers are not affected.
+    pub partitioned_peers: HashSet<PeerId>,
+    /// Whether all the connections are refused and closed.
+    pub isolated: bool,
+}
+
+impl Default for Faults {
+    fn default() -> Self {
+        Self {
+            latency: Duration::from_millis(0),
+            jitter: Duration::from_millis(0),
+            bandwidth: None,
+            drop_rate: 0.0,
+            // The minimum retransmission timeout of TCP
+            retransmission_delay: Duration::from_millis(200),
+            partitioned_peers: HashSet::new(),
+            isolated: false,
+        }
+    }
+}
+
+impl Faults {
+    /// Whether the connections with `remote_peer`, or with an unknown peer, are cut.
+    fn is_cut(&self, remote_peer: Option<&PeerId>) -> bool {
+        self.isolated || remote_peer.map_or(false, |peer| self.partitioned_peers.contains(peer))
+    }
+}
+
+/// Handle to change the [`Faults`] injected by a [`FaultyTransport`] and its connections.
+#[derive(Clone, Debug, Default)]
+pub struct FaultController {
+    inner: Arc<Mutex<ControllerInner>>,
+}
+
+#[derive(Debug, Default)]
+struct ControllerInner {
+    faults: Faults,
+    next_socket_id: u64,
+    // Tasks waiting on each open socket, woken up when the faults change
+    wakers: HashMap<u64, SocketWakers>,
+}
+
+#[derive(Debug, Default)]
+struct SocketWakers {
+    read: Option<Waker>,
+    write: Option<Waker>,
+}
+
+impl FaultController {
+    pub fn new(faults: Faults) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ControllerInner {
+                faults,
+                ..ControllerInner::default()
+            })),
+        }
+    }
+
+    /// The faults currently injected.
+    pub fn faults(&self) -> Faults {
+        self.lock().faults.clone()
+    }
+
+    /// Replace the faults injected from now on.
+    pub fn set(&self, faults: Faults) {
+        self.update(|current| *current = faults);
+    }
+
+    /// Modify the faults injected from now on, e.g. to add a partitioned peer. The sockets
+    /// waiting for data are woken up, so that the connections cut by the new faults are closed.
+    pub fn update<F: FnOnce(&mut Faults)>(&self, f: F) {
+        let wakers = {
+            let mut inner = self.lock();
+            f(&mut inner.faults);
+            inner
+                .wakers
+                .values_mut()
+                .flat_map(|wakers| wakers.read.take().into_iter().chain(wakers.write.take()))
+                .collect::<Vec<_>>()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Stop injecting any fault.
+    pub fn heal(&self) {
+        self.set(Faults::default());
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ControllerInner> {
+        self.inner
+            .lock()
+            .expect("Fault controller lock is poisoned")
+    }
+
+    fn register_socket(&self) -> u64 {
+        let mut inner = self.lock();
+        let id = inner.next_socket_id;
+        inner.next_socket_id += 1;
+        inner.wakers.insert(id, SocketWakers::default());
+        id
+    }
+
+    fn unregister_socket(&self, id: u64) {
+        self.lock().wakers.remove(&id);
+    }
+
+    /// Wake up the task reading (or writing) the socket `id` when the faults change.
+    fn wake_on_update(&self, id: u64, waker: &Waker, read: bool) {
+        if let Some(wakers) = self.lock().wakers.get_mut(&id) {
+            let slot = if read {
+                &mut wakers.read
+            } else {
+                &mut wakers.write
+            };
+            if !slot.as_ref().map_or(false, |w| w.will_wake(waker)) {
+                *slot = Some(waker.clone());
+            }
+        }
+    }
+
+    fn check_connection(&self, remote_peer: Option<&PeerId>) -> io::Result<()> {
+        if self.lock().faults.is_cut(remote_peer) {
+            Err(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "Connection cut by fault injection",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A [`FaultyTransport`] is a transport which wraps another transport and injects faults in the
+/// connections it establishes.
+#[derive(Debug)]
+pub struct FaultyTransport<T> {
+    transport: T,
+    controller: FaultController,
+}
+
+impl<T> FaultyTransport<T> {
+    /// Wraps around a [`Transport`] and injects the faults of `controller` in all the
+    /// connections created by it.
+    pub(crate) fn new(transport: T, controller: FaultController) -> Self {
+        Self {
+            transport,
+            controller,
+        }
+    }
+}
+
+impl<T> Transport for FaultyTransport<T>
+where
+    T: Transport<Error = io::Error>,
+    T::Output: AsyncRead + AsyncWrite + Unpin,
+{
+    type Output = FaultySocket<T::Output>;
+    type Error = io::Error;
+    type Listener = FaultyStream<T::Listener>;
+    type Inbound = FaultyFuture<T::Inbound>;
+    type Outbound = FaultyFuture<T::Outbound>;
+
+    fn listen_on(
+        &self,
+        addr: NetworkAddress,
+    ) -> Result<(Self::Listener, NetworkAddress), Self::Error> {
+        let (listener, addr) = self.transport.listen_on(addr)?;
+        let listener = FaultyStream::new(listener, self.controller.clone());
+
+        Ok((listener, addr))
+    }
+
+    fn dial(&self, peer_id: PeerId, addr: NetworkAddress) -> Result<Self::Outbound, Self::Error> {
+        if self.controller.lock().faults.is_cut(Some(&peer_id)) {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("Dial to {} refused by fault injection", peer_id.short_str()),
+            ));
+        }
+        let fut = self.transport.dial(peer_id, addr)?;
+
+        Ok(FaultyFuture::new(
+            fut,
+            self.controller.clone(),
+            Some(peer_id),
+        ))
+    }
+}
+
+/// Listener stream returned by [listen_on](Transport::listen_on) on a FaultyTransport.
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FaultyStream<St> {
+    #[pin]
+    inner: St,
+    controller: FaultController,
+}
+
+impl<St> FaultyStream<St>
+where
+    St: Stream,
+{
+    fn new(stream: St, controller: FaultController) -> Self {
+        Self {
+            inner: stream,
+            controller,
+        }
+    }
+}
+
+impl<St, Fut> Stream for FaultyStream<St>
+where
+    St: Stream<Item = io::Result<(Fut, NetworkAddress)>>,
+{
+    type Item = io::Result<(FaultyFuture<Fut>, NetworkAddress)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context) -> Poll<Option<Self::Item>> {
+        match self.as_mut().project().inner.poll_next(context) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(Some(Ok((fut, addr)))) => {
+                // The dialer of an inbound connection is unknown at this layer
+                let fut = FaultyFuture::new(fut, self.controller.clone(), None);
+                Poll::Ready(Some(Ok((fut, addr))))
+            }
+        }
+    }
+}
+
+/// Future which wraps the socket of a pending connection to inject faults in it.
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct FaultyFuture<F> {
+    #[pin]
+    future: F,
+    controller: FaultController,
+    remote_peer: Option<PeerId>,
+}
+
+impl<F> FaultyFuture<F>
+where
+    F: Future,
+{
+    fn new(future: F, controller: FaultController, remote_peer: Option<PeerId>) -> Self {
+        Self {
+            future,
+            controller,
+            remote_peer,
+        }
+    }
+}
+
+impl<F, S> Future for FaultyFuture<F>
+where
+    F: Future<Output = io::Result<S>>,
+{
+    type Output = io::Result<FaultySocket<S>>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.future.poll(context) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Ready(Ok(socket)) => {
+                let remote_peer = *this.remote_peer;
+                Poll::Ready(
+                    this.controller
+                        .check_connection(remote_peer.as_ref())
+                        .map(|()| FaultySocket::new(socket, this.controller.clone(), remote_peer)),
+                )
+            }
+        }
+    }
+}
+
+/// Socket which delays the data it receives, and fails once its connection is cut.
+#[derive(Debug)]
+pub struct FaultySocket<S> {
+    socket: S,
+    controller: FaultController,
+    // Identifies the socket in the controller
+    id: u64,
+    remote_peer: Option<PeerId>,
+    // Data read from the socket and the time it is delivered at, in order
+    queue: VecDeque<(Instant, Vec<u8>)>,
+    queued_bytes: usize,
+    read_buf: Vec<u8>,
+    // Time at which the data read so far has gone through the bandwidth cap
+    transmitted_at: Instant,
+    eof: bool,
+    timer: Option<Delay>,
+}
+
+impl<S> FaultySocket<S> {
+    fn new(socket: S, controller: FaultController, remote_peer: Option<PeerId>) -> Self {
+        let id = controller.register_socket();
+        Self {
+            socket,
+            controller,
+            id,
+            remote_peer,
+            queue: VecDeque::new(),
+            queued_bytes: 0,
+            read_buf: vec![0; READ_CHUNK_SIZE],
+            transmitted_at: Instant::now(),
+            eof: false,
+            timer: None,
+        }
+    }
+
+    /// The time at which `len` bytes received now are delivered. Delays too large to be
+    /// represented are ignored rather than overflowing.
+    fn delivery_time(&mut self, len: usize) -> Instant {
+        let inner = self.controller.lock();
+        let faults = &inner.faults;
+        let now = Instant::now();
+        let mut rng = rand::thread_rng();
+        let delay = |time: Instant, delay: Duration| time.checked_add(delay).unwrap_or(time);
+
+        self.transmitted_at = max(self.transmitted_at, now);
+        if let Some(bandwidth) = faults.bandwidth {
+            let nanos = len as u64 * 1_000_000_000 / max(bandwidth, 1);
+            self.transmitted_at = delay(self.transmitted_at, Duration::from_nanos(nanos));
+        }
+        let mut delivery_time = delay(self.transmitted_at, faults.latency);
+        if faults.jitter > Duration::from_millis(0) {
+            let jitter = rng.gen_range(0, faults.jitter.as_micros() as u64 + 1);
+            delivery_time = delay(delivery_time, Duration::from_micros(jitter));
+        }
+        if faults.drop_rate > 0.0 && rng.gen_bool(faults.drop_rate.min(1.0)) {
+            delivery_time = delay(delivery_time, faults.retransmission_delay);
+        }
+
+        // The data is delivered in order
+        match self.queue.back() {
+            Some((last_delivery_time, _)) => max(delivery_time, *last_delivery_time),
+            None => delivery_time,
+        }
+    }
+}
+
+impl<S> AsyncRead for FaultySocket<S>
+where
+    S: AsyncRead + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        context: &mut Context,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        // Registered before checking the faults, so that a change in between is not missed
+        this.controller
+            .wake_on_update(this.id, context.waker(), true);
+        this.controller
+            .check_connection(this.remote_peer.as_ref())?;
+
+        // Receive all the data available, up to the limit of queued data
+        while !this.eof && this.queued_bytes < MAX_QUEUED_BYTES {
+            match Pin::new(&mut this.socket).poll_read(context, &mut this.read_buf) {
+                Poll::Pending => break,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Ready(Ok(0)) => this.eof = true,
+                Poll::Ready(Ok(n)) => {
+                    let delivery_time = this.delivery_time(n);
+                    this.queue
+                        .push_back((delivery_time, this.read_buf[..n].to_vec()));
+                    this.queued_bytes += n;
+                }
+            }
+        }
+
+        loop {
+            let delivery_time = match this.queue.front() {
+                Some((delivery_time, _)) => *delivery_time,
+                None if this.eof => return Poll::Ready(Ok(0)),
+                // The wrapped socket will wake us up
+                None => return Poll::Pending,
+            };
+
+            if delivery_time <= Instant::now() {
+                this.timer = None;
+                let (_, data) = this.queue.front_mut().expect("Queue is not empty");
+                let n = min(buf.len(), data.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                if n == data.len() {
+                    this.queue.pop_front();
+                } else {
+                    data.drain(..n);
+                }
+                this.queued_bytes -= n;
+                return Poll::Ready(Ok(n));
+            }
+
+            let timer = this.timer.get_or_insert_with(|| delay_until(delivery_time));
+            if timer.deadline() != delivery_time {
+                timer.reset(delivery_time);
+            }
+            if Pin::new(timer).poll(context).is_pending() {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<S> AsyncWrite for FaultySocket<S>
+where
+    S: AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        context: &mut Context,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.controller
+            .wake_on_update(this.id, context.waker(), false);
+        this.controller
+            .check_connection(this.remote_peer.as_ref())?;
+        Pin::new(&mut this.socket).poll_write(context, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, context: &mut Context) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.controller
+            .wake_on_update(this.id, context.waker(), false);
+        this.controller
+            .check_connection(this.remote_peer.as_ref())?;
+        Pin::new(&mut this.socket).poll_flush(context)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, context: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().socket).poll_close(context)
+    }
+}
+
+impl<S> Drop for FaultySocket<S> {
+    fn drop(&mut self) {
+        self.controller.unregister_socket(self.id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::transport::{
+        faulty::{FaultController, Faults},
+        memory::MemoryTransport,
+        Transport, TransportExt,
+    };
+    use futures::{
+        future::join,
+        io::{AsyncReadExt, AsyncWriteExt},
+        stream::StreamExt,
+    };
+    use libra_types::PeerId;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn delays_received_data() -> Result<(), ::std::io::Error> {
+        let latency = Duration::from_millis(100);
+        let controller = FaultController::new(Faults {
+            latency,
+            ..Faults::default()
+        });
+        let t = MemoryTransport::default().with_faults(controller);
+
+        let (listener, addr) = t.listen_on("/memory/0".parse().unwrap())?;
+        let listener = async move {
+            let (item, _listener) = listener.into_future().await;
+            let (inbound, _addr) = item.unwrap().unwrap();
+            let mut socket = inbound.await.unwrap();
+            let start = Instant::now();
+            let mut buf = Vec::new();
+            socket.read_to_end(&mut buf).await.unwrap();
+            assert_eq!(buf, b"hello world");
+            assert!(start.elapsed() >= latency);
+        };
+        let outbound = t.dial(PeerId::random(), addr)?;
+        let dialer = async move {
+            let mut socket = outbound.await.unwrap();
+            socket.write_all(b"hello world").await.unwrap();
+            socket.close().await.unwrap();
+        };
+
+        join(dialer, listener).await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unrepresentable_latency_does_not_panic() -> Result<(), ::std::io::Error> {
+        let controller = FaultController::new(Faults {
+            latency: Duration::from_secs(u64::max_value()),
+            ..Faults::default()
+        });
+        let t = MemoryTransport::default().with_faults(controller);
+
+        let (listener, addr) = t.listen_on("/memory/0".parse().unwrap())?;
+        let mut outbound = t.dial(PeerId::random(), addr)?.await?;
+        let (item, _listener) = listener.into_future().await;
+        let mut inbound = item.unwrap()?.0.await?;
+
+        outbound.write_all(b"hello").await?;
+        let mut buf = [0; 5];
+        inbound.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partition_cuts_dialed_connections() -> Result<(), ::std::io::Error> {
+        let controller = FaultController::default();
+        let t = MemoryTransport::default().with_faults(controller.clone());
+        let peer_id = PeerId::random();
+
+        let (_listener, addr) = t.listen_on("/memory/0".parse().unwrap())?;
+        let mut socket = t.dial(peer_id, addr.clone())?.await?;
+        socket.write_all(b"hello").await?;
+
+        controller.update(|faults| {
+            faults.partitioned_peers.insert(peer_id);
+        });
+        assert!(socket.write_all(b"world").await.is_err());
+        assert!(t.dial(peer_id, addr.clone()).is_err());
+        assert!(t.dial(PeerId::random(), addr.clone()).is_ok());
+
+        controller.heal();
+        assert!(t.dial(peer_id, addr)?.await.is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partition_wakes_up_parked_reader() -> Result<(), ::std::io::Error> {
+        let controller = FaultController::default();
+        let t = MemoryTransport::default().with_faults(controller.clone());
+        let peer_id = PeerId::random();
+
+        let (listener, addr) = t.listen_on("/memory/0".parse().unwrap())?;
+        let mut socket = t.dial(peer_id, addr)?.await?;
+        // Keep the inbound connection open, it never sends anything
+        let (item, _listener) = listener.into_future().await;
+        let _inbound = item.unwrap()?.0.await?;
+
+        let reader = tokio::spawn(async move {
+            let mut buf = [0; 8];
+            socket.read(&mut buf).await
+        });
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+        controller.update(|faults| {
+            faults.partitioned_peers.insert(peer_id);
+        });
+        let result = tokio::time::timeout(Duration::from_secs(5), reader)
+            .await
+            .expect("Parked reader was not woken up by the partition")
+            .unwrap();
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partition_does_not_cut_inbound_connections() -> Result<(), ::std::io::Error> {
+        let dialer_id = PeerId::random();
+        let listener_controller = FaultController::default();
+        let listener_transport =
+            MemoryTransport::default().with_faults(listener_controller.clone());
+        let (listener, addr) = listener_transport.listen_on("/memory/0".parse().unwrap())?;
+        let dialer_transport = MemoryTransport::default();
+
+        let mut outbound = dialer_transport.dial(PeerId::random(), addr)?.await?;
+        let (item, _listener) = listener.into_future().await;
+        let mut inbound = item.unwrap()?.0.await?;
+
+        // The listener does not know that the dialer is the partitioned peer
+        listener_controller.update(|faults| {
+            faults.partitioned_peers.insert(dialer_id);
+        });
+        outbound.write_all(b"hello").await?;
+        let mut buf = [0; 5];
+        inbound.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+
+        // Only isolating the listener cuts its inbound connections
+        listener_controller.update(|faults| faults.isolated = true);
+        assert!(inbound.read(&mut buf).await.is_err());
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@ use std::time::Duration;
 
 pub mod and_then;
 pub mod boxed;
+pub mod faulty;
 pub mod memory;
 pub mod tcp;
 pub mod timeout;
@@ -147,4 +148,13 @@ pub trait TransportExt: Transport {
     {
         timeout::TimeoutTransport::new(self, timeout)
     }
+
+    /// Wraps a [`Transport`] to inject faults in the connections it establishes, e.g. latency
+    /// or partitions. The faults can be changed at runtime through the `controller`.
+    fn with_faults(self, controller: faulty::FaultController) -> faulty::FaultyTransport<Self>
+    where
+        Self: Sized,
+    {
+        faulty::FaultyTransport::new(self, controller)
+    }
 }
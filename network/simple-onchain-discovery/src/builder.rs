@@ -1,7 +1,7 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::ConfigurationChangeListener;
+use crate::{ConfigurationChangeListener, OwnIdentity};
 use channel::libra_channel;
 use libra_config::config::RoleType;
 use libra_types::on_chain_config::OnChainConfigPayload;
@@ -12,6 +12,7 @@ struct ConfigurationChangeListenerConfig {
     role: RoleType,
     conn_mgr_reqs_tx: channel::Sender<ConnectivityRequest>,
     reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
+    own_identity: Option<OwnIdentity>,
 }
 
 impl ConfigurationChangeListenerConfig {
@@ -19,11 +20,13 @@ impl ConfigurationChangeListenerConfig {
         role: RoleType,
         conn_mgr_reqs_tx: channel::Sender<ConnectivityRequest>,
         reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
+        own_identity: Option<OwnIdentity>,
     ) -> Self {
         Self {
             role,
             conn_mgr_reqs_tx,
             reconfig_events,
+            own_identity,
         }
     }
 }
@@ -46,12 +49,14 @@ impl ConfigurationChangeListenerBuilder {
         role: RoleType,
         conn_mgr_reqs_tx: channel::Sender<ConnectivityRequest>,
         reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
+        own_identity: Option<OwnIdentity>,
     ) -> ConfigurationChangeListenerBuilder {
         Self {
             config: Some(ConfigurationChangeListenerConfig::new(
                 role,
                 conn_mgr_reqs_tx,
                 reconfig_events,
+                own_identity,
             )),
             listener: None,
             state: State::CREATED,
@@ -66,6 +71,7 @@ impl ConfigurationChangeListenerBuilder {
             config.conn_mgr_reqs_tx,
             config.reconfig_events,
             config.role,
+            config.own_identity,
         ));
         self
     }
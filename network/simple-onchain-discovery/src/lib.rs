@@ -4,7 +4,7 @@
 use channel::libra_channel::{self, Receiver};
 use futures::{sink::SinkExt, StreamExt};
 use libra_canonical_serialization as lcs;
-use libra_config::config::RoleType;
+use libra_config::config::{IdentityFromStorage, RoleType};
 use libra_crypto::x25519;
 use libra_logger::prelude::*;
 use libra_metrics::{register_histogram, DurationHistogram};
@@ -12,8 +12,12 @@ use libra_network_address::NetworkAddress;
 use libra_types::{
     on_chain_config::{OnChainConfigPayload, ValidatorSet, ON_CHAIN_CONFIG_REGISTRY},
     validator_config::ValidatorConfig,
+    PeerId,
+};
+use network::{
+    connectivity_manager::{ConnectivityRequest, DiscoverySource},
+    noise::NetworkIdentity,
 };
-use network::connectivity_manager::{ConnectivityRequest, DiscoverySource};
 use once_cell::sync::Lazy;
 use std::{collections::HashSet, convert::TryFrom, iter, time::Instant};
 use subscription_service::ReconfigSubscription;
@@ -48,6 +52,16 @@ pub struct ConfigurationChangeListener {
     conn_mgr_reqs_tx: channel::Sender<ConnectivityRequest>,
     reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
     role: RoleType,
+    own_identity: Option<OwnIdentity>,
+}
+
+/// The node's own network identity, which the listener keeps in line with the key the
+/// ValidatorSet lists for the node.
+pub struct OwnIdentity {
+    pub peer_id: PeerId,
+    pub keys: NetworkIdentity,
+    /// Where to reload the keys from when the ValidatorSet lists a key that is not held yet.
+    pub storage: Option<IdentityFromStorage>,
 }
 
 pub fn gen_simple_discovery_reconfig_subscription(
@@ -129,11 +143,63 @@ impl ConfigurationChangeListener {
         conn_mgr_reqs_tx: channel::Sender<ConnectivityRequest>,
         reconfig_events: libra_channel::Receiver<(), OnChainConfigPayload>,
         role: RoleType,
+        own_identity: Option<OwnIdentity>,
     ) -> Self {
         Self {
             conn_mgr_reqs_tx,
             reconfig_events,
             role,
+            own_identity,
+        }
+    }
+
+    /// Dials peers with the key the ValidatorSet lists for this node. While a network key
+    /// rotation is in flight, the node holds both the old and the new key, so it keeps answering
+    /// handshakes with the old key and only starts dialing with the new one once a
+    /// reconfiguration has propagated it to peers. If the listed key is not held yet, e.g.,
+    /// because KeyManager rotated it while the node was running, the keys are reloaded from
+    /// storage first.
+    fn update_own_identity(&self, node_set: &ValidatorSet) {
+        let own_identity = match &self.own_identity {
+            Some(own_identity) if self.role == RoleType::Validator => own_identity,
+            _ => return,
+        };
+        let expected_key = match node_set
+            .payload()
+            .iter()
+            .find(|node| *node.account_address() == own_identity.peer_id)
+        {
+            Some(node) => public_key(self.role, node.config()),
+            None => return,
+        };
+
+        if !own_identity.keys.has_key(&expected_key) {
+            if let Some(storage) = &own_identity.storage {
+                match storage.load_keys() {
+                    Ok(keys) => {
+                        if let Some(key) = keys
+                            .into_iter()
+                            .find(|key| key.public_key() == expected_key)
+                        {
+                            own_identity.keys.add_key(key);
+                        }
+                    }
+                    Err(e) => error!("Unable to reload the network identity keys: {}", e),
+                }
+            }
+        }
+
+        let current_key = own_identity.keys.public_key();
+        if !own_identity.keys.use_key(&expected_key) {
+            error!(
+                "The ValidatorSet lists network key {} for us, but only {} is held",
+                expected_key, current_key
+            );
+        } else if current_key != expected_key {
+            info!(
+                "Switched the network key peers are dialed with from {} to {}",
+                current_key, expected_key
+            );
         }
     }
 
@@ -144,6 +210,8 @@ impl ConfigurationChangeListener {
             .get()
             .expect("failed to get ValidatorSet from payload");
 
+        self.update_own_identity(&node_set);
+
         let updates = match self.role {
             RoleType::Validator => extract_updates(self.role, node_set),
             RoleType::FullNode => extract_updates(self.role, node_set),
@@ -133,22 +133,112 @@ impl HandshakeAuthMode {
 //   in order to pass them to the noise implementaiton
 //
 
+/// The static keys a node authenticates with, shared between its transport and whatever keeps
+/// them in sync with the keys registered on-chain. While a network key rotation is in flight, a
+/// node holds both its old and its new key: it answers handshakes addressed to either of them and
+/// dials with the one the rest of the network currently expects it to have.
+#[derive(Clone, Debug)]
+pub struct NetworkIdentity {
+    keys: Arc<RwLock<IdentityKeys>>,
+}
+
+#[derive(Debug)]
+struct IdentityKeys {
+    /// The key used to dial peers, which is also accepted for inbound handshakes.
+    current: Arc<noise::NoiseConfig>,
+    /// A key that is only accepted for inbound handshakes.
+    other: Option<Arc<noise::NoiseConfig>>,
+}
+
+impl NetworkIdentity {
+    pub fn new(key: x25519::PrivateKey) -> Self {
+        Self {
+            keys: Arc::new(RwLock::new(IdentityKeys {
+                current: Arc::new(noise::NoiseConfig::new(key)),
+                other: None,
+            })),
+        }
+    }
+
+    /// The public key peers are dialed with.
+    pub fn public_key(&self) -> x25519::PublicKey {
+        self.current().public_key()
+    }
+
+    /// Returns true if handshakes addressed to the given public key are answered.
+    pub fn has_key(&self, public_key: &x25519::PublicKey) -> bool {
+        self.find(public_key.as_slice()).is_some()
+    }
+
+    /// Answers inbound handshakes addressed to the given key as well, replacing any other key
+    /// accepted this way. Peers keep being dialed with the current key.
+    pub fn add_key(&self, key: x25519::PrivateKey) {
+        if self.has_key(&key.public_key()) {
+            return;
+        }
+        self.keys.write().unwrap().other = Some(Arc::new(noise::NoiseConfig::new(key)));
+    }
+
+    /// Dials peers with the held key matching the given public key from now on. The key used to
+    /// dial until then is still accepted for inbound handshakes, so peers that have yet to learn
+    /// about the switch can still reach us. Returns false if no such key is held.
+    pub fn use_key(&self, public_key: &x25519::PublicKey) -> bool {
+        let mut keys = self.keys.write().unwrap();
+        if keys.current.public_key() == *public_key {
+            return true;
+        }
+        match keys.other.take() {
+            Some(other) if other.public_key() == *public_key => {
+                let previous = std::mem::replace(&mut keys.current, other);
+                keys.other = Some(previous);
+                true
+            }
+            other => {
+                keys.other = other;
+                false
+            }
+        }
+    }
+
+    fn current(&self) -> Arc<noise::NoiseConfig> {
+        self.keys.read().unwrap().current.clone()
+    }
+
+    fn find(&self, public_key: &[u8]) -> Option<Arc<noise::NoiseConfig>> {
+        let keys = self.keys.read().unwrap();
+        std::iter::once(&keys.current)
+            .chain(keys.other.iter())
+            .find(|config| config.public_key().as_slice() == public_key)
+            .cloned()
+    }
+}
+
+impl From<x25519::PrivateKey> for NetworkIdentity {
+    fn from(key: x25519::PrivateKey) -> Self {
+        Self::new(key)
+    }
+}
+
 /// The Noise configuration to be used to perform a protocol upgrade on an underlying socket.
 pub struct NoiseUpgrader {
     /// The validator's own peer id.
     self_peer_id: PeerId,
-    /// Config for executing Noise handshakes. Includes our static private key.
-    noise_config: noise::NoiseConfig,
+    /// The static keys used for executing Noise handshakes.
+    identity: NetworkIdentity,
     /// Handshake authentication can be either mutual or server-only authentication.
     auth_mode: HandshakeAuthMode,
 }
 
 impl NoiseUpgrader {
-    /// Create a new NoiseConfig with the provided keypair and authentication mode.
-    pub fn new(peer_id: PeerId, key: x25519::PrivateKey, auth_mode: HandshakeAuthMode) -> Self {
+    /// Create a new NoiseConfig with the provided keys and authentication mode.
+    pub fn new(
+        peer_id: PeerId,
+        identity: impl Into<NetworkIdentity>,
+        auth_mode: HandshakeAuthMode,
+    ) -> Self {
         Self {
             self_peer_id: peer_id,
-            noise_config: noise::NoiseConfig::new(key),
+            identity: identity.into(),
             auth_mode,
         }
     }
@@ -235,9 +325,9 @@ impl NoiseUpgrader {
         let payload = time_provider();
 
         // craft first handshake message  (-> e, es, s, ss)
+        let noise_config = self.identity.current();
         let mut rng = rand::rngs::OsRng;
-        let initiator_state = self
-            .noise_config
+        let initiator_state = noise_config
             .initiate_connection(
                 &mut rng,
                 &prologue_msg,
@@ -257,8 +347,7 @@ impl NoiseUpgrader {
 
         // parse the server's response
         // TODO: security logging here? (mimoo)
-        let (_, session) = self
-            .noise_config
+        let (_, session) = noise_config
             .finalize_connection(initiator_state, &server_response)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
@@ -317,22 +406,24 @@ impl NoiseUpgrader {
             ));
         }
 
-        // verify that this is indeed our public key
-        if self_expected_public_key != self.noise_config.public_key().as_slice() {
-            // TODO: security logging (mimoo)
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "noise: client expecting us to have incorrect public key: {}",
-                    hex::encode(self_expected_public_key)
-                ),
-            ));
-        }
+        // verify that this is indeed one of our public keys
+        let noise_config = self
+            .identity
+            .find(self_expected_public_key)
+            .ok_or_else(|| {
+                // TODO: security logging (mimoo)
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "noise: client expecting us to have incorrect public key: {}",
+                        hex::encode(self_expected_public_key)
+                    ),
+                )
+            })?;
 
         // parse it
         let (prologue, client_init_message) = client_message.split_at(Self::PROLOGUE_SIZE);
-        let (remote_public_key, handshake_state, payload) = self
-            .noise_config
+        let (remote_public_key, handshake_state, payload) = noise_config
             .parse_client_init_message(&prologue, &client_init_message)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
@@ -425,8 +516,7 @@ impl NoiseUpgrader {
         // construct the response
         let mut rng = rand::rngs::OsRng;
         let mut server_response = [0u8; Self::SERVER_MESSAGE_SIZE];
-        let session = self
-            .noise_config
+        let session = noise_config
             .respond_to_client(&mut rng, handshake_state, None, &mut server_response)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
 
@@ -617,6 +707,39 @@ mod test {
         test_handshake_self_fails(true /* is_mutual_auth */);
     }
 
+    #[test]
+    fn test_handshake_during_key_rotation() {
+        let ((client, _), (server, old_public_key)) = build_peers(false /* is_mutual_auth */);
+        let mut rng = ::rand::rngs::StdRng::from_seed([1u8; 32]);
+        let new_private_key = x25519::PrivateKey::generate(&mut rng);
+        let new_public_key = new_private_key.public_key();
+
+        // before the server holds the new key, only the old key is answered
+        let (client_res, server_res) = perform_handshake(&client, &server, new_public_key);
+        client_res.unwrap_err();
+        server_res.unwrap_err();
+
+        // while the rotation is in flight, both keys are answered and the old one is dialed with
+        server.identity.add_key(new_private_key);
+        for public_key in &[old_public_key, new_public_key] {
+            let (client_res, server_res) = perform_handshake(&client, &server, *public_key);
+            assert_eq!(client_res.unwrap().get_remote_static(), *public_key);
+            server_res.unwrap();
+        }
+        assert_eq!(server.identity.public_key(), old_public_key);
+
+        // once the new key is used, the old key is still answered
+        assert!(server.identity.use_key(&new_public_key));
+        assert_eq!(server.identity.public_key(), new_public_key);
+        let (client_res, server_res) = perform_handshake(&client, &server, old_public_key);
+        client_res.unwrap();
+        server_res.unwrap();
+
+        let unknown_public_key = x25519::PrivateKey::generate(&mut rng).public_key();
+        assert!(!server.identity.use_key(&unknown_public_key));
+        assert_eq!(server.identity.public_key(), new_public_key);
+    }
+
     #[test]
     fn test_handshake_fragmented_reads() {
         // create an in-memory socket for testing
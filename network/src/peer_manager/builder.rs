@@ -3,7 +3,7 @@
 
 use crate::{
     counters,
-    noise::stream::NoiseStream,
+    noise::{stream::NoiseStream, NetworkIdentity},
     peer_manager::{
         conn_notifs_channel, ConnectionRequest, ConnectionRequestSender, PeerManager,
         PeerManagerNotification, PeerManagerRequest, PeerManagerRequestSender,
@@ -20,9 +20,10 @@ use libra_metrics::IntCounterVec;
 use libra_network_address::NetworkAddress;
 use libra_types::PeerId;
 use netcore::transport::{
+    faulty::{FaultController, FaultySocket, FaultyTransport},
     memory::MemoryTransport,
     tcp::{TcpSocket, TcpTransport},
-    Transport,
+    Transport, TransportExt,
 };
 use std::{
     clone::Clone,
@@ -40,22 +41,22 @@ pub enum AuthenticationMode {
     /// clients/dialers will authenticate the servers/listeners. More specifically,
     /// dialers will pin the connection to a specific, expected pubkey while
     /// listeners will accept any inbound dialer's pubkey.
-    ServerOnly(x25519::PrivateKey),
+    ServerOnly(NetworkIdentity),
     /// Inbound and outbound connections are secured with NoiseIK. Both dialer and
     /// listener will only accept connections that successfully authenticate to a
     /// pubkey in their "trusted peers" set.
-    Mutual(x25519::PrivateKey),
+    Mutual(NetworkIdentity),
 }
 
 impl AuthenticationMode {
     /// Convenience method to retrieve the public key for the auth mode's inner
-    /// network identity key.
+    /// network identity key, i.e., the one peers are dialed with.
     ///
     /// Note: this only works because all auth modes are Noise-based.
     pub fn public_key(&self) -> x25519::PublicKey {
         match self {
-            AuthenticationMode::ServerOnly(key) | AuthenticationMode::Mutual(key) => {
-                key.public_key()
+            AuthenticationMode::ServerOnly(identity) | AuthenticationMode::Mutual(identity) => {
+                identity.public_key()
             }
         }
     }
@@ -67,6 +68,7 @@ struct TransportContext {
     rpc_protocols: Vec<ProtocolId>,
     authentication_mode: AuthenticationMode,
     trusted_peers: Arc<RwLock<HashMap<PeerId, HashSet<x25519::PublicKey>>>>,
+    fault_controller: Option<FaultController>,
 }
 
 impl TransportContext {
@@ -83,6 +85,7 @@ impl TransportContext {
             rpc_protocols,
             authentication_mode,
             trusted_peers,
+            fault_controller: None,
         }
     }
 
@@ -174,6 +177,10 @@ impl PeerManagerContext {
 type MemoryPeerManager =
     PeerManager<LibraNetTransport<MemoryTransport>, NoiseStream<memsocket::MemorySocket>>;
 type TcpPeerManager = PeerManager<LibraNetTransport<TcpTransport>, NoiseStream<TcpSocket>>;
+type FaultyTcpPeerManager = PeerManager<
+    LibraNetTransport<FaultyTransport<TcpTransport>>,
+    NoiseStream<FaultySocket<TcpSocket>>,
+>;
 
 #[derive(Debug, PartialEq, PartialOrd)]
 enum State {
@@ -190,6 +197,7 @@ pub struct PeerManagerBuilder {
     // An option to ensure at most one copy of the contained private key.
     memory_peer_manager: Option<MemoryPeerManager>,
     tcp_peer_manager: Option<TcpPeerManager>,
+    faulty_tcp_peer_manager: Option<FaultyTcpPeerManager>,
     // ListenAddress will be updated when the PeerManager is built
     listen_address: NetworkAddress,
     state: State,
@@ -242,6 +250,7 @@ impl PeerManagerBuilder {
             )),
             memory_peer_manager: None,
             tcp_peer_manager: None,
+            faulty_tcp_peer_manager: None,
             listen_address,
             state: State::CREATED,
         }
@@ -266,6 +275,16 @@ impl PeerManagerBuilder {
             .add_connection_event_listener()
     }
 
+    /// Inject the faults of `controller` in the connections of this network. Only supported on
+    /// TCP networks.
+    pub fn add_fault_injection(&mut self, controller: FaultController) -> &mut Self {
+        self.transport_context
+            .as_mut()
+            .expect("Cannot add fault injection once PeerManager has been built")
+            .fault_controller = Some(controller);
+        self
+    }
+
     /// Create the configured transport and start PeerManager.
     /// Return the actual NetworkAddress over which this peer is listening.
     pub fn build(&mut self, executor: &Handle) -> &mut Self {
@@ -298,23 +317,46 @@ impl PeerManagerBuilder {
             }
         };
 
+        let fault_controller = transport_context.fault_controller;
         match self.listen_address.as_slice() {
-            [Ip4(_), Tcp(_)] | [Ip6(_), Tcp(_)] => {
-                self.tcp_peer_manager = Some(self.build_with_transport(
-                    LibraNetTransport::new(
-                        LIBRA_TCP_TRANSPORT.clone(),
-                        peer_id,
-                        key,
-                        maybe_trusted_peers,
-                        HANDSHAKE_VERSION,
-                        chain_id,
-                        network_id,
-                        protos,
-                    ),
-                    executor,
-                ))
-            }
+            [Ip4(_), Tcp(_)] | [Ip6(_), Tcp(_)] => match fault_controller {
+                Some(controller) => {
+                    self.faulty_tcp_peer_manager = Some(self.build_with_transport(
+                        LibraNetTransport::new(
+                            LIBRA_TCP_TRANSPORT.clone().with_faults(controller),
+                            peer_id,
+                            key,
+                            maybe_trusted_peers,
+                            HANDSHAKE_VERSION,
+                            chain_id,
+                            network_id,
+                            protos,
+                        ),
+                        executor,
+                    ))
+                }
+                None => {
+                    self.tcp_peer_manager = Some(self.build_with_transport(
+                        LibraNetTransport::new(
+                            LIBRA_TCP_TRANSPORT.clone(),
+                            peer_id,
+                            key,
+                            maybe_trusted_peers,
+                            HANDSHAKE_VERSION,
+                            chain_id,
+                            network_id,
+                            protos,
+                        ),
+                        executor,
+                    ))
+                }
+            },
             [Memory(_)] => {
+                assert!(
+                    fault_controller.is_none(),
+                    "{} Fault injection is only supported on TCP networks",
+                    self.network_context
+                );
                 self.memory_peer_manager = Some(self.build_with_transport(
                     LibraNetTransport::new(
                         MemoryTransport,
@@ -399,6 +441,9 @@ impl PeerManagerBuilder {
         if let Some(tcp_pm) = self.tcp_peer_manager.take() {
             self.start_peer_manager(tcp_pm, executor);
         }
+        if let Some(faulty_tcp_pm) = self.faulty_tcp_peer_manager.take() {
+            self.start_peer_manager(faulty_tcp_pm, executor);
+        }
     }
 
     /// Add a handler for given protocols using raw bytes.
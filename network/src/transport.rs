@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    noise::{stream::NoiseStream, AntiReplayTimestamps, HandshakeAuthMode, NoiseUpgrader},
+    noise::{
+        stream::NoiseStream, AntiReplayTimestamps, HandshakeAuthMode, NetworkIdentity,
+        NoiseUpgrader,
+    },
     protocols::{
         identity::exchange_handshake,
         wire::handshake::v1::{HandshakeMsg, MessagingProtocolVersion, SupportedProtocols},
@@ -281,7 +284,7 @@ where
     pub fn new(
         base_transport: TTransport,
         self_peer_id: PeerId,
-        identity_key: x25519::PrivateKey,
+        identity: impl Into<NetworkIdentity>,
         trusted_peers: Option<Arc<RwLock<HashMap<PeerId, HashSet<x25519::PublicKey>>>>>,
         handshake_version: u8,
         chain_id: ChainId,
@@ -290,7 +293,8 @@ where
     ) -> Self {
         let mut own_handshake = HandshakeMsg::new(chain_id, network_id);
         own_handshake.add(SUPPORTED_MESSAGING_PROTOCOL, application_protocols);
-        let identity_pubkey = identity_key.public_key();
+        let identity = identity.into();
+        let identity_pubkey = identity.public_key();
 
         let auth_mode = match trusted_peers.as_ref() {
             Some(trusted_peers) => HandshakeAuthMode::mutual(trusted_peers.clone()),
@@ -299,7 +303,7 @@ where
 
         Self {
             ctxt: Arc::new(UpgradeContext {
-                noise: NoiseUpgrader::new(self_peer_id, identity_key, auth_mode),
+                noise: NoiseUpgrader::new(self_peer_id, identity, auth_mode),
                 handshake_version,
                 own_handshake,
             }),
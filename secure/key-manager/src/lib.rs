@@ -1,11 +1,12 @@
 // Copyright (c) The Libra Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-//! The purpose of KeyManager is to rotate consensus key (and eventually the network key). It is not
-//! responsible for generating the first key and fails if the stores have not been properly setup.
-//! During rotation, it first updates the local store, then submits a transaction to rotate to the
-//! new key. After some period of time and upon restarts of the process, it will evaluate the
-//! current status of the system including:
+//! The purpose of KeyManager is to rotate the consensus key and keep the validator network key
+//! registered on-chain in sync with storage. It is not responsible for generating the first keys
+//! and fails if the stores have not been properly setup. The validator network key is only managed
+//! if it is present in storage. During rotation, it first updates the local store, then submits a
+//! transaction to rotate to the new key. After some period of time and upon restarts of the
+//! process, it will evaluate the current status of the system including:
 //! * last rotation time, and rotate if it is too long ago
 //! * if the latest key in the store matches the latest key in the ValidatorConfig, upon mismatch
 //! it will try to submit a transaction to update the ValidatorConfig to the current key in the
@@ -14,6 +15,16 @@
 //! evaluates the current time from the last reconfiguration and logs that delta with greater
 //! levels of severity depending on the delta.
 //!
+//! The validator network key is only rotated periodically if a network rotation period is
+//! configured. Rotating it, or finding that the network key in storage differs from the one
+//! registered on-chain, submits a dedicated transaction that registers the storage key and
+//! replaces the NoiseIK public key embedded in the validator network address. Peers keep
+//! authenticating the old key until a reconfiguration propagates the new key to the ValidatorSet,
+//! and KeyManager does not touch the network key again until then. KeyManager records the public
+//! key it rotated away from in storage, so that the node can keep answering handshakes with the
+//! old key, including across restarts, and switch to dialing with the new key once the
+//! reconfiguration lands.
+//!
 //! KeyManager talks to Libra via the LibraInterface that may either be a direct link into
 //! `LibraDB`/`Executor`, JSON-RPC, or some other concoction.
 //! KeyManager talks to its own storage through the `LibraSecureStorage::Storage trait.
@@ -21,17 +32,21 @@
 
 use crate::{counters::COUNTERS, libra_interface::LibraInterface};
 use libra_crypto::{ed25519::Ed25519PublicKey, x25519};
-use libra_global_constants::{CONSENSUS_KEY, OPERATOR_ACCOUNT, OPERATOR_KEY};
+use libra_global_constants::{
+    CONSENSUS_KEY, OPERATOR_ACCOUNT, OPERATOR_KEY, VALIDATOR_NETWORK_KEY,
+    VALIDATOR_NETWORK_PREVIOUS_KEY,
+};
 use libra_logger::{error, info};
-use libra_network_address::RawNetworkAddress;
-use libra_secure_storage::{CryptoStorage, KVStorage};
+use libra_network_address::{NetworkAddress, RawNetworkAddress};
+use libra_secure_storage::{CryptoStorage, KVStorage, Value};
 use libra_secure_time::TimeService;
 use libra_types::{
     account_address::AccountAddress,
     account_config::LBR_NAME,
     transaction::{RawTransaction, Script, SignedTransaction, Transaction, TransactionArgument},
+    validator_config::ValidatorConfig,
 };
-use std::{str::FromStr, time::Duration};
+use std::{convert::TryFrom, str::FromStr, time::Duration};
 use thiserror::Error;
 
 pub mod counters;
@@ -50,21 +65,30 @@ pub enum Action {
     NoAction,
     /// The system is in a healthy state but sufficient time has passed for another key rotation
     FullKeyRotation,
+    /// The system is in a healthy state but sufficient time has passed for another network key
+    /// rotation
+    FullNetworkKeyRotation,
     /// Storage and the blockchain are inconsistent, submit a new rotation
     SubmitKeyRotationTransaction,
+    /// Storage and the blockchain are inconsistent for the network key, submit a new rotation
+    SubmitNetworkKeyRotationTransaction,
 }
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Error, PartialEq)]
 pub enum Error {
-    #[error("Key mismatch, config: {0}, info: {0}")]
+    #[error("Key mismatch, config: {0}, info: {1}")]
     ConfigInfoKeyMismatch(Ed25519PublicKey, Ed25519PublicKey),
-    #[error("Key mismatch, config: {0}, storage: {0}")]
+    #[error("Key mismatch, config: {0}, storage: {1}")]
     ConfigStorageKeyMismatch(Ed25519PublicKey, Ed25519PublicKey),
+    #[error("Network key mismatch, config: {0}, info: {1}")]
+    ConfigInfoNetworkKeyMismatch(x25519::PublicKey, x25519::PublicKey),
+    #[error("Network key mismatch, config: {0}, storage: {1}")]
+    ConfigStorageNetworkKeyMismatch(x25519::PublicKey, x25519::PublicKey),
     #[error("Data does not exist: {0}")]
     DataDoesNotExist(String),
     #[error(
-        "The libra_timestamp value on-chain isn't increasing. Last value: {0}, Current value: {0}"
+        "The libra_timestamp value on-chain isn't increasing. Last value: {0}, Current value: {1}"
     )]
     LivenessError(u64, u64),
     #[error("Unable to retrieve the operator account address. Storage error: {0}")]
@@ -88,6 +112,7 @@ pub struct KeyManager<LI, S, T> {
     time_service: T,
     last_checked_libra_timestamp: u64,
     rotation_period_secs: u64, // The frequency by which to rotate all keys
+    network_rotation_period_secs: Option<u64>, // The frequency by which to rotate the network key
     sleep_period_secs: u64,    // The amount of time to sleep between key management checks
     txn_expiration_secs: u64,  // The time after which a rotation transaction expires
 }
@@ -103,6 +128,7 @@ where
         storage: S,
         time_service: T,
         rotation_period_secs: u64,
+        network_rotation_period_secs: Option<u64>,
         sleep_period_secs: u64,
         txn_expiration_secs: u64,
     ) -> Self {
@@ -112,6 +138,7 @@ where
             time_service,
             last_checked_libra_timestamp: 0,
             rotation_period_secs,
+            network_rotation_period_secs,
             sleep_period_secs,
             txn_expiration_secs,
         }
@@ -183,6 +210,37 @@ where
         Err(Error::ConfigInfoKeyMismatch(config_key, info_key.clone()))
     }
 
+    pub fn compare_network_storage_to_config(&self) -> Result<(), Error> {
+        let storage_key = match self.network_key_from_storage()? {
+            Some(storage_key) => storage_key,
+            None => return Ok(()),
+        };
+        let operator_account = self.get_operator_account()?;
+        let validator_config = self.libra.retrieve_validator_config(operator_account)?;
+        let config_key = validator_config.validator_network_identity_public_key;
+
+        if storage_key == config_key {
+            return Ok(());
+        }
+        Err(Error::ConfigStorageNetworkKeyMismatch(
+            config_key,
+            storage_key,
+        ))
+    }
+
+    pub fn compare_network_info_to_config(&self) -> Result<(), Error> {
+        let operator_account = self.get_operator_account()?;
+        let validator_info = self.libra.retrieve_validator_info(operator_account)?;
+        let info_key = validator_info.network_identity_public_key();
+        let validator_config = self.libra.retrieve_validator_config(operator_account)?;
+        let config_key = validator_config.validator_network_identity_public_key;
+
+        if config_key == info_key {
+            return Ok(());
+        }
+        Err(Error::ConfigInfoNetworkKeyMismatch(config_key, info_key))
+    }
+
     pub fn last_reconfiguration(&self) -> Result<u64, Error> {
         // Convert the time to seconds
         Ok(self.libra.last_reconfiguration()? / 1_000_000)
@@ -192,6 +250,13 @@ where
         Ok(self.storage.get_public_key(CONSENSUS_KEY)?.last_update)
     }
 
+    pub fn last_network_rotation(&self) -> Result<u64, Error> {
+        Ok(self
+            .storage
+            .get_public_key(VALIDATOR_NETWORK_KEY)?
+            .last_update)
+    }
+
     pub fn libra_timestamp(&self) -> Result<u64, Error> {
         // Convert the time to seconds
         Ok(self.libra.libra_timestamp()? / 1_000_000)
@@ -211,29 +276,83 @@ where
         self.submit_key_rotation_transaction(consensus_key)
     }
 
+    pub fn resubmit_network_key_transaction(&mut self) -> Result<(), Error> {
+        let network_key = to_x25519(
+            &self
+                .storage
+                .get_public_key(VALIDATOR_NETWORK_KEY)?
+                .public_key,
+        )?;
+        COUNTERS.network_rotation_tx_resubmissions.inc();
+        self.submit_network_key_rotation_transaction(network_key)
+            .map(|_| ())
+    }
+
+    /// Rotates the validator network key in storage and registers the new key on-chain. The key
+    /// being replaced is recorded, so the node keeps authenticating with it until a
+    /// reconfiguration has propagated the new key to the ValidatorSet.
+    pub fn rotate_network_key(&mut self) -> Result<x25519::PublicKey, Error> {
+        let previous_key = self
+            .storage
+            .get_public_key(VALIDATOR_NETWORK_KEY)?
+            .public_key;
+        self.storage.set(
+            VALIDATOR_NETWORK_PREVIOUS_KEY,
+            Value::Ed25519PublicKey(previous_key),
+        )?;
+        let network_key = to_x25519(&self.storage.rotate_key(VALIDATOR_NETWORK_KEY)?)?;
+        info!("Successfully rotated the validator network key in secure storage.");
+        COUNTERS.completed_network_key_rotations.inc();
+        self.submit_network_key_rotation_transaction(network_key)
+    }
+
+    /// Submits a transaction updating the ValidatorConfig to the given consensus key. The network
+    /// information registered on-chain is left untouched.
     pub fn submit_key_rotation_transaction(
         &mut self,
         consensus_key: Ed25519PublicKey,
     ) -> Result<Ed25519PublicKey, Error> {
         let operator_account = self.get_operator_account()?;
-        let seq_id = self.libra.retrieve_sequence_number(operator_account)?;
-        let expiration = Duration::from_secs(self.time_service.now() + self.txn_expiration_secs);
+        let mut validator_config = self.libra.retrieve_validator_config(operator_account)?;
+        validator_config.consensus_public_key = consensus_key.clone();
+        self.submit_validator_config_transaction(operator_account, validator_config)?;
+        Ok(consensus_key)
+    }
 
-        // Retrieve existing network information as registered on-chain
-        let validator_config = self.libra.retrieve_validator_config(operator_account)?;
-        let network_key = validator_config.validator_network_identity_public_key;
-        let network_address = validator_config.validator_network_address;
-        let fullnode_network_key = validator_config.full_node_network_identity_public_key;
-        let fullnode_network_address = validator_config.full_node_network_address;
+    /// Submits a transaction updating the ValidatorConfig to the given validator network key and
+    /// replacing the NoiseIK public key in the validator network address. The consensus key
+    /// registered on-chain is left untouched.
+    pub fn submit_network_key_rotation_transaction(
+        &mut self,
+        network_key: x25519::PublicKey,
+    ) -> Result<x25519::PublicKey, Error> {
+        let operator_account = self.get_operator_account()?;
+        let mut validator_config = self.libra.retrieve_validator_config(operator_account)?;
+        validator_config.validator_network_address = rotate_network_address(
+            &validator_config.validator_network_address,
+            &validator_config.validator_network_identity_public_key,
+            &network_key,
+        )?;
+        validator_config.validator_network_identity_public_key = network_key;
+        self.submit_validator_config_transaction(operator_account, validator_config)?;
+        Ok(network_key)
+    }
 
+    fn submit_validator_config_transaction(
+        &mut self,
+        operator_account: AccountAddress,
+        validator_config: ValidatorConfig,
+    ) -> Result<(), Error> {
+        let seq_id = self.libra.retrieve_sequence_number(operator_account)?;
+        let expiration = Duration::from_secs(self.time_service.now() + self.txn_expiration_secs);
         let txn = build_rotation_transaction(
             operator_account,
             seq_id,
-            &consensus_key,
-            &network_key,
-            &network_address,
-            &fullnode_network_key,
-            &fullnode_network_address,
+            &validator_config.consensus_public_key,
+            &validator_config.validator_network_identity_public_key,
+            &validator_config.validator_network_address,
+            &validator_config.full_node_network_identity_public_key,
+            &validator_config.full_node_network_address,
             expiration,
         );
 
@@ -244,8 +363,7 @@ where
         self.libra
             .submit_transaction(Transaction::UserTransaction(signed_txn))?;
         info!("Submitted the rotation transaction to the blockchain.");
-
-        Ok(consensus_key)
+        Ok(())
     }
 
     /// Ensures that the libra_timestamp() value registered on-chain is strictly monotonically
@@ -291,9 +409,43 @@ where
         }
 
         if last_rotation + self.rotation_period_secs <= self.time_service.now() {
-            Ok(Action::FullKeyRotation)
-        } else {
-            Ok(Action::NoAction)
+            return Ok(Action::FullKeyRotation);
+        }
+
+        if self.network_key_from_storage()?.is_some() {
+            return self.evaluate_network_status();
+        }
+        Ok(Action::NoAction)
+    }
+
+    /// Evaluates the status of the validator network key. This mirrors the consensus key checks
+    /// performed by evaluate_status, except that the network key is only rotated periodically if
+    /// a network rotation period is configured.
+    fn evaluate_network_status(&self) -> Result<Action, Error> {
+        // Until the new key reaches the ValidatorSet, peers only know the old key, so hold off.
+        if let Err(Error::ConfigInfoNetworkKeyMismatch(..)) = self.compare_network_info_to_config()
+        {
+            COUNTERS.waiting_on_network_reconfiguration.inc();
+            return Ok(Action::NoAction);
+        }
+
+        let last_rotation = self.last_network_rotation()?;
+
+        if let Err(Error::ConfigStorageNetworkKeyMismatch(..)) =
+            self.compare_network_storage_to_config()
+        {
+            return if last_rotation + self.txn_expiration_secs <= self.time_service.now() {
+                Ok(Action::SubmitNetworkKeyRotationTransaction)
+            } else {
+                Ok(Action::NoAction)
+            };
+        }
+
+        match self.network_rotation_period_secs {
+            Some(period) if last_rotation + period <= self.time_service.now() => {
+                Ok(Action::FullNetworkKeyRotation)
+            }
+            _ => Ok(Action::NoAction),
         }
     }
 
@@ -303,10 +455,18 @@ where
                 info!("A full consensus key rotation needs to be performed.");
                 self.rotate_consensus_key().map(|_| ())
             }
+            Action::FullNetworkKeyRotation => {
+                info!("A full network key rotation needs to be performed.");
+                self.rotate_network_key().map(|_| ())
+            }
             Action::SubmitKeyRotationTransaction => {
                 info!("The consensus key rotation transaction needs to be resubmitted");
                 self.resubmit_consensus_key_transaction()
             }
+            Action::SubmitNetworkKeyRotationTransaction => {
+                info!("The network key rotation transaction needs to be resubmitted");
+                self.resubmit_network_key_transaction()
+            }
             Action::NoAction => {
                 info!("No actions need to be performed.");
                 COUNTERS.no_actions_required.inc();
@@ -315,6 +475,16 @@ where
         }
     }
 
+    /// Returns the validator network key held in storage, or None if storage does not hold one, in
+    /// which case network key rotation is disabled.
+    fn network_key_from_storage(&self) -> Result<Option<x25519::PublicKey>, Error> {
+        match self.storage.get_public_key(VALIDATOR_NETWORK_KEY) {
+            Ok(response) => to_x25519(&response.public_key).map(Some),
+            Err(libra_secure_storage::Error::KeyNotSet(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn get_operator_account(&self) -> Result<AccountAddress, Error> {
         match self
             .storage
@@ -328,6 +498,23 @@ where
     }
 }
 
+fn to_x25519(key: &Ed25519PublicKey) -> Result<x25519::PublicKey, Error> {
+    x25519::PublicKey::from_ed25519_public_bytes(&key.to_bytes())
+        .map_err(|e| Error::UnknownError(e.to_string()))
+}
+
+/// Replaces the NoiseIK public key within the given network address.
+fn rotate_network_address(
+    network_address: &RawNetworkAddress,
+    old_key: &x25519::PublicKey,
+    new_key: &x25519::PublicKey,
+) -> Result<RawNetworkAddress, Error> {
+    let mut network_address = NetworkAddress::try_from(network_address)
+        .map_err(|e| Error::UnknownError(e.to_string()))?;
+    network_address.rotate_noise_public_key(old_key, new_key);
+    RawNetworkAddress::try_from(&network_address).map_err(|e| Error::UnknownError(e.to_string()))
+}
+
 pub fn build_rotation_transaction(
     sender: AccountAddress,
     seq_id: u64,
@@ -58,6 +58,7 @@ fn create_and_execute_key_manager(key_manager_config: KeyManagerConfig) -> Resul
         storage,
         time_service,
         key_manager_config.rotation_period_secs,
+        key_manager_config.network_rotation_period_secs,
         key_manager_config.sleep_period_secs,
         key_manager_config.txn_expiration_secs,
     )
@@ -15,9 +15,9 @@ use libra_config::{
     utils::get_genesis_txn,
 };
 use libra_crypto::{ed25519::Ed25519PrivateKey, x25519, HashValue, PrivateKey, Uniform};
-use libra_global_constants::{OPERATOR_ACCOUNT, OPERATOR_KEY};
+use libra_global_constants::{OPERATOR_ACCOUNT, OPERATOR_KEY, VALIDATOR_NETWORK_KEY};
 use libra_network_address::RawNetworkAddress;
-use libra_secure_storage::{InMemoryStorageInternal, KVStorage, Value};
+use libra_secure_storage::{CryptoStorage, InMemoryStorageInternal, KVStorage, Value};
 use libra_secure_time::{MockTimeService, TimeService};
 use libra_types::{
     account_address::AccountAddress,
@@ -369,6 +369,7 @@ fn setup_node<T: LibraInterface + Clone>(
         storage,
         time.clone(),
         key_manager_config.rotation_period_secs,
+        key_manager_config.network_rotation_period_secs,
         key_manager_config.sleep_period_secs,
         key_manager_config.txn_expiration_secs,
     );
@@ -705,6 +706,200 @@ fn verify_execute_error<T: LibraInterface>(mut node: Node<T>) {
     assert!(node.key_manager.execute().is_err());
 }
 
+#[test]
+// This tests that the validator network key held in storage is registered on-chain, that the key
+// manager waits for a reconfiguration before considering it again, and that it does not rotate the
+// network key on its own without a network rotation period.
+fn test_network_key_rotation() {
+    // Test the mock libra interface implementation
+    let node = setup_node_using_test_mocks();
+    verify_network_key_rotation(node);
+
+    // Test the json libra interface implementation
+    let (node, _runtime) = setup_node_using_json_rpc();
+    verify_network_key_rotation(node);
+}
+
+fn verify_network_key_rotation<T: LibraInterface>(mut node: Node<T>) {
+    let (_, key_manager_config) = get_test_configs();
+
+    // Verify the network key is not managed while storage does not hold one
+    node.update_libra_timestamp();
+    node.key_manager
+        .compare_network_storage_to_config()
+        .unwrap();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+
+    // Add a network key to storage that differs from the genesis key
+    let storage_key = node
+        .key_manager
+        .storage
+        .create_key(VALIDATOR_NETWORK_KEY)
+        .unwrap();
+    let storage_key =
+        x25519::PublicKey::from_ed25519_public_bytes(&storage_key.to_bytes()).unwrap();
+    node.key_manager
+        .compare_network_storage_to_config()
+        .unwrap_err();
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+
+    // Verify the mismatch is resolved by submitting a transaction once enough time has passed
+    node.time
+        .increment_by(key_manager_config.txn_expiration_secs);
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::SubmitNetworkKeyRotationTransaction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+    node.update_libra_timestamp();
+    node.key_manager.execute_once().unwrap();
+    node.execute_and_commit(node.libra.take_all_transactions());
+    node.key_manager
+        .compare_network_storage_to_config()
+        .unwrap();
+
+    // The validator set retains the old key until a reconfiguration
+    node.key_manager
+        .compare_network_info_to_config()
+        .unwrap_err();
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+    submit_reconfiguration_transaction(&node);
+    node.execute_and_commit(node.libra.take_all_transactions());
+    node.key_manager.compare_network_info_to_config().unwrap();
+    let info = node.libra.retrieve_validator_info(node.account).unwrap();
+    assert_eq!(storage_key, info.network_identity_public_key());
+
+    // Verify the consensus key rotation leaves the network key alone and that the network key is
+    // not rotated periodically by default
+    node.time
+        .increment_by(key_manager_config.rotation_period_secs);
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::FullKeyRotation,
+        node.key_manager.evaluate_status().unwrap()
+    );
+    node.update_libra_timestamp();
+    node.key_manager.execute_once().unwrap();
+    submit_reconfiguration_transaction(&node);
+    node.execute_and_commit(node.libra.take_all_transactions());
+
+    let info = node.libra.retrieve_validator_info(node.account).unwrap();
+    assert_eq!(storage_key, info.network_identity_public_key());
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+
+    // Verify an explicit network key rotation only touches the network key
+    let consensus_key = info.consensus_public_key().clone();
+    let new_key = node.key_manager.rotate_network_key().unwrap();
+    assert_ne!(storage_key, new_key);
+    submit_reconfiguration_transaction(&node);
+    node.execute_and_commit(node.libra.take_all_transactions());
+
+    let info = node.libra.retrieve_validator_info(node.account).unwrap();
+    assert_eq!(new_key, info.network_identity_public_key());
+    assert_eq!(&consensus_key, info.consensus_public_key());
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+}
+
+#[test]
+// This tests that the execution loop rotates the validator network key once the network rotation
+// period has passed, and that the old key stays in the validator set, and is not rotated again,
+// until a reconfiguration propagates the new key.
+fn test_periodic_network_key_rotation() {
+    // Test the mock libra interface implementation
+    let node = setup_node_using_test_mocks();
+    verify_periodic_network_key_rotation(node);
+
+    // Test the json libra interface implementation
+    let (node, _runtime) = setup_node_using_json_rpc();
+    verify_periodic_network_key_rotation(node);
+}
+
+fn verify_periodic_network_key_rotation<T: LibraInterface>(mut node: Node<T>) {
+    let (_, key_manager_config) = get_test_configs();
+    let network_rotation_period_secs = 2 * key_manager_config.txn_expiration_secs;
+    node.key_manager.network_rotation_period_secs = Some(network_rotation_period_secs);
+
+    // Register a network key held in storage on-chain
+    node.key_manager
+        .storage
+        .create_key(VALIDATOR_NETWORK_KEY)
+        .unwrap();
+    node.time
+        .increment_by(key_manager_config.txn_expiration_secs);
+    node.update_libra_timestamp();
+    node.key_manager.execute_once().unwrap();
+    submit_reconfiguration_transaction(&node);
+    node.execute_and_commit(node.libra.take_all_transactions());
+    let old_key = node
+        .libra
+        .retrieve_validator_info(node.account)
+        .unwrap()
+        .network_identity_public_key();
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::NoAction,
+        node.key_manager.evaluate_status().unwrap()
+    );
+
+    // Verify a single execution iteration rotates the network key once the period has passed
+    node.time.increment_by(network_rotation_period_secs);
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::FullNetworkKeyRotation,
+        node.key_manager.evaluate_status().unwrap()
+    );
+    node.update_libra_timestamp();
+    node.key_manager.execute_once().unwrap();
+    node.execute_and_commit(node.libra.take_all_transactions());
+    let new_key = node
+        .libra
+        .retrieve_validator_config(node.account)
+        .unwrap()
+        .validator_network_identity_public_key;
+    assert_ne!(old_key, new_key);
+
+    // Until a reconfiguration, the validator set keeps the old key and the key manager waits,
+    // even once another period has passed
+    node.time.increment_by(network_rotation_period_secs);
+    for _ in 0..2 {
+        node.update_libra_timestamp();
+        node.key_manager.execute_once().unwrap();
+    }
+    assert!(node.libra.take_all_transactions().is_empty());
+    let info = node.libra.retrieve_validator_info(node.account).unwrap();
+    assert_eq!(old_key, info.network_identity_public_key());
+
+    // The reconfiguration propagates the new key to the validator set
+    submit_reconfiguration_transaction(&node);
+    node.execute_and_commit(node.libra.take_all_transactions());
+    let info = node.libra.retrieve_validator_info(node.account).unwrap();
+    assert_eq!(new_key, info.network_identity_public_key());
+    node.update_libra_timestamp();
+    assert_eq!(
+        Action::FullNetworkKeyRotation,
+        node.key_manager.evaluate_status().unwrap()
+    );
+}
+
 // Creates and submits a reconfiguration transaction to the given libra interface.
 fn submit_reconfiguration_transaction<T: LibraInterface>(node: &Node<T>) {
     let association_prikey = get_test_association_key();
@@ -14,15 +14,50 @@
 //!
 //! Internally both the client and server leverage a NetworkStream that communications in blocks
 //! where a block is a length prefixed array of bytes.
+//!
+//! Optionally, the client and server can mutually authenticate one another and encrypt all traffic
+//! using the Noise IK handshake. The client pins the server's static public key and the server only
+//! accepts clients whose static public keys are within its set of trusted peers. Each message is
+//! then sent as a sequence of encrypted blocks. Clients may also specify a timeout, after which
+//! connection attempts, reads and writes fail and the stream is reset. Servers may specify a
+//! timeout bounding handshakes and the transfer of a message once it has started. Handshakes are
+//! always bounded, by default by `DEFAULT_HANDSHAKE_TIMEOUT`: the timeout is a deadline for the
+//! whole handshake rather than for each read, and a handshake message larger than the Noise
+//! handshake is rejected before it is buffered.
+//!
+//! A server only serves one client at a time. Connections are accepted and their handshakes
+//! performed on threads separate from the one serving requests, so an unauthenticated client that
+//! stalls its handshake neither delays requests from the current client nor the handshake of
+//! another client. While waiting for the next request, the server lets a newly established client
+//! replace the current one, so a client that went away without closing its connection cannot
+//! prevent its successor from connecting.
 
-use libra_logger::{debug, trace};
+use libra_crypto::{noise, x25519};
+use libra_logger::{debug, trace, warn};
 use std::{
-    io::{Read, Write},
-    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
-    thread, time,
+    io::{ErrorKind, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{self, Duration, Instant},
 };
 use thiserror::Error;
 
+/// Binds the Noise handshake to this protocol.
+const NOISE_PROLOGUE: &[u8] = b"libra-secure-net";
+/// Each encrypted block carries a single byte flag denoting if it is the final block of a message.
+const MAX_NOISE_CHUNK_SIZE: usize = noise::MAX_SIZE_NOISE_MSG - noise::AES_GCM_TAGLEN - 1;
+/// How often a server waiting for a request checks for a new client.
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Bounds the Noise handshake of a server that does not specify a timeout.
+pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Bounds the number of clients a server performs a Noise handshake with at the same time.
+const MAX_PENDING_HANDSHAKES: usize = 32;
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Already called shutdown")]
@@ -33,13 +68,21 @@ pub enum Error {
     NetworkError(#[from] std::io::Error),
     #[error("No active stream")]
     NoActiveStream,
+    #[error("Noise error: {0}")]
+    NoiseError(#[from] noise::NoiseError),
     #[error("Remote stream cleanly closed")]
     RemoteStreamClosed,
+    #[error("Timed out connecting to: {0}")]
+    Timeout(SocketAddr),
+    #[error("Remote peer is not trusted: {0}")]
+    UntrustedPeer(x25519::PublicKey),
 }
 
 pub struct NetworkClient {
     server: SocketAddr,
     stream: Option<NetworkStream>,
+    noise: Option<(noise::NoiseConfig, x25519::PublicKey)>,
+    timeout: Option<Duration>,
 }
 
 impl NetworkClient {
@@ -47,9 +90,33 @@ impl NetworkClient {
         Self {
             server,
             stream: None,
+            noise: None,
+            timeout: None,
+        }
+    }
+
+    /// Creates a client that authenticates itself using the provided private key and only
+    /// communicates with a server in possession of the private key for server_public_key.
+    pub fn new_with_noise(
+        server: SocketAddr,
+        private_key: x25519::PrivateKey,
+        server_public_key: x25519::PublicKey,
+    ) -> Self {
+        Self {
+            server,
+            stream: None,
+            noise: Some((noise::NoiseConfig::new(private_key), server_public_key)),
+            timeout: None,
         }
     }
 
+    /// Bounds the time spent on connecting, reading and writing. Upon expiration, the operation
+    /// returns an error and the stream is reset.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Blocking read until able to successfully read an entire message
     pub fn read(&mut self) -> Result<Vec<u8>, Error> {
         let stream = self.server()?;
@@ -80,21 +147,41 @@ impl NetworkClient {
         result
     }
 
+    /// Connects to the server, if not already connected, without sending any data. Unlike a
+    /// failed write, a failed connection attempt guarantees that the server has not received
+    /// anything.
+    pub fn connect(&mut self) -> Result<(), Error> {
+        self.server().map(|_| ())
+    }
+
     fn server(&mut self) -> Result<&mut NetworkStream, Error> {
         if self.stream.is_none() {
             debug!("Attempting to connect to upstream {}", self.server);
+            let start = Instant::now();
             let mut stream = TcpStream::connect(self.server);
 
             let sleeptime = time::Duration::from_millis(100);
             while let Err(e) = stream {
                 debug!("Failed to connect to upstream {} {:?}", self.server, e);
+                if let Some(timeout) = self.timeout {
+                    if start.elapsed() >= timeout {
+                        return Err(Error::Timeout(self.server));
+                    }
+                }
                 thread::sleep(sleeptime);
                 stream = TcpStream::connect(self.server);
             }
 
             let stream = stream?;
             stream.set_nodelay(true)?;
-            self.stream = Some(NetworkStream::new(stream));
+            stream.set_read_timeout(self.timeout)?;
+            stream.set_write_timeout(self.timeout)?;
+            let mut stream = NetworkStream::new(stream);
+            if let Some((config, server_public_key)) = &self.noise {
+                stream.initiate_noise_handshake(config, *server_public_key)?;
+                debug!("Noise handshake completed with upstream {}", self.server);
+            }
+            self.stream = Some(stream);
             debug!("Connection established to upstream {}", self.server);
         }
 
@@ -103,8 +190,13 @@ impl NetworkClient {
 }
 
 pub struct NetworkServer {
+    /// Held until the first client is awaited, at which point it moves to the acceptor.
     listener: Option<TcpListener>,
+    acceptor: Option<Acceptor>,
     stream: Option<NetworkStream>,
+    noise: Option<(noise::NoiseConfig, Vec<x25519::PublicKey>)>,
+    timeout: Option<Duration>,
+    handshake_timeout: Duration,
 }
 
 impl NetworkServer {
@@ -112,14 +204,46 @@ impl NetworkServer {
         let listener = TcpListener::bind(listen);
         Self {
             listener: Some(listener.unwrap()),
+            acceptor: None,
             stream: None,
+            noise: None,
+            timeout: None,
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
         }
     }
 
+    /// Creates a server that authenticates itself using the provided private key and only accepts
+    /// clients in possession of a private key for one of the trusted_peers.
+    pub fn new_with_noise(
+        listen: SocketAddr,
+        private_key: x25519::PrivateKey,
+        trusted_peers: Vec<x25519::PublicKey>,
+    ) -> Self {
+        let mut server = Self::new(listen);
+        server.noise = Some((noise::NoiseConfig::new(private_key), trusted_peers));
+        server
+    }
+
+    /// Bounds the time spent on a handshake and on reading or writing a message once it has
+    /// started. Upon expiration, the operation returns an error and the stream is reset. An idle
+    /// client is not timed out, but is replaced as soon as another client connects.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.handshake_timeout = timeout;
+        self
+    }
+
+    /// Bounds the time spent on a handshake only. Upon expiration, the client is disconnected.
+    pub fn with_handshake_timeout(mut self, timeout: Duration) -> Self {
+        self.handshake_timeout = timeout;
+        self
+    }
+
     /// If there isn't already a downstream client, it accepts. Otherwise it
     /// blocks until able to successfully read an entire message
     pub fn read(&mut self) -> Result<Vec<u8>, Error> {
-        let stream = self.client()?;
+        self.wait_for_request()?;
+        let stream = self.stream.as_mut().ok_or_else(|| Error::NoActiveStream)?;
         let result = stream.read();
         if result.is_err() {
             debug!("On read, downstream peer disconnected, setting stream to None");
@@ -128,10 +252,44 @@ impl NetworkServer {
         result
     }
 
+    /// Blocks until the current client starts sending a message. While the client is idle, a
+    /// newly connected client replaces it.
+    fn wait_for_request(&mut self) -> Result<(), Error> {
+        loop {
+            let stream = self.client()?;
+            match stream.poll_readable(IDLE_POLL_INTERVAL) {
+                Ok(true) => return Ok(()),
+                Ok(false) => (),
+                Err(e) => {
+                    debug!("While idle, downstream peer disconnected, setting stream to None");
+                    self.stream = None;
+                    return Err(e);
+                }
+            }
+
+            match self.acceptor()?.connections.try_recv() {
+                Ok(Ok(stream)) => {
+                    debug!("Replacing the idle downstream with a new one");
+                    self.stream = Some(stream);
+                }
+                // Already logged by the acceptor, the current client is kept
+                Ok(Err(_)) | Err(TryRecvError::Empty) => (),
+                Err(TryRecvError::Disconnected) => return Err(Error::AlreadyShutdown),
+            }
+        }
+    }
+
     /// Shutdown the internal network stream
     pub fn shutdown(&mut self) -> Result<(), Error> {
         debug!("Shutdown called");
-        self.listener.take().ok_or_else(|| Error::AlreadyShutdown)?;
+        let listener = self.listener.take();
+        let acceptor = self.acceptor.take();
+        if listener.is_none() && acceptor.is_none() {
+            return Err(Error::AlreadyShutdown);
+        }
+        if let Some(acceptor) = acceptor {
+            acceptor.stop();
+        }
         let stream = self.stream.take().ok_or_else(|| Error::NoActiveStream)?;
         stream.shutdown()?;
         Ok(())
@@ -152,24 +310,191 @@ impl NetworkServer {
     fn client(&mut self) -> Result<&mut NetworkStream, Error> {
         if self.stream.is_none() {
             debug!("Waiting for downstream to connect");
-            let listener = self
-                .listener
-                .as_mut()
-                .ok_or_else(|| Error::AlreadyShutdown)?;
-            let (stream, stream_addr) = listener.accept()?;
-            debug!("Connection established with downstream {}", stream_addr);
-            stream.set_nodelay(true)?;
-            self.stream = Some(NetworkStream::new(stream));
+            let stream = self
+                .acceptor()?
+                .connections
+                .recv()
+                .map_err(|_| Error::AlreadyShutdown)??;
+            self.stream = Some(stream);
         }
 
         self.stream.as_mut().ok_or_else(|| Error::NoActiveStream)
     }
+
+    /// Returns the acceptor, starting it with the final configuration of the server on first use.
+    fn acceptor(&mut self) -> Result<&Acceptor, Error> {
+        if self.acceptor.is_none() {
+            let listener = self.listener.take().ok_or_else(|| Error::AlreadyShutdown)?;
+            let establisher = Establisher {
+                noise: self.noise.take(),
+                timeout: self.timeout,
+                handshake_timeout: self.handshake_timeout,
+            };
+            self.acceptor = Some(Acceptor::start(listener, establisher)?);
+        }
+
+        self.acceptor.as_ref().ok_or_else(|| Error::AlreadyShutdown)
+    }
+}
+
+impl Drop for NetworkServer {
+    fn drop(&mut self) {
+        if let Some(acceptor) = self.acceptor.take() {
+            acceptor.stop();
+        }
+    }
+}
+
+/// Accepts clients on a dedicated thread and performs each handshake on a thread of its own, so
+/// that neither the server serving its current client nor a client trying to connect has to wait
+/// for the handshake of an unauthenticated client. Established streams, or the reason a client
+/// could not be established, are handed to the server in the order they complete.
+struct Acceptor {
+    connections: Receiver<Result<NetworkStream, Error>>,
+    local_addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl Acceptor {
+    fn start(listener: TcpListener, establisher: Establisher) -> Result<Self, Error> {
+        let local_addr = listener.local_addr()?;
+        let (sender, connections) = mpsc::channel();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+        let thread = thread::spawn(move || {
+            Self::accept(listener, Arc::new(establisher), sender, thread_shutdown)
+        });
+
+        Ok(Self {
+            connections,
+            local_addr,
+            shutdown,
+            thread,
+        })
+    }
+
+    fn accept(
+        listener: TcpListener,
+        establisher: Arc<Establisher>,
+        connections: Sender<Result<NetworkStream, Error>>,
+        shutdown: Arc<AtomicBool>,
+    ) {
+        let pending_handshakes = Arc::new(AtomicUsize::new(0));
+        loop {
+            let result = listener.accept();
+            if shutdown.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let (stream, stream_addr) = match result {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    warn!("Failed to accept a downstream: {}", e);
+                    if connections.send(Err(e.into())).is_err() {
+                        return;
+                    }
+                    thread::sleep(IDLE_POLL_INTERVAL);
+                    continue;
+                }
+            };
+
+            if establisher.noise.is_none() {
+                if connections
+                    .send(establisher.establish(stream, stream_addr))
+                    .is_err()
+                {
+                    return;
+                }
+                continue;
+            }
+
+            if pending_handshakes.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_HANDSHAKES {
+                pending_handshakes.fetch_sub(1, Ordering::SeqCst);
+                warn!(
+                    "Too many pending handshakes, disconnecting downstream {}",
+                    stream_addr
+                );
+                continue;
+            }
+
+            let establisher = establisher.clone();
+            let connections = connections.clone();
+            let pending_handshakes = pending_handshakes.clone();
+            thread::spawn(move || {
+                let result = establisher.establish(stream, stream_addr);
+                pending_handshakes.fetch_sub(1, Ordering::SeqCst);
+                // The server may have shut down in the meantime
+                let _ = connections.send(result);
+            });
+        }
+    }
+
+    /// Stops accepting clients and releases the listening socket before returning.
+    fn stop(self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wakes up the acceptor blocked on accept
+        let mut wake_addr = self.local_addr;
+        if wake_addr.ip().is_unspecified() {
+            wake_addr.set_ip(match wake_addr {
+                SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            });
+        }
+        if let Err(e) = TcpStream::connect(wake_addr) {
+            warn!("Unable to wake up the acceptor, it may linger: {}", e);
+            return;
+        }
+        if self.thread.join().is_err() {
+            warn!("The acceptor panicked");
+        }
+    }
+}
+
+/// Turns accepted connections into streams, performing the Noise handshake if configured.
+struct Establisher {
+    noise: Option<(noise::NoiseConfig, Vec<x25519::PublicKey>)>,
+    timeout: Option<Duration>,
+    handshake_timeout: Duration,
+}
+
+impl Establisher {
+    fn establish(
+        &self,
+        stream: TcpStream,
+        stream_addr: SocketAddr,
+    ) -> Result<NetworkStream, Error> {
+        debug!("Connection established with downstream {}", stream_addr);
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(self.timeout)?;
+        stream.set_write_timeout(self.timeout)?;
+        let mut stream = NetworkStream::new(stream);
+        if let Some((config, trusted_peers)) = &self.noise {
+            // The client is not authenticated yet, so it may never complete the handshake
+            stream.set_timeout(Some(self.handshake_timeout))?;
+            let result = stream
+                .respond_to_noise_handshake(config, trusted_peers, self.handshake_timeout)
+                .and_then(|()| stream.set_timeout(self.timeout));
+            if let Err(e) = result {
+                warn!(
+                    "Noise handshake failed with downstream {}: {}",
+                    stream_addr, e
+                );
+                stream.shutdown()?;
+                return Err(e);
+            }
+            debug!("Noise handshake completed with downstream {}", stream_addr);
+        }
+        Ok(stream)
+    }
 }
 
 struct NetworkStream {
     stream: TcpStream,
     buffer: Vec<u8>,
     temp_buffer: [u8; 1024],
+    session: Option<noise::NoiseSession>,
 }
 
 impl NetworkStream {
@@ -178,17 +503,122 @@ impl NetworkStream {
             stream,
             buffer: Vec::new(),
             temp_buffer: [0; 1024],
+            session: None,
         }
     }
 
-    /// Blocking read until able to successfully read an entire message
+    /// Performs the initiator side of the Noise IK handshake
+    fn initiate_noise_handshake(
+        &mut self,
+        config: &noise::NoiseConfig,
+        server_public_key: x25519::PublicKey,
+    ) -> Result<(), Error> {
+        let mut rng = rand::rngs::OsRng;
+        let mut init_message = vec![0; noise::handshake_init_msg_len(0)];
+        let state = config.initiate_connection(
+            &mut rng,
+            NOISE_PROLOGUE,
+            server_public_key,
+            None,
+            &mut init_message,
+        )?;
+        self.write_block(&init_message)?;
+        let response = self.read_block(noise::handshake_resp_msg_len(0), None)?;
+        let (_, session) = config.finalize_connection(state, &response)?;
+        self.session = Some(session);
+        Ok(())
+    }
+
+    /// Performs the responder side of the Noise IK handshake, rejecting untrusted initiators and
+    /// initiators that do not send their handshake message within timeout.
+    fn respond_to_noise_handshake(
+        &mut self,
+        config: &noise::NoiseConfig,
+        trusted_peers: &[x25519::PublicKey],
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+        let init_message = self.read_block(noise::handshake_init_msg_len(0), Some(deadline))?;
+        let (remote_public_key, state, _) =
+            config.parse_client_init_message(NOISE_PROLOGUE, &init_message)?;
+        if !trusted_peers.contains(&remote_public_key) {
+            return Err(Error::UntrustedPeer(remote_public_key));
+        }
+
+        let mut rng = rand::rngs::OsRng;
+        let mut response = vec![0; noise::handshake_resp_msg_len(0)];
+        let session = config.respond_to_client(&mut rng, state, None, &mut response)?;
+        self.write_block(&response)?;
+        self.session = Some(session);
+        Ok(())
+    }
+
+    fn set_timeout(&mut self, timeout: Option<Duration>) -> Result<(), Error> {
+        self.stream.set_read_timeout(timeout)?;
+        self.stream.set_write_timeout(timeout)?;
+        Ok(())
+    }
+
+    /// Waits up to timeout for data to become available, returning false if none arrived. The
+    /// read timeout of the stream is left unchanged.
+    fn poll_readable(&mut self, timeout: Duration) -> Result<bool, Error> {
+        if !self.buffer.is_empty() {
+            return Ok(true);
+        }
+
+        let read_timeout = self.stream.read_timeout()?;
+        self.stream.set_read_timeout(Some(timeout))?;
+        let result = self.stream.peek(&mut [0; 1]);
+        self.stream.set_read_timeout(read_timeout)?;
+        match result {
+            Ok(0) => Err(Error::RemoteStreamClosed),
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                Ok(false)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Blocking read until able to successfully read an entire message. If a Noise session has
+    /// been established, this decrypts blocks until the final block of the message.
     pub fn read(&mut self) -> Result<Vec<u8>, Error> {
-        let result = self.read_buffer();
+        if self.session.is_none() {
+            return self.read_block(u32::max_value() as usize, None);
+        }
+
+        let mut message = Vec::new();
+        loop {
+            let mut block = self.read_block(noise::MAX_SIZE_NOISE_MSG, None)?;
+            let session = self.session.as_mut().ok_or_else(|| Error::NoActiveStream)?;
+            let chunk = session.read_message_in_place(&mut block)?;
+            let (last, chunk) = chunk
+                .split_first()
+                .ok_or_else(|| noise::NoiseError::MsgTooShort)?;
+            message.extend_from_slice(chunk);
+            if *last == 1 {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// Blocking read until able to successfully read an entire block. Blocks larger than max_size
+    /// are rejected before they are buffered. If a deadline is specified, the read fails once it
+    /// has passed, no matter how slowly the remote peer keeps sending data.
+    fn read_block(&mut self, max_size: usize, deadline: Option<Instant>) -> Result<Vec<u8>, Error> {
+        let result = self.read_buffer(max_size)?;
         if !result.is_empty() {
             return Ok(result);
         }
 
         loop {
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining == Duration::from_secs(0) {
+                    return Err(std::io::Error::from(ErrorKind::TimedOut).into());
+                }
+                self.stream.set_read_timeout(Some(remaining))?;
+            }
             trace!("Attempting to read from stream");
             let read = self.stream.read(&mut self.temp_buffer)?;
             trace!("Read {} bytes from stream", read);
@@ -196,7 +626,7 @@ impl NetworkStream {
                 return Err(Error::RemoteStreamClosed);
             }
             self.buffer.extend(self.temp_buffer[..read].to_vec());
-            let result = self.read_buffer();
+            let result = self.read_buffer(max_size)?;
             if !result.is_empty() {
                 trace!("Found a message in the stream");
                 return Ok(result);
@@ -210,8 +640,38 @@ impl NetworkStream {
         Ok(self.stream.shutdown(Shutdown::Both)?)
     }
 
-    /// Blocking write until able to successfully send an entire message
+    /// Blocking write until able to successfully send an entire message. If a Noise session has
+    /// been established, the message is encrypted and split across one or more blocks.
     pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => return self.write_block(data),
+        };
+
+        let mut chunks = data.chunks(MAX_NOISE_CHUNK_SIZE).peekable();
+        let mut blocks = Vec::new();
+        loop {
+            let chunk = chunks.next().unwrap_or(&[]);
+            let last = chunks.peek().is_none();
+            let mut block = Vec::with_capacity(chunk.len() + 1 + noise::AES_GCM_TAGLEN);
+            block.push(last as u8);
+            block.extend_from_slice(chunk);
+            let tag = session.write_message_in_place(&mut block)?;
+            block.extend_from_slice(&tag);
+            blocks.push(block);
+            if last {
+                break;
+            }
+        }
+
+        for block in blocks {
+            self.write_block(&block)?;
+        }
+        Ok(())
+    }
+
+    /// Blocking write until able to successfully send an entire block
+    fn write_block(&mut self, data: &[u8]) -> Result<(), Error> {
         let u32_max = u32::max_value() as usize;
         if u32_max <= data.len() {
             return Err(Error::DataTooLarge(data.len()));
@@ -231,23 +691,26 @@ impl NetworkStream {
     /// Data sent on a TCP socket may not necessarily be delivered at the exact time. So a read may
     /// only include a subset of what was sent. This wraps around the TCP read buffer to ensure
     /// that only full messages are received.
-    fn read_buffer(&mut self) -> Vec<u8> {
+    fn read_buffer(&mut self, max_size: usize) -> Result<Vec<u8>, Error> {
         if self.buffer.len() < 4 {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
         let mut u32_bytes = [0; 4];
         u32_bytes.copy_from_slice(&self.buffer[..4]);
         let data_size = u32::from_le_bytes(u32_bytes) as usize;
+        if data_size > max_size {
+            return Err(Error::DataTooLarge(data_size));
+        }
 
         let remaining_data = &self.buffer[4..];
         if remaining_data.len() < data_size {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
         let returnable_data = remaining_data[..data_size].to_vec();
         self.buffer = remaining_data[data_size..].to_vec();
-        returnable_data
+        Ok(returnable_data)
     }
 
     /// Writing to a TCP socket will take in as much data as the underlying buffer has space for.
@@ -269,6 +732,8 @@ impl NetworkStream {
 mod test {
     use super::*;
     use libra_config::utils;
+    use libra_crypto::{test_utils::TEST_SEED, Uniform};
+    use rand::{rngs::StdRng, SeedableRng};
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
     #[test]
@@ -351,4 +816,229 @@ mod test {
         assert_eq!(data1, result1);
         assert_eq!(data2, result2);
     }
+
+    #[test]
+    fn test_noise_ping() {
+        let mut rng = StdRng::from_seed(TEST_SEED);
+        let server_key = x25519::PrivateKey::generate(&mut rng);
+        let server_public_key = server_key.public_key();
+        let client_key = x25519::PrivateKey::generate(&mut rng);
+        let client_public_key = client_key.public_key();
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server =
+            NetworkServer::new_with_noise(server_addr, server_key, vec![client_public_key]);
+
+        let client = thread::spawn(move || {
+            let mut client =
+                NetworkClient::new_with_noise(server_addr, client_key, server_public_key);
+            // Large enough to span multiple noise messages
+            let data: Vec<u8> = (0..200_000).map(|i| i as u8).collect();
+            client.write(&data).unwrap();
+            assert_eq!(vec![4, 5, 6, 7], client.read().unwrap());
+            client.write(&[]).unwrap();
+        });
+
+        let result = server.read().unwrap();
+        assert_eq!(200_000, result.len());
+        assert_eq!(result[199_999], (199_999 % 256) as u8);
+        server.write(&[4, 5, 6, 7]).unwrap();
+        assert!(server.read().unwrap().is_empty());
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn test_noise_untrusted_client() {
+        let mut rng = StdRng::from_seed(TEST_SEED);
+        let server_key = x25519::PrivateKey::generate(&mut rng);
+        let server_public_key = server_key.public_key();
+        let trusted_key = x25519::PrivateKey::generate(&mut rng);
+        let untrusted_key = x25519::PrivateKey::generate(&mut rng);
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server =
+            NetworkServer::new_with_noise(server_addr, server_key, vec![trusted_key.public_key()])
+                .with_timeout(Duration::from_secs(5));
+
+        let client = thread::spawn(move || {
+            let mut client =
+                NetworkClient::new_with_noise(server_addr, untrusted_key, server_public_key)
+                    .with_timeout(Duration::from_secs(5));
+            assert!(client.write(&[0, 1, 2, 3]).is_err());
+
+            let mut client =
+                NetworkClient::new_with_noise(server_addr, trusted_key, server_public_key);
+            client.write(&[0, 1, 2, 3]).unwrap();
+        });
+
+        match server.read() {
+            Err(Error::UntrustedPeer(_)) => (),
+            result => panic!("Expected an untrusted peer, found: {:?}", result),
+        }
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn test_noise_handshake_timeout() {
+        let mut rng = StdRng::from_seed(TEST_SEED);
+        let server_key = x25519::PrivateKey::generate(&mut rng);
+        let server_public_key = server_key.public_key();
+        let client_key = x25519::PrivateKey::generate(&mut rng);
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server =
+            NetworkServer::new_with_noise(server_addr, server_key, vec![client_key.public_key()])
+                .with_handshake_timeout(Duration::from_millis(250));
+
+        // One client never starts the handshake, another one sends a partial handshake
+        let _silent = TcpStream::connect(server_addr).unwrap();
+        let mut partial = TcpStream::connect(server_addr).unwrap();
+        partial.write_all(&[4, 0, 0, 0, 0]).unwrap();
+        assert!(server.read().is_err());
+        assert!(server.read().is_err());
+
+        let client = thread::spawn(move || {
+            let mut client =
+                NetworkClient::new_with_noise(server_addr, client_key, server_public_key);
+            client.write(&[0, 1, 2, 3]).unwrap();
+        });
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn test_noise_handshake_deadline() {
+        let mut rng = StdRng::from_seed(TEST_SEED);
+        let server_key = x25519::PrivateKey::generate(&mut rng);
+        let client_key = x25519::PrivateKey::generate(&mut rng);
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let handshake_timeout = Duration::from_millis(250);
+        let mut server =
+            NetworkServer::new_with_noise(server_addr, server_key, vec![client_key.public_key()])
+                .with_handshake_timeout(handshake_timeout);
+
+        // A client announcing a block larger than a handshake message is rejected right away
+        let mut oversized = TcpStream::connect(server_addr).unwrap();
+        oversized.write_all(&[0xff, 0xff, 0xff, 0xff, 0]).unwrap();
+        match server.read() {
+            Err(Error::DataTooLarge(_)) => (),
+            result => panic!("Expected data too large, found: {:?}", result),
+        }
+
+        // A client trickling its handshake message is dropped once the handshake timeout expires,
+        // even though each read completes well within the timeout
+        let mut trickle = TcpStream::connect(server_addr).unwrap();
+        let init_len = noise::handshake_init_msg_len(0) as u32;
+        trickle.write_all(&init_len.to_le_bytes()).unwrap();
+        let start = Instant::now();
+        let (sender, receiver) = mpsc::channel();
+        let trickler = thread::spawn(move || {
+            while receiver.recv_timeout(Duration::from_millis(50)).is_err() {
+                if trickle.write_all(&[0]).is_err() {
+                    return;
+                }
+            }
+        });
+        assert!(server.read().is_err());
+        assert!(start.elapsed() < handshake_timeout * 4);
+        sender.send(()).unwrap();
+        trickler.join().unwrap();
+    }
+
+    #[test]
+    fn test_noise_silent_client_does_not_delay_requests() {
+        let mut rng = StdRng::from_seed(TEST_SEED);
+        let server_key = x25519::PrivateKey::generate(&mut rng);
+        let server_public_key = server_key.public_key();
+        let client_key = x25519::PrivateKey::generate(&mut rng);
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server =
+            NetworkServer::new_with_noise(server_addr, server_key, vec![client_key.public_key()]);
+
+        // A client that never starts its handshake connects before the authenticated client
+        let _silent = TcpStream::connect(server_addr).unwrap();
+        let start = Instant::now();
+        let (sender, receiver) = mpsc::channel();
+        let client = thread::spawn(move || {
+            let mut client =
+                NetworkClient::new_with_noise(server_addr, client_key, server_public_key);
+            client.write(&[0, 1, 2, 3]).unwrap();
+            // Another silent client connects while the authenticated client is being served
+            let _silent = TcpStream::connect(server_addr).unwrap();
+            receiver.recv().unwrap();
+            client.write(&[4, 5, 6, 7]).unwrap();
+        });
+
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+        sender.send(()).unwrap();
+        assert_eq!(vec![4, 5, 6, 7], server.read().unwrap());
+        assert!(start.elapsed() < DEFAULT_HANDSHAKE_TIMEOUT);
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn test_client_timeout() {
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut client = NetworkClient::new(server_addr).with_timeout(Duration::from_millis(250));
+
+        // No server is listening
+        match client.write(&[0, 1, 2, 3]) {
+            Err(Error::Timeout(_)) => (),
+            result => panic!("Expected a timeout, found: {:?}", result),
+        }
+
+        // The server never responds
+        let mut server = NetworkServer::new(server_addr);
+        client.write(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+        assert!(client.read().is_err());
+    }
+
+    #[test]
+    fn test_new_client_replaces_idle_client() {
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server = NetworkServer::new(server_addr).with_timeout(Duration::from_secs(5));
+
+        let mut stale_client = NetworkClient::new(server_addr);
+        stale_client.write(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+
+        // The stale client never sends another request nor closes its connection
+        let client = thread::spawn(move || {
+            let mut client = NetworkClient::new(server_addr);
+            client.write(&[4, 5, 6, 7]).unwrap();
+            assert_eq!(vec![8, 9, 10, 11], client.read().unwrap());
+        });
+
+        assert_eq!(vec![4, 5, 6, 7], server.read().unwrap());
+        server.write(&[8, 9, 10, 11]).unwrap();
+        client.join().unwrap();
+        assert!(stale_client.read().is_err());
+    }
+
+    #[test]
+    fn test_server_timeout() {
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let mut server = NetworkServer::new(server_addr).with_timeout(Duration::from_millis(250));
+
+        // The client sends a partial message and then stalls
+        let mut stream = TcpStream::connect(server_addr).unwrap();
+        stream.write_all(&[4, 0, 0, 0, 0]).unwrap();
+        assert!(server.read().is_err());
+
+        let mut client = NetworkClient::new(server_addr);
+        client.write(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(vec![0, 1, 2, 3], server.read().unwrap());
+    }
 }
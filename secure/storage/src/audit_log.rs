@@ -0,0 +1,302 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Error;
+use fs2::FileExt;
+use libra_crypto::HashValue;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// The set of storage operations that are recorded within the audit log.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Operation {
+    Get,
+    Set,
+    Sign,
+    RotateKey,
+    CreateKey,
+    GetPublicKey,
+    ImportKey,
+    ExportKey,
+}
+
+/// A single record within the audit log. Each entry commits to the hash of the entry that came
+/// before it, so any modification, removal or reordering of entries breaks the chain.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditEntry {
+    /// Position of this entry within the log, starting at 0.
+    pub sequence: u64,
+    /// Time since Unix Epoch in seconds.
+    pub timestamp: u64,
+    /// The identity of the caller that performed the operation.
+    pub identity: String,
+    pub operation: Operation,
+    /// The name of the key the operation was performed on.
+    pub key: String,
+    /// None if the operation succeeded, otherwise the error that was returned to the caller.
+    pub error: Option<String>,
+    /// The hash of the previous entry or zero for the first entry.
+    pub previous_hash: HashValue,
+    /// The hash of all the fields above.
+    pub hash: HashValue,
+}
+
+impl AuditEntry {
+    fn compute_hash(&self) -> Result<HashValue, Error> {
+        let bytes = lcs::to_bytes(&(
+            self.sequence,
+            self.timestamp,
+            &self.identity,
+            self.operation,
+            &self.key,
+            &self.error,
+            self.previous_hash,
+        ))?;
+        Ok(HashValue::sha3_256_of(&bytes))
+    }
+}
+
+/// AuditLog is an append-only, hash-chained log of storage operations persisted to the local
+/// filesystem. Every entry is written as a single line of JSON and is flushed before the call that
+/// produced it returns. When opening an existing log, the chain is verified and new entries are
+/// appended to its tail.
+///
+/// Appends hold an exclusive lock on the file, so several logs, possibly in different processes,
+/// can share the same file: each append picks up the entries written by the others since its
+/// last one and chains onto them.
+pub struct AuditLog {
+    file_path: PathBuf,
+    next_sequence: u64,
+    previous_hash: HashValue,
+    /// Length of the file after the last entry known to this log.
+    file_len: u64,
+}
+
+impl AuditLog {
+    pub fn new(file_path: PathBuf) -> Result<Self, Error> {
+        let file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&file_path)?;
+        let _lock = FileLock::exclusive(&file)?;
+        let entries = Self::verify(&file_path)?;
+        let (next_sequence, previous_hash) =
+            entries.last().map_or((0, HashValue::zero()), |entry| {
+                (entry.sequence + 1, entry.hash)
+            });
+
+        Ok(Self {
+            file_path,
+            next_sequence,
+            previous_hash,
+            file_len: file.metadata()?.len(),
+        })
+    }
+
+    /// Appends a new entry to the tail of the log.
+    pub fn append(
+        &mut self,
+        timestamp: u64,
+        identity: &str,
+        operation: Operation,
+        key: &str,
+        error: Option<String>,
+    ) -> Result<(), Error> {
+        let file = OpenOptions::new().append(true).open(&self.file_path)?;
+        let _lock = FileLock::exclusive(&file)?;
+        if file.metadata()?.len() != self.file_len {
+            self.catch_up()?;
+        }
+
+        let mut entry = AuditEntry {
+            sequence: self.next_sequence,
+            timestamp,
+            identity: identity.to_string(),
+            operation,
+            key: key.to_string(),
+            error,
+            previous_hash: self.previous_hash,
+            hash: HashValue::zero(),
+        };
+        entry.hash = entry.compute_hash()?;
+
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+        (&file).write_all(&line)?;
+        file.sync_data()?;
+
+        self.next_sequence += 1;
+        self.previous_hash = entry.hash;
+        self.file_len = file.metadata()?.len();
+        Ok(())
+    }
+
+    /// Moves the tail of this log past the entries appended by other writers. Must be called
+    /// while holding the file lock.
+    fn catch_up(&mut self) -> Result<(), Error> {
+        let reader = BufReader::new(File::open(&self.file_path)?);
+        let mut last_line = None;
+        for line in reader.lines() {
+            let line = line?;
+            if !line.is_empty() {
+                last_line = Some(line);
+            }
+        }
+
+        if let Some(line) = last_line {
+            let entry: AuditEntry = serde_json::from_str(&line)?;
+            if entry.sequence < self.next_sequence || entry.hash != entry.compute_hash()? {
+                return Err(Error::AuditLogCorrupted(entry.sequence));
+            }
+            self.next_sequence = entry.sequence + 1;
+            self.previous_hash = entry.hash;
+        }
+        Ok(())
+    }
+
+    /// Reads the log stored at the given path and verifies that every entry is correctly hashed
+    /// and chained to its predecessor. Returns the entries in order on success.
+    pub fn verify(file_path: &Path) -> Result<Vec<AuditEntry>, Error> {
+        let reader = BufReader::new(File::open(file_path)?);
+        let mut entries: Vec<AuditEntry> = Vec::new();
+        let mut previous_hash = HashValue::zero();
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let entry: AuditEntry = serde_json::from_str(&line)?;
+            let sequence = entries.len() as u64;
+            if entry.sequence != sequence
+                || entry.previous_hash != previous_hash
+                || entry.hash != entry.compute_hash()?
+            {
+                return Err(Error::AuditLogCorrupted(sequence));
+            }
+
+            previous_hash = entry.hash;
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// An exclusive advisory lock on a file, released when dropped.
+struct FileLock<'a> {
+    file: &'a File,
+}
+
+impl<'a> FileLock<'a> {
+    fn exclusive(file: &'a File) -> Result<Self, Error> {
+        file.lock_exclusive()?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for FileLock<'_> {
+    fn drop(&mut self) {
+        // The lock is released anyway once the file is closed.
+        let _ = self.file.unlock();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use libra_temppath::TempPath;
+
+    #[test]
+    fn test_append_and_reopen() {
+        let temp_path = TempPath::new();
+        let path = temp_path.path().to_path_buf();
+
+        let mut log = AuditLog::new(path.clone()).unwrap();
+        log.append(1, "safety_rules", Operation::Sign, "consensus", None)
+            .unwrap();
+        log.append(
+            2,
+            "anyone",
+            Operation::Get,
+            "consensus",
+            Some("denied".into()),
+        )
+        .unwrap();
+
+        let mut log = AuditLog::new(path.clone()).unwrap();
+        log.append(3, "key_manager", Operation::RotateKey, "consensus", None)
+            .unwrap();
+
+        let entries = AuditLog::verify(&path).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].sequence, 2);
+        assert_eq!(entries[2].previous_hash, entries[1].hash);
+        assert_eq!(entries[0].identity, "safety_rules");
+    }
+
+    #[test]
+    fn test_detect_tampering() {
+        let temp_path = TempPath::new();
+        let path = temp_path.path().to_path_buf();
+
+        let mut log = AuditLog::new(path.clone()).unwrap();
+        log.append(1, "safety_rules", Operation::Sign, "consensus", None)
+            .unwrap();
+        log.append(2, "safety_rules", Operation::Sign, "consensus", None)
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let tampered = contents.replacen("safety_rules", "key_manager", 1);
+        std::fs::write(&path, tampered).unwrap();
+
+        assert_eq!(
+            AuditLog::verify(&path).unwrap_err(),
+            Error::AuditLogCorrupted(0)
+        );
+        assert!(AuditLog::new(path).is_err());
+    }
+
+    #[test]
+    fn test_concurrent_writers() {
+        let temp_path = TempPath::new();
+        let path = temp_path.path().to_path_buf();
+
+        let mut first = AuditLog::new(path.clone()).unwrap();
+        let mut second = AuditLog::new(path.clone()).unwrap();
+        first
+            .append(1, "safety_rules", Operation::Sign, "consensus", None)
+            .unwrap();
+        second
+            .append(2, "key_manager", Operation::ExportKey, "consensus", None)
+            .unwrap();
+        first
+            .append(3, "safety_rules", Operation::Sign, "consensus", None)
+            .unwrap();
+
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let mut log = AuditLog::new(path).unwrap();
+                    for timestamp in 0..10 {
+                        log.append(timestamp, &i.to_string(), Operation::Get, "key", None)
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let entries = AuditLog::verify(&path).unwrap();
+        assert_eq!(entries.len(), 43);
+        assert_eq!(entries[1].identity, "key_manager");
+    }
+}
@@ -7,6 +7,8 @@ use thiserror::Error;
 
 #[derive(Debug, Deserialize, Error, PartialEq, Serialize)]
 pub enum Error {
+    #[error("Audit log corrupted at entry: {0}")]
+    AuditLogCorrupted(u64),
     #[error("Entropy error: {0}")]
     EntropyError(String),
     #[error("Internal error: {0}")]
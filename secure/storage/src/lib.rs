@@ -3,6 +3,7 @@
 
 #![forbid(unsafe_code)]
 
+mod audit_log;
 mod crypto_kv_storage;
 mod crypto_storage;
 mod error;
@@ -12,11 +13,13 @@ mod kv_storage;
 mod namespaced_storage;
 mod on_disk;
 mod policy;
+mod policy_storage;
 mod storage;
 mod value;
 mod vault;
 
 pub use crate::{
+    audit_log::{AuditEntry, AuditLog, Operation},
     crypto_kv_storage::CryptoKVStorage,
     crypto_storage::{CryptoStorage, PublicKeyResponse},
     error::Error,
@@ -26,6 +29,7 @@ pub use crate::{
     namespaced_storage::NamespacedStorage,
     on_disk::{OnDiskStorage, OnDiskStorageInternal},
     policy::{Capability, Identity, Permission, Policy},
+    policy_storage::PolicyStorage,
     storage::Storage,
     value::Value,
     vault::VaultStorage,
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Dictates a set of permissions
-#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct Policy {
     pub permissions: Vec<Permission>,
 }
@@ -23,7 +23,7 @@ impl Policy {
 }
 
 /// Maps an identity to a set of capabilities
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Permission {
     pub id: Identity,
     pub capabilities: Vec<Capability>,
@@ -40,7 +40,7 @@ impl Permission {
 /// verifiable material. For example, the process running safety_rules may have a token that is
 /// intended for only safety_rules to own. The specifics are left to the implementation of the
 /// storage backend interface layer.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Identity {
     User(String),
     Anyone,
@@ -48,7 +48,7 @@ pub enum Identity {
 }
 
 /// Represents actions
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Capability {
     Export,
     Read,
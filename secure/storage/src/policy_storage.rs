@@ -0,0 +1,181 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    audit_log::{AuditLog, Operation},
+    Capability, CryptoStorage, Error, GetResponse, Identity, KVStorage, Policy, PublicKeyResponse,
+    Storage, Value,
+};
+use libra_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
+    hash::CryptoHash,
+};
+use libra_secure_time::{RealTimeService, TimeService};
+use serde::Serialize;
+use std::{collections::HashMap, path::PathBuf, sync::Mutex};
+
+/// PolicyStorage wraps another storage backend and enforces a Policy for each key on behalf of a
+/// single caller identity, for example, safety_rules or key_manager. This brings the permission
+/// model offered by Vault to backends that have no notion of permissions, such as InMemoryStorage,
+/// OnDiskStorage and NamespacedStorage. Keys without a registered policy are inaccessible.
+///
+/// Every call that reads, writes, signs with, creates, rotates, imports or exports a key, whether
+/// permitted or not, is recorded in an append-only, hash-chained AuditLog along with the caller
+/// identity. If the audit entry cannot be persisted, the call fails even if the underlying
+/// operation succeeded.
+pub struct PolicyStorage {
+    identity: String,
+    policies: HashMap<String, Policy>,
+    audit_log: Mutex<AuditLog>,
+    inner: Box<Storage>,
+    time_service: RealTimeService,
+}
+
+impl PolicyStorage {
+    pub fn new(storage: Storage, identity: String, audit_log_path: PathBuf) -> Result<Self, Error> {
+        Ok(Self {
+            identity,
+            policies: HashMap::new(),
+            audit_log: Mutex::new(AuditLog::new(audit_log_path)?),
+            inner: Box::new(storage),
+            time_service: RealTimeService::new(),
+        })
+    }
+
+    /// Sets the policy for the given key, replacing any prior policy.
+    pub fn set_policy(&mut self, key: &str, policy: Policy) {
+        self.policies.insert(key.to_string(), policy);
+    }
+
+    fn check(&self, key: &str, capability: Capability) -> Result<(), Error> {
+        let policy = self.policies.get(key).ok_or(Error::PermissionDenied)?;
+        let permitted = policy.permissions.iter().any(|permission| {
+            let applies = match &permission.id {
+                Identity::User(id) => *id == self.identity,
+                Identity::Anyone => true,
+                Identity::NoOne => false,
+            };
+            applies && permission.capabilities.contains(&capability)
+        });
+
+        if permitted {
+            Ok(())
+        } else {
+            Err(Error::PermissionDenied)
+        }
+    }
+
+    fn audit<T>(
+        &self,
+        operation: Operation,
+        key: &str,
+        result: Result<T, Error>,
+    ) -> Result<T, Error> {
+        let error = result.as_ref().err().map(|e| e.to_string());
+        self.audit_log.lock().unwrap().append(
+            self.time_service.now(),
+            &self.identity,
+            operation,
+            key,
+            error,
+        )?;
+        result
+    }
+}
+
+impl KVStorage for PolicyStorage {
+    fn available(&self) -> Result<(), Error> {
+        self.inner.available()
+    }
+
+    fn get(&self, key: &str) -> Result<GetResponse, Error> {
+        let result = self
+            .check(key, Capability::Read)
+            .and_then(|_| self.inner.get(key));
+        self.audit(Operation::Get, key, result)
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), Error> {
+        let result = self
+            .check(key, Capability::Write)
+            .and_then(|_| self.inner.set(key, value));
+        self.audit(Operation::Set, key, result)
+    }
+
+    /// Note: This does not clear policies nor the audit log
+    #[cfg(any(test, feature = "testing"))]
+    fn reset_and_clear(&mut self) -> Result<(), Error> {
+        self.inner.reset_and_clear()
+    }
+}
+
+impl CryptoStorage for PolicyStorage {
+    fn create_key(&mut self, name: &str) -> Result<Ed25519PublicKey, Error> {
+        let result = self
+            .check(name, Capability::Write)
+            .and_then(|_| self.inner.create_key(name));
+        self.audit(Operation::CreateKey, name, result)
+    }
+
+    fn export_private_key(&self, name: &str) -> Result<Ed25519PrivateKey, Error> {
+        let result = self
+            .check(name, Capability::Export)
+            .and_then(|_| self.inner.export_private_key(name));
+        self.audit(Operation::ExportKey, name, result)
+    }
+
+    fn import_private_key(&mut self, name: &str, key: Ed25519PrivateKey) -> Result<(), Error> {
+        let result = self
+            .check(name, Capability::Write)
+            .and_then(|_| self.inner.import_private_key(name, key));
+        self.audit(Operation::ImportKey, name, result)
+    }
+
+    fn export_private_key_for_version(
+        &self,
+        name: &str,
+        version: Ed25519PublicKey,
+    ) -> Result<Ed25519PrivateKey, Error> {
+        let result = self
+            .check(name, Capability::Export)
+            .and_then(|_| self.inner.export_private_key_for_version(name, version));
+        self.audit(Operation::ExportKey, name, result)
+    }
+
+    fn get_public_key(&self, name: &str) -> Result<PublicKeyResponse, Error> {
+        let result = self
+            .check(name, Capability::Read)
+            .and_then(|_| self.inner.get_public_key(name));
+        self.audit(Operation::GetPublicKey, name, result)
+    }
+
+    fn rotate_key(&mut self, name: &str) -> Result<Ed25519PublicKey, Error> {
+        let result = self
+            .check(name, Capability::Rotate)
+            .and_then(|_| self.inner.rotate_key(name));
+        self.audit(Operation::RotateKey, name, result)
+    }
+
+    fn sign<T: CryptoHash + Serialize>(
+        &mut self,
+        name: &str,
+        message: &T,
+    ) -> Result<Ed25519Signature, Error> {
+        let result = self
+            .check(name, Capability::Sign)
+            .and_then(|_| self.inner.sign(name, message));
+        self.audit(Operation::Sign, name, result)
+    }
+
+    fn sign_using_version<T: CryptoHash + Serialize>(
+        &mut self,
+        name: &str,
+        version: Ed25519PublicKey,
+        message: &T,
+    ) -> Result<Ed25519Signature, Error> {
+        let result = self
+            .check(name, Capability::Sign)
+            .and_then(|_| self.inner.sign_using_version(name, version, message));
+        self.audit(Operation::Sign, name, result)
+    }
+}
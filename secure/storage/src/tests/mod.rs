@@ -4,5 +4,6 @@
 mod github;
 mod in_memory;
 mod on_disk;
+mod policy;
 mod suite;
 mod vault;
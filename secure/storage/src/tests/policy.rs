@@ -0,0 +1,165 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    AuditLog, Capability, CryptoStorage, Error, Identity, InMemoryStorage, KVStorage,
+    NamespacedStorage, OnDiskStorage, Operation, Permission, Policy, PolicyStorage, Storage, Value,
+};
+use libra_crypto::test_utils::TestLibraCrypto;
+use libra_temppath::TempPath;
+use std::path::PathBuf;
+
+const CONSENSUS_KEY: &str = "consensus";
+const WAYPOINT: &str = "waypoint";
+
+fn policies() -> Vec<(&'static str, Policy)> {
+    vec![
+        (
+            CONSENSUS_KEY,
+            Policy::new(vec![
+                Permission::new(
+                    Identity::User("safety_rules".into()),
+                    vec![Capability::Read, Capability::Sign],
+                ),
+                Permission::new(
+                    Identity::User("key_manager".into()),
+                    vec![Capability::Read, Capability::Rotate, Capability::Write],
+                ),
+            ]),
+        ),
+        (WAYPOINT, Policy::public()),
+    ]
+}
+
+fn policy_storage(inner: Storage, identity: &str, audit_log: &TempPath) -> PolicyStorage {
+    let mut storage =
+        PolicyStorage::new(inner, identity.into(), audit_log.path().to_path_buf()).unwrap();
+    for (key, policy) in policies() {
+        storage.set_policy(key, policy);
+    }
+    storage
+}
+
+fn audit_summary(audit_log: &TempPath) -> Vec<(Operation, bool)> {
+    AuditLog::verify(audit_log.path())
+        .unwrap()
+        .iter()
+        .map(|entry| (entry.operation, entry.error.is_none()))
+        .collect()
+}
+
+/// Each identity wraps its own instance of the backend, all of which are backed by the same file,
+/// and each writes to its own audit log.
+fn test_enforcement(new_storage: fn(PathBuf) -> Storage) {
+    let path = TempPath::new();
+    let (km_log, sr_log, stranger_log) = (TempPath::new(), TempPath::new(), TempPath::new());
+    let new_storage = || new_storage(path.path().to_path_buf());
+    let mut key_manager = policy_storage(new_storage(), "key_manager", &km_log);
+    let mut safety_rules = policy_storage(new_storage(), "safety_rules", &sr_log);
+    let mut stranger = policy_storage(new_storage(), "stranger", &stranger_log);
+
+    key_manager.create_key(CONSENSUS_KEY).unwrap();
+    assert_eq!(
+        safety_rules.create_key(CONSENSUS_KEY).unwrap_err(),
+        Error::PermissionDenied
+    );
+
+    let message = TestLibraCrypto("Hello, World".to_string());
+    safety_rules.sign(CONSENSUS_KEY, &message).unwrap();
+    assert_eq!(
+        key_manager.sign(CONSENSUS_KEY, &message).unwrap_err(),
+        Error::PermissionDenied
+    );
+    assert_eq!(
+        safety_rules.rotate_key(CONSENSUS_KEY).unwrap_err(),
+        Error::PermissionDenied
+    );
+    key_manager.rotate_key(CONSENSUS_KEY).unwrap();
+
+    assert_eq!(
+        stranger.get(CONSENSUS_KEY).unwrap_err(),
+        Error::PermissionDenied
+    );
+    assert_eq!(
+        stranger.export_private_key(CONSENSUS_KEY).unwrap_err(),
+        Error::PermissionDenied
+    );
+    stranger.set(WAYPOINT, Value::U64(5)).unwrap();
+    assert_eq!(safety_rules.get(WAYPOINT).unwrap().value, Value::U64(5));
+    assert_eq!(
+        stranger.set("unknown", Value::U64(5)).unwrap_err(),
+        Error::PermissionDenied
+    );
+
+    assert_eq!(
+        audit_summary(&km_log),
+        vec![
+            (Operation::CreateKey, true),
+            (Operation::Sign, false),
+            (Operation::RotateKey, true),
+        ]
+    );
+    assert_eq!(
+        audit_summary(&sr_log),
+        vec![
+            (Operation::CreateKey, false),
+            (Operation::Sign, true),
+            (Operation::RotateKey, false),
+            (Operation::Get, true),
+        ]
+    );
+    assert_eq!(
+        audit_summary(&stranger_log),
+        vec![
+            (Operation::Get, false),
+            (Operation::ExportKey, false),
+            (Operation::Set, true),
+            (Operation::Set, false),
+        ]
+    );
+}
+
+#[test]
+fn in_memory() {
+    let audit_log = TempPath::new();
+    let mut storage = policy_storage(
+        Storage::from(InMemoryStorage::new()),
+        "key_manager",
+        &audit_log,
+    );
+    storage.create_key(CONSENSUS_KEY).unwrap();
+    let message = TestLibraCrypto("Hello, World".to_string());
+    assert_eq!(
+        storage.sign(CONSENSUS_KEY, &message).unwrap_err(),
+        Error::PermissionDenied
+    );
+    storage.rotate_key(CONSENSUS_KEY).unwrap();
+    storage.set(WAYPOINT, Value::U64(5)).unwrap();
+    assert_eq!(storage.get(WAYPOINT).unwrap().value, Value::U64(5));
+    storage.get_public_key(CONSENSUS_KEY).unwrap();
+
+    assert_eq!(
+        audit_summary(&audit_log),
+        vec![
+            (Operation::CreateKey, true),
+            (Operation::Sign, false),
+            (Operation::RotateKey, true),
+            (Operation::Set, true),
+            (Operation::Get, true),
+            (Operation::GetPublicKey, true),
+        ]
+    );
+}
+
+#[test]
+fn on_disk() {
+    test_enforcement(|path| Storage::from(OnDiskStorage::new(path)));
+}
+
+#[test]
+fn namespaced() {
+    test_enforcement(|path| {
+        let storage = OnDiskStorage::new(path);
+        Storage::from(NamespacedStorage::new(Box::new(storage), "ns".into()))
+    });
+}
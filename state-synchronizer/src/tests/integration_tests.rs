@@ -348,7 +348,7 @@ impl SynchronizerEnv {
                 );
             }
             let authentication_mode =
-                AuthenticationMode::Mutual(self.network_keys[new_peer_idx].clone());
+                AuthenticationMode::Mutual(self.network_keys[new_peer_idx].clone().into());
             let pub_key = authentication_mode.public_key();
             let mut network_builder = NetworkBuilder::new(
                 self.runtime.handle().clone(),
@@ -459,6 +459,7 @@ fn test_non_existence() {
         let (value, proof) = tree.get_with_proof(non_existing_key, 0).unwrap();
         assert_eq!(value, None);
         assert!(proof.verify(root, non_existing_key, None).is_ok());
+        assert_eq!(tree.get(non_existing_key, 0).unwrap(), None);
     }
     // 2. Non-existing node at non-root internal node
     {
@@ -466,6 +467,7 @@ fn test_non_existence() {
         let (value, proof) = tree.get_with_proof(non_existing_key, 0).unwrap();
         assert_eq!(value, None);
         assert!(proof.verify(root, non_existing_key, None).is_ok());
+        assert_eq!(tree.get(non_existing_key, 0).unwrap(), None);
     }
     // 3. Non-existing node at leaf node
     {
@@ -473,6 +475,7 @@ fn test_non_existence() {
         let (value, proof) = tree.get_with_proof(non_existing_key, 0).unwrap();
         assert_eq!(value, None);
         assert!(proof.verify(root, non_existing_key, None).is_ok());
+        assert_eq!(tree.get(non_existing_key, 0).unwrap(), None);
     }
 }
 
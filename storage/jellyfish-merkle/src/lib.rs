@@ -503,63 +503,13 @@ where
         key: HashValue,
         version: Version,
     ) -> Result<(Option<AccountStateBlob>, SparseMerkleProof)> {
-        // Empty tree just returns proof with no sibling hash.
-        let mut next_node_key = NodeKey::new_empty_path(version);
         let mut siblings = vec![];
-        let nibble_path = NibblePath::new(key.to_vec());
-        let mut nibble_iter = nibble_path.nibbles();
-
-        // We limit the number of loops here deliberately to avoid potential cyclic graph bugs
-        // in the tree structure.
-        for nibble_depth in 0..=ROOT_NIBBLE_HEIGHT {
-            let next_node = self.reader.get_node(&next_node_key)?;
-            match next_node {
-                Node::Internal(internal_node) => {
-                    let queried_child_index = nibble_iter
-                        .next()
-                        .ok_or_else(|| format_err!("ran out of nibbles"))?;
-                    let (child_node_key, mut siblings_in_internal) =
-                        internal_node.get_child_with_siblings(&next_node_key, queried_child_index);
-                    siblings.append(&mut siblings_in_internal);
-                    next_node_key = match child_node_key {
-                        Some(node_key) => node_key,
-                        None => {
-                            return Ok((
-                                None,
-                                SparseMerkleProof::new(None, {
-                                    siblings.reverse();
-                                    siblings
-                                }),
-                            ))
-                        }
-                    };
-                }
-                Node::Leaf(leaf_node) => {
-                    return Ok((
-                        if leaf_node.account_key() == key {
-                            Some(leaf_node.blob().clone())
-                        } else {
-                            None
-                        },
-                        SparseMerkleProof::new(Some(leaf_node.into()), {
-                            siblings.reverse();
-                            siblings
-                        }),
-                    ));
-                }
-                Node::Null => {
-                    if nibble_depth == 0 {
-                        return Ok((None, SparseMerkleProof::new(None, vec![])));
-                    } else {
-                        bail!(
-                            "Non-root null node exists with node key {:?}",
-                            next_node_key
-                        );
-                    }
-                }
-            }
-        }
-        bail!("Jellyfish Merkle tree has cyclic graph inside.");
+        let leaf_node = self.search(key, version, Some(&mut siblings))?;
+        siblings.reverse();
+        Ok((
+            Self::blob_of(key, leaf_node.as_ref()),
+            SparseMerkleProof::new(leaf_node.map(Into::into), siblings),
+        ))
     }
 
     /// Gets the proof that shows a list of keys up to `rightmost_key_to_prove` exist at `version`.
@@ -589,9 +539,71 @@ where
         Ok(SparseMerkleRangeProof::new(siblings))
     }
 
-    #[cfg(test)]
+    /// Returns the account state blob (if applicable), without computing a proof, for readers
+    /// that trust the tree.
     pub fn get(&self, key: HashValue, version: Version) -> Result<Option<AccountStateBlob>> {
-        Ok(self.get_with_proof(key, version)?.0)
+        let leaf_node = self.search(key, version, None)?;
+        Ok(Self::blob_of(key, leaf_node.as_ref()))
+    }
+
+    /// Walks down the tree at `version` along the path of `key` and returns the leaf node the
+    /// walk ends at, if any, which may hold another key. If `siblings` is given, the hashes of the
+    /// siblings along the path are appended to it, from the root down.
+    fn search(
+        &self,
+        key: HashValue,
+        version: Version,
+        mut siblings: Option<&mut Vec<HashValue>>,
+    ) -> Result<Option<LeafNode>> {
+        let mut next_node_key = NodeKey::new_empty_path(version);
+        let nibble_path = NibblePath::new(key.to_vec());
+        let mut nibble_iter = nibble_path.nibbles();
+
+        // We limit the number of loops here deliberately to avoid potential cyclic graph bugs
+        // in the tree structure.
+        for nibble_depth in 0..=ROOT_NIBBLE_HEIGHT {
+            match self.reader.get_node(&next_node_key)? {
+                Node::Internal(internal_node) => {
+                    let queried_child_index = nibble_iter
+                        .next()
+                        .ok_or_else(|| format_err!("ran out of nibbles"))?;
+                    let child_node_key = match siblings.as_mut() {
+                        Some(siblings) => {
+                            let (child_node_key, mut siblings_in_internal) = internal_node
+                                .get_child_with_siblings(&next_node_key, queried_child_index);
+                            siblings.append(&mut siblings_in_internal);
+                            child_node_key
+                        }
+                        None => internal_node.child(queried_child_index).map(|child| {
+                            next_node_key.gen_child_node_key(child.version, queried_child_index)
+                        }),
+                    };
+                    next_node_key = match child_node_key {
+                        Some(node_key) => node_key,
+                        None => return Ok(None),
+                    };
+                }
+                Node::Leaf(leaf_node) => return Ok(Some(leaf_node)),
+                Node::Null => {
+                    if nibble_depth == 0 {
+                        return Ok(None);
+                    } else {
+                        bail!(
+                            "Non-root null node exists with node key {:?}",
+                            next_node_key
+                        );
+                    }
+                }
+            }
+        }
+        bail!("Jellyfish Merkle tree has cyclic graph inside.");
+    }
+
+    /// Returns the blob of `leaf_node` if it holds `key`.
+    fn blob_of(key: HashValue, leaf_node: Option<&LeafNode>) -> Option<AccountStateBlob> {
+        leaf_node
+            .filter(|leaf_node| leaf_node.account_key() == key)
+            .map(|leaf_node| leaf_node.blob().clone())
     }
 
     #[cfg(any(test, feature = "fuzzing"))]
@@ -282,6 +282,12 @@ impl LibraDB {
 
     // ================================== Backup APIs ===================================
 
+    /// Creates a checkpoint of the DB at `path`, which must not yet exist. The checkpoint can be
+    /// opened as a regular LibraDB whose root path is the parent of `path`.
+    pub fn create_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.db.create_checkpoint(path)
+    }
+
     /// Gets an instance of `BackupHandler` for data backup purpose.
     pub fn get_backup_handler(&self) -> BackupHandler {
         BackupHandler::new(
@@ -700,6 +706,15 @@ impl DbReader for LibraDB {
             .get_account_state_with_proof_by_version(address, version)
     }
 
+    fn get_account_state_by_version(
+        &self,
+        address: AccountAddress,
+        version: Version,
+    ) -> Result<Option<AccountStateBlob>> {
+        self.state_store
+            .get_account_state_by_version(address, version)
+    }
+
     fn get_latest_state_root(&self) -> Result<(Version, HashValue)> {
         let (version, txn_info) = self.ledger_store.get_latest_transaction_info()?;
         Ok((version, txn_info.state_root_hash()))
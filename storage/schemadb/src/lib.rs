@@ -394,6 +394,13 @@ impl DB {
         Ok(cf_sizes)
     }
 
+    /// Creates a consistent, openable copy of the DB at `path`, which must not yet exist. SST files
+    /// are hard linked when `path` is on the same filesystem, so this is cheap.
+    pub fn create_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        rocksdb::checkpoint::Checkpoint::new(&self.inner)?.create_checkpoint(path)?;
+        Ok(())
+    }
+
     /// Flushes all memtable data. This is only used for testing `get_approximate_sizes_cf` in unit
     /// tests.
     pub fn flush_all(&self) -> Result<()> {
@@ -338,3 +338,20 @@ fn test_report_size() {
     assert!(*cf_sizes.get("TestCF2").unwrap() > 0);
     assert_eq!(*cf_sizes.get("default").unwrap(), 0);
 }
+
+#[test]
+fn test_checkpoint() {
+    let db = TestDB::new();
+    db.put::<TestSchema1>(&TestField(0), &TestField(0)).unwrap();
+
+    let checkpoint_dir = libra_temppath::TempPath::new();
+    db.create_checkpoint(checkpoint_dir.path()).unwrap();
+    db.put::<TestSchema1>(&TestField(1), &TestField(1)).unwrap();
+
+    let checkpoint = open_db_read_only(&checkpoint_dir);
+    assert_eq!(
+        checkpoint.get::<TestSchema1>(&TestField(0)).unwrap(),
+        Some(TestField(0)),
+    );
+    assert_eq!(checkpoint.get::<TestSchema1>(&TestField(1)).unwrap(), None);
+}
@@ -242,6 +242,19 @@ pub trait DbReader: Send + Sync {
         version: Version,
     ) -> Result<(Option<AccountStateBlob>, SparseMerkleProof)>;
 
+    /// Gets an account state by account address as of a version, like
+    /// `get_account_state_with_proof_by_version` but without computing the proof, for readers
+    /// that trust the database, like JSON-RPC.
+    fn get_account_state_by_version(
+        &self,
+        address: AccountAddress,
+        version: Version,
+    ) -> Result<Option<AccountStateBlob>> {
+        Ok(self
+            .get_account_state_with_proof_by_version(address, version)?
+            .0)
+    }
+
     /// See [`LibraDB::get_latest_state_root`].
     ///
     /// [`LibraDB::get_latest_state_root`]:
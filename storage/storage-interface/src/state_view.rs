@@ -17,10 +17,9 @@ use libra_types::{
 };
 use scratchpad::{AccountStatus, SparseMerkleTree};
 use std::{
-    cell::RefCell,
-    collections::{hash_map::Entry, HashMap},
-    convert::TryInto,
-    sync::Arc,
+    collections::HashMap,
+    convert::{TryFrom, TryInto},
+    sync::{Arc, Mutex},
 };
 
 /// `VerifiedStateView` is like a snapshot of the global state comprised of state view at two
@@ -78,8 +77,8 @@ pub struct VerifiedStateView<'a> {
     ///        | +------------------------------+ +--------------------+ |
     ///        +---------------------------------------------------------+
     /// ```
-    account_to_state_cache: RefCell<HashMap<AccountAddress, AccountState>>,
-    account_to_proof_cache: RefCell<HashMap<HashValue, SparseMerkleProof>>,
+    account_to_state_cache: Mutex<HashMap<AccountAddress, AccountState>>,
+    account_to_proof_cache: Mutex<HashMap<HashValue, SparseMerkleProof>>,
 }
 
 impl<'a> VerifiedStateView<'a> {
@@ -108,8 +107,8 @@ impl<'a> VerifiedStateView<'a> {
             latest_persistent_version,
             latest_persistent_state_root,
             speculative_state,
-            account_to_state_cache: RefCell::new(HashMap::new()),
-            account_to_proof_cache: RefCell::new(HashMap::new()),
+            account_to_state_cache: Mutex::new(HashMap::new()),
+            account_to_proof_cache: Mutex::new(HashMap::new()),
         }
     }
 }
@@ -127,8 +126,8 @@ impl<'a>
         HashMap<HashValue, SparseMerkleProof>,
     ) {
         (
-            self.account_to_state_cache.into_inner(),
-            self.account_to_proof_cache.into_inner(),
+            self.account_to_state_cache.into_inner().unwrap(),
+            self.account_to_proof_cache.into_inner().unwrap(),
         )
     }
 }
@@ -141,56 +140,61 @@ impl<'a> StateView for VerifiedStateView<'a> {
     fn get(&self, access_path: &AccessPath) -> Result<Option<Vec<u8>>> {
         let address = access_path.address;
         let path = &access_path.path;
-        match self.account_to_state_cache.borrow_mut().entry(address) {
-            Entry::Occupied(occupied) => Ok(occupied.get().get(path).cloned()),
-            Entry::Vacant(vacant) => {
-                let address_hash = address.hash();
-                let account_blob_option = match self.speculative_state.get(address_hash) {
-                    AccountStatus::ExistsInScratchPad(blob) => Some(blob),
-                    AccountStatus::DoesNotExist => None,
-                    // No matter it is in db or unknown, we have to query from db since even the
-                    // former case, we don't have the blob data but only its hash.
-                    AccountStatus::ExistsInDB | AccountStatus::Unknown => {
-                        let (blob, proof) = match self.latest_persistent_version {
-                            Some(version) => self
-                                .reader
-                                .get_account_state_with_proof_by_version(address, version)?,
-                            None => (None, SparseMerkleProof::new(None, vec![])),
-                        };
-                        proof
-                            .verify(
-                                self.latest_persistent_state_root,
-                                address.hash(),
-                                blob.as_ref(),
-                            )
-                            .map_err(|err| {
-                                format_err!(
-                                "Proof is invalid for address {:?} with state root hash {:?}: {}",
-                                address,
-                                self.latest_persistent_state_root,
-                                err
-                            )
-                            })?;
-                        assert!(self
-                            .account_to_proof_cache
-                            .borrow_mut()
-                            .insert(address_hash, proof)
-                            .is_none());
-                        blob
-                    }
+        if let Some(account_state) = self.account_to_state_cache.lock().unwrap().get(&address) {
+            return Ok(account_state.get(path).cloned());
+        }
+
+        // The caches are not locked while reading from the db, so that concurrent readers of
+        // other accounts are not blocked. Readers of the same account may race to fetch it, in
+        // which case the first one to insert wins; they all read the same verified state anyway.
+        let address_hash = address.hash();
+        let account_blob_option = match self.speculative_state.get(address_hash) {
+            AccountStatus::ExistsInScratchPad(blob) => Some(blob),
+            AccountStatus::DoesNotExist => None,
+            // No matter it is in db or unknown, we have to query from db since even the
+            // former case, we don't have the blob data but only its hash.
+            AccountStatus::ExistsInDB | AccountStatus::Unknown => {
+                let (blob, proof) = match self.latest_persistent_version {
+                    Some(version) => self
+                        .reader
+                        .get_account_state_with_proof_by_version(address, version)?,
+                    None => (None, SparseMerkleProof::new(None, vec![])),
                 };
-                Ok(vacant
-                    .insert(
-                        account_blob_option
-                            .as_ref()
-                            .map(TryInto::try_into)
-                            .transpose()?
-                            .unwrap_or_default(),
+                proof
+                    .verify(
+                        self.latest_persistent_state_root,
+                        address.hash(),
+                        blob.as_ref(),
                     )
-                    .get(path)
-                    .cloned())
+                    .map_err(|err| {
+                        format_err!(
+                            "Proof is invalid for address {:?} with state root hash {:?}: {}",
+                            address,
+                            self.latest_persistent_state_root,
+                            err
+                        )
+                    })?;
+                self.account_to_proof_cache
+                    .lock()
+                    .unwrap()
+                    .entry(address_hash)
+                    .or_insert(proof);
+                blob
             }
-        }
+        };
+        let account_state: AccountState = account_blob_option
+            .as_ref()
+            .map(TryInto::try_into)
+            .transpose()?
+            .unwrap_or_default();
+        Ok(self
+            .account_to_state_cache
+            .lock()
+            .unwrap()
+            .entry(address)
+            .or_insert(account_state)
+            .get(path)
+            .cloned())
     }
 
     fn multi_get(&self, _access_paths: &[AccessPath]) -> Result<Vec<Option<Vec<u8>>>> {
@@ -201,3 +205,51 @@ impl<'a> StateView for VerifiedStateView<'a> {
         self.latest_persistent_version.is_none()
     }
 }
+
+/// `DbStateView` is the committed global state as of a version, read from persistent storage
+/// without proofs, for services like JSON-RPC that only read from their own database. The state of
+/// each account is read once per view, so a view is meant to serve a single request.
+pub struct DbStateView {
+    reader: Arc<dyn DbReader>,
+    version: Version,
+    account_states: Mutex<HashMap<AccountAddress, Option<AccountState>>>,
+}
+
+impl DbStateView {
+    pub fn new(reader: Arc<dyn DbReader>, version: Version) -> Self {
+        Self {
+            reader,
+            version,
+            account_states: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl StateView for DbStateView {
+    fn get(&self, access_path: &AccessPath) -> Result<Option<Vec<u8>>> {
+        let address = access_path.address;
+        let mut account_states = self.account_states.lock().unwrap();
+        if !account_states.contains_key(&address) {
+            let account_state = self
+                .reader
+                .get_account_state_by_version(address, self.version)?
+                .map(|blob| AccountState::try_from(&blob))
+                .transpose()?;
+            account_states.insert(address, account_state);
+        }
+        Ok(account_states[&address]
+            .as_ref()
+            .and_then(|account_state| account_state.get(&access_path.path).cloned()))
+    }
+
+    fn multi_get(&self, access_paths: &[AccessPath]) -> Result<Vec<Option<Vec<u8>>>> {
+        access_paths
+            .iter()
+            .map(|access_path| self.get(access_path))
+            .collect()
+    }
+
+    fn is_genesis(&self) -> bool {
+        false
+    }
+}
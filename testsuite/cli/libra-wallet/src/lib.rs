@@ -19,6 +19,9 @@ mod key_factory;
 /// Utils for mnemonic seed
 mod mnemonic;
 
+/// Utils for multi-signature accounts
+pub mod multisig;
+
 /// Utils for wallet library
 mod wallet_library;
 
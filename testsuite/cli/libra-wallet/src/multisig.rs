@@ -0,0 +1,146 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for K-of-N multi-signature accounts. The authentication key of such an account is
+//! derived from a `MultiEd25519PublicKey`, i.e. N Ed25519 public keys and a threshold K. Each of
+//! the N key holders signs a transaction independently with their own Ed25519 key, and any K of
+//! these partial signatures are then assembled into a `MultiEd25519Signature`.
+
+use crate::error::WalletError;
+use anyhow::Result;
+use libra_crypto::{
+    ed25519::{Ed25519PublicKey, Ed25519Signature},
+    multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
+    traits::Signature,
+};
+use libra_types::transaction::{
+    authenticator::AuthenticationKey, RawTransaction, SignedTransaction,
+};
+use serde::{Deserialize, Serialize};
+
+/// The signature of a transaction by one of the key holders of a multi-signature account.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PartialSignature {
+    /// The public key of the key holder.
+    pub public_key: Ed25519PublicKey,
+    /// The signature of the raw transaction by the key holder.
+    pub signature: Ed25519Signature,
+}
+
+/// Create the public key of a `threshold`-of-`public_keys.len()` multi-signature account.
+pub fn multisig_public_key(
+    public_keys: Vec<Ed25519PublicKey>,
+    threshold: u8,
+) -> Result<MultiEd25519PublicKey> {
+    MultiEd25519PublicKey::new(public_keys, threshold).map_err(|error| {
+        WalletError::LibraWalletGeneric(format!("Invalid multi-signature public key: {}", error))
+            .into()
+    })
+}
+
+/// Compute the authentication key of a multi-signature account, e.g. to rotate an existing
+/// account to it. The address of a new account is derived from this key.
+pub fn multisig_authentication_key(public_key: &MultiEd25519PublicKey) -> AuthenticationKey {
+    AuthenticationKey::multi_ed25519(public_key)
+}
+
+/// Assemble the partial signatures of `raw_txn` into a transaction signed by the
+/// multi-signature account with the given public key. Each partial signature is checked, and
+/// at least as many as the threshold of the account are required.
+pub fn assemble_multisig_txn(
+    raw_txn: RawTransaction,
+    public_key: MultiEd25519PublicKey,
+    partial_signatures: &[PartialSignature],
+) -> Result<SignedTransaction> {
+    let mut signatures = Vec::with_capacity(partial_signatures.len());
+    for partial in partial_signatures {
+        let index = public_key
+            .public_keys()
+            .iter()
+            .position(|key| key == &partial.public_key)
+            .ok_or_else(|| {
+                WalletError::LibraWalletGeneric(format!(
+                    "Public key {} is not a key of this multi-signature account",
+                    partial.public_key
+                ))
+            })?;
+        partial
+            .signature
+            .verify(&raw_txn, &partial.public_key)
+            .map_err(|_| {
+                WalletError::LibraWalletGeneric(format!(
+                    "Invalid signature from public key {}",
+                    partial.public_key
+                ))
+            })?;
+        signatures.push((partial.signature.clone(), index as u8));
+    }
+    if signatures.len() < *public_key.threshold() as usize {
+        return Err(WalletError::LibraWalletGeneric(format!(
+            "Not enough signatures: {} collected, {} required",
+            signatures.len(),
+            public_key.threshold()
+        ))
+        .into());
+    }
+    let signature = MultiEd25519Signature::new(signatures)
+        .map_err(|error| WalletError::LibraWalletGeneric(error.to_string()))?;
+    Ok(SignedTransaction::new_multisig(
+        raw_txn, public_key, signature,
+    ))
+}
+
+#[test]
+fn test_assemble_multisig_txn() {
+    use crate::WalletLibrary;
+    use libra_types::transaction::{helpers::create_unsigned_txn, Script, TransactionPayload};
+
+    let mut wallet = WalletLibrary::new();
+    let addresses: Vec<_> = (0..3)
+        .map(|_| wallet.new_address().unwrap().0.derived_address())
+        .collect();
+    let public_keys = addresses
+        .iter()
+        .map(|address| wallet.get_public_key(address).unwrap())
+        .collect();
+    let public_key = multisig_public_key(public_keys, 2).unwrap();
+    let raw_txn = create_unsigned_txn(
+        TransactionPayload::Script(Script::new(vec![], vec![], vec![])),
+        multisig_authentication_key(&public_key).derived_address(),
+        0,
+        1_000_000,
+        0,
+        "LBR".to_string(),
+        100,
+    );
+    let partial_signatures: Vec<_> = addresses
+        .iter()
+        .map(|address| wallet.sign_txn_as(address, &raw_txn).unwrap())
+        .collect();
+
+    assert!(assemble_multisig_txn(
+        raw_txn.clone(),
+        public_key.clone(),
+        &partial_signatures[..1]
+    )
+    .is_err());
+    let signed_txn = assemble_multisig_txn(
+        raw_txn.clone(),
+        public_key.clone(),
+        &partial_signatures[1..],
+    )
+    .unwrap();
+    assert!(signed_txn.check_signature().is_ok());
+
+    let mut other_wallet = WalletLibrary::new();
+    let (other_key, _) = other_wallet.new_address().unwrap();
+    let other_signature = other_wallet
+        .sign_txn_as(&other_key.derived_address(), &raw_txn)
+        .unwrap();
+    assert!(assemble_multisig_txn(
+        raw_txn,
+        public_key,
+        &[partial_signatures[0].clone(), other_signature]
+    )
+    .is_err());
+}
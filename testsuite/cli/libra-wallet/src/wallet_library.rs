@@ -16,8 +16,10 @@ use crate::{
     io_utils,
     key_factory::{ChildNumber, KeyFactory, Seed},
     mnemonic::Mnemonic,
+    multisig::PartialSignature,
 };
 use anyhow::Result;
+use libra_crypto::ed25519::Ed25519PublicKey;
 use libra_types::{
     account_address::AccountAddress,
     transaction::{
@@ -154,6 +156,39 @@ impl WalletLibrary {
         Ok(ret)
     }
 
+    /// Return the PublicKey associated to a particular AccountAddress of this wallet, e.g. to
+    /// share it with the other key holders of a multi-signature account.
+    pub fn get_public_key(&self, address: &AccountAddress) -> Result<Ed25519PublicKey> {
+        let child = self.get_child_number(address)?;
+        Ok(self.key_factory.private_child(child)?.get_public())
+    }
+
+    /// Sign a Libra RawTransaction with the PrivateKey associated to `signer`, which does not
+    /// need to be the sender of the transaction. This is how the key holders of a
+    /// multi-signature account produce their partial signatures.
+    pub fn sign_txn_as(
+        &self,
+        signer: &AccountAddress,
+        txn: &RawTransaction,
+    ) -> Result<PartialSignature> {
+        let child = self.get_child_number(signer)?;
+        let child_key = self.key_factory.private_child(child)?;
+        Ok(PartialSignature {
+            public_key: child_key.get_public(),
+            signature: child_key.sign(txn),
+        })
+    }
+
+    fn get_child_number(&self, address: &AccountAddress) -> Result<ChildNumber> {
+        self.addr_map.get(address).copied().ok_or_else(|| {
+            WalletError::LibraWalletGeneric(format!(
+                "Address {} is not an address of this wallet",
+                address
+            ))
+            .into()
+        })
+    }
+
     /// Simple public function that allows to sign a Libra RawTransaction with the PrivateKey
     /// associated to a particular AccountAddress. If the PrivateKey associated to an
     /// AccountAddress is not contained in the addr_map, then this function will return an Error
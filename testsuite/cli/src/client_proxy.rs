@@ -4,12 +4,14 @@
 use crate::{
     commands::{is_address, is_authentication_key},
     libra_client::LibraClient,
+    offline_transaction::{read_json_file, write_json_file, OfflineTransaction},
     AccountData, AccountStatus,
 };
 use anyhow::{bail, ensure, format_err, Error, Result};
 use compiled_stdlib::{transaction_scripts::StdlibScript, StdLibOptions};
 use libra_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
+    multi_ed25519::MultiEd25519PublicKey,
     test_utils::KeyPair,
     traits::ValidCryptoMaterial,
     x25519, ValidCryptoMaterialStringExt,
@@ -37,7 +39,11 @@ use libra_types::{
     vm_status::StatusCode,
     waypoint::Waypoint,
 };
-use libra_wallet::{io_utils, WalletLibrary};
+use libra_wallet::{
+    io_utils,
+    multisig::{self, PartialSignature},
+    WalletLibrary,
+};
 use num_traits::{
     cast::{FromPrimitive, ToPrimitive},
     identities::Zero,
@@ -61,6 +67,8 @@ const CLIENT_WALLET_MNEMONIC_FILE: &str = "client.mnemonic";
 const GAS_UNIT_PRICE: u64 = 0;
 const MAX_GAS_AMOUNT: u64 = 1_000_000;
 const TX_EXPIRATION: i64 = 100;
+/// Transactions signed offline leave time to carry files to and from the signing machine.
+const OFFLINE_TX_EXPIRATION: i64 = 3600;
 
 /// Enum used for error formatting.
 #[derive(Debug)]
@@ -969,6 +977,268 @@ impl ClientProxy {
         self.wait_for_transaction(sender_address, sender_sequence + 1)
     }
 
+    /// Build an unsigned transfer transaction and write it to a file, so that it can be signed
+    /// on an offline machine with `sign_offline_transaction`.
+    pub fn prepare_offline_transfer(
+        &mut self,
+        space_delim_strings: &[&str],
+    ) -> Result<RawTransaction> {
+        ensure!(
+            space_delim_strings.len() >= 6 && space_delim_strings.len() <= 9,
+            "Invalid number of arguments for preparing an offline transfer"
+        );
+
+        // The signer is offline: the sequence number of the sender must come from the chain
+        // rather than from a local account.
+        let (sender_address, _) =
+            self.get_account_address_from_parameter(space_delim_strings[2])?;
+        let sequence_number = self
+            .get_account_resource_and_update(sender_address)?
+            .sequence_number;
+        let num_coins =
+            self.convert_to_on_chain_represenation(space_delim_strings[4], space_delim_strings[5])?;
+        self.write_offline_transfer(space_delim_strings, sequence_number, num_coins)
+    }
+
+    /// Build the unsigned transfer transaction of `prepare_offline_transfer`, with the sequence
+    /// number and amount it read from the chain, and write it to a file.
+    fn write_offline_transfer(
+        &self,
+        space_delim_strings: &[&str],
+        sequence_number: u64,
+        num_coins: u64,
+    ) -> Result<RawTransaction> {
+        let output_file = Path::new(space_delim_strings[1]);
+        let (sender_address, _) =
+            self.get_account_address_from_parameter(space_delim_strings[2])?;
+        let (receiver_address, _) =
+            self.get_account_address_from_parameter(space_delim_strings[3])?;
+        let transfer_currency = space_delim_strings[5];
+        let currency_code = from_currency_code_string(transfer_currency)
+            .map_err(|_| format_err!("Invalid currency code {} specified", transfer_currency))?;
+
+        let parse_optional_u64 = |index: usize, field: &str| -> Result<Option<u64>> {
+            match space_delim_strings.get(index) {
+                Some(value) => Ok(Some(value.parse::<u64>().map_err(|error| {
+                    format_parse_data_error(field, InputType::UnsignedInt, value, error)
+                })?)),
+                None => Ok(None),
+            }
+        };
+        let gas_unit_price = parse_optional_u64(6, "gas_unit_price")?;
+        let max_gas_amount = parse_optional_u64(7, "max_gas_amount")?;
+        let expiration_secs = parse_optional_u64(8, "expiration_secs")?;
+
+        let program = transaction_builder::encode_transfer_with_metadata_script(
+            type_tag_for_currency_code(currency_code),
+            receiver_address,
+            num_coins,
+            vec![],
+            vec![],
+        );
+        let raw_txn = create_unsigned_txn(
+            TransactionPayload::Script(program),
+            sender_address,
+            sequence_number,
+            max_gas_amount.unwrap_or(MAX_GAS_AMOUNT),
+            gas_unit_price.unwrap_or(GAS_UNIT_PRICE),
+            transfer_currency.to_owned(),
+            expiration_secs.map_or(OFFLINE_TX_EXPIRATION, |secs| secs as i64),
+        );
+        OfflineTransaction::unsigned(&raw_txn)?.write(output_file)?;
+        Ok(raw_txn)
+    }
+
+    /// Sign the transaction read from a file with the wallet of this client, or with the wallet
+    /// recovered from the given mnemonic file, and write the signed transaction to another file.
+    /// The transaction is only signed if `confirm` accepts it, otherwise `None` is returned and
+    /// nothing is written. This does not require a connection to a validator.
+    pub fn sign_offline_transaction(
+        &self,
+        space_delim_strings: &[&str],
+        confirm: impl FnOnce(&RawTransaction) -> bool,
+    ) -> Result<Option<SignedTransaction>> {
+        ensure!(
+            space_delim_strings.len() == 3 || space_delim_strings.len() == 4,
+            "Invalid number of arguments for signing an offline transaction"
+        );
+
+        let offline_txn = OfflineTransaction::read(Path::new(space_delim_strings[1]))?;
+        ensure!(
+            !offline_txn.is_signed(),
+            "Transaction in {} is already signed",
+            space_delim_strings[1]
+        );
+        let raw_txn = offline_txn.raw_txn()?;
+        if !confirm(&raw_txn) {
+            return Ok(None);
+        }
+        let signed_txn = match space_delim_strings.get(3) {
+            Some(mnemonic_file) => {
+                WalletLibrary::recover(&Path::new(mnemonic_file))?.sign_txn(raw_txn)?
+            }
+            None => self.wallet.sign_txn(raw_txn)?,
+        };
+        OfflineTransaction::signed(&signed_txn)?.write(Path::new(space_delim_strings[2]))?;
+        Ok(Some(signed_txn))
+    }
+
+    /// Read a transaction from a file, whether it is signed or not.
+    pub fn read_offline_transaction(&self, space_delim_strings: &[&str]) -> Result<RawTransaction> {
+        ensure!(
+            space_delim_strings.len() == 2,
+            "Invalid number of arguments for reading an offline transaction"
+        );
+        OfflineTransaction::read(Path::new(space_delim_strings[1]))?.raw_txn()
+    }
+
+    /// Submit a transaction signed offline and read from a file. If is_blocking = true, it will
+    /// keep querying validator till the transaction is executed.
+    pub fn submit_offline_transaction(
+        &mut self,
+        space_delim_strings: &[&str],
+        is_blocking: bool,
+    ) -> Result<SignedTransaction> {
+        ensure!(
+            space_delim_strings.len() == 2,
+            "Invalid number of arguments for submitting an offline transaction"
+        );
+
+        let signed_txn =
+            Self::read_verified_offline_transaction(Path::new(space_delim_strings[1]))?;
+        let sender_address = signed_txn.sender();
+        let sender_sequence = signed_txn.sequence_number();
+
+        self.client.submit_transaction(None, signed_txn.clone())?;
+        if is_blocking {
+            self.wait_for_transaction(sender_address, sender_sequence + 1)?;
+        }
+        Ok(signed_txn)
+    }
+
+    /// Read a signed transaction from a file and check its signature.
+    fn read_verified_offline_transaction(path: &Path) -> Result<SignedTransaction> {
+        Ok(OfflineTransaction::read(path)?
+            .signed_txn()?
+            .check_signature()?
+            .into_inner())
+    }
+
+    /// Get the public key of an account of the wallet, to share it with the other key holders
+    /// of a multi-signature account.
+    pub fn get_wallet_public_key(&self, space_delim_strings: &[&str]) -> Result<Ed25519PublicKey> {
+        ensure!(
+            space_delim_strings.len() == 2,
+            "Invalid number of arguments for getting a public key"
+        );
+        let (address, _) = self.get_account_address_from_parameter(space_delim_strings[1])?;
+        self.wallet.get_public_key(&address)
+    }
+
+    /// Create the public key of a K-of-N multi-signature account from the public keys of its
+    /// key holders, given either in hex or as accounts of the wallet, and write it to a file.
+    pub fn create_multisig_public_key(
+        &self,
+        space_delim_strings: &[&str],
+    ) -> Result<MultiEd25519PublicKey> {
+        ensure!(
+            space_delim_strings.len() >= 4,
+            "Invalid number of arguments for creating a multi-signature public key"
+        );
+        let threshold = space_delim_strings[2].parse::<u8>().map_err(|error| {
+            format_parse_data_error(
+                "threshold",
+                InputType::UnsignedInt,
+                space_delim_strings[2],
+                error,
+            )
+        })?;
+        let public_keys = space_delim_strings[3..]
+            .iter()
+            .map(|param| match Ed25519PublicKey::from_encoded_string(param) {
+                Ok(public_key) => Ok(public_key),
+                Err(_) => {
+                    let (address, _) = self.get_account_address_from_parameter(param)?;
+                    self.wallet.get_public_key(&address)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let public_key = multisig::multisig_public_key(public_keys, threshold)?;
+        write_json_file(&public_key, Path::new(space_delim_strings[1]))?;
+        Ok(public_key)
+    }
+
+    /// Rotate the authentication key of an account of the wallet to the key of a
+    /// multi-signature account read from a file. The wallet can no longer sign alone for
+    /// this account afterwards.
+    pub fn rotate_to_multisig(
+        &mut self,
+        space_delim_strings: &[&str],
+    ) -> Result<AuthenticationKey> {
+        ensure!(
+            space_delim_strings.len() == 3,
+            "Invalid number of arguments for rotating to a multi-signature key"
+        );
+        let public_key: MultiEd25519PublicKey = read_json_file(Path::new(space_delim_strings[2]))?;
+        let authentication_key = multisig::multisig_authentication_key(&public_key);
+        let program = transaction_builder::encode_rotate_authentication_key_script(
+            authentication_key.to_vec(),
+        );
+        self.submit_program(space_delim_strings, TransactionPayload::Script(program))?;
+        self.mut_account_from_parameter(space_delim_strings[1])?
+            .authentication_key = Some(authentication_key.to_vec());
+        Ok(authentication_key)
+    }
+
+    /// Sign the unsigned transaction of a multi-signature account read from a file with the key
+    /// of one of its key holders, and write the partial signature to a file. The transaction is
+    /// passed to confirm first, and nothing is signed nor written unless it returns true.
+    pub fn sign_multisig_transaction(
+        &self,
+        space_delim_strings: &[&str],
+        confirm: impl FnOnce(&RawTransaction) -> bool,
+    ) -> Result<Option<PartialSignature>> {
+        ensure!(
+            space_delim_strings.len() == 4,
+            "Invalid number of arguments for signing a multi-signature transaction"
+        );
+        let offline_txn = OfflineTransaction::read(Path::new(space_delim_strings[1]))?;
+        ensure!(
+            !offline_txn.is_signed(),
+            "Transaction in {} is already signed",
+            space_delim_strings[1]
+        );
+        let raw_txn = offline_txn.raw_txn()?;
+        let (signer, _) = self.get_account_address_from_parameter(space_delim_strings[2])?;
+        if !confirm(&raw_txn) {
+            return Ok(None);
+        }
+        let partial_signature = self.wallet.sign_txn_as(&signer, &raw_txn)?;
+        write_json_file(&partial_signature, Path::new(space_delim_strings[3]))?;
+        Ok(Some(partial_signature))
+    }
+
+    /// Assemble the partial signatures read from files into a transaction signed by a
+    /// multi-signature account, and write it to a file to be submitted.
+    pub fn assemble_multisig_transaction(
+        &self,
+        space_delim_strings: &[&str],
+    ) -> Result<SignedTransaction> {
+        ensure!(
+            space_delim_strings.len() >= 5,
+            "Invalid number of arguments for assembling a multi-signature transaction"
+        );
+        let raw_txn = OfflineTransaction::read(Path::new(space_delim_strings[1]))?.raw_txn()?;
+        let public_key: MultiEd25519PublicKey = read_json_file(Path::new(space_delim_strings[2]))?;
+        let partial_signatures = space_delim_strings[4..]
+            .iter()
+            .map(|file| read_json_file(Path::new(file)))
+            .collect::<Result<Vec<PartialSignature>>>()?;
+        let signed_txn = multisig::assemble_multisig_txn(raw_txn, public_key, &partial_signatures)?;
+        OfflineTransaction::signed(&signed_txn)?.write(Path::new(space_delim_strings[3]))?;
+        Ok(signed_txn)
+    }
+
     fn submit_program(
         &mut self,
         space_delim_strings: &[&str],
@@ -1642,9 +1912,19 @@ impl fmt::Display for AccountEntry {
 
 #[cfg(test)]
 mod tests {
-    use crate::client_proxy::{parse_bool, AddressAndIndex, ClientProxy};
+    use crate::{
+        client_proxy::{parse_bool, AddressAndIndex, ClientProxy},
+        offline_transaction::OfflineTransaction,
+    };
     use libra_temppath::TempPath;
-    use libra_types::{ledger_info::LedgerInfo, on_chain_config::ValidatorSet, waypoint::Waypoint};
+    use libra_types::{
+        account_address::AccountAddress,
+        account_config::LBR_NAME,
+        ledger_info::LedgerInfo,
+        on_chain_config::ValidatorSet,
+        transaction::{authenticator::AuthenticationKey, SignedTransaction},
+        waypoint::Waypoint,
+    };
     use libra_wallet::io_utils;
     use proptest::prelude::*;
 
@@ -1787,6 +2067,211 @@ mod tests {
         assert_eq!(client.wallet.mnemonic(), wallet.mnemonic());
     }
 
+    #[test]
+    fn test_offline_signing() {
+        let (mut client, accounts) = generate_accounts_from_wallet(2);
+        let raw_txn = client
+            .prepare_transfer_coins(
+                accounts[0].address,
+                0,
+                accounts[1].address,
+                10,
+                LBR_NAME.to_owned(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let unsigned_file = TempPath::new();
+        let signed_file = TempPath::new();
+        let unsigned_path = unsigned_file.path().to_str().unwrap();
+        let signed_path = signed_file.path().to_str().unwrap();
+        OfflineTransaction::unsigned(&raw_txn)
+            .unwrap()
+            .write(unsigned_file.path())
+            .unwrap();
+
+        // Nothing is written when the transaction is not confirmed.
+        assert_eq!(
+            client
+                .sign_offline_transaction(&["sign", unsigned_path, signed_path], |txn| {
+                    assert_eq!(txn, &raw_txn);
+                    false
+                })
+                .unwrap(),
+            None
+        );
+        assert!(!signed_file.path().exists());
+
+        let signed_txn = client
+            .sign_offline_transaction(&["sign", unsigned_path, signed_path], |_| true)
+            .unwrap()
+            .unwrap();
+        let offline_txn = OfflineTransaction::read(signed_file.path()).unwrap();
+        assert!(offline_txn.is_signed());
+        assert_eq!(offline_txn.raw_txn().unwrap(), raw_txn);
+        assert_eq!(offline_txn.signed_txn().unwrap(), signed_txn);
+        assert!(signed_txn.check_signature().is_ok());
+
+        // Signed transactions are not signed again.
+        assert!(client
+            .sign_offline_transaction(&["sign", signed_path, unsigned_path], |_| true)
+            .is_err());
+    }
+
+    #[test]
+    fn test_offline_transfer_files() {
+        let (client, accounts) = generate_accounts_from_wallet(2);
+        let unsigned_file = TempPath::new();
+        let signed_file = TempPath::new();
+        let mnemonic_file = TempPath::new();
+        let unsigned_path = unsigned_file.path().to_str().unwrap();
+        let signed_path = signed_file.path().to_str().unwrap();
+        let mnemonic_path = mnemonic_file.path().to_str().unwrap();
+        io_utils::write_recovery(&client.wallet, &mnemonic_file.path()).unwrap();
+
+        let raw_txn = client
+            .write_offline_transfer(
+                &["prepare", unsigned_path, "0", "1", "10", LBR_NAME],
+                7,
+                10_000_000,
+            )
+            .unwrap();
+        assert_eq!(raw_txn.sender(), accounts[0].address);
+        assert_eq!(raw_txn.sequence_number(), 7);
+        let offline_txn = OfflineTransaction::read(unsigned_file.path()).unwrap();
+        assert!(!offline_txn.is_signed());
+        assert_eq!(offline_txn.raw_txn().unwrap(), raw_txn);
+
+        // The offline machine only holds the mnemonic of the sender.
+        let (offline_client, _) = generate_accounts_from_wallet(0);
+        let sign = ["sign", unsigned_path, signed_path, mnemonic_path];
+        assert_eq!(
+            offline_client
+                .sign_offline_transaction(&sign, |_| false)
+                .unwrap(),
+            None
+        );
+        assert!(!signed_file.path().exists());
+        let signed_txn = offline_client
+            .sign_offline_transaction(&sign, |txn| {
+                assert_eq!(txn, &raw_txn);
+                true
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            ClientProxy::read_verified_offline_transaction(signed_file.path()).unwrap(),
+            signed_txn
+        );
+        assert_eq!(signed_txn.into_raw_transaction(), raw_txn);
+
+        // A signed transaction modified afterwards is rejected.
+        let mut bytes = lcs::to_bytes(
+            &OfflineTransaction::read(signed_file.path())
+                .unwrap()
+                .signed_txn()
+                .unwrap(),
+        )
+        .unwrap();
+        // The first byte of the sequence number, right after the sender.
+        bytes[AccountAddress::LENGTH] += 1;
+        let tampered_txn: SignedTransaction = lcs::from_bytes(&bytes).unwrap();
+        OfflineTransaction::signed(&tampered_txn)
+            .unwrap()
+            .write(signed_file.path())
+            .unwrap();
+        assert!(ClientProxy::read_verified_offline_transaction(signed_file.path()).is_err());
+    }
+
+    #[test]
+    fn test_multisig_signing() {
+        let (mut client, accounts) = generate_accounts_from_wallet(3);
+        let key_file = TempPath::new();
+        let key_path = key_file.path().to_str().unwrap();
+        let public_key = client
+            .create_multisig_public_key(&["create", key_path, "2", "0", "1", "2"])
+            .unwrap();
+        let sender = AuthenticationKey::multi_ed25519(&public_key).derived_address();
+        let raw_txn = client
+            .prepare_transfer_coins(
+                sender,
+                0,
+                accounts[0].address,
+                10,
+                LBR_NAME.to_owned(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let txn_file = TempPath::new();
+        let txn_path = txn_file.path().to_str().unwrap();
+        OfflineTransaction::unsigned(&raw_txn)
+            .unwrap()
+            .write(txn_file.path())
+            .unwrap();
+        let signature_files = vec![TempPath::new(), TempPath::new()];
+        let signature_paths: Vec<_> = signature_files
+            .iter()
+            .map(|file| file.path().to_str().unwrap())
+            .collect();
+        // Nothing is written when the key holder does not confirm the transaction.
+        assert_eq!(
+            client
+                .sign_multisig_transaction(&["sign", txn_path, "0", signature_paths[0]], |txn| {
+                    assert_eq!(txn, &raw_txn);
+                    false
+                })
+                .unwrap(),
+            None
+        );
+        assert!(!signature_files[0].path().exists());
+
+        client
+            .sign_multisig_transaction(&["sign", txn_path, "0", signature_paths[0]], |_| true)
+            .unwrap()
+            .unwrap();
+
+        // One signature is not enough for a 2-of-3 account.
+        let signed_file = TempPath::new();
+        let signed_path = signed_file.path().to_str().unwrap();
+        assert!(client
+            .assemble_multisig_transaction(&[
+                "assemble",
+                txn_path,
+                key_path,
+                signed_path,
+                signature_paths[0]
+            ])
+            .is_err());
+
+        client
+            .sign_multisig_transaction(&["sign", txn_path, "2", signature_paths[1]], |_| true)
+            .unwrap()
+            .unwrap();
+        let signed_txn = client
+            .assemble_multisig_transaction(&[
+                "assemble",
+                txn_path,
+                key_path,
+                signed_path,
+                signature_paths[0],
+                signature_paths[1],
+            ])
+            .unwrap();
+        assert_eq!(
+            OfflineTransaction::read(signed_file.path())
+                .unwrap()
+                .signed_txn()
+                .unwrap(),
+            signed_txn
+        );
+        assert!(signed_txn.check_signature().is_ok());
+    }
+
     proptest! {
         // Proptest is used to verify that the conversion will not panic with random input.
         #[test]
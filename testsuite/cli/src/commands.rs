@@ -3,12 +3,17 @@
 
 use crate::{
     account_commands::AccountCommand, client_proxy::ClientProxy, dev_commands::DevCommand,
+    multisig_commands::MultisigCommand, offline_commands::OfflineCommand,
     query_commands::QueryCommand, transfer_commands::TransferCommand,
 };
 use anyhow::Error;
 use libra_metrics::counters::*;
 use libra_types::{account_address::AccountAddress, transaction::authenticator::AuthenticationKey};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    sync::Arc,
+};
 
 /// Print the error and bump up error counter.
 pub fn report_error(msg: &str, e: Error) {
@@ -21,6 +26,19 @@ pub fn blocking_cmd(cmd: &str) -> bool {
     cmd.ends_with('b')
 }
 
+/// Ask a yes/no question on the terminal. Anything but "y" or "yes" is a no.
+pub fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    if io::stdout().flush().is_err() {
+        return false;
+    }
+    let mut answer = String::new();
+    match io::stdin().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+        Err(_) => false,
+    }
+}
+
 /// Check whether a command is debugging command.
 pub fn debug_format_cmd(cmd: &str) -> bool {
     cmd.ends_with('?')
@@ -48,6 +66,8 @@ pub fn get_commands(
         Arc::new(AccountCommand {}),
         Arc::new(QueryCommand {}),
         Arc::new(TransferCommand {}),
+        Arc::new(OfflineCommand {}),
+        Arc::new(MultisigCommand {}),
     ];
     if include_dev {
         commands.push(Arc::new(DevCommand {}));
@@ -25,6 +25,9 @@ pub mod commands;
 mod dev_commands;
 /// Client wrapper to connect to validator.
 mod libra_client;
+mod multisig_commands;
+mod offline_commands;
+mod offline_transaction;
 mod query_commands;
 mod transfer_commands;
 
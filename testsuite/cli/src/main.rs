@@ -64,6 +64,10 @@ struct Args {
     /// Verbose output.
     #[structopt(short = "v", long = "verbose")]
     pub verbose: bool,
+    /// If set, the client does not connect to the validator, e.g. to sign transactions with
+    /// the `offline` commands on an air-gapped machine.
+    #[structopt(long)]
+    pub offline: bool,
 }
 
 fn main() {
@@ -83,7 +87,11 @@ fn main() {
 
     // If waypoint is given explicitly, use its value,
     // otherwise waypoint_url is required, try to retrieve the waypoint from the URL.
+    // An offline client never verifies responses from a validator and needs no waypoint.
     let waypoint = args.waypoint.unwrap_or_else(|| {
+        if args.offline {
+            return Waypoint::default();
+        }
         args.waypoint_url
             .as_ref()
             .map(|url_str| {
@@ -97,31 +105,35 @@ fn main() {
         &args.url,
         &faucet_account_file,
         &treasury_compliance_account_file,
-        args.sync,
+        args.sync && !args.offline,
         args.faucet_server.clone(),
         mnemonic_file,
         waypoint,
     )
     .expect("Failed to construct client.");
 
-    // Test connection to validator
-    let block_metadata = client_proxy
-        .test_validator_connection()
-        .unwrap_or_else(|e| {
-            panic!(
-                "Not able to connect to validator at {}. Error: {}",
-                args.url, e,
-            )
-        });
-    let ledger_info_str = format!(
-        "latest version = {}, timestamp = {}",
-        block_metadata.version,
-        DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_micros(block_metadata.timestamp))
-    );
-    let cli_info = format!(
-        "Connected to validator at: {}, {}",
-        args.url, ledger_info_str
-    );
+    let cli_info = if args.offline {
+        "Running offline: transactions can be signed but not submitted".to_string()
+    } else {
+        // Test connection to validator
+        let block_metadata = client_proxy
+            .test_validator_connection()
+            .unwrap_or_else(|e| {
+                panic!(
+                    "Not able to connect to validator at {}. Error: {}",
+                    args.url, e,
+                )
+            });
+        let ledger_info_str = format!(
+            "latest version = {}, timestamp = {}",
+            block_metadata.version,
+            DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_micros(block_metadata.timestamp))
+        );
+        format!(
+            "Connected to validator at: {}, {}",
+            args.url, ledger_info_str
+        )
+    };
     if args.mnemonic_file.is_some() {
         match client_proxy.recover_accounts_in_wallet() {
             Ok(account_data) => {
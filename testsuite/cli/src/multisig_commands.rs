@@ -0,0 +1,185 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client_proxy::ClientProxy,
+    commands::{confirm, report_error, subcommand_execute, Command},
+    offline_transaction::format_raw_transaction,
+};
+use libra_crypto::ValidCryptoMaterialStringExt;
+use libra_types::transaction::authenticator::AuthenticationKey;
+
+/// Major command for multi-signature accounts. Transactions of such accounts are prepared and
+/// submitted with the `offline` commands.
+pub struct MultisigCommand {}
+
+impl Command for MultisigCommand {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["multisig", "ms"]
+    }
+    fn get_description(&self) -> &'static str {
+        "Multi-signature account operations"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        let commands: Vec<Box<dyn Command>> = vec![
+            Box::new(MultisigCommandPublicKey {}),
+            Box::new(MultisigCommandCreate {}),
+            Box::new(MultisigCommandRotate {}),
+            Box::new(MultisigCommandSign {}),
+            Box::new(MultisigCommandAssemble {}),
+        ];
+
+        subcommand_execute(&params[0], commands, client, &params[1..]);
+    }
+}
+
+/// Sub command to print the public key of an account of the wallet.
+pub struct MultisigCommandPublicKey {}
+
+impl Command for MultisigCommandPublicKey {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["public_key", "pk"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<account_address>|<account_ref_id>"
+    }
+    fn get_description(&self) -> &'static str {
+        "Print the public key of an account of the wallet, to share with the other key holders"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        match client
+            .get_wallet_public_key(&params)
+            .and_then(|public_key| public_key.to_encoded_string())
+        {
+            Ok(public_key) => println!("Public key: {}", public_key),
+            Err(e) => report_error("Error getting public key", e),
+        }
+    }
+}
+
+/// Sub command to create the public key of a K-of-N multi-signature account.
+pub struct MultisigCommandCreate {}
+
+impl Command for MultisigCommandCreate {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["create", "c"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<output_file_path> <threshold> <public_key>|<account_address>|<account_ref_id> ..."
+    }
+    fn get_description(&self) -> &'static str {
+        "Create the public key of a multi-signature account requiring <threshold> signatures \
+         from the given keys. Coins sent to its authentication key create the account."
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        println!(">> Creating multi-signature public key");
+        match client.create_multisig_public_key(&params) {
+            Ok(public_key) => {
+                let authentication_key = AuthenticationKey::multi_ed25519(&public_key);
+                println!(
+                    "Created {}-of-{} public key, written to {}",
+                    public_key.threshold(),
+                    public_key.public_keys().len(),
+                    params[1]
+                );
+                println!(
+                    "Authentication key: {}",
+                    hex::encode(authentication_key.to_vec())
+                );
+                println!(
+                    "Address of a new account: {}",
+                    hex::encode(authentication_key.derived_address())
+                );
+            }
+            Err(e) => report_error("Error creating multi-signature public key", e),
+        }
+    }
+}
+
+/// Sub command to rotate the authentication key of an account to a multi-signature key.
+pub struct MultisigCommandRotate {}
+
+impl Command for MultisigCommandRotate {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["rotate", "r"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<account_address>|<account_ref_id> <public_key_file_path>"
+    }
+    fn get_description(&self) -> &'static str {
+        "Rotate the authentication key of an account of the wallet to a multi-signature key"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        println!(">> Rotating authentication key");
+        match client.rotate_to_multisig(&params) {
+            Ok(authentication_key) => println!(
+                "Authentication key rotated to {}",
+                hex::encode(authentication_key.to_vec())
+            ),
+            Err(e) => report_error("Error rotating authentication key", e),
+        }
+    }
+}
+
+/// Sub command for a key holder to sign a transaction of a multi-signature account.
+pub struct MultisigCommandSign {}
+
+impl Command for MultisigCommandSign {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["sign", "s"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<transaction_file_path> <signer_account_address>|<signer_account_ref_id> \
+         <output_file_path> [--yes]"
+    }
+    fn get_description(&self) -> &'static str {
+        "Sign an unsigned transaction with the key of one of the key holders of the sender. The \
+         transaction is printed and only signed once confirmed, unless --yes is given"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        let yes = params.contains(&"--yes");
+        let params: Vec<&str> = params
+            .iter()
+            .copied()
+            .filter(|param| *param != "--yes")
+            .collect();
+        println!(">> Signing multi-signature transaction");
+        match client.sign_multisig_transaction(&params, |raw_txn| {
+            println!("{}", format_raw_transaction(raw_txn));
+            yes || confirm("Sign this transaction?")
+        }) {
+            Ok(Some(_)) => println!("Signature written to {}", params[3]),
+            Ok(None) => println!("Transaction was not signed"),
+            Err(e) => report_error("Error signing transaction", e),
+        }
+    }
+}
+
+/// Sub command to assemble the signatures of the key holders into a signed transaction.
+pub struct MultisigCommandAssemble {}
+
+impl Command for MultisigCommandAssemble {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["assemble", "as"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<transaction_file_path> <public_key_file_path> <output_file_path> \
+         <signature_file_path> ..."
+    }
+    fn get_description(&self) -> &'static str {
+        "Assemble signatures into a signed transaction, to submit with 'offline submit'"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        println!(">> Assembling multi-signature transaction");
+        match client.assemble_multisig_transaction(&params) {
+            Ok(signed_txn) => {
+                println!(
+                    "{}",
+                    format_raw_transaction(&signed_txn.into_raw_transaction())
+                );
+                println!("Signed transaction written to {}", params[3]);
+            }
+            Err(e) => report_error("Error assembling transaction", e),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client_proxy::ClientProxy,
+    commands::{blocking_cmd, confirm, report_error, subcommand_execute, Command},
+    offline_transaction::format_raw_transaction,
+};
+
+/// Major command to build, sign and submit transactions in separate steps, so that signing can
+/// happen on a machine that is not connected to the network.
+pub struct OfflineCommand {}
+
+impl Command for OfflineCommand {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["offline", "o"]
+    }
+    fn get_description(&self) -> &'static str {
+        "Offline transaction signing operations"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        let commands: Vec<Box<dyn Command>> = vec![
+            Box::new(OfflineCommandPrepareTransfer {}),
+            Box::new(OfflineCommandSign {}),
+            Box::new(OfflineCommandShow {}),
+            Box::new(OfflineCommandSubmit {}),
+        ];
+
+        subcommand_execute(&params[0], commands, client, &params[1..]);
+    }
+}
+
+/// Sub command to write an unsigned transfer transaction to a file.
+pub struct OfflineCommandPrepareTransfer {}
+
+impl Command for OfflineCommandPrepareTransfer {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["prepare_transfer", "pt"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "\n\t<output_file_path> <sender_account_address>|<sender_account_ref_id> \
+         <receiver_account_address>|<receiver_account_ref_id> <number_of_coins> <currency_code> \
+         [gas_unit_price_in_micro_libras (default=0)] [max_gas_amount_in_micro_libras (default 1_000_000)] \
+         [expiration_in_seconds (default 3600)]"
+    }
+    fn get_description(&self) -> &'static str {
+        "Write an unsigned transfer transaction to a file, to be signed offline. \
+         The sequence number of the sender is read from the validator."
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        println!(">> Preparing transfer for offline signing");
+        match client.prepare_offline_transfer(&params) {
+            Ok(raw_txn) => {
+                println!("{}", format_raw_transaction(&raw_txn));
+                println!("Unsigned transaction written to {}", params[1]);
+            }
+            Err(e) => report_error("Error preparing transfer", e),
+        }
+    }
+}
+
+/// Sub command to sign a transaction read from a file. This is meant to be run offline.
+pub struct OfflineCommandSign {}
+
+impl Command for OfflineCommandSign {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["sign", "s"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<input_file_path> <output_file_path> [mnemonic_file_path] [--yes]"
+    }
+    fn get_description(&self) -> &'static str {
+        "Sign an unsigned transaction with the key of its sender, from the wallet of this client \
+         or from the wallet recovered from the given mnemonic file. The transaction is printed \
+         and only signed once confirmed, unless --yes is given"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        let yes = params.contains(&"--yes");
+        let params: Vec<&str> = params
+            .iter()
+            .copied()
+            .filter(|param| *param != "--yes")
+            .collect();
+        println!(">> Signing transaction");
+        match client.sign_offline_transaction(&params, |raw_txn| {
+            println!("{}", format_raw_transaction(raw_txn));
+            yes || confirm("Sign this transaction?")
+        }) {
+            Ok(Some(_)) => println!("Signed transaction written to {}", params[2]),
+            Ok(None) => println!("Transaction was not signed"),
+            Err(e) => report_error("Error signing transaction", e),
+        }
+    }
+}
+
+/// Sub command to print a transaction read from a file.
+pub struct OfflineCommandShow {}
+
+impl Command for OfflineCommandShow {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["show", "sh"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<file_path>"
+    }
+    fn get_description(&self) -> &'static str {
+        "Print a signed or unsigned transaction"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        match client.read_offline_transaction(&params) {
+            Ok(raw_txn) => println!("{}", format_raw_transaction(&raw_txn)),
+            Err(e) => report_error("Error reading transaction", e),
+        }
+    }
+}
+
+/// Sub command to submit a transaction signed offline.
+pub struct OfflineCommandSubmit {}
+
+impl Command for OfflineCommandSubmit {
+    fn get_aliases(&self) -> Vec<&'static str> {
+        vec!["submit", "submitb"]
+    }
+    fn get_params_help(&self) -> &'static str {
+        "<file_path> Suffix 'b' is for blocking."
+    }
+    fn get_description(&self) -> &'static str {
+        "Check the signature of a transaction signed offline and submit it"
+    }
+    fn execute(&self, client: &mut ClientProxy, params: &[&str]) {
+        println!(">> Submitting signed transaction");
+        let is_blocking = blocking_cmd(&params[0]);
+        match client.submit_offline_transaction(&params, is_blocking) {
+            Ok(signed_txn) => {
+                let sender = signed_txn.sender();
+                let sequence_number = signed_txn.sequence_number();
+                println!(
+                    "{}",
+                    format_raw_transaction(&signed_txn.into_raw_transaction())
+                );
+                if is_blocking {
+                    println!("Finished transaction!");
+                } else {
+                    println!("Transaction submitted to validator");
+                }
+                println!(
+                    "To query for transaction status, run: query txn_acc_seq {} {} \
+                     <fetch_events=true|false>",
+                    hex::encode(sender),
+                    sequence_number
+                );
+            }
+            Err(e) => report_error("Failed to submit transaction", e),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use libra_types::transaction::{RawTransaction, SignedTransaction, TransactionPayload};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fs, path::Path};
+use transaction_builder::ScriptCall;
+
+/// A transaction exchanged as a file between an online machine, which builds and submits it,
+/// and an offline machine, which signs it. Transactions are stored as hex-encoded LCS bytes so
+/// that the file carries exactly what is signed.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OfflineTransaction {
+    /// A transaction waiting to be signed.
+    Unsigned {
+        /// The LCS bytes of the `RawTransaction`.
+        raw_txn: String,
+    },
+    /// A transaction ready to be submitted.
+    Signed {
+        /// The LCS bytes of the `SignedTransaction`.
+        signed_txn: String,
+    },
+}
+
+impl OfflineTransaction {
+    /// Wrap a transaction that still needs to be signed.
+    pub fn unsigned(raw_txn: &RawTransaction) -> Result<Self> {
+        Ok(OfflineTransaction::Unsigned {
+            raw_txn: hex::encode(lcs::to_bytes(raw_txn)?),
+        })
+    }
+
+    /// Wrap a signed transaction.
+    pub fn signed(signed_txn: &SignedTransaction) -> Result<Self> {
+        Ok(OfflineTransaction::Signed {
+            signed_txn: hex::encode(lcs::to_bytes(signed_txn)?),
+        })
+    }
+
+    /// Read a transaction from a JSON file.
+    pub fn read(path: &Path) -> Result<Self> {
+        read_json_file(path)
+    }
+
+    /// Write the transaction to a JSON file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        write_json_file(self, path)
+    }
+
+    /// Return true if the transaction was signed.
+    pub fn is_signed(&self) -> bool {
+        matches!(self, OfflineTransaction::Signed { .. })
+    }
+
+    /// Return the raw transaction, whether or not it was signed.
+    pub fn raw_txn(&self) -> Result<RawTransaction> {
+        match self {
+            OfflineTransaction::Unsigned { raw_txn } => {
+                Ok(lcs::from_bytes(&hex::decode(raw_txn)?)?)
+            }
+            OfflineTransaction::Signed { .. } => Ok(self.signed_txn()?.into_raw_transaction()),
+        }
+    }
+
+    /// Return the signed transaction, or an error if the transaction was not signed yet.
+    pub fn signed_txn(&self) -> Result<SignedTransaction> {
+        match self {
+            OfflineTransaction::Unsigned { .. } => bail!("Transaction is not signed"),
+            OfflineTransaction::Signed { signed_txn } => {
+                Ok(lcs::from_bytes(&hex::decode(signed_txn)?)?)
+            }
+        }
+    }
+}
+
+/// Read a value exchanged between machines, e.g. a transaction or a signature, from a JSON file.
+pub fn read_json_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+/// Write a value exchanged between machines to a JSON file.
+pub fn write_json_file<T: Serialize>(value: &T, path: &Path) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(value)?)?;
+    Ok(())
+}
+
+/// Describe a transaction for a human reviewer. The script is decoded into its typed, named
+/// arguments when it is a known stdlib script.
+pub fn format_raw_transaction(raw_txn: &RawTransaction) -> String {
+    let mut output = format!(
+        "sender: {}\n\
+         sequence_number: {}\n\
+         max_gas_amount: {}\n\
+         gas_unit_price: {}\n\
+         gas_currency_code: {}\n\
+         expiration_time: {}",
+        raw_txn.sender(),
+        raw_txn.sequence_number(),
+        raw_txn.max_gas_amount(),
+        raw_txn.gas_unit_price(),
+        raw_txn.gas_currency_code(),
+        raw_txn.expiration_time().as_secs(),
+    );
+    match raw_txn.payload() {
+        TransactionPayload::Script(script) => match ScriptCall::decode(script) {
+            Some(call) => {
+                output.push_str(&format!("\nscript: {}", call.name()));
+                for (name, type_tag) in call.type_arguments() {
+                    output.push_str(&format!("\n\t{}: {}", name, type_tag));
+                }
+                for (name, value) in call.arguments() {
+                    output.push_str(&format!("\n\t{}: {:?}", name, value));
+                }
+            }
+            None => output.push_str("\nscript: <unknown script>"),
+        },
+        TransactionPayload::WriteSet(_) => output.push_str("\npayload: write set"),
+        TransactionPayload::Module(_) => output.push_str("\npayload: module publishing"),
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_raw_transaction;
+    use libra_types::{
+        account_address::AccountAddress,
+        account_config::{lbr_type_tag, LBR_NAME},
+        transaction::{RawTransaction, Script},
+    };
+    use std::time::Duration;
+
+    fn raw_txn(script: Script) -> RawTransaction {
+        RawTransaction::new_script(
+            AccountAddress::random(),
+            3,
+            script,
+            1_000_000,
+            0,
+            LBR_NAME.to_string(),
+            Duration::from_secs(3600),
+        )
+    }
+
+    #[test]
+    fn formats_decoded_script_arguments() {
+        let payee = AccountAddress::random();
+        let output = format_raw_transaction(&raw_txn(
+            transaction_builder::encode_peer_to_peer_with_metadata_script(
+                lbr_type_tag(),
+                payee,
+                100,
+                vec![],
+                vec![],
+            ),
+        ));
+        assert!(output.contains("sequence_number: 3\n"));
+        assert!(output.contains("expiration_time: 3600\n"));
+        assert!(output.contains("script: peer_to_peer_with_metadata\n"));
+        assert!(output.contains(&format!("payee: {{ADDRESS: {:?}}}\n", payee)));
+        assert!(output.contains("amount: {U64: 100}\n"));
+    }
+
+    #[test]
+    fn formats_unknown_script() {
+        let output =
+            format_raw_transaction(&raw_txn(Script::new(vec![0xde, 0xad], vec![], vec![])));
+        assert!(output.ends_with("script: <unknown script>"));
+    }
+}
@@ -36,11 +36,20 @@ impl Cluster {
                 )
             })
             .collect();
+        Self::from_instances(instances, vec![], mint_file)
+    }
+
+    /// A cluster of local nodes whose mint key is stored in `mint_file`.
+    pub fn from_instances(
+        validator_instances: Vec<Instance>,
+        fullnode_instances: Vec<Instance>,
+        mint_file: &str,
+    ) -> Self {
         let mint_key: Ed25519PrivateKey = generate_key::load_key(mint_file);
         let mint_key_pair = KeyPair::from(mint_key);
         Self {
-            validator_instances: instances,
-            fullnode_instances: vec![],
+            validator_instances,
+            fullnode_instances,
             mint_key_pair,
         }
     }
@@ -0,0 +1,30 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::instance::{Instance, InstanceConfig};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ClusterSwarm: Send + Sync {
+    /// Spawns a new instance.
+    async fn spawn_new_instance(
+        &self,
+        instance_config: InstanceConfig,
+        delete_data: bool,
+    ) -> Result<Instance>;
+
+    /// Deletes the instance spawned with this config.
+    async fn delete_node(&self, instance_config: &InstanceConfig) -> Result<()>;
+
+    /// Runs command in a container of `docker_image` on the host `k8s_node`.
+    async fn run(
+        &self,
+        k8s_node: &str,
+        docker_image: &str,
+        command: &str,
+        job_name: &str,
+    ) -> Result<()>;
+
+    async fn get_grafana_baseurl(&self) -> Result<String>;
+}
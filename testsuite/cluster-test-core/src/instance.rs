@@ -3,7 +3,7 @@
 
 #![forbid(unsafe_code)]
 
-use crate::cluster_swarm::cluster_swarm_kube::ClusterSwarmKube;
+use crate::cluster_swarm::ClusterSwarm;
 use anyhow::{format_err, Result};
 use debug_interface::AsyncNodeDebugClient;
 use libra_config::config::NodeConfig;
@@ -14,6 +14,7 @@ use std::{
     collections::HashSet,
     fmt,
     str::FromStr,
+    sync::Arc,
     time::{Duration, Instant},
 };
 use tokio::{process::Command, time};
@@ -80,7 +81,7 @@ enum InstanceBackend {
 struct K8sInstanceInfo {
     k8s_node: String,
     instance_config: InstanceConfig,
-    kube: ClusterSwarmKube,
+    cluster_swarm: Arc<dyn ClusterSwarm>,
 }
 
 impl InstanceConfig {
@@ -143,12 +144,12 @@ impl Instance {
         k8s_node: String,
         instance_config: InstanceConfig,
         http_client: Client,
-        kube: ClusterSwarmKube,
+        cluster_swarm: Arc<dyn ClusterSwarm>,
     ) -> Instance {
         let backend = InstanceBackend::K8S(K8sInstanceInfo {
             k8s_node,
             instance_config,
-            kube,
+            cluster_swarm,
         });
         Instance {
             peer_name,
@@ -239,15 +240,18 @@ impl Instance {
 
     pub async fn stop(&self) -> Result<()> {
         let backend = self.k8s_backend();
-        backend.kube.delete_node(&backend.instance_config).await
+        backend
+            .cluster_swarm
+            .delete_node(&backend.instance_config)
+            .await
     }
 
     /// Node must be stopped first
     pub async fn start(&self, delete_data: bool) -> Result<()> {
         let backend = self.k8s_backend();
         backend
-            .kube
-            .upsert_node(backend.instance_config.clone(), delete_data)
+            .cluster_swarm
+            .spawn_new_instance(backend.instance_config.clone(), delete_data)
             .await
             .map(|_| ())
     }
@@ -261,7 +265,7 @@ impl Instance {
     pub async fn util_cmd<S: AsRef<str>>(&self, command: S, job_name: &str) -> Result<()> {
         let backend = self.k8s_backend();
         backend
-            .kube
+            .cluster_swarm
             .run(
                 &backend.k8s_node,
                 "853397791086.dkr.ecr.us-west-2.amazonaws.com/cluster-test-util:latest",
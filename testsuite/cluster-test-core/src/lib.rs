@@ -0,0 +1,24 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The parts of cluster-test that do not depend on where the nodes run: the instances and
+//! clusters of nodes, the health checks and the transaction emitter. They are shared by
+//! cluster-test, which runs the nodes on Kubernetes, and libra-swarm, which runs them as local
+//! processes.
+
+pub mod atomic_histogram;
+pub mod cluster;
+pub mod cluster_swarm;
+pub mod health;
+pub mod instance;
+pub mod tx_emitter;
+
+pub mod util {
+    use std::time::{Duration, SystemTime};
+
+    pub fn unix_timestamp_now() -> Duration {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("now < UNIX_EPOCH")
+    }
+}
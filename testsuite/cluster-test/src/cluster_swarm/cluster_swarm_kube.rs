@@ -379,37 +379,6 @@ impl ClusterSwarmKube {
         Ok(workspace.clone())
     }
 
-    pub async fn run(
-        &self,
-        k8s_node: &str,
-        docker_image: &str,
-        command: &str,
-        job_name: &str,
-    ) -> Result<()> {
-        let back_off_limit = 0;
-        let suffix = thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(10)
-            .collect::<String>()
-            .to_ascii_lowercase();
-        let job_full_name = format!("{}-{}", job_name, suffix);
-        let job_yaml = format!(
-            include_str!("job_template.yaml"),
-            name = &job_full_name,
-            label = job_name,
-            image = docker_image,
-            node_name = k8s_node,
-            command = command,
-            back_off_limit = back_off_limit,
-        );
-        let job_spec: serde_yaml::Value = serde_yaml::from_str(&job_yaml)?;
-        let job_spec = serde_json::value::to_value(job_spec)?;
-        let job_spec = serde_json::from_value(job_spec)
-            .map_err(|e| format_err!("serde_json::from_value failed: {}", e))?;
-        debug!("Running job {} for node {}", job_name, k8s_node);
-        self.run_jobs(vec![job_spec], back_off_limit).await
-    }
-
     async fn allocate_node(&self, pod_name: &str) -> Result<KubeNode> {
         libra_retrier::retry_async(libra_retrier::fixed_retry_strategy(5000, 15), || {
             Box::pin(async move { self.allocate_node_impl(pod_name).await })
@@ -534,18 +503,11 @@ impl ClusterSwarmKube {
             node.name.clone(),
             instance_config.clone(),
             self.http_client.clone(),
-            self.clone(),
+            Arc::new(self.clone()),
         );
         Ok(instance)
     }
 
-    pub async fn delete_node(&self, instance_config: &InstanceConfig) -> Result<()> {
-        let pod_name = instance_config.pod_name();
-        let service_name = pod_name.clone();
-        self.delete_resource::<Pod>(&pod_name).await?;
-        self.delete_resource::<Service>(&service_name).await
-    }
-
     async fn remove_all_network_effects(&self) -> Result<()> {
         libra_retrier::retry_async(libra_retrier::fixed_retry_strategy(5000, 3), || {
             Box::pin(async move { self.remove_all_network_effects_helper().await })
@@ -646,6 +608,44 @@ impl ClusterSwarm for ClusterSwarmKube {
         self.upsert_node(instance_config, delete_data).await
     }
 
+    async fn delete_node(&self, instance_config: &InstanceConfig) -> Result<()> {
+        let pod_name = instance_config.pod_name();
+        let service_name = pod_name.clone();
+        self.delete_resource::<Pod>(&pod_name).await?;
+        self.delete_resource::<Service>(&service_name).await
+    }
+
+    async fn run(
+        &self,
+        k8s_node: &str,
+        docker_image: &str,
+        command: &str,
+        job_name: &str,
+    ) -> Result<()> {
+        let back_off_limit = 0;
+        let suffix = thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(10)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let job_full_name = format!("{}-{}", job_name, suffix);
+        let job_yaml = format!(
+            include_str!("job_template.yaml"),
+            name = &job_full_name,
+            label = job_name,
+            image = docker_image,
+            node_name = k8s_node,
+            command = command,
+            back_off_limit = back_off_limit,
+        );
+        let job_spec: serde_yaml::Value = serde_yaml::from_str(&job_yaml)?;
+        let job_spec = serde_json::value::to_value(job_spec)?;
+        let job_spec = serde_json::from_value(job_spec)
+            .map_err(|e| format_err!("serde_json::from_value failed: {}", e))?;
+        debug!("Running job {} for node {}", job_name, k8s_node);
+        self.run_jobs(vec![job_spec], back_off_limit).await
+    }
+
     async fn get_grafana_baseurl(&self) -> Result<String> {
         let workspace = self.get_workspace().await?;
         Ok(format!(
@@ -4,4 +4,6 @@
 #![forbid(unsafe_code)]
 
 pub mod client;
+pub mod orchestrator;
 pub mod swarm;
+pub mod topology;
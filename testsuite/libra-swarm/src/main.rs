@@ -5,9 +5,9 @@
 
 use libra_config::config::NodeConfig;
 use libra_management::config_builder::FullnodeType;
-use libra_swarm::{client, swarm::LibraSwarm};
+use libra_swarm::{client, orchestrator::Orchestrator, swarm::LibraSwarm, topology::Topology};
 use libra_temppath::TempPath;
-use std::path::Path;
+use std::{path::Path, process};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -31,6 +31,11 @@ struct Args {
     /// swarm.
     #[structopt(short = "f", long, default_value = "0")]
     pub num_full_nodes: usize,
+    /// Start the nodes described in this topology file, run a transaction workload while
+    /// injecting its failures and print a health report. The number of nodes and the client
+    /// options are ignored.
+    #[structopt(short = "t", long)]
+    pub topology: Option<String>,
 }
 
 fn main() {
@@ -40,6 +45,11 @@ fn main() {
 
     libra_logger::Logger::new().init();
 
+    if let Some(topology) = &args.topology {
+        run_topology(topology, args.config_dir.clone(), !args.enable_logging);
+        return;
+    }
+
     let mut validator_swarm =
         LibraSwarm::configure_validator_swarm(num_nodes, args.config_dir.clone(), None)
             .expect("Failed to configure validator swarm");
@@ -155,3 +165,21 @@ fn main() {
 
     println!("Exit libra-swarm.");
 }
+
+fn run_topology(topology_path: &str, config_dir: Option<String>, disable_logging: bool) {
+    let topology = Topology::load(topology_path).expect("Failed to load topology");
+    let mut orchestrator = Orchestrator::configure(topology, config_dir.clone(), disable_logging)
+        .expect("Failed to configure topology");
+    orchestrator.launch().expect("Failed to launch topology");
+    let report = orchestrator.run().expect("Failed to run topology");
+    println!("{}", report);
+    // Stop the nodes and remove the network delays before exiting
+    drop(orchestrator);
+
+    if let Some(dir) = &config_dir {
+        println!("Please manually cleanup {:?} after inspection", dir);
+    }
+    if !report.is_healthy() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs a `Topology` as local processes: launches its nodes, injects its network delays and
+//! failures while a transaction workload runs, and reports the health of the testnet as seen
+//! by the health checks of cluster-test-core.
+//!
+//! Network delays and partitions are injected by the nodes themselves: their networks are
+//! configured with fault injection, which is controlled through the `network_faults` admin
+//! command of their debug interface. Running a topology thus needs no privileges.
+
+use crate::{
+    swarm::LibraSwarm,
+    topology::{FailureAction, FailureSpec, NodeIndex, Topology},
+};
+use anyhow::{format_err, Result};
+use cluster_test_core::{
+    cluster::Cluster,
+    health::{
+        CommitHistoryHealthCheck, DebugPortLogWorker, HealthCheckRunner, LivenessHealthCheck,
+        PrintFailures,
+    },
+    instance::Instance,
+    tx_emitter::{EmitJobRequest, EmitThreadParams, TxEmitter, TxStats},
+};
+use config_builder::SwarmConfig;
+use debug_interface::NodeDebugClient;
+use libra_config::config::{NodeConfig, HANDSHAKE_VERSION};
+use libra_crypto::HashValue;
+use libra_logger::prelude::*;
+use libra_management::config_builder::FullnodeType;
+use reqwest::Client;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use tokio::runtime::Runtime;
+
+pub struct Orchestrator {
+    topology: Topology,
+    validator_swarm: LibraSwarm,
+    // One swarm per full node, in the order of `topology.full_nodes`
+    full_node_swarms: Vec<LibraSwarm>,
+    disable_logging: bool,
+    // Token of the admin interface of all the nodes, used to inject the network faults
+    admin_token: String,
+}
+
+impl Orchestrator {
+    /// Generate the configs of all the nodes of the topology. If `config_dir` is given, the
+    /// validators are set up in its `validators` subdirectory and each full node in the
+    /// subdirectory named after it.
+    pub fn configure(
+        topology: Topology,
+        config_dir: Option<String>,
+        disable_logging: bool,
+    ) -> Result<Self> {
+        let node_dir = |name: &str| {
+            config_dir
+                .as_ref()
+                .map(|dir| Path::new(dir).join(name).to_string_lossy().into_owned())
+        };
+        let admin_token = HashValue::random().to_hex();
+        let template = |mut config: NodeConfig| {
+            config.debug_interface.admin_token = Some(admin_token.clone());
+            for network in config
+                .validator_network
+                .iter_mut()
+                .chain(config.full_node_networks.iter_mut())
+            {
+                network.fault_injection = true;
+            }
+            Some(config)
+        };
+
+        let validator_swarm = LibraSwarm::configure_validator_swarm(
+            topology.validators.len(),
+            node_dir("validators"),
+            template(NodeConfig::default_for_validator()),
+        )?;
+
+        let mut full_node_swarms: Vec<LibraSwarm> = vec![];
+        for full_node in &topology.full_nodes {
+            let upstream = full_node
+                .upstream
+                .as_ref()
+                .and_then(|upstream| topology.node_index(upstream));
+            let swarm = match upstream {
+                Some(NodeIndex::Validator(index)) => {
+                    let upstream_config = SwarmConfig {
+                        config_files: vec![validator_swarm.config.config_files[index].clone()],
+                        faucet_key_path: validator_swarm.config.faucet_key_path.clone(),
+                        waypoint: validator_swarm.config.waypoint,
+                    };
+                    LibraSwarm::configure_fn_swarm(
+                        node_dir(&full_node.name),
+                        template(NodeConfig::default_for_validator_full_node()),
+                        &upstream_config,
+                        FullnodeType::ValidatorFullnode,
+                    )?
+                }
+                _ => {
+                    let swarm = LibraSwarm::configure_fn_swarm(
+                        node_dir(&full_node.name),
+                        template(NodeConfig::default_for_public_full_node()),
+                        &validator_swarm.config,
+                        FullnodeType::PublicFullnode(1),
+                    )?;
+                    let seeds = full_node
+                        .upstream
+                        .iter()
+                        .chain(full_node.seeds.iter())
+                        .map(|seed| match topology.node_index(seed) {
+                            Some(NodeIndex::FullNode(index)) => {
+                                full_node_swarms[index].config.config_files[0].clone()
+                            }
+                            _ => unreachable!("Seeds are validated to be full nodes"),
+                        })
+                        .collect::<Vec<_>>();
+                    add_seeds(&swarm.config.config_files[0], &seeds)?;
+                    swarm
+                }
+            };
+            full_node_swarms.push(swarm);
+        }
+
+        Ok(Self {
+            topology,
+            validator_swarm,
+            full_node_swarms,
+            disable_logging,
+            admin_token,
+        })
+    }
+
+    /// Start all the nodes and wait for them to be connected, then add the network delays.
+    pub fn launch(&mut self) -> Result<()> {
+        self.validator_swarm.launch_attempt(self.disable_logging)?;
+        for swarm in &mut self.full_node_swarms {
+            swarm.launch_attempt(self.disable_logging)?;
+        }
+
+        for delay in &self.topology.network_delays {
+            self.add_network_delay(&delay.node)?;
+        }
+        Ok(())
+    }
+
+    /// Run the workload and inject the failures of the topology, checking the health of the
+    /// nodes every second.
+    pub fn run(&mut self) -> Result<HealthReport> {
+        let mut runtime = Runtime::new()?;
+        let cluster = self.cluster()?;
+        let workload = &self.topology.workload;
+        let duration = Duration::from_secs(workload.duration_secs);
+        let recovery = Duration::from_secs(workload.recovery_secs);
+
+        let instances = if workload.targets.is_empty() {
+            cluster.validator_instances().to_vec()
+        } else {
+            cluster
+                .all_instances()
+                .filter(|instance| workload.targets.contains(instance.peer_name()))
+                .cloned()
+                .collect()
+        };
+        let request = EmitJobRequest {
+            instances,
+            accounts_per_client: workload.accounts_per_client,
+            workers_per_ac: workload.workers_per_ac,
+            thread_params: EmitThreadParams {
+                wait_millis: workload.wait_millis,
+                wait_committed: true,
+            },
+        };
+        let mut emitter = TxEmitter::new(&cluster);
+        let job = runtime
+            .block_on(emitter.start_job(request))
+            .map_err(|e| format_err!("Failed to start emit job: {}", e))?;
+        let logs = runtime.enter(|| DebugPortLogWorker::spawn_new(&cluster).0);
+        // The full node check compares the full nodes to the validators of their Kubernetes
+        // validator group, local nodes have none
+        let liveness_health_check = LivenessHealthCheck::new(&cluster);
+        let mut health_check_runner = HealthCheckRunner::new(
+            cluster,
+            vec![
+                Box::new(CommitHistoryHealthCheck::new()),
+                Box::new(liveness_health_check),
+            ],
+        );
+
+        let mut report = HealthReport::default();
+        let mut schedule = self.topology.failure_schedule().into_iter().peekable();
+        let mut killed = HashSet::new();
+        let mut partitioned = HashSet::new();
+        let mut recovering: HashMap<String, Instant> = HashMap::new();
+        // Restarted nodes lose their network delay, it is added again once they are up
+        let mut undelayed = HashSet::new();
+        let start = Instant::now();
+        while start.elapsed() < duration {
+            while schedule.peek().map_or(false, |failure| {
+                Duration::from_secs(failure.at_secs) <= start.elapsed()
+            }) {
+                let failure = schedule.next().expect("Peeked failure");
+                info!("Injecting {:?}", failure);
+                match failure.action {
+                    FailureAction::Kill => {
+                        self.kill_node(&failure.node);
+                        recovering.remove(&failure.node);
+                        killed.insert(failure.node.clone());
+                    }
+                    FailureAction::Restart => {
+                        self.restart_node(&failure.node)?;
+                        health_check_runner.invalidate(&failure.node);
+                        killed.remove(&failure.node);
+                        recovering.insert(failure.node.clone(), Instant::now() + recovery);
+                        if self.network_delay(&failure.node).is_some() {
+                            undelayed.insert(failure.node.clone());
+                        }
+                    }
+                    FailureAction::Partition => {
+                        self.network_faults(&failure.node, &[("isolated", "true")])?;
+                        recovering.remove(&failure.node);
+                        partitioned.insert(failure.node.clone());
+                    }
+                    FailureAction::Heal => {
+                        self.network_faults(&failure.node, &[("isolated", "false")])?;
+                        partitioned.remove(&failure.node);
+                        recovering.insert(failure.node.clone(), Instant::now() + recovery);
+                    }
+                }
+                report.failures.push((start.elapsed(), failure));
+            }
+            undelayed.retain(|node: &String| match self.add_network_delay(node) {
+                Ok(()) => false,
+                Err(e) => {
+                    debug!("Network delay of {} not added yet: {}", node, e);
+                    true
+                }
+            });
+            let now = Instant::now();
+            recovering.retain(|_, deadline| *deadline > now);
+
+            let affected = killed
+                .iter()
+                .chain(partitioned.iter())
+                .chain(recovering.keys())
+                .cloned()
+                .collect::<HashSet<_>>();
+            let events = logs.recv_all_until_deadline(now + Duration::from_secs(1));
+            let result = runtime.block_on(health_check_runner.run(
+                &events,
+                &affected,
+                PrintFailures::UnexpectedOnly,
+            ));
+            report.checks += 1;
+            if let Err(unexpected_failures) = result {
+                for node in unexpected_failures.to_string().split(',') {
+                    *report
+                        .unexpected_failures
+                        .entry(node.to_string())
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        report.duration = start.elapsed();
+        report.stats = runtime.block_on(emitter.stop_job(job));
+        Ok(report)
+    }
+
+    /// The nodes as cluster-test-core instances, named after the topology.
+    fn cluster(&self) -> Result<Cluster> {
+        let http_client = Client::new();
+        let instance = |name: &str, config_path: &PathBuf| -> Result<Instance> {
+            let config = NodeConfig::load(config_path)?;
+            Ok(Instance::new(
+                name.to_string(),
+                "localhost".to_string(),
+                config.rpc.address.port() as u32,
+                Some(config.debug_interface.admission_control_node_debug_port as u32),
+                http_client.clone(),
+            ))
+        };
+
+        let validator_instances = self
+            .topology
+            .validators
+            .iter()
+            .zip(self.validator_swarm.config.config_files.iter())
+            .map(|(name, path)| instance(name, path))
+            .collect::<Result<Vec<_>>>()?;
+        let fullnode_instances = self
+            .topology
+            .full_nodes
+            .iter()
+            .zip(self.full_node_swarms.iter())
+            .map(|(full_node, swarm)| instance(&full_node.name, &swarm.config.config_files[0]))
+            .collect::<Result<Vec<_>>>()?;
+        let mint_file = self
+            .validator_swarm
+            .config
+            .faucet_key_path
+            .to_str()
+            .ok_or_else(|| format_err!("Unable to read faucet path"))?;
+        Ok(Cluster::from_instances(
+            validator_instances,
+            fullnode_instances,
+            mint_file,
+        ))
+    }
+
+    /// The swarm running a node and the index of the node in it.
+    fn swarm_mut(&mut self, name: &str) -> (&mut LibraSwarm, usize) {
+        match self.topology.node_index(name) {
+            Some(NodeIndex::Validator(index)) => (&mut self.validator_swarm, index),
+            Some(NodeIndex::FullNode(index)) => (&mut self.full_node_swarms[index], 0),
+            None => panic!("Unknown node {}", name),
+        }
+    }
+
+    fn config_path(&self, name: &str) -> PathBuf {
+        match self.topology.node_index(name) {
+            Some(NodeIndex::Validator(index)) => {
+                self.validator_swarm.config.config_files[index].clone()
+            }
+            Some(NodeIndex::FullNode(index)) => {
+                self.full_node_swarms[index].config.config_files[0].clone()
+            }
+            None => panic!("Unknown node {}", name),
+        }
+    }
+
+    fn kill_node(&mut self, name: &str) {
+        let (swarm, index) = self.swarm_mut(name);
+        swarm.kill_node(index);
+    }
+
+    fn restart_node(&mut self, name: &str) -> Result<()> {
+        let disable_logging = self.disable_logging;
+        let (swarm, index) = self.swarm_mut(name);
+        swarm.restart_node(index, disable_logging)
+    }
+
+    /// The network delay of a node in the topology, if any.
+    fn network_delay(&self, name: &str) -> Option<u64> {
+        self.topology
+            .network_delays
+            .iter()
+            .find(|delay| delay.node == name)
+            .map(|delay| delay.delay_ms)
+    }
+
+    fn add_network_delay(&self, name: &str) -> Result<()> {
+        if let Some(delay_ms) = self.network_delay(name) {
+            self.network_faults(name, &[("latency_ms", &delay_ms.to_string())])?;
+        }
+        Ok(())
+    }
+
+    /// Changes the faults injected in all the networks of a node, see the `network_faults` admin
+    /// command of libra-node for the parameters.
+    fn network_faults(&self, name: &str, params: &[(&str, &str)]) -> Result<()> {
+        let config = NodeConfig::load(self.config_path(name))?;
+        let mut debug_client = NodeDebugClient::new(
+            "localhost",
+            config.debug_interface.admission_control_node_debug_port,
+        );
+        debug_client.admin(&self.admin_token, "network_faults", params)?;
+        Ok(())
+    }
+}
+
+/// Add the public networks of the full nodes configured in `seed_paths` as seeds of the public
+/// network of the full node configured in `config_path`.
+fn add_seeds(config_path: &PathBuf, seed_paths: &[PathBuf]) -> Result<()> {
+    let mut config = NodeConfig::load(config_path)?;
+    let network = config
+        .full_node_networks
+        .first_mut()
+        .ok_or_else(|| format_err!("No public network in {:?}", config_path))?;
+    for seed_path in seed_paths {
+        let mut seed_config = NodeConfig::load(seed_path)?;
+        // Public full nodes get a random identity when their config is loaded, keep it
+        seed_config.save(seed_path)?;
+        let seed_network = seed_config
+            .full_node_networks
+            .first_mut()
+            .ok_or_else(|| format_err!("No public network in {:?}", seed_path))?;
+        let seed_pub_key = match seed_network.identity.public_key_from_config() {
+            Some(pub_key) => pub_key,
+            None => seed_network.identity_key().public_key(),
+        };
+        let seed_address = seed_network
+            .listen_address
+            .clone()
+            .append_prod_protos(seed_pub_key, HANDSHAKE_VERSION);
+        network
+            .seed_addrs
+            .insert(seed_network.peer_id(), vec![seed_address]);
+    }
+    config.save(config_path)?;
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+pub struct HealthReport {
+    pub duration: Duration,
+    /// Failures injected, with the time elapsed since the start of the workload.
+    pub failures: Vec<(Duration, FailureSpec)>,
+    /// Number of health checks run, one per second.
+    pub checks: usize,
+    /// For each node, the number of health checks it failed while not killed or recovering.
+    pub unexpected_failures: BTreeMap<String, usize>,
+    pub stats: TxStats,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.unexpected_failures.is_empty() && self.stats.committed > 0
+    }
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Ran for {}s", self.duration.as_secs())?;
+        for (elapsed, failure) in &self.failures {
+            writeln!(
+                f,
+                "  {:?} {} at {}s",
+                failure.action,
+                failure.node,
+                elapsed.as_secs()
+            )?;
+        }
+        writeln!(f, "Transactions: {}", self.stats)?;
+        if self.stats.committed > 0 {
+            writeln!(f, "Average rate: {}", self.stats.rate(self.duration))?;
+        }
+        if self.unexpected_failures.is_empty() {
+            write!(f, "All {} health checks passed", self.checks)
+        } else {
+            write!(
+                f,
+                "Unexpected failures out of {} health checks:",
+                self.checks
+            )?;
+            for (node, count) in &self.unexpected_failures {
+                write!(f, "\n  {}: {}", node, count)?;
+            }
+            Ok(())
+        }
+    }
+}
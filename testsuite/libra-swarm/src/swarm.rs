@@ -530,8 +530,26 @@ impl LibraSwarm {
         idx: usize,
         disable_logging: bool,
     ) -> Result<(), SwarmLaunchFailure> {
-        // First take the configs out to not keep immutable borrow on self when calling
-        // `launch_node`.
+        let node_id = format!("{}", idx);
+        let mut node = self.launch_node(idx, disable_logging).unwrap();
+        for _ in 0..60 {
+            if let HealthStatus::Healthy = node.health_check() {
+                self.nodes.insert(node_id, node);
+                return self.wait_for_connectivity(self.nodes.len() as i64 - 1);
+            }
+            ::std::thread::sleep(::std::time::Duration::from_millis(1000));
+        }
+        Err(SwarmLaunchFailure::LaunchTimeout)
+    }
+
+    /// Launch again a node removed by `kill_node`, without waiting for it to be healthy.
+    pub fn restart_node(&mut self, idx: usize, disable_logging: bool) -> Result<()> {
+        let node = self.launch_node(idx, disable_logging)?;
+        self.nodes.insert(format!("{}", idx), node);
+        Ok(())
+    }
+
+    fn launch_node(&self, idx: usize, disable_logging: bool) -> Result<LibraNode> {
         let path = self
             .config
             .config_files
@@ -543,24 +561,14 @@ impl LibraSwarm {
             .as_ref()
             .join("logs")
             .join(format!("{}.struct.log", idx));
-        let node_id = format!("{}", idx);
-        let mut node = LibraNode::launch(
-            node_id.clone(),
+        LibraNode::launch(
+            format!("{}", idx),
             self.role,
             path,
             log_file_path,
             struct_log_file_path,
             disable_logging,
         )
-        .unwrap();
-        for _ in 0..60 {
-            if let HealthStatus::Healthy = node.health_check() {
-                self.nodes.insert(node_id, node);
-                return self.wait_for_connectivity(self.nodes.len() as i64 - 1);
-            }
-            ::std::thread::sleep(::std::time::Duration::from_millis(1000));
-        }
-        Err(SwarmLaunchFailure::LaunchTimeout)
     }
 }
 
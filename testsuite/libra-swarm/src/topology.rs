@@ -0,0 +1,549 @@
+// Copyright (c) The Libra Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A declarative description of a local testnet: its validators, its full nodes and how they
+//! connect, the network delays of the nodes and a schedule of failures and partitions to inject
+//! while a transaction workload runs. For example:
+//!
+//! ```yaml
+//! validators: [val-0, val-1, val-2, val-3]
+//! full_nodes:
+//!   - name: vfn-0
+//!     upstream: val-0
+//!   - name: pfn-0
+//!     upstream: vfn-0
+//! network_delays:
+//!   - node: val-3
+//!     delay_ms: 150
+//! failures:
+//!   - node: val-1
+//!     at_secs: 30
+//!     action: kill
+//!   - node: val-1
+//!     at_secs: 60
+//!     action: restart
+//!   - node: val-2
+//!     at_secs: 70
+//!     action: partition
+//!   - node: val-2
+//!     at_secs: 90
+//!     action: heal
+//! workload:
+//!   duration_secs: 120
+//! ```
+
+use anyhow::{bail, ensure, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fs, path::Path};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Topology {
+    /// Names of the validators, there is at least one.
+    pub validators: Vec<String>,
+    #[serde(default)]
+    pub full_nodes: Vec<FullNodeSpec>,
+    #[serde(default)]
+    pub network_delays: Vec<NetworkDelaySpec>,
+    #[serde(default)]
+    pub failures: Vec<FailureSpec>,
+    #[serde(default)]
+    pub workload: WorkloadSpec,
+}
+
+/// A full node. With a validator as upstream, it is the validator full node of that validator.
+/// Otherwise, it is a public full node that syncs from its upstream full node and its seeds, or
+/// from the peers discovered on-chain if it has none.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FullNodeSpec {
+    pub name: String,
+    #[serde(default)]
+    pub upstream: Option<String>,
+    /// Other full nodes the public network of this node connects to.
+    #[serde(default)]
+    pub seeds: Vec<String>,
+}
+
+/// Delay added to the data received by a node on all its network connections, both dialed and
+/// accepted.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkDelaySpec {
+    pub node: String,
+    pub delay_ms: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FailureSpec {
+    pub node: String,
+    /// Seconds since the start of the workload.
+    pub at_secs: u64,
+    pub action: FailureAction,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureAction {
+    Kill,
+    Restart,
+    /// Closes all the network connections of the node and refuses new ones.
+    Partition,
+    /// Lets a partitioned node connect again.
+    Heal,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorkloadSpec {
+    pub duration_secs: u64,
+    /// Nodes the transactions are submitted to, all the validators if empty.
+    pub targets: Vec<String>,
+    pub accounts_per_client: usize,
+    pub workers_per_ac: Option<usize>,
+    pub wait_millis: u64,
+    /// Seconds during which a restarted node is not expected to be healthy.
+    pub recovery_secs: u64,
+}
+
+impl Default for WorkloadSpec {
+    fn default() -> Self {
+        Self {
+            duration_secs: 60,
+            targets: vec![],
+            accounts_per_client: 15,
+            workers_per_ac: None,
+            wait_millis: 0,
+            recovery_secs: 30,
+        }
+    }
+}
+
+/// Index of a node in its list of the topology.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NodeIndex {
+    Validator(usize),
+    FullNode(usize),
+}
+
+impl Topology {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let topology: Self = serde_yaml::from_str(&contents)?;
+        topology.validate()?;
+        Ok(topology)
+    }
+
+    pub fn node_index(&self, name: &str) -> Option<NodeIndex> {
+        if let Some(index) = self.validators.iter().position(|v| v == name) {
+            return Some(NodeIndex::Validator(index));
+        }
+        self.full_nodes
+            .iter()
+            .position(|f| f.name == name)
+            .map(NodeIndex::FullNode)
+    }
+
+    /// The failures in the order they are injected.
+    pub fn failure_schedule(&self) -> Vec<FailureSpec> {
+        let mut schedule = self.failures.clone();
+        schedule.sort_by_key(|failure| failure.at_secs);
+        schedule
+    }
+
+    /// Check that all the names are unique and refer to nodes, that full nodes only refer to the
+    /// nodes declared before them, and that the failures only kill or partition running nodes,
+    /// restart killed ones and heal partitioned ones.
+    pub fn validate(&self) -> Result<()> {
+        ensure!(
+            !self.validators.is_empty(),
+            "At least one validator is needed"
+        );
+
+        let mut names = HashSet::new();
+        for name in self
+            .validators
+            .iter()
+            .chain(self.full_nodes.iter().map(|f| &f.name))
+        {
+            ensure!(names.insert(name), "Duplicate node name {}", name);
+        }
+
+        let mut vfn_upstreams = HashSet::new();
+        for (index, full_node) in self.full_nodes.iter().enumerate() {
+            let is_earlier_full_node = |name: &str| match self.node_index(name) {
+                Some(NodeIndex::FullNode(i)) => i < index,
+                _ => false,
+            };
+            if let Some(upstream) = &full_node.upstream {
+                match self.node_index(upstream) {
+                    Some(NodeIndex::Validator(_)) => {
+                        ensure!(
+                            full_node.seeds.is_empty(),
+                            "Validator full node {} cannot have seeds",
+                            full_node.name
+                        );
+                        ensure!(
+                            vfn_upstreams.insert(upstream),
+                            "Validator {} has more than one validator full node",
+                            upstream
+                        );
+                    }
+                    _ => ensure!(
+                        is_earlier_full_node(upstream),
+                        "Upstream {} of {} must be a validator or a full node declared before it",
+                        upstream,
+                        full_node.name
+                    ),
+                }
+            }
+            for seed in &full_node.seeds {
+                ensure!(
+                    is_earlier_full_node(seed),
+                    "Seed {} of {} must be a full node declared before it",
+                    seed,
+                    full_node.name
+                );
+            }
+        }
+
+        for delay in &self.network_delays {
+            ensure!(
+                self.node_index(&delay.node).is_some(),
+                "Unknown node {} in network delays",
+                delay.node
+            );
+        }
+        for target in &self.workload.targets {
+            ensure!(
+                self.node_index(target).is_some(),
+                "Unknown node {} in workload targets",
+                target
+            );
+        }
+
+        let mut killed = HashSet::new();
+        let mut partitioned = HashSet::new();
+        for failure in self.failure_schedule() {
+            ensure!(
+                self.node_index(&failure.node).is_some(),
+                "Unknown node {} in failures",
+                failure.node
+            );
+            ensure!(
+                failure.at_secs < self.workload.duration_secs,
+                "Failure of {} at {}s is after the end of the workload",
+                failure.node,
+                failure.at_secs
+            );
+            match failure.action {
+                // A restarted node would come back without its partition
+                FailureAction::Kill if partitioned.contains(&failure.node) => bail!(
+                    "{} is killed at {}s but is partitioned",
+                    failure.node,
+                    failure.at_secs
+                ),
+                FailureAction::Kill if !killed.insert(failure.node.clone()) => bail!(
+                    "{} is killed at {}s but is not running",
+                    failure.node,
+                    failure.at_secs
+                ),
+                FailureAction::Partition if killed.contains(&failure.node) => bail!(
+                    "{} is partitioned at {}s but is not running",
+                    failure.node,
+                    failure.at_secs
+                ),
+                FailureAction::Partition if !partitioned.insert(failure.node.clone()) => bail!(
+                    "{} is partitioned at {}s but is already partitioned",
+                    failure.node,
+                    failure.at_secs
+                ),
+                FailureAction::Heal if !partitioned.remove(&failure.node) => bail!(
+                    "{} is healed at {}s but is not partitioned",
+                    failure.node,
+                    failure.at_secs
+                ),
+                FailureAction::Restart if !killed.remove(&failure.node) => bail!(
+                    "{} is restarted at {}s but is not killed",
+                    failure.node,
+                    failure.at_secs
+                ),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topology(yaml: &str) -> Topology {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn validation_error(yaml: &str) -> String {
+        topology(yaml).validate().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn valid_topology() {
+        topology(
+            r#"
+validators: [val-0, val-1]
+full_nodes:
+  - name: vfn-0
+    upstream: val-0
+  - name: pfn-0
+    upstream: vfn-0
+  - name: pfn-1
+    seeds: [pfn-0]
+network_delays:
+  - node: pfn-1
+    delay_ms: 100
+workload:
+  targets: [val-1, pfn-0]
+"#,
+        )
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn duplicate_names() {
+        assert_eq!(
+            validation_error("validators: [val-0, val-0]"),
+            "Duplicate node name val-0"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+full_nodes:
+  - name: val-0
+"#
+            ),
+            "Duplicate node name val-0"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+full_nodes:
+  - name: pfn-0
+  - name: pfn-0
+"#
+            ),
+            "Duplicate node name pfn-0"
+        );
+    }
+
+    #[test]
+    fn unknown_names() {
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+full_nodes:
+  - name: pfn-0
+    upstream: pfn-1
+"#
+            ),
+            "Upstream pfn-1 of pfn-0 must be a validator or a full node declared before it"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+full_nodes:
+  - name: pfn-0
+    seeds: [val-0]
+"#
+            ),
+            "Seed val-0 of pfn-0 must be a full node declared before it"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+network_delays:
+  - node: val-1
+    delay_ms: 100
+"#
+            ),
+            "Unknown node val-1 in network delays"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+workload:
+  targets: [val-1]
+"#
+            ),
+            "Unknown node val-1 in workload targets"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-1
+    at_secs: 10
+    action: kill
+"#
+            ),
+            "Unknown node val-1 in failures"
+        );
+    }
+
+    #[test]
+    fn failure_schedule_is_sorted_by_time() {
+        let topology = topology(
+            r#"
+validators: [val-0, val-1]
+failures:
+  - node: val-0
+    at_secs: 40
+    action: restart
+  - node: val-1
+    at_secs: 10
+    action: kill
+  - node: val-0
+    at_secs: 20
+    action: kill
+  - node: val-1
+    at_secs: 20
+    action: restart
+"#,
+        );
+        topology.validate().unwrap();
+        let schedule = topology
+            .failure_schedule()
+            .into_iter()
+            .map(|failure| (failure.node, failure.at_secs, failure.action))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            schedule,
+            vec![
+                ("val-1".to_string(), 10, FailureAction::Kill),
+                // Failures at the same time keep their order
+                ("val-0".to_string(), 20, FailureAction::Kill),
+                ("val-1".to_string(), 20, FailureAction::Restart),
+                ("val-0".to_string(), 40, FailureAction::Restart),
+            ]
+        );
+    }
+
+    #[test]
+    fn failures_follow_the_schedule() {
+        // Listed after the kill, but scheduled before it
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 30
+    action: kill
+  - node: val-0
+    at_secs: 20
+    action: restart
+"#
+            ),
+            "val-0 is restarted at 20s but is not killed"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 10
+    action: kill
+  - node: val-0
+    at_secs: 20
+    action: kill
+"#
+            ),
+            "val-0 is killed at 20s but is not running"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 60
+    action: kill
+"#
+            ),
+            "Failure of val-0 at 60s is after the end of the workload"
+        );
+    }
+
+    #[test]
+    fn partitions_follow_the_schedule() {
+        topology(
+            r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 10
+    action: partition
+  - node: val-0
+    at_secs: 20
+    action: heal
+  - node: val-0
+    at_secs: 30
+    action: kill
+"#,
+        )
+        .validate()
+        .unwrap();
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 10
+    action: heal
+"#
+            ),
+            "val-0 is healed at 10s but is not partitioned"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 10
+    action: partition
+  - node: val-0
+    at_secs: 20
+    action: kill
+"#
+            ),
+            "val-0 is killed at 20s but is partitioned"
+        );
+        assert_eq!(
+            validation_error(
+                r#"
+validators: [val-0]
+failures:
+  - node: val-0
+    at_secs: 10
+    action: kill
+  - node: val-0
+    at_secs: 20
+    action: partition
+"#
+            ),
+            "val-0 is partitioned at 20s but is not running"
+        );
+    }
+}
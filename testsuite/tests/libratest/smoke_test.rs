@@ -59,10 +59,13 @@ struct TestEnvironment {
 
 impl TestEnvironment {
     fn new(num_validators: usize) -> Self {
-        ::libra_logger::Logger::new().init();
         let mut template = NodeConfig::default_for_validator();
         template.state_sync.chunk_limit = 5;
+        Self::with_template(num_validators, template)
+    }
 
+    fn with_template(num_validators: usize, template: NodeConfig) -> Self {
+        ::libra_logger::Logger::new().init();
         let validator_swarm =
             LibraSwarm::configure_validator_swarm(num_validators, None, Some(template)).unwrap();
 
@@ -451,6 +454,64 @@ fn test_basic_fault_tolerance() {
     test_smoke_script(client_proxy);
 }
 
+#[test]
+fn test_network_partition() {
+    let admin_token = "partition-test-token";
+    let mut template = NodeConfig::default_for_validator();
+    template.state_sync.chunk_limit = 5;
+    template.debug_interface.admin_token = Some(admin_token.to_string());
+    template.validator_network.as_mut().unwrap().fault_injection = true;
+    let mut env = TestEnvironment::with_template(4, template);
+    env.validator_swarm.launch();
+
+    let peer_ids: Vec<_> = (0..2)
+        .map(|i| env.get_validator(i).unwrap().validator_peer_id().unwrap())
+        .collect();
+    let mut debug_clients: Vec<_> = (0..2)
+        .map(|i| env.get_validator_debug_interface_client(i))
+        .collect();
+    let connected = |debug_client: &mut NodeDebugClient, peer_id: &AccountAddress| {
+        let peers = debug_client
+            .admin(admin_token, "network_peers", &[])
+            .unwrap();
+        peers["Validator"].get(peer_id.to_string()).is_some()
+    };
+    let wait_until = |debug_clients: &mut Vec<NodeDebugClient>, expected: bool| {
+        for _ in 0..60 {
+            if connected(&mut debug_clients[0], &peer_ids[1]) == expected
+                && connected(&mut debug_clients[1], &peer_ids[0]) == expected
+            {
+                return;
+            }
+            sleep(Duration::from_secs(1));
+        }
+        panic!(
+            "validators 0 and 1 did not {}",
+            if expected { "connect" } else { "disconnect" }
+        );
+    };
+    wait_until(&mut debug_clients, true);
+
+    // Partitioning only blocks dials and outbound connections, so both sides have to do it.
+    for (i, debug_client) in debug_clients.iter_mut().enumerate() {
+        let peer_id = peer_ids[1 - i].to_string();
+        debug_client
+            .admin(admin_token, "network_faults", &[("partition", &peer_id)])
+            .unwrap();
+    }
+    wait_until(&mut debug_clients, false);
+
+    // The two partitioned validators still reach the others, so consensus makes progress.
+    test_smoke_script(env.get_validator_client(2, None));
+
+    for debug_client in debug_clients.iter_mut() {
+        debug_client
+            .admin(admin_token, "network_faults", &[("heal", "true")])
+            .unwrap();
+    }
+    wait_until(&mut debug_clients, true);
+}
+
 #[test]
 fn test_basic_restartability() {
     let (mut env, mut client_proxy) = setup_swarm_and_client_proxy(4, 0);
@@ -1517,3 +1578,105 @@ fn test_network_key_rotation() {
         validator_config.validator_network_identity_public_key
     );
 }
+
+#[test]
+/// Rotates the network key of a validator through KeyManager, once while restarting the validator
+/// before the reconfiguration lands and once without restarting it, and checks that every other
+/// validator can (re)connect to it before and after each reconfiguration.
+fn test_network_key_rotation_keeps_connectivity() {
+    let mut swarm = TestEnvironment::new(4);
+    swarm.validator_swarm.launch();
+
+    let association_info = AccountContext {
+        private_key: swarm.faucet_key.0,
+        address: association_address(),
+    };
+    let validator_swarm = &mut swarm.validator_swarm;
+    let node_configs: Vec<_> = validator_swarm
+        .validator_swarm
+        .config
+        .config_files
+        .iter()
+        .map(|config_path| NodeConfig::load(config_path).unwrap())
+        .collect();
+    let libra_interfaces: Vec<_> = node_configs.iter().map(get_libra_interface).collect();
+    let libra = libra_interfaces.first().unwrap();
+    let validator_network = node_configs[0].validator_network.as_ref().unwrap();
+    let validator_account = validator_network.peer_id();
+
+    // KeyManager shares the validator's on disk storage, which lacks the operator account
+    let backend = if let Identity::FromStorage(storage_identity) = &validator_network.identity {
+        storage_identity.backend.clone()
+    } else {
+        panic!("Couldn't load identity from storage");
+    };
+    let mut storage: Storage = (&backend).try_into().unwrap();
+    let operator_key = storage.export_private_key(OPERATOR_KEY).unwrap();
+    let operator_account =
+        libra_types::account_address::from_public_key(&operator_key.public_key());
+    storage
+        .set(
+            OPERATOR_ACCOUNT,
+            Value::String(operator_account.to_string()),
+        )
+        .unwrap();
+    let mut key_manager = libra_key_manager::KeyManager::new(
+        get_libra_interface(&node_configs[0]),
+        (&backend).try_into().unwrap(),
+        RealTimeService::new(),
+        u64::max_value(),
+        None,
+        1,
+        constants::TXN_EXPIRATION_SECS,
+    );
+
+    let time_service = RealTimeService::new();
+    let mut rotate_and_reconfigure =
+        |validator_swarm: &mut LibraSwarm, restart_before_reconfig: Option<usize>| {
+            let new_public_key = key_manager.rotate_network_key().unwrap();
+            wait_for_all_nodes(&libra_interfaces, |libra| {
+                libra
+                    .retrieve_validator_config(validator_account)
+                    .unwrap()
+                    .validator_network_identity_public_key
+                    == new_public_key
+            });
+
+            // Peers only know the old key until the reconfiguration
+            if let Some(idx) = restart_before_reconfig {
+                validator_swarm.kill_node(idx);
+                validator_swarm.add_node(idx, false).unwrap();
+            }
+
+            let last_reconfig = libra.last_reconfiguration().unwrap();
+            submit_new_reconfig(libra, &time_service, &association_info).unwrap();
+            wait_for_all_nodes(&libra_interfaces, |libra| {
+                libra.last_reconfiguration().unwrap() > last_reconfig
+                    && libra
+                        .retrieve_validator_info(validator_account)
+                        .unwrap()
+                        .network_identity_public_key()
+                        == new_public_key
+            });
+            new_public_key
+        };
+
+    // The validator restarts before the reconfiguration, so it has to answer with the old key
+    rotate_and_reconfigure(validator_swarm, Some(0));
+    // Afterwards peers only know the new key, which fresh connections have to use
+    validator_swarm.kill_node(1);
+    validator_swarm.add_node(1, false).unwrap();
+
+    // The validator keeps running while its key is rotated, so it has to pick up the new key
+    let new_public_key = rotate_and_reconfigure(validator_swarm, None);
+    validator_swarm.kill_node(2);
+    validator_swarm.add_node(2, false).unwrap();
+
+    assert_eq!(
+        new_public_key,
+        libra
+            .retrieve_validator_config(validator_account)
+            .unwrap()
+            .validator_network_identity_public_key
+    );
+}
@@ -7,10 +7,11 @@ use libra_crypto::HashValue;
 use move_core_types::gas_schedule::{CostTable, GasConstants};
 use serde::{Deserialize, Serialize};
 
-/// Defines and holds the publishing policies for the VM. There are three possible configurations:
+/// Defines and holds the publishing policies for the VM. There are four possible configurations:
 /// 1. No module publishing, only whitelisted scripts are allowed.
 /// 2. No module publishing, custom scripts are allowed.
 /// 3. Both module publishing and custom scripts are allowed.
+/// 4. As 3., and published modules can be replaced by compatible versions.
 /// We represent these as an enum instead of a struct since whitelisting and module/script
 /// publishing are mutually exclusive options.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -21,19 +22,31 @@ pub enum VMPublishingOption {
     CustomScripts,
     /// Allow both custom scripts and custom module publishing
     Open,
+    /// Allow custom scripts, custom module publishing, and upgrades of published modules that
+    /// are backward compatible
+    OpenWithUpgrades,
 }
 
 impl VMPublishingOption {
     pub fn is_open(&self) -> bool {
         match self {
-            VMPublishingOption::Open => true,
+            VMPublishingOption::Open | VMPublishingOption::OpenWithUpgrades => true,
+            _ => false,
+        }
+    }
+
+    pub fn allows_module_upgrades(&self) -> bool {
+        match self {
+            VMPublishingOption::OpenWithUpgrades => true,
             _ => false,
         }
     }
 
     pub fn is_allowed_script(&self, program: &[u8]) -> bool {
         match self {
-            VMPublishingOption::Open | VMPublishingOption::CustomScripts => true,
+            VMPublishingOption::Open
+            | VMPublishingOption::OpenWithUpgrades
+            | VMPublishingOption::CustomScripts => true,
             VMPublishingOption::Locked(whitelist) => {
                 let hash_value = HashValue::sha3_256_of(program);
                 whitelist.contains(hash_value.as_ref())
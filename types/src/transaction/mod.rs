@@ -293,6 +293,36 @@ impl RawTransaction {
     pub fn sender(&self) -> AccountAddress {
         self.sender
     }
+
+    /// Return the sequence number of this transaction.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Return the payload of this transaction.
+    pub fn payload(&self) -> &TransactionPayload {
+        &self.payload
+    }
+
+    /// Return the maximum amount of gas this transaction may use.
+    pub fn max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    /// Return the price of a unit of gas for this transaction.
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    /// Return the currency in which gas is paid.
+    pub fn gas_currency_code(&self) -> &str {
+        &self.gas_currency_code
+    }
+
+    /// Return the time after which this transaction is discarded.
+    pub fn expiration_time(&self) -> Duration {
+        self.expiration_time
+    }
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]